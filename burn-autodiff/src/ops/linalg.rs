@@ -0,0 +1,128 @@
+use super::{unary, Backward, Ops};
+use crate::grads::Gradients;
+use burn_tensor::{backend::Backend, linalg, Tensor};
+
+#[derive(Debug)]
+pub(crate) struct Cholesky;
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for Cholesky {
+    type State = B::TensorPrimitive<D>;
+
+    fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+        unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+            let factor = Tensor::<B, D>::from_primitive(ops.state);
+            let grad = Tensor::<B, D>::from_primitive(grad);
+
+            // gA = L^-T phi(L^T gL) L^-1, phi taking the lower triangular part with the diagonal
+            // halved, then symmetrized.
+            let phi = factor.clone().transpose().matmul(grad).tril(0);
+            let phi = phi.clone().sub(phi.tril(0).triu(0).mul_scalar(0.5));
+            let grad = linalg::solve(factor.clone().transpose(), phi);
+            let grad = linalg::solve(factor.transpose(), grad.transpose()).transpose();
+
+            grad.clone()
+                .add(grad.transpose())
+                .mul_scalar(0.5)
+                .into_primitive()
+        });
+    }
+}
+
+/// The output of the singular value decomposition, each output having its own node.
+///
+/// The singular values are row vectors of shape `[..., 1, k]`, reshaped afterward.
+#[derive(Debug)]
+pub(crate) enum SvdOutput {
+    U,
+    S,
+    Vt,
+}
+
+impl<B: Backend, const D: usize> Backward<B, D, 1> for SvdOutput {
+    /// The matrices `U`, the singular values as row vectors of shape `[..., 1, k]`, and the
+    /// matrices `V^T`.
+    type State = (
+        B::TensorPrimitive<D>,
+        B::TensorPrimitive<D>,
+        B::TensorPrimitive<D>,
+    );
+
+    fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+        let [node_parent] = ops.parents;
+        let grad = grads.consume::<B, D>(&ops.node);
+
+        if let Some(node) = node_parent {
+            let (u, s, vt) = ops.state;
+            let (u, s, vt) = (
+                Tensor::<B, D>::from_primitive(u),
+                Tensor::<B, D>::from_primitive(s),
+                Tensor::<B, D>::from_primitive(vt),
+            );
+            let grad = Tensor::<B, D>::from_primitive(grad);
+            let grad = match self {
+                SvdOutput::U => svd_backward(u, s, vt, Some(grad), None, None),
+                SvdOutput::S => svd_backward(u, s, vt, None, Some(grad), None),
+                SvdOutput::Vt => svd_backward(u, s, vt, None, None, Some(grad)),
+            };
+
+            grads.register::<B, D>(node, grad.into_primitive());
+        }
+    }
+}
+
+/// The gradient of the matrices decomposed by the singular value decomposition, the gradients of
+/// the outputs being optional since the gradient is linear in each of them.
+///
+/// The singular values and their gradient are row vectors of shape `[..., 1, k]`.
+fn svd_backward<B: Backend, const D: usize>(
+    u: Tensor<B, D>,
+    s: Tensor<B, D>,
+    vt: Tensor<B, D>,
+    grad_u: Option<Tensor<B, D>>,
+    grad_s: Option<Tensor<B, D>>,
+    grad_vt: Option<Tensor<B, D>>,
+) -> Tensor<B, D> {
+    let [m, k] = [u.dims()[D - 2], u.dims()[D - 1]];
+    let n = vt.dims()[D - 1];
+    let s_col = s.clone().transpose();
+
+    // E_ij = s_j^2 - s_i^2, with ones on the diagonal.
+    let s2 = s.clone().powf(2.0);
+    let e = s2.clone().sub(s2.transpose());
+    let e = e.clone().add(e.ones_like().tril(0).triu(0));
+    let skew = |x: Tensor<B, D>| x.clone().sub(x.transpose());
+
+    let mut inner = match &grad_s {
+        Some(grad) => grad.clone().transpose().mul(e.ones_like().tril(0).triu(0)),
+        None => e.zeros_like(),
+    };
+    if let Some(grad) = &grad_u {
+        let utgu = u.clone().transpose().matmul(grad.clone());
+        inner = inner.add(skew(utgu).div(e.clone()).mul(s.clone()));
+    }
+    if let Some(grad) = &grad_vt {
+        let vtgv = vt.clone().matmul(grad.clone().transpose());
+        inner = inner.add(s_col.clone().mul(skew(vtgv).div(e)));
+    }
+
+    match (grad_u, grad_vt) {
+        (Some(grad), _) if m > k => {
+            // gA = [U inner + (I - U U^T) gU S^-1] V^T
+            let grad = grad.div(s);
+            let projection = u.clone().matmul(u.clone().transpose().matmul(grad.clone()));
+
+            u.matmul(inner).add(grad).sub(projection).matmul(vt)
+        }
+        (_, Some(grad)) if n > k => {
+            // gA = U [inner V^T + S^-1 gV^T (I - V V^T)]
+            let grad = grad.div(s_col);
+            let projection = grad
+                .clone()
+                .matmul(vt.clone().transpose())
+                .matmul(vt.clone());
+
+            u.matmul(inner.matmul(vt).add(grad).sub(projection))
+        }
+        _ => u.matmul(inner).matmul(vt),
+    }
+}
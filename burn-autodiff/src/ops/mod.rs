@@ -6,6 +6,7 @@ mod int_tensor;
 mod module;
 mod tensor;
 
+pub(crate) mod linalg;
 pub(crate) mod maxmin;
 
 pub use backward::*;
@@ -149,6 +149,110 @@ impl<B: Backend> ModuleOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         }
     }
 
+    fn conv3d(
+        x: ADTensor<B, 5>,
+        weight: ADTensor<B, 5>,
+        bias: Option<ADTensor<B, 1>>,
+        options: ConvOptions<3>,
+    ) -> ADTensor<B, 5> {
+        #[derive(Debug)]
+        struct Conv3DWithBias;
+        #[derive(Debug)]
+        struct Conv3DNoBias;
+
+        impl<B: Backend> Backward<B, 5, 3> for Conv3DWithBias {
+            type State = (
+                B::TensorPrimitive<5>,
+                B::TensorPrimitive<5>,
+                B::TensorPrimitive<1>,
+                ConvOptions<3>,
+            );
+
+            fn backward(self, ops: Ops<Self::State, 3>, grads: &mut Gradients) {
+                let [node_x, node_weight, node_bias] = ops.parents;
+                let grad = grads.consume::<B, 5>(&ops.node);
+
+                let (x, weight, bias, options) = ops.state;
+                let backward = B::conv3d_backward(x, weight, Some(bias), grad, options);
+
+                if let Some(node) = node_x {
+                    grads.register::<B, 5>(node, backward.x_grad)
+                }
+                if let Some(node) = node_weight {
+                    grads.register::<B, 5>(node, backward.weights_grad)
+                }
+                if let Some(node) = node_bias {
+                    grads.register::<B, 1>(node, backward.bias_grad.unwrap())
+                }
+            }
+        }
+
+        impl<B: Backend> Backward<B, 5, 2> for Conv3DNoBias {
+            type State = (B::TensorPrimitive<5>, B::TensorPrimitive<5>, ConvOptions<3>);
+
+            fn backward(self, ops: Ops<Self::State, 2>, grads: &mut Gradients) {
+                let [node_x, node_weight] = ops.parents;
+                let grad = grads.consume::<B, 5>(&ops.node);
+
+                let (x, weight, options) = ops.state;
+                let backward = B::conv3d_backward(x, weight, None, grad, options);
+
+                if let Some(node) = node_x {
+                    grads.register::<B, 5>(node, backward.x_grad)
+                }
+                if let Some(node) = node_weight {
+                    grads.register::<B, 5>(node, backward.weights_grad)
+                }
+            }
+        }
+
+        match bias {
+            Some(bias) => {
+                match Conv3DWithBias
+                    .prepare(
+                        [x.node, weight.node, bias.node],
+                        [x.graph, weight.graph, bias.graph],
+                    )
+                    .statefull()
+                {
+                    OpsKind::Tracked(prep) => prep.finish(
+                        (
+                            x.primitive.clone(),
+                            weight.primitive.clone(),
+                            bias.primitive.clone(),
+                            options.clone(),
+                        ),
+                        B::conv3d(x.primitive, weight.primitive, Some(bias.primitive), options),
+                    ),
+                    OpsKind::UnTracked(prep) => prep.finish(B::conv3d(
+                        x.primitive,
+                        weight.primitive,
+                        Some(bias.primitive),
+                        options,
+                    )),
+                }
+            }
+            None => {
+                match Conv3DNoBias
+                    .prepare([x.node, weight.node], [x.graph, weight.graph])
+                    .statefull()
+                {
+                    OpsKind::Tracked(prep) => prep.finish(
+                        (
+                            x.primitive.clone(),
+                            weight.primitive.clone(),
+                            options.clone(),
+                        ),
+                        B::conv3d(x.primitive, weight.primitive, None, options),
+                    ),
+                    OpsKind::UnTracked(prep) => {
+                        prep.finish(B::conv3d(x.primitive, weight.primitive, None, options))
+                    }
+                }
+            }
+        }
+    }
+
     fn conv_transpose2d(
         x: ADTensor<B, 4>,
         weight: ADTensor<B, 4>,
@@ -11,6 +11,7 @@ use crate::{
 
 use burn_tensor::{backend::Backend, ops::TensorOps, Data, ElementConversion, Shape, Tensor};
 
+use super::linalg::{Cholesky, SvdOutput};
 use super::maxmin::MaxMinDim;
 
 impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
@@ -354,6 +355,77 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         }
     }
 
+    fn solve<const D: usize>(lhs: ADTensor<B, D>, rhs: ADTensor<B, D>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Solve;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 2> for Solve {
+            type State = (B::TensorPrimitive<D>, B::TensorPrimitive<D>);
+
+            fn backward(self, ops: Ops<Self::State, 2>, grads: &mut Gradients) {
+                let (lhs, output) = ops.state;
+                let [node_lhs, node_rhs] = ops.parents;
+                let grad = grads.consume::<B, D>(&ops.node);
+                let grad_rhs = B::solve(B::transpose(lhs), grad);
+
+                if let Some(node) = node_lhs {
+                    let grad = B::neg(B::matmul(grad_rhs.clone(), B::transpose(output)));
+                    grads.register::<B, D>(node, grad);
+                }
+
+                if let Some(node) = node_rhs {
+                    grads.register::<B, D>(node, grad_rhs);
+                }
+            }
+        }
+
+        match Solve
+            .prepare([lhs.node, rhs.node], [lhs.graph, rhs.graph])
+            .statefull()
+        {
+            OpsKind::Tracked(prep) => {
+                let output = B::solve(lhs.primitive.clone(), rhs.primitive);
+                prep.finish((lhs.primitive, output.clone()), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::solve(lhs.primitive, rhs.primitive)),
+        }
+    }
+
+    fn cholesky<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        match Cholesky.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => {
+                let output = B::cholesky(tensor.primitive);
+                prep.finish(output.clone(), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::cholesky(tensor.primitive)),
+        }
+    }
+
+    fn svd<const D: usize, const D2: usize>(
+        tensor: ADTensor<B, D>,
+    ) -> (ADTensor<B, D>, ADTensor<B, D2>, ADTensor<B, D>) {
+        let (u, s, vt) = B::svd::<D, D2>(tensor.primitive);
+        let shape_s = B::shape(&s);
+        let mut dims = B::shape(&u).dims;
+        dims[D - 2] = 1;
+        let s = B::reshape(s, Shape::new(dims));
+
+        let state = (u.clone(), s.clone(), vt.clone());
+        let output = |backward: SvdOutput, primitive| match backward
+            .prepare([tensor.node.clone()], [tensor.graph.clone()])
+            .statefull()
+        {
+            OpsKind::Tracked(prep) => prep.finish(state.clone(), primitive),
+            OpsKind::UnTracked(prep) => prep.finish(primitive),
+        };
+
+        let u = output(SvdOutput::U, u);
+        let s = output(SvdOutput::S, s);
+        let vt = output(SvdOutput::Vt, vt);
+
+        (u, Self::reshape(s, shape_s), vt)
+    }
+
     fn neg<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
         #[derive(Debug)]
         struct Neg;
@@ -483,24 +555,17 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         struct Scatter;
 
         impl<B: Backend, const D: usize> Backward<B, D, 2> for Scatter {
-            type State = (usize, IntTensor<B, D>, Shape<D>, Shape<D>, B::Device);
+            type State = (usize, IntTensor<B, D>);
 
             fn backward(self, ops: Ops<Self::State, 2>, grads: &mut Gradients) {
-                let (dim, indices, shape_lhs, shape_rhs, device) = ops.state;
-                let [indices_4lhs, indices_4rhs] = duplicate(&ops.parents, Some(indices));
+                let (dim, indices) = ops.state;
 
                 binary::<B, D, D, D, _, _>(
                     ops.parents,
                     ops.node,
                     grads,
-                    |grad| {
-                        let zeros = B::zeros(shape_lhs, &device);
-                        B::scatter(dim, grad, indices_4lhs.unwrap(), zeros)
-                    },
-                    |grad| {
-                        let zeros = B::zeros(shape_rhs, &device);
-                        B::scatter(dim, zeros, indices_4rhs.unwrap(), grad)
-                    },
+                    |grad| grad,
+                    |grad| B::gather(dim, grad, indices),
                 );
             }
         }
@@ -510,13 +575,7 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
             .statefull()
         {
             OpsKind::Tracked(prep) => prep.finish(
-                (
-                    dim,
-                    indices.clone(),
-                    B::shape(&tensor.primitive),
-                    B::shape(&value.primitive),
-                    B::device(&value.primitive),
-                ),
+                (dim, indices.clone()),
                 B::scatter(dim, tensor.primitive, indices, value.primitive),
             ),
             OpsKind::UnTracked(prep) => {
@@ -573,24 +632,17 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         struct IndexSelectDimAssign<const D: usize>;
 
         impl<B: Backend, const D: usize> Backward<B, D, 2> for IndexSelectDimAssign<D> {
-            type State = (usize, IntTensor<B, 1>, Shape<D>, Shape<D>, B::Device);
+            type State = (usize, IntTensor<B, 1>);
 
             fn backward(self, ops: Ops<Self::State, 2>, grads: &mut Gradients) {
-                let (dim, indices, shape_lhs, shape_rhs, device) = ops.state;
-                let [indices_4lhs, indices_4rhs] = duplicate(&ops.parents, Some(indices));
+                let (dim, indices) = ops.state;
 
                 binary::<B, D, D, D, _, _>(
                     ops.parents,
                     ops.node,
                     grads,
-                    |grad| {
-                        let zeros = B::zeros(shape_lhs, &device);
-                        B::select_assign(grad, dim, indices_4lhs.unwrap(), zeros)
-                    },
-                    |grad| {
-                        let zeros = B::zeros(shape_rhs, &device);
-                        B::select_assign(zeros, dim, indices_4rhs.unwrap(), grad)
-                    },
+                    |grad| grad,
+                    |grad| B::select(grad, dim, indices),
                 );
             }
         }
@@ -600,13 +652,7 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
             .statefull()
         {
             OpsKind::Tracked(prep) => prep.finish(
-                (
-                    dim,
-                    indices.clone(),
-                    B::shape(&tensor.primitive),
-                    B::shape(&value.primitive),
-                    B::device(&value.primitive),
-                ),
+                (dim, indices.clone()),
                 B::select_assign(tensor.primitive, dim, indices, value.primitive),
             ),
             OpsKind::UnTracked(prep) => prep.finish(B::select_assign(
@@ -781,6 +827,14 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         B::equal_elem(lhs.primitive, rhs)
     }
 
+    fn is_nan<const D: usize>(tensor: ADTensor<B, D>) -> BoolTensor<B, D> {
+        B::is_nan(tensor.primitive)
+    }
+
+    fn is_inf<const D: usize>(tensor: ADTensor<B, D>) -> BoolTensor<B, D> {
+        B::is_inf(tensor.primitive)
+    }
+
     fn greater<const D: usize>(lhs: ADTensor<B, D>, rhs: ADTensor<B, D>) -> BoolTensor<B, D> {
         B::greater(lhs.primitive, rhs.primitive)
     }
@@ -1217,6 +1271,187 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         }
     }
 
+    fn atan<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Atan;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Atan {
+            type State = B::TensorPrimitive<D>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let value = B::add_scalar(B::powf(ops.state, 2.0), 1.elem());
+                    B::div(grad, value)
+                });
+            }
+        }
+
+        match Atan.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => {
+                prep.finish(tensor.primitive.clone(), B::atan(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::atan(tensor.primitive)),
+        }
+    }
+
+    fn tan<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Tan;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Tan {
+            type State = B::TensorPrimitive<D>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let value = B::add_scalar(B::powf(ops.state, 2.0), 1.elem());
+                    B::mul(grad, value)
+                });
+            }
+        }
+
+        match Tan.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => {
+                let output = B::tan(tensor.primitive);
+                prep.finish(output.clone(), output)
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::tan(tensor.primitive)),
+        }
+    }
+
+    fn asin<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Asin;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Asin {
+            type State = B::TensorPrimitive<D>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let value = B::sqrt(B::add_scalar(B::neg(B::powf(ops.state, 2.0)), 1.elem()));
+                    B::div(grad, value)
+                });
+            }
+        }
+
+        match Asin.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => {
+                prep.finish(tensor.primitive.clone(), B::asin(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::asin(tensor.primitive)),
+        }
+    }
+
+    fn acos<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Acos;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Acos {
+            type State = B::TensorPrimitive<D>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let value = B::sqrt(B::add_scalar(B::neg(B::powf(ops.state, 2.0)), 1.elem()));
+                    B::neg(B::div(grad, value))
+                });
+            }
+        }
+
+        match Acos.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => {
+                prep.finish(tensor.primitive.clone(), B::acos(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::acos(tensor.primitive)),
+        }
+    }
+
+    fn atan2<const D: usize>(lhs: ADTensor<B, D>, rhs: ADTensor<B, D>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Atan2;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 2> for Atan2 {
+            type State = (B::TensorPrimitive<D>, B::TensorPrimitive<D>);
+
+            fn backward(self, ops: Ops<Self::State, 2>, grads: &mut Gradients) {
+                let (lhs, rhs) = ops.state;
+                // d/dy atan2(y, x) = x / (x^2 + y^2) and d/dx atan2(y, x) = -y / (x^2 + y^2).
+                let denominator = B::add(B::powf(lhs.clone(), 2.0), B::powf(rhs.clone(), 2.0));
+                let [denominator_4lhs, denominator_4rhs] =
+                    duplicate(&ops.parents, Some(denominator));
+
+                binary::<B, D, D, D, _, _>(
+                    ops.parents,
+                    ops.node,
+                    grads,
+                    |grad| {
+                        let value = B::div(rhs, denominator_4lhs.unwrap());
+                        B::mul(grad, value)
+                    },
+                    |grad| {
+                        let value = B::div(B::neg(lhs), denominator_4rhs.unwrap());
+                        B::mul(grad, value)
+                    },
+                );
+            }
+        }
+
+        match Atan2
+            .prepare([lhs.node, rhs.node], [lhs.graph, rhs.graph])
+            .statefull()
+        {
+            OpsKind::Tracked(prep) => prep.finish(
+                (lhs.primitive.clone(), rhs.primitive.clone()),
+                B::atan2(lhs.primitive, rhs.primitive),
+            ),
+            OpsKind::UnTracked(prep) => prep.finish(B::atan2(lhs.primitive, rhs.primitive)),
+        }
+    }
+
+    fn sinh<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Sinh;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Sinh {
+            type State = B::TensorPrimitive<D>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let value = B::cosh(ops.state);
+                    B::mul(grad, value)
+                });
+            }
+        }
+
+        match Sinh.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => {
+                prep.finish(tensor.primitive.clone(), B::sinh(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::sinh(tensor.primitive)),
+        }
+    }
+
+    fn cosh<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Cosh;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Cosh {
+            type State = B::TensorPrimitive<D>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let value = B::sinh(ops.state);
+                    B::mul(grad, value)
+                });
+            }
+        }
+
+        match Cosh.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => {
+                prep.finish(tensor.primitive.clone(), B::cosh(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::cosh(tensor.primitive)),
+        }
+    }
+
     fn tanh<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
         #[derive(Debug)]
         struct Tanh;
@@ -1268,6 +1503,56 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         }
     }
 
+    fn erfc<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Erfc;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Erfc {
+            type State = B::TensorPrimitive<D>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let exponent = B::neg(B::powf(ops.state, 2.0));
+                    let numerator = B::mul_scalar(B::exp(exponent), (-2.0).elem());
+                    let denominator = std::f64::consts::PI.sqrt().elem();
+                    let value = B::div_scalar(numerator, denominator);
+
+                    B::mul(grad, value)
+                });
+            }
+        }
+
+        match Erfc.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => {
+                prep.finish(tensor.primitive.clone(), B::erfc(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::erfc(tensor.primitive)),
+        }
+    }
+
+    fn lgamma<const D: usize>(tensor: ADTensor<B, D>) -> ADTensor<B, D> {
+        #[derive(Debug)]
+        struct Lgamma;
+
+        impl<B: Backend, const D: usize> Backward<B, D, 1> for Lgamma {
+            type State = B::TensorPrimitive<D>;
+
+            fn backward(self, ops: Ops<Self::State, 1>, grads: &mut Gradients) {
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    let value = digamma::<B, D>(ops.state);
+                    B::mul(grad, value)
+                });
+            }
+        }
+
+        match Lgamma.prepare([tensor.node], [tensor.graph]).statefull() {
+            OpsKind::Tracked(prep) => {
+                prep.finish(tensor.primitive.clone(), B::lgamma(tensor.primitive))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::lgamma(tensor.primitive)),
+        }
+    }
+
     fn cat<const D: usize>(tensors: Vec<ADTensor<B, D>>, dim: usize) -> ADTensor<B, D> {
         #[derive(new, Debug)]
         struct CatStep<B: Backend, const D: usize> {
@@ -1426,3 +1711,41 @@ fn broadcast_shape<B: Backend, const D: usize>(
 
     grad
 }
+
+/// The digamma function, the derivative of the log-gamma function.
+///
+/// The values are shifted above six with the recurrence `psi(x + 1) = psi(x) + 1 / x` before
+/// using the asymptotic expansion, the reflection formula `psi(x) = psi(1 - x) - pi / tan(pi x)`
+/// being used for values lower than one half.
+fn digamma<B: Backend, const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
+    let pi = std::f64::consts::PI;
+    let reflect = B::lower_elem(tensor.clone(), 0.5.elem());
+    let reflected = B::add_scalar(B::neg(tensor.clone()), 1.elem());
+    let x = B::mask_where(tensor.clone(), reflect.clone(), reflected);
+
+    let mut shift = B::zeros(B::shape(&x), &B::device(&x));
+    for i in 0..6 {
+        let term = B::powf(B::add_scalar(x.clone(), (i as f64).elem()), -1.0);
+        shift = B::add(shift, term);
+    }
+
+    let x = B::add_scalar(x, 6.elem());
+    let inv = B::powf(x.clone(), -1.0);
+    let inv2 = B::powf(inv.clone(), 2.0);
+    // 1/12 - 1/(120 x^2) + 1/(252 x^4), multiplied by 1/x^2.
+    let series = B::add_scalar(
+        B::mul_scalar(inv2.clone(), (1.0 / 252.0).elem()),
+        (-1.0 / 120.0).elem(),
+    );
+    let series = B::add_scalar(B::mul(series, inv2.clone()), (1.0 / 12.0).elem());
+    let series = B::mul(series, inv2);
+    let output = B::sub(
+        B::sub(B::sub(B::log(x), B::mul_scalar(inv, 0.5.elem())), series),
+        shift,
+    );
+
+    let correction = B::div_scalar(B::tan(B::mul_scalar(tensor, pi.elem())), pi.elem());
+    let reflected = B::sub(output.clone(), B::powf(correction, -1.0));
+
+    B::mask_where(output, reflect, reflected)
+}
@@ -0,0 +1,21 @@
+#[burn_tensor_testgen::testgen(ad_acos)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_acos() {
+        let data = Data::<f32, 2>::from([[0.0, 0.5, -0.5], [0.25, -0.75, 0.9]]);
+
+        let tensor_1 = TestADTensor::from_data(data).require_grad();
+        let tensor_2 = tensor_1.clone().acos().sum();
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1.to_data().assert_approx_eq(
+            &Data::from([[-1.0, -1.1547, -1.1547], [-1.0328, -1.5119, -2.2942]]),
+            3,
+        );
+    }
+}
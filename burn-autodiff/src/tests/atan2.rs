@@ -0,0 +1,27 @@
+#[burn_tensor_testgen::testgen(ad_atan2)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_atan2() {
+        let data_1 = Data::<f32, 2>::from([[1.0, 1.0, -1.0], [-1.0, 0.0, 2.0]]);
+        let data_2 = Data::<f32, 2>::from([[1.0, -1.0, -1.0], [1.0, -1.0, 0.5]]);
+
+        let tensor_1 = TestADTensor::from_data(data_1).require_grad();
+        let tensor_2 = TestADTensor::from_data(data_2).require_grad();
+
+        let tensor_3 = tensor_1.clone().atan2(tensor_2.clone()).sum();
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.5, -0.5, -0.5], [0.5, -1.0, 0.1176]]), 3);
+        grad_2
+            .to_data()
+            .assert_approx_eq(&Data::from([[-0.5, -0.5, 0.5], [0.5, 0.0, -0.4706]]), 3);
+    }
+}
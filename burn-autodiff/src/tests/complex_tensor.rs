@@ -0,0 +1,48 @@
+#[burn_tensor_testgen::testgen(ad_complex_tensor)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Complex, ComplexArithmetic, Data, Tensor};
+
+    #[test]
+    fn should_diff_abs_and_angle() {
+        let real = TestADTensor::from_data([3.0, -1.0]).require_grad();
+        let imag = TestADTensor::from_data([4.0, 1.0]).require_grad();
+
+        let tensor = Tensor::<TestADBackend, 1, Complex>::from_parts(real.clone(), imag.clone());
+        let grads = tensor.clone().abs().add(tensor.angle()).sum().backward();
+
+        let grad_real = real.grad(&grads).unwrap();
+        let grad_imag = imag.grad(&grads).unwrap();
+
+        grad_real
+            .into_data()
+            .assert_approx_eq(&Data::from([0.44, -1.2071]), 3);
+        grad_imag
+            .into_data()
+            .assert_approx_eq(&Data::from([0.92, 0.2071]), 3);
+    }
+
+    #[test]
+    fn should_diff_complex_mul() {
+        let real = TestADTensor::from_data([1.0, 2.0]).require_grad();
+        let imag = TestADTensor::from_data([3.0, -1.0]).require_grad();
+        let other = Tensor::<TestADBackend, 1, Complex>::from_parts(
+            TestADTensor::from_data([2.0, 0.5]),
+            TestADTensor::from_data([-1.0, 4.0]),
+        );
+
+        let tensor = Tensor::<TestADBackend, 1, Complex>::from_parts(real.clone(), imag.clone());
+        let (output_real, output_imag) = tensor.mul(other).into_parts();
+        let grads = output_real.add(output_imag).sum().backward();
+
+        let grad_real = real.grad(&grads).unwrap();
+        let grad_imag = imag.grad(&grads).unwrap();
+
+        grad_real
+            .into_data()
+            .assert_approx_eq(&Data::from([1.0, 4.5]), 3);
+        grad_imag
+            .into_data()
+            .assert_approx_eq(&Data::from([3.0, -3.5]), 3);
+    }
+}
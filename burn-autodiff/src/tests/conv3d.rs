@@ -0,0 +1,163 @@
+#[burn_tensor_testgen::testgen(ad_conv3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::{module::conv3d, ops::ConvOptions, Data, Shape};
+
+    #[test]
+    fn test_conv3d_groups() {
+        let test = Conv3dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size: [2, 2, 2],
+            padding: [1, 0, 0],
+            stride: [2, 1, 1],
+            dilation: [1, 1, 1],
+            groups: 2,
+            size: [3, 3, 3],
+        };
+        let grads = Grads {
+            x: TestTensor::from_floats([[
+                [
+                    [[4., 9., 5.], [10., 22., 12.], [6., 13., 7.]],
+                    [[0., 1., 1.], [2., 6., 4.], [2., 5., 3.]],
+                    [[4., 9., 5.], [10., 22., 12.], [6., 13., 7.]],
+                ],
+                [
+                    [[12., 25., 13.], [26., 54., 28.], [14., 29., 15.]],
+                    [[8., 17., 9.], [18., 38., 20.], [10., 21., 11.]],
+                    [[12., 25., 13.], [26., 54., 28.], [14., 29., 15.]],
+                ],
+            ]]),
+            weight: TestTensor::from_floats([
+                [[[[44., 48.], [56., 60.]], [[88., 96.], [112., 120.]]]],
+                [[[[152., 156.], [164., 168.]], [[304., 312.], [328., 336.]]]],
+            ]),
+            bias: TestTensor::from_floats([8., 8.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    #[test]
+    fn test_conv3d_complex() {
+        let test = Conv3dTestCase {
+            batch_size: 2,
+            channels_in: 2,
+            channels_out: 3,
+            kernel_size: [2, 1, 2],
+            padding: [1, 0, 1],
+            stride: [1, 2, 1],
+            dilation: [2, 1, 1],
+            groups: 1,
+            size: [3, 3, 3],
+        };
+        let x_grad_sample = [
+            [
+                [[51., 51., 51.], [0., 0., 0.], [51., 51., 51.]],
+                [[114., 114., 114.], [0., 0., 0.], [114., 114., 114.]],
+                [[63., 63., 63.], [0., 0., 0.], [63., 63., 63.]],
+            ],
+            [
+                [[75., 75., 75.], [0., 0., 0.], [75., 75., 75.]],
+                [[162., 162., 162.], [0., 0., 0.], [162., 162., 162.]],
+                [[87., 87., 87.], [0., 0., 0.], [87., 87., 87.]],
+            ],
+        ];
+        let weight_grad_out_channel = [
+            [[[852., 852.]], [[1068., 1068.]]],
+            [[[1500., 1500.]], [[1716., 1716.]]],
+        ];
+        let grads = Grads {
+            x: TestTensor::from_floats([x_grad_sample, x_grad_sample]),
+            weight: TestTensor::from_floats([
+                weight_grad_out_channel,
+                weight_grad_out_channel,
+                weight_grad_out_channel,
+            ]),
+            bias: TestTensor::from_floats([48., 48., 48.]),
+        };
+        test.assert_grads(grads);
+    }
+
+    struct Conv3dTestCase {
+        batch_size: usize,
+        channels_in: usize,
+        channels_out: usize,
+        kernel_size: [usize; 3],
+        padding: [usize; 3],
+        stride: [usize; 3],
+        dilation: [usize; 3],
+        groups: usize,
+        size: [usize; 3],
+    }
+
+    struct Grads {
+        x: TestTensor<5>,
+        weight: TestTensor<5>,
+        bias: TestTensor<1>,
+    }
+
+    impl Conv3dTestCase {
+        fn assert_grads(self, expected_grads: Grads) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels_in,
+                self.size[0],
+                self.size[1],
+                self.size[2],
+            ]);
+            let shape_weight = Shape::new([
+                self.channels_out,
+                self.channels_in / self.groups,
+                self.kernel_size[0],
+                self.kernel_size[1],
+                self.kernel_size[2],
+            ]);
+            let weight = TestADTensor::from_data(
+                TestTensorInt::arange(0..shape_weight.num_elements())
+                    .reshape(shape_weight)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let bias = TestADTensor::from_data(
+                TestTensorInt::arange(0..self.channels_out)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let x = TestADTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            )
+            .require_grad();
+            let output = conv3d(
+                x.clone(),
+                weight.clone(),
+                Some(bias.clone()),
+                ConvOptions::new(self.stride, self.padding, self.dilation, self.groups),
+            );
+            let grads = output.backward();
+
+            // Assert
+            let x_grad_actual = x.grad(&grads).unwrap();
+            let weight_grad_actual = weight.grad(&grads).unwrap();
+            let bias_grad_actual = bias.grad(&grads).unwrap();
+
+            expected_grads
+                .bias
+                .to_data()
+                .assert_approx_eq(&bias_grad_actual.to_data(), 3);
+            expected_grads
+                .x
+                .to_data()
+                .assert_approx_eq(&x_grad_actual.to_data(), 3);
+            expected_grads
+                .weight
+                .to_data()
+                .assert_approx_eq(&weight_grad_actual.to_data(), 3);
+        }
+    }
+}
@@ -0,0 +1,21 @@
+#[burn_tensor_testgen::testgen(ad_cosh)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_cosh() {
+        let data = Data::<f32, 2>::from([[0.0, 1.0, 2.0], [-1.0, -2.0, 0.5]]);
+
+        let tensor_1 = TestADTensor::from_data(data).require_grad();
+        let tensor_2 = tensor_1.clone().cosh().sum();
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1.to_data().assert_approx_eq(
+            &Data::from([[0.0, 1.1752, 3.6269], [-1.1752, -3.6269, 0.5211]]),
+            3,
+        );
+    }
+}
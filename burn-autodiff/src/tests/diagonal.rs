@@ -0,0 +1,31 @@
+#[burn_tensor_testgen::testgen(ad_diagonal)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_diff_diagonal() {
+        let tensor_1 = TestADTensor::from_data([[1.0, 2.0], [3.0, 4.0]]).require_grad();
+        let weights = TestADTensor::from_data([[2.0, 3.0]]);
+
+        let tensor_2: Tensor<TestADBackend, 1> = tensor_1.clone().diagonal(0);
+        let grads = tensor_2.unsqueeze::<2>().mul(weights).backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.into_data(), Data::from([[2.0, 0.0], [0.0, 3.0]]));
+    }
+
+    #[test]
+    fn should_diff_diag_embed() {
+        let tensor_1 = TestADTensor::from_data([[1.0, 2.0]]).require_grad();
+        let weights = Tensor::<TestADBackend, 3>::from_data([[[1.0, 2.0], [3.0, 4.0]]]);
+
+        let tensor_2: Tensor<TestADBackend, 3> = tensor_1.clone().diag_embed(0);
+        let grads = tensor_2.mul(weights).backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.into_data(), Data::from([[1.0, 4.0]]));
+    }
+}
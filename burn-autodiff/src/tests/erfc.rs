@@ -0,0 +1,25 @@
+#[burn_tensor_testgen::testgen(ad_erfc)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+    use core::f32::consts::FRAC_2_SQRT_PI;
+
+    #[test]
+    fn should_diff_erfc() {
+        let data = Data::<f32, 2>::from([[0.0, 1.0, 2.0], [-1.0, -0.5, 3.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data).require_grad();
+        let tensor_2 = tensor_1.clone().erfc().sum();
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1.to_data().assert_approx_eq(
+            &Data::from([
+                [-FRAC_2_SQRT_PI, -0.4151, -0.0207],
+                [-0.4151, -0.8788, -0.0001],
+            ]),
+            3,
+        );
+    }
+}
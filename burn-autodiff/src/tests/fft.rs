@@ -0,0 +1,19 @@
+#[burn_tensor_testgen::testgen(ad_fft)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_rfft() {
+        let tensor_1 = TestADTensor::from_data([1.0, 2.0, 3.0, 4.0]).require_grad();
+
+        let (real, imag) = tensor_1.clone().rfft(0);
+        let grads = real.add(imag).sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .into_data()
+            .assert_approx_eq(&Data::from([3.0, -1.0, 1.0, 1.0]), 3);
+    }
+}
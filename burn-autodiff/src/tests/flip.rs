@@ -0,0 +1,18 @@
+#[burn_tensor_testgen::testgen(ad_flip)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_flip() {
+        let tensor_1 = TestADTensor::from_data([[1.0, 2.0], [3.0, 4.0]]).require_grad();
+        let weights = TestADTensor::from_data([[1.0, 2.0], [3.0, 4.0]]);
+
+        let tensor_2 = tensor_1.clone().flip(&[0, 1]);
+        let grads = tensor_2.mul(weights).backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.into_data(), Data::from([[4.0, 3.0], [2.0, 1.0]]));
+    }
+}
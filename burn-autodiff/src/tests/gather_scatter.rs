@@ -49,4 +49,24 @@ mod tests {
             Data::from([[19., 19., 19.], [64., 64., 64.]])
         );
     }
+
+    #[test]
+    fn test_scatter_assign_grad() {
+        let tensor_1 =
+            TestADTensor::from_data(Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]])).require_grad();
+        let values = TestADTensor::from_data(Data::from([[1.0], [2.0]])).require_grad();
+        let weights = TestADTensor::from_data(Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]));
+        let indices = TestADTensor::from_data(Data::from([[2], [0]]));
+
+        let tensor_2 = tensor_1.clone().scatter_assign(1, indices, values.clone());
+        let tensor_3 = tensor_2.mul(weights);
+
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = values.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.into_data(), Data::from([[1., 2., 0.], [0., 5., 6.]]));
+        assert_eq!(grad_2.into_data(), Data::from([[3.], [4.]]));
+    }
 }
@@ -0,0 +1,48 @@
+#[burn_tensor_testgen::testgen(ad_grid_sample)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::grid_sample_2d;
+    use burn_tensor::ops::{GridSampleMode, GridSampleOptions, GridSamplePaddingMode};
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_grid_sample_bilinear_grads() {
+        let x =
+            TestADTensor::from_floats([[[[0.5, -1.0, 2.0], [1.5, 3.0, -0.5], [2.5, 1.0, 0.0]]]])
+                .require_grad();
+        let grid =
+            TestADTensor::from_floats([[[[-0.3, 0.6], [0.45, -0.2]], [[0.8, 0.1], [-0.7, -0.9]]]])
+                .require_grad();
+        let weights = TestADTensor::from_floats([[[[1.0, -2.0], [0.5, 3.0]]]]);
+        let options = GridSampleOptions::new(
+            GridSampleMode::Bilinear,
+            GridSamplePaddingMode::Zeros,
+            false,
+        );
+
+        let output = grid_sample_2d(x.clone(), grid.clone(), options);
+        let grads = output.clone().mul(weights).sum().backward();
+
+        let x_grad = x.grad(&grads).unwrap();
+        let grid_grad = grid.grad(&grads).unwrap();
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[1.74, 0.7538], [-0.34, 0.3087]]]]), 3);
+        x_grad.to_data().assert_approx_eq(
+            &Data::from([[[
+                [1.8525, -0.195, -0.405],
+                [0.045, -0.4, -0.605],
+                [0.405, 0.495, 0.06],
+            ]]]),
+            3,
+        );
+        grid_grad.to_data().assert_approx_eq(
+            &Data::from([[
+                [[-1.8, -0.975], [4.65, 1.1625]],
+                [[0.3187, 0.3], [1.4625, 2.1375]],
+            ]]),
+            3,
+        );
+    }
+}
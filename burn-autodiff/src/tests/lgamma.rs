@@ -0,0 +1,21 @@
+#[burn_tensor_testgen::testgen(ad_lgamma)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_lgamma() {
+        let data = Data::<f32, 2>::from([[1.0, 2.0, 3.5], [0.5, -0.5, 10.0]]);
+
+        let tensor_1 = TestADTensor::from_data(data).require_grad();
+        let tensor_2 = tensor_1.clone().lgamma().sum();
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1.to_data().assert_approx_eq(
+            &Data::from([[-0.5772, 0.4228, 1.1032], [-1.9635, 0.0365, 2.2518]]),
+            3,
+        );
+    }
+}
@@ -0,0 +1,99 @@
+#[burn_tensor_testgen::testgen(ad_linalg)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, Data};
+
+    #[test]
+    fn should_diff_solve() {
+        let lhs = TestADTensor::from_data([[2.0, 1.0], [1.0, 3.0]]).require_grad();
+        let rhs = TestADTensor::from_data([[3.0], [5.0]]).require_grad();
+
+        let solution = linalg::solve(lhs.clone(), rhs.clone());
+        let grads = solution.sum().backward();
+
+        let grad_lhs = lhs.grad(&grads).unwrap();
+        let grad_rhs = rhs.grad(&grads).unwrap();
+
+        grad_lhs
+            .to_data()
+            .assert_approx_eq(&Data::from([[-0.32, -0.56], [-0.16, -0.28]]), 3);
+        grad_rhs
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.4], [0.2]]), 3);
+    }
+
+    #[test]
+    fn should_diff_inverse() {
+        let tensor = TestADTensor::from_data([[2.0, 1.0], [1.0, 3.0]]).require_grad();
+
+        let inverse = linalg::inverse(tensor.clone());
+        let grads = inverse.sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[-0.16, -0.08], [-0.08, -0.04]]), 3);
+    }
+
+    #[test]
+    fn should_diff_cholesky() {
+        let tensor = TestADTensor::from_data([[4.0, 2.0, 0.4], [2.0, 5.0, 1.0], [0.4, 1.0, 3.0]])
+            .require_grad();
+        let weights = TestADTensor::from_data([[1.0, 3.0, 5.0], [2.0, 4.0, 6.0], [3.0, 5.0, 7.0]]);
+
+        let factor = linalg::cholesky(tensor.clone());
+        let grads = factor.mul(weights).sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.to_data().assert_approx_eq(
+            &Data::from([
+                [0.2375, 0.0, 0.125],
+                [0.0, 0.8337, 0.8317],
+                [0.125, 0.8317, 2.0917],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_diff_singular_values() {
+        let tensor = TestADTensor::from_data([[3.0, 0.0], [0.0, -2.0]]).require_grad();
+
+        let (_, s, _) = linalg::svd::<TestADBackend, 2, 1>(tensor.clone());
+        let grads = s.sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[1.0, 0.0], [0.0, -1.0]]), 3);
+    }
+
+    #[test]
+    fn should_diff_svd_reconstruction_of_tall_matrices() {
+        let tensor = TestADTensor::from_data([[1.0, 2.0], [3.0, -4.0], [5.0, 6.0]]).require_grad();
+        let weights = TestADTensor::from_data([[1.0, -2.0], [3.0, 0.5], [-1.0, 2.0]]);
+
+        let (u, s, vt) = linalg::svd::<TestADBackend, 2, 1>(tensor.clone());
+        let reconstruction = u.mul(s.unsqueeze()).matmul(vt);
+        let grads = reconstruction.mul(weights.clone()).sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.to_data().assert_approx_eq(&weights.into_data(), 3);
+    }
+
+    #[test]
+    fn should_diff_svd_reconstruction_of_wide_matrices() {
+        let tensor = TestADTensor::from_data([[[1.0, 3.0, 5.0], [2.0, -4.0, 6.0]]]).require_grad();
+        let weights = TestADTensor::from_data([[[1.0, 3.0, -1.0], [-2.0, 0.5, 2.0]]]);
+
+        let (u, s, vt) = linalg::svd::<TestADBackend, 3, 2>(tensor.clone());
+        let reconstruction = u.mul(s.reshape([1, 1, 2])).matmul(vt);
+        let grads = reconstruction.mul(weights.clone()).sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.to_data().assert_approx_eq(&weights.into_data(), 3);
+    }
+}
@@ -50,4 +50,53 @@ mod tests {
             .into_data()
             .assert_approx_eq(&Data::from([[15., 18.], [23., 29.]]), 3);
     }
+
+    #[test]
+    fn should_diff_masked_select() {
+        let tensor_1 = TestADTensor::from_data([[1.0, 7.0], [2.0, 3.0]]).require_grad();
+        let weights = TestADTensor::from_data([2.0, 3.0]);
+        let mask = TestADTensor::from_data([[false, true], [true, false]]);
+
+        let tensor_2 = tensor_1.clone().masked_select(mask).mul(weights);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.into_data(), Data::from([[0.0, 2.0], [3.0, 0.0]]));
+    }
+
+    #[test]
+    fn should_diff_where_cond_with_broadcast() {
+        let tensor_1 = TestADTensor::from_data([[1.0, 2.0], [3.0, 4.0]]).require_grad();
+        let tensor_2 = TestADTensor::from_data([[5.0, 6.0]]).require_grad();
+        let weights = TestADTensor::from_data([[1.0, 2.0], [3.0, 4.0]]);
+        let condition = TestADTensor::from_data([[true], [false]]);
+
+        let tensor_3 = tensor_1.clone().where_cond(condition, tensor_2.clone());
+        let grads = tensor_3.mul(weights).backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.into_data(), Data::from([[1.0, 2.0], [0.0, 0.0]]));
+        assert_eq!(grad_2.into_data(), Data::from([[3.0, 4.0]]));
+    }
+
+    #[test]
+    fn should_diff_nan_to_num() {
+        let tensor_1 =
+            TestADTensor::from_data([[1.0, f32::INFINITY], [f32::NAN, f32::NEG_INFINITY]])
+                .require_grad();
+
+        let tensor_2 = tensor_1
+            .clone()
+            .nan_to_num(0.0, 1.0, -1.0)
+            .mul_scalar(3.0)
+            .sum();
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[3.0, 0.0], [0.0, 0.0]]));
+    }
 }
@@ -42,4 +42,42 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::from([[10.0, 8.0], [15.0, 56.0]]), 5);
     }
+
+    #[test]
+    fn should_diff_max_min_pair() {
+        let tensor_1 = TestADTensor::from_floats([[1.0, 7.0], [-2.0, -3.0]]).require_grad();
+        let tensor_2 = TestADTensor::from_floats([[4.0, -7.0]]).require_grad();
+        let weights = TestADTensor::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+
+        let max = tensor_1.clone().max_pair(tensor_2.clone());
+        let min = tensor_1.clone().min_pair(tensor_2.clone());
+        let tensor_3 = max
+            .mul(weights.clone())
+            .add(min.mul(weights.mul_scalar(10.0)));
+        let grads = tensor_3.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[10.0, 2.0], [30.0, 4.0]]), 5);
+        grad_2
+            .to_data()
+            .assert_approx_eq(&Data::from([[4.0, 60.0]]), 5);
+    }
+
+    #[test]
+    fn should_diff_clamp_min_max() {
+        let tensor = TestADTensor::from_floats([[1.0, 7.0], [-2.0, -3.0]]).require_grad();
+
+        let tensor_2 = tensor.clone().clamp_min(0.0).mul_scalar(2.0);
+        let tensor_3 = tensor.clone().clamp_max(1.0).mul_scalar(3.0);
+        let grads = tensor_2.add(tensor_3).sum().backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[5.0, 2.0], [3.0, 3.0]]), 5);
+    }
 }
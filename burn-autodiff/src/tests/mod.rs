@@ -1,27 +1,41 @@
 #![allow(missing_docs)]
 
 mod abs;
+mod acos;
 mod adaptive_avgpool1d;
 mod adaptive_avgpool2d;
 mod add;
 mod aggregation;
+mod asin;
+mod atan;
+mod atan2;
 mod avgpool1d;
 mod avgpool2d;
 mod backward;
 mod broadcast;
 mod cat;
 mod complex;
+mod complex_tensor;
 mod conv1d;
 mod conv2d;
+mod conv3d;
 mod conv_transpose1d;
 mod conv_transpose2d;
 mod cos;
+mod cosh;
 mod cross_entropy;
+mod diagonal;
 mod div;
 mod erf;
+mod erfc;
 mod exp;
+mod fft;
+mod flip;
 mod gather_scatter;
 mod gelu;
+mod grid_sample;
+mod lgamma;
+mod linalg;
 mod log;
 mod log1p;
 mod mask;
@@ -32,16 +46,22 @@ mod maxpool2d;
 mod mul;
 mod multithread;
 mod neg;
+mod pad;
 mod pow;
+mod random;
 mod relu;
 mod reshape;
 mod select;
 mod sin;
+mod sinh;
 mod slice;
 mod softmax;
+mod sparse;
 mod sqrt;
 mod sub;
+mod tan;
 mod tanh;
+mod topk;
 mod transpose;
 
 #[macro_export]
@@ -60,6 +80,7 @@ macro_rules! testgen_all {
         // Modules
         burn_autodiff::testgen_ad_conv1d!();
         burn_autodiff::testgen_ad_conv2d!();
+        burn_autodiff::testgen_ad_conv3d!();
         burn_autodiff::testgen_ad_conv_transpose1d!();
         burn_autodiff::testgen_ad_conv_transpose2d!();
         burn_autodiff::testgen_ad_max_pool1d!();
@@ -68,23 +89,36 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_avg_pool2d!();
         burn_autodiff::testgen_ad_adaptive_avg_pool1d!();
         burn_autodiff::testgen_ad_adaptive_avg_pool2d!();
+        burn_autodiff::testgen_ad_grid_sample!();
         burn_autodiff::testgen_module_backward!();
 
         // Tensor
         burn_autodiff::testgen_ad_complex!();
+        burn_autodiff::testgen_ad_complex_tensor!();
         burn_autodiff::testgen_ad_multithread!();
         burn_autodiff::testgen_ad_add!();
         burn_autodiff::testgen_ad_aggregation!();
         burn_autodiff::testgen_ad_maxmin!();
         burn_autodiff::testgen_ad_cat!();
         burn_autodiff::testgen_ad_cos!();
+        burn_autodiff::testgen_ad_atan!();
+        burn_autodiff::testgen_ad_atan2!();
+        burn_autodiff::testgen_ad_tan!();
+        burn_autodiff::testgen_ad_asin!();
+        burn_autodiff::testgen_ad_acos!();
+        burn_autodiff::testgen_ad_sinh!();
+        burn_autodiff::testgen_ad_cosh!();
+        burn_autodiff::testgen_ad_erfc!();
+        burn_autodiff::testgen_ad_lgamma!();
         burn_autodiff::testgen_ad_cross_entropy_loss!();
         burn_autodiff::testgen_ad_div!();
         burn_autodiff::testgen_ad_erf!();
         burn_autodiff::testgen_ad_exp!();
         burn_autodiff::testgen_ad_slice!();
+        burn_autodiff::testgen_ad_sparse!();
         burn_autodiff::testgen_ad_gather_scatter!();
         burn_autodiff::testgen_ad_select!();
+        burn_autodiff::testgen_ad_linalg!();
         burn_autodiff::testgen_ad_log!();
         burn_autodiff::testgen_ad_log1p!();
         burn_autodiff::testgen_ad_mask!();
@@ -92,6 +126,7 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_mul!();
         burn_autodiff::testgen_ad_neg!();
         burn_autodiff::testgen_ad_powf!();
+        burn_autodiff::testgen_ad_random!();
         burn_autodiff::testgen_ad_reshape!();
         burn_autodiff::testgen_ad_sin!();
         burn_autodiff::testgen_ad_softmax!();
@@ -99,6 +134,11 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_abs!();
         burn_autodiff::testgen_ad_sub!();
         burn_autodiff::testgen_ad_tanh!();
+        burn_autodiff::testgen_ad_diagonal!();
+        burn_autodiff::testgen_ad_fft!();
+        burn_autodiff::testgen_ad_flip!();
+        burn_autodiff::testgen_ad_pad!();
+        burn_autodiff::testgen_ad_topk!();
         burn_autodiff::testgen_ad_transpose!();
     };
 }
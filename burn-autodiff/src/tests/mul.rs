@@ -36,6 +36,22 @@ mod tests {
         assert_eq!(grad.to_data(), Data::from([4.0, 4.0]));
     }
 
+    #[test]
+    fn should_diff_mul_assign() {
+        let tensor_1 = TestADTensor::from_data([1.0, 2.0]).require_grad();
+
+        let mut tensor_2 = tensor_1.clone().mul(tensor_1.clone());
+        tensor_2.add_scalar_assign(1.0);
+        tensor_2.mul_assign(tensor_1.clone());
+
+        let grads = tensor_2.backward();
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(tensor_2.into_data(), Data::from([2.0, 10.0]));
+        assert_eq!(tensor_1.into_data(), Data::from([1.0, 2.0]));
+        assert_eq!(grad_1.to_data(), Data::from([4.0, 13.0]));
+    }
+
     #[test]
     fn test_mul_complex_1() {
         let data_1: Data<f32, 2> = Data::from([[1.0, 7.0], [13.0, -3.0]]);
@@ -0,0 +1,33 @@
+#[burn_tensor_testgen::testgen(ad_pad)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, PadMode};
+
+    #[test]
+    fn should_diff_pad_constant() {
+        let tensor_1 = TestADTensor::from_data([[1.0, 2.0], [3.0, 4.0]]).require_grad();
+        let weights = TestADTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+
+        let tensor_2 = tensor_1
+            .clone()
+            .pad([(1, 0), (0, 1)], PadMode::Constant(0.0));
+        let grads = tensor_2.mul(weights).backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.into_data(), Data::from([[4.0, 5.0], [7.0, 8.0]]));
+    }
+
+    #[test]
+    fn should_diff_pad_reflect() {
+        let tensor_1 = TestADTensor::from_data([1.0, 2.0, 3.0]).require_grad();
+        let weights = TestADTensor::from_data([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let tensor_2 = tensor_1.clone().pad([(2, 1)], PadMode::Reflect);
+        let grads = tensor_2.mul(weights).backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.into_data(), Data::from([3.0, 12.0, 6.0]));
+    }
+}
@@ -0,0 +1,24 @@
+#[burn_tensor_testgen::testgen(ad_random)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_diff_normal() {
+        let mean = TestADTensor::from_data([[1.0, -2.0], [0.5, 3.0]]).require_grad();
+        let std = TestADTensor::from_data([[1.0, 2.0], [0.5, 4.0]]).require_grad();
+
+        let samples = Tensor::normal(mean.clone(), std.clone());
+        let grads = samples.clone().sum().backward();
+
+        let grad_mean = mean.grad(&grads).unwrap();
+        let grad_std = std.grad(&grads).unwrap();
+
+        // The gradient of the standard deviation is the sampled standard normal noise.
+        let noise = samples.inner().sub(mean.inner()).div(std.inner());
+        grad_mean
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 1.0], [1.0, 1.0]]), 3);
+        grad_std.into_data().assert_approx_eq(&noise.into_data(), 3);
+    }
+}
@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(ad_select)]
 mod tests {
     use super::*;
-    use burn_tensor::Data;
+    use burn_tensor::{Data, Int, Tensor};
 
     #[test]
     fn test_select_grad() {
@@ -23,6 +23,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_index_grad() {
+        let tensor_1 =
+            TestADTensor::from_data(Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]])).require_grad();
+        let rows = TestADTensor::from_data(Data::from([1, 0, 1]));
+        let columns = TestADTensor::from_data(Data::from([2, 1, 2]));
+        let weights = TestADTensor::from_data(Data::from([1.0, 2.0, 3.0]));
+
+        let tensor_2 = tensor_1.clone().index([rows, columns]);
+        let tensor_3 = tensor_2.mul(weights);
+
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.into_data(), Data::from([[0., 2., 0.], [0., 0., 4.]]));
+    }
+
     #[test]
     fn test_select_assign_grad() {
         let tensor_1 =
@@ -49,4 +67,20 @@ mod tests {
             Data::from([[64., 64., 64.], [19., 19., 19.]])
         );
     }
+
+    #[test]
+    fn should_diff_repeat_interleave_counts() {
+        let tensor_1 = TestADTensor::from_data(Data::from([1.0, 2.0, 3.0])).require_grad();
+        let weights = TestADTensor::from_data(Data::from([1.0, 2.0, 3.0, 4.0, 5.0]));
+        let counts = Tensor::<TestADBackend, 1, Int>::from_ints([2, 0, 3]);
+
+        let tensor_2 = tensor_1.clone().repeat_interleave_counts(counts, 0);
+        let tensor_3 = tensor_2.mul(weights).sum();
+
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.into_data(), Data::from([3.0, 0.0, 12.0]));
+    }
 }
@@ -74,4 +74,26 @@ mod tests {
         assert_eq!(grad_1.to_data(), Data::from([[85.0, 65.0], [118.0, 82.0]]));
         assert_eq!(grad_2.to_data(), Data::from([[88.0, 15.0], [24.0, 50.0]]));
     }
+
+    #[test]
+    fn should_diff_chunk() {
+        let data: Data<f32, 2> = Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let tensor = TestADTensor::from_data(data).require_grad();
+
+        let chunks = tensor.clone().chunk(2, 1);
+        let output = chunks[0]
+            .clone()
+            .mul_scalar(2.0)
+            .sum()
+            .add(chunks[1].clone().mul_scalar(3.0).sum());
+        let grads = output.backward();
+
+        let grad = tensor.grad(&grads).unwrap();
+
+        assert_eq!(
+            grad.to_data(),
+            Data::from([[2.0, 2.0, 3.0], [2.0, 2.0, 3.0]])
+        );
+    }
 }
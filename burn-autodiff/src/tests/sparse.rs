@@ -0,0 +1,56 @@
+#[burn_tensor_testgen::testgen(ad_sparse)]
+mod tests {
+    use super::*;
+    use burn_tensor::sparse::{self, SparseTensor};
+    use burn_tensor::{module::embedding, Data, Int, Tensor};
+
+    #[test]
+    fn should_diff_sparse_matmul() {
+        let indices = Tensor::<TestADBackend, 2, Int>::from_ints([[0, 1], [1, 0], [1, 2]]);
+        let values = TestADTensor::from_data(Data::from([2.0, 3.0, -1.0])).require_grad();
+        let dense = TestADTensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]))
+            .require_grad();
+
+        let sparse = SparseTensor::new(indices, values.clone(), [2, 3]);
+        let output = sparse.matmul(dense.clone());
+        let grads = output.backward();
+
+        let grad_values = values.grad(&grads).unwrap();
+        let grad_dense = dense.grad(&grads).unwrap();
+
+        assert_eq!(grad_values.to_data(), Data::from([7.0, 3.0, 11.0]));
+        assert_eq!(
+            grad_dense.to_data(),
+            Data::from([[3.0, 3.0], [2.0, 2.0], [-1.0, -1.0]])
+        );
+    }
+
+    #[test]
+    fn should_match_dense_embedding_grad() {
+        let weights =
+            TestADTensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0]]))
+                .require_grad();
+        let indices = Tensor::<TestADBackend, 2, Int>::from_ints([[0, 2], [2, 3]]);
+        let output_grad = TestADTensor::from_data(Data::from([
+            [[1.0, 2.0], [3.0, 4.0]],
+            [[5.0, 6.0], [7.0, 8.0]],
+        ]));
+
+        let output = embedding(weights.clone(), indices.clone());
+        let grads = output.mul(output_grad.clone()).backward();
+        let grad_dense = weights.grad(&grads).unwrap();
+
+        let grad_sparse = sparse::embedding_backward(4, output_grad, indices);
+
+        assert_eq!(grad_sparse.nnz(), 6);
+        assert_eq!(
+            grad_sparse.indices().into_data(),
+            Data::from([[0, 0], [0, 1], [2, 0], [2, 1], [3, 0], [3, 1]])
+        );
+        assert_eq!(
+            grad_sparse.values().into_data(),
+            Data::from([1.0, 2.0, 8.0, 10.0, 7.0, 8.0])
+        );
+        assert_eq!(grad_sparse.to_dense().into_data(), grad_dense.into_data());
+    }
+}
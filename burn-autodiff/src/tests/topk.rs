@@ -0,0 +1,21 @@
+#[burn_tensor_testgen::testgen(ad_topk)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_diff_topk() {
+        let tensor_1 =
+            TestADTensor::from_floats([[1.0, 7.0, 3.0], [-2.0, -3.0, 4.0]]).require_grad();
+
+        let (values, _indices) = tensor_1.clone().topk(2, 1);
+        let tensor_2 = values.mul(TestADTensor::from_floats([[1.0, 2.0], [3.0, 4.0]]));
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        grad_1
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0, 1.0, 2.0], [4.0, 0.0, 3.0]]), 5);
+    }
+}
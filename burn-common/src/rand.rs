@@ -1,4 +1,10 @@
 pub use rand::{rngs::StdRng, Rng, SeedableRng};
+pub use rand_chacha::ChaCha12Rng;
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::any::Any;
+use core::sync::atomic::{AtomicUsize, Ordering};
 
 #[cfg(feature = "std")]
 use std::sync::Mutex;
@@ -25,3 +31,180 @@ pub fn get_seeded_rng() -> StdRng {
 }
 
 pub(crate) static SEED: Mutex<Option<StdRng>> = Mutex::new(None);
+
+/// The global random number generator, separate from the one of the [ids](crate::id) so seeding it
+/// twice doesn't generate the same ids.
+///
+/// It uses the same algorithm as [StdRng], which doesn't expose its position in the stream.
+static RNG: Mutex<Option<ChaCha12Rng>> = Mutex::new(None);
+
+/// The generators of the devices seeded with [seed_device], identified by their device.
+static DEVICE_RNGS: Mutex<Vec<(Box<dyn Any + Send>, ChaCha12Rng)>> = Mutex::new(Vec::new());
+
+/// The number of times a generator has been seeded or restored.
+static GENERATION: AtomicUsize = AtomicUsize::new(0);
+
+/// Seeds the global random number generator, used by the backends to sample random tensors.
+pub fn seed(seed: u64) {
+    let mut rng = RNG.lock().unwrap();
+    *rng = Some(ChaCha12Rng::seed_from_u64(seed));
+    GENERATION.fetch_add(1, Ordering::SeqCst);
+}
+
+/// Runs the function with the global random number generator, which is seeded using entropy
+/// until [seed] is called.
+pub fn with_rng<T, F: FnOnce(&mut ChaCha12Rng) -> T>(func: F) -> T {
+    let mut rng = RNG.lock().unwrap();
+    func(rng.get_or_insert_with(|| ChaCha12Rng::from_rng(get_seeded_rng()).unwrap()))
+}
+
+/// The state of the global random number generator, captured with [rng_state] and restored with
+/// [set_rng_state].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RngState {
+    /// The seed of the generator.
+    pub seed: [u8; 32],
+    /// The number of 32-bit words already generated from the seed.
+    pub word_pos: u128,
+}
+
+/// Captures the state of the global random number generator without advancing it.
+pub fn rng_state() -> RngState {
+    with_rng(|rng| RngState {
+        seed: rng.get_seed(),
+        word_pos: rng.get_word_pos(),
+    })
+}
+
+/// Restores the state of the global random number generator, which resumes the sequence of
+/// random values where it was captured.
+pub fn set_rng_state(state: RngState) {
+    let mut rng = ChaCha12Rng::from_seed(state.seed);
+    rng.set_word_pos(state.word_pos);
+
+    *RNG.lock().unwrap() = Some(rng);
+    GENERATION.fetch_add(1, Ordering::SeqCst);
+}
+
+/// Seeds the random number generator of the device, used instead of the global one by the
+/// backends to sample random tensors on that device.
+pub fn seed_device<D: PartialEq + Clone + Send + 'static>(device: &D, seed: u64) {
+    set_device_rng(device, ChaCha12Rng::seed_from_u64(seed));
+}
+
+/// If the device has its own generator, seeded with [seed_device].
+pub fn is_device_seeded<D: PartialEq + 'static>(device: &D) -> bool {
+    device_rng_index(&DEVICE_RNGS.lock().unwrap(), device).is_some()
+}
+
+/// Runs the function with the random number generator of the device if it has been seeded with
+/// [seed_device], or with the global one otherwise.
+pub fn with_device_rng<D, T, F>(device: &D, func: F) -> T
+where
+    D: PartialEq + 'static,
+    F: FnOnce(&mut ChaCha12Rng) -> T,
+{
+    let mut rngs = DEVICE_RNGS.lock().unwrap();
+
+    if let Some(index) = device_rng_index(&rngs, device) {
+        return func(&mut rngs[index].1);
+    }
+
+    core::mem::drop(rngs);
+    with_rng(func)
+}
+
+/// Captures the state of the random number generator of the device without advancing it, if the
+/// device has been seeded with [seed_device].
+pub fn device_rng_state<D: PartialEq + 'static>(device: &D) -> Option<RngState> {
+    let rngs = DEVICE_RNGS.lock().unwrap();
+
+    device_rng_index(&rngs, device).map(|index| {
+        let rng = &rngs[index].1;
+
+        RngState {
+            seed: rng.get_seed(),
+            word_pos: rng.get_word_pos(),
+        }
+    })
+}
+
+/// Restores the state of the random number generator of the device, captured with
+/// [device_rng_state].
+pub fn set_device_rng_state<D: PartialEq + Clone + Send + 'static>(device: &D, state: RngState) {
+    let mut rng = ChaCha12Rng::from_seed(state.seed);
+    rng.set_word_pos(state.word_pos);
+
+    set_device_rng(device, rng);
+}
+
+/// The number of times a generator has been seeded or restored, so the backends keeping their
+/// own generator, such as libtorch, only reseed it when it changes.
+pub fn generation() -> usize {
+    GENERATION.load(Ordering::SeqCst)
+}
+
+fn set_device_rng<D: PartialEq + Clone + Send + 'static>(device: &D, rng: ChaCha12Rng) {
+    let mut rngs = DEVICE_RNGS.lock().unwrap();
+
+    match device_rng_index(&rngs, device) {
+        Some(index) => rngs[index].1 = rng,
+        None => rngs.push((Box::new(device.clone()), rng)),
+    }
+
+    GENERATION.fetch_add(1, Ordering::SeqCst);
+}
+
+fn device_rng_index<D: PartialEq + 'static>(
+    rngs: &[(Box<dyn Any + Send>, ChaCha12Rng)],
+    device: &D,
+) -> Option<usize> {
+    rngs.iter()
+        .position(|(key, _)| key.downcast_ref::<D>() == Some(device))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A single test since the tests of the crate share the global generator.
+    #[test]
+    fn rng_state_should_resume_the_sequence_without_changing_it() {
+        seed(42);
+        let _: u32 = with_rng(|rng| rng.gen());
+        let values: [u64; 4] = with_rng(|rng| rng.gen());
+
+        seed(42);
+        let _: u32 = with_rng(|rng| rng.gen());
+        let state = rng_state();
+        let values_with_state: [u64; 4] = with_rng(|rng| rng.gen());
+
+        set_rng_state(state);
+        let values_restored: [u64; 4] = with_rng(|rng| rng.gen());
+
+        assert_eq!(values, values_with_state);
+        assert_eq!(values, values_restored);
+    }
+
+    #[test]
+    fn device_rng_should_only_be_used_for_its_device() {
+        #[derive(Clone, PartialEq)]
+        struct Device(usize);
+
+        seed_device(&Device(0), 42);
+        seed_device(&Device(1), 7);
+        let values: [u64; 4] = with_device_rng(&Device(0), |rng| rng.gen());
+        let state = device_rng_state(&Device(1)).unwrap();
+
+        seed_device(&Device(0), 42);
+        let _: [u64; 4] = with_device_rng(&Device(1), |rng| rng.gen());
+        let values_device: [u64; 4] = with_device_rng(&Device(0), |rng| rng.gen());
+        set_device_rng_state(&Device(1), state.clone());
+
+        assert_eq!(values, values_device);
+        assert_eq!(device_rng_state(&Device(1)), Some(state));
+        assert!(is_device_seeded(&Device(1)));
+        assert!(!is_device_seeded(&Device(2)));
+        assert_eq!(device_rng_state(&Device(2)), None);
+    }
+}
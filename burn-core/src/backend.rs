@@ -0,0 +1,10 @@
+/// The [WGPU](burn_wgpu) backend crate.
+pub use burn_wgpu as wgpu;
+
+/// The [WGPU](burn_wgpu) backend, running on the best graphics API of the platform: Vulkan,
+/// Metal, DirectX 12 or WebGPU.
+pub type WgpuBackend<F = f32, I = i32> = burn_wgpu::WgpuBackend<burn_wgpu::AutoGraphicsApi, F, I>;
+
+/// The [WGPU backend](WgpuBackend) with automatic differentiation, used for training.
+pub type WgpuAutodiffBackend<F = f32, I = i32> =
+    burn_autodiff::ADBackendDecorator<WgpuBackend<F, I>>;
@@ -1,4 +1,5 @@
 use super::{batcher::Batcher, BatchDataLoader, BatchStrategy, DataLoader, FixBatchStrategy};
+use burn_common::rand::{with_rng, Rng};
 use burn_dataset::{transform::ShuffledDataset, Dataset};
 use std::sync::Arc;
 
@@ -65,6 +66,17 @@ where
         self
     }
 
+    /// Sets the seed for shuffling from the global random number generator, so the order is
+    /// reproducible after calling [seed](crate::seed).
+    ///
+    /// # Returns
+    ///
+    /// The data loader builder.
+    pub fn shuffle_global(self) -> Self {
+        let seed = with_rng(|rng| rng.gen());
+        self.shuffle(seed)
+    }
+
     /// Sets the number of workers.
     ///
     /// # Arguments
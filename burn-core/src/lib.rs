@@ -36,8 +36,19 @@ pub mod record;
 /// Module for the tensor.
 pub mod tensor;
 
+/// Backend module.
+#[cfg(feature = "wgpu")]
+pub mod backend;
+
 extern crate alloc;
 
+/// Seed the global random number generator, or capture and restore its state, making the random
+/// tensors of every backend, as well as the dataloader shuffling with `shuffle_global`,
+/// reproducible across runs. A device can also be given its own generator with `seed_device`.
+pub use burn_common::rand::{
+    device_rng_state, rng_state, seed, seed_device, set_device_rng_state, set_rng_state, RngState,
+};
+
 #[cfg(all(test, not(feature = "test-tch"), not(feature = "test-wgpu")))]
 pub type TestBackend = burn_ndarray::NdArrayBackend<f32>;
 
@@ -1,6 +1,6 @@
 use alloc::vec::Vec;
 
-use super::ParamId;
+use super::{ModuleSummary, ParamId, SummaryVisitor};
 use crate::{
     record::Record,
     tensor::backend::{ADBackend, Backend},
@@ -171,6 +171,21 @@ pub trait Module<B: Backend>: Clone + Send + Sync + core::fmt::Debug {
             init = || 0
         )
     }
+
+    /// Get a [summary](ModuleSummary) of the parameters of the module and all of its sub-modules,
+    /// including their shapes, devices and the total memory they use.
+    fn summary(&self) -> ModuleSummary<B> {
+        let mut visitor = SummaryVisitor {
+            path: Vec::new(),
+            params: Vec::new(),
+        };
+        self.visit(&mut visitor);
+
+        ModuleSummary {
+            params: visitor.params,
+        }
+    }
+
     /// Visit each tensor in the module with a [visitor](ModuleVisitor).
     fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V);
 
@@ -188,6 +203,13 @@ pub trait Module<B: Backend>: Clone + Send + Sync + core::fmt::Debug {
 pub trait ModuleVisitor<B: Backend> {
     /// Visit a tensor in the module.
     fn visit<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>);
+
+    /// Called before visiting the sub-module stored under the given name, which is the name of
+    /// its field, or its index in a collection.
+    fn enter_module(&mut self, _name: &str) {}
+
+    /// Called after visiting the sub-module stored under the given name.
+    fn exit_module(&mut self, _name: &str) {}
 }
 
 /// Module mapper trait.
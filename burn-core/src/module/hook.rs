@@ -0,0 +1,273 @@
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::any::Any;
+use core::fmt::Debug;
+
+use crate::module::{ADModule, Module, ModuleMapper, ModuleVisitor};
+use crate::tensor::backend::{ADBackend, Backend};
+
+type ErasedHook = Arc<dyn Fn(&dyn Any, &dyn Any) + Send + Sync>;
+
+/// Module wrapper calling hooks with the input and output of each forward pass of the wrapped
+/// module.
+///
+/// Modules don't share a common forward signature, so the forward pass of the wrapped module is
+/// given when calling [forward_with](Hooked::forward_with). The wrapper is transparent for the
+/// record and the parameters of the module, which keep the same path.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[derive(Module, Debug)]
+/// struct Model<B: Backend> {
+///     conv: Hooked<Conv2d<B>>,
+/// }
+///
+/// impl<B: Backend> Model<B> {
+///     fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+///         self.conv.forward_with(input, Conv2d::forward)
+///     }
+/// }
+///
+/// let model = Model {
+///     conv: Hooked::new(conv).with_hook(|_input: &Tensor<B, 4>, output: &Tensor<B, 4>| {
+///         println!("{:?}", output.dims());
+///     }),
+/// };
+/// ```
+#[derive(Clone)]
+pub struct Hooked<M> {
+    module: M,
+    hooks: Vec<ErasedHook>,
+}
+
+impl<M> Hooked<M> {
+    /// Wrap the module, without any hook.
+    pub fn new(module: M) -> Self {
+        Self {
+            module,
+            hooks: Vec::new(),
+        }
+    }
+
+    /// Add a hook called after each forward pass with the input and output of the module.
+    ///
+    /// The hook is only called by the forward passes whose input and output have the given
+    /// types. In particular, the hooks registered with the tensors of an autodiff backend are
+    /// kept by the [inner module](ADModule::valid), but not called by its forward passes, which
+    /// use the tensors of the inner backend.
+    pub fn with_hook<I, O, F>(mut self, hook: F) -> Self
+    where
+        I: 'static,
+        O: 'static,
+        F: Fn(&I, &O) + Send + Sync + 'static,
+    {
+        self.hooks.push(Arc::new(move |input, output| {
+            if let (Some(input), Some(output)) = (input.downcast_ref(), output.downcast_ref()) {
+                hook(input, output);
+            }
+        }));
+        self
+    }
+
+    /// Remove all the hooks.
+    pub fn clear_hooks(&mut self) {
+        self.hooks.clear();
+    }
+
+    /// The wrapped module.
+    pub fn module(&self) -> &M {
+        &self.module
+    }
+
+    /// Unwrap the module, dropping its hooks.
+    pub fn into_module(self) -> M {
+        self.module
+    }
+
+    /// Applies the given forward pass of the wrapped module on the input, then calls the hooks
+    /// with the input and the output.
+    ///
+    /// The input is only cloned when there are hooks.
+    pub fn forward_with<I, O, F>(&self, input: I, forward: F) -> O
+    where
+        I: Clone + 'static,
+        O: 'static,
+        F: FnOnce(&M, I) -> O,
+    {
+        if self.hooks.is_empty() {
+            return forward(&self.module, input);
+        }
+
+        let output = forward(&self.module, input.clone());
+        for hook in self.hooks.iter() {
+            hook(&input, &output);
+        }
+
+        output
+    }
+}
+
+impl<M: Debug> Debug for Hooked<M> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Hooked")
+            .field("module", &self.module)
+            .field("num_hooks", &self.hooks.len())
+            .finish()
+    }
+}
+
+impl<M, B> Module<B> for Hooked<M>
+where
+    M: Module<B>,
+    B: Backend,
+{
+    type Record = M::Record;
+
+    fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
+        self.module.visit(visitor)
+    }
+
+    fn map<Mapper: ModuleMapper<B>>(self, mapper: &mut Mapper) -> Self {
+        Self {
+            module: self.module.map(mapper),
+            hooks: self.hooks,
+        }
+    }
+
+    fn load_record(self, record: Self::Record) -> Self {
+        Self {
+            module: self.module.load_record(record),
+            hooks: self.hooks,
+        }
+    }
+
+    fn into_record(self) -> Self::Record {
+        self.module.into_record()
+    }
+}
+
+impl<M, B> ADModule<B> for Hooked<M>
+where
+    M: ADModule<B>,
+    B: ADBackend,
+{
+    type InnerModule = Hooked<M::InnerModule>;
+
+    fn valid(&self) -> Self::InnerModule {
+        Hooked {
+            module: self.module.valid(),
+            hooks: self.hooks.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as burn;
+    use crate::nn::{Linear, LinearConfig, ReLU};
+    use crate::tensor::{Distribution, Tensor};
+    use crate::TestBackend;
+    use alloc::vec;
+
+    #[derive(Module, Debug)]
+    struct Mlp<B: Backend> {
+        hidden: Hooked<Linear<B>>,
+        activation: Hooked<ReLU>,
+        output: Linear<B>,
+    }
+
+    impl<B: Backend> Mlp<B> {
+        fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2> {
+            let x = self.hidden.forward_with(input, Linear::forward);
+            let x = self.activation.forward_with(x, ReLU::forward);
+
+            self.output.forward(x)
+        }
+    }
+
+    #[test]
+    fn hooks_should_receive_the_input_and_output_of_the_module() {
+        let outputs = Arc::new(spin::Mutex::new(Vec::new()));
+        let captured = outputs.clone();
+        let model = Mlp::<TestBackend> {
+            hidden: Hooked::new(LinearConfig::new(4, 8).init()).with_hook(
+                move |input: &Tensor<TestBackend, 2>, output: &Tensor<TestBackend, 2>| {
+                    captured.lock().push((input.clone(), output.clone()));
+                },
+            ),
+            activation: Hooked::new(ReLU::new()),
+            output: LinearConfig::new(8, 2).init(),
+        };
+        let input = Tensor::random([3, 4], Distribution::Default);
+
+        model.forward(input.clone());
+        model.forward(input.clone());
+
+        let outputs = outputs.lock();
+        assert_eq!(outputs.len(), 2);
+        let (hook_input, hook_output) = &outputs[0];
+        hook_input
+            .clone()
+            .into_data()
+            .assert_approx_eq(&input.clone().into_data(), 3);
+        hook_output
+            .clone()
+            .into_data()
+            .assert_approx_eq(&model.hidden.module().forward(input).into_data(), 3);
+    }
+
+    #[test]
+    fn hooks_should_only_be_called_with_their_tensor_types() {
+        let calls = Arc::new(spin::Mutex::new(0));
+        let counter = calls.clone();
+        let hidden = Hooked::new(LinearConfig::new(4, 8).init::<TestBackend>()).with_hook(
+            move |_: &Tensor<TestBackend, 3>, _: &Tensor<TestBackend, 3>| *counter.lock() += 1,
+        );
+
+        hidden.forward_with(
+            Tensor::random([3, 4], Distribution::Default),
+            Linear::forward,
+        );
+        hidden.forward_with(
+            Tensor::random([2, 3, 4], Distribution::Default),
+            Linear::forward,
+        );
+
+        assert_eq!(*calls.lock(), 1);
+    }
+
+    #[test]
+    fn hooked_module_should_keep_the_parameter_paths_and_record() {
+        let linear = LinearConfig::new(4, 8).init::<TestBackend>();
+        let model = Mlp::<TestBackend> {
+            hidden: Hooked::new(linear.clone()),
+            activation: Hooked::new(ReLU::new()),
+            output: LinearConfig::new(8, 2).init(),
+        };
+
+        let names = model
+            .summary()
+            .params
+            .into_iter()
+            .map(|param| param.name)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec![
+                "hidden.weight",
+                "hidden.bias",
+                "output.weight",
+                "output.bias"
+            ]
+        );
+
+        let record = model.hidden.clone().into_record();
+        linear
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&record.weight.val().into_data(), 3);
+    }
+}
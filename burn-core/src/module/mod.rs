@@ -1,5 +1,9 @@
 mod base;
+mod hook;
 mod param;
+mod summary;
 
 pub use base::*;
+pub use hook::*;
 pub use param::*;
+pub use summary::*;
@@ -1,4 +1,5 @@
 use crate::module::{ADModule, Module, ModuleMapper, ModuleVisitor};
+use alloc::string::ToString;
 use alloc::vec::Vec;
 use burn_tensor::backend::{ADBackend, Backend};
 use core::fmt::Debug;
@@ -59,8 +60,11 @@ where
     }
 
     fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
-        self.iter().for_each(|module| {
+        self.iter().enumerate().for_each(|(index, module)| {
+            let name = index.to_string();
+            visitor.enter_module(&name);
             module.visit(visitor);
+            visitor.exit_module(&name);
         });
     }
 
@@ -118,8 +122,11 @@ where
     }
 
     fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
-        self.iter().for_each(|module| {
+        self.iter().enumerate().for_each(|(index, module)| {
+            let name = index.to_string();
+            visitor.enter_module(&name);
             module.visit(visitor);
+            visitor.exit_module(&name);
         });
     }
 
@@ -0,0 +1,260 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Display;
+
+use super::{ModuleVisitor, ParamId};
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// Summary of a single parameter tensor of a [module](crate::module::Module).
+#[derive(Debug, Clone)]
+pub struct ParamSummary<B: Backend> {
+    /// The id of the parameter.
+    pub id: ParamId,
+    /// The path of the parameter in the module, e.g. `layers.0.weight`.
+    pub name: String,
+    /// The dimensions of the parameter.
+    pub dims: Vec<usize>,
+    /// The number of elements of the parameter.
+    pub num_params: usize,
+    /// If the parameter requires gradients.
+    pub require_grad: bool,
+    /// The device where the parameter is stored.
+    pub device: B::Device,
+}
+
+impl<B: Backend> ParamSummary<B> {
+    /// The path of the layer holding the parameter, empty for the parameters of the root module.
+    pub fn layer(&self) -> &str {
+        match self.name.rsplit_once('.') {
+            Some((layer, _)) => layer,
+            None => "",
+        }
+    }
+}
+
+/// Summary of the parameters of a single layer of a [module](crate::module::Module).
+#[derive(Debug, Clone)]
+pub struct LayerSummary {
+    /// The path of the layer in the module, e.g. `layers.0`, empty for the root module.
+    pub name: String,
+    /// The dimensions of each parameter of the layer.
+    pub dims: Vec<Vec<usize>>,
+    /// The number of parameters of the layer.
+    pub num_params: usize,
+    /// The number of parameters of the layer requiring gradients.
+    pub num_trainable_params: usize,
+}
+
+/// Summary of a [module](crate::module::Module) listing all of its parameters, in the order they
+/// are visited.
+///
+/// The summary can be printed with its [Display](core::fmt::Display) implementation, which lists
+/// the parameters aggregated per [layer](LayerSummary).
+#[derive(Debug, Clone)]
+pub struct ModuleSummary<B: Backend> {
+    /// The parameters of the module and all of its sub-modules.
+    pub params: Vec<ParamSummary<B>>,
+}
+
+impl<B: Backend> ModuleSummary<B> {
+    /// The name of the float element type used to store the parameters.
+    pub fn dtype(&self) -> &'static str {
+        core::any::type_name::<B::FloatElem>()
+    }
+
+    /// The total number of parameters.
+    pub fn num_params(&self) -> usize {
+        self.params.iter().map(|param| param.num_params).sum()
+    }
+
+    /// The number of parameters requiring gradients.
+    pub fn num_trainable_params(&self) -> usize {
+        self.params
+            .iter()
+            .filter(|param| param.require_grad)
+            .map(|param| param.num_params)
+            .sum()
+    }
+
+    /// The total memory used by the parameters in bytes.
+    pub fn num_bytes(&self) -> usize {
+        self.num_params() * core::mem::size_of::<B::FloatElem>()
+    }
+
+    /// The parameters aggregated per layer, in the order the layers are visited.
+    pub fn layers(&self) -> Vec<LayerSummary> {
+        let mut layers: Vec<LayerSummary> = Vec::new();
+
+        for param in self.params.iter() {
+            let index = match layers.iter().position(|layer| layer.name == param.layer()) {
+                Some(index) => index,
+                None => {
+                    layers.push(LayerSummary {
+                        name: param.layer().to_string(),
+                        dims: Vec::new(),
+                        num_params: 0,
+                        num_trainable_params: 0,
+                    });
+                    layers.len() - 1
+                }
+            };
+            let layer = &mut layers[index];
+
+            layer.dims.push(param.dims.clone());
+            layer.num_params += param.num_params;
+            if param.require_grad {
+                layer.num_trainable_params += param.num_params;
+            }
+        }
+
+        layers
+    }
+
+    /// The devices where the parameters are stored.
+    pub fn devices(&self) -> Vec<B::Device> {
+        let mut devices = Vec::new();
+        for param in self.params.iter() {
+            if !devices.contains(&param.device) {
+                devices.push(param.device.clone());
+            }
+        }
+        devices
+    }
+}
+
+impl<B: Backend> Display for ModuleSummary<B> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        writeln!(
+            f,
+            "{:<24} {:<24} {:>12} {:>12}",
+            "layer", "shapes", "params", "trainable"
+        )?;
+
+        for layer in self.layers() {
+            let name = match layer.name.is_empty() {
+                true => "(root)",
+                false => layer.name.as_str(),
+            };
+            let dims = layer
+                .dims
+                .iter()
+                .map(|dims| format!("{dims:?}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            writeln!(
+                f,
+                "{:<24} {:<24} {:>12} {:>12}",
+                name, dims, layer.num_params, layer.num_trainable_params
+            )?;
+        }
+
+        writeln!(f, "Total params: {}", self.num_params())?;
+        writeln!(f, "Trainable params: {}", self.num_trainable_params())?;
+        writeln!(f, "Dtype: {}", self.dtype())?;
+        writeln!(f, "Devices: {:?}", self.devices())?;
+        write!(f, "Memory: {} bytes", self.num_bytes())
+    }
+}
+
+/// Visitor collecting the parameters along with their path, made of the names of the sub-modules
+/// entered to reach them.
+pub(crate) struct SummaryVisitor<B: Backend> {
+    pub(crate) path: Vec<String>,
+    pub(crate) params: Vec<ParamSummary<B>>,
+}
+
+impl<B: Backend> ModuleVisitor<B> for SummaryVisitor<B> {
+    fn visit<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        let shape = tensor.shape();
+
+        self.params.push(ParamSummary {
+            id: id.clone(),
+            name: self.path.join("."),
+            num_params: shape.num_elements(),
+            dims: shape.dims.to_vec(),
+            require_grad: tensor.is_require_grad(),
+            device: tensor.device(),
+        });
+    }
+
+    fn enter_module(&mut self, name: &str) {
+        self.path.push(name.to_string());
+    }
+
+    fn exit_module(&mut self, _name: &str) {
+        self.path.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as burn;
+    use crate::module::Module;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::tensor::backend::Backend;
+    use crate::TestBackend;
+    use alloc::string::ToString;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn summary_should_list_every_param() {
+        let linear = LinearConfig::new(4, 3).init::<TestBackend>();
+
+        let summary = linear.summary();
+
+        assert_eq!(summary.params.len(), 2);
+        assert_eq!(summary.params[0].dims, [4, 3]);
+        assert_eq!(summary.params[1].dims, [3]);
+        assert_eq!(summary.num_params(), 15);
+        assert_eq!(summary.num_params(), linear.num_params());
+        assert_eq!(summary.num_bytes(), 15 * 4);
+        assert_eq!(summary.dtype(), "f32");
+    }
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        encoder: Linear<B>,
+        layers: Vec<Linear<B>>,
+    }
+
+    #[test]
+    fn summary_should_aggregate_the_params_per_layer() {
+        let model = Model::<TestBackend> {
+            encoder: LinearConfig::new(4, 3).init(),
+            layers: vec![
+                LinearConfig::new(3, 2).init(),
+                LinearConfig::new(2, 1).with_bias(false).init(),
+            ],
+        };
+
+        let summary = model.summary();
+        let layers = summary.layers();
+
+        assert_eq!(summary.params[2].name, "layers.0.weight");
+        assert_eq!(layers.len(), 3);
+        assert_eq!(layers[0].name, "encoder");
+        assert_eq!(layers[0].dims, [vec![4, 3], vec![3]]);
+        assert_eq!(layers[0].num_params, 15);
+        assert_eq!(layers[1].name, "layers.0");
+        assert_eq!(layers[1].num_params, 8);
+        assert_eq!(layers[2].name, "layers.1");
+        assert_eq!(layers[2].dims, [vec![2, 1]]);
+        assert!(summary.to_string().lines().any(|line| {
+            line.split_whitespace().collect::<Vec<_>>()
+                == ["layers.0", "[3,", "2],", "[2]", "8", "0"]
+        }));
+    }
+
+    #[test]
+    fn summary_should_count_trainable_params() {
+        let linear = LinearConfig::new(4, 3).init::<TestBackend>().no_grad();
+
+        let summary = linear.summary();
+
+        assert_eq!(summary.num_trainable_params(), 0);
+        assert!(summary.to_string().contains("Total params: 15"));
+    }
+}
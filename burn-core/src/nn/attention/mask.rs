@@ -2,6 +2,70 @@ use alloc::vec::Vec;
 
 use burn_tensor::{backend::Backend, Bool, Data, ElementConversion, Int, Shape, Tensor};
 
+/// Attention mask used by the [multihead attention](super::MultiHeadAttention) module.
+///
+/// The mask has the shape `[batch_size, seq_length_1, seq_length_2]`, where `true` marks the
+/// positions of the keys that can't be attended to by each query.
+#[derive(Debug, Clone)]
+pub struct AttentionMask<B: Backend> {
+    tensor: Tensor<B, 3, Bool>,
+}
+
+impl<B: Backend> AttentionMask<B> {
+    /// Create a causal mask, where each position can only attend to itself and the previous ones.
+    pub fn causal(batch_size: usize, seq_length: usize, device: &B::Device) -> Self {
+        Self::new(generate_autoregressive_mask(batch_size, seq_length, device))
+    }
+
+    /// Create a mask from a padding mask of shape `[batch_size, seq_length_2]`, which is
+    /// broadcasted over the `seq_length_1` queries.
+    pub fn padding(mask_pad: Tensor<B, 2, Bool>, seq_length_1: usize) -> Self {
+        let [batch_size, seq_length_2] = mask_pad.dims();
+        let tensor = mask_pad
+            .reshape([batch_size, 1, seq_length_2])
+            .repeat(1, seq_length_1);
+
+        Self::new(tensor)
+    }
+
+    /// Create a padding mask from the length of each sequence in the batch.
+    ///
+    /// Every key position greater or equal to the length of its sequence is masked.
+    pub fn padding_from_lengths(
+        lengths: &[usize],
+        seq_length_1: usize,
+        seq_length_2: usize,
+        device: &B::Device,
+    ) -> Self {
+        Self::padding(
+            generate_padding_mask_from_lengths(lengths, seq_length_2, device),
+            seq_length_1,
+        )
+    }
+
+    /// Combine two masks, masking every position masked by at least one of them.
+    pub fn combine(self, other: Self) -> Self {
+        let tensor = self.tensor.int().add(other.tensor.int()).greater_elem(0);
+
+        Self::new(tensor)
+    }
+
+    /// Get the mask as a boolean tensor of shape `[batch_size, seq_length_1, seq_length_2]`.
+    pub fn into_tensor(self) -> Tensor<B, 3, Bool> {
+        self.tensor
+    }
+
+    fn new(tensor: Tensor<B, 3, Bool>) -> Self {
+        Self { tensor }
+    }
+}
+
+impl<B: Backend> From<Tensor<B, 3, Bool>> for AttentionMask<B> {
+    fn from(tensor: Tensor<B, 3, Bool>) -> Self {
+        Self::new(tensor)
+    }
+}
+
 /// Generate an autoregressive attention mask.
 ///
 /// The mask can be used in Transformer modules to train models to generate tensors sequentially.
@@ -10,16 +74,7 @@ pub fn generate_autoregressive_mask<B: Backend>(
     seq_length: usize,
     device: &B::Device,
 ) -> Tensor<B, 3, Bool> {
-    let mut mask = Tensor::<B, 3, Int>::zeros([1, seq_length, seq_length]);
-
-    for i in 0..(seq_length - 1) {
-        let values = Tensor::<B, 3, Int>::ones([1, 1, seq_length - (i + 1)]);
-        mask = mask.slice_assign([0..1, i..i + 1, i + 1..seq_length], values);
-    }
-
-    mask = mask.to_device(device).repeat(0, batch_size);
-
-    mask.equal_elem(1_i64.elem::<i64>())
+    Tensor::tril_mask([batch_size, seq_length, seq_length], 0, device)
 }
 
 /// Generate a padding attention mask.
@@ -86,6 +141,24 @@ pub fn generate_padding_mask<B: Backend>(
     GeneratePaddingMask { tensor, mask }
 }
 
+/// Generate a padding mask of shape `[batch_size, max_seq_length]` from the length of each
+/// sequence in the batch.
+pub fn generate_padding_mask_from_lengths<B: Backend>(
+    lengths: &[usize],
+    max_seq_length: usize,
+    device: &B::Device,
+) -> Tensor<B, 2, Bool> {
+    let values = lengths
+        .iter()
+        .flat_map(|length| (0..max_seq_length).map(move |position| position >= *length))
+        .collect();
+
+    Tensor::from_bool_device(
+        Data::new(values, Shape::new([lengths.len(), max_seq_length])),
+        device,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -138,4 +211,44 @@ mod tests {
             ])
         );
     }
+
+    #[test]
+    fn test_generate_padding_mask_from_lengths() {
+        let device = <TestBackend as Backend>::Device::default();
+
+        let mask = generate_padding_mask_from_lengths::<TestBackend>(&[1, 3, 2], 3, &device);
+
+        assert_eq!(
+            mask.into_data(),
+            Data::from([
+                [false, true, true],
+                [false, false, false],
+                [false, false, true],
+            ])
+        );
+    }
+
+    #[test]
+    fn test_attention_mask_combine_causal_and_padding() {
+        let device = <TestBackend as Backend>::Device::default();
+
+        let causal = AttentionMask::<TestBackend>::causal(2, 3, &device);
+        let padding = AttentionMask::padding_from_lengths(&[3, 2], 3, 3, &device);
+
+        assert_eq!(
+            causal.combine(padding).into_tensor().into_data(),
+            Data::from([
+                [
+                    [false, true, true],
+                    [false, false, true],
+                    [false, false, false],
+                ],
+                [
+                    [false, true, true],
+                    [false, false, true],
+                    [false, false, true],
+                ]
+            ])
+        );
+    }
 }
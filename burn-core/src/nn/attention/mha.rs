@@ -1,5 +1,6 @@
 use crate as burn;
 
+use crate::nn::attention::AttentionMask;
 use crate::nn::cache::TensorCache;
 use crate::{
     config::Config,
@@ -53,8 +54,7 @@ pub struct MhaInput<B: Backend> {
     query: Tensor<B, 3>,
     key: Tensor<B, 3>,
     value: Tensor<B, 3>,
-    mask_pad: Option<Tensor<B, 2, Bool>>,
-    mask_attn: Option<Tensor<B, 3, Bool>>,
+    mask: Option<AttentionMask<B>>,
 }
 
 impl MultiHeadAttentionConfig {
@@ -107,8 +107,7 @@ impl<B: Backend> MhaInput<B> {
             query: tensor.clone(),
             key: tensor.clone(),
             value: tensor,
-            mask_pad: None,
-            mask_attn: None,
+            mask: None,
         }
     }
 
@@ -118,21 +117,28 @@ impl<B: Backend> MhaInput<B> {
             query,
             key,
             value,
-            mask_pad: None,
-            mask_attn: None,
+            mask: None,
         }
     }
 
-    /// Register the padding mask.
-    pub fn mask_pad(mut self, mask_pad: Tensor<B, 2, Bool>) -> Self {
-        self.mask_pad = Some(mask_pad);
+    /// Register an attention mask, combined with the mask already registered, if any.
+    pub fn mask(mut self, mask: AttentionMask<B>) -> Self {
+        self.mask = Some(match self.mask.take() {
+            Some(current) => current.combine(mask),
+            None => mask,
+        });
         self
     }
 
+    /// Register the padding mask.
+    pub fn mask_pad(self, mask_pad: Tensor<B, 2, Bool>) -> Self {
+        let [_, seq_length_1, _] = self.query.dims();
+        self.mask(AttentionMask::padding(mask_pad, seq_length_1))
+    }
+
     /// Register the attention mask.
-    pub fn mask_attn(mut self, mask_attn: Tensor<B, 3, Bool>) -> Self {
-        self.mask_attn = Some(mask_attn);
-        self
+    pub fn mask_attn(self, mask_attn: Tensor<B, 3, Bool>) -> Self {
+        self.mask(AttentionMask::from(mask_attn))
     }
 }
 
@@ -162,7 +168,7 @@ impl<B: Backend> MultiHeadAttention<B> {
         let value = self.attention_linear(input.value, &self.value);
 
         let attn_scores = self.attn_scores(query, key);
-        let weights = self.attn_weights(attn_scores, input.mask_pad, input.mask_attn);
+        let weights = self.attn_weights(attn_scores, input.mask);
 
         let context = weights.clone().matmul(value);
         let context = context
@@ -195,7 +201,7 @@ impl<B: Backend> MultiHeadAttention<B> {
             .forward(input.value, |t| self.attention_linear(t, &self.value));
 
         let attn_scores = self.attn_scores(query, key);
-        let weights = self.attn_weights(attn_scores, input.mask_pad, input.mask_attn);
+        let weights = self.attn_weights(attn_scores, input.mask);
 
         let context = weights.clone().matmul(value);
         let context = context
@@ -218,19 +224,10 @@ impl<B: Backend> MultiHeadAttention<B> {
     fn attn_weights(
         &self,
         mut attn_scores: Tensor<B, 4>,
-        mask_pad: Option<Tensor<B, 2, Bool>>,
-        mask_attn: Option<Tensor<B, 3, Bool>>,
+        mask: Option<AttentionMask<B>>,
     ) -> Tensor<B, 4> {
-        if let Some(mask_pad) = mask_pad {
-            let [batch_size, seq_length] = mask_pad.dims();
-
-            attn_scores = attn_scores.mask_fill(
-                mask_pad.reshape([batch_size, 1, 1, seq_length]),
-                self.min_float,
-            );
-        }
-
-        if let Some(mask_attn) = mask_attn {
+        if let Some(mask) = mask {
+            let mask_attn = mask.into_tensor();
             let [batch_size, seq_length_1, seq_length_2] = mask_attn.dims();
 
             attn_scores = attn_scores.mask_fill(
@@ -254,6 +251,7 @@ impl<B: Backend> MultiHeadAttention<B> {
 /// Cache for the [Multi Head Attention](MultiHeadAttention) layer.
 ///
 /// To be used during inference when decoding tokens.
+#[derive(Clone)]
 pub struct MhaCache<B: Backend> {
     query: MhaLinearCache<B, 4>,
     key: MhaLinearCache<B, 4>,
@@ -261,6 +259,7 @@ pub struct MhaCache<B: Backend> {
     output: MhaLinearCache<B, 3>,
 }
 
+#[derive(Clone)]
 enum MhaLinearCache<B: Backend, const D: usize> {
     Autoregressive(TensorCache<B, D>, usize),
     Full(TensorCache<B, D>),
@@ -287,6 +286,16 @@ impl<B: Backend> MhaCache<B> {
             output: MhaLinearCache::Autoregressive(TensorCache::empty(), 1),
         }
     }
+
+    /// Move the cached keys, values and projections to the given device.
+    pub fn to_device(self, device: &B::Device) -> Self {
+        Self {
+            query: self.query.into_device(device),
+            key: self.key.into_device(device),
+            value: self.value.into_device(device),
+            output: self.output.into_device(device),
+        }
+    }
 }
 
 impl<B: Backend, const D: usize> MhaLinearCache<B, D> {
@@ -302,6 +311,15 @@ impl<B: Backend, const D: usize> MhaLinearCache<B, D> {
             MhaLinearCache::Full(cache) => cache.forward_full(tensor, func),
         }
     }
+
+    fn into_device(self, device: &B::Device) -> Self {
+        match self {
+            MhaLinearCache::Autoregressive(cache, dim) => {
+                MhaLinearCache::Autoregressive(cache.to_device(device), dim)
+            }
+            MhaLinearCache::Full(cache) => MhaLinearCache::Full(cache.to_device(device)),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1,12 +1,14 @@
 use crate::tensor::backend::Backend;
 use crate::tensor::Tensor;
 
+#[derive(Clone)]
 pub(crate) enum CacheState<T> {
     Value(T),
     Empty,
 }
 
 /// A cache for a tensor.
+#[derive(Clone)]
 pub struct TensorCache<B: Backend, const D: usize> {
     pub(crate) state: CacheState<Tensor<B, D>>,
 }
@@ -22,4 +24,18 @@ impl<B: Backend, const D: usize> TensorCache<B, D> {
             state: CacheState::Empty,
         }
     }
+
+    /// Moves the cached tensor, if any, to the given device.
+    ///
+    /// # Returns
+    ///
+    /// The cache with its state on the given device.
+    pub fn to_device(self, device: &B::Device) -> Self {
+        let state = match self.state {
+            CacheState::Value(tensor) => CacheState::Value(tensor.to_device(device)),
+            CacheState::Empty => CacheState::Empty,
+        };
+
+        Self { state }
+    }
 }
@@ -2,7 +2,19 @@ pub(crate) fn checks_channels_div_groups(channels_in: usize, channels_out: usize
     let channels_in_div_by_group = channels_in % groups == 0;
     let channels_out_div_by_group = channels_out % groups == 0;
 
-    if !channels_in_div_by_group && !channels_out_div_by_group {
+    if !channels_in_div_by_group || !channels_out_div_by_group {
         panic!("Both channels must be divisible by the number of groups. Got channels_in={channels_in}, channels_out={channels_out}, groups={groups}");
     }
 }
+
+pub(crate) fn checks_padding_out<const D: usize>(
+    padding_out: [usize; D],
+    stride: [usize; D],
+    dilation: [usize; D],
+) {
+    for i in 0..D {
+        if padding_out[i] >= stride[i] && padding_out[i] >= dilation[i] {
+            panic!("The output padding must be smaller than either the stride or the dilation. Got padding_out={padding_out:?}, stride={stride:?}, dilation={dilation:?}");
+        }
+    }
+}
@@ -50,7 +50,7 @@ pub struct Conv1dConfig {
 /// - bias:   Tensor of shape `[channels_out]`
 #[derive(Module, Debug)]
 pub struct Conv1d<B: Backend> {
-    weight: Param<Tensor<B, 3>>,
+    pub(crate) weight: Param<Tensor<B, 3>>,
     bias: Option<Param<Tensor<B, 1>>>,
     stride: usize,
     kernel_size: usize,
@@ -113,14 +113,22 @@ impl<B: Backend> Conv1d<B> {
     /// - input: [batch_size, channels_in, length_in],
     /// - output: [batch_size, channels_out, length_out],
     pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
-        let [_batch_size, _channels, length] = input.dims();
-        let padding = self
-            .padding
-            .calculate_padding_1d(length, self.kernel_size, self.stride);
+        self.forward_with_weight(input, self.weight.val())
+    }
+
+    /// Applies the forward pass using the given weight instead of the module's own weight.
+    pub(crate) fn forward_with_weight(
+        &self,
+        input: Tensor<B, 3>,
+        weight: Tensor<B, 3>,
+    ) -> Tensor<B, 3> {
+        let (input, padding) =
+            self.padding
+                .pad_conv_input_1d(input, self.kernel_size, self.stride, self.dilation);
 
         conv1d(
             input,
-            self.weight.val(),
+            weight,
             self.bias.as_ref().map(|bias| bias.val()),
             ConvOptions::new([self.stride], [padding], [self.dilation], self.groups),
         )
@@ -131,7 +139,7 @@ impl<B: Backend> Conv1d<B> {
 mod tests {
     use super::*;
     use crate::TestBackend;
-    use burn_tensor::Data;
+    use burn_tensor::{Data, Distribution};
 
     #[test]
     fn initializer_default() {
@@ -157,4 +165,34 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::zeros(conv.weight.shape()), 3);
     }
+
+    #[test]
+    fn depthwise_forward() {
+        TestBackend::seed(0);
+
+        let config = Conv1dConfig::new(4, 8, 3).with_groups(4);
+        let conv = config.init::<TestBackend>();
+        let input = Tensor::<TestBackend, 3>::random([2, 4, 6], Distribution::Default);
+
+        let output = conv.forward(input);
+
+        assert_eq!(conv.weight.dims(), [8, 1, 3]);
+        assert_eq!(output.dims(), [2, 8, 4]);
+    }
+
+    #[test]
+    fn forward_same_padding_even_kernel() {
+        let config = Conv1dConfig::new(1, 1, 2)
+            .with_padding(PaddingConfig1d::Same)
+            .with_initializer(Initializer::Ones)
+            .with_bias(false);
+        let conv = config.init::<TestBackend>();
+        let input = Tensor::<TestBackend, 3>::from_floats([[[1.0, 2.0, 3.0]]]);
+
+        let output = conv.forward(input);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[3.0, 5.0, 3.0]]]), 3);
+    }
 }
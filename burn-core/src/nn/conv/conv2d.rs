@@ -49,7 +49,7 @@ pub struct Conv2dConfig {
 /// - bias:   Tensor of shape `[channels_out]`
 #[derive(Module, Debug)]
 pub struct Conv2d<B: Backend> {
-    weight: Param<Tensor<B, 4>>,
+    pub(crate) weight: Param<Tensor<B, 4>>,
     bias: Option<Param<Tensor<B, 1>>>,
     stride: [usize; 2],
     kernel_size: [usize; 2],
@@ -114,13 +114,21 @@ impl<B: Backend> Conv2d<B> {
     /// - input: [batch_size, channels_in, height_in, width_in],
     /// - output: [batch_size, channels_out, height_out, width_out],
     pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
-        let [_batch_size, _channels_in, height_in, width_in] = input.dims();
-        let padding =
+        self.forward_with_weight(input, self.weight.val())
+    }
+
+    /// Applies the forward pass using the given weight instead of the module's own weight.
+    pub(crate) fn forward_with_weight(
+        &self,
+        input: Tensor<B, 4>,
+        weight: Tensor<B, 4>,
+    ) -> Tensor<B, 4> {
+        let (input, padding) =
             self.padding
-                .calculate_padding_2d(height_in, width_in, &self.kernel_size, &self.stride);
+                .pad_conv_input_2d(input, self.kernel_size, self.stride, self.dilation);
         conv2d(
             input,
-            self.weight.val(),
+            weight,
             self.bias.as_ref().map(|bias| bias.val()),
             ConvOptions::new(self.stride, padding, self.dilation, self.groups),
         )
@@ -131,7 +139,7 @@ impl<B: Backend> Conv2d<B> {
 mod tests {
     use super::*;
     use crate::TestBackend;
-    use burn_tensor::Data;
+    use burn_tensor::{Data, Distribution};
 
     #[test]
     fn initializer_default() {
@@ -157,4 +165,43 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::zeros(conv.weight.shape()), 3);
     }
+
+    #[test]
+    fn depthwise_forward() {
+        TestBackend::seed(0);
+
+        let config = Conv2dConfig::new([4, 4], [3, 3])
+            .with_groups(4)
+            .with_padding(PaddingConfig2d::Explicit(1, 1));
+        let conv = config.init::<TestBackend>();
+        let input = Tensor::<TestBackend, 4>::random([2, 4, 5, 5], Distribution::Default);
+
+        let output = conv.forward(input);
+
+        assert_eq!(conv.weight.dims(), [4, 1, 3, 3]);
+        assert_eq!(output.dims(), [2, 4, 5, 5]);
+    }
+
+    #[test]
+    #[should_panic = "Both channels must be divisible by the number of groups."]
+    fn channels_not_divisible_by_groups() {
+        let config = Conv2dConfig::new([4, 6], [3, 3]).with_groups(4);
+
+        let _conv = config.init::<TestBackend>();
+    }
+
+    #[test]
+    fn forward_same_padding() {
+        TestBackend::seed(0);
+
+        let config = Conv2dConfig::new([2, 3], [2, 3])
+            .with_padding(PaddingConfig2d::Same)
+            .with_dilation([1, 2]);
+        let conv = config.init::<TestBackend>();
+        let input = Tensor::<TestBackend, 4>::random([1, 2, 5, 6], Distribution::Default);
+
+        let output = conv.forward(input);
+
+        assert_eq!(output.dims(), [1, 3, 5, 6]);
+    }
 }
@@ -65,6 +65,7 @@ impl ConvTranspose1dConfig {
     /// Initialize a new [conv transpose 1d](ConvTranspose1d) module.
     pub fn init<B: Backend>(&self) -> ConvTranspose1d<B> {
         checks::checks_channels_div_groups(self.channels[0], self.channels[1], self.groups);
+        checks::checks_padding_out([self.padding_out], [self.stride], [self.dilation]);
 
         let shape = [
             self.channels[0],
@@ -137,7 +138,7 @@ impl<B: Backend> ConvTranspose1d<B> {
 mod tests {
     use super::*;
     use crate::TestBackend;
-    use burn_tensor::Data;
+    use burn_tensor::{Data, Distribution};
 
     #[test]
     fn initializer_default() {
@@ -163,4 +164,20 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::zeros(conv.weight.shape()), 3);
     }
+
+    #[test]
+    fn forward_with_padding_out() {
+        TestBackend::seed(0);
+
+        let config = ConvTranspose1dConfig::new([2, 4], 3)
+            .with_stride(2)
+            .with_padding(1)
+            .with_padding_out(1);
+        let conv = config.init::<TestBackend>();
+        let input = Tensor::<TestBackend, 3>::random([1, 2, 5], Distribution::Default);
+
+        let output = conv.forward(input);
+
+        assert_eq!(output.dims(), [1, 4, 10]);
+    }
 }
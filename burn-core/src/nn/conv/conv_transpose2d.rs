@@ -65,6 +65,7 @@ impl ConvTranspose2dConfig {
     /// Initialize a new [conv transpose 2d](ConvTranspose2d) module.
     pub fn init<B: Backend>(&self) -> ConvTranspose2d<B> {
         checks::checks_channels_div_groups(self.channels[0], self.channels[1], self.groups);
+        checks::checks_padding_out(self.padding_out, self.stride, self.dilation);
 
         let shape = [
             self.channels[0],
@@ -138,7 +139,7 @@ impl<B: Backend> ConvTranspose2d<B> {
 mod tests {
     use super::*;
     use crate::TestBackend;
-    use burn_tensor::Data;
+    use burn_tensor::{Data, Distribution};
 
     #[test]
     fn initializer_default() {
@@ -165,4 +166,28 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::zeros(conv.weight.shape()), 3);
     }
+
+    #[test]
+    fn forward_with_padding_out() {
+        TestBackend::seed(0);
+
+        let config = ConvTranspose2dConfig::new([2, 4], [3, 3])
+            .with_stride([2, 2])
+            .with_padding([1, 1])
+            .with_padding_out([1, 1]);
+        let conv = config.init::<TestBackend>();
+        let input = Tensor::<TestBackend, 4>::random([1, 2, 4, 5], Distribution::Default);
+
+        let output = conv.forward(input);
+
+        assert_eq!(output.dims(), [1, 4, 8, 10]);
+    }
+
+    #[test]
+    #[should_panic = "The output padding must be smaller than either the stride or the dilation"]
+    fn padding_out_bigger_than_stride() {
+        let config = ConvTranspose2dConfig::new([2, 4], [3, 3]).with_padding_out([1, 1]);
+
+        let _conv = config.init::<TestBackend>();
+    }
 }
@@ -0,0 +1,208 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{ADModule, Module, ModuleMapper, ModuleVisitor};
+use crate::nn::conv::{Conv2d, Conv2dConfig, Conv2dRecord};
+use crate::nn::{Initializer, PaddingConfig2d};
+use crate::tensor::backend::{ADBackend, Backend};
+use crate::tensor::Tensor;
+use alloc::sync::Arc;
+use libm::sqrt;
+use spin::Once;
+
+/// Configuration to create a [LazyConv2d](LazyConv2d) layer.
+#[derive(Config, Debug)]
+pub struct LazyConv2dConfig {
+    /// The number of output channels.
+    pub channels_out: usize,
+    /// The size of the kernel.
+    pub kernel_size: [usize; 2],
+    /// The stride of the convolution.
+    #[config(default = "[1, 1]")]
+    pub stride: [usize; 2],
+    /// Spacing between kernel elements.
+    #[config(default = "[1, 1]")]
+    pub dilation: [usize; 2],
+    /// Controls the connections between input and output channels.
+    #[config(default = "1")]
+    pub groups: usize,
+    /// The padding configuration.
+    #[config(default = "PaddingConfig2d::Valid")]
+    pub padding: PaddingConfig2d,
+    /// If bias should be added to the output.
+    #[config(default = true)]
+    pub bias: bool,
+    /// The type of function used to initialize neural network parameters
+    #[config(default = "Initializer::KaimingUniform{gain:1.0/sqrt(3.0),fan_out_only:false}")]
+    pub initializer: Initializer,
+}
+
+/// A [2D convolution](Conv2d) layer where the number of input channels is inferred from the
+/// first forward pass.
+///
+/// The parameters are only created during the first forward pass, or when a record is loaded,
+/// so the layer has no parameter before then. The materialized layer is shared with the clones
+/// of the module, so the parameters created by a forward pass on a clone are also visible from
+/// the original module.
+#[derive(Clone, Debug)]
+pub struct LazyConv2d<B: Backend> {
+    config: LazyConv2dConfig,
+    conv: Arc<Once<Conv2d<B>>>,
+}
+
+impl LazyConv2dConfig {
+    /// Initialize a new [lazy conv2d](LazyConv2d) module.
+    pub fn init<B: Backend>(&self) -> LazyConv2d<B> {
+        LazyConv2d {
+            config: self.clone(),
+            conv: Arc::new(Once::new()),
+        }
+    }
+
+    fn conv_config(&self, channels_in: usize) -> Conv2dConfig {
+        Conv2dConfig::new([channels_in, self.channels_out], self.kernel_size)
+            .with_stride(self.stride)
+            .with_dilation(self.dilation)
+            .with_groups(self.groups)
+            .with_padding(self.padding.clone())
+            .with_bias(self.bias)
+            .with_initializer(self.initializer.clone())
+    }
+}
+
+impl<B: Backend> LazyConv2d<B> {
+    /// Applies the forward pass on the input tensor, creating the parameters on the device of the
+    /// input if it is the first pass.
+    ///
+    /// # Shapes
+    ///
+    /// - input: [batch_size, channels_in, height_in, width_in],
+    /// - output: [batch_size, channels_out, height_out, width_out],
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let conv = self.conv.call_once(|| {
+            let [_batch_size, channels_in, _height_in, _width_in] = input.dims();
+
+            self.config
+                .conv_config(channels_in)
+                .init()
+                .fork(&input.device())
+        });
+
+        conv.forward(input)
+    }
+
+    /// The materialized [conv2d](Conv2d) layer, if the parameters have been created.
+    pub fn conv(&self) -> Option<&Conv2d<B>> {
+        self.conv.get()
+    }
+
+    fn with_conv<O: Backend>(&self, conv: Option<Conv2d<O>>) -> LazyConv2d<O> {
+        let conv = match conv {
+            Some(conv) => Once::initialized(conv),
+            None => Once::new(),
+        };
+
+        LazyConv2d {
+            config: self.config.clone(),
+            conv: Arc::new(conv),
+        }
+    }
+}
+
+impl<B: Backend> Module<B> for LazyConv2d<B> {
+    type Record = Option<Conv2dRecord<B>>;
+
+    fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
+        if let Some(conv) = self.conv() {
+            conv.visit(visitor);
+        }
+    }
+
+    fn map<M: ModuleMapper<B>>(self, mapper: &mut M) -> Self {
+        let conv = self.conv().cloned().map(|conv| conv.map(mapper));
+
+        self.with_conv(conv)
+    }
+
+    fn load_record(self, record: Self::Record) -> Self {
+        let conv = match (self.conv().cloned(), record) {
+            (Some(conv), Some(record)) => Some(conv.load_record(record)),
+            (None, Some(record)) => {
+                let [_channels_out, channels_per_group, _, _] = record.weight.dims();
+                let channels_in = channels_per_group * self.config.groups;
+
+                Some(self.config.conv_config(channels_in).init_with(record))
+            }
+            (conv, None) => conv,
+        };
+
+        self.with_conv(conv)
+    }
+
+    fn into_record(self) -> Self::Record {
+        self.conv().cloned().map(Module::into_record)
+    }
+}
+
+impl<B: ADBackend> ADModule<B> for LazyConv2d<B> {
+    type InnerModule = LazyConv2d<B::InnerBackend>;
+
+    fn valid(&self) -> Self::InnerModule {
+        self.with_conv(self.conv().map(|conv| conv.valid()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Shape;
+
+    #[test]
+    fn lazy_conv2d_should_infer_channels_in() {
+        let conv = LazyConv2dConfig::new(4, [3, 3])
+            .with_padding(PaddingConfig2d::Same)
+            .init::<TestBackend>();
+        assert_eq!(conv.num_params(), 0);
+
+        let output = conv.forward(Tensor::<TestBackend, 4>::ones(Shape::new([2, 3, 8, 8])));
+
+        assert_eq!(output.shape(), Shape::new([2, 4, 8, 8]));
+        assert_eq!(conv.num_params(), 4 * 3 * 3 * 3 + 4);
+    }
+
+    #[test]
+    fn lazy_conv2d_should_materialize_from_record() {
+        let conv = LazyConv2dConfig::new(4, [3, 3])
+            .with_groups(2)
+            .init::<TestBackend>();
+        let input = Tensor::<TestBackend, 4>::ones(Shape::new([1, 6, 5, 5]));
+        let expected = conv.forward(input.clone());
+
+        let conv_loaded = LazyConv2dConfig::new(4, [3, 3])
+            .with_groups(2)
+            .init::<TestBackend>()
+            .load_record(conv.into_record());
+
+        assert_eq!(conv_loaded.num_params(), 4 * 3 * 3 * 3 + 4);
+        conv_loaded
+            .forward(input)
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn lazy_conv2d_should_be_materialized_by_a_forward_pass_on_a_clone() {
+        let conv = LazyConv2dConfig::new(4, [3, 3]).init::<TestBackend>();
+        let input = Tensor::<TestBackend, 4>::ones(Shape::new([1, 2, 5, 5]));
+
+        let expected = conv.clone().forward(input.clone());
+        let conv_mapped = conv.clone().fork(&input.device());
+
+        assert_eq!(conv.num_params(), 4 * 2 * 3 * 3 + 4);
+        conv_mapped
+            .forward(input)
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+}
@@ -0,0 +1,122 @@
+use crate as burn;
+
+use alloc::vec::Vec;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::{Distribution, Tensor};
+
+/// Configuration to create a [DropPath](DropPath) layer.
+#[derive(Config, Debug)]
+pub struct DropPathConfig {
+    /// The probability of randomly dropping the whole residual branch of a sample during training.
+    pub prob: f64,
+}
+
+/// Drop at random the residual branch of entire samples during training, also known as
+/// stochastic depth.
+///
+/// This regularization technique is described in the paper
+/// [Deep Networks with Stochastic Depth](https://arxiv.org/abs/1603.09382) and is commonly used
+/// by vision transformers. The module must be applied on the output of the residual branch,
+/// before it is added to the skip connection.
+///
+/// The input is also scaled during training to `1 / (1 - prob_keep)`.
+#[derive(Module, Clone, Debug)]
+pub struct DropPath {
+    prob: f64,
+}
+
+impl DropPathConfig {
+    /// Initialize a new [drop path](DropPath) module.
+    pub fn init(&self) -> DropPath {
+        DropPath { prob: self.prob }
+    }
+
+    /// Create the configurations of `num_layers` stacked [drop path](DropPath) layers, where the
+    /// probability increases linearly from zero for the first layer up to `prob` for the last one.
+    pub fn linear_schedule(prob: f64, num_layers: usize) -> Vec<Self> {
+        (0..num_layers)
+            .map(|layer| match num_layers {
+                1 => Self::new(prob),
+                _ => Self::new(prob * layer as f64 / (num_layers - 1) as f64),
+            })
+            .collect()
+    }
+}
+
+impl DropPath {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, ...]`
+    /// - output: `[batch_size, ...]`
+    pub fn forward<B: Backend, const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        if !B::ad_enabled() || self.prob == 0.0 {
+            return input;
+        }
+
+        let mut shape = [1; D];
+        shape[0] = input.dims()[0];
+
+        let prob_keep = 1.0 - self.prob;
+        let random =
+            Tensor::random_device(shape, Distribution::Bernoulli(prob_keep), &input.device());
+        let x = input * random;
+
+        x * (1.0 / prob_keep)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Shape;
+
+    #[cfg(feature = "std")]
+    use crate::{TestADBackend, TestBackend};
+
+    #[cfg(not(feature = "std"))]
+    use crate::TestBackend;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn with_ad_backend_should_drop_entire_samples() {
+        TestADBackend::seed(0);
+        let tensor = Tensor::<TestADBackend, 3>::ones(Shape::new([32, 4, 5]));
+        let drop_path = DropPathConfig::new(0.5).init();
+
+        let output = drop_path.forward(tensor.clone());
+
+        assert_ne!(tensor.to_data(), output.to_data());
+        let samples = output.reshape([32, 20]).into_data();
+        for sample in samples.value.chunks(20) {
+            assert!(sample.iter().all(|value| *value == sample[0]));
+            assert!(sample[0] == 0.0 || sample[0] == 2.0);
+        }
+    }
+
+    #[test]
+    fn without_ad_backend_should_not_change_input() {
+        let tensor = Tensor::<TestBackend, 3>::ones(Shape::new([32, 4, 5]));
+        let drop_path = DropPathConfig::new(0.5).init();
+
+        let output = drop_path.forward(tensor.clone());
+
+        assert_eq!(tensor.to_data(), output.to_data());
+    }
+
+    #[test]
+    fn linear_schedule_should_increase_prob_up_to_max() {
+        let configs = DropPathConfig::linear_schedule(0.3, 4);
+        let probs = configs.iter().map(|config| config.prob).collect::<Vec<_>>();
+
+        assert_eq!(probs.len(), 4);
+        assert_eq!(probs[0], 0.0);
+        assert!((probs[1] - 0.1).abs() < 1e-9);
+        assert!((probs[2] - 0.2).abs() < 1e-9);
+        assert!((probs[3] - 0.3).abs() < 1e-9);
+    }
+}
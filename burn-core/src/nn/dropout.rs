@@ -23,6 +23,26 @@ pub struct Dropout {
     prob: f64,
 }
 
+/// Configuration to create a [Dropout2d](Dropout2d) layer.
+#[derive(Config, Debug)]
+pub struct Dropout2dConfig {
+    /// The probability of randomly zeroes entire channels of the input tensor during training.
+    pub prob: f64,
+}
+
+/// Set at random entire channels of the input tensor to zero during training.
+///
+/// Contrary to [Dropout](Dropout), the same feature map is zeroed for all spatial positions,
+/// which is better suited to convolutional layers where adjacent pixels are strongly
+/// correlated, as described in the paper
+/// [Efficient Object Localization Using Convolutional Networks](https://arxiv.org/abs/1411.4280).
+///
+/// The input is also scaled during training to `1 / (1 - prob_keep)`.
+#[derive(Module, Clone, Debug)]
+pub struct Dropout2d {
+    prob: f64,
+}
+
 impl DropoutConfig {
     /// Initialize a new [dropout](Dropout) module.
     pub fn init(&self) -> Dropout {
@@ -30,6 +50,13 @@ impl DropoutConfig {
     }
 }
 
+impl Dropout2dConfig {
+    /// Initialize a new [dropout 2d](Dropout2d) module.
+    pub fn init(&self) -> Dropout2d {
+        Dropout2d { prob: self.prob }
+    }
+}
+
 impl Dropout {
     /// Applies the forward pass on the input tensor.
     ///
@@ -50,6 +77,32 @@ impl Dropout {
     }
 }
 
+impl Dropout2d {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels, ...]`
+    /// - output: `[batch_size, channels, ...]`
+    pub fn forward<B: Backend, const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        if !B::ad_enabled() || self.prob == 0.0 {
+            return input;
+        }
+
+        let dims = input.dims();
+        let mut shape = [1; D];
+        shape[0] = dims[0];
+        shape[1] = dims[1];
+
+        let prob_keep = 1.0 - self.prob;
+        let random =
+            Tensor::random_device(shape, Distribution::Bernoulli(prob_keep), &input.device());
+        let x = input * random;
+
+        x * (1.0 / prob_keep)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -81,4 +134,31 @@ mod tests {
 
         assert_eq!(tensor.to_data(), output.to_data());
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn dropout2d_with_ad_backend_should_zero_entire_channels() {
+        TestADBackend::seed(0);
+        let tensor = Tensor::<TestADBackend, 4>::ones(Shape::new([8, 16, 5, 5]));
+        let dropout = Dropout2dConfig::new(0.5).init();
+
+        let output = dropout.forward(tensor.clone());
+
+        assert_ne!(tensor.to_data(), output.to_data());
+        let channels = output.reshape([8 * 16, 25]).into_data();
+        for channel in channels.value.chunks(25) {
+            assert!(channel.iter().all(|value| *value == channel[0]));
+            assert!(channel[0] == 0.0 || channel[0] == 2.0);
+        }
+    }
+
+    #[test]
+    fn dropout2d_without_ad_backend_should_not_change_input() {
+        let tensor = Tensor::<TestBackend, 4>::ones(Shape::new([2, 3, 4, 4]));
+        let dropout = Dropout2dConfig::new(0.5).init();
+
+        let output = dropout.forward(tensor.clone());
+
+        assert_eq!(tensor.to_data(), output.to_data());
+    }
 }
@@ -6,18 +6,24 @@ use crate::module::Module;
 use crate::module::Param;
 use crate::tensor::backend::Backend;
 use crate::tensor::Tensor;
-use burn_tensor::Int;
+use burn_tensor::{ElementConversion, Int};
 
 /// Configuration to create an [Embedding](Embedding) layer.
-#[derive(Config)]
+#[derive(Config, Debug)]
 pub struct EmbeddingConfig {
     /// The number of embedding vectors.
-    n_embedding: usize,
+    pub n_embedding: usize,
     /// The size of each vector.
-    d_model: usize,
+    pub d_model: usize,
     /// The type of function used to initialize neural network parameters
     #[config(default = "Initializer::Normal{mean:0.0, std:1.0}")]
     pub initializer: Initializer,
+    /// If specified, the vector at `padding_idx` is initialized to zeros and doesn't receive any
+    /// gradient, so it stays a fixed padding vector during training.
+    pub padding_idx: Option<usize>,
+    /// If specified, each looked up vector with a L2 norm larger than `max_norm` is renormalized
+    /// to have a norm of `max_norm`.
+    pub max_norm: Option<f64>,
 }
 
 /// Lookup table to store a fix number of vectors.
@@ -26,27 +32,45 @@ pub struct EmbeddingConfig {
 ///
 /// - weight: Matrix of shape `[n_embedding, d_model]` initialized from a normal distribution:
 ///     `N(0, 1)`
+///
+/// # Notes
+///
+/// Contrary to PyTorch, the renormalization with `max_norm` is applied on the looked up vectors
+/// only and doesn't modify the weight in place. The gradient is computed as if the weight had
+/// been renormalized before the lookup.
 #[derive(Module, Debug)]
 pub struct Embedding<B: Backend> {
     weight: Param<Tensor<B, 2>>,
+    #[module(skip)]
+    padding_idx: Option<usize>,
+    #[module(skip)]
+    max_norm: Option<f64>,
 }
 
 impl EmbeddingConfig {
     /// Initialize a new [embedding](Embedding) module.
     pub fn init<B: Backend>(&self) -> Embedding<B> {
-        let weight = self
-            .initializer
-            .init([self.n_embedding, self.d_model])
-            .require_grad();
+        let mut weight = self.initializer.init([self.n_embedding, self.d_model]);
+
+        if let Some(padding_idx) = self.padding_idx {
+            weight = weight.slice_assign(
+                [padding_idx..padding_idx + 1, 0..self.d_model],
+                Tensor::zeros([1, self.d_model]),
+            );
+        }
 
         Embedding {
-            weight: Param::from(weight),
+            weight: Param::from(weight.require_grad()),
+            padding_idx: self.padding_idx,
+            max_norm: self.max_norm,
         }
     }
     /// Initialize a new [embedding](Embedding) module with a [record](EmbeddingRecord).
     pub fn init_with<B: Backend>(&self, record: EmbeddingRecord<B>) -> Embedding<B> {
         Embedding {
             weight: record.weight,
+            padding_idx: self.padding_idx,
+            max_norm: self.max_norm,
         }
     }
 }
@@ -59,7 +83,37 @@ impl<B: Backend> Embedding<B> {
     /// - input: [batch_size, seq_length]
     /// - output: [batch_size, d_model]
     pub fn forward(&self, input: Tensor<B, 2, Int>) -> Tensor<B, 3> {
-        burn_tensor::module::embedding(self.weight.val(), input)
+        let [batch_size, seq_length] = input.dims();
+        let mut output = burn_tensor::module::embedding(self.weight.val(), input.clone());
+
+        if let Some(padding_idx) = self.padding_idx {
+            let padding = input
+                .equal_elem(padding_idx as i64)
+                .float()
+                .reshape([batch_size, seq_length, 1]);
+            let not_padding = padding.clone().neg().add_scalar(1.0);
+
+            // The looked up padding vectors keep their values but don't receive any gradient.
+            output = output
+                .clone()
+                .mul(not_padding)
+                .add(output.detach().mul(padding));
+        }
+
+        if let Some(max_norm) = self.max_norm {
+            let norm = output.clone().powf(2.0).sum_dim(2).sqrt();
+            let scale = norm
+                .add_scalar(1e-7)
+                .powf(-1.0)
+                .mul_scalar(max_norm)
+                .clamp_max(1.0.elem());
+            let renormalized = output.clone().mul(scale);
+
+            // Only the values are renormalized, the gradient flows to the weight unchanged.
+            output = output.clone().add(renormalized.sub(output).detach());
+        }
+
+        output
     }
 }
 
@@ -69,6 +123,9 @@ mod tests {
     use crate::TestBackend;
     use burn_tensor::Data;
 
+    #[cfg(feature = "std")]
+    use crate::TestADBackend;
+
     #[test]
     fn initializer_default() {
         TestBackend::seed(0);
@@ -104,4 +161,75 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::zeros(embed.weight.shape()), 3);
     }
+
+    #[test]
+    fn padding_idx_is_initialized_to_zeros() {
+        TestBackend::seed(0);
+
+        let config = EmbeddingConfig::new(5, 3).with_padding_idx(Some(1));
+        let embed = config.init::<TestBackend>();
+        let output = embed.forward(Tensor::from_data(Data::from([[1, 1]])));
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::zeros([1, 2, 3]), 3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn padding_idx_has_zero_gradient() {
+        TestADBackend::seed(0);
+
+        let config = EmbeddingConfig::new(3, 2)
+            .with_initializer(Initializer::Constant { value: 1.0 })
+            .with_padding_idx(Some(0));
+        let embed = config.init::<TestADBackend>();
+
+        let output = embed.forward(Tensor::from_data(Data::from([[0, 1, 0, 2]])));
+        let grads = output.backward();
+
+        embed
+            .weight
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0, 0.0], [1.0, 1.0], [1.0, 1.0]]), 3);
+    }
+
+    #[test]
+    fn max_norm_renormalizes_vectors() {
+        let config = EmbeddingConfig::new(2, 2).with_max_norm(Some(1.0));
+        let record = EmbeddingRecord {
+            weight: Param::from(Tensor::from_data(Data::from([[3.0, 4.0], [0.3, 0.4]]))),
+        };
+        let embed = config.init_with::<TestBackend>(record);
+
+        let output = embed.forward(Tensor::from_data(Data::from([[0, 1]])));
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[0.6, 0.8], [0.3, 0.4]]]), 3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn max_norm_gradient_flows_to_looked_up_vectors() {
+        let config = EmbeddingConfig::new(3, 2).with_max_norm(Some(1.0));
+        let record = EmbeddingRecord {
+            weight: Param::from(
+                Tensor::from_data(Data::from([[3.0, 4.0], [0.3, 0.4], [1.0, 1.0]])).require_grad(),
+            ),
+        };
+        let embed = config.init_with::<TestADBackend>(record);
+
+        let output = embed.forward(Tensor::from_data(Data::from([[0, 0, 1]])));
+        let grads = output.backward();
+
+        embed
+            .weight
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&Data::from([[2.0, 2.0], [1.0, 1.0], [0.0, 0.0]]), 3);
+    }
 }
@@ -1,4 +1,5 @@
-use burn_tensor::Shape;
+use alloc::vec::Vec;
+use burn_tensor::{Data, Shape};
 use libm::sqrt;
 
 use crate::config::Config;
@@ -65,6 +66,14 @@ pub enum Initializer {
         /// The gain to use in initialization formula
         gain: f64,
     },
+    /// Fills tensor with a (semi) orthogonal matrix as described in [Exact solutions to the
+    /// nonlinear dynamics of learning in deep linear neural networks
+    /// ](https://arxiv.org/abs/1312.6120). The trailing dimensions are flattened, so the
+    /// tensor must have at least 2 dimensions.
+    Orthogonal {
+        /// The gain to use in initialization formula
+        gain: f64,
+    },
 }
 
 impl Initializer {
@@ -114,6 +123,7 @@ impl Initializer {
                 let std = *gain * self.xavier_std(fan_in, fan_out);
                 normal_draw(shape, 0.0, std)
             }
+            Initializer::Orthogonal { gain } => orthogonal_draw(shape, *gain),
         }
     }
 
@@ -152,6 +162,52 @@ fn uniform_draw<B: Backend, const D: usize, S: Into<Shape<D>>>(
     Tensor::<B, D>::random(shape, distribution)
 }
 
+fn orthogonal_draw<B: Backend, const D: usize>(shape: Shape<D>, gain: f64) -> Tensor<B, D> {
+    if D < 2 {
+        panic!("Can't use orthogonal initialization on a tensor with less than 2 dimensions.");
+    }
+
+    let rows = shape.dims[0];
+    let cols = shape.num_elements() / rows;
+    let (m, n) = (usize::max(rows, cols), usize::min(rows, cols));
+
+    let values = normal_draw::<B, 1, _>([m * n], 0.0, 1.0).into_data().value;
+    let mut columns: Vec<Vec<f64>> = (0..n)
+        .map(|j| (0..m).map(|i| values[i * n + j].elem()).collect())
+        .collect();
+
+    // Modified Gram-Schmidt, which gives the Q factor of the QR decomposition with a positive
+    // diagonal for R.
+    for j in 0..n {
+        let (previous, current) = columns.split_at_mut(j);
+        let column = &mut current[0];
+
+        for other in previous.iter() {
+            let dot: f64 = other.iter().zip(column.iter()).map(|(a, b)| a * b).sum();
+            column
+                .iter_mut()
+                .zip(other.iter())
+                .for_each(|(value, other)| *value -= dot * other);
+        }
+
+        let norm = sqrt(column.iter().map(|value| value * value).sum());
+        column.iter_mut().for_each(|value| *value /= norm);
+    }
+
+    let columns = &columns;
+    let values = (0..rows)
+        .flat_map(|i| {
+            (0..cols).map(move |j| match rows >= cols {
+                true => columns[j][i],
+                false => columns[i][j],
+            })
+        })
+        .map(|value| (value * gain).elem::<B::FloatElem>())
+        .collect::<Vec<_>>();
+
+    Tensor::from_data(Data::new(values, shape))
+}
+
 fn normal_draw<B: Backend, const D: usize, S: Into<Shape<D>>>(
     shape: S,
     mean: f64,
@@ -369,4 +425,61 @@ mod tests {
         let (fan_in, fan_out) = (5, 6);
         let _: Tensor<TB, 2> = Initializer::XavierUniform { gain }.init([fan_out, fan_in]);
     }
+
+    fn assert_orthogonal(tensor: Tensor<TB, 2>, gain: f64) {
+        let [rows, cols] = tensor.dims();
+        let (product, size) = match rows <= cols {
+            true => (tensor.clone().matmul(tensor.transpose()), rows),
+            false => (tensor.clone().transpose().matmul(tensor), cols),
+        };
+        let identity = Tensor::<TB, 2>::from_data(Data::new(
+            (0..size * size)
+                .map(|i| {
+                    if i % (size + 1) == 0 {
+                        (gain * gain) as f32
+                    } else {
+                        0.0
+                    }
+                })
+                .collect(),
+            Shape::new([size, size]),
+        ));
+
+        product
+            .into_data()
+            .assert_approx_eq(&identity.into_data(), 3);
+    }
+
+    #[test]
+    fn initializer_orthogonal_init_wide() {
+        TB::seed(0);
+
+        let tensor: Tensor<TB, 2> = Initializer::Orthogonal { gain: 2.0 }.init([4, 6]);
+
+        assert_orthogonal(tensor, 2.0);
+    }
+
+    #[test]
+    fn initializer_orthogonal_init_tall() {
+        TB::seed(0);
+
+        let tensor: Tensor<TB, 2> = Initializer::Orthogonal { gain: 1.0 }.init([6, 4]);
+
+        assert_orthogonal(tensor, 1.0);
+    }
+
+    #[test]
+    fn initializer_orthogonal_init_conv_weight() {
+        TB::seed(0);
+
+        let tensor: Tensor<TB, 4> = Initializer::Orthogonal { gain: 1.0 }.init([3, 2, 2, 2]);
+
+        assert_orthogonal(tensor.reshape([3, 8]), 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn initializer_orthogonal_init_1d() {
+        let _: Tensor<TB, 1> = Initializer::Orthogonal { gain: 1.0 }.init([3]);
+    }
 }
@@ -0,0 +1,207 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{ADModule, Module, ModuleMapper, ModuleVisitor};
+use crate::nn::{Initializer, Linear, LinearConfig, LinearRecord};
+use crate::tensor::backend::{ADBackend, Backend};
+use crate::tensor::Tensor;
+use alloc::sync::Arc;
+use libm::sqrt;
+use spin::Once;
+
+/// Configuration to create a [LazyLinear](LazyLinear) layer.
+#[derive(Config, Debug)]
+pub struct LazyLinearConfig {
+    /// The size of the output features.
+    pub d_output: usize,
+    /// If a bias should be applied during the linear transformation.
+    #[config(default = true)]
+    pub bias: bool,
+    /// The type of function used to initialize neural network parameters
+    #[config(default = "Initializer::KaimingUniform{gain:1.0/sqrt(3.0), fan_out_only:false}")]
+    pub initializer: Initializer,
+}
+
+/// A [linear](Linear) layer where the size of the input features is inferred from the first
+/// forward pass.
+///
+/// The parameters are only created during the first forward pass, or when a record is loaded,
+/// so the layer has no parameter before then. The materialized layer is shared with the clones
+/// of the module, so the parameters created by a forward pass on a clone are also visible from
+/// the original module.
+#[derive(Clone, Debug)]
+pub struct LazyLinear<B: Backend> {
+    config: LazyLinearConfig,
+    linear: Arc<Once<Linear<B>>>,
+}
+
+impl LazyLinearConfig {
+    /// Initialize a new [lazy linear](LazyLinear) module.
+    pub fn init<B: Backend>(&self) -> LazyLinear<B> {
+        LazyLinear {
+            config: self.clone(),
+            linear: Arc::new(Once::new()),
+        }
+    }
+
+    fn linear_config(&self, d_input: usize) -> LinearConfig {
+        LinearConfig::new(d_input, self.d_output)
+            .with_bias(self.bias)
+            .with_initializer(self.initializer.clone())
+    }
+}
+
+impl<B: Backend> LazyLinear<B> {
+    /// Applies the forward pass on the input tensor, creating the parameters on the device of the
+    /// input if it is the first pass.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any, d_input]`
+    /// - output: `[..., any, d_output]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let linear = self.linear.call_once(|| {
+            let d_input = input.dims()[D - 1];
+
+            self.config
+                .linear_config(d_input)
+                .init()
+                .fork(&input.device())
+        });
+
+        linear.forward(input)
+    }
+
+    /// The materialized [linear](Linear) layer, if the parameters have been created.
+    pub fn linear(&self) -> Option<&Linear<B>> {
+        self.linear.get()
+    }
+
+    fn with_linear<O: Backend>(&self, linear: Option<Linear<O>>) -> LazyLinear<O> {
+        let linear = match linear {
+            Some(linear) => Once::initialized(linear),
+            None => Once::new(),
+        };
+
+        LazyLinear {
+            config: self.config.clone(),
+            linear: Arc::new(linear),
+        }
+    }
+}
+
+impl<B: Backend> Module<B> for LazyLinear<B> {
+    type Record = Option<LinearRecord<B>>;
+
+    fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
+        if let Some(linear) = self.linear() {
+            linear.visit(visitor);
+        }
+    }
+
+    fn map<M: ModuleMapper<B>>(self, mapper: &mut M) -> Self {
+        let linear = self.linear().cloned().map(|linear| linear.map(mapper));
+
+        self.with_linear(linear)
+    }
+
+    fn load_record(self, record: Self::Record) -> Self {
+        let linear = match (self.linear().cloned(), record) {
+            (Some(linear), Some(record)) => Some(linear.load_record(record)),
+            (None, Some(record)) => {
+                let [d_input, _] = record.weight.dims();
+                Some(self.config.linear_config(d_input).init_with(record))
+            }
+            (linear, None) => linear,
+        };
+
+        self.with_linear(linear)
+    }
+
+    fn into_record(self) -> Self::Record {
+        self.linear().cloned().map(Module::into_record)
+    }
+}
+
+impl<B: ADBackend> ADModule<B> for LazyLinear<B> {
+    type InnerModule = LazyLinear<B::InnerBackend>;
+
+    fn valid(&self) -> Self::InnerModule {
+        self.with_linear(self.linear().map(|linear| linear.valid()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optim::{GradientsParams, Optimizer, SgdConfig};
+    use crate::{TestADBackend, TestBackend};
+    use burn_tensor::{Data, Shape};
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        linear: LazyLinear<B>,
+    }
+
+    impl<B: Backend> Model<B> {
+        fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2> {
+            self.linear.forward(input)
+        }
+    }
+
+    #[test]
+    fn lazy_linear_should_infer_input_size() {
+        let linear = LazyLinearConfig::new(3)
+            .with_initializer(Initializer::Constant { value: 2.0 })
+            .init::<TestBackend>();
+        assert_eq!(linear.num_params(), 0);
+
+        let output = linear.forward(Tensor::<TestBackend, 3>::ones(Shape::new([2, 4, 5])));
+
+        assert_eq!(output.shape(), Shape::new([2, 4, 3]));
+        assert_eq!(linear.num_params(), 5 * 3 + 3);
+        output
+            .slice([0..1, 0..1])
+            .into_data()
+            .assert_approx_eq(&Data::from([[[12.0, 12.0, 12.0]]]), 3);
+    }
+
+    #[test]
+    fn lazy_linear_should_materialize_from_record() {
+        let linear = LazyLinearConfig::new(3).init::<TestBackend>();
+        let input = Tensor::<TestBackend, 2>::ones(Shape::new([2, 4]));
+        let expected = linear.forward(input.clone());
+
+        let linear_loaded = LazyLinearConfig::new(3)
+            .init::<TestBackend>()
+            .load_record(linear.into_record());
+
+        assert_eq!(linear_loaded.num_params(), 4 * 3 + 3);
+        linear_loaded
+            .forward(input)
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn lazy_linear_should_be_materialized_by_a_forward_pass_on_a_clone() {
+        let model = Model::<TestADBackend> {
+            linear: LazyLinearConfig::new(2).init(),
+        };
+        let mut optim = SgdConfig::new().init();
+        let input = Tensor::<TestADBackend, 2>::ones(Shape::new([3, 4]));
+
+        let output = model.clone().forward(input.clone());
+        assert_eq!(model.num_params(), 4 * 2 + 2);
+
+        let grads = GradientsParams::from_grads(output.sum().backward(), &model);
+        let model_updated = optim.step(0.1, model.clone(), grads);
+
+        assert_eq!(model_updated.num_params(), 4 * 2 + 2);
+        let weight = model.linear.linear().unwrap().weight.val();
+        let weight_updated = model_updated.linear.linear().unwrap().weight.val();
+        weight_updated
+            .into_data()
+            .assert_approx_eq(&(weight - 0.3).into_data(), 3);
+    }
+}
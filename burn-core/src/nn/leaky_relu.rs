@@ -0,0 +1,62 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// Configuration to create a [LeakyReLU](LeakyReLU) layer.
+#[derive(Config, Debug)]
+pub struct LeakyReLUConfig {
+    /// The slope applied to the negative values. Default: 0.01
+    #[config(default = 0.01)]
+    pub negative_slope: f64,
+}
+
+/// Applies the leaky rectified linear unit function element-wise:
+///
+/// `y = max(0, x) + negative_slope * min(0, x)`
+#[derive(Module, Clone, Debug)]
+pub struct LeakyReLU {
+    negative_slope: f64,
+}
+
+impl LeakyReLUConfig {
+    /// Initialize a new [leaky relu](LeakyReLU) module.
+    pub fn init(&self) -> LeakyReLU {
+        LeakyReLU {
+            negative_slope: self.negative_slope,
+        }
+    }
+}
+
+impl LeakyReLU {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any]`
+    /// - output: `[..., any]`
+    pub fn forward<B: Backend, const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        crate::tensor::activation::leaky_relu(input, self.negative_slope)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn leaky_relu_forward() {
+        let module = LeakyReLUConfig::new().with_negative_slope(0.2).init();
+        let input = Tensor::<TestBackend, 2>::from_floats([[-2.0, -0.5], [0.0, 3.0]]);
+
+        let output = module.forward(input);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[-0.4, -0.1], [0.0, 3.0]]), 3);
+    }
+}
@@ -80,7 +80,16 @@ impl<B: Backend> Linear<B> {
     /// - input: `[..., any, d_input]`
     /// - output: `[..., any, d_output]`
     pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
-        let output = input.matmul(self.weight.val().unsqueeze());
+        self.forward_with_weight(input, self.weight.val())
+    }
+
+    /// Applies the forward pass using the given weight instead of the module's own weight.
+    pub(crate) fn forward_with_weight<const D: usize>(
+        &self,
+        input: Tensor<B, D>,
+        weight: Tensor<B, 2>,
+    ) -> Tensor<B, D> {
+        let output = input.matmul(weight.unsqueeze());
 
         match &self.bias {
             Some(bias) => output + bias.val().unsqueeze(),
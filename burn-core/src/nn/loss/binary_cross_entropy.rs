@@ -0,0 +1,259 @@
+use crate::nn::loss::reduction::Reduction;
+use core::marker::PhantomData;
+
+use burn_tensor::{backend::Backend, ElementConversion, Tensor};
+
+/// Calculate the binary cross entropy loss from the input probabilities and the targets.
+///
+/// `loss = -w * (y * log(p) + (1 - y) * log(1 - p))`
+///
+/// The log terms are clamped to be greater or equal to `-100` so the loss stays finite when a
+/// probability is exactly 0 or 1.
+#[derive(Clone, Debug)]
+pub struct BinaryCrossEntropyLoss<B: Backend> {
+    weights: Option<Tensor<B, 1>>,
+    backend: PhantomData<B>,
+}
+
+/// Calculate the binary cross entropy loss from the input logits and the targets.
+///
+/// This combines a sigmoid with the [binary cross entropy loss](BinaryCrossEntropyLoss) using
+/// the log-sum-exp trick, which is more numerically stable than applying both separately:
+///
+/// `loss = -w * (pos_weight * y * log(sigmoid(x)) + (1 - y) * log(1 - sigmoid(x)))`
+#[derive(Clone, Debug)]
+pub struct BinaryCrossEntropyWithLogitsLoss<B: Backend> {
+    weights: Option<Tensor<B, 1>>,
+    pos_weight: Option<Tensor<B, 1>>,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> BinaryCrossEntropyLoss<B> {
+    /// Create the criterion.
+    ///
+    /// The optional `weights` rescale the loss of each target and must have the shape
+    /// `[num_targets]`.
+    pub fn new(weights: Option<Tensor<B, 1>>) -> Self {
+        Self {
+            weights,
+            backend: PhantomData,
+        }
+    }
+
+    /// Compute the criterion on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - probs: `[..., num_targets]`
+    /// - targets: `[..., num_targets]`
+    pub fn forward<const D: usize>(
+        &self,
+        probs: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        reduce(self.forward_no_reduction(probs, targets), reduction)
+    }
+
+    /// Compute the criterion on the input tensor without reducing.
+    pub fn forward_no_reduction<const D: usize>(
+        &self,
+        probs: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        let log_probs = probs.clone().log().clamp_min((-100.0).elem());
+        let log_probs_neg = probs.neg().log1p().clamp_min((-100.0).elem());
+
+        let loss = targets
+            .clone()
+            .mul(log_probs)
+            .add(targets.neg().add_scalar(1.0).mul(log_probs_neg))
+            .neg();
+
+        apply_weights(loss, &self.weights)
+    }
+}
+
+impl<B: Backend> BinaryCrossEntropyWithLogitsLoss<B> {
+    /// Create the criterion.
+    ///
+    /// The optional `weights` rescale the loss of each target, while the optional `pos_weight`
+    /// rescale only the loss of the positive examples of each target. Both must have the shape
+    /// `[num_targets]`.
+    pub fn new(weights: Option<Tensor<B, 1>>, pos_weight: Option<Tensor<B, 1>>) -> Self {
+        Self {
+            weights,
+            pos_weight,
+            backend: PhantomData,
+        }
+    }
+
+    /// Compute the criterion on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: `[..., num_targets]`
+    /// - targets: `[..., num_targets]`
+    pub fn forward<const D: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        reduce(self.forward_no_reduction(logits, targets), reduction)
+    }
+
+    /// Compute the criterion on the input tensor without reducing.
+    pub fn forward_no_reduction<const D: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        // -log(sigmoid(x)) = log(1 + exp(-|x|)) + max(-x, 0)
+        let log_sigmoid_neg = logits
+            .clone()
+            .abs()
+            .neg()
+            .exp()
+            .log1p()
+            .add(logits.clone().neg().clamp_min(0.0.elem()));
+
+        let log_weight = match &self.pos_weight {
+            Some(pos_weight) => {
+                let shape = last_dim_shape::<D>(pos_weight.dims()[0]);
+                let pos_weight = pos_weight.clone().sub_scalar(1.0).reshape(shape);
+
+                targets.clone().mul(pos_weight).add_scalar(1.0)
+            }
+            None => targets.ones_like(),
+        };
+
+        let loss = targets
+            .neg()
+            .add_scalar(1.0)
+            .mul(logits)
+            .add(log_weight.mul(log_sigmoid_neg));
+
+        apply_weights(loss, &self.weights)
+    }
+}
+
+fn reduce<B: Backend, const D: usize>(tensor: Tensor<B, D>, reduction: Reduction) -> Tensor<B, 1> {
+    match reduction {
+        Reduction::Mean | Reduction::Auto => tensor.mean(),
+        Reduction::Sum => tensor.sum(),
+    }
+}
+
+fn apply_weights<B: Backend, const D: usize>(
+    loss: Tensor<B, D>,
+    weights: &Option<Tensor<B, 1>>,
+) -> Tensor<B, D> {
+    match weights {
+        Some(weights) => {
+            let shape = last_dim_shape::<D>(weights.dims()[0]);
+            loss.mul(weights.clone().reshape(shape))
+        }
+        None => loss,
+    }
+}
+
+fn last_dim_shape<const D: usize>(size: usize) -> [usize; D] {
+    let mut shape = [1; D];
+    shape[D - 1] = size;
+    shape
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_binary_cross_entropy_loss() {
+        let probs = Tensor::<TestBackend, 2>::from_data(Data::from([[0.9, 0.2], [0.6, 0.3]]));
+        let targets = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 0.0], [0.0, 1.0]]));
+
+        let bce = BinaryCrossEntropyLoss::new(None);
+        let loss_no_reduction = bce.forward_no_reduction(probs.clone(), targets.clone());
+        let loss = bce.forward(probs, targets, Reduction::Auto);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.1054, 0.2231], [0.9163, 1.2040]]), 3);
+        loss.into_data().assert_approx_eq(&Data::from([0.6122]), 3);
+    }
+
+    #[test]
+    fn test_binary_cross_entropy_loss_with_weights() {
+        let probs = Tensor::<TestBackend, 2>::from_data(Data::from([[0.9, 0.2], [0.6, 0.3]]));
+        let targets = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 0.0], [0.0, 1.0]]));
+        let weights = Tensor::from_data(Data::from([1.0, 3.0]));
+
+        let bce = BinaryCrossEntropyLoss::new(Some(weights));
+        let loss = bce.forward(probs, targets, Reduction::Mean);
+
+        loss.into_data().assert_approx_eq(&Data::from([1.3258]), 3);
+    }
+
+    #[test]
+    fn test_binary_cross_entropy_loss_saturated_probs() {
+        let probs = Tensor::<TestBackend, 1>::from_data(Data::from([0.0, 1.0]));
+        let targets = Tensor::<TestBackend, 1>::from_data(Data::from([1.0, 0.0]));
+
+        let bce = BinaryCrossEntropyLoss::new(None);
+        let loss = bce.forward_no_reduction(probs, targets);
+
+        loss.into_data()
+            .assert_approx_eq(&Data::from([100.0, 100.0]), 3);
+    }
+
+    #[test]
+    fn test_binary_cross_entropy_with_logits_loss() {
+        let logits = Tensor::<TestBackend, 2>::from_data(Data::from([[0.8, -1.2], [2.5, 0.3]]));
+        let targets = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 0.0], [0.0, 1.0]]));
+
+        let bce = BinaryCrossEntropyWithLogitsLoss::new(None, None);
+        let loss_no_reduction = bce.forward_no_reduction(logits.clone(), targets.clone());
+        let loss = bce.forward(logits.clone(), targets.clone(), Reduction::Auto);
+        let loss_sum = bce.forward(logits, targets, Reduction::Sum);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.3711, 0.2633], [2.5789, 0.5544]]), 3);
+        loss.into_data().assert_approx_eq(&Data::from([0.9419]), 3);
+        loss_sum
+            .into_data()
+            .assert_approx_eq(&Data::from([3.7676]), 3);
+    }
+
+    #[test]
+    fn test_binary_cross_entropy_with_logits_loss_weights_and_pos_weight() {
+        let logits = Tensor::<TestBackend, 2>::from_data(Data::from([[0.8, -1.2], [2.5, 0.3]]));
+        let targets = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 0.0], [0.0, 1.0]]));
+        let weights = Tensor::from_data(Data::from([1.0, 3.0]));
+        let pos_weight = Tensor::from_data(Data::from([2.0, 0.5]));
+
+        let bce = BinaryCrossEntropyWithLogitsLoss::new(Some(weights), Some(pos_weight));
+        let loss_no_reduction = bce.forward_no_reduction(logits.clone(), targets.clone());
+        let loss = bce.forward(logits, targets, Reduction::Mean);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.7422, 0.7898], [2.5789, 0.8315]]), 3);
+        loss.into_data().assert_approx_eq(&Data::from([1.2356]), 3);
+    }
+
+    #[test]
+    fn test_binary_cross_entropy_with_logits_loss_large_logits() {
+        let logits = Tensor::<TestBackend, 1>::from_data(Data::from([100.0, -100.0]));
+        let targets = Tensor::<TestBackend, 1>::from_data(Data::from([0.0, 1.0]));
+
+        let bce = BinaryCrossEntropyWithLogitsLoss::new(None, None);
+        let loss = bce.forward_no_reduction(logits, targets);
+
+        loss.into_data()
+            .assert_approx_eq(&Data::from([100.0, 100.0]), 3);
+    }
+}
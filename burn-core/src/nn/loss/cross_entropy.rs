@@ -3,9 +3,16 @@ use core::marker::PhantomData;
 use burn_tensor::{activation, backend::Backend, Bool, Int, Tensor};
 
 /// Calculate the cross entropy loss from the input logits and the targets.
+///
+/// The targets equal to the padding index are ignored and the mean is taken over the remaining
+/// targets. Optionally, the loss of each class can be rescaled with
+/// [weights](CrossEntropyLoss::with_weights) and the targets can be smoothed with
+/// [label smoothing](CrossEntropyLoss::with_smoothing).
 #[derive(Clone, Debug)]
 pub struct CrossEntropyLoss<B: Backend> {
     pad_index: Option<usize>,
+    weights: Option<Tensor<B, 1>>,
+    smoothing: f64,
     backend: PhantomData<B>,
 }
 
@@ -14,10 +21,35 @@ impl<B: Backend> CrossEntropyLoss<B> {
     pub fn new(pad_index: Option<usize>) -> Self {
         Self {
             pad_index,
+            weights: None,
+            smoothing: 0.0,
             backend: PhantomData,
         }
     }
 
+    /// Rescale the loss of each class with the given weights of shape `[num_targets]`.
+    ///
+    /// When weights are used, the mean loss is normalized by the sum of the weights of the
+    /// targets instead of their number.
+    pub fn with_weights(mut self, weights: Tensor<B, 1>) -> Self {
+        self.weights = Some(weights);
+        self
+    }
+
+    /// Apply label smoothing as described in the paper
+    /// [Rethinking the Inception Architecture for Computer Vision](https://arxiv.org/abs/1512.00567).
+    ///
+    /// The targets become a mixture of the one-hot targets and the uniform distribution:
+    /// `(1 - smoothing) * one_hot + smoothing / num_targets`.
+    pub fn with_smoothing(mut self, smoothing: f64) -> Self {
+        if !(0.0..=1.0).contains(&smoothing) {
+            panic!("The label smoothing must be between 0 and 1, got {smoothing}");
+        }
+
+        self.smoothing = smoothing;
+        self
+    }
+
     /// Compute the criterion on the input tensor.
     ///
     /// # Shapes
@@ -25,14 +57,80 @@ impl<B: Backend> CrossEntropyLoss<B> {
     /// - logits: `[batch_size, num_targets]`
     /// - targets: `[batch_size]`
     pub fn forward(&self, logits: Tensor<B, 2>, targets: Tensor<B, 1, Int>) -> Tensor<B, 1> {
-        let [batch_size] = targets.dims();
+        let [batch_size, num_targets] = logits.dims();
 
         let mask = self.padding_mask(&targets);
-        let tensor = activation::log_softmax(logits, 1);
-        let tensor = tensor.gather(1, targets.reshape([batch_size, 1]));
-        let tensor = self.apply_mask(tensor.reshape([batch_size]), mask);
+        let targets = targets.reshape([batch_size, 1]);
+        let log_probs = activation::log_softmax(logits, 1);
+        let mut tensor = log_probs
+            .clone()
+            .gather(1, targets.clone())
+            .reshape([batch_size]);
+
+        let weights_targets = self.weights.as_ref().map(|weights| {
+            weights
+                .clone()
+                .reshape([1, num_targets])
+                .repeat(0, batch_size)
+                .gather(1, targets)
+                .reshape([batch_size])
+        });
+
+        if let Some(weights_targets) = &weights_targets {
+            tensor = tensor.mul(weights_targets.clone());
+        }
+
+        if self.smoothing > 0.0 {
+            let log_probs = match &self.weights {
+                Some(weights) => log_probs.mul(weights.clone().reshape([1, num_targets])),
+                None => log_probs,
+            };
+            let smoothed = log_probs
+                .sum_dim(1)
+                .reshape([batch_size])
+                .div_scalar(num_targets as f64);
+
+            tensor = tensor
+                .mul_scalar(1.0 - self.smoothing)
+                .add(smoothed.mul_scalar(self.smoothing));
+        }
+
+        // The mean is taken over the targets that aren't ignored, weighted by their class weight.
+        let weights_targets = weights_targets.unwrap_or_else(|| tensor.ones_like());
+        let tensor = self.apply_mask(tensor, mask.clone());
+        let weights_targets = self.apply_mask(weights_targets, mask);
+
+        tensor.sum().div(weights_targets.sum()).neg()
+    }
 
-        tensor.mean().neg()
+    /// Compute the criterion on the input tensor with soft targets, where each target is a
+    /// probability distribution over the classes, as used by mixup or knowledge distillation.
+    ///
+    /// The padding index is not used, while the weights and the label smoothing are applied on
+    /// the target probabilities.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: `[batch_size, num_targets]`
+    /// - target_probs: `[batch_size, num_targets]`
+    pub fn forward_probs(&self, logits: Tensor<B, 2>, target_probs: Tensor<B, 2>) -> Tensor<B, 1> {
+        let [_, num_targets] = logits.dims();
+
+        let mut target_probs = target_probs;
+        if self.smoothing > 0.0 {
+            target_probs = target_probs
+                .mul_scalar(1.0 - self.smoothing)
+                .add_scalar(self.smoothing / num_targets as f64);
+        }
+        if let Some(weights) = &self.weights {
+            target_probs = target_probs.mul(weights.clone().reshape([1, num_targets]));
+        }
+
+        activation::log_softmax(logits, 1)
+            .mul(target_probs)
+            .sum_dim(1)
+            .mean()
+            .neg()
     }
 
     fn padding_mask(&self, targets: &Tensor<B, 1, Int>) -> Option<Tensor<B, 1, Bool>> {
@@ -98,12 +196,110 @@ mod tests {
             [0.0, 0.0, 1.0, 0.0, 0.0],
             [1.0, 0.0, 0.0, 0.0, 0.0],
             [0.0, 0.0, 0.0, 0.0, 1.0],
-            [0.0, 0.0, 0.0, 0.0, 0.0],
         ]));
 
         let loss_1 = CrossEntropyLoss::new(Some(pad_index)).forward(logits.clone(), targets);
-        let loss_2 = cross_entropy_with_logits(logits, targets_logits);
+        let loss_2 =
+            cross_entropy_with_logits(logits.slice([0..3, 0..num_targets]), targets_logits);
 
         loss_1.into_data().assert_approx_eq(&loss_2.into_data(), 3);
     }
+
+    #[test]
+    fn test_cross_entropy_loss_with_smoothing() {
+        let loss = CrossEntropyLoss::new(None)
+            .with_smoothing(0.1)
+            .forward(logits(), targets());
+
+        loss.into_data().assert_approx_eq(&Data::from([1.2854]), 3);
+    }
+
+    #[test]
+    fn test_cross_entropy_loss_with_weights() {
+        let weights = Tensor::from_floats([0.5, 2.0, 1.0]);
+
+        let loss = CrossEntropyLoss::new(None)
+            .with_weights(weights)
+            .forward(logits(), targets());
+
+        loss.into_data().assert_approx_eq(&Data::from([1.4854]), 3);
+    }
+
+    #[test]
+    fn test_cross_entropy_loss_with_smoothing_and_pad_token() {
+        let loss = CrossEntropyLoss::new(Some(1))
+            .with_smoothing(0.1)
+            .forward(logits(), targets());
+
+        loss.into_data().assert_approx_eq(&Data::from([0.7787]), 3);
+    }
+
+    #[test]
+    fn test_cross_entropy_loss_with_pad_token_is_mean_over_non_padded_targets() {
+        let loss = CrossEntropyLoss::new(Some(1)).forward(logits(), targets());
+
+        loss.into_data().assert_approx_eq(&Data::from([0.7254]), 3);
+    }
+
+    #[test]
+    fn test_cross_entropy_loss_with_weights_smoothing_and_pad_token() {
+        let weights = Tensor::from_floats([0.5, 2.0, 1.0]);
+
+        let loss = CrossEntropyLoss::new(Some(1))
+            .with_weights(weights)
+            .with_smoothing(0.1)
+            .forward(logits(), targets());
+
+        loss.into_data().assert_approx_eq(&Data::from([0.7526]), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_cross_entropy_loss_invalid_smoothing() {
+        CrossEntropyLoss::<TestBackend>::new(None).with_smoothing(1.5);
+    }
+
+    #[test]
+    fn test_cross_entropy_loss_with_soft_targets() {
+        let target_probs = Tensor::from_floats([
+            [0.0, 1.0, 0.0],
+            [0.0, 0.0, 1.0],
+            [1.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0],
+        ]);
+        let loss = CrossEntropyLoss::new(None);
+
+        let loss_1 = loss.forward_probs(logits(), target_probs);
+        let loss_2 = loss.forward(logits(), targets());
+
+        loss_1.into_data().assert_approx_eq(&loss_2.into_data(), 3);
+    }
+
+    #[test]
+    fn test_cross_entropy_loss_with_soft_targets_and_smoothing() {
+        let target_probs = Tensor::from_floats([
+            [0.0, 1.0, 0.0],
+            [0.0, 0.0, 1.0],
+            [1.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0],
+        ]);
+        let loss = CrossEntropyLoss::new(None).with_smoothing(0.1);
+
+        let loss = loss.forward_probs(logits(), target_probs);
+
+        loss.into_data().assert_approx_eq(&Data::from([1.2854]), 3);
+    }
+
+    fn logits() -> Tensor<TestBackend, 2> {
+        Tensor::from_floats([
+            [1.0, 2.0, 0.5],
+            [0.2, -0.4, 1.5],
+            [0.3, 0.3, 0.3],
+            [2.0, -1.0, 0.0],
+        ])
+    }
+
+    fn targets() -> Tensor<TestBackend, 1, Int> {
+        Tensor::from_data(Data::from([1, 2, 0, 1]))
+    }
 }
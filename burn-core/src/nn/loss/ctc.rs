@@ -0,0 +1,442 @@
+use crate::nn::loss::reduction::Reduction;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use burn_tensor::{
+    backend::Backend, f16, Bool, Data, ElementConversion, ElementPrecision, Int, Precision, Shape,
+    Tensor,
+};
+
+/// Calculate the connectionist temporal classification loss from the input log-probabilities and
+/// the targets, as described in the paper
+/// [Connectionist Temporal Classification](https://www.cs.toronto.edu/~graves/icml_2006.pdf).
+///
+/// The loss is the negative log-likelihood of all the alignments of the input sequence that
+/// collapse into the target sequence once the repeated labels are merged and the blanks are
+/// removed. It is computed with the forward algorithm in log space.
+#[derive(Clone, Debug)]
+pub struct CTCLoss<B: Backend> {
+    blank: usize,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> CTCLoss<B> {
+    /// Create the criterion with the given blank class index.
+    pub fn new(blank: usize) -> Self {
+        Self {
+            blank,
+            backend: PhantomData,
+        }
+    }
+
+    /// Compute the criterion on the input tensor.
+    ///
+    /// With the mean reduction, the loss of each sequence is divided by its target length before
+    /// averaging over the batch.
+    ///
+    /// # Shapes
+    ///
+    /// - log_probs: `[batch_size, seq_length, num_classes]`
+    /// - targets: `[batch_size, max_target_length]`
+    /// - input_lengths: `[batch_size]`
+    /// - target_lengths: `[batch_size]`
+    pub fn forward(
+        &self,
+        log_probs: Tensor<B, 3>,
+        targets: Tensor<B, 2, Int>,
+        input_lengths: Tensor<B, 1, Int>,
+        target_lengths: Tensor<B, 1, Int>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let device = log_probs.device();
+        let lengths = target_lengths
+            .to_data()
+            .value
+            .iter()
+            .map(|length| length.elem::<f32>().max(1.0))
+            .collect::<Vec<_>>();
+        let tensor = self.forward_no_reduction(log_probs, targets, input_lengths, target_lengths);
+
+        match reduction {
+            Reduction::Mean | Reduction::Auto => {
+                let shape = Shape::new([lengths.len()]);
+                let lengths =
+                    Tensor::from_data_device(Data::new(lengths, shape).convert(), &device);
+
+                tensor.div(lengths).mean()
+            }
+            Reduction::Sum => tensor.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor without reducing.
+    ///
+    /// The loss of a sequence whose target can't be aligned with its input is a very large
+    /// value instead of infinity.
+    ///
+    /// # Shapes
+    ///
+    /// - log_probs: `[batch_size, seq_length, num_classes]`
+    /// - targets: `[batch_size, max_target_length]`
+    /// - input_lengths: `[batch_size]`
+    /// - target_lengths: `[batch_size]`
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction(
+        &self,
+        log_probs: Tensor<B, 3>,
+        targets: Tensor<B, 2, Int>,
+        input_lengths: Tensor<B, 1, Int>,
+        target_lengths: Tensor<B, 1, Int>,
+    ) -> Tensor<B, 1> {
+        let [batch_size, seq_length, num_classes] = log_probs.dims();
+        let [_, max_target_length] = targets.dims();
+        let device = log_probs.device();
+        let num_states = 2 * max_target_length + 1;
+
+        if self.blank >= num_classes {
+            panic!(
+                "The blank index should be lower than the number of classes, got {} for {} classes",
+                self.blank, num_classes
+            );
+        }
+
+        let targets = into_usize_vec(targets.into_data().value);
+        let target_lengths = into_usize_vec(target_lengths.into_data().value);
+        let input_lengths_data = into_usize_vec(input_lengths.to_data().value);
+
+        // The extended labels interleave the target labels with blanks: [b, l_0, b, l_1, ..., b].
+        let mut labels = vec![self.blank as i64; batch_size * num_states];
+        // The transition from the state `s - 2` is only allowed between two different labels.
+        let mut skips = vec![0; batch_size * num_states];
+
+        for b in 0..batch_size {
+            let target_length = target_lengths[b];
+
+            if target_length > max_target_length {
+                panic!("The target length {target_length} is greater than the targets size {max_target_length}");
+            }
+            if input_lengths_data[b] > seq_length {
+                panic!(
+                    "The input length {} is greater than the sequence length {seq_length}",
+                    input_lengths_data[b]
+                );
+            }
+
+            let target = &targets[b * max_target_length..b * max_target_length + target_length];
+            for (i, label) in target.iter().enumerate() {
+                labels[b * num_states + 2 * i + 1] = *label as i64;
+
+                if i > 0 && target[i - 1] != *label {
+                    skips[b * num_states + 2 * i + 1] = 1;
+                }
+            }
+        }
+
+        let labels = Tensor::<B, 2, Int>::from_data_device(
+            Data::new(labels, Shape::new([batch_size, num_states])).convert(),
+            &device,
+        );
+        let no_skips = Tensor::<B, 2, Int>::from_data_device(
+            Data::new(skips, Shape::new([batch_size, num_states])).convert(),
+            &device,
+        )
+        .equal_elem(0);
+        let input_lengths = input_lengths.reshape([batch_size, 1]).repeat(1, num_states);
+
+        // Log-probabilities of the extended labels at each time step.
+        let emissions = log_probs.gather(
+            2,
+            labels
+                .reshape([batch_size, 1, num_states])
+                .repeat(1, seq_length),
+        );
+        let emission = |t: usize| {
+            emissions
+                .clone()
+                .slice([0..batch_size, t..t + 1])
+                .reshape([batch_size, num_states])
+        };
+
+        // Only the first blank and the first label can start an alignment.
+        let min = neg_inf::<B>();
+        let mut alpha = emission(0).clamp_min(min);
+        if num_states > 2 {
+            alpha = alpha.slice_assign(
+                [0..batch_size, 2..num_states],
+                Tensor::full_device([batch_size, num_states - 2], min, &device),
+            );
+        }
+
+        for t in 1..seq_length {
+            let from_prev = shift_right(alpha.clone(), 1);
+            let from_skip = shift_right(alpha.clone(), 2).mask_fill(no_skips.clone(), min);
+            let alpha_next = log_sum_exp(vec![alpha.clone(), from_prev, from_skip]) + emission(t);
+            // Impossible states stay at the sentinel instead of overflowing to negative infinity.
+            let alpha_next = alpha_next.clamp_min(min);
+
+            // Sequences shorter than the current time step keep their last state.
+            alpha = alpha.mask_where(input_lengths.clone().greater_elem(t as i64), alpha_next);
+        }
+
+        // Valid alignments end either on the trailing blank or on the last label.
+        let mut ends = Vec::with_capacity(batch_size * 2);
+        let mut ends_invalid = Vec::with_capacity(batch_size * 2);
+        for target_length in target_lengths {
+            ends.push((2 * target_length) as i64);
+            ends.push((2 * target_length).saturating_sub(1) as i64);
+            ends_invalid.push(false);
+            ends_invalid.push(target_length == 0);
+        }
+        let ends = Tensor::<B, 2, Int>::from_data_device(
+            Data::new(ends, Shape::new([batch_size, 2])).convert(),
+            &device,
+        );
+        let ends_invalid = Tensor::<B, 2, Bool>::from_data_device(
+            Data::new(ends_invalid, Shape::new([batch_size, 2])),
+            &device,
+        );
+
+        let alpha_end = alpha.gather(1, ends).mask_fill(ends_invalid, min);
+        let alpha_blank = alpha_end.clone().slice([0..batch_size, 0..1]);
+        let alpha_label = alpha_end.slice([0..batch_size, 1..2]);
+
+        log_sum_exp(vec![alpha_blank, alpha_label])
+            .reshape([batch_size])
+            .neg()
+    }
+}
+
+fn into_usize_vec<E: ElementConversion + Copy>(values: Vec<E>) -> Vec<usize> {
+    values
+        .into_iter()
+        .map(|value| value.elem::<i64>() as usize)
+        .collect()
+}
+
+/// Log-probability used for the impossible states of the dynamic programming, the lowest finite
+/// value of the float element type.
+///
+/// Negative infinity can't be used since the log-sum-exp of two impossible states would produce
+/// NaN values, and a fixed value such as `-1e30` overflows with half precision elements.
+fn neg_inf<B: Backend>() -> B::FloatElem {
+    match B::FloatElem::precision() {
+        Precision::Double => f64::MIN.elem(),
+        Precision::Full => f32::MIN.elem(),
+        Precision::Half | Precision::Other => f16::MIN.elem(),
+    }
+}
+
+/// Shift the states to the right, filling the first states with impossible values.
+fn shift_right<B: Backend>(tensor: Tensor<B, 2>, shift: usize) -> Tensor<B, 2> {
+    let [batch_size, num_states] = tensor.dims();
+    let device = tensor.device();
+
+    let filled = Tensor::full_device([batch_size, num_states], neg_inf::<B>(), &device);
+
+    if shift >= num_states {
+        return filled;
+    }
+
+    filled.slice_assign(
+        [0..batch_size, shift..num_states],
+        tensor.slice([0..batch_size, 0..num_states - shift]),
+    )
+}
+
+/// Computes `log(sum_i(exp(x_i)))` element-wise over the given tensors in a stable way.
+fn log_sum_exp<B: Backend>(tensors: Vec<Tensor<B, 2>>) -> Tensor<B, 2> {
+    let max = tensors
+        .iter()
+        .map(|tensor| tensor.clone().detach())
+        .reduce(|max, tensor| max.clone().mask_where(tensor.clone().greater(max), tensor))
+        .unwrap();
+
+    tensors
+        .into_iter()
+        .map(|tensor| tensor.sub(max.clone()).exp())
+        .reduce(|sum, tensor| sum.add(tensor))
+        .unwrap()
+        .log()
+        .add(max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_tensor::activation::log_softmax;
+
+    #[cfg(feature = "std")]
+    use crate::{TestADBackend, TestBackend};
+
+    #[cfg(not(feature = "std"))]
+    use crate::TestBackend;
+
+    #[test]
+    fn test_ctc_loss() {
+        let ctc = CTCLoss::new(0);
+
+        let loss_no_reduction = ctc.forward_no_reduction(
+            log_probs::<TestBackend>(),
+            targets(),
+            input_lengths(),
+            target_lengths(),
+        );
+        let loss = ctc.forward(
+            log_probs::<TestBackend>(),
+            targets(),
+            input_lengths(),
+            target_lengths(),
+            Reduction::Auto,
+        );
+        let loss_sum = ctc.forward(
+            log_probs::<TestBackend>(),
+            targets(),
+            input_lengths(),
+            target_lengths(),
+            Reduction::Sum,
+        );
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([1.3999, 1.0215]), 3);
+        loss.into_data().assert_approx_eq(&Data::from([0.8607]), 3);
+        loss_sum
+            .into_data()
+            .assert_approx_eq(&Data::from([2.4213]), 3);
+    }
+
+    #[test]
+    fn test_ctc_loss_repeated_labels() {
+        let ctc = CTCLoss::new(0);
+        let log_probs = log_probs::<TestBackend>().slice([0..1, 0..4]);
+
+        let loss = ctc.forward_no_reduction(
+            log_probs,
+            Tensor::from_ints([[1, 1]]),
+            Tensor::from_ints([4]),
+            Tensor::from_ints([2]),
+        );
+
+        loss.into_data().assert_approx_eq(&Data::from([2.783]), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ctc_loss_invalid_blank() {
+        CTCLoss::new(3).forward_no_reduction(
+            log_probs::<TestBackend>(),
+            targets(),
+            input_lengths(),
+            target_lengths(),
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_ctc_loss_grads() {
+        let log_probs = log_probs::<TestADBackend>().detach().require_grad();
+        let ctc = CTCLoss::new(0);
+
+        let loss = ctc.forward(
+            log_probs.clone(),
+            targets(),
+            input_lengths(),
+            target_lengths(),
+            Reduction::Sum,
+        );
+        let grads = loss.backward();
+
+        log_probs
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(
+                &Data::from([
+                    [
+                        [-0.1564, -0.8436, 0.0],
+                        [-0.3712, -0.3155, -0.3133],
+                        [-0.0788, -0.0864, -0.8348],
+                        [-0.5336, 0.0, -0.4664],
+                    ],
+                    [
+                        [-0.7587, -0.2413, 0.0],
+                        [-0.1713, -0.8287, 0.0],
+                        [-0.5256, -0.4744, 0.0],
+                        [0.0, 0.0, 0.0],
+                    ],
+                ]),
+                3,
+            );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_ctc_loss_zero_probabilities_should_not_produce_nan() {
+        // The label has a probability of zero, so only the blank target is possible.
+        let log_probs = Tensor::<TestADBackend, 3>::from_floats([[
+            [0.0, f32::NEG_INFINITY],
+            [0.0, f32::NEG_INFINITY],
+        ]])
+        .require_grad();
+        let ctc = CTCLoss::new(0);
+
+        let loss = ctc.forward_no_reduction(
+            log_probs.clone(),
+            Tensor::from_ints([[1]]),
+            Tensor::from_ints([2]),
+            Tensor::from_ints([1]),
+        );
+        let grads = loss.clone().backward();
+        let loss_empty = ctc.forward_no_reduction(
+            log_probs.clone(),
+            Tensor::from_ints([[1]]),
+            Tensor::from_ints([2]),
+            Tensor::from_ints([0]),
+        );
+
+        let loss = loss.into_data().value[0];
+        assert!(loss.is_finite() && loss > 1e30);
+        assert!(log_probs
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .value
+            .iter()
+            .all(|grad| grad.is_finite()));
+        loss_empty
+            .into_data()
+            .assert_approx_eq(&Data::from([0.0]), 3);
+    }
+
+    fn log_probs<B: Backend>() -> Tensor<B, 3> {
+        let logits = Tensor::from_floats([
+            [
+                [0.1, 0.6, -0.3],
+                [0.5, -0.2, 0.4],
+                [-0.7, 0.2, 0.9],
+                [0.3, 0.3, -0.1],
+            ],
+            [
+                [0.8, -0.4, 0.2],
+                [-0.1, 0.7, 0.0],
+                [0.4, 0.1, -0.6],
+                [0.2, -0.5, 0.3],
+            ],
+        ]);
+
+        log_softmax(logits, 2)
+    }
+
+    fn targets<B: Backend>() -> Tensor<B, 2, Int> {
+        Tensor::from_ints([[1, 2], [1, 0]])
+    }
+
+    fn input_lengths<B: Backend>() -> Tensor<B, 1, Int> {
+        Tensor::from_ints([4, 3])
+    }
+
+    fn target_lengths<B: Backend>() -> Tensor<B, 1, Int> {
+        Tensor::from_ints([2, 1])
+    }
+}
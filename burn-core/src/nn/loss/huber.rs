@@ -0,0 +1,185 @@
+use crate::nn::loss::reduction::Reduction;
+use core::marker::PhantomData;
+
+use burn_tensor::{backend::Backend, ElementConversion, Tensor};
+
+/// Calculate the Huber loss from the input logits and the targets.
+///
+/// The loss is quadratic when the absolute error is lower than `delta` and linear otherwise:
+///
+/// - `loss = 0.5 * (x - y)^2` if `|x - y| <= delta`
+/// - `loss = delta * (|x - y| - 0.5 * delta)` otherwise
+#[derive(Clone, Debug)]
+pub struct HuberLoss<B: Backend> {
+    delta: f64,
+    backend: PhantomData<B>,
+}
+
+/// Calculate the smooth L1 loss from the input logits and the targets.
+///
+/// This is the [Huber loss](HuberLoss) divided by `beta`, which makes it converge to the L1 loss
+/// when `beta` is zero:
+///
+/// - `loss = 0.5 * (x - y)^2 / beta` if `|x - y| < beta`
+/// - `loss = |x - y| - 0.5 * beta` otherwise
+#[derive(Clone, Debug)]
+pub struct SmoothL1Loss<B: Backend> {
+    beta: f64,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> HuberLoss<B> {
+    /// Create the criterion.
+    pub fn new(delta: f64) -> Self {
+        if delta <= 0.0 {
+            panic!("The delta of the Huber loss must be positive, got {delta}");
+        }
+
+        Self {
+            delta,
+            backend: PhantomData,
+        }
+    }
+
+    /// Compute the criterion on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: `[batch_size, num_targets]`
+    /// - targets: `[batch_size, num_targets]`
+    pub fn forward<const D: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let tensor = self.forward_no_reduction(logits, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => tensor.mean(),
+            Reduction::Sum => tensor.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor without reducing.
+    pub fn forward_no_reduction<const D: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        let error = logits.sub(targets).abs();
+        // Using the clamped error avoids masking: both branches are merged into a single formula.
+        let quadratic = error.clone().clamp_max(self.delta.elem());
+        let linear = error.sub(quadratic.clone());
+
+        quadratic
+            .powf(2.0)
+            .mul_scalar(0.5)
+            .add(linear.mul_scalar(self.delta))
+    }
+}
+
+impl<B: Backend> SmoothL1Loss<B> {
+    /// Create the criterion.
+    pub fn new(beta: f64) -> Self {
+        if beta < 0.0 {
+            panic!("The beta of the smooth L1 loss must be non-negative, got {beta}");
+        }
+
+        Self {
+            beta,
+            backend: PhantomData,
+        }
+    }
+
+    /// Compute the criterion on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: `[batch_size, num_targets]`
+    /// - targets: `[batch_size, num_targets]`
+    pub fn forward<const D: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let tensor = self.forward_no_reduction(logits, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => tensor.mean(),
+            Reduction::Sum => tensor.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor without reducing.
+    pub fn forward_no_reduction<const D: usize>(
+        &self,
+        logits: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        if self.beta == 0.0 {
+            return logits.sub(targets).abs();
+        }
+
+        HuberLoss::new(self.beta)
+            .forward_no_reduction(logits, targets)
+            .div_scalar(self.beta)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_huber_loss() {
+        let logits = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]));
+        let targets = Tensor::<TestBackend, 2>::from_data(Data::from([[2.0, 1.0], [3.0, -2.0]]));
+
+        let huber = HuberLoss::new(1.0);
+        let loss_no_reduction = huber.forward_no_reduction(logits.clone(), targets.clone());
+        let loss = huber.forward(logits.clone(), targets.clone(), Reduction::Auto);
+        let loss_sum = huber.forward(logits, targets, Reduction::Sum);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.5, 0.5], [0.0, 5.5]]), 3);
+        loss.into_data().assert_approx_eq(&Data::from([1.625]), 3);
+        loss_sum.into_data().assert_approx_eq(&Data::from([6.5]), 3);
+    }
+
+    #[test]
+    fn test_smooth_l1_loss() {
+        let logits = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]));
+        let targets = Tensor::<TestBackend, 2>::from_data(Data::from([[2.0, 1.0], [3.0, -2.0]]));
+
+        let smooth_l1 = SmoothL1Loss::new(2.0);
+        let loss_no_reduction = smooth_l1.forward_no_reduction(logits.clone(), targets.clone());
+        let loss = smooth_l1.forward(logits.clone(), targets.clone(), Reduction::Mean);
+        let loss_sum = smooth_l1.forward(logits, targets, Reduction::Sum);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.25, 0.25], [0.0, 5.0]]), 3);
+        loss.into_data().assert_approx_eq(&Data::from([1.375]), 3);
+        loss_sum.into_data().assert_approx_eq(&Data::from([5.5]), 3);
+    }
+
+    #[test]
+    fn test_smooth_l1_loss_zero_beta_is_l1() {
+        let logits = Tensor::<TestBackend, 1>::from_data(Data::from([1.0, 2.0, -3.0]));
+        let targets = Tensor::<TestBackend, 1>::from_data(Data::from([2.0, 2.0, 1.0]));
+
+        let loss = SmoothL1Loss::new(0.0).forward_no_reduction(logits, targets);
+
+        loss.into_data()
+            .assert_approx_eq(&Data::from([1.0, 0.0, 4.0]), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_huber_loss_non_positive_delta() {
+        HuberLoss::<TestBackend>::new(0.0);
+    }
+}
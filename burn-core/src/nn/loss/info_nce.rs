@@ -0,0 +1,111 @@
+use crate::nn::loss::reduction::Reduction;
+use core::marker::PhantomData;
+
+use burn_tensor::{activation, backend::Backend, ElementConversion, Int, Tensor};
+
+/// Calculate the InfoNCE contrastive loss from pairs of query and key embeddings, as described in
+/// the paper [Representation Learning with Contrastive Predictive Coding](https://arxiv.org/abs/1807.03748).
+///
+/// The embeddings are L2-normalized and the cosine similarity of each query with every key of the
+/// batch is scaled by the temperature. Each query must match the key at the same position, while
+/// all the other keys of the batch are used as negatives.
+#[derive(Clone, Debug)]
+pub struct InfoNCELoss<B: Backend> {
+    temperature: f64,
+    epsilon: f64,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> InfoNCELoss<B> {
+    /// Create the criterion with the given temperature.
+    pub fn new(temperature: f64) -> Self {
+        if temperature <= 0.0 {
+            panic!("The temperature of the InfoNCE loss must be positive, got {temperature}");
+        }
+
+        Self {
+            temperature,
+            epsilon: 1e-12,
+            backend: PhantomData,
+        }
+    }
+
+    /// Compute the criterion on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - queries: `[batch_size, d_model]`
+    /// - keys: `[batch_size, d_model]`
+    pub fn forward(
+        &self,
+        queries: Tensor<B, 2>,
+        keys: Tensor<B, 2>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let tensor = self.forward_no_reduction(queries, keys);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => tensor.mean(),
+            Reduction::Sum => tensor.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor without reducing.
+    ///
+    /// # Shapes
+    ///
+    /// - queries: `[batch_size, d_model]`
+    /// - keys: `[batch_size, d_model]`
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction(&self, queries: Tensor<B, 2>, keys: Tensor<B, 2>) -> Tensor<B, 1> {
+        let [batch_size, _] = queries.dims();
+
+        let queries = self.normalize(queries);
+        let keys = self.normalize(keys);
+        let logits = queries
+            .matmul(keys.transpose())
+            .div_scalar(self.temperature);
+
+        let targets = Tensor::<B, 1, Int>::arange_device(0..batch_size, &logits.device())
+            .reshape([batch_size, 1]);
+
+        activation::log_softmax(logits, 1)
+            .gather(1, targets)
+            .reshape([batch_size])
+            .neg()
+    }
+
+    fn normalize(&self, tensor: Tensor<B, 2>) -> Tensor<B, 2> {
+        let norm = tensor
+            .clone()
+            .powf(2.0)
+            .sum_dim(1)
+            .sqrt()
+            .clamp_min(self.epsilon.elem());
+
+        tensor.div(norm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_info_nce_loss() {
+        let queries = Tensor::<TestBackend, 2>::from_floats([[2.0, 0.0], [0.6, 0.8]]);
+        let keys = Tensor::<TestBackend, 2>::from_floats([[0.8, 0.6], [0.0, 3.0]]);
+        let loss = InfoNCELoss::new(0.5);
+
+        let loss_no_reduction = loss.forward_no_reduction(queries.clone(), keys.clone());
+        let loss_mean = loss.forward(queries, keys, Reduction::Auto);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([0.1839, 0.8659]), 3);
+        loss_mean
+            .into_data()
+            .assert_approx_eq(&Data::from([0.5249]), 3);
+    }
+}
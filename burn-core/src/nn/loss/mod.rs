@@ -1,7 +1,19 @@
+mod binary_cross_entropy;
 mod cross_entropy;
+mod ctc;
+mod huber;
+mod info_nce;
 mod mse;
+mod nll;
 mod reduction;
+mod triplet_margin;
 
+pub use binary_cross_entropy::*;
 pub use cross_entropy::*;
+pub use ctc::*;
+pub use huber::*;
+pub use info_nce::*;
 pub use mse::*;
+pub use nll::*;
 pub use reduction::*;
+pub use triplet_margin::*;
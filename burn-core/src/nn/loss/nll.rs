@@ -0,0 +1,121 @@
+use core::marker::PhantomData;
+
+use burn_tensor::{backend::Backend, Bool, Int, Tensor};
+
+/// Calculate the negative log likelihood loss from the input log-probabilities and the targets.
+///
+/// Contrary to the [cross entropy loss](crate::nn::loss::CrossEntropyLoss), no softmax is
+/// applied on the input, which must already contain log-probabilities. The targets equal to the
+/// padding index are ignored and the mean is taken over the remaining targets.
+#[derive(Clone, Debug)]
+pub struct NLLLoss<B: Backend> {
+    pad_index: Option<usize>,
+    weights: Option<Tensor<B, 1>>,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> NLLLoss<B> {
+    /// Create the criterion.
+    pub fn new(pad_index: Option<usize>) -> Self {
+        Self {
+            pad_index,
+            weights: None,
+            backend: PhantomData,
+        }
+    }
+
+    /// Rescale the loss of each class with the given weights of shape `[num_targets]`.
+    ///
+    /// When weights are used, the mean loss is normalized by the sum of the weights of the
+    /// targets instead of the batch size.
+    pub fn with_weights(mut self, weights: Tensor<B, 1>) -> Self {
+        self.weights = Some(weights);
+        self
+    }
+
+    /// Compute the criterion on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - log_probs: `[batch_size, num_targets]`
+    /// - targets: `[batch_size]`
+    pub fn forward(&self, log_probs: Tensor<B, 2>, targets: Tensor<B, 1, Int>) -> Tensor<B, 1> {
+        let [batch_size, num_targets] = log_probs.dims();
+
+        let mask = self.padding_mask(&targets);
+        let targets = targets.reshape([batch_size, 1]);
+        let tensor = log_probs.gather(1, targets.clone()).reshape([batch_size]);
+
+        // The mean is taken over the targets that aren't ignored, weighted by their class weight.
+        let weights = match &self.weights {
+            Some(weights) => weights
+                .clone()
+                .reshape([1, num_targets])
+                .repeat(0, batch_size)
+                .gather(1, targets)
+                .reshape([batch_size]),
+            None => tensor.ones_like(),
+        };
+        let tensor = self.apply_mask(tensor.mul(weights.clone()), mask.clone());
+        let weights = self.apply_mask(weights, mask);
+
+        tensor.sum().div(weights.sum()).neg()
+    }
+
+    fn padding_mask(&self, targets: &Tensor<B, 1, Int>) -> Option<Tensor<B, 1, Bool>> {
+        self.pad_index
+            .map(|pad_index| targets.clone().equal_elem(pad_index as i64))
+    }
+
+    fn apply_mask(&self, tensor: Tensor<B, 1>, mask: Option<Tensor<B, 1, Bool>>) -> Tensor<B, 1> {
+        match mask {
+            Some(mask) => tensor.mask_fill(mask, 0),
+            None => tensor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::loss::CrossEntropyLoss;
+    use crate::TestBackend;
+    use burn_tensor::{activation, Data, Distribution};
+
+    #[test]
+    fn test_nll_loss_equals_cross_entropy_on_log_softmax() {
+        let logits = Tensor::<TestBackend, 2>::random([4, 5], Distribution::Normal(0., 1.0));
+        let targets = Tensor::<TestBackend, 1, Int>::from_data(Data::from([2, 0, 4, 1]));
+        let weights = Tensor::<TestBackend, 1>::from_floats([0.5, 2.0, 1.0, 1.0, 3.0]);
+
+        let loss_1 = NLLLoss::new(Some(4))
+            .with_weights(weights.clone())
+            .forward(activation::log_softmax(logits.clone(), 1), targets.clone());
+        let loss_2 = CrossEntropyLoss::new(Some(4))
+            .with_weights(weights)
+            .forward(logits, targets);
+
+        loss_1.into_data().assert_approx_eq(&loss_2.into_data(), 3);
+    }
+
+    #[test]
+    fn test_nll_loss() {
+        let log_probs = Tensor::<TestBackend, 2>::from_floats([[-0.5, -1.0], [-2.0, -0.1]]);
+        let targets = Tensor::<TestBackend, 1, Int>::from_data(Data::from([1, 0]));
+
+        let loss = NLLLoss::new(None).forward(log_probs, targets);
+
+        loss.into_data().assert_approx_eq(&Data::from([1.5]), 3);
+    }
+
+    #[test]
+    fn test_nll_loss_with_pad_token_is_mean_over_non_padded_targets() {
+        let log_probs =
+            Tensor::<TestBackend, 2>::from_floats([[-0.5, -1.0], [-2.0, -0.1], [-0.3, -1.2]]);
+        let targets = Tensor::<TestBackend, 1, Int>::from_data(Data::from([1, 0, 1]));
+
+        let loss = NLLLoss::new(Some(0)).forward(log_probs, targets);
+
+        loss.into_data().assert_approx_eq(&Data::from([1.1]), 3);
+    }
+}
@@ -0,0 +1,142 @@
+use crate::nn::loss::reduction::Reduction;
+use core::marker::PhantomData;
+
+use burn_tensor::{activation, backend::Backend, Tensor};
+
+/// Calculate the triplet margin loss from the anchor, positive and negative embeddings, as
+/// described in the paper [Learning local feature descriptors with triplets and shallow
+/// convolutional neural networks](http://www.bmva.org/bmvc/2016/papers/paper119/index.html).
+///
+/// `loss = max(d(a, p) - d(a, n) + margin, 0)` where `d(x, y) = ||x - y + eps||_p`
+#[derive(Clone, Debug)]
+pub struct TripletMarginLoss<B: Backend> {
+    margin: f64,
+    p: f64,
+    epsilon: f64,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> TripletMarginLoss<B> {
+    /// Create the criterion with the given margin, using the euclidean distance.
+    pub fn new(margin: f64) -> Self {
+        Self::with_norm(margin, 2.0)
+    }
+
+    /// Create the criterion with the given margin, using the `p`-norm distance.
+    pub fn with_norm(margin: f64, p: f64) -> Self {
+        if p <= 0.0 {
+            panic!("The norm degree of the triplet margin loss must be positive, got {p}");
+        }
+
+        Self {
+            margin,
+            p,
+            epsilon: 1e-6,
+            backend: PhantomData,
+        }
+    }
+
+    /// Compute the criterion on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - anchor: `[batch_size, d_model]`
+    /// - positive: `[batch_size, d_model]`
+    /// - negative: `[batch_size, d_model]`
+    pub fn forward(
+        &self,
+        anchor: Tensor<B, 2>,
+        positive: Tensor<B, 2>,
+        negative: Tensor<B, 2>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let tensor = self.forward_no_reduction(anchor, positive, negative);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => tensor.mean(),
+            Reduction::Sum => tensor.sum(),
+        }
+    }
+
+    /// Compute the criterion on the input tensor without reducing.
+    ///
+    /// # Shapes
+    ///
+    /// - anchor: `[batch_size, d_model]`
+    /// - positive: `[batch_size, d_model]`
+    /// - negative: `[batch_size, d_model]`
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction(
+        &self,
+        anchor: Tensor<B, 2>,
+        positive: Tensor<B, 2>,
+        negative: Tensor<B, 2>,
+    ) -> Tensor<B, 1> {
+        let [batch_size, _] = anchor.dims();
+        let distance_positive = self.distance(anchor.clone(), positive);
+        let distance_negative = self.distance(anchor, negative);
+
+        let loss = distance_positive
+            .sub(distance_negative)
+            .add_scalar(self.margin);
+
+        activation::relu(loss).reshape([batch_size])
+    }
+
+    fn distance(&self, x: Tensor<B, 2>, y: Tensor<B, 2>) -> Tensor<B, 2> {
+        x.sub(y)
+            .add_scalar(self.epsilon)
+            .abs()
+            .powf(self.p as f32)
+            .sum_dim(1)
+            .powf(1.0 / self.p as f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_triplet_margin_loss() {
+        let loss = TripletMarginLoss::new(1.0);
+
+        let loss_no_reduction =
+            loss.forward_no_reduction(anchor::<TestBackend>(), positive(), negative());
+        let loss_mean = loss.forward(anchor(), positive(), negative(), Reduction::Auto);
+
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([1.0, 1.5]), 3);
+        loss_mean
+            .into_data()
+            .assert_approx_eq(&Data::from([1.25]), 3);
+    }
+
+    #[test]
+    fn test_triplet_margin_loss_is_zero_when_negative_is_far() {
+        let loss = TripletMarginLoss::with_norm(0.5, 1.0);
+
+        let loss = loss.forward(
+            anchor::<TestBackend>(),
+            anchor(),
+            anchor().add_scalar(10.0),
+            Reduction::Sum,
+        );
+
+        loss.into_data().assert_approx_eq(&Data::from([0.0]), 3);
+    }
+
+    fn anchor<B: Backend>() -> Tensor<B, 2> {
+        Tensor::from_floats([[1.0, 2.0], [0.0, 1.0]])
+    }
+
+    fn positive<B: Backend>() -> Tensor<B, 2> {
+        Tensor::from_floats([[1.0, 1.0], [1.0, 1.0]])
+    }
+
+    fn negative<B: Backend>() -> Tensor<B, 2> {
+        Tensor::from_floats([[2.0, 2.0], [0.0, 1.5]])
+    }
+}
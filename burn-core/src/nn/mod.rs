@@ -16,24 +16,44 @@ pub mod pool;
 /// Transformer module
 pub mod transformer;
 
+mod drop_path;
 mod dropout;
 mod embedding;
 mod gelu;
+mod hard_sigmoid;
+mod hard_swish;
 mod initializer;
+mod lazy_linear;
+mod leaky_relu;
 mod linear;
+mod mish;
 mod norm;
 mod padding;
 mod pos_encoding;
+mod prelu;
 mod relu;
 mod rnn;
+mod sequential;
+mod silu;
+mod spectral_norm;
 
+pub use drop_path::*;
 pub use dropout::*;
 pub use embedding::*;
 pub use gelu::*;
+pub use hard_sigmoid::*;
+pub use hard_swish::*;
 pub use initializer::*;
+pub use lazy_linear::*;
+pub use leaky_relu::*;
 pub use linear::*;
+pub use mish::*;
 pub use norm::*;
 pub use padding::*;
 pub use pos_encoding::*;
+pub use prelu::*;
 pub use relu::*;
 pub use rnn::*;
+pub use sequential::*;
+pub use silu::*;
+pub use spectral_norm::*;
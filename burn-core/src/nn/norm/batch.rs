@@ -17,11 +17,25 @@ pub struct BatchNormConfig {
     /// Momentum used to update the metrics. Default: 0.1
     #[config(default = 0.1)]
     pub momentum: f64,
+    /// If the running mean and variance should be tracked during training and used during
+    /// inference. When disabled, the batch statistics are always used. Default: true
+    #[config(default = true)]
+    pub track_running_stats: bool,
 }
 
 /// Applies Batch Normalization over a tensor as described in the paper [Batch Normalization](https://arxiv.org/abs/1502.03167)
 ///
 /// `Y = norm(X) * γ + β`
+///
+/// The const generic `D` is the number of spatial dimensions, so `BatchNorm<B, 1>` is applied
+/// on `[batch_size, channels, length]` tensors, `BatchNorm<B, 2>` on
+/// `[batch_size, channels, height, width]` tensors and `BatchNorm<B, 3>` on
+/// `[batch_size, channels, depth, height, width]` tensors.
+///
+/// By default, the module is in training mode when the backend has autodiff enabled and in
+/// inference mode otherwise. Use [train](BatchNorm::train) and [eval](BatchNorm::eval) to
+/// select the mode explicitly. The module returned by [valid](crate::module::ADModule::valid)
+/// is back to the default, and is thus in inference mode.
 #[derive(Module, Debug)]
 pub struct BatchNorm<B: Backend, const D: usize> {
     gamma: Param<Tensor<B, 1>>,
@@ -30,6 +44,10 @@ pub struct BatchNorm<B: Backend, const D: usize> {
     running_var: RunningState<Tensor<B, 1>>,
     momentum: f64,
     epsilon: f64,
+    #[module(skip)]
+    track_running_stats: bool,
+    #[module(mode)]
+    training: Option<bool>,
 }
 
 impl BatchNormConfig {
@@ -48,6 +66,8 @@ impl BatchNormConfig {
             running_var: RunningState::new(running_var),
             momentum: self.momentum,
             epsilon: self.epsilon,
+            track_running_stats: self.track_running_stats,
+            training: None,
         }
     }
 
@@ -63,6 +83,8 @@ impl BatchNormConfig {
             running_var: RunningState::from_record(record.running_var),
             momentum: self.momentum,
             epsilon: self.epsilon,
+            track_running_stats: self.track_running_stats,
+            training: None,
         }
     }
 }
@@ -81,12 +103,31 @@ impl<const D: usize, B: Backend> BatchNorm<B, D> {
             panic!("BatchNorm{}D can only be applied on tensors of size {} with the following shape [batch_size, channels, ...], received {}D tensor", D, D+2, DI);
         }
 
-        match B::ad_enabled() {
+        match self.is_training() || !self.track_running_stats {
             true => self.forward_train(input),
             false => self.forward_inference(input),
         }
     }
 
+    /// Set the module in training mode, where the batch statistics are used to normalize the
+    /// input and to update the running statistics.
+    pub fn train(mut self) -> Self {
+        self.training = Some(true);
+        self
+    }
+
+    /// Set the module in inference mode, where the running statistics are used to normalize
+    /// the input.
+    pub fn eval(mut self) -> Self {
+        self.training = Some(false);
+        self
+    }
+
+    /// Returns if the module is in training mode.
+    pub fn is_training(&self) -> bool {
+        self.training.unwrap_or_else(B::ad_enabled)
+    }
+
     fn forward_inference<const DI: usize>(&self, input: Tensor<B, DI>) -> Tensor<B, DI> {
         let channels = input.dims()[1];
         let mean = self.running_mean.value();
@@ -127,26 +168,27 @@ impl<const D: usize, B: Backend> BatchNorm<B, D> {
             .mean_dim(1)
             .reshape(shape_unsqueeze);
 
+        if self.is_training() && self.track_running_stats {
+            self.update_running_stats(mean.clone(), var.clone());
+        }
+
+        self.forward_shared(input, mean, var)
+    }
+
+    fn update_running_stats<const DI: usize>(&self, mean: Tensor<B, DI>, var: Tensor<B, DI>) {
+        let channels = mean.dims()[1];
         let running_mean = self.running_mean.value_sync();
         let running_var = self.running_var.value_sync();
 
-        let running_mean = running_mean.mul_scalar(1.0 - self.momentum).add(
-            mean.clone()
-                .detach()
-                .mul_scalar(self.momentum)
-                .reshape([channels]),
-        );
-        let running_var = running_var.mul_scalar(1.0 - self.momentum).add(
-            var.clone()
-                .detach()
-                .mul_scalar(self.momentum)
-                .reshape([channels]),
-        );
+        let running_mean = running_mean
+            .mul_scalar(1.0 - self.momentum)
+            .add(mean.detach().mul_scalar(self.momentum).reshape([channels]));
+        let running_var = running_var
+            .mul_scalar(1.0 - self.momentum)
+            .add(var.detach().mul_scalar(self.momentum).reshape([channels]));
 
         self.running_mean.update(running_mean.detach());
         self.running_var.update(running_var.detach());
-
-        self.forward_shared(input, mean, var)
     }
 
     fn forward_shared<const DI: usize>(
@@ -380,3 +422,178 @@ mod tests_2d {
         ])
     }
 }
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests_3d {
+    use super::*;
+    use crate::{module::ADModule, TestADBackend};
+    use burn_tensor::Data;
+
+    #[test]
+    fn batch_norm_forward_train() {
+        let module = BatchNormConfig::new(2).init::<TestADBackend, 3>();
+
+        let output = module.forward(input_tensor());
+
+        output.to_data().assert_approx_eq(
+            &Data::from([
+                [[[[-1.0, -1.0], [1.0, 1.0]]], [[[1.0, 1.0], [-1.0, -1.0]]]],
+                [[[[-1.0, -1.0], [1.0, 1.0]]], [[[1.0, 1.0], [-1.0, -1.0]]]],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn batch_norm_forward_inference() {
+        let module = BatchNormConfig::new(2).init::<TestADBackend, 3>();
+
+        module.forward(input_tensor());
+        let module = module.valid();
+        let output = module.forward(input_tensor());
+
+        output.to_data().assert_approx_eq(
+            &Data::from([
+                [[[[-0.1, -0.1], [1.9, 1.9]]], [[[3.7, 3.7], [1.7, 1.7]]]],
+                [[[[-0.1, -0.1], [1.9, 1.9]]], [[[3.7, 3.7], [1.7, 1.7]]]],
+            ]),
+            3,
+        );
+    }
+
+    fn input_tensor<B: Backend>() -> Tensor<B, 5> {
+        Tensor::<B, 5>::from_floats([
+            [[[[0.0, 0.0], [2.0, 2.0]]], [[[4.0, 4.0], [2.0, 2.0]]]],
+            [[[[0.0, 0.0], [2.0, 2.0]]], [[[4.0, 4.0], [2.0, 2.0]]]],
+        ])
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests_mode {
+    use super::*;
+    use crate::{module::ADModule, TestADBackend, TestBackend};
+    use burn_tensor::Data;
+
+    #[test]
+    fn batch_norm_valid_is_in_inference_mode() {
+        let module = BatchNormConfig::new(2).init::<TestADBackend, 1>().train();
+
+        assert!(!module.valid().is_training());
+    }
+
+    #[test]
+    fn batch_norm_eval_uses_running_stats() {
+        let module = BatchNormConfig::new(2).init::<TestADBackend, 1>().eval();
+
+        let output = module.forward(input_tensor());
+
+        assert!(!module.is_training());
+        output
+            .into_data()
+            .assert_approx_eq(&input_tensor::<TestADBackend>().into_data(), 3);
+        module
+            .running_mean
+            .value_sync()
+            .into_data()
+            .assert_approx_eq(&Data::from([0.0, 0.0]), 3);
+    }
+
+    #[test]
+    fn batch_norm_train_updates_running_stats() {
+        let module = BatchNormConfig::new(2).init::<TestBackend, 1>().train();
+
+        let output = module.forward(input_tensor());
+
+        assert!(module.is_training());
+        output.into_data().assert_approx_eq(
+            &Data::from([[[-1.0, 1.0], [1.0, -1.0]], [[-1.0, 1.0], [1.0, -1.0]]]),
+            3,
+        );
+        module
+            .running_mean
+            .value_sync()
+            .into_data()
+            .assert_approx_eq(&Data::from([0.1, 0.3]), 3);
+        module
+            .running_var
+            .value_sync()
+            .into_data()
+            .assert_approx_eq(&Data::from([1.0, 1.0]), 3);
+    }
+
+    #[test]
+    fn batch_norm_without_running_stats() {
+        let module = BatchNormConfig::new(2)
+            .with_track_running_stats(false)
+            .init::<TestBackend, 1>();
+
+        let output = module.forward(input_tensor());
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[-1.0, 1.0], [1.0, -1.0]], [[-1.0, 1.0], [1.0, -1.0]]]),
+            3,
+        );
+        module
+            .running_mean
+            .value_sync()
+            .into_data()
+            .assert_approx_eq(&Data::from([0.0, 0.0]), 3);
+    }
+
+    fn input_tensor<B: Backend>() -> Tensor<B, 3> {
+        Tensor::<B, 3>::from_floats([[[0.0, 2.0], [4.0, 2.0]], [[0.0, 2.0], [4.0, 2.0]]])
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests_record {
+    use super::*;
+    use crate::{
+        record::{BinBytesRecorder, FullPrecisionSettings, Recorder},
+        TestBackend,
+    };
+    use burn_tensor::Data;
+
+    /// The batch norm module as it was recorded before the train/eval mode was added.
+    #[derive(Module, Debug)]
+    struct LegacyBatchNorm<B: Backend> {
+        gamma: Param<Tensor<B, 1>>,
+        beta: Param<Tensor<B, 1>>,
+        running_mean: RunningState<Tensor<B, 1>>,
+        running_var: RunningState<Tensor<B, 1>>,
+        momentum: f64,
+        epsilon: f64,
+    }
+
+    #[test]
+    fn batch_norm_load_legacy_record() {
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let legacy = LegacyBatchNorm::<TestBackend> {
+            gamma: Param::from(Tensor::from_floats([1.0, 2.0])),
+            beta: Param::from(Tensor::from_floats([0.5, -0.5])),
+            running_mean: RunningState::new(Tensor::from_floats([1.0, -1.0])),
+            running_var: RunningState::new(Tensor::from_floats([4.0, 0.25])),
+            momentum: 0.1,
+            epsilon: 0.0,
+        };
+        let bytes = recorder.record(legacy.into_record(), ()).unwrap();
+
+        let record = recorder
+            .load::<BatchNormRecord<TestBackend, 0>>(bytes.clone())
+            .unwrap();
+        let module = BatchNormConfig::new(2)
+            .with_epsilon(0.0)
+            .init_with::<TestBackend, 0>(record)
+            .eval();
+        let output = module.forward(Tensor::<TestBackend, 2>::from_floats([[3.0, 0.0]]));
+
+        output
+            .to_data()
+            .assert_approx_eq(&Data::from([[1.5, 3.5]]), 3);
+        assert_eq!(recorder.record(module.into_record(), ()).unwrap(), bytes);
+    }
+}
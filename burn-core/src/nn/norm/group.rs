@@ -0,0 +1,256 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::Param;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// Configuration to create a [GroupNorm](GroupNorm) layer.
+#[derive(Config, Debug)]
+pub struct GroupNormConfig {
+    /// The number of groups to separate the channels into.
+    pub num_groups: usize,
+    /// The number of channels expected in the input.
+    pub num_channels: usize,
+    /// A value required for numerical stability. Default: 1e-5
+    #[config(default = 1e-5)]
+    pub epsilon: f64,
+    /// A boolean value that when set to `true`, this module has learnable
+    /// per-channel affine parameters initialized to ones (for weights)
+    /// and zeros (for biases). Default: true
+    #[config(default = true)]
+    pub affine: bool,
+}
+
+/// Applies Group Normalization over a mini-batch of inputs as described in the paper [Group Normalization](https://arxiv.org/abs/1803.08494).
+///
+/// `Y = groupnorm(X) * γ + β`
+#[derive(Module, Debug)]
+pub struct GroupNorm<B: Backend> {
+    gamma: Option<Param<Tensor<B, 1>>>,
+    beta: Option<Param<Tensor<B, 1>>>,
+    num_groups: usize,
+    num_channels: usize,
+    epsilon: f64,
+}
+
+impl GroupNormConfig {
+    /// Initialize a new [group norm](GroupNorm) module.
+    pub fn init<B: Backend>(&self) -> GroupNorm<B> {
+        self.check();
+
+        let (gamma, beta) = match self.affine {
+            true => (
+                Some(Param::from(Tensor::ones([self.num_channels]))),
+                Some(Param::from(Tensor::zeros([self.num_channels]))),
+            ),
+            false => (None, None),
+        };
+
+        GroupNorm {
+            gamma,
+            beta,
+            num_groups: self.num_groups,
+            num_channels: self.num_channels,
+            epsilon: self.epsilon,
+        }
+    }
+
+    /// Initialize a new [group norm](GroupNorm) module with a [record](GroupNormRecord).
+    pub fn init_with<B: Backend>(&self, record: GroupNormRecord<B>) -> GroupNorm<B> {
+        self.check();
+
+        GroupNorm {
+            gamma: record.gamma,
+            beta: record.beta,
+            num_groups: self.num_groups,
+            num_channels: self.num_channels,
+            epsilon: self.epsilon,
+        }
+    }
+
+    fn check(&self) {
+        if self.num_channels.checked_rem(self.num_groups) != Some(0) {
+            panic!(
+                "The number of channels must be divisible by the number of groups. Got num_channels={}, num_groups={}",
+                self.num_channels, self.num_groups
+            );
+        }
+    }
+}
+
+impl<B: Backend> GroupNorm<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, num_channels, ...]`
+    /// - output: `[batch_size, num_channels, ...]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let shape = input.shape();
+        let batch_size = shape.dims[0];
+        let num_channels = shape.dims[1];
+
+        if num_channels != self.num_channels {
+            panic!(
+                "The number of channels in the input tensor should be equal to the number of channels in the GroupNorm module. Expected {}, got {}",
+                self.num_channels, num_channels
+            );
+        }
+
+        let hidden_size =
+            shape.dims[2..].iter().product::<usize>() * num_channels / self.num_groups;
+        let input = input.reshape([batch_size, self.num_groups, hidden_size]);
+
+        let (var, mean) = input.clone().var_mean_bias(2);
+        let input_normalized = input
+            .sub(mean)
+            .div(var.add_scalar(self.epsilon).sqrt())
+            .reshape(shape);
+
+        match (&self.gamma, &self.beta) {
+            (Some(gamma), Some(beta)) => {
+                let mut affine_shape = [1; D];
+                affine_shape[1] = num_channels;
+
+                input_normalized
+                    .mul(gamma.val().reshape(affine_shape))
+                    .add(beta.val().reshape(affine_shape))
+            }
+            _ => input_normalized,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn group_norm_forward_affine_false() {
+        let module = GroupNormConfig::new(2, 6)
+            .with_affine(false)
+            .init::<TestBackend>();
+
+        assert!(module.gamma.is_none());
+        assert!(module.beta.is_none());
+
+        let input = Tensor::from_data(Data::from([
+            [
+                [-0.3034, 0.2726, -0.9659],
+                [-1.1845, -1.3236, 0.0172],
+                [1.9507, 1.2554, -0.8625],
+                [1.0682, 0.3604, 0.3985],
+                [-0.4957, -0.4461, -0.9721],
+                [1.5157, -0.1546, -0.5596],
+            ],
+            [
+                [-1.6698, -0.4040, -0.7927],
+                [0.3736, -0.0975, -0.1351],
+                [-0.9461, 0.5461, -0.6334],
+                [-1.0919, -0.1158, 0.1213],
+                [-0.9535, 0.1281, 0.4372],
+                [-0.2845, 0.3488, 0.5641],
+            ],
+        ]));
+
+        let output = module.forward(input);
+
+        output.to_data().assert_approx_eq(
+            &Data::from([
+                [
+                    [-0.1653, 0.3748, -0.7866],
+                    [-0.9916, -1.1220, 0.1353],
+                    [1.9485, 1.2965, -0.6896],
+                    [1.2769, 0.3628, 0.4120],
+                    [-0.7427, -0.6786, -1.3578],
+                    [1.8547, -0.3022, -0.8252],
+                ],
+                [
+                    [-1.9342, 0.0211, -0.5793],
+                    [1.2223, 0.4945, 0.4365],
+                    [-0.8163, 1.4887, -0.3333],
+                    [-1.7960, -0.0392, 0.3875],
+                    [-1.5469, 0.3998, 0.9561],
+                    [-0.3428, 0.7970, 1.1845],
+                ],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn group_norm_forward_affine_true() {
+        let module = GroupNormConfig::new(3, 6)
+            .with_affine(true)
+            .init::<TestBackend>();
+
+        module
+            .gamma
+            .as_ref()
+            .expect("gamma should not be None")
+            .val()
+            .to_data()
+            .assert_approx_eq(&Data::ones([6].into()), 3);
+
+        module
+            .beta
+            .as_ref()
+            .expect("beta should not be None")
+            .val()
+            .to_data()
+            .assert_approx_eq(&Data::zeros([6]), 3);
+
+        let input = Tensor::from_data(Data::from([
+            [
+                [0.3345, 0.4429, 0.6639],
+                [0.5041, 0.4175, 0.8437],
+                [0.6159, 0.3758, 0.4071],
+                [0.5417, 0.5785, 0.7671],
+                [0.3837, 0.9883, 0.0420],
+                [0.4808, 0.8989, 0.6144],
+            ],
+            [
+                [0.3930, 0.2098, 0.0602],
+                [0.2298, 0.9425, 0.0333],
+                [0.7409, 0.8172, 0.8879],
+                [0.4846, 0.0486, 0.2029],
+                [0.6741, 0.9765, 0.6864],
+                [0.2827, 0.5534, 0.2125],
+            ],
+        ]));
+
+        let output = module.forward(input);
+
+        output.to_data().assert_approx_eq(
+            &Data::from([
+                [
+                    [-1.1693, -0.5353, 0.7572],
+                    [-0.1774, -0.6839, 1.8087],
+                    [0.5203, -1.3109, -1.0722],
+                    [-0.0456, 0.2350, 1.6734],
+                    [-0.5797, 1.3218, -1.6543],
+                    [-0.2743, 1.0406, 0.1459],
+                ],
+                [
+                    [0.2664, -0.3320, -0.8206],
+                    [-0.2666, 2.0612, -0.9084],
+                    [0.6681, 0.9102, 1.1345],
+                    [-0.1452, -1.5286, -1.0390],
+                    [0.4253, 1.5963, 0.4730],
+                    [-1.0903, -0.0421, -1.3622],
+                ],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    #[should_panic = "The number of channels must be divisible by the number of groups."]
+    fn group_norm_channels_not_divisible_by_groups() {
+        let _module = GroupNormConfig::new(4, 6).init::<TestBackend>();
+    }
+}
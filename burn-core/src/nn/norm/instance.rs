@@ -0,0 +1,336 @@
+use crate as burn;
+
+use crate::{
+    config::Config,
+    module::{Module, Param, RunningState},
+    tensor::{backend::Backend, Tensor},
+};
+
+/// Configuration to create a [InstanceNorm](InstanceNorm) layer.
+#[derive(Config, Debug)]
+pub struct InstanceNormConfig {
+    /// The number of channels expected in the input.
+    pub num_channels: usize,
+    /// A value required for numerical stability. Default: 1e-5
+    #[config(default = 1e-5)]
+    pub epsilon: f64,
+    /// Momentum used to update the running statistics. Default: 0.1
+    #[config(default = 0.1)]
+    pub momentum: f64,
+    /// A boolean value that when set to `true`, this module has learnable
+    /// per-channel affine parameters initialized to ones (for weights)
+    /// and zeros (for biases). Default: true
+    #[config(default = true)]
+    pub affine: bool,
+    /// If the running mean and variance should be tracked during training and used during
+    /// inference. When disabled, the instance statistics are always used. Default: false
+    #[config(default = false)]
+    pub track_running_stats: bool,
+}
+
+/// Applies Instance Normalization over a tensor as described in the paper [Instance Normalization](https://arxiv.org/abs/1607.08022)
+///
+/// Each channel of each sample is normalized independently.
+///
+/// `Y = norm(X) * γ + β`
+#[derive(Module, Debug)]
+pub struct InstanceNorm<B: Backend> {
+    gamma: Option<Param<Tensor<B, 1>>>,
+    beta: Option<Param<Tensor<B, 1>>>,
+    running_mean: RunningState<Tensor<B, 1>>,
+    running_var: RunningState<Tensor<B, 1>>,
+    num_channels: usize,
+    momentum: f64,
+    epsilon: f64,
+    #[module(skip)]
+    track_running_stats: bool,
+    #[module(mode)]
+    training: Option<bool>,
+}
+
+impl InstanceNormConfig {
+    /// Initialize a new [instance norm](InstanceNorm) module.
+    pub fn init<B: Backend>(&self) -> InstanceNorm<B> {
+        let (gamma, beta) = match self.affine {
+            true => (
+                Some(Param::from(Tensor::ones([self.num_channels]))),
+                Some(Param::from(Tensor::zeros([self.num_channels]))),
+            ),
+            false => (None, None),
+        };
+
+        let running_mean = Tensor::zeros([self.num_channels]);
+        let running_var = Tensor::ones([self.num_channels]);
+
+        InstanceNorm {
+            gamma,
+            beta,
+            running_mean: RunningState::new(running_mean),
+            running_var: RunningState::new(running_var),
+            num_channels: self.num_channels,
+            momentum: self.momentum,
+            epsilon: self.epsilon,
+            track_running_stats: self.track_running_stats,
+            training: None,
+        }
+    }
+
+    /// Initialize a new [instance norm](InstanceNorm) module with a [record](InstanceNormRecord).
+    pub fn init_with<B: Backend>(&self, record: InstanceNormRecord<B>) -> InstanceNorm<B> {
+        InstanceNorm {
+            gamma: record.gamma,
+            beta: record.beta,
+            running_mean: RunningState::from_record(record.running_mean),
+            running_var: RunningState::from_record(record.running_var),
+            num_channels: self.num_channels,
+            momentum: self.momentum,
+            epsilon: self.epsilon,
+            track_running_stats: self.track_running_stats,
+            training: None,
+        }
+    }
+}
+
+impl<B: Backend> InstanceNorm<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, num_channels, ...]`
+    /// - output: `[batch_size, num_channels, ...]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let shape = input.shape();
+        let batch_size = shape.dims[0];
+        let num_channels = shape.dims[1];
+
+        if num_channels != self.num_channels {
+            panic!(
+                "The number of channels in the input tensor should be equal to the number of channels in the InstanceNorm module. Expected {}, got {}",
+                self.num_channels, num_channels
+            );
+        }
+
+        let hidden_size = shape.dims[2..].iter().product::<usize>();
+        let input = input.reshape([batch_size, num_channels, hidden_size]);
+
+        let (mean, var) = match self.is_training() || !self.track_running_stats {
+            true => {
+                let (var, mean) = input.clone().var_mean_bias(2);
+
+                if self.is_training() && self.track_running_stats {
+                    self.update_running_stats(mean.clone(), var.clone());
+                }
+
+                (mean, var)
+            }
+            false => (
+                self.running_mean.value().reshape([1, num_channels, 1]),
+                self.running_var.value().reshape([1, num_channels, 1]),
+            ),
+        };
+
+        let input_normalized = input
+            .sub(mean)
+            .div(var.add_scalar(self.epsilon).sqrt())
+            .reshape(shape);
+
+        match (&self.gamma, &self.beta) {
+            (Some(gamma), Some(beta)) => {
+                let mut affine_shape = [1; D];
+                affine_shape[1] = num_channels;
+
+                input_normalized
+                    .mul(gamma.val().reshape(affine_shape))
+                    .add(beta.val().reshape(affine_shape))
+            }
+            _ => input_normalized,
+        }
+    }
+
+    /// Set the module in training mode, where the instance statistics are used to normalize the
+    /// input and to update the running statistics.
+    pub fn train(mut self) -> Self {
+        self.training = Some(true);
+        self
+    }
+
+    /// Set the module in inference mode, where the running statistics are used to normalize
+    /// the input when they are tracked.
+    pub fn eval(mut self) -> Self {
+        self.training = Some(false);
+        self
+    }
+
+    /// Returns if the module is in training mode.
+    pub fn is_training(&self) -> bool {
+        self.training.unwrap_or_else(B::ad_enabled)
+    }
+
+    fn update_running_stats(&self, mean: Tensor<B, 3>, var: Tensor<B, 3>) {
+        let running_mean = self.running_mean.value_sync();
+        let running_var = self.running_var.value_sync();
+
+        let running_mean = running_mean.mul_scalar(1.0 - self.momentum).add(
+            mean.detach()
+                .mean_dim(0)
+                .mul_scalar(self.momentum)
+                .reshape([self.num_channels]),
+        );
+        let running_var = running_var.mul_scalar(1.0 - self.momentum).add(
+            var.detach()
+                .mean_dim(0)
+                .mul_scalar(self.momentum)
+                .reshape([self.num_channels]),
+        );
+
+        self.running_mean.update(running_mean.detach());
+        self.running_var.update(running_var.detach());
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{module::ADModule, TestADBackend, TestBackend};
+    use burn_tensor::Data;
+
+    #[test]
+    fn instance_norm_forward_affine_false() {
+        let module = InstanceNormConfig::new(2)
+            .with_affine(false)
+            .init::<TestBackend>();
+
+        assert!(module.gamma.is_none());
+        assert!(module.beta.is_none());
+
+        let output = module.forward(input_tensor());
+
+        output.to_data().assert_approx_eq(&expected_output(), 3);
+    }
+
+    #[test]
+    fn instance_norm_forward_affine_true() {
+        let module = InstanceNormConfig::new(2).init::<TestBackend>();
+
+        let output = module.forward(input_tensor());
+
+        output.to_data().assert_approx_eq(&expected_output(), 3);
+    }
+
+    #[test]
+    fn instance_norm_running_stats() {
+        let module = InstanceNormConfig::new(2)
+            .with_track_running_stats(true)
+            .init::<TestBackend>()
+            .train();
+
+        let output = module.forward(input_tensor());
+
+        output.to_data().assert_approx_eq(&expected_output(), 3);
+        module
+            .running_mean
+            .value_sync()
+            .into_data()
+            .assert_approx_eq(&Data::from([0.5, 0.425]), 3);
+        module
+            .running_var
+            .value_sync()
+            .into_data()
+            .assert_approx_eq(&Data::from([1.9, 1.1125]), 3);
+
+        let module = module.eval();
+        let output = module.forward(input_tensor());
+
+        output.to_data().assert_approx_eq(
+            &Data::from([
+                [[-0.3627, 2.5392], [3.3894, 4.3375]],
+                [[2.5392, 8.3430], [1.4932, 5.2856]],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    fn instance_norm_valid_is_in_inference_mode() {
+        let module = InstanceNormConfig::new(2).init::<TestADBackend>().train();
+
+        assert!(!module.valid().is_training());
+    }
+
+    fn input_tensor<B: Backend>() -> Tensor<B, 3> {
+        Tensor::<B, 3>::from_floats([[[0.0, 4.0], [4.0, 5.0]], [[4.0, 12.0], [2.0, 6.0]]])
+    }
+
+    fn expected_output() -> Data<f32, 3> {
+        Data::from([[[-1.0, 1.0], [-1.0, 1.0]], [[-1.0, 1.0], [-1.0, 1.0]]])
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests_record {
+    use super::*;
+    use crate::{
+        record::{BinBytesRecorder, FullPrecisionSettings, Recorder},
+        TestBackend,
+    };
+    use burn_tensor::Data;
+
+    /// The instance norm module without its train/eval mode, which is not part of the record.
+    #[derive(Module, Debug)]
+    struct LegacyInstanceNorm<B: Backend> {
+        gamma: Option<Param<Tensor<B, 1>>>,
+        beta: Option<Param<Tensor<B, 1>>>,
+        running_mean: RunningState<Tensor<B, 1>>,
+        running_var: RunningState<Tensor<B, 1>>,
+        num_channels: usize,
+        momentum: f64,
+        epsilon: f64,
+    }
+
+    #[test]
+    fn instance_norm_load_legacy_record() {
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let legacy = LegacyInstanceNorm::<TestBackend> {
+            gamma: Some(Param::from(Tensor::from_floats([1.0, 2.0]))),
+            beta: Some(Param::from(Tensor::from_floats([0.5, -0.5]))),
+            running_mean: RunningState::new(Tensor::from_floats([1.0, -1.0])),
+            running_var: RunningState::new(Tensor::from_floats([4.0, 0.25])),
+            num_channels: 2,
+            momentum: 0.1,
+            epsilon: 0.0,
+        };
+        let bytes = recorder.record(legacy.into_record(), ()).unwrap();
+
+        let record = recorder
+            .load::<InstanceNormRecord<TestBackend>>(bytes.clone())
+            .unwrap();
+        let module = InstanceNormConfig::new(2)
+            .with_epsilon(0.0)
+            .with_track_running_stats(true)
+            .init_with::<TestBackend>(record)
+            .eval();
+        let output = module.forward(Tensor::<TestBackend, 3>::from_floats([[[3.0], [0.0]]]));
+
+        output
+            .to_data()
+            .assert_approx_eq(&Data::from([[[1.5], [3.5]]]), 3);
+        assert_eq!(recorder.record(module.into_record(), ()).unwrap(), bytes);
+    }
+
+    #[test]
+    fn instance_norm_load_record_keeps_the_mode() {
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let config = InstanceNormConfig::new(2).with_track_running_stats(true);
+        let bytes = recorder
+            .record(config.init::<TestBackend>().into_record(), ())
+            .unwrap();
+
+        let record = recorder.load(bytes.clone()).unwrap();
+        let module = config.init::<TestBackend>().train().load_record(record);
+
+        assert!(module.is_training());
+        assert_eq!(recorder.record(module.into_record(), ()).unwrap(), bytes);
+    }
+}
@@ -0,0 +1,108 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::Param;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// Configuration to create a [RmsNorm](RmsNorm) layer.
+#[derive(Config, Debug)]
+pub struct RmsNormConfig {
+    /// The size of the input features.
+    pub d_model: usize,
+    /// A value required for numerical stability. Default: 1e-5
+    #[config(default = 1e-5)]
+    pub epsilon: f64,
+}
+
+/// Applies RMS Normalization over an input tensor as described in the paper [Root Mean Square Layer Normalization](https://arxiv.org/abs/1910.07467).
+///
+/// `Y = X / sqrt(mean(X^2) + eps) * γ`
+#[derive(Module, Debug)]
+pub struct RmsNorm<B: Backend> {
+    gamma: Param<Tensor<B, 1>>,
+    epsilon: f64,
+}
+
+impl RmsNormConfig {
+    /// Initialize a new [rms norm](RmsNorm) module.
+    pub fn init<B: Backend>(&self) -> RmsNorm<B> {
+        let gamma = Tensor::ones([self.d_model]);
+
+        RmsNorm {
+            gamma: Param::from(gamma),
+            epsilon: self.epsilon,
+        }
+    }
+
+    /// Initialize a new [rms norm](RmsNorm) module with a [record](RmsNormRecord).
+    pub fn init_with<B: Backend>(&self, record: RmsNormRecord<B>) -> RmsNorm<B> {
+        RmsNorm {
+            gamma: record.gamma,
+            epsilon: self.epsilon,
+        }
+    }
+}
+
+impl<B: Backend> RmsNorm<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any, d_model]`
+    /// - output: `[..., any, d_model]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let rms = input
+            .clone()
+            .powf(2.0)
+            .mean_dim(D - 1)
+            .add_scalar(self.epsilon)
+            .sqrt();
+
+        input.div(rms).mul(self.gamma.val().unsqueeze())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[cfg(feature = "std")]
+    use crate::{TestADBackend, TestBackend};
+
+    #[cfg(not(feature = "std"))]
+    use crate::TestBackend;
+
+    #[test]
+    fn rms_norm_forward() {
+        let module = RmsNormConfig::new(3).init::<TestBackend>();
+        let input = Tensor::from_data(Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]));
+
+        let output = module.forward(input);
+
+        output.to_data().assert_approx_eq(
+            &Data::from([[0.0, 0.7746, 1.5492], [0.7348, 0.9798, 1.2247]]),
+            3,
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn rms_norm_backward() {
+        let module = RmsNormConfig::new(3).init::<TestADBackend>();
+        let input =
+            Tensor::<TestADBackend, 2>::from_data(Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]))
+                .require_grad();
+
+        let output = module.forward(input);
+        let grads = output.backward();
+
+        let gamma_grad = module.gamma.grad(&grads).unwrap();
+
+        gamma_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([0.7348, 1.7544, 2.7739]), 3);
+    }
+}
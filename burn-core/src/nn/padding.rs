@@ -4,6 +4,8 @@ use burn_tensor::ops::conv::calculate_conv_padding;
 
 use crate::config::Config;
 use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::{PadMode, Tensor};
 
 /// Padding configuration for 1D operators.
 #[derive(Module, Config, Debug, PartialEq)]
@@ -31,6 +33,25 @@ impl PaddingConfig1d {
             Self::Explicit(value) => *value,
         }
     }
+
+    /// Pad the input of a convolution, returning the padding to apply on both sides by the
+    /// convolution.
+    pub(crate) fn pad_conv_input_1d<B: Backend>(
+        &self,
+        input: Tensor<B, 3>,
+        kernel_size: usize,
+        stride: usize,
+        dilation: usize,
+    ) -> (Tensor<B, 3>, usize) {
+        match self {
+            Self::Same => {
+                let (input, [padding]) = pad_same(input, [kernel_size], [stride], [dilation]);
+                (input, padding)
+            }
+            Self::Valid => (input, 0),
+            Self::Explicit(value) => (input, *value),
+        }
+    }
 }
 
 /// Padding configuration for 2D operators.
@@ -66,4 +87,79 @@ impl PaddingConfig2d {
             Self::Explicit(v1, v2) => [*v1, *v2],
         }
     }
+
+    /// Pad the input of a convolution, returning the padding to apply on both sides by the
+    /// convolution.
+    pub(crate) fn pad_conv_input_2d<B: Backend>(
+        &self,
+        input: Tensor<B, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        dilation: [usize; 2],
+    ) -> (Tensor<B, 4>, [usize; 2]) {
+        match self {
+            Self::Same => pad_same(input, kernel_size, stride, dilation),
+            Self::Valid => (input, [0, 0]),
+            Self::Explicit(v1, v2) => (input, [*v1, *v2]),
+        }
+    }
+}
+
+/// Padding configuration for 3D operators.
+#[derive(Module, Config, Debug, PartialEq)]
+pub enum PaddingConfig3d {
+    /// Dynamically calculate the amount of padding necessary to ensure that the output size will be
+    /// the same as the input.
+    Same,
+    /// Same as no padding.
+    Valid,
+    /// Applies the specified amount of padding to all inputs.
+    Explicit(usize, usize, usize),
+}
+
+impl PaddingConfig3d {
+    /// Pad the input of a convolution, returning the padding to apply on both sides by the
+    /// convolution.
+    pub(crate) fn pad_conv_input_3d<B: Backend>(
+        &self,
+        input: Tensor<B, 5>,
+        kernel_size: [usize; 3],
+        stride: [usize; 3],
+        dilation: [usize; 3],
+    ) -> (Tensor<B, 5>, [usize; 3]) {
+        match self {
+            Self::Same => pad_same(input, kernel_size, stride, dilation),
+            Self::Valid => (input, [0, 0, 0]),
+            Self::Explicit(v1, v2, v3) => (input, [*v1, *v2, *v3]),
+        }
+    }
+}
+
+/// The `Same` padding of the spatial dimensions, the last `S` dimensions of the input.
+///
+/// When the total padding of a dimension is odd, the extra zero is added after the input with
+/// [pad](Tensor::pad), like PyTorch does, since the convolution pads both sides equally.
+fn pad_same<B: Backend, const D: usize, const S: usize>(
+    input: Tensor<B, D>,
+    kernel_size: [usize; S],
+    stride: [usize; S],
+    dilation: [usize; S],
+) -> (Tensor<B, D>, [usize; S]) {
+    let dims = input.dims();
+    let mut padding = [0; S];
+    let mut pads = [(0, 0); D];
+
+    for i in 0..S {
+        let size = dims[D - S + i];
+        let total =
+            (stride[i] * (size - 1) + dilation[i] * (kernel_size[i] - 1) + 1).saturating_sub(size);
+
+        padding[i] = total / 2;
+        pads[D - S + i].1 = total % 2;
+    }
+
+    match pads.iter().any(|(_, after)| *after > 0) {
+        true => (input.pad(pads, PadMode::Constant(0.0)), padding),
+        false => (input, padding),
+    }
 }
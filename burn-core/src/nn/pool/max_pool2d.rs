@@ -4,8 +4,8 @@ use crate::config::Config;
 use crate::module::Module;
 use crate::nn::PaddingConfig2d;
 use crate::tensor::backend::Backend;
-use crate::tensor::Tensor;
-use burn_tensor::module::max_pool2d;
+use crate::tensor::{Int, Tensor};
+use burn_tensor::module::{max_pool2d, max_pool2d_with_indices};
 
 /// Configuration to create an [2D max pooling](MaxPool2d) layer.
 #[derive(Debug, Config)]
@@ -54,4 +54,26 @@ impl MaxPool2d {
 
         max_pool2d(input, self.kernel_size, self.stride, padding)
     }
+
+    /// Applies the forward pass on the input tensor and returns the indices of the max values,
+    /// which can be used by [max unpool 2d](crate::nn::pool::MaxUnpool2d).
+    ///
+    /// The indices are flattened over the spatial dimensions of each input channel.
+    ///
+    /// # Shapes
+    ///
+    /// - input: [batch_size, channels, height_in, width_in],
+    /// - output: [batch_size, channels, height_out, width_out],
+    /// - indices: [batch_size, channels, height_out, width_out],
+    pub fn forward_with_indices<B: Backend>(
+        &self,
+        input: Tensor<B, 4>,
+    ) -> (Tensor<B, 4>, Tensor<B, 4, Int>) {
+        let [_batch_size, _channels_in, height_in, width_in] = input.dims();
+        let padding =
+            self.padding
+                .calculate_padding_2d(height_in, width_in, &self.kernel_size, &self.stride);
+
+        max_pool2d_with_indices(input, self.kernel_size, self.stride, padding)
+    }
 }
@@ -0,0 +1,178 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::{ElementConversion, Int, Tensor};
+
+/// Configuration to create an [2D max unpooling](MaxUnpool2d) layer.
+#[derive(Debug, Config)]
+pub struct MaxUnpool2dConfig {
+    /// The size of the kernel.
+    pub kernel_size: [usize; 2],
+    /// The strides.
+    #[config(default = "[1, 1]")]
+    pub strides: [usize; 2],
+    /// The padding that was applied by the max pooling.
+    #[config(default = "[0, 0]")]
+    pub padding: [usize; 2],
+}
+
+/// Computes a partial inverse of a [2D max pooling](crate::nn::pool::MaxPool2d).
+///
+/// The max values are put back at the positions given by the indices returned by
+/// [forward_with_indices](crate::nn::pool::MaxPool2d::forward_with_indices), and all the
+/// other values are set to zero.
+#[derive(Module, Debug, Clone)]
+pub struct MaxUnpool2d {
+    stride: [usize; 2],
+    kernel_size: [usize; 2],
+    padding: [usize; 2],
+}
+
+impl MaxUnpool2dConfig {
+    /// Initialize a new [max unpool 2d](MaxUnpool2d) module.
+    pub fn init(&self) -> MaxUnpool2d {
+        MaxUnpool2d {
+            stride: self.strides,
+            kernel_size: self.kernel_size,
+            padding: self.padding,
+        }
+    }
+}
+
+impl MaxUnpool2d {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// The output size is `(size_in - 1) * stride - 2 * padding + kernel_size` for each spatial
+    /// dimension. Use [forward_with_output_size](MaxUnpool2d::forward_with_output_size) when the
+    /// max pooling input size can't be inferred that way.
+    ///
+    /// # Shapes
+    ///
+    /// - input: [batch_size, channels, height_in, width_in],
+    /// - indices: [batch_size, channels, height_in, width_in],
+    /// - output: [batch_size, channels, height_out, width_out],
+    pub fn forward<B: Backend>(
+        &self,
+        input: Tensor<B, 4>,
+        indices: Tensor<B, 4, Int>,
+    ) -> Tensor<B, 4> {
+        let [_batch_size, _channels, height_in, width_in] = input.dims();
+        let output_size = [
+            self.calculate_output_size(height_in, 0),
+            self.calculate_output_size(width_in, 1),
+        ];
+
+        self.forward_with_output_size(input, indices, output_size)
+    }
+
+    /// Applies the forward pass on the input tensor with the given output spatial size.
+    ///
+    /// # Shapes
+    ///
+    /// - input: [batch_size, channels, height_in, width_in],
+    /// - indices: [batch_size, channels, height_in, width_in],
+    /// - output: [batch_size, channels, output_size[0], output_size[1]],
+    pub fn forward_with_output_size<B: Backend>(
+        &self,
+        input: Tensor<B, 4>,
+        indices: Tensor<B, 4, Int>,
+        output_size: [usize; 2],
+    ) -> Tensor<B, 4> {
+        let [batch_size, channels, height_in, width_in] = input.dims();
+        let [height_out, width_out] = output_size;
+        let device = input.device();
+
+        let shape_in = [batch_size, channels, height_in * width_in];
+        let shape_out = [batch_size, channels, height_out * width_out];
+
+        let input = input.reshape(shape_in);
+        let indices = indices.reshape(shape_in);
+
+        let output = Tensor::zeros_device(shape_out, &device).scatter(2, indices.clone(), input);
+        // Overlapping windows can select the same max value multiple times.
+        let counts = Tensor::zeros_device(shape_out, &device)
+            .scatter(2, indices, Tensor::ones_device(shape_in, &device))
+            .clamp_min(1.0.elem());
+
+        output
+            .div(counts)
+            .reshape([batch_size, channels, height_out, width_out])
+    }
+
+    fn calculate_output_size(&self, size_in: usize, dim: usize) -> usize {
+        (size_in - 1) * self.stride[dim] + self.kernel_size[dim] - 2 * self.padding[dim]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::pool::MaxPool2dConfig;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn max_unpool2d_inverse_of_max_pool2d() {
+        let pool = MaxPool2dConfig::new([2, 2]).with_strides([2, 2]).init();
+        let unpool = MaxUnpool2dConfig::new([2, 2]).with_strides([2, 2]).init();
+        let input = Tensor::<TestBackend, 4>::from_floats([[[
+            [1.0, 2.0, 3.0, 4.0],
+            [5.0, 6.0, 7.0, 8.0],
+            [9.0, 10.0, 11.0, 12.0],
+            [13.0, 14.0, 15.0, 16.0],
+        ]]]);
+
+        let (output, indices) = pool.forward_with_indices(input);
+        let output = unpool.forward(output, indices);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[
+                [0.0, 0.0, 0.0, 0.0],
+                [0.0, 6.0, 0.0, 8.0],
+                [0.0, 0.0, 0.0, 0.0],
+                [0.0, 14.0, 0.0, 16.0],
+            ]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn max_unpool2d_overlapping_windows() {
+        let pool = MaxPool2dConfig::new([2, 2]).init();
+        let unpool = MaxUnpool2dConfig::new([2, 2]).init();
+        let input = Tensor::<TestBackend, 4>::from_floats([[[
+            [1.0, 2.0, 3.0],
+            [4.0, 9.0, 5.0],
+            [6.0, 7.0, 8.0],
+        ]]]);
+
+        let (output, indices) = pool.forward_with_indices(input);
+        let output = unpool.forward(output, indices);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[[0.0, 0.0, 0.0], [0.0, 9.0, 0.0], [0.0, 0.0, 0.0]]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn max_unpool2d_with_output_size() {
+        let pool = MaxPool2dConfig::new([2, 2]).with_strides([2, 2]).init();
+        let unpool = MaxUnpool2dConfig::new([2, 2]).with_strides([2, 2]).init();
+        let input = Tensor::<TestBackend, 4>::from_floats([[[
+            [1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0],
+            [7.0, 8.0, 9.0],
+        ]]]);
+
+        let (output, indices) = pool.forward_with_indices(input);
+        let output = unpool.forward_with_output_size(output, indices, [3, 3]);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[[0.0, 0.0, 0.0], [0.0, 5.0, 0.0], [0.0, 0.0, 0.0]]]]),
+            3,
+        );
+    }
+}
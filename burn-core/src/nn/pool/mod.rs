@@ -4,6 +4,7 @@ mod avg_pool1d;
 mod avg_pool2d;
 mod max_pool1d;
 mod max_pool2d;
+mod max_unpool2d;
 
 pub use adaptive_avg_pool1d::*;
 pub use adaptive_avg_pool2d::*;
@@ -11,3 +12,4 @@ pub use avg_pool1d::*;
 pub use avg_pool2d::*;
 pub use max_pool1d::*;
 pub use max_pool2d::*;
+pub use max_unpool2d::*;
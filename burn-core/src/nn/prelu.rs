@@ -0,0 +1,149 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Module, Param};
+use crate::tensor::activation::relu;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// Configuration to create a [PReLU](PReLU) layer.
+#[derive(Config, Debug)]
+pub struct PReLUConfig {
+    /// The number of learnable slopes, either 1 to share the same slope across all channels or
+    /// the number of channels of the input. Default: 1
+    #[config(default = 1)]
+    pub num_parameters: usize,
+    /// The initial value of the slopes. Default: 0.25
+    #[config(default = 0.25)]
+    pub alpha: f64,
+}
+
+/// Applies the parametric rectified linear unit function element-wise as described in the paper
+/// [Delving Deep into Rectifiers](https://arxiv.org/abs/1502.01852).
+///
+/// `y = max(0, x) + α * min(0, x)`
+///
+/// The slope `α` is learned, either shared or per channel.
+#[derive(Module, Debug)]
+pub struct PReLU<B: Backend> {
+    alpha: Param<Tensor<B, 1>>,
+}
+
+impl PReLUConfig {
+    /// Initialize a new [prelu](PReLU) module.
+    pub fn init<B: Backend>(&self) -> PReLU<B> {
+        let alpha = Tensor::ones([self.num_parameters]).mul_scalar(self.alpha);
+
+        PReLU {
+            alpha: Param::from(alpha),
+        }
+    }
+
+    /// Initialize a new [prelu](PReLU) module with a [record](PReLURecord).
+    pub fn init_with<B: Backend>(&self, record: PReLURecord<B>) -> PReLU<B> {
+        PReLU {
+            alpha: record.alpha,
+        }
+    }
+}
+
+impl<B: Backend> PReLU<B> {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// When the slope is learned per channel, the channels must be on the second dimension.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels, ...]`
+    /// - output: `[batch_size, channels, ...]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let [num_parameters] = self.alpha.dims();
+        let mut shape = [1; D];
+
+        if num_parameters > 1 {
+            let channels = input.dims()[1];
+
+            if channels != num_parameters {
+                panic!(
+                    "The number of channels in the input tensor should be equal to the number of parameters in the PReLU module. Expected {}, got {}",
+                    num_parameters, channels
+                );
+            }
+
+            shape[1] = num_parameters;
+        }
+
+        let negative = relu(input.clone().neg()).mul(self.alpha.val().reshape(shape));
+
+        relu(input).sub(negative)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[cfg(feature = "std")]
+    use crate::{TestADBackend, TestBackend};
+
+    #[cfg(not(feature = "std"))]
+    use crate::TestBackend;
+
+    #[test]
+    fn prelu_forward_shared() {
+        let module = PReLUConfig::new().init::<TestBackend>();
+        let input = Tensor::from_floats([[-2.0, -0.5], [0.0, 3.0]]);
+
+        let output = module.forward(input);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[-0.5, -0.125], [0.0, 3.0]]), 3);
+    }
+
+    #[test]
+    fn prelu_forward_per_channel() {
+        let module = PReLUConfig::new()
+            .with_num_parameters(2)
+            .init_with(PReLURecord {
+                alpha: Param::from(Tensor::from_floats([0.1, 0.5])),
+            });
+        let input = Tensor::<TestBackend, 3>::from_floats([[[-2.0, 1.0], [-2.0, 1.0]]]);
+
+        let output = module.forward(input);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[-0.2, 1.0], [-1.0, 1.0]]]), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn prelu_forward_invalid_channels() {
+        let module = PReLUConfig::new()
+            .with_num_parameters(3)
+            .init::<TestBackend>();
+
+        module.forward(Tensor::<TestBackend, 3>::zeros([1, 2, 4]));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn prelu_backward() {
+        let module = PReLUConfig::new()
+            .with_num_parameters(2)
+            .init::<TestADBackend>();
+        let input = Tensor::<TestADBackend, 3>::from_floats([[[-2.0, 1.0], [-3.0, -1.0]]]);
+
+        let output = module.forward(input);
+        let grads = output.sum().backward();
+
+        module
+            .alpha
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&Data::from([-2.0, -4.0]), 3);
+    }
+}
@@ -12,7 +12,7 @@ use burn_tensor::activation;
 use super::gate_controller::GateController;
 
 /// The configuration for a [gru](Gru) module.
-#[derive(Config)]
+#[derive(Config, Debug)]
 pub struct GruConfig {
     /// The size of the input features.
     pub d_input: usize,
@@ -23,18 +23,30 @@ pub struct GruConfig {
     /// Gru initializer
     #[config(default = "Initializer::XavierNormal{gain:1.0}")]
     pub initializer: Initializer,
-    /// The batch size.
-    pub batch_size: usize,
+    /// If the reset gate is applied after the hidden transformation of the new gate, which is
+    /// the behavior of PyTorch and of ONNX with `linear_before_reset`.
+    #[config(default = false)]
+    pub reset_after: bool,
 }
 
 /// The Gru module. This implementation is for a unidirectional, stateless, Gru.
+///
+/// Each gate holds its own input and hidden [linear](crate::nn::Linear) transformations with
+/// weights of shape `[d_input, d_hidden]` and `[d_hidden, d_hidden]`.
+///
+/// # Notes
+///
+/// Since burn 0.10, the hidden state of each step is computed from the one of the previous step,
+/// the batch size is taken from the input instead of the config, and the optional initial state
+/// has the shape `[batch_size, hidden_size]` instead of the shape of the output.
 #[derive(Module, Debug)]
 pub struct Gru<B: Backend> {
     update_gate: GateController<B>,
     reset_gate: GateController<B>,
     new_gate: GateController<B>,
-    batch_size: usize,
     d_hidden: usize,
+    #[module(skip)]
+    reset_after: bool,
 }
 
 impl GruConfig {
@@ -65,8 +77,8 @@ impl GruConfig {
             update_gate,
             reset_gate,
             new_gate,
-            batch_size: self.batch_size,
             d_hidden: self.d_hidden,
+            reset_after: self.reset_after,
         }
     }
 
@@ -89,106 +101,79 @@ impl GruConfig {
                 record.reset_gate,
             ),
             new_gate: gate_controller::GateController::new_with(&linear_config, record.new_gate),
-            batch_size: self.batch_size,
             d_hidden: self.d_hidden,
+            reset_after: self.reset_after,
         }
     }
 }
 
 impl<B: Backend> Gru<B> {
     /// Applies the forward pass on the input tensor. This GRU implementation
-    /// returns a single state tensor with dimensions [batch_size, sequence_length, hidden_size].
+    /// returns the hidden state for each element in a sequence (i.e., across `seq_length`),
+    /// producing a tensor with dimensions [batch_size, sequence_length, hidden_size].
     ///
     /// Parameters:
     ///     batched_input: The input tensor of shape [batch_size, sequence_length, input_size].
-    ///     state: An optional tensor representing an initial cell state with the same dimensions
-    ///            as batched_input. If none is provided, one will be generated.
+    ///     state: An optional tensor representing the initial hidden state of shape
+    ///            [batch_size, hidden_size]. If none is provided, it is initialized to zeros.
     ///
     /// Returns:
     ///     The resulting state tensor, with shape [batch_size, sequence_length, hidden_size].
     pub fn forward(
-        &mut self,
+        &self,
         batched_input: Tensor<B, 3>,
-        state: Option<Tensor<B, 3>>,
+        state: Option<Tensor<B, 2>>,
     ) -> Tensor<B, 3> {
-        let seq_length = batched_input.shape().dims[1];
+        let [batch_size, seq_length, _] = batched_input.shape().dims;
+        let device = batched_input.device();
+        let mut batched_hidden_state =
+            Tensor::zeros_device([batch_size, seq_length, self.d_hidden], &device);
 
-        let mut hidden_state = match state {
+        let mut hidden_t = match state {
             Some(state) => state,
-            None => Tensor::zeros([self.batch_size, seq_length, self.d_hidden]),
+            None => Tensor::zeros_device([batch_size, self.d_hidden], &device),
         };
 
         for t in 0..seq_length {
             let indices = Tensor::arange(t..t + 1);
-            let input_t = batched_input.clone().select(1, indices.clone()).squeeze(1);
-            let hidden_t = hidden_state.clone().select(1, indices.clone()).squeeze(1);
+            let input_t = batched_input.clone().select(1, indices).squeeze(1);
 
             // u(pdate)g(ate) tensors
-            let biased_ug_input_sum = self.gate_product(&input_t, &hidden_t, &self.update_gate);
+            let biased_ug_input_sum = self.update_gate.gate_product(&input_t, &hidden_t);
             let update_values = activation::sigmoid(biased_ug_input_sum); // Colloquially referred to as z(t)
 
             // r(eset)g(ate) tensors
-            let biased_rg_input_sum = self.gate_product(&input_t, &hidden_t, &self.reset_gate);
+            let biased_rg_input_sum = self.reset_gate.gate_product(&input_t, &hidden_t);
             let reset_values = activation::sigmoid(biased_rg_input_sum); // Colloquially referred to as r(t)
-            let reset_t = hidden_t.clone().mul(reset_values); // Passed as input to new_gate
 
             // n(ew)g(ate) tensor
-            let biased_ng_input_sum = self.gate_product(&input_t, &reset_t, &self.new_gate);
+            let biased_ng_input_sum = match self.reset_after {
+                // r(t) * (Wh * hidden_t + bh) is added to the input transformation
+                true => {
+                    self.new_gate.input_transform.forward(input_t)
+                        + reset_values.mul(self.new_gate.hidden_transform.forward(hidden_t.clone()))
+                }
+                // r(t) * hidden_t is passed as the hidden state of the new gate
+                false => {
+                    let reset_t = hidden_t.clone().mul(reset_values);
+                    self.new_gate.gate_product(&input_t, &reset_t)
+                }
+            };
             let candidate_state = biased_ng_input_sum.tanh(); // Colloquially referred to as g(t)
 
             // calculate linear interpolation between previous hidden state and candidate state:
             // g(t) * (1 - z(t)) + z(t) * hidden_t
-            let state_vector = candidate_state
-                .clone()
+            hidden_t = candidate_state
                 .mul(update_values.clone().sub_scalar(1).mul_scalar(-1)) // (1 - z(t)) = -(z(t) - 1)
-                + update_values.clone().mul(hidden_t);
+                + update_values.mul(hidden_t);
 
-            hidden_state = hidden_state.slice_assign(
-                [0..self.batch_size, t..(t + 1), 0..self.d_hidden],
-                state_vector.clone().unsqueeze(),
+            batched_hidden_state = batched_hidden_state.slice_assign(
+                [0..batch_size, t..(t + 1), 0..self.d_hidden],
+                hidden_t.clone().reshape([batch_size, 1, self.d_hidden]),
             );
         }
 
-        hidden_state
-    }
-
-    /// Helper function for performing weighted matrix product for a gate and adds
-    /// bias, if any.
-    ///
-    ///  Mathematically, performs `Wx*X + Wh*H + b`, where:
-    ///     Wx = weight matrix for the connection to input vector X
-    ///     Wh = weight matrix for the connection to hidden state H
-    ///     X = input vector
-    ///     H = hidden state
-    ///     b = bias terms
-    fn gate_product(
-        &self,
-        input: &Tensor<B, 2>,
-        hidden: &Tensor<B, 2>,
-        gate: &GateController<B>,
-    ) -> Tensor<B, 2> {
-        let input_product = input.clone().matmul(gate.input_transform.weight.val());
-        let hidden_product = hidden.clone().matmul(gate.hidden_transform.weight.val());
-
-        let input_bias = gate
-            .input_transform
-            .bias
-            .as_ref()
-            .map(|bias_param| bias_param.val());
-        let hidden_bias = gate
-            .hidden_transform
-            .bias
-            .as_ref()
-            .map(|bias_param| bias_param.val());
-
-        match (input_bias, hidden_bias) {
-            (Some(input_bias), Some(hidden_bias)) => {
-                input_product + input_bias.unsqueeze() + hidden_product + hidden_bias.unsqueeze()
-            }
-            (Some(input_bias), None) => input_product + input_bias.unsqueeze() + hidden_product,
-            (None, Some(hidden_bias)) => input_product + hidden_product + hidden_bias.unsqueeze(),
-            (None, None) => input_product + hidden_product,
-        }
+        batched_hidden_state
     }
 }
 
@@ -198,6 +183,32 @@ mod tests {
     use crate::{module::Param, nn::LinearRecord, TestBackend};
     use burn_tensor::Data;
 
+    fn create_gate_controller(weights: f32, biases: f32) -> GateController<TestBackend> {
+        let record = LinearRecord {
+            weight: Param::from(Tensor::from_data(Data::from([[weights]]))),
+            bias: Some(Param::from(Tensor::from_data(Data::from([biases])))),
+        };
+        gate_controller::GateController::create_with_weights(
+            1,
+            1,
+            true,
+            Initializer::XavierNormal { gain: 1.0 },
+            record.clone(),
+            record,
+        )
+    }
+
+    /// Single feature GRU with update, reset and new gate weights of 0.5, 0.6 and 0.7.
+    fn create_gru(config: GruConfig, new_gate_bias: f32) -> Gru<TestBackend> {
+        let mut gru = config.init::<TestBackend>();
+
+        gru.update_gate = create_gate_controller(0.5, 0.0);
+        gru.reset_gate = create_gate_controller(0.6, 0.0);
+        gru.new_gate = create_gate_controller(0.7, new_gate_bias);
+
+        gru
+    }
+
     /// Test forward pass with simple input vector.
     ///
     /// z_t = sigmoid(0.5*0.1 + 0.5*0) = 0.5125
@@ -208,55 +219,7 @@ mod tests {
     #[test]
     fn tests_forward_single_input_single_feature() {
         TestBackend::seed(0);
-        let config = GruConfig::new(1, 1, false, 1);
-        let mut gru = config.init::<TestBackend>();
-
-        fn create_gate_controller(
-            weights: f32,
-            biases: f32,
-            d_input: usize,
-            d_output: usize,
-            bias: bool,
-            initializer: Initializer,
-        ) -> GateController<TestBackend> {
-            let record = LinearRecord {
-                weight: Param::from(Tensor::from_data(Data::from([[weights]]))),
-                bias: Some(Param::from(Tensor::from_data(Data::from([biases])))),
-            };
-            gate_controller::GateController::create_with_weights(
-                d_input,
-                d_output,
-                bias,
-                initializer,
-                record.clone(),
-                record,
-            )
-        }
-
-        gru.update_gate = create_gate_controller(
-            0.5,
-            0.0,
-            1,
-            1,
-            false,
-            Initializer::XavierNormal { gain: 1.0 },
-        );
-        gru.reset_gate = create_gate_controller(
-            0.6,
-            0.0,
-            1,
-            1,
-            false,
-            Initializer::XavierNormal { gain: 1.0 },
-        );
-        gru.new_gate = create_gate_controller(
-            0.7,
-            0.0,
-            1,
-            1,
-            false,
-            Initializer::XavierNormal { gain: 1.0 },
-        );
+        let gru = create_gru(GruConfig::new(1, 1, false), 0.0);
 
         let input = Tensor::<TestBackend, 3>::from_data(Data::from([[[0.1]]]));
 
@@ -266,4 +229,75 @@ mod tests {
 
         output.to_data().assert_approx_eq(&Data::from([[0.034]]), 3);
     }
+
+    /// The hidden state of the second step is computed from the one of the first step.
+    ///
+    /// z_t = sigmoid(0.5*0.2 + 0.5*0.0341) = 0.5292
+    /// r_t = sigmoid(0.6*0.2 + 0.6*0.0341) = 0.5351
+    /// g_t = tanh(0.7*0.2 + 0.7*0.5351*0.0341) = 0.1518
+    ///
+    /// h_t = z_t * h' + (1 - z_t) * g_t = 0.0894
+    #[test]
+    fn tests_forward_should_carry_the_hidden_state() {
+        TestBackend::seed(0);
+        let gru = create_gru(GruConfig::new(1, 1, true), 0.0);
+
+        let input = Tensor::<TestBackend, 3>::from_data(Data::from([[[0.1], [0.2]]]));
+
+        let state = gru.forward(input, None);
+
+        state
+            .to_data()
+            .assert_approx_eq(&Data::from([[[0.0341], [0.0894]]]), 3);
+    }
+
+    #[test]
+    fn tests_forward_with_initial_state() {
+        TestBackend::seed(0);
+        let gru = create_gru(GruConfig::new(1, 1, true), 0.0);
+
+        let input = Tensor::<TestBackend, 3>::from_data(Data::from([[[0.2]]]));
+        let initial_state = Tensor::<TestBackend, 2>::from_data(Data::from([[0.034069553]]));
+
+        let state = gru.forward(input, Some(initial_state));
+
+        state
+            .to_data()
+            .assert_approx_eq(&Data::from([[[0.0894]]]), 3);
+    }
+
+    /// The reset gate is applied to the hidden transformation including its bias.
+    ///
+    /// g_t = tanh(0.7*x + 0.1 + r_t * (0.7*h' + 0.1)) instead of
+    /// g_t = tanh(0.7*x + 0.1 + 0.7*r_t*h' + 0.1)
+    #[test]
+    fn tests_forward_reset_after() {
+        TestBackend::seed(0);
+        let input = Tensor::<TestBackend, 3>::from_data(Data::from([[[0.1], [0.2]]]));
+
+        let gru = create_gru(GruConfig::new(1, 1, true), 0.1);
+        let gru_reset_after = create_gru(GruConfig::new(1, 1, true).with_reset_after(true), 0.1);
+
+        gru.forward(input.clone(), None)
+            .to_data()
+            .assert_approx_eq(&Data::from([[[0.1285], [0.2398]]]), 3);
+        gru_reset_after
+            .forward(input, None)
+            .to_data()
+            .assert_approx_eq(&Data::from([[[0.1062], [0.2064]]]), 3);
+    }
+
+    #[test]
+    fn tests_reset_after_should_come_from_the_config() {
+        TestBackend::seed(0);
+        let input = Tensor::<TestBackend, 3>::from_data(Data::from([[[0.1], [0.2]]]));
+        let config = GruConfig::new(1, 1, true).with_reset_after(true);
+        let record = create_gru(config.clone(), 0.1).into_record();
+
+        let gru = config.init::<TestBackend>().load_record(record);
+
+        gru.forward(input, None)
+            .to_data()
+            .assert_approx_eq(&Data::from([[[0.1062], [0.2064]]]), 3);
+    }
 }
@@ -12,7 +12,7 @@ use burn_tensor::activation;
 use super::gate_controller::GateController;
 
 /// The configuration for a [lstm](Lstm) module.
-#[derive(Config)]
+#[derive(Config, Debug)]
 pub struct LstmConfig {
     /// The size of the input features.
     pub d_input: usize,
@@ -23,18 +23,20 @@ pub struct LstmConfig {
     /// Lstm initializer
     #[config(default = "Initializer::XavierNormal{gain:1.0}")]
     pub initializer: Initializer,
-    /// The batch size.
-    pub batch_size: usize,
 }
 
 /// The Lstm module. This implementation is for a unidirectional, stateless, Lstm.
+///
+/// Each gate holds its own input and hidden [linear](crate::nn::Linear) transformations with
+/// weights of shape `[d_input, d_hidden]` and `[d_hidden, d_hidden]`. Weights exported by
+/// PyTorch (`weight_ih_l0`, `weight_hh_l0`) or ONNX (`W`, `R`) are stacked along the first
+/// dimension and must be split per gate and transposed before being loaded in the record.
 #[derive(Module, Debug)]
 pub struct Lstm<B: Backend> {
     input_gate: GateController<B>,
     forget_gate: GateController<B>,
     output_gate: GateController<B>,
     cell_gate: GateController<B>,
-    batch_size: usize,
     d_hidden: usize,
 }
 
@@ -73,7 +75,6 @@ impl LstmConfig {
             forget_gate,
             output_gate,
             cell_gate,
-            batch_size: self.batch_size,
             d_hidden: self.d_hidden,
         }
     }
@@ -101,7 +102,6 @@ impl LstmConfig {
                 record.output_gate,
             ),
             cell_gate: gate_controller::GateController::new_with(&linear_config, record.cell_gate),
-            batch_size: self.batch_size,
             d_hidden: self.d_hidden,
         }
     }
@@ -123,19 +123,22 @@ impl<B: Backend> Lstm<B> {
     ///     the second tensor represents the hidden states for each sequence element.
     ///     Both output tensors have the shape [batch_size, sequence_length, hidden_size].
     pub fn forward(
-        &mut self,
+        &self,
         batched_input: Tensor<B, 3>,
         state: Option<(Tensor<B, 2>, Tensor<B, 2>)>,
     ) -> (Tensor<B, 3>, Tensor<B, 3>) {
-        let seq_length = batched_input.shape().dims[1];
-        let mut batched_cell_state = Tensor::zeros([self.batch_size, seq_length, self.d_hidden]);
-        let mut batched_hidden_state = Tensor::zeros([self.batch_size, seq_length, self.d_hidden]);
+        let [batch_size, seq_length, _] = batched_input.shape().dims;
+        let device = batched_input.device();
+        let mut batched_cell_state =
+            Tensor::zeros_device([batch_size, seq_length, self.d_hidden], &device);
+        let mut batched_hidden_state =
+            Tensor::zeros_device([batch_size, seq_length, self.d_hidden], &device);
 
         let (mut cell_state, mut hidden_state) = match state {
             Some((cell_state, hidden_state)) => (cell_state, hidden_state),
             None => (
-                Tensor::zeros([self.batch_size, self.d_hidden]),
-                Tensor::zeros([self.batch_size, self.d_hidden]),
+                Tensor::zeros_device([batch_size, self.d_hidden], &device),
+                Tensor::zeros_device([batch_size, self.d_hidden], &device),
             ),
         };
 
@@ -143,19 +146,19 @@ impl<B: Backend> Lstm<B> {
             let indices = Tensor::arange(t..t + 1);
             let input_t = batched_input.clone().select(1, indices).squeeze(1);
             // f(orget)g(ate) tensors
-            let biased_fg_input_sum = self.gate_product(&input_t, &hidden_state, &self.forget_gate);
+            let biased_fg_input_sum = self.forget_gate.gate_product(&input_t, &hidden_state);
             let forget_values = activation::sigmoid(biased_fg_input_sum); // to multiply with cell state
 
             // i(nput)g(ate) tensors
-            let biased_ig_input_sum = self.gate_product(&input_t, &hidden_state, &self.input_gate);
+            let biased_ig_input_sum = self.input_gate.gate_product(&input_t, &hidden_state);
             let add_values = activation::sigmoid(biased_ig_input_sum);
 
             // o(output)g(ate) tensors
-            let biased_og_input_sum = self.gate_product(&input_t, &hidden_state, &self.output_gate);
+            let biased_og_input_sum = self.output_gate.gate_product(&input_t, &hidden_state);
             let output_values = activation::sigmoid(biased_og_input_sum);
 
             // c(ell)g(ate) tensors
-            let biased_cg_input_sum = self.gate_product(&input_t, &hidden_state, &self.cell_gate);
+            let biased_cg_input_sum = self.cell_gate.gate_product(&input_t, &hidden_state);
             let candidate_cell_values = biased_cg_input_sum.tanh();
 
             cell_state = forget_values * cell_state.clone() + add_values * candidate_cell_values;
@@ -163,69 +166,30 @@ impl<B: Backend> Lstm<B> {
 
             // store the state for this timestep
             batched_cell_state = batched_cell_state.slice_assign(
-                [0..self.batch_size, t..(t + 1), 0..self.d_hidden],
-                cell_state.clone().unsqueeze(),
+                [0..batch_size, t..(t + 1), 0..self.d_hidden],
+                cell_state.clone().reshape([batch_size, 1, self.d_hidden]),
             );
             batched_hidden_state = batched_hidden_state.slice_assign(
-                [0..self.batch_size, t..(t + 1), 0..self.d_hidden],
-                hidden_state.clone().unsqueeze(),
+                [0..batch_size, t..(t + 1), 0..self.d_hidden],
+                hidden_state.clone().reshape([batch_size, 1, self.d_hidden]),
             );
         }
 
         (batched_cell_state, batched_hidden_state)
     }
-
-    /// Helper function for performing weighted matrix product for a gate and adds
-    /// bias, if any.
-    ///
-    ///  Mathematically, performs `Wx*X + Wh*H + b`, where:
-    ///     Wx = weight matrix for the connection to input vector X
-    ///     Wh = weight matrix for the connection to hidden state H
-    ///     X = input vector
-    ///     H = hidden state
-    ///     b = bias terms
-    fn gate_product(
-        &self,
-        input: &Tensor<B, 2>,
-        hidden: &Tensor<B, 2>,
-        gate: &GateController<B>,
-    ) -> Tensor<B, 2> {
-        let input_product = input.clone().matmul(gate.input_transform.weight.val());
-        let hidden_product = hidden.clone().matmul(gate.hidden_transform.weight.val());
-
-        let input_bias = gate
-            .input_transform
-            .bias
-            .as_ref()
-            .map(|bias_param| bias_param.val());
-        let hidden_bias = gate
-            .hidden_transform
-            .bias
-            .as_ref()
-            .map(|bias_param| bias_param.val());
-
-        match (input_bias, hidden_bias) {
-            (Some(input_bias), Some(hidden_bias)) => {
-                input_product + input_bias.unsqueeze() + hidden_product + hidden_bias.unsqueeze()
-            }
-            (Some(input_bias), None) => input_product + input_bias.unsqueeze() + hidden_product,
-            (None, Some(hidden_bias)) => input_product + hidden_product + hidden_bias.unsqueeze(),
-            (None, None) => input_product + hidden_product,
-        }
-    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{module::Param, nn::LinearRecord, TestBackend};
-    use burn_tensor::Data;
+    use burn_tensor::{Data, Distribution};
 
     #[test]
     fn test_with_uniform_initializer() {
         TestBackend::seed(0);
 
-        let config = LstmConfig::new(5, 5, false, 2)
+        let config = LstmConfig::new(5, 5, false)
             .with_initializer(Initializer::Uniform { min: 0.0, max: 1.0 });
         let lstm = config.init::<TestBackend>();
 
@@ -250,7 +214,7 @@ mod tests {
     #[test]
     fn test_forward_single_input_single_feature() {
         TestBackend::seed(0);
-        let config = LstmConfig::new(1, 1, false, 1);
+        let config = LstmConfig::new(1, 1, false);
         let mut lstm = config.init::<TestBackend>();
 
         fn create_gate_controller(
@@ -323,4 +287,30 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::from([[0.024]]), 3)
     }
+
+    #[test]
+    fn test_batched_forward_pass() {
+        let lstm = LstmConfig::new(64, 1024, true).init::<TestBackend>();
+        let batched_input = Tensor::<TestBackend, 3>::random([8, 10, 64], Distribution::Default);
+
+        let (cell_state, hidden_state) = lstm.forward(batched_input, None);
+
+        assert_eq!(cell_state.shape().dims, [8, 10, 1024]);
+        assert_eq!(hidden_state.shape().dims, [8, 10, 1024]);
+    }
+
+    #[test]
+    fn test_forward_with_initial_state() {
+        let lstm = LstmConfig::new(4, 3, true).init::<TestBackend>();
+        let batched_input = Tensor::<TestBackend, 3>::random([2, 5, 4], Distribution::Default);
+        let state = (
+            Tensor::<TestBackend, 2>::random([2, 3], Distribution::Default),
+            Tensor::<TestBackend, 2>::random([2, 3], Distribution::Default),
+        );
+
+        let (cell_state, hidden_state) = lstm.forward(batched_input, Some(state));
+
+        assert_eq!(cell_state.shape().dims, [2, 5, 3]);
+        assert_eq!(hidden_state.shape().dims, [2, 5, 3]);
+    }
 }
@@ -0,0 +1,427 @@
+use crate as burn;
+
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+use crate::module::{ADModule, Module, ModuleMapper, ModuleVisitor};
+use crate::nn::{
+    Dropout, DropoutConfig, GELUTanh, HardSigmoid, HardSwish, LayerNorm, LayerNormConfig,
+    LeakyReLU, LeakyReLUConfig, Linear, LinearConfig, Mish, PReLU, PReLUConfig, ReLU, SiLU, GELU,
+};
+use crate::record::{PrecisionSettings, Record};
+use crate::tensor::backend::{ADBackend, Backend};
+use crate::tensor::Tensor;
+
+/// Configuration to create a [layer](SequentialLayer) of a [Sequential](Sequential) container.
+#[derive(Config, Debug)]
+pub enum SequentialLayerConfig {
+    /// A [linear](Linear) layer.
+    Linear(LinearConfig),
+    /// A [layer norm](LayerNorm) layer.
+    LayerNorm(LayerNormConfig),
+    /// A [dropout](Dropout) layer.
+    Dropout(DropoutConfig),
+    /// A [relu](ReLU) activation.
+    ReLU,
+    /// A [leaky relu](LeakyReLU) activation.
+    LeakyReLU(LeakyReLUConfig),
+    /// A [prelu](PReLU) activation.
+    PReLU(PReLUConfig),
+    /// A [gelu](GELU) activation.
+    GELU,
+    /// A [gelu](GELUTanh) activation using the tanh approximation.
+    GELUTanh,
+    /// A [silu](SiLU) activation.
+    SiLU,
+    /// A [mish](Mish) activation.
+    Mish,
+    /// A [hard sigmoid](HardSigmoid) activation.
+    HardSigmoid,
+    /// A [hard swish](HardSwish) activation.
+    HardSwish,
+}
+
+/// Configuration to create a [Sequential](Sequential) container.
+#[derive(Config, Debug)]
+pub struct SequentialConfig {
+    /// The configurations of the layers, in the order they are applied.
+    pub layers: Vec<SequentialLayerConfig>,
+}
+
+/// Chain of layers applied one after the other.
+///
+/// The layers are stored in a [Vec](Vec), so a simple feed-forward stack can be built at runtime,
+/// from a [config](SequentialConfig) or from an imported graph for instance, without declaring a
+/// new module struct. Each layer must keep the shape of its input rank.
+#[derive(Module, Debug)]
+pub struct Sequential<B: Backend> {
+    layers: Vec<SequentialLayer<B>>,
+}
+
+impl SequentialLayerConfig {
+    /// Initialize a new [layer](SequentialLayer).
+    pub fn init<B: Backend>(&self) -> SequentialLayer<B> {
+        match self {
+            Self::Linear(config) => SequentialLayer::Linear(config.init()),
+            Self::LayerNorm(config) => SequentialLayer::LayerNorm(config.init()),
+            Self::Dropout(config) => SequentialLayer::Dropout(config.init()),
+            Self::ReLU => SequentialLayer::ReLU(ReLU::new()),
+            Self::LeakyReLU(config) => SequentialLayer::LeakyReLU(config.init()),
+            Self::PReLU(config) => SequentialLayer::PReLU(config.init()),
+            Self::GELU => SequentialLayer::GELU(GELU::new()),
+            Self::GELUTanh => SequentialLayer::GELUTanh(GELUTanh::new()),
+            Self::SiLU => SequentialLayer::SiLU(SiLU::new()),
+            Self::Mish => SequentialLayer::Mish(Mish::new()),
+            Self::HardSigmoid => SequentialLayer::HardSigmoid(HardSigmoid::new()),
+            Self::HardSwish => SequentialLayer::HardSwish(HardSwish::new()),
+        }
+    }
+}
+
+impl SequentialConfig {
+    /// Initialize a new [sequential](Sequential) container.
+    pub fn init<B: Backend>(&self) -> Sequential<B> {
+        Sequential {
+            layers: self.layers.iter().map(|layer| layer.init()).collect(),
+        }
+    }
+
+    /// Initialize a new [sequential](Sequential) container with a [record](SequentialRecord).
+    pub fn init_with<B: Backend>(&self, record: SequentialRecord<B>) -> Sequential<B> {
+        self.init().load_record(record)
+    }
+}
+
+impl<B: Backend> Sequential<B> {
+    /// Create an empty container.
+    pub fn new() -> Self {
+        Self { layers: Vec::new() }
+    }
+
+    /// Append a layer at the end of the container.
+    pub fn add_layer<L: Into<SequentialLayer<B>>>(mut self, layer: L) -> Self {
+        self.layers.push(layer.into());
+        self
+    }
+
+    /// The number of layers in the container.
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// If the container has no layer.
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    /// The layers of the container, in the order they are applied.
+    pub fn layers(&self) -> &[SequentialLayer<B>] {
+        &self.layers
+    }
+
+    /// Applies the forward pass of every layer on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any]`
+    /// - output: `[..., any]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        self.layers
+            .iter()
+            .fold(input, |input, layer| layer.forward(input))
+    }
+
+    /// Applies the forward pass of every layer on the input tensor, calling the hook after each
+    /// layer with its index, the layer, its input and its output.
+    ///
+    /// This can be used to inspect or capture the intermediate activations, for feature
+    /// extraction or debugging. The tensors are still part of the autodiff graph when using an
+    /// autodiff backend.
+    ///
+    /// # Notes
+    ///
+    /// The hook only lives for this forward pass. Hooks can also be registered on any submodule
+    /// of a custom module by wrapping it in a [Hooked](crate::module::Hooked) module.
+    pub fn forward_with_hook<const D: usize, F>(
+        &self,
+        input: Tensor<B, D>,
+        mut hook: F,
+    ) -> Tensor<B, D>
+    where
+        F: FnMut(usize, &SequentialLayer<B>, &Tensor<B, D>, &Tensor<B, D>),
+    {
+        self.layers
+            .iter()
+            .enumerate()
+            .fold(input, |input, (index, layer)| {
+                let output = layer.forward(input.clone());
+                hook(index, layer, &input, &output);
+                output
+            })
+    }
+
+    /// Applies the forward pass of every layer on the input tensor and returns the output of
+    /// each layer, the last one being the output of the container.
+    pub fn forward_activations<const D: usize>(&self, input: Tensor<B, D>) -> Vec<Tensor<B, D>> {
+        let mut activations = Vec::with_capacity(self.layers.len());
+
+        self.forward_with_hook(input, |_, _, _, output| activations.push(output.clone()));
+
+        activations
+    }
+}
+
+impl<B: Backend> Default for Sequential<B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+macro_rules! sequential_layers {
+    ($($variant:ident($module:ident $(<$backend:ident>)?)),* $(,)?) => {
+        /// A layer of a [Sequential](Sequential) container.
+        #[derive(Debug, Clone)]
+        #[allow(missing_docs)]
+        pub enum SequentialLayer<B: Backend> {
+            $($variant($module $(<$backend>)?)),*
+        }
+
+        /// The record type of a [layer](SequentialLayer).
+        #[derive(Debug, Clone)]
+        #[allow(missing_docs)]
+        pub enum SequentialLayerRecord<B: Backend> {
+            $($variant(<$module $(<$backend>)? as Module<B>>::Record)),*
+        }
+
+        /// The record item type of a [layer](SequentialLayer).
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        #[serde(bound = "")]
+        #[allow(missing_docs)]
+        pub enum SequentialLayerRecordItem<B: Backend, S: PrecisionSettings> {
+            $($variant(<<$module $(<$backend>)? as Module<B>>::Record as Record>::Item<S>)),*
+        }
+
+        impl<B: Backend> SequentialLayer<B> {
+            /// Applies the forward pass on the input tensor.
+            pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+                match self {
+                    $(Self::$variant(module) => module.forward(input)),*
+                }
+            }
+        }
+
+        impl<B: Backend> Module<B> for SequentialLayer<B> {
+            type Record = SequentialLayerRecord<B>;
+
+            fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
+                match self {
+                    $(Self::$variant(module) => Module::<B>::visit(module, visitor)),*
+                }
+            }
+
+            fn map<M: ModuleMapper<B>>(self, mapper: &mut M) -> Self {
+                match self {
+                    $(Self::$variant(module) => Self::$variant(Module::<B>::map(module, mapper))),*
+                }
+            }
+
+            fn load_record(self, record: Self::Record) -> Self {
+                match (self, record) {
+                    $((Self::$variant(module), SequentialLayerRecord::$variant(record)) => {
+                        Self::$variant(Module::<B>::load_record(module, record))
+                    })*
+                    (layer, _) => panic!("The record doesn't match the layer {layer:?}"),
+                }
+            }
+
+            fn into_record(self) -> Self::Record {
+                match self {
+                    $(Self::$variant(module) => {
+                        SequentialLayerRecord::$variant(Module::<B>::into_record(module))
+                    })*
+                }
+            }
+        }
+
+        impl<B: ADBackend> ADModule<B> for SequentialLayer<B> {
+            type InnerModule = SequentialLayer<B::InnerBackend>;
+
+            fn valid(&self) -> Self::InnerModule {
+                match self {
+                    $(Self::$variant(module) => SequentialLayer::$variant(ADModule::<B>::valid(module))),*
+                }
+            }
+        }
+
+        impl<B: Backend> Record for SequentialLayerRecord<B> {
+            type Item<S: PrecisionSettings> = SequentialLayerRecordItem<B, S>;
+
+            fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+                match self {
+                    $(Self::$variant(record) => SequentialLayerRecordItem::$variant(record.into_item::<S>())),*
+                }
+            }
+
+            fn from_item<S: PrecisionSettings>(item: Self::Item<S>) -> Self {
+                match item {
+                    $(SequentialLayerRecordItem::$variant(item) => Self::$variant(Record::from_item::<S>(item))),*
+                }
+            }
+        }
+
+        $(
+            impl<B: Backend> From<$module $(<$backend>)?> for SequentialLayer<B> {
+                fn from(module: $module $(<$backend>)?) -> Self {
+                    Self::$variant(module)
+                }
+            }
+        )*
+    };
+}
+
+sequential_layers!(
+    Linear(Linear<B>),
+    LayerNorm(LayerNorm<B>),
+    Dropout(Dropout),
+    ReLU(ReLU),
+    LeakyReLU(LeakyReLU),
+    PReLU(PReLU<B>),
+    GELU(GELU),
+    GELUTanh(GELUTanh),
+    SiLU(SiLU),
+    Mish(Mish),
+    HardSigmoid(HardSigmoid),
+    HardSwish(HardSwish),
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::{BinBytesRecorder, FullPrecisionSettings, Recorder};
+    use crate::TestBackend;
+    use burn_tensor::{Data, Shape};
+
+    fn config() -> SequentialConfig {
+        SequentialConfig::new(alloc::vec![
+            SequentialLayerConfig::Linear(LinearConfig::new(4, 8)),
+            SequentialLayerConfig::ReLU,
+            SequentialLayerConfig::Dropout(DropoutConfig::new(0.1)),
+            SequentialLayerConfig::Linear(LinearConfig::new(8, 2)),
+            SequentialLayerConfig::GELU,
+        ])
+    }
+
+    fn model() -> Sequential<TestBackend> {
+        let linear = LinearConfig::new(2, 2)
+            .with_bias(false)
+            .init_with(crate::nn::LinearRecord {
+                weight: crate::module::Param::from(Tensor::from_floats([[1.0, 0.0], [0.0, -1.0]])),
+                bias: None,
+            });
+
+        Sequential::new()
+            .add_layer(linear)
+            .add_layer(ReLU::new())
+            .add_layer(LeakyReLUConfig::new().with_negative_slope(0.5).init())
+    }
+
+    #[test]
+    fn sequential_forward_should_apply_layers_in_order() {
+        let model = model();
+        let input = Tensor::from_floats([[1.0, 2.0], [-3.0, -4.0]]);
+
+        let output = model.forward(input);
+
+        assert_eq!(model.len(), 3);
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 0.0], [0.0, 4.0]]), 3);
+    }
+
+    #[test]
+    fn sequential_forward_activations_should_capture_each_layer_output() {
+        let model = model();
+        let input = Tensor::from_floats([[-1.0, -2.0]]);
+
+        let activations = model.forward_activations(input);
+
+        assert_eq!(activations.len(), 3);
+        activations[0]
+            .to_data()
+            .assert_approx_eq(&Data::from([[-1.0, 2.0]]), 3);
+        activations[1]
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0, 2.0]]), 3);
+        activations[2]
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0, 2.0]]), 3);
+    }
+
+    #[test]
+    fn sequential_forward_with_hook_should_receive_layer_inputs() {
+        let model = model();
+        let input = Tensor::from_floats([[-1.0, 2.0]]);
+        let mut inputs = Vec::new();
+        let mut linear_layers = 0;
+
+        let output = model.forward_with_hook(input, |index, layer, input, _| {
+            if let SequentialLayer::Linear(_) = layer {
+                linear_layers += 1;
+            }
+            inputs.push((index, input.to_data()));
+        });
+
+        assert_eq!(linear_layers, 1);
+        assert_eq!(inputs.len(), 3);
+        assert_eq!(inputs[0].0, 0);
+        inputs[0].1.assert_approx_eq(&Data::from([[-1.0, 2.0]]), 3);
+        inputs[1].1.assert_approx_eq(&Data::from([[-1.0, -2.0]]), 3);
+        inputs[2].1.assert_approx_eq(&Data::from([[0.0, 0.0]]), 3);
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0, 0.0]]), 3);
+    }
+
+    #[test]
+    fn sequential_from_config() {
+        let model = config().init::<TestBackend>();
+
+        let output = model.forward(Tensor::<TestBackend, 3>::ones(Shape::new([3, 5, 4])));
+
+        assert_eq!(model.len(), 5);
+        assert_eq!(model.num_params(), 4 * 8 + 8 + 8 * 2 + 2);
+        assert_eq!(output.shape(), Shape::new([3, 5, 2]));
+    }
+
+    #[test]
+    fn sequential_record_round_trip() {
+        let config = config();
+        let model = config.init::<TestBackend>();
+        let input = Tensor::<TestBackend, 2>::ones(Shape::new([3, 4]));
+
+        let bytes = BinBytesRecorder::<FullPrecisionSettings>::default()
+            .record(model.clone().into_record(), ())
+            .unwrap();
+        let record = BinBytesRecorder::<FullPrecisionSettings>::default()
+            .load(bytes)
+            .unwrap();
+        let model_loaded = config.init_with::<TestBackend>(record);
+
+        model
+            .forward(input.clone())
+            .into_data()
+            .assert_approx_eq(&model_loaded.forward(input).into_data(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn sequential_load_record_with_mismatched_layers() {
+        let model = config().init::<TestBackend>();
+        let other = SequentialConfig::new(alloc::vec![SequentialLayerConfig::SiLU])
+            .init::<TestBackend>()
+            .into_record();
+
+        let _ = model.load_record(other);
+    }
+}
@@ -0,0 +1,434 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Module, RunningState};
+use crate::nn::conv::{Conv1d, Conv2d};
+use crate::nn::Linear;
+use crate::tensor::backend::Backend;
+use crate::tensor::{Distribution, ElementConversion, Tensor};
+
+/// Configuration to apply [spectral normalization](SpectralNorm) on the weight of a module.
+#[derive(Config, Debug)]
+pub struct SpectralNormConfig {
+    /// The number of power iterations used to estimate the spectral norm at each forward pass
+    /// during training. Default: 1
+    #[config(default = 1)]
+    pub n_power_iterations: usize,
+    /// A value required for numerical stability. Default: 1e-12
+    #[config(default = 1e-12)]
+    pub epsilon: f64,
+}
+
+/// Spectral normalization of a weight tensor as described in the paper
+/// [Spectral Normalization for Generative Adversarial Networks](https://arxiv.org/abs/1802.05957).
+///
+/// The weight is divided by its largest singular value, which is estimated with the power
+/// iteration method. The left singular vector `u` is a non-trainable buffer that is refined at
+/// each forward pass during training and saved along with the module record.
+///
+/// By default, the module is in training mode when the backend has autodiff enabled and in
+/// inference mode otherwise. Use [train](SpectralNorm::train) and [eval](SpectralNorm::eval) to
+/// select the mode explicitly. The module returned by [valid](crate::module::ADModule::valid)
+/// is back to the default, and is thus in inference mode.
+#[derive(Module, Debug)]
+pub struct SpectralNorm<B: Backend> {
+    u: RunningState<Tensor<B, 1>>,
+    dim: usize,
+    n_power_iterations: usize,
+    epsilon: f64,
+    #[module(mode)]
+    training: Option<bool>,
+}
+
+/// [Linear](Linear) layer with a [spectrally normalized](SpectralNorm) weight.
+#[derive(Module, Debug)]
+pub struct SpectralNormLinear<B: Backend> {
+    linear: Linear<B>,
+    norm: SpectralNorm<B>,
+}
+
+/// [Conv1d](Conv1d) layer with a [spectrally normalized](SpectralNorm) weight.
+#[derive(Module, Debug)]
+pub struct SpectralNormConv1d<B: Backend> {
+    conv: Conv1d<B>,
+    norm: SpectralNorm<B>,
+}
+
+/// [Conv2d](Conv2d) layer with a [spectrally normalized](SpectralNorm) weight.
+#[derive(Module, Debug)]
+pub struct SpectralNormConv2d<B: Backend> {
+    conv: Conv2d<B>,
+    norm: SpectralNorm<B>,
+}
+
+impl SpectralNormConfig {
+    /// Initialize a new [spectral norm](SpectralNorm) for a weight where the output features
+    /// are on the dimension `dim`.
+    pub fn init<B: Backend>(&self, num_features: usize, dim: usize) -> SpectralNorm<B> {
+        let u = Tensor::random([num_features, 1], Distribution::Normal(0.0, 1.0));
+        let u = l2_normalize(u, self.epsilon).reshape([num_features]);
+
+        SpectralNorm {
+            u: RunningState::new(u),
+            dim,
+            n_power_iterations: self.n_power_iterations,
+            epsilon: self.epsilon,
+            training: None,
+        }
+    }
+
+    /// Apply spectral normalization on the weight of a [linear](Linear) module.
+    pub fn init_linear<B: Backend>(&self, linear: Linear<B>) -> SpectralNormLinear<B> {
+        // The weight has the shape [d_input, d_output].
+        let [_d_input, d_output] = linear.weight.dims();
+        let norm = self.init(d_output, 1);
+
+        SpectralNormLinear { linear, norm }
+    }
+
+    /// Apply spectral normalization on the weight of a [conv1d](Conv1d) module.
+    pub fn init_conv1d<B: Backend>(&self, conv: Conv1d<B>) -> SpectralNormConv1d<B> {
+        let [channels_out, _, _] = conv.weight.dims();
+        let norm = self.init(channels_out, 0);
+
+        SpectralNormConv1d { conv, norm }
+    }
+
+    /// Apply spectral normalization on the weight of a [conv2d](Conv2d) module.
+    pub fn init_conv2d<B: Backend>(&self, conv: Conv2d<B>) -> SpectralNormConv2d<B> {
+        let [channels_out, _, _, _] = conv.weight.dims();
+        let norm = self.init(channels_out, 0);
+
+        SpectralNormConv2d { conv, norm }
+    }
+}
+
+impl<B: Backend> SpectralNorm<B> {
+    /// Set the module in training mode, where the estimation of the singular vectors is refined
+    /// at each forward pass.
+    pub fn train(mut self) -> Self {
+        self.training = Some(true);
+        self
+    }
+
+    /// Set the module in inference mode, where the estimated singular vectors are kept.
+    pub fn eval(mut self) -> Self {
+        self.training = Some(false);
+        self
+    }
+
+    /// Returns if the module is in training mode.
+    pub fn is_training(&self) -> bool {
+        self.training.unwrap_or_else(B::ad_enabled)
+    }
+
+    /// Divide the weight by its spectral norm.
+    ///
+    /// During training, the estimation of the singular vectors is refined with
+    /// `n_power_iterations` steps of the power iteration method.
+    pub fn normalize<const D: usize>(&self, weight: Tensor<B, D>) -> Tensor<B, D> {
+        let dims = weight.dims();
+        let height = dims[self.dim];
+        let width = dims.iter().product::<usize>() / height;
+
+        let matrix = weight
+            .clone()
+            .swap_dims(0, self.dim)
+            .reshape([height, width]);
+        let matrix_detached = matrix.clone().detach();
+
+        let n_power_iterations = match self.is_training() {
+            true => self.n_power_iterations,
+            false => 0,
+        };
+        let mut u = match n_power_iterations {
+            0 => self.u.value(),
+            _ => self.u.value_sync(),
+        }
+        .reshape([height, 1]);
+        let mut v = l2_normalize(
+            matrix_detached.clone().transpose().matmul(u.clone()),
+            self.epsilon,
+        );
+
+        for _ in 0..n_power_iterations {
+            u = l2_normalize(matrix_detached.clone().matmul(v), self.epsilon);
+            v = l2_normalize(
+                matrix_detached.clone().transpose().matmul(u.clone()),
+                self.epsilon,
+            );
+        }
+
+        if n_power_iterations > 0 {
+            self.u.update(u.clone().reshape([height]).detach());
+        }
+
+        let sigma = u.transpose().matmul(matrix.matmul(v));
+
+        weight.div(sigma.reshape([1; D]))
+    }
+}
+
+impl<B: Backend> SpectralNormLinear<B> {
+    /// Set the [spectral norm](SpectralNorm::train) in training mode.
+    pub fn train(mut self) -> Self {
+        self.norm = self.norm.train();
+        self
+    }
+
+    /// Set the [spectral norm](SpectralNorm::eval) in inference mode.
+    pub fn eval(mut self) -> Self {
+        self.norm = self.norm.eval();
+        self
+    }
+
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any, d_input]`
+    /// - output: `[..., any, d_output]`
+    pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let weight = self.norm.normalize(self.linear.weight.val());
+
+        self.linear.forward_with_weight(input, weight)
+    }
+}
+
+impl<B: Backend> SpectralNormConv1d<B> {
+    /// Set the [spectral norm](SpectralNorm::train) in training mode.
+    pub fn train(mut self) -> Self {
+        self.norm = self.norm.train();
+        self
+    }
+
+    /// Set the [spectral norm](SpectralNorm::eval) in inference mode.
+    pub fn eval(mut self) -> Self {
+        self.norm = self.norm.eval();
+        self
+    }
+
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: [batch_size, channels_in, length_in],
+    /// - output: [batch_size, channels_out, length_out],
+    pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        let weight = self.norm.normalize(self.conv.weight.val());
+
+        self.conv.forward_with_weight(input, weight)
+    }
+}
+
+impl<B: Backend> SpectralNormConv2d<B> {
+    /// Set the [spectral norm](SpectralNorm::train) in training mode.
+    pub fn train(mut self) -> Self {
+        self.norm = self.norm.train();
+        self
+    }
+
+    /// Set the [spectral norm](SpectralNorm::eval) in inference mode.
+    pub fn eval(mut self) -> Self {
+        self.norm = self.norm.eval();
+        self
+    }
+
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: [batch_size, channels_in, height_in, width_in],
+    /// - output: [batch_size, channels_out, height_out, width_out],
+    pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let weight = self.norm.normalize(self.conv.weight.val());
+
+        self.conv.forward_with_weight(input, weight)
+    }
+}
+
+fn l2_normalize<B: Backend>(tensor: Tensor<B, 2>, epsilon: f64) -> Tensor<B, 2> {
+    let norm = tensor
+        .clone()
+        .powf(2.0)
+        .sum()
+        .sqrt()
+        .clamp_min(epsilon.elem())
+        .reshape([1, 1]);
+
+    tensor.div(norm)
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::{ADModule, Hooked, Param};
+    use crate::nn::conv::Conv2dConfig;
+    use crate::nn::{LinearConfig, LinearRecord};
+    use crate::{TestADBackend, TestBackend};
+    use alloc::sync::Arc;
+    use alloc::vec::Vec;
+    use burn_tensor::Data;
+
+    #[test]
+    fn spectral_norm_linear_weight_has_unit_spectral_norm() {
+        TestADBackend::seed(0);
+
+        let linear = LinearConfig::new(2, 2).init_with(LinearRecord {
+            weight: Param::from(Tensor::from_data(Data::from([[3.0, 0.0], [0.0, 1.0]]))),
+            bias: None,
+        });
+        let module = SpectralNormConfig::new()
+            .with_n_power_iterations(20)
+            .init_linear::<TestADBackend>(linear);
+
+        let output = module.forward(Tensor::from_data(Data::from([[1.0, 1.0]])));
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 0.3333]]), 3);
+    }
+
+    #[test]
+    fn spectral_norm_u_is_updated_and_recorded() {
+        TestADBackend::seed(0);
+
+        let linear = LinearConfig::new(2, 2).init_with(LinearRecord {
+            weight: Param::from(Tensor::from_data(Data::from([[3.0, 0.0], [0.0, 1.0]]))),
+            bias: None,
+        });
+        let module = SpectralNormConfig::new()
+            .with_n_power_iterations(20)
+            .init_linear::<TestADBackend>(linear);
+
+        module.forward(Tensor::<TestADBackend, 2>::from_data(Data::from([[
+            1.0, 1.0,
+        ]])));
+
+        let u = module.valid().into_record().norm.u.val().abs();
+        u.into_data().assert_approx_eq(&Data::from([1.0, 0.0]), 3);
+    }
+
+    #[test]
+    fn spectral_norm_eval_keeps_u() {
+        TestADBackend::seed(0);
+
+        let linear = LinearConfig::new(2, 2).init_with(LinearRecord {
+            weight: Param::from(Tensor::from_data(Data::from([[3.0, 0.0], [0.0, 1.0]]))),
+            bias: None,
+        });
+        let module = SpectralNormConfig::new()
+            .init_linear::<TestADBackend>(linear)
+            .eval();
+        let u = module.norm.u.value_sync();
+
+        module.forward(Tensor::<TestADBackend, 2>::from_data(Data::from([[
+            1.0, 1.0,
+        ]])));
+
+        module
+            .norm
+            .u
+            .value_sync()
+            .into_data()
+            .assert_approx_eq(&u.into_data(), 3);
+    }
+
+    #[test]
+    fn spectral_norm_train_updates_u_without_autodiff() {
+        TestBackend::seed(0);
+
+        let linear = LinearConfig::new(2, 2).init_with(LinearRecord {
+            weight: Param::from(Tensor::from_data(Data::from([[3.0, 0.0], [0.0, 1.0]]))),
+            bias: None,
+        });
+        let module = SpectralNormConfig::new()
+            .with_n_power_iterations(20)
+            .init_linear::<TestBackend>(linear)
+            .train();
+
+        module.forward(Tensor::from_data(Data::from([[1.0, 1.0]])));
+
+        let u = module.into_record().norm.u.val().abs();
+        u.into_data().assert_approx_eq(&Data::from([1.0, 0.0]), 3);
+    }
+
+    #[test]
+    fn spectral_norm_valid_is_in_inference_mode() {
+        let linear = LinearConfig::new(2, 2).init();
+        let module = SpectralNormConfig::new()
+            .init_linear::<TestADBackend>(linear)
+            .train();
+
+        assert!(module.norm.is_training());
+        assert!(!module.valid().norm.is_training());
+    }
+
+    #[test]
+    fn spectral_norm_linear_can_be_hooked() {
+        TestBackend::seed(0);
+
+        let outputs = Arc::new(spin::Mutex::new(Vec::new()));
+        let captured = outputs.clone();
+        let module = Hooked::new(
+            SpectralNormConfig::new().init_linear::<TestBackend>(LinearConfig::new(2, 3).init()),
+        )
+        .with_hook(
+            move |_: &Tensor<TestBackend, 2>, output: &Tensor<TestBackend, 2>| {
+                captured.lock().push(output.clone())
+            },
+        );
+        let input = Tensor::random([4, 2], Distribution::Default);
+
+        let output = module.forward_with(input.clone(), SpectralNormLinear::forward);
+
+        let outputs = outputs.lock();
+        assert_eq!(outputs.len(), 1);
+        outputs[0]
+            .clone()
+            .into_data()
+            .assert_approx_eq(&output.into_data(), 3);
+        outputs[0]
+            .clone()
+            .into_data()
+            .assert_approx_eq(&module.module().forward(input).into_data(), 3);
+    }
+
+    #[test]
+    fn spectral_norm_conv2d_forward() {
+        TestBackend::seed(0);
+
+        let conv = Conv2dConfig::new([2, 3], [3, 3]).init::<TestBackend>();
+        let module = SpectralNormConfig::new().init_conv2d(conv);
+
+        let output = module.forward(Tensor::random([1, 2, 5, 5], Distribution::Default));
+
+        assert_eq!(output.dims(), [1, 3, 3, 3]);
+    }
+
+    #[test]
+    fn spectral_norm_grads() {
+        TestADBackend::seed(0);
+
+        let linear = LinearConfig::new(2, 2).init_with(LinearRecord {
+            weight: Param::from(Tensor::from_data(Data::from([[3.0, 0.0], [0.0, 1.0]]))),
+            bias: None,
+        });
+        let module = SpectralNormConfig::new()
+            .with_n_power_iterations(20)
+            .init_linear::<TestADBackend>(linear);
+
+        let output = module.forward(Tensor::from_data(Data::from([[1.0, 1.0]])));
+        let grads = output.sum().backward();
+
+        // The spectral norm is w_00, so the output sum is (w_00 + w_01 + w_10 + w_11) / w_00.
+        module
+            .linear
+            .weight
+            .grad(&grads)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&Data::from([[-0.1111, 0.3333], [0.3333, 0.3333]]), 3);
+    }
+}
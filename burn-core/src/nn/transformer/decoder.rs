@@ -137,6 +137,7 @@ pub struct TransformerDecoderLayer<B: Backend> {
     norm_first: bool,
 }
 
+#[derive(Clone)]
 struct TransformerDecoderLayerAutoregressiveCache<B: Backend> {
     cross_attn: MhaCache<B>,
     self_attn: MhaCache<B>,
@@ -157,11 +158,23 @@ impl<B: Backend> TransformerDecoderLayerAutoregressiveCache<B> {
             norm_3: TensorCache::empty(),
         }
     }
+
+    fn into_device(self, device: &B::Device) -> Self {
+        Self {
+            cross_attn: self.cross_attn.to_device(device),
+            self_attn: self.self_attn.to_device(device),
+            pwff: self.pwff.to_device(device),
+            norm_1: self.norm_1.to_device(device),
+            norm_2: self.norm_2.to_device(device),
+            norm_3: self.norm_3.to_device(device),
+        }
+    }
 }
 
 /// Autoregressive cache for the [Transformer Decoder](TransformerDecoder) layer.
 ///
 /// To be used during inference when decoding tokens.
+#[derive(Clone)]
 pub struct TransformerDecoderAutoregressiveCache<B: Backend> {
     layers: Vec<TransformerDecoderLayerAutoregressiveCache<B>>,
 }
@@ -174,6 +187,17 @@ impl<B: Backend> TransformerDecoderAutoregressiveCache<B> {
                 .collect(),
         }
     }
+
+    /// Move the cached keys and values of every layer to the given device.
+    pub fn to_device(self, device: &B::Device) -> Self {
+        Self {
+            layers: self
+                .layers
+                .into_iter()
+                .map(|layer| layer.into_device(device))
+                .collect(),
+        }
+    }
 }
 
 impl<B: Backend> TransformerDecoderLayer<B> {
@@ -439,4 +463,41 @@ mod tests {
             .into_data()
             .assert_approx_eq(&output_2.into_data(), 3);
     }
+
+    #[test]
+    fn test_autoregressive_cache_clone_and_to_device() {
+        let [batch_size, seq_length, d_model, d_ff, n_heads, num_layers] = [2, 3, 12, 24, 2, 2];
+        TestBackend::seed(0);
+
+        let transformer = TransformerDecoderConfig::new(d_model, d_ff, n_heads, num_layers).init();
+        let memory = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+        );
+        let target = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+        );
+        let device = target.device();
+
+        let input_of = |i: usize| {
+            let mask_attn = generate_autoregressive_mask(batch_size, i, &device);
+            TransformerDecoderInput::new(
+                target.clone().slice([0..batch_size, 0..i, 0..d_model]),
+                memory.clone(),
+            )
+            .target_mask_attn(mask_attn)
+        };
+
+        let mut cache = transformer.new_autoregressive_cache();
+        transformer.forward_autoregressive_inference(input_of(1), &mut cache);
+        let mut cache_cloned = cache.clone().to_device(&device);
+
+        let output_1 = transformer.forward_autoregressive_inference(input_of(2), &mut cache);
+        let output_2 = transformer.forward_autoregressive_inference(input_of(2), &mut cache_cloned);
+
+        output_1
+            .into_data()
+            .assert_approx_eq(&output_2.into_data(), 3);
+    }
 }
@@ -285,6 +285,7 @@ impl<B: Backend> TransformerEncoderLayer<B> {
     }
 }
 
+#[derive(Clone)]
 struct TransformerEncoderLayerAutoregressiveCache<B: Backend> {
     mha: MhaCache<B>,
     pwff: TensorCache<B, 3>,
@@ -301,11 +302,21 @@ impl<B: Backend> TransformerEncoderLayerAutoregressiveCache<B> {
             norm_2: TensorCache::empty(),
         }
     }
+
+    fn into_device(self, device: &B::Device) -> Self {
+        Self {
+            mha: self.mha.to_device(device),
+            pwff: self.pwff.to_device(device),
+            norm_1: self.norm_1.to_device(device),
+            norm_2: self.norm_2.to_device(device),
+        }
+    }
 }
 
 /// Autoregressive cache for the [Transformer Encoder](TransformerEncoder) layer.
 ///
 /// To be used during inference when decoding tokens.
+#[derive(Clone)]
 pub struct TransformerEncoderAutoregressiveCache<B: Backend> {
     layers: Vec<TransformerEncoderLayerAutoregressiveCache<B>>,
 }
@@ -318,6 +329,17 @@ impl<B: Backend> TransformerEncoderAutoregressiveCache<B> {
                 .collect(),
         }
     }
+
+    /// Move the cached keys and values of every layer to the given device.
+    pub fn to_device(self, device: &B::Device) -> Self {
+        Self {
+            layers: self
+                .layers
+                .into_iter()
+                .map(|layer| layer.into_device(device))
+                .collect(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -16,4 +16,4 @@ mod file;
 #[cfg(feature = "std")]
 pub use file::*;
 
-pub use primitive::ParamSerde;
+pub use primitive::{ParamSerde, RngStateSerde};
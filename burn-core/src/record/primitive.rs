@@ -1,6 +1,7 @@
 use alloc::string::String;
 use alloc::string::ToString;
 use alloc::vec::Vec;
+use burn_common::rand::RngState;
 use burn_tensor::backend::Backend;
 use burn_tensor::Tensor;
 use serde::Deserialize;
@@ -92,6 +93,32 @@ impl<E: Element> Record for DataSerialize<E> {
     }
 }
 
+impl Record for RngState {
+    type Item<S: PrecisionSettings> = RngStateSerde;
+
+    fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+        RngStateSerde {
+            seed: self.seed,
+            word_pos: [(self.word_pos >> 64) as u64, self.word_pos as u64],
+        }
+    }
+
+    fn from_item<S: PrecisionSettings>(item: Self::Item<S>) -> Self {
+        RngState {
+            seed: item.seed,
+            word_pos: ((item.word_pos[0] as u128) << 64) | item.word_pos[1] as u128,
+        }
+    }
+}
+
+/// (De)serialize the state of the random number generator, with its position split in two words
+/// since not every format supports 128-bit integers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RngStateSerde {
+    seed: [u8; 32],
+    word_pos: [u64; 2],
+}
+
 /// (De)serialize parameters into a clean format.
 #[derive(new, Debug, Clone, Serialize, Deserialize)]
 pub struct ParamSerde<T> {
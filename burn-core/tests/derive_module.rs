@@ -37,9 +37,56 @@ impl<B: Backend> ModuleComposed<B> {
     }
 }
 
+#[derive(Module, Debug)]
+pub struct ModuleSkip<B: Backend> {
+    basic: ModuleBasic<B>,
+    #[module(skip)]
+    scale: usize,
+    #[module(mode)]
+    training: Option<bool>,
+}
+
 mod state {
     use super::*;
 
+    #[test]
+    fn should_keep_skipped_fields_when_loading_record() {
+        let module_1 = ModuleSkip::<TestBackend> {
+            basic: ModuleBasic::new(),
+            scale: 1,
+            training: None,
+        };
+        let module_2 = ModuleSkip::<TestBackend> {
+            basic: ModuleBasic::new(),
+            scale: 2,
+            training: Some(true),
+        };
+
+        let record: ModuleSkipRecord<TestBackend> = module_1.clone().into_record();
+        let module_2 = module_2.load_record(record);
+
+        assert_eq!(module_2.scale, 2);
+        assert_eq!(module_2.training, Some(true));
+        assert_eq!(
+            module_1.basic.weight_basic.to_data(),
+            module_2.basic.weight_basic.to_data()
+        );
+    }
+
+    #[test]
+    fn should_reset_mode_fields_when_validating() {
+        let module = ModuleSkip::<TestADBackend> {
+            basic: ModuleBasic::new(),
+            scale: 2,
+            training: Some(true),
+        };
+
+        let module = burn::module::ADModule::valid(&module);
+
+        assert_eq!(module.scale, 2);
+        assert_eq!(module.training, None);
+    }
+
     #[test]
     fn should_load_from_record_basic() {
         let module_1 = ModuleBasic::<TestBackend>::new();
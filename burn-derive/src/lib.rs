@@ -14,7 +14,31 @@ use module::module_derive_impl;
 use record::record_derive_impl;
 
 /// Derive macro for the module.
-#[proc_macro_derive(Module)]
+///
+/// Every field is a module, or a constant when it has no backend generic.
+///
+/// # Field attributes
+///
+/// - `#[module(skip)]`: the field isn't part of the record. It is cloned when the module is
+///   mapped or validated, and kept when a record is loaded. The field doesn't need to implement
+///   the module trait, e.g. a configuration value.
+/// - `#[module(mode)]`: the field is skipped the same way, but is reset to its default value by
+///   `ADModule::valid`, such as a train/eval switch that must not be
+///   carried over to the inference module.
+///
+/// Any other `module` attribute is a compile error.
+///
+/// ```rust,ignore
+/// #[derive(Module, Debug)]
+/// pub struct BatchNorm<B: Backend> {
+///     running_mean: RunningState<Tensor<B, 1>>,
+///     #[module(skip)]
+///     track_running_stats: bool,
+///     #[module(mode)]
+///     training: Option<bool>,
+/// }
+/// ```
+#[proc_macro_derive(Module, attributes(module))]
 pub fn module_derive(input: TokenStream) -> TokenStream {
     let input = syn::parse(input).unwrap();
     module_derive_impl(&input)
@@ -1,10 +1,18 @@
 use super::{generator::FnGenerator, record::ModuleRecordGenerator};
 use crate::module::display;
+use crate::shared::field::FieldTypeAnalyzer;
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::parse_quote;
 
+/// The flags supported by the `module` attribute of the fields.
+const FIELD_FLAGS: [&str; 2] = ["skip", "mode"];
+
 pub(crate) fn module_derive_impl(ast: &syn::DeriveInput) -> TokenStream {
+    if let Err(err) = check_field_attributes(ast) {
+        return err.to_compile_error().into();
+    }
+
     let name = &ast.ident;
     let has_backend = ast
         .generics
@@ -124,3 +132,77 @@ fn generics_names_except_backend(generics: &syn::Generics) -> proc_macro2::Token
 
     named
 }
+
+/// Check that the `module` attributes of the fields only hold a supported flag.
+fn check_field_attributes(ast: &syn::DeriveInput) -> Result<(), syn::Error> {
+    let fields = match &ast.data {
+        syn::Data::Struct(data) => &data.fields,
+        _ => return Ok(()),
+    };
+
+    for field in fields.iter() {
+        let field = FieldTypeAnalyzer::new(field.clone());
+
+        for attr in field.attributes().filter(|attr| attr.has_name("module")) {
+            let supported = attr
+                .flag()
+                .map(|flag| FIELD_FLAGS.iter().any(|supported| flag == supported))
+                .unwrap_or(false);
+
+            if !supported {
+                return Err(syn::Error::new_spanned(
+                    attr.attr(),
+                    format!(
+                        "Unsupported module attribute, expected one of: {}",
+                        FIELD_FLAGS
+                            .iter()
+                            .map(|flag| format!("#[module({flag})]"))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn supported_field_flags_should_be_accepted() {
+        let ast: syn::DeriveInput = parse_quote! {
+            struct Model<B: Backend> {
+                linear: Linear<B>,
+                #[module(skip)]
+                size: usize,
+                #[module(mode)]
+                training: Option<bool>,
+            }
+        };
+
+        assert!(check_field_attributes(&ast).is_ok());
+    }
+
+    #[test]
+    fn unknown_field_flags_should_be_rejected() {
+        let misspelled: syn::DeriveInput = parse_quote! {
+            struct Model<B: Backend> {
+                #[module(skp)]
+                size: usize,
+            }
+        };
+        let with_value: syn::DeriveInput = parse_quote! {
+            struct Model<B: Backend> {
+                #[module(skip = true)]
+                size: usize,
+            }
+        };
+
+        assert!(check_field_attributes(&misspelled).is_err());
+        assert!(check_field_attributes(&with_value).is_err());
+    }
+}
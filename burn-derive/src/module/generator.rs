@@ -49,11 +49,18 @@ impl FnGenerator {
     }
 
     pub fn gen_load_record_fn(&self) -> TokenStream {
-        let body = self.gen_fields_fn(|name| {
-            quote! {
-                #name: burn::module::Module::<B>::load_record(self.#name, record.#name),
-            }
-        });
+        let body = self.gen_all_fields_fn(
+            |name| {
+                quote! {
+                    #name: burn::module::Module::<B>::load_record(self.#name, record.#name),
+                }
+            },
+            |name| {
+                quote! {
+                    #name: self.#name,
+                }
+            },
+        );
 
         quote! {
             fn load_record(self, record: Self::Record) -> Self {
@@ -67,7 +74,9 @@ impl FnGenerator {
     pub fn gen_visit_fn(&self) -> TokenStream {
         let body = self.gen_fields_fn(|name| {
             quote! {
+                visitor.enter_module(stringify!(#name));
                 burn::module::Module::visit(&self.#name, visitor);
+                visitor.exit_module(stringify!(#name));
             }
         });
 
@@ -79,11 +88,18 @@ impl FnGenerator {
     }
 
     pub fn gen_map_fn(&self) -> TokenStream {
-        let (names, body) = self.gen_fields_fn_names(|name| {
-            quote! {
-                let #name = burn::module::Module::map(self.#name, mapper);
-            }
-        });
+        let (names, body) = self.gen_fields_fn_names(
+            |name| {
+                quote! {
+                    let #name = burn::module::Module::map(self.#name, mapper);
+                }
+            },
+            |name| {
+                quote! {
+                    let #name = self.#name;
+                }
+            },
+        );
 
         quote! {
             fn map<M: burn::module::ModuleMapper<B>>(self, mapper: &mut M) -> Self {
@@ -97,11 +113,25 @@ impl FnGenerator {
     }
 
     pub fn gen_valid_fn(&self) -> TokenStream {
-        let (names, body) = self.gen_fields_fn_names(|name| {
-            quote! {
-                let #name = burn::module::ADModule::<B>::valid(&self.#name);
-            }
-        });
+        let mut names = Vec::new();
+        let mut body = quote! {};
+
+        for field in self.fields.iter() {
+            let name = field.ident();
+
+            body.extend(match (field.is_mode(), field.is_skipped()) {
+                (true, _) => quote! {
+                    let #name = Default::default();
+                },
+                (false, true) => quote! {
+                    let #name = self.#name.clone();
+                },
+                (false, false) => quote! {
+                    let #name = burn::module::ADModule::<B>::valid(&self.#name);
+                },
+            });
+            names.push(name);
+        }
 
         quote! {
             fn valid(&self) -> Self::InnerModule {
@@ -115,11 +145,12 @@ impl FnGenerator {
     }
 
     pub fn gen_clone_fn(&self) -> TokenStream {
-        let (names, body) = self.gen_fields_fn_names(|name| {
+        let clone = |name| {
             quote! {
                 let #name = self.#name.clone();
             }
-        });
+        };
+        let (names, body) = self.gen_fields_fn_names(clone, clone);
 
         quote! {
             fn clone(&self) -> Self {
@@ -132,30 +163,47 @@ impl FnGenerator {
         }
     }
 
-    pub fn gen_fields_fn_names<F>(&self, func: F) -> (Vec<Ident>, TokenStream)
+    /// Generates the code of all the fields, the skipped fields using their own function.
+    pub fn gen_fields_fn_names<F, S>(&self, func: F, func_skipped: S) -> (Vec<Ident>, TokenStream)
     where
         F: Fn(Ident) -> TokenStream,
+        S: Fn(Ident) -> TokenStream,
     {
-        let mut body = quote! {};
         let mut names = Vec::new();
 
         for field in self.fields.iter() {
-            let name = field.ident();
+            names.push(field.ident());
+        }
 
-            names.push(name.clone());
-            body.extend(func(field.ident()));
+        (names, self.gen_all_fields_fn(func, func_skipped))
+    }
+
+    /// Generates the code of all the fields, the skipped fields using their own function.
+    pub fn gen_all_fields_fn<F, S>(&self, func: F, func_skipped: S) -> TokenStream
+    where
+        F: Fn(Ident) -> TokenStream,
+        S: Fn(Ident) -> TokenStream,
+    {
+        let mut body = quote! {};
+
+        for field in self.fields.iter() {
+            match field.is_skipped() {
+                true => body.extend(func_skipped(field.ident())),
+                false => body.extend(func(field.ident())),
+            }
         }
 
-        (names, body)
+        body
     }
 
+    /// Generates the code of the fields that are part of the record.
     pub fn gen_fields_fn<F>(&self, func: F) -> TokenStream
     where
         F: Fn(Ident) -> TokenStream,
     {
         let mut body = quote! {};
 
-        for field in self.fields.iter() {
+        for field in self.fields.iter().filter(|field| !field.is_skipped()) {
             body.extend(func(field.ident()));
         }
 
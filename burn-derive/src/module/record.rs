@@ -21,7 +21,7 @@ impl ModuleRecordGenerator {
     pub fn gen_record_struct(&self) -> TokenStream {
         let mut fields = quote! {};
 
-        for field in self.fields.iter() {
+        for field in self.fields.iter().filter(|field| !field.is_skipped()) {
             let ty = &field.field.ty;
             let name = &field.field.ident;
 
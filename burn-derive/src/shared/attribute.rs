@@ -6,7 +6,6 @@ pub struct AttributeAnalyzer {
 
 #[derive(Clone)]
 pub struct AttributeItem {
-    pub ident: Ident,
     pub value: syn::Lit,
 }
 
@@ -27,12 +26,27 @@ impl AttributeAnalyzer {
             _ => panic!("Only literal is supported"),
         };
 
-        AttributeItem {
-            ident: value.path.get_ident().unwrap().clone(),
-            value: lit,
+        AttributeItem { value: lit }
+    }
+
+    /// Whether the attribute is a list holding only the given flag, e.g. `#[module(skip)]`.
+    pub fn has_flag(&self, flag: &str) -> bool {
+        self.flag().map(|ident| ident == flag).unwrap_or(false)
+    }
+
+    /// The flag held by the attribute when it is a list of a single identifier.
+    pub fn flag(&self) -> Option<Ident> {
+        match &self.attr.meta {
+            Meta::List(list) => list.parse_args::<Ident>().ok(),
+            _ => None,
         }
     }
 
+    /// The attribute itself, to report errors on it.
+    pub fn attr(&self) -> &Attribute {
+        &self.attr
+    }
+
     pub fn has_name(&self, name: &str) -> bool {
         Self::path_syn_name(self.attr.path()) == name
     }
@@ -66,6 +66,20 @@ impl FieldTypeAnalyzer {
         name
     }
 
+    /// Whether the field is marked with `#[module(skip)]` or `#[module(mode)]`, keeping it out of
+    /// the record.
+    pub fn is_skipped(&self) -> bool {
+        self.attributes()
+            .any(|attr| attr.has_name("module") && (attr.has_flag("skip") || attr.has_flag("mode")))
+    }
+
+    /// Whether the field is marked with `#[module(mode)]`, being reset to its default value when
+    /// the module is validated.
+    pub fn is_mode(&self) -> bool {
+        self.attributes()
+            .any(|attr| attr.has_name("module") && attr.has_flag("mode"))
+    }
+
     pub fn attributes(&self) -> impl Iterator<Item = AttributeAnalyzer> {
         self.field
             .attrs
@@ -11,15 +11,22 @@ fn main() {
         .input("tests/concat/concat.onnx")
         .input("tests/conv1d/conv1d.onnx")
         .input("tests/conv2d/conv2d.onnx")
+        .input("tests/depth_to_space/depth_to_space.onnx")
         .input("tests/div/div.onnx")
         .input("tests/dropout/dropout_opset16.onnx")
         .input("tests/dropout/dropout_opset7.onnx")
         .input("tests/flatten/flatten.onnx")
         .input("tests/global_avr_pool/global_avr_pool.onnx")
+        .input("tests/gru/gru.onnx")
         .input("tests/log_softmax/log_softmax.onnx")
+        .input("tests/lstm/lstm.onnx")
         .input("tests/maxpool2d/maxpool2d.onnx")
         .input("tests/mul/mul.onnx")
+        .input("tests/non_max_suppression/non_max_suppression.onnx")
+        .input("tests/pad/pad.onnx")
         .input("tests/reshape/reshape.onnx")
+        .input("tests/scatter_nd/scatter_nd.onnx")
+        .input("tests/slice/slice.onnx")
         .input("tests/softmax/softmax.onnx")
         .input("tests/sub/sub.onnx")
         .out_dir("model/")
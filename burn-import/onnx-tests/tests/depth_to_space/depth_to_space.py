@@ -0,0 +1,42 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/depth_to_space/depth_to_space.onnx
+
+import numpy as np
+import onnx
+import onnxruntime
+from onnx import TensorProto, helper
+
+
+def main():
+    # The same input rearranged with the depth-column-row and column-row-depth orders
+    nodes = [
+        helper.make_node("DepthToSpace", ["x"], ["dcr"], blocksize=2, mode="DCR"),
+        helper.make_node("DepthToSpace", ["x"], ["crd"], blocksize=2, mode="CRD"),
+    ]
+    graph = helper.make_graph(
+        nodes,
+        "depth_to_space",
+        [helper.make_tensor_value_info("x", TensorProto.FLOAT, [1, 8, 1, 2])],
+        [
+            helper.make_tensor_value_info("dcr", TensorProto.FLOAT, [1, 2, 2, 4]),
+            helper.make_tensor_value_info("crd", TensorProto.FLOAT, [1, 2, 2, 4]),
+        ],
+    )
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    onnx.checker.check_model(model)
+
+    file_name = "depth_to_space.onnx"
+    onnx.save(model, file_name)
+    print("Finished exporting model to {}".format(file_name))
+
+    # Output some test data for use in the test
+    test_input = np.arange(16, dtype=np.float32).reshape(1, 8, 1, 2)
+    print("Test input data: {}".format(test_input))
+    session = onnxruntime.InferenceSession(file_name)
+    for name, output in zip(["dcr", "crd"], session.run(None, {"x": test_input})):
+        print("Test output {}: {}".format(name, output))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,78 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/lstm/lstm.onnx
+
+import numpy as np
+import onnx
+import onnxruntime
+from onnx import TensorProto, helper
+
+SEQ_LENGTH = 3
+INPUT_SIZE = 2
+HIDDEN_SIZE = 3
+
+
+def values(count, offset):
+    # Small deterministic values, so that the weights are the same as in the test
+    return [((index * 7 + offset) % 11 - 5) / 10 for index in range(count)]
+
+
+def main():
+    # The packed weights of the input, output, forget and cell gates
+    weights = [
+        helper.make_tensor(
+            "W", TensorProto.FLOAT, [1, 4 * HIDDEN_SIZE, INPUT_SIZE],
+            values(4 * HIDDEN_SIZE * INPUT_SIZE, 0),
+        ),
+        helper.make_tensor(
+            "R", TensorProto.FLOAT, [1, 4 * HIDDEN_SIZE, HIDDEN_SIZE],
+            values(4 * HIDDEN_SIZE * HIDDEN_SIZE, 3),
+        ),
+        helper.make_tensor(
+            "B", TensorProto.FLOAT, [1, 8 * HIDDEN_SIZE], values(8 * HIDDEN_SIZE, 5)
+        ),
+    ]
+    node = helper.make_node(
+        "LSTM",
+        ["X", "W", "R", "B", "", "initial_h", "initial_c"],
+        ["Y", "Y_h", "Y_c"],
+        hidden_size=HIDDEN_SIZE,
+    )
+    graph = helper.make_graph(
+        [node],
+        "lstm",
+        [
+            helper.make_tensor_value_info("X", TensorProto.FLOAT, [SEQ_LENGTH, 1, INPUT_SIZE]),
+            helper.make_tensor_value_info("initial_h", TensorProto.FLOAT, [1, 1, HIDDEN_SIZE]),
+            helper.make_tensor_value_info("initial_c", TensorProto.FLOAT, [1, 1, HIDDEN_SIZE]),
+        ],
+        [
+            helper.make_tensor_value_info(
+                "Y", TensorProto.FLOAT, [SEQ_LENGTH, 1, 1, HIDDEN_SIZE]
+            ),
+            helper.make_tensor_value_info("Y_h", TensorProto.FLOAT, [1, 1, HIDDEN_SIZE]),
+            helper.make_tensor_value_info("Y_c", TensorProto.FLOAT, [1, 1, HIDDEN_SIZE]),
+        ],
+        initializer=weights,
+    )
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    onnx.checker.check_model(model)
+
+    file_name = "lstm.onnx"
+    onnx.save(model, file_name)
+    print("Finished exporting model to {}".format(file_name))
+
+    # Output some test data for use in the test
+    test_input = {
+        "X": np.array([[[1.0, 2.0]], [[-1.0, 0.5]], [[0.5, -0.5]]], dtype=np.float32),
+        "initial_h": np.array([[[0.1, -0.2, 0.3]]], dtype=np.float32),
+        "initial_c": np.array([[[0.0, 0.5, -0.5]]], dtype=np.float32),
+    }
+    print("Test input data: {}".format(test_input))
+    session = onnxruntime.InferenceSession(file_name)
+    for name, output in zip(["Y", "Y_h", "Y_c"], session.run(None, test_input)):
+        print("Test output {}: {}".format(name, output))
+
+
+if __name__ == "__main__":
+    main()
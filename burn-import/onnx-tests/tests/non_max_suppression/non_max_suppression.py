@@ -0,0 +1,73 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/non_max_suppression/non_max_suppression.onnx
+
+import numpy as np
+import onnx
+import onnxruntime
+from onnx import TensorProto, helper
+
+
+def main():
+    # The thresholds are constants, as in the exported detection models
+    constants = [
+        helper.make_tensor("max_output_boxes_per_class", TensorProto.INT64, [1], [2]),
+        helper.make_tensor("iou_threshold", TensorProto.FLOAT, [1], [0.5]),
+        helper.make_tensor("score_threshold", TensorProto.FLOAT, [1], [0.2]),
+    ]
+    node = helper.make_node(
+        "NonMaxSuppression",
+        [
+            "boxes",
+            "scores",
+            "max_output_boxes_per_class",
+            "iou_threshold",
+            "score_threshold",
+        ],
+        ["selected_indices"],
+    )
+    graph = helper.make_graph(
+        [node],
+        "non_max_suppression",
+        [
+            helper.make_tensor_value_info("boxes", TensorProto.FLOAT, [1, 4, 4]),
+            helper.make_tensor_value_info("scores", TensorProto.FLOAT, [1, 1, 4]),
+        ],
+        [
+            helper.make_tensor_value_info(
+                "selected_indices", TensorProto.INT64, ["num_selected", 3]
+            )
+        ],
+        initializer=constants,
+    )
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    onnx.checker.check_model(model)
+
+    file_name = "non_max_suppression.onnx"
+    onnx.save(model, file_name)
+    print("Finished exporting model to {}".format(file_name))
+
+    # Output some test data for use in the test: the second box overlaps the first one, and the
+    # last one is below the score threshold
+    test_input = {
+        "boxes": np.array(
+            [
+                [
+                    [0.0, 0.0, 1.0, 1.0],
+                    [0.0, 0.1, 1.0, 1.1],
+                    [0.0, 2.0, 1.0, 3.0],
+                    [0.0, 4.0, 1.0, 5.0],
+                ]
+            ],
+            dtype=np.float32,
+        ),
+        "scores": np.array([[[0.9, 0.8, 0.7, 0.1]]], dtype=np.float32),
+    }
+    print("Test input data: {}".format(test_input))
+    session = onnxruntime.InferenceSession(file_name)
+    (output,) = session.run(None, test_input)
+    print("Test output selected_indices: {}".format(output))
+
+
+if __name__ == "__main__":
+    main()
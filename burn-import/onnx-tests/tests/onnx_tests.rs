@@ -16,15 +16,22 @@ include_models!(
     concat,
     conv1d,
     conv2d,
+    depth_to_space,
     div,
     dropout_opset16,
     dropout_opset7,
     flatten,
     global_avr_pool,
+    gru,
     log_softmax,
+    lstm,
     maxpool2d,
     mul,
+    non_max_suppression,
+    pad,
     reshape,
+    scatter_nd,
+    slice,
     softmax,
     sub
 );
@@ -33,7 +40,7 @@ include_models!(
 mod tests {
     use super::*;
 
-    use burn::tensor::{Data, Shape, Tensor};
+    use burn::tensor::{Data, Int, Shape, Tensor};
 
     use float_cmp::ApproxEq;
 
@@ -323,4 +330,167 @@ mod tests {
         let expected_shape = Shape::from([1, 75]);
         assert_eq!(expected_shape, output.shape());
     }
+
+    #[test]
+    fn lstm() {
+        // Initialize the model with weights (loaded from the exported file)
+        let model: lstm::Model<Backend> = lstm::Model::default();
+
+        // Run the model
+        let input = Tensor::<Backend, 3>::from_floats([[[1.0, 2.0]], [[-1.0, 0.5]], [[0.5, -0.5]]]);
+        let initial_h = Tensor::<Backend, 3>::from_floats([[[0.1, -0.2, 0.3]]]);
+        let initial_c = Tensor::<Backend, 3>::from_floats([[[0.0, 0.5, -0.5]]]);
+        let (output, output_h, output_c) = model.forward(input, initial_h, initial_c);
+
+        // computed with the equations of the ONNX LSTM operator
+        let expected = Data::from([
+            [[[-0.070852, 0.299593, -0.112085]]],
+            [[[-0.066858, 0.176867, -0.048265]]],
+            [[[-0.030031, 0.132975, -0.204032]]],
+        ]);
+        let expected_h = Data::from([[[-0.030031, 0.132975, -0.204032]]]);
+        let expected_c = Data::from([[[-0.071573, 0.409461, -0.391122]]]);
+
+        output.to_data().assert_approx_eq(&expected, 4);
+        output_h.to_data().assert_approx_eq(&expected_h, 4);
+        output_c.to_data().assert_approx_eq(&expected_c, 4);
+    }
+
+    #[test]
+    fn gru_linear_before_reset() {
+        // Initialize the model with weights (loaded from the exported file)
+        let model: gru::Model<Backend> = gru::Model::default();
+
+        // Run the model
+        let input = Tensor::<Backend, 3>::from_floats([[[1.0, 2.0]], [[-1.0, 0.5]], [[0.5, -0.5]]]);
+        let initial_h = Tensor::<Backend, 3>::from_floats([[[0.1, -0.2, 0.3]]]);
+        let (output, output_h) = model.forward(input, initial_h);
+
+        // computed with the equations of the ONNX GRU operator
+        let expected = Data::from([
+            [[[-0.321768, 0.16246, 0.436855]]],
+            [[[-0.442849, 0.180387, 0.467875]]],
+            [[[-0.411187, 0.436634, 0.21217]]],
+        ]);
+        let expected_h = Data::from([[[-0.411187, 0.436634, 0.21217]]]);
+
+        output.to_data().assert_approx_eq(&expected, 4);
+        output_h.to_data().assert_approx_eq(&expected_h, 4);
+    }
+
+    #[test]
+    fn pad_modes() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let model: pad::Model<Backend> = pad::Model::new();
+
+        // Run the model
+        let input = Tensor::<Backend, 2>::from_floats([[1., 2., 3.], [4., 5., 6.]]);
+        let (constant, reflect, edge) = model.forward(input);
+
+        let expected_constant = Data::from([
+            [0.5, 0.5, 0.5, 0.5, 0.5, 0.5],
+            [0.5, 0.5, 1., 2., 3., 0.5],
+            [0.5, 0.5, 4., 5., 6., 0.5],
+            [0.5, 0.5, 0.5, 0.5, 0.5, 0.5],
+        ]);
+        let expected_reflect = Data::from([
+            [6., 5., 4., 5., 6., 5.],
+            [3., 2., 1., 2., 3., 2.],
+            [6., 5., 4., 5., 6., 5.],
+            [3., 2., 1., 2., 3., 2.],
+        ]);
+        let expected_edge = Data::from([
+            [1., 1., 1., 2., 3., 3.],
+            [1., 1., 1., 2., 3., 3.],
+            [4., 4., 4., 5., 6., 6.],
+            [4., 4., 4., 5., 6., 6.],
+        ]);
+
+        assert_eq!(constant.to_data(), expected_constant);
+        assert_eq!(reflect.to_data(), expected_reflect);
+        assert_eq!(edge.to_data(), expected_edge);
+    }
+
+    #[test]
+    fn slice_negative_steps() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let model: slice::Model<Backend> = slice::Model::new();
+
+        // Run the model
+        let input = Tensor::<Backend, 2>::from_floats([[0., 1., 2., 3., 4.], [5., 6., 7., 8., 9.]]);
+        let (reversed, strided) = model.forward(input);
+
+        let expected_reversed = Data::from([[4., 3., 2., 1., 0.], [9., 8., 7., 6., 5.]]);
+        let expected_strided = Data::from([[9., 7.], [4., 2.]]);
+
+        assert_eq!(reversed.to_data(), expected_reversed);
+        assert_eq!(strided.to_data(), expected_strided);
+    }
+
+    #[test]
+    fn depth_to_space_modes() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let model: depth_to_space::Model<Backend> = depth_to_space::Model::new();
+
+        // Run the model
+        let input = Tensor::<Backend, 1, Int>::arange(0..16)
+            .float()
+            .reshape([1, 8, 1, 2]);
+        let (dcr, crd) = model.forward(input);
+
+        let expected_dcr = Data::from([[
+            [[0., 4., 1., 5.], [8., 12., 9., 13.]],
+            [[2., 6., 3., 7.], [10., 14., 11., 15.]],
+        ]]);
+        let expected_crd = Data::from([[
+            [[0., 2., 1., 3.], [4., 6., 5., 7.]],
+            [[8., 10., 9., 11.], [12., 14., 13., 15.]],
+        ]]);
+
+        assert_eq!(dcr.to_data(), expected_dcr);
+        assert_eq!(crd.to_data(), expected_crd);
+    }
+
+    #[test]
+    fn scatter_nd() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let model: scatter_nd::Model<Backend> = scatter_nd::Model::new();
+
+        // Run the model
+        let data = Tensor::<Backend, 1, Int>::arange(0..12)
+            .float()
+            .reshape([4, 3]);
+        let indices = Tensor::<Backend, 2, Int>::from_ints([[3], [1]]);
+        let updates = Tensor::<Backend, 2>::from_floats([[10., 20., 30.], [-1., -2., -3.]]);
+        let (replaced, added) = model.forward(data, indices, updates);
+
+        let expected_replaced =
+            Data::from([[0., 1., 2.], [-1., -2., -3.], [6., 7., 8.], [10., 20., 30.]]);
+        let expected_added =
+            Data::from([[0., 1., 2.], [2., 2., 2.], [6., 7., 8.], [19., 30., 41.]]);
+
+        assert_eq!(replaced.to_data(), expected_replaced);
+        assert_eq!(added.to_data(), expected_added);
+    }
+
+    #[test]
+    fn non_max_suppression() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let model: non_max_suppression::Model<Backend> = non_max_suppression::Model::new();
+
+        // Run the model: the second box overlaps the first one, and the last one is below the
+        // score threshold
+        let boxes = Tensor::<Backend, 3>::from_floats([[
+            [0.0, 0.0, 1.0, 1.0],
+            [0.0, 0.1, 1.0, 1.1],
+            [0.0, 2.0, 1.0, 3.0],
+            [0.0, 4.0, 1.0, 5.0],
+        ]]);
+        let scores = Tensor::<Backend, 3>::from_floats([[[0.9, 0.8, 0.7, 0.1]]]);
+        let output = model.forward(boxes, scores);
+
+        let expected = Data::from([[0, 0, 0], [0, 0, 2]]);
+
+        assert_eq!(output.to_data(), expected);
+    }
 }
@@ -0,0 +1,50 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/pad/pad.onnx
+
+import numpy as np
+import onnx
+import onnxruntime
+from onnx import TensorProto, helper
+
+
+def main():
+    # One row before and after, two columns before and one after
+    constants = [
+        helper.make_tensor("pads", TensorProto.INT64, [4], [1, 2, 1, 1]),
+        helper.make_tensor("constant_value", TensorProto.FLOAT, [], [0.5]),
+    ]
+    nodes = [
+        helper.make_node(
+            "Pad", ["x", "pads", "constant_value"], ["constant"], mode="constant"
+        ),
+        helper.make_node("Pad", ["x", "pads"], ["reflect"], mode="reflect"),
+        helper.make_node("Pad", ["x", "pads"], ["edge"], mode="edge"),
+    ]
+    graph = helper.make_graph(
+        nodes,
+        "pad",
+        [helper.make_tensor_value_info("x", TensorProto.FLOAT, [2, 3])],
+        [
+            helper.make_tensor_value_info(name, TensorProto.FLOAT, [4, 6])
+            for name in ["constant", "reflect", "edge"]
+        ],
+        initializer=constants,
+    )
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    onnx.checker.check_model(model)
+
+    file_name = "pad.onnx"
+    onnx.save(model, file_name)
+    print("Finished exporting model to {}".format(file_name))
+
+    # Output some test data for use in the test
+    test_input = np.array([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], dtype=np.float32)
+    print("Test input data: {}".format(test_input))
+    session = onnxruntime.InferenceSession(file_name)
+    for name, output in zip(["constant", "reflect", "edge"], session.run(None, {"x": test_input})):
+        print("Test output {}: {}".format(name, output))
+
+
+if __name__ == "__main__":
+    main()
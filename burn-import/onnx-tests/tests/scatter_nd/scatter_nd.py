@@ -0,0 +1,52 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/scatter_nd/scatter_nd.onnx
+
+import numpy as np
+import onnx
+import onnxruntime
+from onnx import TensorProto, helper
+
+
+def main():
+    # The rows selected by the indices are replaced by, or added to, the updates
+    nodes = [
+        helper.make_node("ScatterND", ["data", "indices", "updates"], ["replaced"]),
+        helper.make_node(
+            "ScatterND", ["data", "indices", "updates"], ["added"], reduction="add"
+        ),
+    ]
+    graph = helper.make_graph(
+        nodes,
+        "scatter_nd",
+        [
+            helper.make_tensor_value_info("data", TensorProto.FLOAT, [4, 3]),
+            helper.make_tensor_value_info("indices", TensorProto.INT64, [2, 1]),
+            helper.make_tensor_value_info("updates", TensorProto.FLOAT, [2, 3]),
+        ],
+        [
+            helper.make_tensor_value_info("replaced", TensorProto.FLOAT, [4, 3]),
+            helper.make_tensor_value_info("added", TensorProto.FLOAT, [4, 3]),
+        ],
+    )
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    onnx.checker.check_model(model)
+
+    file_name = "scatter_nd.onnx"
+    onnx.save(model, file_name)
+    print("Finished exporting model to {}".format(file_name))
+
+    # Output some test data for use in the test
+    test_input = {
+        "data": np.arange(12, dtype=np.float32).reshape(4, 3),
+        "indices": np.array([[3], [1]], dtype=np.int64),
+        "updates": np.array([[10.0, 20.0, 30.0], [-1.0, -2.0, -3.0]], dtype=np.float32),
+    }
+    print("Test input data: {}".format(test_input))
+    session = onnxruntime.InferenceSession(file_name)
+    for name, output in zip(["replaced", "added"], session.run(None, test_input)):
+        print("Test output {}: {}".format(name, output))
+
+
+if __name__ == "__main__":
+    main()
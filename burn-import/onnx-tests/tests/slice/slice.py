@@ -0,0 +1,65 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/slice/slice.onnx
+
+import numpy as np
+import onnx
+import onnxruntime
+from onnx import TensorProto, helper
+
+# The bound exporters use for "up to the start" of an axis walked backward
+INT64_MIN = -(2**63)
+
+
+def main():
+    constants = [
+        # The columns in reverse order
+        helper.make_tensor("reverse_starts", TensorProto.INT64, [1], [-1]),
+        helper.make_tensor("reverse_ends", TensorProto.INT64, [1], [INT64_MIN]),
+        helper.make_tensor("reverse_axes", TensorProto.INT64, [1], [1]),
+        helper.make_tensor("reverse_steps", TensorProto.INT64, [1], [-1]),
+        # The rows in reverse order, and every other column from the last one
+        helper.make_tensor("stride_starts", TensorProto.INT64, [2], [-1, 4]),
+        helper.make_tensor("stride_ends", TensorProto.INT64, [2], [INT64_MIN, 0]),
+        helper.make_tensor("stride_axes", TensorProto.INT64, [2], [0, 1]),
+        helper.make_tensor("stride_steps", TensorProto.INT64, [2], [-1, -2]),
+    ]
+    nodes = [
+        helper.make_node(
+            "Slice",
+            ["x", "reverse_starts", "reverse_ends", "reverse_axes", "reverse_steps"],
+            ["reversed"],
+        ),
+        helper.make_node(
+            "Slice",
+            ["x", "stride_starts", "stride_ends", "stride_axes", "stride_steps"],
+            ["strided"],
+        ),
+    ]
+    graph = helper.make_graph(
+        nodes,
+        "slice",
+        [helper.make_tensor_value_info("x", TensorProto.FLOAT, [2, 5])],
+        [
+            helper.make_tensor_value_info("reversed", TensorProto.FLOAT, [2, 5]),
+            helper.make_tensor_value_info("strided", TensorProto.FLOAT, [2, 2]),
+        ],
+        initializer=constants,
+    )
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    onnx.checker.check_model(model)
+
+    file_name = "slice.onnx"
+    onnx.save(model, file_name)
+    print("Finished exporting model to {}".format(file_name))
+
+    # Output some test data for use in the test
+    test_input = np.arange(10, dtype=np.float32).reshape(2, 5)
+    print("Test input data: {}".format(test_input))
+    session = onnxruntime.InferenceSession(file_name)
+    for name, output in zip(["reversed", "strided"], session.run(None, {"x": test_input})):
+        print("Test output {}: {}".format(name, output))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,72 @@
+#!/usr/bin/env python3
+
+# Computes the reference values of an ONNX model with ONNX Runtime, to verify the model generated
+# by burn-import against them.
+#
+# Usage: onnx_reference.py model.onnx reference.json
+#
+# The model is run on random inputs, the dynamic dimensions having a size of 1. Each value computed
+# by a node is saved with the inputs, by name.
+
+import json
+import sys
+
+import numpy as np
+import onnx
+import onnxruntime as ort
+
+# must set for the inputs to be the same between runs
+np.random.seed(0)
+
+
+def tensor(value):
+    return {"shape": list(value.shape), "data": value.flatten().tolist()}
+
+
+def main():
+    model_file, reference_file = sys.argv[1], sys.argv[2]
+    model = onnx.load(model_file)
+
+    # Every value computed by a node is an output, to compare the nodes one by one
+    outputs = {output.name for output in model.graph.output}
+    for node in model.graph.node:
+        for output in node.output:
+            if output and output not in outputs:
+                model.graph.output.append(onnx.ValueInfoProto(name=output))
+                outputs.add(output)
+
+    initializers = {initializer.name for initializer in model.graph.initializer}
+    inputs = {}
+    for input in model.graph.input:
+        if input.name in initializers:
+            continue
+
+        tensor_type = input.type.tensor_type
+        if tensor_type.elem_type != onnx.TensorProto.FLOAT:
+            sys.exit("Only the models with float inputs can be verified, not {}".format(input.name))
+
+        shape = [dim.dim_value if dim.dim_value > 0 else 1 for dim in tensor_type.shape.dim]
+        inputs[input.name] = np.random.randn(*shape).astype(np.float32)
+
+    session = ort.InferenceSession(
+        model.SerializeToString(), providers=["CPUExecutionProvider"]
+    )
+    names = [output.name for output in session.get_outputs()]
+    values = session.run(names, inputs)
+
+    reference = {
+        "inputs": {name: tensor(value) for name, value in inputs.items()},
+        "values": {
+            name: tensor(value.astype(np.float32))
+            for name, value in zip(names, values)
+            if np.issubdtype(value.dtype, np.floating)
+        },
+    }
+    with open(reference_file, "w") as file:
+        json.dump(reference, file)
+
+    print("Saved the reference values of {} to {}".format(model_file, reference_file))
+
+
+if __name__ == "__main__":
+    main()
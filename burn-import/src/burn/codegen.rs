@@ -1,8 +1,9 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Literal, TokenStream};
 use quote::quote;
 
 use burn::nn::PaddingConfig1d;
 use burn::nn::PaddingConfig2d;
+use burn::tensor::PadMode;
 
 fn convert_primitive<T: ToString>(primitive: T) -> TokenStream {
     let value = primitive.to_string();
@@ -91,3 +92,37 @@ impl ToTokens for PaddingConfig2d {
         }
     }
 }
+
+/// Pairs, such as the `(before, after)` paddings
+impl<A: ToTokens, B: ToTokens> ToTokens for (A, B) {
+    fn to_tokens(&self) -> TokenStream {
+        let first = self.0.to_tokens();
+        let second = self.1.to_tokens();
+
+        quote! { (#first, #second) }
+    }
+}
+
+/// Pad mode
+impl ToTokens for PadMode {
+    fn to_tokens(&self) -> TokenStream {
+        match self {
+            Self::Constant(value) => {
+                let value = if value.is_nan() {
+                    quote! { f64::NAN }
+                } else if value.is_infinite() && value.is_sign_positive() {
+                    quote! { f64::INFINITY }
+                } else if value.is_infinite() {
+                    quote! { f64::NEG_INFINITY }
+                } else {
+                    let literal = Literal::f64_unsuffixed(*value);
+                    quote! { #literal }
+                };
+
+                quote! { PadMode::Constant(#value) }
+            }
+            Self::Reflect => quote! { PadMode::Reflect },
+            Self::Edge => quote! { PadMode::Edge },
+        }
+    }
+}
@@ -1,15 +1,34 @@
 use super::{BurnImports, Scope, Type};
 use crate::burn::{
     node::{Node, NodeCodegen},
-    TensorType,
+    TensorKind, TensorType,
 };
 use burn::record::{
-    BurnRecord, DefaultFileRecorder, FileRecorder, PrecisionSettings, PrettyJsonFileRecorder,
+    BinFileRecorder, BinGzFileRecorder, BurnRecord, FileRecorder, JsonGzFileRecorder,
+    NamedMpkGzFileRecorder, PrecisionSettings, PrettyJsonFileRecorder,
 };
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 use serde::{ser::SerializeMap, Serialize};
 use std::{collections::HashMap, path::PathBuf};
+use strum_macros::{Display, EnumString};
+
+/// The file format of the record saving the states of a generated model.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Display, EnumString)]
+#[strum(serialize_all = "kebab-case")]
+pub enum RecordType {
+    /// [Pretty json](PrettyJsonFileRecorder), readable but large.
+    PrettyJson,
+    /// [Json](JsonGzFileRecorder) compressed with gzip.
+    JsonGz,
+    /// [Named msgpack](NamedMpkGzFileRecorder) compressed with gzip, the default format of Burn.
+    #[default]
+    NamedMpkGz,
+    /// [Bincode](BinFileRecorder), which can be embedded in the binary of the model.
+    Bin,
+    /// [Bincode](BinGzFileRecorder) compressed with gzip.
+    BinGz,
+}
 
 /// Burn graph intermediate representation of modules and tensor operations.
 #[derive(Default, Debug)]
@@ -18,7 +37,8 @@ pub struct BurnGraph<PS: PrecisionSettings> {
     scope: Scope,
     imports: BurnImports,
     top_comment: Option<String>,
-    default: Option<TokenStream>,
+    model_name: Option<String>,
+    record: Option<TokenStream>,
     blank_spaces: bool,
     gen_new_fn: bool,
     graph_input_types: Vec<Type>,
@@ -37,6 +57,11 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
         self.nodes.push(node);
     }
 
+    /// The registered nodes, to generate their forward pass inside another node.
+    pub(crate) fn into_nodes(self) -> Vec<Node<PS>> {
+        self.nodes
+    }
+
     /// Generate a function `Model::new()` without any argument when `gen_new_fn` is `true`.
     ///
     /// This is useful if you intend to train the model generated.
@@ -53,35 +78,66 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
     /// # Notes
     ///
     /// The development argument will change the recorder used.
-    /// [pretty json](PrettyJsonFileRecorder) is used when development is true and [default](DefaultFileRecorder) is used otherwise.
+    /// [pretty json](PrettyJsonFileRecorder) is used when development is true and
+    /// [named msgpack](NamedMpkGzFileRecorder) is used otherwise.
     ///
     /// The precision type must be passed as `&str` and should be the same type definition as the
     /// `PS` graph generic argument. [type_name](std::any::type_name) can't be used reliably for
     /// that purpose.
-    pub fn with_record(
+    pub fn with_record(self, out_file: PathBuf, development: bool, precision_ty_str: &str) -> Self {
+        let record_type = match development {
+            true => RecordType::PrettyJson,
+            false => RecordType::NamedMpkGz,
+        };
+
+        self.with_record_type(out_file, record_type, precision_ty_str)
+    }
+
+    /// Save the state of each node in a record file of the given type.
+    ///
+    /// See [with_record](Self::with_record).
+    pub fn with_record_type(
         mut self,
         out_file: PathBuf,
-        development: bool,
+        record_type: RecordType,
         precision_ty_str: &str,
     ) -> Self {
-        if development {
-            let recorder = PrettyJsonFileRecorder::<PS>::new();
-            self.register_record(
-                recorder,
+        match record_type {
+            RecordType::PrettyJson => self.register_record(
+                PrettyJsonFileRecorder::<PS>::new(),
                 out_file,
                 &format!("burn::record::PrettyJsonFileRecorder::<{precision_ty_str}>"),
-            );
-        } else {
-            let recorder = DefaultFileRecorder::<PS>::new();
-            self.register_record(
-                recorder,
+            ),
+            RecordType::JsonGz => self.register_record(
+                JsonGzFileRecorder::<PS>::new(),
                 out_file,
-                &format!("burn::record::DefaultFileRecorder::<{precision_ty_str}>"),
-            );
+                &format!("burn::record::JsonGzFileRecorder::<{precision_ty_str}>"),
+            ),
+            RecordType::NamedMpkGz => self.register_record(
+                NamedMpkGzFileRecorder::<PS>::new(),
+                out_file,
+                &format!("burn::record::NamedMpkGzFileRecorder::<{precision_ty_str}>"),
+            ),
+            RecordType::Bin => self.register_record(
+                BinFileRecorder::<PS>::new(),
+                out_file,
+                &format!("burn::record::BinFileRecorder::<{precision_ty_str}>"),
+            ),
+            RecordType::BinGz => self.register_record(
+                BinGzFileRecorder::<PS>::new(),
+                out_file,
+                &format!("burn::record::BinGzFileRecorder::<{precision_ty_str}>"),
+            ),
         }
         self
     }
 
+    /// Name the generated model struct, `Model` by default.
+    pub fn with_model_name(mut self, model_name: &str) -> Self {
+        self.model_name = Some(model_name.to_string());
+        self
+    }
+
     /// Add blank spaces in some places
     ///
     /// # Notes
@@ -104,6 +160,7 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
         self.nodes
             .iter()
             .for_each(|node| node.register_imports(&mut self.imports));
+        self.register_kind_imports();
 
         let codegen_imports = self.imports.codegen();
         let codegen_struct = self.codegen_struct();
@@ -126,9 +183,15 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
             }
             false => quote! {},
         };
-        let codegen_default = match self.default {
-            Some(default) => quote! {
-                #default
+        let model = self.model_ident();
+        let codegen_default = match self.record {
+            Some(record) => quote! {
+                impl<B: Backend> Default for #model<B> {
+                    fn default() -> Self {
+                        let record = #record;
+                        Self::new_with(record)
+                    }
+                }
                 #maybe_blank
             },
             None => quote! {},
@@ -152,7 +215,7 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
 
             #codegen_default
 
-            impl<B: Backend> Model<B> {
+            impl<B: Backend> #model<B> {
                 #codegen_new_record
                 #maybe_blank
 
@@ -169,6 +232,7 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
             match ty {
                 Type::Tensor(tensor) => Some(tensor),
                 Type::Scalar(_) => None,
+                Type::Shape(_) => None,
                 Type::Other(_) => None,
             }
         }
@@ -207,6 +271,16 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
                             .tensor_register_future_use(&tensor, node_position)
                     })
             });
+
+        // Register graph tensor output as used after the last node
+        self.graph_output_types
+            .clone()
+            .into_iter()
+            .flat_map(to_tensor)
+            .for_each(|tensor| {
+                self.scope
+                    .tensor_register_future_use(&tensor, self.nodes.len())
+            });
     }
 
     fn register_record<FR: FileRecorder>(
@@ -225,19 +299,38 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
         let recorder_ty = syn::parse_str::<syn::Type>(recorder_str).unwrap();
         let file = file.to_str();
 
-        // Add default implementation
-        self.default = Some(quote! {
-            impl<B: Backend> Default for Model<B> {
-                fn default() -> Self {
-                    let record = #recorder_ty::new()
-                        .load(#file.into())
-                        .expect("Record file to exist.");
-                    Self::new_with(record)
-                }
-            }
+        // Loaded by the default implementation
+        self.record = Some(quote! {
+            #recorder_ty::new()
+                .load(#file.into())
+                .expect("Record file to exist.")
         });
     }
 
+    fn model_ident(&self) -> Ident {
+        Ident::new(
+            self.model_name.as_deref().unwrap_or("Model"),
+            Span::call_site(),
+        )
+    }
+
+    /// Register the tensor kinds of the graph inputs and outputs, which are not imported by
+    /// default.
+    fn register_kind_imports(&mut self) {
+        self.graph_input_types
+            .iter()
+            .chain(self.graph_output_types.iter())
+            .for_each(|ty| match ty {
+                Type::Tensor(tensor) if tensor.kind == TensorKind::Int => {
+                    self.imports.register("burn::tensor::Int")
+                }
+                Type::Tensor(tensor) if tensor.kind == TensorKind::Bool => {
+                    self.imports.register("burn::tensor::Bool")
+                }
+                _ => {}
+            });
+    }
+
     fn codegen_struct(&self) -> TokenStream {
         let mut body = quote! {};
         self.nodes
@@ -264,9 +357,11 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
             phantom: core::marker::PhantomData<B>,
         });
 
+        let model = self.model_ident();
+
         quote! {
             #[derive(Module, Debug)]
-            pub struct Model<B: Backend> {
+            pub struct #model<B: Backend> {
                 #body
             }
         }
@@ -314,9 +409,11 @@ impl<PS: PrecisionSettings> BurnGraph<PS> {
             .map(|field| field.name().clone())
             .collect::<Vec<_>>();
 
+        let record = Ident::new(&format!("{}Record", self.model_ident()), Span::call_site());
+
         quote! {
             #[allow(unused_variables)]
-            pub fn new_with(record: ModelRecord<B>) -> Self {
+            pub fn new_with(record: #record<B>) -> Self {
                 #body
 
                 Self {
@@ -1,8 +1,17 @@
 use super::{
     avg_pool2d::AvgPool2dNode, batch_norm::BatchNormNode, binary::BinaryNode, concat::ConcatNode,
-    constant::ConstantNode, conv1d::Conv1dNode, conv2d::Conv2dNode, dropout::DropoutNode,
-    global_avg_pool::GlobalAvgPoolNode, linear::LinearNode, matmul::MatmulNode,
-    max_pool2d::MaxPool2dNode, reshape::ReshapeNode, unary::UnaryNode,
+    constant::ConstantNode, constant_of_shape::ConstantOfShapeNode, conv1d::Conv1dNode,
+    conv2d::Conv2dNode, conv_transpose1d::ConvTranspose1dNode,
+    conv_transpose2d::ConvTranspose2dNode, custom::CustomNode, depth_to_space::DepthToSpaceNode,
+    dropout::DropoutNode, einsum::EinsumNode, embedding::EmbeddingNode, expand::ExpandNode,
+    gather::GatherNode, gemm::GemmNode, global_avg_pool::GlobalAvgPoolNode,
+    group_norm::GroupNormNode, gru::GruNode, if_op::IfNode, layer_norm::LayerNormNode,
+    leaky_relu::LeakyReluNode, linear::LinearNode, loop_op::LoopNode, lstm::LstmNode,
+    matmul::MatmulNode, max_pool2d::MaxPool2dNode, non_max_suppression::NonMaxSuppressionNode,
+    one_hot::OneHotNode, pad::PadNode, prelu::PReluNode, reshape::ReshapeNode, scan::ScanNode,
+    scatter_elements::ScatterElementsNode, scatter_nd::ScatterNdNode, shape::ShapeNode,
+    slice::SliceNode, space_to_depth::SpaceToDepthNode, split::SplitNode, tile::TileNode,
+    unary::UnaryNode, where_op::WhereNode,
 };
 use crate::burn::{BurnImports, Scope, Type};
 use burn::record::PrecisionSettings;
@@ -77,15 +86,45 @@ pub enum Node<PS: PrecisionSettings> {
     Matmul(MatmulNode),
     Conv1d(Conv1dNode<PS>),
     Conv2d(Conv2dNode<PS>),
+    ConvTranspose1d(ConvTranspose1dNode<PS>),
+    ConvTranspose2d(ConvTranspose2dNode<PS>),
+    Custom(CustomNode),
     MaxPool2d(MaxPool2dNode),
     Linear(LinearNode<PS>),
+    Loop(LoopNode<PS>),
+    Lstm(LstmNode<PS>),
     BatchNorm(BatchNormNode<PS>),
     Constant(ConstantNode<PS>),
+    ConstantOfShape(ConstantOfShapeNode),
     Unary(UnaryNode),
     Reshape(ReshapeNode),
     Concat(ConcatNode),
+    DepthToSpace(DepthToSpaceNode),
     Dropout(DropoutNode),
+    Einsum(EinsumNode),
+    Gemm(GemmNode),
     GlobalAvgPool(GlobalAvgPoolNode),
+    GroupNorm(GroupNormNode<PS>),
+    Gru(GruNode<PS>),
+    If(IfNode<PS>),
+    LayerNorm(LayerNormNode<PS>),
+    LeakyRelu(LeakyReluNode),
+    NonMaxSuppression(NonMaxSuppressionNode),
+    OneHot(OneHotNode),
+    Pad(PadNode),
+    PRelu(PReluNode<PS>),
+    Shape(ShapeNode),
+    Slice(SliceNode),
+    SpaceToDepth(SpaceToDepthNode),
+    Split(SplitNode),
+    Scan(ScanNode<PS>),
+    ScatterElements(ScatterElementsNode),
+    ScatterNd(ScatterNdNode),
+    Tile(TileNode),
+    Embedding(EmbeddingNode<PS>),
+    Expand(ExpandNode),
+    Gather(GatherNode),
+    Where(WhereNode),
 }
 
 macro_rules! match_all {
@@ -96,15 +135,45 @@ macro_rules! match_all {
             Node::Binary(node) => $func(node),
             Node::Concat(node) => $func(node),
             Node::Constant(node) => $func(node),
+            Node::ConstantOfShape(node) => $func(node),
             Node::Conv1d(node) => $func(node),
             Node::Conv2d(node) => $func(node),
+            Node::ConvTranspose1d(node) => $func(node),
+            Node::ConvTranspose2d(node) => $func(node),
+            Node::Custom(node) => $func(node),
+            Node::DepthToSpace(node) => $func(node),
             Node::Dropout(node) => $func(node),
+            Node::Einsum(node) => $func(node),
+            Node::Embedding(node) => $func(node),
+            Node::Expand(node) => $func(node),
+            Node::Gather(node) => $func(node),
+            Node::Gemm(node) => $func(node),
             Node::GlobalAvgPool(node) => $func(node),
+            Node::GroupNorm(node) => $func(node),
+            Node::Gru(node) => $func(node),
+            Node::If(node) => $func(node),
+            Node::LayerNorm(node) => $func(node),
+            Node::LeakyRelu(node) => $func(node),
             Node::Linear(node) => $func(node),
+            Node::Loop(node) => $func(node),
+            Node::Lstm(node) => $func(node),
             Node::Matmul(node) => $func(node),
             Node::MaxPool2d(node) => $func(node),
+            Node::NonMaxSuppression(node) => $func(node),
+            Node::OneHot(node) => $func(node),
+            Node::Pad(node) => $func(node),
+            Node::PRelu(node) => $func(node),
             Node::Reshape(node) => $func(node),
+            Node::Shape(node) => $func(node),
+            Node::Slice(node) => $func(node),
+            Node::SpaceToDepth(node) => $func(node),
+            Node::Split(node) => $func(node),
+            Node::Scan(node) => $func(node),
+            Node::ScatterElements(node) => $func(node),
+            Node::ScatterNd(node) => $func(node),
+            Node::Tile(node) => $func(node),
             Node::Unary(node) => $func(node),
+            Node::Where(node) => $func(node),
         }
     }};
 }
@@ -126,15 +195,45 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::Binary(binary) => binary.binary_type.as_str(),
             Node::Concat(_) => "concat",
             Node::Constant(_) => "constant",
+            Node::ConstantOfShape(_) => "constant_of_shape",
             Node::Conv1d(_) => "conv1d",
             Node::Conv2d(_) => "conv2d",
+            Node::ConvTranspose1d(_) => "conv_transpose1d",
+            Node::ConvTranspose2d(_) => "conv_transpose2d",
+            Node::Custom(_) => "custom",
+            Node::DepthToSpace(_) => "depth_to_space",
             Node::Dropout(_) => "dropout",
+            Node::Einsum(_) => "einsum",
+            Node::Embedding(_) => "embedding",
+            Node::Expand(_) => "expand",
+            Node::Gather(_) => "gather",
+            Node::Gemm(_) => "gemm",
             Node::GlobalAvgPool(_) => "global_avg_pool",
+            Node::GroupNorm(_) => "group_norm",
+            Node::Gru(_) => "gru",
+            Node::If(_) => "if",
+            Node::LayerNorm(_) => "layer_norm",
+            Node::LeakyRelu(_) => "leaky_relu",
             Node::Linear(_) => "linear",
+            Node::Loop(_) => "loop",
+            Node::Lstm(_) => "lstm",
             Node::Matmul(_) => "matmul",
             Node::MaxPool2d(_) => "max_pool2d",
+            Node::NonMaxSuppression(_) => "non_max_suppression",
+            Node::OneHot(_) => "one_hot",
+            Node::Pad(_) => "pad",
+            Node::PRelu(_) => "prelu",
             Node::Reshape(_) => "reshape",
+            Node::Shape(_) => "shape",
+            Node::Slice(_) => "slice",
+            Node::SpaceToDepth(_) => "space_to_depth",
+            Node::Split(_) => "split",
+            Node::Scan(_) => "scan",
+            Node::ScatterElements(_) => "scatter_elements",
+            Node::ScatterNd(_) => "scatter_nd",
+            Node::Tile(_) => "tile",
             Node::Unary(unary) => unary.kind.as_str(),
+            Node::Where(_) => "where",
         }
     }
 }
@@ -292,7 +391,19 @@ pub(crate) mod tests {
                 }
                 #[allow(clippy::let_and_return)]
                 pub fn forward(&self, tensor1: Tensor<B, 4>, tensor2: Tensor<B, 4>) -> Tensor<B, 4> {
-                    let tensor3 = tensor1.matmul(tensor2);
+                    let tensor3 = {
+                        let (lhs, rhs) = (tensor1, tensor2);
+                        let mut lhs_shape = lhs.dims();
+                        let mut rhs_shape = rhs.dims();
+
+                        for (lhs_size, rhs_size) in lhs_shape.iter_mut().zip(rhs_shape.iter_mut()).take(2) {
+                            let size = usize::max(*lhs_size, *rhs_size);
+                            *lhs_size = size;
+                            *rhs_size = size;
+                        }
+
+                        lhs.expand(lhs_shape).matmul(rhs.expand(rhs_shape))
+                    };
                     let tensor4 = self.conv2d.forward(tensor3);
 
                     tensor4
@@ -364,9 +475,33 @@ pub(crate) mod tests {
                 }
                 #[allow(clippy::let_and_return)]
                 pub fn forward(&self, tensor1: Tensor<B, 4>, tensor2: Tensor<B, 4>) -> Tensor<B, 4> {
-                    let tensor3 = tensor1.matmul(tensor2.clone());
+                    let tensor3 = {
+                        let (lhs, rhs) = (tensor1, tensor2.clone());
+                        let mut lhs_shape = lhs.dims();
+                        let mut rhs_shape = rhs.dims();
+
+                        for (lhs_size, rhs_size) in lhs_shape.iter_mut().zip(rhs_shape.iter_mut()).take(2) {
+                            let size = usize::max(*lhs_size, *rhs_size);
+                            *lhs_size = size;
+                            *rhs_size = size;
+                        }
+
+                        lhs.expand(lhs_shape).matmul(rhs.expand(rhs_shape))
+                    };
                     let tensor4 = self.conv2d.forward(tensor2);
-                    let output = tensor3.matmul(tensor4);
+                    let output = {
+                        let (lhs, rhs) = (tensor3, tensor4);
+                        let mut lhs_shape = lhs.dims();
+                        let mut rhs_shape = rhs.dims();
+
+                        for (lhs_size, rhs_size) in lhs_shape.iter_mut().zip(rhs_shape.iter_mut()).take(2) {
+                            let size = usize::max(*lhs_size, *rhs_size);
+                            *lhs_size = size;
+                            *rhs_size = size;
+                        }
+
+                        lhs.expand(lhs_shape).matmul(rhs.expand(rhs_shape))
+                    };
 
                     output
                 }
@@ -126,10 +126,17 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for BatchNormNode<PS> {
             },
         };
 
+        // The running statistics are tracked by default
+        let track_running_stats = match self.config.track_running_stats {
+            true => quote! {},
+            false => quote! { .with_track_running_stats(false) },
+        };
+
         let tokens = quote! {
             let #name = BatchNormConfig::new(#num_features)
                 .with_epsilon(#epsilon)
                 .with_momentum(#momentum)
+                #track_running_stats
                 .#init_line
         };
 
@@ -1,5 +1,5 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{ScalarKind, ScalarType, Scope, TensorType, ToTokens, Type};
+use crate::burn::{ScalarKind, ScalarType, Scope, TensorKind, TensorType, ToTokens, Type};
 use burn::{
     module::ParamId,
     record::{ParamSerde, PrecisionSettings},
@@ -48,7 +48,7 @@ impl<PS: PrecisionSettings> ConstantValue<PS> {
             ConstantValue::Int64(_) => quote! { i64 },
             ConstantValue::Bool(_) => quote! { bool },
             ConstantValue::Tensor(tensor_type, _) => {
-                let ty = tensor_type.ty();
+                let ty = param_type(tensor_type).ty();
                 quote! { burn::module::Param<#ty>}
             }
         }
@@ -67,6 +67,14 @@ impl<PS: PrecisionSettings> ConstantValue<PS> {
     }
 }
 
+/// Parameters are float tensors, the constants of other kinds are converted when they are used.
+fn param_type(tensor_type: &TensorType) -> TensorType {
+    TensorType {
+        kind: TensorKind::Float,
+        ..tensor_type.clone()
+    }
+}
+
 impl<PS: PrecisionSettings> ConstantNode<PS> {
     pub fn new(name: String, value: ConstantValue<PS>, output: Type) -> Self {
         Self {
@@ -115,7 +123,7 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ConstantNode<PS> {
 
     fn field_type(&self) -> Option<Type> {
         match &self.value {
-            ConstantValue::Tensor(tensor_type, _) => Some(Type::Tensor(tensor_type.clone())),
+            ConstantValue::Tensor(tensor_type, _) => Some(Type::Tensor(param_type(tensor_type))),
             _ => None,
         }
     }
@@ -123,7 +131,7 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ConstantNode<PS> {
     fn field_init(&self, with_record: bool) -> Option<TokenStream> {
         match &self.value {
             ConstantValue::Tensor(tensor_type, _) => {
-                let ty = tensor_type.ty();
+                let ty = param_type(tensor_type).ty();
                 let name = Ident::new(self.name.as_ref(), Span::call_site());
                 let shape = tensor_type.clone().shape.unwrap().to_tokens();
                 let dim = tensor_type.clone().dim.to_tokens();
@@ -150,11 +158,17 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ConstantNode<PS> {
         let output = self.output.name();
 
         match &self.value {
-            ConstantValue::Tensor(_, _) => {
-                quote! {
+            ConstantValue::Tensor(tensor_type, _) => match tensor_type.kind {
+                TensorKind::Float => quote! {
                     let #output = self.#name.val();
-                }
-            }
+                },
+                TensorKind::Int => quote! {
+                    let #output = self.#name.val().int();
+                },
+                TensorKind::Bool => quote! {
+                    let #output = self.#name.val().equal_elem(0).int().equal_elem(0);
+                },
+            },
             _ => {
                 let val = self.value.val_tokens();
                 let ty = self.value.ty_tokens();
@@ -0,0 +1,130 @@
+use super::{shape::ShapeValue, Node, NodeCodegen};
+use crate::burn::{BurnImports, Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+/// The value of every element of the created tensor.
+#[derive(Debug, Clone)]
+pub enum FillValue {
+    Float(f64),
+    Int(i64),
+    Bool(bool),
+}
+
+/// Creates a tensor of the given shape filled with a single value.
+#[derive(Debug, Clone, new)]
+pub struct ConstantOfShapeNode {
+    pub shape: ShapeValue,
+    pub output: TensorType,
+    pub value: FillValue,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for ConstantOfShapeNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        self.shape.input_types()
+    }
+
+    fn forward(&self, _scope: &mut Scope, _node_position: usize) -> TokenStream {
+        let output = &self.output.name;
+        let shape = self.shape.to_tokens();
+        let dim = self.output.dim.to_tokens();
+
+        let tensor = match &self.value {
+            FillValue::Float(value) => {
+                let value = Literal::f64_unsuffixed(*value);
+                quote! { Tensor::<B, #dim>::full(#shape, #value) }
+            }
+            FillValue::Int(value) => {
+                let value = Literal::i64_unsuffixed(*value);
+                quote! { Tensor::<B, #dim, Int>::full(#shape, #value) }
+            }
+            FillValue::Bool(value) => {
+                let value = Literal::i64_unsuffixed(*value as i64);
+                quote! { Tensor::<B, #dim, Int>::full(#shape, #value).equal_elem(1) }
+            }
+        };
+
+        quote! {
+            let #output = #tensor;
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        if !matches!(self.value, FillValue::Float(_)) {
+            imports.register("burn::tensor::Int");
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::ConstantOfShape(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{constant_of_shape::ConstantOfShapeNode, test::assert_tokens},
+        ShapeType, TensorType,
+    };
+
+    #[test]
+    fn test_codegen_nodes() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ConstantOfShapeNode::new(
+            ShapeValue::Static(vec![2, 3]),
+            TensorType::new_float("tensor1", 2),
+            FillValue::Float(0.5),
+        ));
+        graph.register(ConstantOfShapeNode::new(
+            ShapeValue::Runtime(ShapeType::new("shape1", 3)),
+            TensorType::new_int("tensor2", 3),
+            FillValue::Int(0),
+        ));
+
+        graph.register_input_output(
+            vec!["shape1".to_string()],
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::tensor::Int;
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, shape1: [usize; 3]) -> (Tensor<B, 2>, Tensor<B, 3, Int>) {
+                    let tensor1 = Tensor::<B, 2>::full([2, 3], 0.5);
+                    let tensor2 = Tensor::<B, 3, Int>::full(shape1, 0);
+
+                    (tensor1, tensor2)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
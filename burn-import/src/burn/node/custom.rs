@@ -0,0 +1,128 @@
+use super::{subgraph::tuple, Node, NodeCodegen};
+use crate::burn::{Scope, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// A node of a custom operator, whose forward pass is the code generated by the handler registered
+/// for the operator.
+///
+/// The code reads the inputs by their names and declares the outputs with `let` statements.
+#[derive(Debug, Clone, new)]
+pub struct CustomNode {
+    pub inputs: Vec<Type>,
+    pub outputs: Vec<Type>,
+    pub code: TokenStream,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for CustomNode {
+    fn output_types(&self) -> Vec<Type> {
+        self.outputs.clone()
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        self.inputs.clone()
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        // The tensors used later are cloned before running the code
+        let mut bindings = quote! {};
+        for input in self.inputs.iter() {
+            if let Type::Tensor(tensor) = input {
+                let name = &tensor.name;
+                let value = scope.tensor_use_owned(tensor, node_position);
+
+                if *name != value.to_string() {
+                    bindings.extend(quote! { let #name = #value; });
+                }
+            }
+        }
+
+        let code = &self.code;
+        let outputs = tuple(
+            self.outputs
+                .iter()
+                .map(|output| {
+                    let name = output.name();
+                    quote! { #name }
+                })
+                .collect(),
+        );
+
+        quote! {
+            let #outputs = {
+                #bindings
+                #code
+                #outputs
+            };
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Custom(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{custom::CustomNode, test::assert_tokens, unary::UnaryNode},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_nodes() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        let tensor = |name| Type::Tensor(TensorType::new_float(name, 4));
+        graph.register(CustomNode::new(
+            vec![tensor("tensor1")],
+            vec![tensor("tensor2")],
+            quote! { let tensor2 = tensor1.exp(); },
+        ));
+        graph.register(UnaryNode::relu(tensor("tensor1"), tensor("tensor3")));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string(), "tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> (Tensor<B, 4>, Tensor<B, 4>) {
+                    let tensor2 = {
+                        let tensor1 = tensor1.clone();
+                        let tensor2 = tensor1.exp();
+                        tensor2
+                    };
+                    let tensor3 = burn::tensor::activation::relu(tensor1);
+
+                    (tensor2, tensor3)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,172 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// The order of the blocks in the channels of the input.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DepthToSpaceMode {
+    /// The channels are ordered by block row, block column and output channel.
+    Dcr,
+    /// The channels are ordered by output channel, block row and block column.
+    Crd,
+}
+
+/// Rearranges blocks of channels into spatial blocks, the inverse of
+/// [SpaceToDepthNode](super::space_to_depth::SpaceToDepthNode).
+#[derive(Debug, Clone, new)]
+pub struct DepthToSpaceNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub block_size: usize,
+    pub mode: DepthToSpaceMode,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for DepthToSpaceNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        if self.input.dim != 4 {
+            panic!("DepthToSpace: the input must be of rank 4");
+        }
+
+        let input_name = &self.input.name;
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let block_size = self.block_size.to_tokens();
+        let num_blocks = (self.block_size * self.block_size).to_tokens();
+
+        let (split, axes) = match self.mode {
+            DepthToSpaceMode::Dcr => (
+                quote! { [batch_size, #block_size, #block_size, channels / #num_blocks, height, width] },
+                [0, 3, 4, 1, 5, 2],
+            ),
+            DepthToSpaceMode::Crd => (
+                quote! { [batch_size, channels / #num_blocks, #block_size, #block_size, height, width] },
+                [0, 1, 4, 2, 5, 3],
+            ),
+        };
+        let tensor = permute(quote! { #input.reshape(#split) }, &axes);
+
+        quote! {
+            let #output = {
+                let [batch_size, channels, height, width] = #input_name.dims();
+                #tensor.reshape([
+                    batch_size,
+                    channels / #num_blocks,
+                    height * #block_size,
+                    width * #block_size,
+                ])
+            };
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::DepthToSpace(self)
+    }
+}
+
+/// Moves the axes of a tensor so that the axis `i` of the result is the axis `axes[i]` of the
+/// tensor, by swapping the axes two by two.
+pub(crate) fn permute(tensor: TokenStream, axes: &[usize]) -> TokenStream {
+    let mut current = (0..axes.len()).collect::<Vec<_>>();
+    let mut tensor = tensor;
+
+    for (i, axis) in axes.iter().enumerate() {
+        let j = current.iter().position(|current| current == axis).unwrap();
+        if j == i {
+            continue;
+        }
+
+        current.swap(i, j);
+        let (i, j) = (i.to_tokens(), j.to_tokens());
+        tensor = quote! { #tensor.swap_dims(#i, #j) };
+    }
+
+    tensor
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{depth_to_space::DepthToSpaceNode, test::assert_tokens},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_nodes() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(DepthToSpaceNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 4),
+            2,
+            DepthToSpaceMode::Dcr,
+        ));
+        graph.register(DepthToSpaceNode::new(
+            TensorType::new_float("tensor2", 4),
+            TensorType::new_float("tensor3", 4),
+            3,
+            DepthToSpaceMode::Crd,
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor3".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = {
+                        let [batch_size, channels, height, width] = tensor1.dims();
+                        tensor1
+                            .reshape([batch_size, 2, 2, channels / 4, height, width])
+                            .swap_dims(1, 3)
+                            .swap_dims(2, 4)
+                            .swap_dims(4, 5)
+                            .reshape([batch_size, channels / 4, height * 2, width * 2,])
+                    };
+                    let tensor3 = {
+                        let [batch_size, channels, height, width] = tensor2.dims();
+                        tensor2
+                            .reshape([batch_size, channels / 9, 3, 3, height, width])
+                            .swap_dims(2, 4)
+                            .swap_dims(3, 4)
+                            .swap_dims(4, 5)
+                            .reshape([batch_size, channels / 9, height * 3, width * 3,])
+                    };
+
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,236 @@
+use super::{depth_to_space::permute, Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+
+/// Sums the products of the elements of the inputs along the labels of the equation that are not
+/// in the output.
+///
+/// Two inputs are multiplied with [einsum](burn::tensor::Tensor::einsum), while the labels of a
+/// single input that are not in the output are summed before it is permuted to the output labels.
+#[derive(Debug, Clone, new)]
+pub struct EinsumNode {
+    pub inputs: Vec<TensorType>,
+    pub output: TensorType,
+    pub input_labels: Vec<Vec<char>>,
+    pub output_labels: Vec<char>,
+}
+
+/// The axes of an input of the equation.
+struct Operand<'a> {
+    labels: &'a [char],
+    dims: Ident,
+}
+
+impl Operand<'_> {
+    fn axis(&self, label: char) -> usize {
+        self.labels.iter().position(|l| *l == label).unwrap()
+    }
+
+    fn axes(&self, labels: &[char]) -> Vec<usize> {
+        labels.iter().map(|label| self.axis(*label)).collect()
+    }
+
+    fn size(&self, label: char) -> TokenStream {
+        let dims = &self.dims;
+        let axis = self.axis(label).to_tokens();
+        quote! { #dims[#axis] }
+    }
+
+    fn sizes(&self, labels: &[char]) -> Vec<TokenStream> {
+        labels.iter().map(|label| self.size(*label)).collect()
+    }
+
+    /// Sums the given labels, keeping the axes with a size of 1.
+    fn sum(&self, mut tensor: TokenStream, labels: &[char]) -> TokenStream {
+        for axis in self.axes(labels) {
+            let axis = axis.to_tokens();
+            tensor = quote! { #tensor.sum_dim(#axis) };
+        }
+        tensor
+    }
+}
+
+impl EinsumNode {
+    fn unary(&self, tensor: TokenStream, input: &Operand) -> TokenStream {
+        let summed = labels_not_in(input.labels, &[&self.output_labels]);
+        let kept = labels_in(input.labels, &[&self.output_labels]);
+
+        let mut tensor = input.sum(tensor, &summed);
+        if !summed.is_empty() {
+            tensor = self.reshape_output(tensor, input.sizes(&kept));
+        }
+
+        let axes = self
+            .output_labels
+            .iter()
+            .map(|label| kept.iter().position(|l| l == label).unwrap())
+            .collect::<Vec<_>>();
+        permute(tensor, &axes)
+    }
+
+    /// Reshapes the tensor to the sizes of the output, a scalar being a tensor of a single
+    /// element.
+    fn reshape_output(&self, tensor: TokenStream, sizes: Vec<TokenStream>) -> TokenStream {
+        match sizes.is_empty() {
+            true => quote! { #tensor.reshape([1]) },
+            false => quote! { #tensor.reshape([#(#sizes),*]) },
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for EinsumNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        self.inputs
+            .iter()
+            .map(|input| Type::Tensor(input.clone()))
+            .collect()
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let output = &self.output.name;
+
+        match self.inputs.as_slice() {
+            [input] => {
+                let name = &input.name;
+                let operand = Operand {
+                    labels: &self.input_labels[0],
+                    dims: Ident::new("dims", Span::call_site()),
+                };
+                let input = scope.tensor_use_owned(input, node_position);
+                let tensor = self.unary(input, &operand);
+
+                // The sizes of the input are not needed when all its labels are summed.
+                let dims = match tensor.to_string().contains("dims") {
+                    true => quote! { let dims = #name.dims(); },
+                    false => quote! {},
+                };
+
+                quote! {
+                    let #output = {
+                        #dims
+                        #tensor
+                    };
+                }
+            }
+            [lhs, rhs] => {
+                let equation = format!(
+                    "{},{}->{}",
+                    String::from_iter(&self.input_labels[0]),
+                    String::from_iter(&self.input_labels[1]),
+                    String::from_iter(&self.output_labels)
+                );
+                let dim = self.output.dim.to_tokens();
+                let lhs = scope.tensor_use_owned(lhs, node_position);
+                let rhs = scope.tensor_use_owned(rhs, node_position);
+
+                quote! {
+                    let #output: Tensor<B, #dim> = Tensor::einsum(#equation, #lhs, #rhs);
+                }
+            }
+            _ => panic!("Einsum: only one or two inputs are supported"),
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Einsum(self)
+    }
+}
+
+/// The labels that are in all the others.
+fn labels_in(labels: &[char], others: &[&[char]]) -> Vec<char> {
+    labels
+        .iter()
+        .filter(|label| others.iter().all(|other| other.contains(label)))
+        .copied()
+        .collect()
+}
+
+/// The labels that are in none of the others.
+fn labels_not_in(labels: &[char], others: &[&[char]]) -> Vec<char> {
+    labels
+        .iter()
+        .filter(|label| others.iter().all(|other| !other.contains(label)))
+        .copied()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{einsum::EinsumNode, test::assert_tokens},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_nodes() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(EinsumNode::new(
+            vec![
+                TensorType::new_float("tensor1", 4),
+                TensorType::new_float("tensor2", 4),
+            ],
+            TensorType::new_float("tensor3", 4),
+            vec!["bhid".chars().collect(), "bhjd".chars().collect()],
+            "bhij".chars().collect(),
+        ));
+        graph.register(EinsumNode::new(
+            vec![TensorType::new_float("tensor3", 4)],
+            TensorType::new_float("tensor4", 2),
+            vec!["bhij".chars().collect()],
+            "jb".chars().collect(),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["tensor4".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>, tensor2: Tensor<B, 4>) -> Tensor<B, 2> {
+                    let tensor3: Tensor<B, 4> = Tensor::einsum("bhid,bhjd->bhij", tensor1, tensor2);
+                    let tensor4 = {
+                        let dims = tensor3.dims();
+                        tensor3
+                            .sum_dim(1)
+                            .sum_dim(2)
+                            .reshape([dims[0], dims[3]])
+                            .swap_dims(0, 1)
+                    };
+
+                    tensor4
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
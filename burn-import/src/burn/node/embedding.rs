@@ -0,0 +1,179 @@
+use super::{Node, NodeCodegen, SerializationBackend};
+use crate::burn::{BurnImports, OtherType, Scope, TensorKind, TensorType, ToTokens, Type};
+use burn::{
+    module::{Param, ParamId},
+    nn::{EmbeddingConfig, EmbeddingRecord},
+    record::{PrecisionSettings, Record},
+    tensor::{DataSerialize, Tensor},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde::Serialize;
+
+#[derive(Debug, Clone)]
+pub struct EmbeddingNode<PS: PrecisionSettings> {
+    pub field: OtherType,
+    pub input: TensorType,
+    pub output: TensorType,
+    pub data_weights: DataSerialize<PS::FloatElem>,
+    pub config: EmbeddingConfig,
+}
+
+impl<PS: PrecisionSettings> EmbeddingNode<PS> {
+    pub fn new<S: AsRef<str>>(
+        name: S,
+        input: TensorType,
+        output: TensorType,
+        data_weights: DataSerialize<PS::FloatElem>,
+        config: EmbeddingConfig,
+    ) -> Self {
+        Self {
+            field: OtherType::new(
+                name,
+                quote! {
+                    Embedding<B>
+                },
+            ),
+            input,
+            output,
+            data_weights,
+            config,
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for EmbeddingNode<PS> {
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Other(self.field.clone()))
+    }
+
+    fn field_init(&self, with_record: bool) -> Option<TokenStream> {
+        let name = &self.field.name;
+        let n_embedding = self.config.n_embedding.to_tokens();
+        let d_model = self.config.d_model.to_tokens();
+
+        let init_line = match with_record {
+            true => quote! {
+                init_with(record.#name);
+            },
+            false => quote! {
+                init();
+            },
+        };
+
+        let tokens = quote! {
+            let #name = EmbeddingConfig::new(#n_embedding, #d_model)
+                .#init_line
+        };
+
+        Some(tokens)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let record = EmbeddingRecord::<SerializationBackend> {
+            weight: Param::new(
+                ParamId::new(),
+                Tensor::from_data(self.data_weights.clone().convert()),
+            ),
+        };
+
+        let item = Record::into_item::<PS>(record);
+        item.serialize(serializer)
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let field = &self.field.name;
+
+        // The indices of another kind than int are converted.
+        let input = match self.input.kind {
+            TensorKind::Int => input,
+            _ => quote! { #input.int() },
+        };
+
+        match self.input.dim {
+            1 => quote! {
+                let #output = self.#field.forward(#input.unsqueeze()).squeeze::<2>(0);
+            },
+            2 => quote! {
+                let #output = self.#field.forward(#input);
+            },
+            dim => panic!("Embedding: indices of rank {dim} are not supported"),
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::nn::Embedding");
+        imports.register("burn::nn::EmbeddingConfig");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Embedding(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+    use burn::{record::FullPrecisionSettings, tensor::Data};
+
+    #[test]
+    fn test_codegen() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(EmbeddingNode::new(
+            "embedding",
+            TensorType::new_float("input", 2),
+            TensorType::new_float("output", 3),
+            Data::from([[2.]]).serialize(),
+            EmbeddingConfig::new(32, 8),
+        ));
+
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::Embedding;
+            use burn::nn::EmbeddingConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                embedding: Embedding<B>,
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    let embedding = EmbeddingConfig::new(32, 8)
+                        .init_with(record.embedding);
+
+                    Self {
+                        embedding,
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 3> {
+                    let output = self.embedding.forward(input.int());
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
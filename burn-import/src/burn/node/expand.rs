@@ -0,0 +1,188 @@
+use super::shape::ShapeValue;
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Debug, Clone, new)]
+pub struct ExpandNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub shape: ShapeValue,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for ExpandNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        let mut inputs = vec![Type::Tensor(self.input.clone())];
+        inputs.extend(self.shape.input_types());
+
+        inputs
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let shape = self.shape.to_tokens();
+
+        quote! {
+            let #output = #input.expand(#shape);
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Expand(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{expand::ExpandNode, shape::ShapeDim, test::assert_tokens},
+        ShapeType, TensorType,
+    };
+
+    #[test]
+    fn test_codegen_nodes() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ExpandNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_float("tensor2", 3),
+            ShapeValue::Static(vec![2, 3, 4]),
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> Tensor<B, 3> {
+                    let tensor2 = tensor1.expand([2, 3, 4]);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_runtime_shape() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ExpandNode::new(
+            TensorType::new_float("tensor1", 1),
+            TensorType::new_float("tensor2", 2),
+            ShapeValue::Runtime(ShapeType::new("shape1", 2)),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "shape1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 1>, shape1: [usize; 2]) -> Tensor<B, 2> {
+                    let tensor2 = tensor1.expand(shape1);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_runtime_dims() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ExpandNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_float("tensor2", 3),
+            ShapeValue::Dims(vec![
+                ShapeDim::Runtime(ShapeType::new("shape1", 3), 0),
+                ShapeDim::Static(4),
+                ShapeDim::Runtime(ShapeType::new("shape1", 3), 2),
+            ]),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "shape1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>, shape1: [usize; 3]) -> Tensor<B, 3> {
+                    let tensor2 = tensor1.expand([shape1[0], 4, shape1[2]]);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,219 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorKind, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+/// The indices of a [gather](GatherNode) node.
+#[derive(Debug, Clone)]
+pub enum GatherIndices {
+    /// Indices computed by the graph.
+    Tensor(TensorType),
+    /// Constant indices with their shape, a scalar index having an empty shape.
+    Constant(Vec<i64>, Vec<usize>),
+}
+
+impl GatherIndices {
+    fn dim(&self) -> usize {
+        match self {
+            Self::Tensor(tensor) => tensor.dim,
+            Self::Constant(_, shape) => shape.len(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, new)]
+pub struct GatherNode {
+    pub input: TensorType,
+    pub indices: GatherIndices,
+    pub output: TensorType,
+    pub dim: usize,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for GatherNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        let mut types = vec![Type::Tensor(self.input.clone())];
+
+        if let GatherIndices::Tensor(indices) = &self.indices {
+            types.push(Type::Tensor(indices.clone()));
+        }
+
+        types
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input_name = &self.input.name;
+        let output = &self.output.name;
+        let dim = self.dim.to_tokens();
+        let indices_dim = self.indices.dim();
+
+        // The indices are selected as a vector, their shape then replaces the selected axis.
+        let (indices, indices_shape, indices_dims) = match &self.indices {
+            GatherIndices::Tensor(indices) => {
+                let indices_name = &indices.name;
+                let tensor = scope.tensor_use_owned(indices, node_position);
+                // The indices of another kind than int are converted.
+                let tensor = match indices.kind {
+                    TensorKind::Int => tensor,
+                    _ => quote! { #tensor.int() },
+                };
+                let shape = (0..indices_dim)
+                    .map(|i| {
+                        let i = i.to_tokens();
+                        quote! { indices_dims[#i] }
+                    })
+                    .collect::<Vec<_>>();
+
+                match indices_dim {
+                    0 | 1 => (tensor, shape, None),
+                    _ => {
+                        let end_dim = (indices_dim - 1).to_tokens();
+                        (
+                            quote! { #tensor.flatten::<1>(0, #end_dim) },
+                            shape,
+                            Some(quote! { let indices_dims = #indices_name.dims(); }),
+                        )
+                    }
+                }
+            }
+            GatherIndices::Constant(values, shape) => {
+                let values = values
+                    .iter()
+                    .map(|value| Literal::i32_unsuffixed(*value as i32));
+                let shape = shape.iter().map(|size| size.to_tokens()).collect();
+
+                (quote! { Tensor::from_ints([#(#values),*]) }, shape, None)
+            }
+        };
+        let input = scope.tensor_use_owned(&self.input, node_position);
+
+        match indices_dim {
+            0 => {
+                let output_dim = self.output.dim.to_tokens();
+
+                quote! {
+                    let #output = #input.select(#dim, #indices).squeeze::<#output_dim>(#dim);
+                }
+            }
+            1 => quote! {
+                let #output = #input.select(#dim, #indices);
+            },
+            _ => {
+                let input_dims = |range: core::ops::Range<usize>| {
+                    range.map(|i| {
+                        let i = i.to_tokens();
+                        quote! { dims[#i] }
+                    })
+                };
+                let shape = input_dims(0..self.dim)
+                    .chain(indices_shape)
+                    .chain(input_dims(self.dim + 1..self.input.dim));
+                let dims = (self.input.dim > 1).then(|| quote! { let dims = #input_name.dims(); });
+
+                quote! {
+                    let #output = {
+                        #dims
+                        #indices_dims
+                        #input.select(#dim, #indices).reshape([#(#shape),*])
+                    };
+                }
+            }
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Gather(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{gather::GatherNode, test::assert_tokens},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_nodes() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(GatherNode::new(
+            TensorType::new_float("tensor1", 3),
+            GatherIndices::Tensor(TensorType::new_int("indices", 1)),
+            TensorType::new_float("tensor2", 3),
+            1,
+        ));
+        graph.register(GatherNode::new(
+            TensorType::new_float("tensor2", 3),
+            GatherIndices::Constant(vec![2], vec![]),
+            TensorType::new_float("tensor3", 2),
+            0,
+        ));
+        graph.register(GatherNode::new(
+            TensorType::new_float("tensor3", 2),
+            GatherIndices::Tensor(TensorType::new_float("indices2", 2)),
+            TensorType::new_float("tensor4", 3),
+            1,
+        ));
+
+        graph.register_input_output(
+            vec![
+                "tensor1".to_string(),
+                "indices".to_string(),
+                "indices2".to_string(),
+            ],
+            vec!["tensor4".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::tensor::Int;
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 3>,
+                    indices: Tensor<B, 1, Int>,
+                    indices2: Tensor<B, 2>
+                ) -> Tensor<B, 3> {
+                    let tensor2 = tensor1.select(1, indices);
+                    let tensor3 = tensor2.select(0, Tensor::from_ints([2])).squeeze::<2>(0);
+                    let tensor4 = {
+                        let dims = tensor3.dims();
+                        let indices_dims = indices2.dims();
+                        tensor3
+                            .select(1, indices2.int().flatten::<1>(0, 1))
+                            .reshape([dims[0], indices_dims[0], indices_dims[1]])
+                    };
+
+                    tensor4
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
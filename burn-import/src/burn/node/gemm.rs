@@ -0,0 +1,320 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+/// General matrix multiplication `Y = alpha * A' * B' + beta * C`, where `C` is a tensor or a
+/// scalar broadcast to the output.
+///
+/// When `A` is computed by the graph and `B` and `C` are initializers of a linear layer, the node
+/// is converted to a linear layer instead.
+#[derive(Debug, Clone)]
+pub struct GemmNode {
+    pub a: TensorType,
+    pub b: TensorType,
+    pub c: Option<Type>,
+    pub output: TensorType,
+    pub alpha: f64,
+    pub beta: f64,
+    pub trans_a: bool,
+    pub trans_b: bool,
+}
+
+impl GemmNode {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        a: TensorType,
+        b: TensorType,
+        c: Option<Type>,
+        output: TensorType,
+        alpha: f64,
+        beta: f64,
+        trans_a: bool,
+        trans_b: bool,
+    ) -> Self {
+        Self {
+            a,
+            b,
+            c,
+            output,
+            alpha,
+            beta,
+            trans_a,
+            trans_b,
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for GemmNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        let mut inputs = vec![Type::Tensor(self.a.clone()), Type::Tensor(self.b.clone())];
+
+        if let Some(c) = &self.c {
+            inputs.push(c.clone());
+        }
+
+        inputs
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let a = scope.tensor_use_owned(&self.a, node_position);
+        let b = scope.tensor_use_owned(&self.b, node_position);
+        let output = &self.output.name;
+
+        let a = match self.trans_a {
+            true => quote! { #a.transpose() },
+            false => a,
+        };
+        let b = match self.trans_b {
+            true => quote! { #b.transpose() },
+            false => b,
+        };
+
+        let mut product = quote! { #a.matmul(#b) };
+
+        if self.alpha != 1.0 {
+            let alpha = self.alpha.to_tokens();
+            product = quote! { #product.mul_scalar(#alpha) };
+        }
+
+        let c = match &self.c {
+            Some(Type::Tensor(c)) => {
+                let mut tokens = scope.tensor_use_owned(c, node_position);
+
+                // C is unidirectionally broadcastable to the output
+                if c.dim < 2 {
+                    tokens = quote! { #tokens.unsqueeze::<2>() };
+                }
+
+                if self.beta != 1.0 {
+                    let beta = self.beta.to_tokens();
+                    tokens = quote! { #tokens.mul_scalar(#beta) };
+                }
+
+                tokens
+            }
+            Some(Type::Scalar(c)) => {
+                let name = &c.name;
+                let mut tokens = quote! { #name };
+
+                if self.beta != 1.0 {
+                    let beta = Literal::f64_unsuffixed(self.beta);
+                    tokens = quote! { #tokens * #beta };
+                }
+
+                return quote! {
+                    let #output = #product.add_scalar(#tokens);
+                };
+            }
+            Some(_) => panic!("Gemm: C must be a tensor or a scalar"),
+            None => {
+                return quote! {
+                    let #output = #product;
+                }
+            }
+        };
+
+        quote! {
+            let #output = #product + #c;
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Gemm(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{gemm::GemmNode, test::assert_tokens},
+        ScalarKind, ScalarType, TensorType,
+    };
+
+    #[test]
+    fn test_codegen() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(GemmNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_float("tensor2", 2),
+            Some(Type::Tensor(TensorType::new_float("tensor3", 1))),
+            TensorType::new_float("tensor4", 2),
+            2.0,
+            0.5,
+            false,
+            true,
+        ));
+
+        graph.register_input_output(
+            vec![
+                "tensor1".to_string(),
+                "tensor2".to_string(),
+                "tensor3".to_string(),
+            ],
+            vec!["tensor4".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+
+                #[allow(clippy::let_and_return)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 2>,
+                    tensor2: Tensor<B, 2>,
+                    tensor3: Tensor<B, 1>
+                ) -> Tensor<B, 2> {
+                    let tensor4 = tensor1
+                        .matmul(tensor2.transpose())
+                        .mul_scalar(2)
+                        + tensor3.unsqueeze::<2>().mul_scalar(0.5);
+
+                    tensor4
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_no_bias() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(GemmNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_float("tensor2", 2),
+            None,
+            TensorType::new_float("tensor3", 2),
+            1.0,
+            1.0,
+            true,
+            false,
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>, tensor2: Tensor<B, 2>) -> Tensor<B, 2> {
+                    let tensor3 = tensor1.transpose().matmul(tensor2);
+
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_scalar_c() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(GemmNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_float("tensor2", 2),
+            Some(Type::Scalar(ScalarType::new(
+                "scalar1",
+                ScalarKind::Float32,
+            ))),
+            TensorType::new_float("tensor3", 2),
+            1.0,
+            2.0,
+            false,
+            false,
+        ));
+
+        graph.register_input_output(
+            vec![
+                "tensor1".to_string(),
+                "tensor2".to_string(),
+                "scalar1".to_string(),
+            ],
+            vec!["tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+
+                #[allow(clippy::let_and_return)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 2>,
+                    tensor2: Tensor<B, 2>,
+                    scalar1: f32
+                ) -> Tensor<B, 2> {
+                    let tensor3 = tensor1.matmul(tensor2).add_scalar(scalar1 * 2.0);
+
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
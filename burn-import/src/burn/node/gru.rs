@@ -0,0 +1,493 @@
+use super::{
+    rnn::{RnnDirection, RnnGateData},
+    Node, NodeCodegen, SerializationBackend,
+};
+use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+use burn::{
+    module::ConstantRecord,
+    nn::gru::{GruConfig, GruRecord},
+    record::{PrecisionSettings, Record},
+};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use serde::Serialize;
+
+/// The weights of the gates for one direction.
+#[derive(Debug, Clone)]
+pub struct GruWeights<PS: PrecisionSettings> {
+    pub update_gate: RnnGateData<PS>,
+    pub reset_gate: RnnGateData<PS>,
+    pub new_gate: RnnGateData<PS>,
+}
+
+/// GRU with the ONNX semantics, producing `Y` and optionally `Y_h`.
+///
+/// A [burn GRU](burn::nn::gru::Gru) module is used per direction, so the field is a vector of
+/// modules when the node is bidirectional.
+#[derive(Debug, Clone)]
+pub struct GruNode<PS: PrecisionSettings> {
+    pub field: OtherType,
+    pub input: TensorType,
+    pub initial_state: Option<TensorType>,
+    pub output: TensorType,
+    pub output_hidden: Option<TensorType>,
+    pub weights: Vec<GruWeights<PS>>,
+    pub config: GruConfig,
+    pub direction: RnnDirection,
+    pub batch_first: bool,
+}
+
+impl<PS: PrecisionSettings> GruNode<PS> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<S: AsRef<str>>(
+        name: S,
+        input: TensorType,
+        initial_state: Option<TensorType>,
+        output: TensorType,
+        output_hidden: Option<TensorType>,
+        weights: Vec<GruWeights<PS>>,
+        config: GruConfig,
+        direction: RnnDirection,
+        batch_first: bool,
+    ) -> Self {
+        let ty = match direction {
+            RnnDirection::Bidirectional => quote! { Vec<Gru<B>> },
+            _ => quote! { Gru<B> },
+        };
+
+        Self {
+            field: OtherType::new(name, ty),
+            input,
+            initial_state,
+            output,
+            output_hidden,
+            weights,
+            config,
+            direction,
+            batch_first,
+        }
+    }
+
+    fn record(weights: &GruWeights<PS>) -> GruRecord<SerializationBackend> {
+        GruRecord {
+            update_gate: weights.update_gate.record(),
+            reset_gate: weights.reset_gate.record(),
+            new_gate: weights.new_gate.record(),
+            d_hidden: ConstantRecord::new(),
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for GruNode<PS> {
+    fn input_types(&self) -> Vec<Type> {
+        let mut inputs = vec![Type::Tensor(self.input.clone())];
+
+        if let Some(hidden) = &self.initial_state {
+            inputs.push(Type::Tensor(hidden.clone()));
+        }
+
+        inputs
+    }
+
+    fn output_types(&self) -> Vec<Type> {
+        [Some(&self.output), self.output_hidden.as_ref()]
+            .into_iter()
+            .flatten()
+            .map(|output| Type::Tensor(output.clone()))
+            .collect()
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Other(self.field.clone()))
+    }
+
+    fn field_init(&self, with_record: bool) -> Option<TokenStream> {
+        let name = &self.field.name;
+        let d_input = self.config.d_input.to_tokens();
+        let d_hidden = self.config.d_hidden.to_tokens();
+        let bias = self.config.bias;
+        let reset_after = self.config.reset_after;
+
+        let config = quote! {
+            GruConfig::new(#d_input, #d_hidden, #bias).with_reset_after(#reset_after)
+        };
+
+        let tokens = match (self.direction, with_record) {
+            (RnnDirection::Bidirectional, true) => quote! {
+                let #name = record.#name
+                    .into_iter()
+                    .map(|record| #config.init_with(record))
+                    .collect();
+            },
+            (RnnDirection::Bidirectional, false) => quote! {
+                let #name = (0..2).map(|_| #config.init()).collect();
+            },
+            (_, true) => quote! {
+                let #name = #config.init_with(record.#name);
+            },
+            (_, false) => quote! {
+                let #name = #config.init();
+            },
+        };
+
+        Some(tokens)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.direction {
+            RnnDirection::Bidirectional => {
+                let records = self.weights.iter().map(Self::record).collect::<Vec<_>>();
+                Record::into_item::<PS>(records).serialize(serializer)
+            }
+            _ => Record::into_item::<PS>(Self::record(&self.weights[0])).serialize(serializer),
+        }
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let field = &self.field.name;
+        let d_hidden = self.config.d_hidden.to_tokens();
+        let num_directions = self.direction.num_directions();
+        let with_hidden = self.output_hidden.is_some();
+
+        let mut body = match self.batch_first {
+            true => quote! { let sequence = #input; },
+            false => quote! { let sequence = #input.swap_dims(0, 1); },
+        };
+
+        if let Some(hidden) = &self.initial_state {
+            let hidden = scope.tensor_use_owned(hidden, node_position);
+
+            body.extend(quote! {
+                let initial_hidden = #hidden;
+            });
+        }
+
+        body.extend(quote! {
+            let [batch_size, seq_length, _] = sequence.dims();
+        });
+
+        if with_hidden {
+            body.extend(quote! {
+                let last = Tensor::arange(seq_length - 1..seq_length);
+            });
+        }
+        if (0..num_directions).any(|direction| self.direction.is_reverse(direction)) {
+            body.extend(quote! {
+                let reversed = Tensor::arange(0..seq_length)
+                    .mul_scalar(-1)
+                    .add_scalar(seq_length as i64 - 1);
+            });
+        }
+
+        // The initial state is [num_directions, batch_size, hidden_size], or
+        // [batch_size, num_directions, hidden_size] when the layout is batch first.
+        let state_dim = match self.batch_first {
+            true => 1usize.to_tokens(),
+            false => 0usize.to_tokens(),
+        };
+
+        for direction in 0..num_directions {
+            let is_last = direction == num_directions - 1;
+            let reverse = self.direction.is_reverse(direction);
+            let clone = match is_last {
+                true => quote! {},
+                false => quote! { .clone() },
+            };
+
+            let module = match self.direction {
+                RnnDirection::Bidirectional => {
+                    let index = direction.to_tokens();
+                    quote! { self.#field[#index] }
+                }
+                _ => quote! { self.#field },
+            };
+
+            let sequence = match reverse {
+                true => quote! { sequence #clone.select(1, reversed.clone()) },
+                false => quote! { sequence #clone },
+            };
+
+            let state = match (&self.initial_state, num_directions) {
+                (None, _) => quote! { None },
+                (Some(_), 1) => quote! { Some(initial_hidden.squeeze(#state_dim)) },
+                (Some(_), _) => {
+                    let start = direction.to_tokens();
+                    let end = (direction + 1).to_tokens();
+                    let range = match self.batch_first {
+                        true => quote! { [0..batch_size, #start..#end] },
+                        false => quote! { [#start..#end] },
+                    };
+
+                    quote! { Some(initial_hidden #clone.slice(#range).squeeze(#state_dim)) }
+                }
+            };
+
+            body.extend(quote! {
+                let hidden_states = #module.forward(#sequence, #state);
+            });
+
+            let y = format_ident!("y_{}", direction);
+            let y_h = format_ident!("y_h_{}", direction);
+
+            if with_hidden {
+                body.extend(quote! {
+                    let #y_h = hidden_states.clone().select(1, last.clone());
+                });
+            }
+
+            // The outputs of the reversed direction are put back in the sequence order
+            let hidden_states = match reverse {
+                true => quote! { hidden_states.select(1, reversed.clone()) },
+                false => quote! { hidden_states },
+            };
+
+            body.extend(quote! {
+                let #y = #hidden_states.reshape([batch_size, seq_length, 1, #d_hidden]);
+            });
+        }
+
+        // Concatenate the directions, giving [batch_size, seq_length, num_directions, hidden_size]
+        // for Y and [batch_size, num_directions, hidden_size] for Y_h.
+        let concat = |prefix: &str, dim: usize| {
+            let tensors = (0..num_directions)
+                .map(|direction| format_ident!("{}_{}", prefix, direction))
+                .collect::<Vec<_>>();
+
+            match num_directions {
+                1 => quote! { #(#tensors)* },
+                _ => {
+                    let dim = dim.to_tokens();
+                    quote! { Tensor::cat(vec![#(#tensors),*], #dim) }
+                }
+            }
+        };
+
+        let y = concat("y", 2);
+        body.extend(match self.batch_first {
+            true => quote! { let y = #y; },
+            false => quote! { let y = #y.swap_dims(0, 1).swap_dims(1, 2); },
+        });
+
+        let output = &self.output.name;
+
+        match &self.output_hidden {
+            Some(output_hidden) => {
+                let y_h = concat("y_h", 1);
+                let output_hidden = &output_hidden.name;
+
+                body.extend(match self.batch_first {
+                    true => quote! { let y_h = #y_h; },
+                    false => quote! { let y_h = #y_h.swap_dims(0, 1); },
+                });
+
+                quote! {
+                    let (#output, #output_hidden) = {
+                        #body
+
+                        (y, y_h)
+                    };
+                }
+            }
+            None => quote! {
+                let #output = {
+                    #body
+
+                    y
+                };
+            },
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::nn::gru::Gru");
+        imports.register("burn::nn::gru::GruConfig");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Gru(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+    use burn::{record::FullPrecisionSettings, tensor::Data};
+
+    fn weights() -> GruWeights<FullPrecisionSettings> {
+        let gate = || RnnGateData {
+            input_weight: Data::from([[2.]]).serialize(),
+            hidden_weight: Data::from([[2.]]).serialize(),
+            input_bias: None,
+            hidden_bias: None,
+        };
+
+        GruWeights {
+            update_gate: gate(),
+            reset_gate: gate(),
+            new_gate: gate(),
+        }
+    }
+
+    #[test]
+    fn test_codegen_forward() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(GruNode::new(
+            "gru",
+            TensorType::new_float("input", 3),
+            None,
+            TensorType::new_float("output", 4),
+            None,
+            vec![weights()],
+            GruConfig::new(4, 8, true).with_reset_after(true),
+            RnnDirection::Forward,
+            true,
+        ));
+
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::gru::Gru;
+            use burn::nn::gru::GruConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                gru: Gru<B>,
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    let gru = GruConfig::new(4, 8, true)
+                        .with_reset_after(true)
+                        .init_with(record.gru);
+
+                    Self {
+                        gru,
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 4> {
+                    let output = {
+                        let sequence = input;
+                        let [batch_size, seq_length, _] = sequence.dims();
+                        let hidden_states = self.gru.forward(sequence, None);
+                        let y_0 = hidden_states.reshape([batch_size, seq_length, 1, 8]);
+                        let y = y_0;
+
+                        y
+                    };
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_bidirectional() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(GruNode::new(
+            "gru",
+            TensorType::new_float("input", 3),
+            Some(TensorType::new_float("initial_h", 3)),
+            TensorType::new_float("output", 4),
+            Some(TensorType::new_float("output_h", 3)),
+            vec![weights(), weights()],
+            GruConfig::new(4, 8, false),
+            RnnDirection::Bidirectional,
+            false,
+        ));
+
+        graph.register_input_output(
+            vec!["input".to_string(), "initial_h".to_string()],
+            vec!["output".to_string(), "output_h".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::gru::Gru;
+            use burn::nn::gru::GruConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                gru: Vec<Gru<B>>,
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    let gru = record
+                        .gru
+                        .into_iter()
+                        .map(|record| {
+                            GruConfig::new(4, 8, false)
+                                .with_reset_after(false)
+                                .init_with(record)
+                        })
+                        .collect();
+
+                    Self {
+                        gru,
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(
+                    &self,
+                    input: Tensor<B, 3>,
+                    initial_h: Tensor<B, 3>,
+                ) -> (Tensor<B, 4>, Tensor<B, 3>) {
+                    let (output, output_h) = {
+                        let sequence = input.swap_dims(0, 1);
+                        let initial_hidden = initial_h;
+                        let [batch_size, seq_length, _] = sequence.dims();
+                        let last = Tensor::arange(seq_length - 1..seq_length);
+                        let reversed = Tensor::arange(0..seq_length)
+                            .mul_scalar(-1)
+                            .add_scalar(seq_length as i64 - 1);
+                        let hidden_states = self.gru[0].forward(
+                            sequence.clone(),
+                            Some(initial_hidden.clone().slice([0..1]).squeeze(0)),
+                        );
+                        let y_h_0 = hidden_states.clone().select(1, last.clone());
+                        let y_0 = hidden_states.reshape([batch_size, seq_length, 1, 8]);
+                        let hidden_states = self.gru[1].forward(
+                            sequence.select(1, reversed.clone()),
+                            Some(initial_hidden.slice([1..2]).squeeze(0)),
+                        );
+                        let y_h_1 = hidden_states.clone().select(1, last.clone());
+                        let y_1 = hidden_states
+                            .select(1, reversed.clone())
+                            .reshape([batch_size, seq_length, 1, 8]);
+                        let y = Tensor::cat(vec![y_0, y_1], 2)
+                            .swap_dims(0, 1)
+                            .swap_dims(1, 2);
+                        let y_h = Tensor::cat(vec![y_h_0, y_h_1], 1).swap_dims(0, 1);
+
+                        (y, y_h)
+                    };
+
+                    (output, output_h)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,158 @@
+use super::{
+    subgraph::{condition, tuple, use_capture, Subgraph},
+    Node, NodeCodegen,
+};
+use crate::burn::{BurnImports, Scope, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Runs the nodes of one of the two branches depending on the condition.
+#[derive(Debug, new)]
+pub struct IfNode<PS: PrecisionSettings> {
+    pub condition: Type,
+    pub then_branch: Subgraph<PS>,
+    pub else_branch: Subgraph<PS>,
+    pub outputs: Vec<Type>,
+}
+
+impl<PS: PrecisionSettings> IfNode<PS> {
+    fn captures(&self) -> Vec<Type> {
+        let mut captures = self.then_branch.captures();
+        for capture in self.else_branch.captures() {
+            if !captures.iter().any(|other| other.name() == capture.name()) {
+                captures.push(capture);
+            }
+        }
+
+        captures
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for IfNode<PS> {
+    fn output_types(&self) -> Vec<Type> {
+        self.outputs.clone()
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        let mut types = vec![self.condition.clone()];
+        types.extend(self.captures());
+
+        types
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let value = match &self.condition {
+            Type::Tensor(tensor) => scope.tensor_use_owned(tensor, node_position),
+            ty => {
+                let name = ty.name();
+                quote! { #name }
+            }
+        };
+        let condition = condition(value, &self.condition);
+        self.captures()
+            .iter()
+            .for_each(|capture| use_capture(capture, scope, node_position));
+
+        let outputs = tuple(
+            self.outputs
+                .iter()
+                .map(|output| {
+                    let name = output.name();
+                    quote! { #name }
+                })
+                .collect(),
+        );
+        let then_branch = self.then_branch.block();
+        let else_branch = self.else_branch.block();
+
+        quote! {
+            let #outputs = if #condition #then_branch else #else_branch;
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        self.then_branch.register_imports(imports);
+        self.else_branch.register_imports(imports);
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::If(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{binary::BinaryNode, if_op::IfNode, test::assert_tokens, unary::UnaryNode},
+        ScalarKind, ScalarType, TensorType,
+    };
+
+    #[test]
+    fn test_codegen_nodes() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        let tensor = |name| Type::Tensor(TensorType::new_float(name, 2));
+        graph.register(IfNode::new(
+            Type::Scalar(ScalarType::new("scalar1", ScalarKind::Bool)),
+            Subgraph::new(
+                vec![
+                    BinaryNode::add(tensor("tensor1"), tensor("tensor1"), tensor("tensor3"))
+                        .into_node(),
+                ],
+                vec![],
+                vec![tensor("tensor3")],
+            ),
+            Subgraph::new(
+                vec![UnaryNode::relu(tensor("tensor1"), tensor("tensor4")).into_node()],
+                vec![],
+                vec![tensor("tensor4")],
+            ),
+            vec![tensor("tensor2")],
+        ));
+
+        graph.register_input_output(
+            vec!["scalar1".to_string(), "tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, scalar1: bool, tensor1: Tensor<B, 2>) -> Tensor<B, 2> {
+                    let tensor2 = if scalar1 {
+                        let tensor3 = tensor1.clone().add(tensor1.clone());
+                        tensor3
+                    } else {
+                        let tensor4 = burn::tensor::activation::relu(tensor1.clone());
+                        tensor4
+                    };
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,137 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use burn::{nn::LeakyReLUConfig, record::PrecisionSettings};
+
+use super::{Node, NodeCodegen};
+use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+
+#[derive(Debug, Clone)]
+pub struct LeakyReluNode {
+    pub field: OtherType,
+    pub input: TensorType,
+    pub output: TensorType,
+    pub config: LeakyReLUConfig,
+}
+
+impl LeakyReluNode {
+    pub fn new<S: AsRef<str>>(
+        name: S,
+        input: TensorType,
+        output: TensorType,
+        config: LeakyReLUConfig,
+    ) -> Self {
+        Self {
+            field: OtherType::new(
+                name,
+                quote! {
+                    LeakyReLU
+                },
+            ),
+            input,
+            output,
+            config,
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for LeakyReluNode {
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Other(self.field.clone()))
+    }
+
+    fn field_init(&self, _with_record: bool) -> Option<TokenStream> {
+        let name = &self.field.name;
+        let negative_slope = self.config.negative_slope.to_tokens();
+
+        let tokens = quote! {
+            let #name = LeakyReLUConfig::new()
+                .with_negative_slope(#negative_slope)
+                .init();
+        };
+
+        Some(tokens)
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let field = &self.field.name;
+
+        quote! {
+            let #output = self.#field.forward(#input);
+        }
+    }
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::nn::LeakyReLU");
+        imports.register("burn::nn::LeakyReLUConfig");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::LeakyRelu(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+    use burn::record::FullPrecisionSettings;
+
+    #[test]
+    fn test_codegen() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(LeakyReluNode::new(
+            "leaky_relu",
+            TensorType::new_float("input", 4),
+            TensorType::new_float("output", 4),
+            LeakyReLUConfig::new().with_negative_slope(0.2),
+        ));
+
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::LeakyReLU;
+            use burn::nn::LeakyReLUConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                leaky_relu: LeakyReLU,
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    let leaky_relu = LeakyReLUConfig::new()
+                        .with_negative_slope(0.2)
+                        .init();
+
+                    Self {
+                        leaky_relu,
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let output = self.leaky_relu.forward(input);
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
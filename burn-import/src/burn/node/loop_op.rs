@@ -0,0 +1,259 @@
+use super::{
+    subgraph::{assign, condition, stack, tuple, use_capture, Subgraph},
+    Node, NodeCodegen,
+};
+use crate::burn::{BurnImports, Scope, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Runs the body while the condition holds and the number of iterations is below the maximum trip
+/// count, carrying values from an iteration to the next.
+///
+/// The body reads the iteration number, the condition and the carried values, and returns the next
+/// condition, the next carried values and the scan outputs, which are stacked along a new first
+/// axis after the last iteration.
+#[derive(Debug, new)]
+pub struct LoopNode<PS: PrecisionSettings> {
+    pub max_trip_count: Option<Type>,
+    pub condition: Option<Type>,
+    pub initial_values: Vec<Type>,
+    pub body: Subgraph<PS>,
+    pub outputs: Vec<Type>,
+}
+
+/// The value of a variable, which can also be a tensor of a single element.
+fn use_value(ty: &Type, scope: &mut Scope, node_position: usize) -> TokenStream {
+    match ty {
+        Type::Tensor(tensor) => scope.tensor_use_owned(tensor, node_position),
+        ty => {
+            let name = ty.name();
+            quote! { #name }
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for LoopNode<PS> {
+    fn output_types(&self) -> Vec<Type> {
+        self.outputs.clone()
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        let mut types = Vec::new();
+        types.extend(self.max_trip_count.clone());
+        types.extend(self.condition.clone());
+        types.extend(self.initial_values.iter().cloned());
+        types.extend(self.body.captures());
+
+        types
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let max_trip_count = match &self.max_trip_count {
+            Some(Type::Tensor(tensor)) => {
+                let tensor = scope.tensor_use_owned(tensor, node_position);
+                quote! { #tensor.into_data().convert::<i64>().value[0] }
+            }
+            Some(ty) => use_value(ty, scope, node_position),
+            None => quote! { i64::MAX },
+        };
+        let initial_condition = match &self.condition {
+            Some(ty) => condition(use_value(ty, scope, node_position), ty),
+            None => quote! { true },
+        };
+        let initial_values = self
+            .initial_values
+            .iter()
+            .map(|ty| use_value(ty, scope, node_position))
+            .collect::<Vec<_>>();
+        self.body
+            .captures()
+            .iter()
+            .for_each(|capture| use_capture(capture, scope, node_position));
+
+        let names = |types: &[Type]| {
+            types
+                .iter()
+                .map(|ty| {
+                    let name = ty.name();
+                    quote! { #name }
+                })
+                .collect::<Vec<_>>()
+        };
+        let num_carried = self.initial_values.len();
+        let iteration = self.body.inputs[0].name();
+        let body_condition = self.body.inputs[1].name();
+        let carried = names(&self.body.inputs[2..]);
+        let scans = names(&self.outputs[num_carried..]);
+
+        let (body, mut body_scope) = self.body.forward();
+        let scan_values = (0..scans.len())
+            .map(|index| self.body.output(1 + num_carried + index, &mut body_scope))
+            .collect::<Vec<_>>();
+        let next_condition = condition(self.body.output(0, &mut body_scope), &self.body.outputs[0]);
+        let mut variables = vec![quote! { #body_condition }];
+        variables.extend(carried.iter().cloned());
+        let mut next_values = vec![next_condition];
+        next_values
+            .extend((0..num_carried).map(|index| self.body.output(1 + index, &mut body_scope)));
+
+        // The variables passed as is to the next iteration are not mutable.
+        let mut declarations = variables
+            .iter()
+            .zip(next_values.iter())
+            .map(
+                |(variable, value)| match variable.to_string() == value.to_string() {
+                    true => quote! { let #variable },
+                    false => quote! { let mut #variable },
+                },
+            )
+            .collect::<Vec<_>>();
+        let next_values = assign(variables, next_values);
+        let condition_declaration = declarations.remove(0);
+
+        let mut values = carried.clone();
+        values.extend(
+            scans
+                .iter()
+                .zip(self.outputs[num_carried..].iter())
+                .map(|(scan, output)| stack(scan.clone(), output, 0, false)),
+        );
+        let values = tuple(values);
+        let outputs = tuple(names(&self.outputs));
+
+        quote! {
+            let #outputs = {
+                let max_trip_count = #max_trip_count;
+                let mut #iteration = 0;
+                #condition_declaration = #initial_condition;
+                #(#declarations = #initial_values;)*
+                #(let mut #scans = Vec::new();)*
+
+                while #body_condition && #iteration < max_trip_count {
+                    #body
+                    #(#scans.push(#scan_values);)*
+                    #next_values
+                    #iteration += 1;
+                }
+
+                #values
+            };
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        self.body.register_imports(imports);
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Loop(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{binary::BinaryNode, loop_op::LoopNode, test::assert_tokens},
+        ScalarKind, ScalarType, TensorType,
+    };
+
+    #[test]
+    fn test_codegen_nodes() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        let tensor = |name, dim| Type::Tensor(TensorType::new_float(name, dim));
+        let scalar = |name, kind| Type::Scalar(ScalarType::new(name, kind));
+        graph.register(LoopNode::new(
+            Some(scalar("scalar1", ScalarKind::Int64)),
+            None,
+            vec![tensor("tensor1", 2)],
+            Subgraph::new(
+                vec![BinaryNode::add(
+                    tensor("loop1_in3", 2),
+                    tensor("tensor2", 2),
+                    tensor("add1_out1", 2),
+                )
+                .into_node()],
+                vec![
+                    scalar("loop1_in1", ScalarKind::Int64),
+                    scalar("loop1_in2", ScalarKind::Bool),
+                    tensor("loop1_in3", 2),
+                ],
+                vec![
+                    scalar("loop1_in2", ScalarKind::Bool),
+                    tensor("add1_out1", 2),
+                    tensor("add1_out1", 2),
+                ],
+            ),
+            vec![tensor("tensor3", 2), tensor("tensor4", 3)],
+        ));
+
+        graph.register_input_output(
+            vec![
+                "scalar1".to_string(),
+                "tensor1".to_string(),
+                "tensor2".to_string(),
+            ],
+            vec!["tensor3".to_string(), "tensor4".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(
+                    &self,
+                    scalar1: i64,
+                    tensor1: Tensor<B, 2>,
+                    tensor2: Tensor<B, 2>
+                ) -> (Tensor<B, 2>, Tensor<B, 3>) {
+                    let (tensor3, tensor4) = {
+                        let max_trip_count = scalar1;
+                        let mut loop1_in1 = 0;
+                        let loop1_in2 = true;
+                        let mut loop1_in3 = tensor1;
+                        let mut tensor4 = Vec::new();
+
+                        while loop1_in2 && loop1_in1 < max_trip_count {
+                            let add1_out1 = loop1_in3.add(tensor2.clone());
+                            tensor4.push(add1_out1.clone());
+                            loop1_in3 = add1_out1;
+                            loop1_in1 += 1;
+                        }
+
+                        (
+                            loop1_in3,
+                            Tensor::cat(
+                                tensor4.into_iter().map(|tensor| tensor.unsqueeze::<3>()).collect(),
+                                0,
+                            )
+                        )
+                    };
+
+                    (tensor3, tensor4)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,623 @@
+use super::{
+    rnn::{RnnDirection, RnnGateData},
+    Node, NodeCodegen, SerializationBackend,
+};
+use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+use burn::{
+    module::ConstantRecord,
+    nn::{LstmConfig, LstmRecord},
+    record::{PrecisionSettings, Record},
+};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use serde::Serialize;
+
+/// The weights of the gates for one direction.
+#[derive(Debug, Clone)]
+pub struct LstmWeights<PS: PrecisionSettings> {
+    pub input_gate: RnnGateData<PS>,
+    pub forget_gate: RnnGateData<PS>,
+    pub output_gate: RnnGateData<PS>,
+    pub cell_gate: RnnGateData<PS>,
+}
+
+/// LSTM with the ONNX semantics, producing `Y` and optionally `Y_h` and `Y_c`.
+///
+/// A [burn LSTM](burn::nn::Lstm) module is used per direction, so the field is a vector of
+/// modules when the node is bidirectional.
+#[derive(Debug, Clone)]
+pub struct LstmNode<PS: PrecisionSettings> {
+    pub field: OtherType,
+    pub input: TensorType,
+    pub initial_state: Option<(TensorType, TensorType)>,
+    pub output: TensorType,
+    pub output_hidden: Option<TensorType>,
+    pub output_cell: Option<TensorType>,
+    pub weights: Vec<LstmWeights<PS>>,
+    pub config: LstmConfig,
+    pub direction: RnnDirection,
+    pub batch_first: bool,
+}
+
+impl<PS: PrecisionSettings> LstmNode<PS> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<S: AsRef<str>>(
+        name: S,
+        input: TensorType,
+        initial_state: Option<(TensorType, TensorType)>,
+        output: TensorType,
+        output_hidden: Option<TensorType>,
+        output_cell: Option<TensorType>,
+        weights: Vec<LstmWeights<PS>>,
+        config: LstmConfig,
+        direction: RnnDirection,
+        batch_first: bool,
+    ) -> Self {
+        let ty = match direction {
+            RnnDirection::Bidirectional => quote! { Vec<Lstm<B>> },
+            _ => quote! { Lstm<B> },
+        };
+
+        Self {
+            field: OtherType::new(name, ty),
+            input,
+            initial_state,
+            output,
+            output_hidden,
+            output_cell,
+            weights,
+            config,
+            direction,
+            batch_first,
+        }
+    }
+
+    fn record(weights: &LstmWeights<PS>) -> LstmRecord<SerializationBackend> {
+        LstmRecord {
+            input_gate: weights.input_gate.record(),
+            forget_gate: weights.forget_gate.record(),
+            output_gate: weights.output_gate.record(),
+            cell_gate: weights.cell_gate.record(),
+            d_hidden: ConstantRecord::new(),
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for LstmNode<PS> {
+    fn input_types(&self) -> Vec<Type> {
+        let mut inputs = vec![Type::Tensor(self.input.clone())];
+
+        if let Some((hidden, cell)) = &self.initial_state {
+            inputs.push(Type::Tensor(hidden.clone()));
+            inputs.push(Type::Tensor(cell.clone()));
+        }
+
+        inputs
+    }
+
+    fn output_types(&self) -> Vec<Type> {
+        [
+            Some(&self.output),
+            self.output_hidden.as_ref(),
+            self.output_cell.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|output| Type::Tensor(output.clone()))
+        .collect()
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Other(self.field.clone()))
+    }
+
+    fn field_init(&self, with_record: bool) -> Option<TokenStream> {
+        let name = &self.field.name;
+        let d_input = self.config.d_input.to_tokens();
+        let d_hidden = self.config.d_hidden.to_tokens();
+        let bias = self.config.bias;
+
+        let config = quote! {
+            LstmConfig::new(#d_input, #d_hidden, #bias)
+        };
+
+        let tokens = match (self.direction, with_record) {
+            (RnnDirection::Bidirectional, true) => quote! {
+                let #name = record.#name
+                    .into_iter()
+                    .map(|record| #config.init_with(record))
+                    .collect();
+            },
+            (RnnDirection::Bidirectional, false) => quote! {
+                let #name = (0..2).map(|_| #config.init()).collect();
+            },
+            (_, true) => quote! {
+                let #name = #config.init_with(record.#name);
+            },
+            (_, false) => quote! {
+                let #name = #config.init();
+            },
+        };
+
+        Some(tokens)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.direction {
+            RnnDirection::Bidirectional => {
+                let records = self.weights.iter().map(Self::record).collect::<Vec<_>>();
+                Record::into_item::<PS>(records).serialize(serializer)
+            }
+            _ => Record::into_item::<PS>(Self::record(&self.weights[0])).serialize(serializer),
+        }
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let field = &self.field.name;
+        let d_hidden = self.config.d_hidden.to_tokens();
+        let num_directions = self.direction.num_directions();
+        let with_hidden = self.output_hidden.is_some();
+        let with_cell = self.output_cell.is_some();
+
+        let mut body = match self.batch_first {
+            true => quote! { let sequence = #input; },
+            false => quote! { let sequence = #input.swap_dims(0, 1); },
+        };
+
+        if let Some((hidden, cell)) = &self.initial_state {
+            let hidden = scope.tensor_use_owned(hidden, node_position);
+            let cell = scope.tensor_use_owned(cell, node_position);
+
+            body.extend(quote! {
+                let initial_hidden = #hidden;
+                let initial_cell = #cell;
+            });
+        }
+
+        body.extend(quote! {
+            let [batch_size, seq_length, _] = sequence.dims();
+        });
+
+        if with_hidden || with_cell {
+            body.extend(quote! {
+                let last = Tensor::arange(seq_length - 1..seq_length);
+            });
+        }
+        if (0..num_directions).any(|direction| self.direction.is_reverse(direction)) {
+            body.extend(quote! {
+                let reversed = Tensor::arange(0..seq_length)
+                    .mul_scalar(-1)
+                    .add_scalar(seq_length as i64 - 1);
+            });
+        }
+
+        // The initial states are [num_directions, batch_size, hidden_size], or
+        // [batch_size, num_directions, hidden_size] when the layout is batch first.
+        let state_dim = match self.batch_first {
+            true => 1usize.to_tokens(),
+            false => 0usize.to_tokens(),
+        };
+
+        for direction in 0..num_directions {
+            let is_last = direction == num_directions - 1;
+            let reverse = self.direction.is_reverse(direction);
+            let clone = match is_last {
+                true => quote! {},
+                false => quote! { .clone() },
+            };
+
+            let module = match self.direction {
+                RnnDirection::Bidirectional => {
+                    let index = direction.to_tokens();
+                    quote! { self.#field[#index] }
+                }
+                _ => quote! { self.#field },
+            };
+
+            let sequence = match reverse {
+                true => quote! { sequence #clone.select(1, reversed.clone()) },
+                false => quote! { sequence #clone },
+            };
+
+            let state = match (&self.initial_state, num_directions) {
+                (None, _) => quote! { None },
+                (Some(_), 1) => quote! {
+                    Some((initial_cell.squeeze(#state_dim), initial_hidden.squeeze(#state_dim)))
+                },
+                (Some(_), _) => {
+                    let start = direction.to_tokens();
+                    let end = (direction + 1).to_tokens();
+                    let range = match self.batch_first {
+                        true => quote! { [0..batch_size, #start..#end] },
+                        false => quote! { [#start..#end] },
+                    };
+
+                    quote! {
+                        Some((
+                            initial_cell #clone.slice(#range).squeeze(#state_dim),
+                            initial_hidden #clone.slice(#range).squeeze(#state_dim),
+                        ))
+                    }
+                }
+            };
+
+            let cell_states = match with_cell {
+                true => quote! { cell_states },
+                false => quote! { _ },
+            };
+
+            body.extend(quote! {
+                let (#cell_states, hidden_states) = #module.forward(#sequence, #state);
+            });
+
+            let y = format_ident!("y_{}", direction);
+            let y_h = format_ident!("y_h_{}", direction);
+            let y_c = format_ident!("y_c_{}", direction);
+
+            if with_hidden {
+                body.extend(quote! {
+                    let #y_h = hidden_states.clone().select(1, last.clone());
+                });
+            }
+            if with_cell {
+                body.extend(quote! {
+                    let #y_c = cell_states.select(1, last.clone());
+                });
+            }
+
+            // The outputs of the reversed direction are put back in the sequence order
+            let hidden_states = match reverse {
+                true => quote! { hidden_states.select(1, reversed.clone()) },
+                false => quote! { hidden_states },
+            };
+
+            body.extend(quote! {
+                let #y = #hidden_states.reshape([batch_size, seq_length, 1, #d_hidden]);
+            });
+        }
+
+        // Concatenate the directions, giving [batch_size, seq_length, num_directions, hidden_size]
+        // for Y and [batch_size, num_directions, hidden_size] for Y_h and Y_c.
+        let concat = |prefix: &str, dim: usize| {
+            let tensors = (0..num_directions)
+                .map(|direction| format_ident!("{}_{}", prefix, direction))
+                .collect::<Vec<_>>();
+
+            match num_directions {
+                1 => quote! { #(#tensors)* },
+                _ => {
+                    let dim = dim.to_tokens();
+                    quote! { Tensor::cat(vec![#(#tensors),*], #dim) }
+                }
+            }
+        };
+
+        let mut outputs = Vec::new();
+        let mut returned = Vec::new();
+
+        let y = concat("y", 2);
+        body.extend(match self.batch_first {
+            true => quote! { let y = #y; },
+            false => quote! { let y = #y.swap_dims(0, 1).swap_dims(1, 2); },
+        });
+        outputs.push(&self.output.name);
+        returned.push(quote! { y });
+
+        for (output, prefix) in [(&self.output_hidden, "y_h"), (&self.output_cell, "y_c")] {
+            if let Some(output) = output {
+                let tensor = concat(prefix, 1);
+                let name = format_ident!("{}", prefix);
+
+                body.extend(match self.batch_first {
+                    true => quote! { let #name = #tensor; },
+                    false => quote! { let #name = #tensor.swap_dims(0, 1); },
+                });
+                outputs.push(&output.name);
+                returned.push(quote! { #name });
+            }
+        }
+
+        match outputs.len() {
+            1 => quote! {
+                let #(#outputs)* = {
+                    #body
+
+                    #(#returned)*
+                };
+            },
+            _ => quote! {
+                let (#(#outputs),*) = {
+                    #body
+
+                    (#(#returned),*)
+                };
+            },
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::nn::Lstm");
+        imports.register("burn::nn::LstmConfig");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Lstm(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+    use burn::{record::FullPrecisionSettings, tensor::Data};
+
+    fn weights() -> LstmWeights<FullPrecisionSettings> {
+        let gate = || RnnGateData {
+            input_weight: Data::from([[2.]]).serialize(),
+            hidden_weight: Data::from([[2.]]).serialize(),
+            input_bias: None,
+            hidden_bias: None,
+        };
+
+        LstmWeights {
+            input_gate: gate(),
+            forget_gate: gate(),
+            output_gate: gate(),
+            cell_gate: gate(),
+        }
+    }
+
+    #[test]
+    fn test_codegen_forward() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(LstmNode::new(
+            "lstm",
+            TensorType::new_float("input", 3),
+            None,
+            TensorType::new_float("output", 4),
+            None,
+            None,
+            vec![weights()],
+            LstmConfig::new(4, 8, true),
+            RnnDirection::Forward,
+            true,
+        ));
+
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::Lstm;
+            use burn::nn::LstmConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                lstm: Lstm<B>,
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    let lstm = LstmConfig::new(4, 8, true).init_with(record.lstm);
+
+                    Self {
+                        lstm,
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 4> {
+                    let output = {
+                        let sequence = input;
+                        let [batch_size, seq_length, _] = sequence.dims();
+                        let (_, hidden_states) = self.lstm.forward(sequence, None);
+                        let y_0 = hidden_states.reshape([batch_size, seq_length, 1, 8]);
+                        let y = y_0;
+
+                        y
+                    };
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_reverse() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(LstmNode::new(
+            "lstm",
+            TensorType::new_float("input", 3),
+            None,
+            TensorType::new_float("output", 4),
+            Some(TensorType::new_float("output_h", 3)),
+            None,
+            vec![weights()],
+            LstmConfig::new(4, 8, false),
+            RnnDirection::Reverse,
+            false,
+        ));
+
+        graph.register_input_output(
+            vec!["input".to_string()],
+            vec!["output".to_string(), "output_h".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::Lstm;
+            use burn::nn::LstmConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                lstm: Lstm<B>,
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    let lstm = LstmConfig::new(4, 8, false).init_with(record.lstm);
+
+                    Self {
+                        lstm,
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, input: Tensor<B, 3>) -> (Tensor<B, 4>, Tensor<B, 3>) {
+                    let (output, output_h) = {
+                        let sequence = input.swap_dims(0, 1);
+                        let [batch_size, seq_length, _] = sequence.dims();
+                        let last = Tensor::arange(seq_length - 1..seq_length);
+                        let reversed = Tensor::arange(0..seq_length)
+                            .mul_scalar(-1)
+                            .add_scalar(seq_length as i64 - 1);
+                        let (_, hidden_states) = self.lstm.forward(sequence.select(1, reversed.clone()), None);
+                        let y_h_0 = hidden_states.clone().select(1, last.clone());
+                        let y_0 = hidden_states
+                            .select(1, reversed.clone())
+                            .reshape([batch_size, seq_length, 1, 8]);
+                        let y = y_0.swap_dims(0, 1).swap_dims(1, 2);
+                        let y_h = y_h_0.swap_dims(0, 1);
+
+                        (y, y_h)
+                    };
+
+                    (output, output_h)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_bidirectional() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(LstmNode::new(
+            "lstm",
+            TensorType::new_float("input", 3),
+            Some((
+                TensorType::new_float("initial_h", 3),
+                TensorType::new_float("initial_c", 3),
+            )),
+            TensorType::new_float("output", 4),
+            Some(TensorType::new_float("output_h", 3)),
+            Some(TensorType::new_float("output_c", 3)),
+            vec![weights(), weights()],
+            LstmConfig::new(4, 8, false),
+            RnnDirection::Bidirectional,
+            false,
+        ));
+
+        graph.register_input_output(
+            vec![
+                "input".to_string(),
+                "initial_h".to_string(),
+                "initial_c".to_string(),
+            ],
+            vec![
+                "output".to_string(),
+                "output_h".to_string(),
+                "output_c".to_string(),
+            ],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::Lstm;
+            use burn::nn::LstmConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                lstm: Vec<Lstm<B>>,
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    let lstm = record
+                        .lstm
+                        .into_iter()
+                        .map(|record| LstmConfig::new(4, 8, false).init_with(record))
+                        .collect();
+
+                    Self {
+                        lstm,
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(
+                    &self,
+                    input: Tensor<B, 3>,
+                    initial_h: Tensor<B, 3>,
+                    initial_c: Tensor<B, 3>,
+                ) -> (Tensor<B, 4>, Tensor<B, 3>, Tensor<B, 3>) {
+                    let (output, output_h, output_c) = {
+                        let sequence = input.swap_dims(0, 1);
+                        let initial_hidden = initial_h;
+                        let initial_cell = initial_c;
+                        let [batch_size, seq_length, _] = sequence.dims();
+                        let last = Tensor::arange(seq_length - 1..seq_length);
+                        let reversed = Tensor::arange(0..seq_length)
+                            .mul_scalar(-1)
+                            .add_scalar(seq_length as i64 - 1);
+                        let (cell_states, hidden_states) = self.lstm[0].forward(
+                            sequence.clone(),
+                            Some((
+                                initial_cell.clone().slice([0..1]).squeeze(0),
+                                initial_hidden.clone().slice([0..1]).squeeze(0),
+                            )),
+                        );
+                        let y_h_0 = hidden_states.clone().select(1, last.clone());
+                        let y_c_0 = cell_states.select(1, last.clone());
+                        let y_0 = hidden_states.reshape([batch_size, seq_length, 1, 8]);
+                        let (cell_states, hidden_states) = self.lstm[1].forward(
+                            sequence.select(1, reversed.clone()),
+                            Some((
+                                initial_cell.slice([1..2]).squeeze(0),
+                                initial_hidden.slice([1..2]).squeeze(0),
+                            )),
+                        );
+                        let y_h_1 = hidden_states.clone().select(1, last.clone());
+                        let y_c_1 = cell_states.select(1, last.clone());
+                        let y_1 = hidden_states
+                            .select(1, reversed.clone())
+                            .reshape([batch_size, seq_length, 1, 8]);
+                        let y = Tensor::cat(vec![y_0, y_1], 2)
+                            .swap_dims(0, 1)
+                            .swap_dims(1, 2);
+                        let y_h = Tensor::cat(vec![y_h_0, y_h_1], 1).swap_dims(0, 1);
+                        let y_c = Tensor::cat(vec![y_c_0, y_c_1], 1).swap_dims(0, 1);
+
+                        (y, y_h, y_c)
+                    };
+
+                    (output, output_h, output_c)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -1,5 +1,5 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{Scope, TensorType, Type};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
 use burn::record::PrecisionSettings;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -24,12 +24,77 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for MatmulNode {
     }
 
     fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
-        let lhs = scope.tensor_use_owned(&self.lhs, node_position);
-        let rhs = scope.tensor_use_owned(&self.rhs, node_position);
+        let mut lhs = scope.tensor_use_owned(&self.lhs, node_position);
+        let mut rhs = scope.tensor_use_owned(&self.rhs, node_position);
         let output = &self.output.name;
 
+        // The dot product of two vectors is a scalar, kept in a tensor of one element
+        if self.lhs.dim == 1 && self.rhs.dim == 1 {
+            return quote! {
+                let #output = #lhs.mul(#rhs).sum();
+            };
+        }
+
+        // Both operands are promoted to the same rank following the numpy semantics: a vector
+        // becomes a row on the left side and a column on the right side, and the missing leading
+        // dimensions are broadcasted.
+        let dim = usize::max(self.lhs.dim, self.rhs.dim).max(2);
+
+        if self.lhs.dim < dim {
+            let dim_tokens = dim.to_tokens();
+            lhs = quote! { #lhs.unsqueeze::<#dim_tokens>() };
+        }
+
+        if self.rhs.dim == 1 {
+            rhs = quote! { #rhs.unsqueeze::<2>().transpose() };
+        }
+
+        if self.rhs.dim < dim && dim > 2 {
+            let dim_tokens = dim.to_tokens();
+            rhs = quote! { #rhs.unsqueeze::<#dim_tokens>() };
+        }
+
+        let mut product = quote! { #lhs.matmul(#rhs) };
+        let mut product_dim = dim;
+
+        // The batch dimensions of size 1 are expanded on both operands to the broadcasted batch
+        // shape, since the backends only broadcast the batch of one of the operands.
+        if dim > 2 {
+            let batch_dims = (dim - 2).to_tokens();
+            product = quote! {
+                {
+                    let (lhs, rhs) = (#lhs, #rhs);
+                    let mut lhs_shape = lhs.dims();
+                    let mut rhs_shape = rhs.dims();
+
+                    for (lhs_size, rhs_size) in lhs_shape.iter_mut().zip(rhs_shape.iter_mut()).take(#batch_dims) {
+                        let size = usize::max(*lhs_size, *rhs_size);
+                        *lhs_size = size;
+                        *rhs_size = size;
+                    }
+
+                    lhs.expand(lhs_shape).matmul(rhs.expand(rhs_shape))
+                }
+            };
+        }
+
+        // The dimensions added to the vectors are removed from the product
+        if self.rhs.dim == 1 {
+            product_dim -= 1;
+            let dim_tokens = product_dim.to_tokens();
+            let squeeze_dim = product_dim.to_tokens();
+            product = quote! { #product.squeeze::<#dim_tokens>(#squeeze_dim) };
+        }
+
+        if self.lhs.dim == 1 {
+            product_dim -= 1;
+            let dim_tokens = product_dim.to_tokens();
+            let squeeze_dim = (product_dim - 1).to_tokens();
+            product = quote! { #product.squeeze::<#dim_tokens>(#squeeze_dim) };
+        }
+
         quote! {
-            let #output = #lhs.matmul(#rhs);
+            let #output = #product;
         }
     }
 
@@ -86,7 +151,299 @@ mod tests {
 
                 #[allow(clippy::let_and_return)]
                 pub fn forward(&self, tensor1: Tensor<B, 4>, tensor2: Tensor<B, 4>) -> Tensor<B, 4> {
-                    let tensor3 = tensor1.matmul(tensor2);
+                    let tensor3 = {
+                        let (lhs, rhs) = (tensor1, tensor2);
+                        let mut lhs_shape = lhs.dims();
+                        let mut rhs_shape = rhs.dims();
+
+                        for (lhs_size, rhs_size) in lhs_shape.iter_mut().zip(rhs_shape.iter_mut()).take(2) {
+                            let size = usize::max(*lhs_size, *rhs_size);
+                            *lhs_size = size;
+                            *rhs_size = size;
+                        }
+
+                        lhs.expand(lhs_shape).matmul(rhs.expand(rhs_shape))
+                    };
+
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_broadcast_rank() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(MatmulNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_float("tensor2", 4),
+            TensorType::new_float("tensor3", 4),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>, tensor2: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor3 = {
+                        let (lhs, rhs) = (tensor1.unsqueeze::<4>(), tensor2);
+                        let mut lhs_shape = lhs.dims();
+                        let mut rhs_shape = rhs.dims();
+
+                        for (lhs_size, rhs_size) in lhs_shape.iter_mut().zip(rhs_shape.iter_mut()).take(2) {
+                            let size = usize::max(*lhs_size, *rhs_size);
+                            *lhs_size = size;
+                            *rhs_size = size;
+                        }
+
+                        lhs.expand(lhs_shape).matmul(rhs.expand(rhs_shape))
+                    };
+
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_broadcast_batch() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(MatmulNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 3),
+            TensorType::new_float("tensor3", 4),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>, tensor2: Tensor<B, 3>) -> Tensor<B, 4> {
+                    let tensor3 = {
+                        let (lhs, rhs) = (tensor1, tensor2.unsqueeze::<4>());
+                        let mut lhs_shape = lhs.dims();
+                        let mut rhs_shape = rhs.dims();
+
+                        for (lhs_size, rhs_size) in lhs_shape.iter_mut().zip(rhs_shape.iter_mut()).take(2) {
+                            let size = usize::max(*lhs_size, *rhs_size);
+                            *lhs_size = size;
+                            *rhs_size = size;
+                        }
+
+                        lhs.expand(lhs_shape).matmul(rhs.expand(rhs_shape))
+                    };
+
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_vector_lhs() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(MatmulNode::new(
+            TensorType::new_float("tensor1", 1),
+            TensorType::new_float("tensor2", 3),
+            TensorType::new_float("tensor3", 2),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 1>, tensor2: Tensor<B, 3>) -> Tensor<B, 2> {
+                    let tensor3 = {
+                        let (lhs, rhs) = (tensor1.unsqueeze::<3>(), tensor2);
+                        let mut lhs_shape = lhs.dims();
+                        let mut rhs_shape = rhs.dims();
+
+                        for (lhs_size, rhs_size) in lhs_shape.iter_mut().zip(rhs_shape.iter_mut()).take(1) {
+                            let size = usize::max(*lhs_size, *rhs_size);
+                            *lhs_size = size;
+                            *rhs_size = size;
+                        }
+
+                        lhs.expand(lhs_shape).matmul(rhs.expand(rhs_shape))
+                    }
+                    .squeeze::<2>(1);
+
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_vector_rhs() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(MatmulNode::new(
+            TensorType::new_float("tensor1", 3),
+            TensorType::new_float("tensor2", 1),
+            TensorType::new_float("tensor3", 2),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 3>, tensor2: Tensor<B, 1>) -> Tensor<B, 2> {
+                    let tensor3 = {
+                        let (lhs, rhs) = (tensor1, tensor2.unsqueeze::<2>().transpose().unsqueeze::<3>());
+                        let mut lhs_shape = lhs.dims();
+                        let mut rhs_shape = rhs.dims();
+
+                        for (lhs_size, rhs_size) in lhs_shape.iter_mut().zip(rhs_shape.iter_mut()).take(1) {
+                            let size = usize::max(*lhs_size, *rhs_size);
+                            *lhs_size = size;
+                            *rhs_size = size;
+                        }
+
+                        lhs.expand(lhs_shape).matmul(rhs.expand(rhs_shape))
+                    }
+                    .squeeze::<2>(2);
+
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_dot_product() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(MatmulNode::new(
+            TensorType::new_float("tensor1", 1),
+            TensorType::new_float("tensor2", 1),
+            TensorType::new_float("tensor3", 1),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 1>, tensor2: Tensor<B, 1>) -> Tensor<B, 1> {
+                    let tensor3 = tensor1.mul(tensor2).sum();
 
                     tensor3
                 }
@@ -5,15 +5,47 @@ pub(crate) mod batch_norm;
 pub(crate) mod binary;
 pub(crate) mod concat;
 pub(crate) mod constant;
+pub(crate) mod constant_of_shape;
 pub(crate) mod conv1d;
 pub(crate) mod conv2d;
+pub(crate) mod conv_transpose1d;
+pub(crate) mod conv_transpose2d;
+pub(crate) mod custom;
+pub(crate) mod depth_to_space;
 pub(crate) mod dropout;
+pub(crate) mod einsum;
+pub(crate) mod embedding;
+pub(crate) mod expand;
+pub(crate) mod gather;
+pub(crate) mod gemm;
 pub(crate) mod global_avg_pool;
+pub(crate) mod group_norm;
+pub(crate) mod gru;
+pub(crate) mod if_op;
+pub(crate) mod layer_norm;
+pub(crate) mod leaky_relu;
 pub(crate) mod linear;
+pub(crate) mod loop_op;
+pub(crate) mod lstm;
 pub(crate) mod matmul;
 pub(crate) mod max_pool2d;
+pub(crate) mod non_max_suppression;
+pub(crate) mod one_hot;
+pub(crate) mod pad;
+pub(crate) mod prelu;
 pub(crate) mod reshape;
+pub(crate) mod rnn;
+pub(crate) mod scan;
+pub(crate) mod scatter_elements;
+pub(crate) mod scatter_nd;
+pub(crate) mod shape;
+pub(crate) mod slice;
+pub(crate) mod space_to_depth;
+pub(crate) mod split;
+pub(crate) mod subgraph;
+pub(crate) mod tile;
 pub(crate) mod unary;
+pub(crate) mod where_op;
 
 pub(crate) use base::*;
 
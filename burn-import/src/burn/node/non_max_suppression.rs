@@ -0,0 +1,340 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{BurnImports, Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+/// Selects the boxes of each batch and class by decreasing score, skipping the boxes overlapping
+/// an already selected one by more than the IoU threshold.
+///
+/// The selection is sequential, so the boxes and scores are read back and the selection runs on
+/// the CPU. The output holds a `[batch, class, box]` row for each selected box.
+#[derive(Debug, Clone, new)]
+pub struct NonMaxSuppressionNode {
+    pub boxes: TensorType,
+    pub scores: TensorType,
+    pub max_output_boxes_per_class: Option<NmsParameter>,
+    pub iou_threshold: Option<NmsParameter>,
+    pub score_threshold: Option<NmsParameter>,
+    pub output: TensorType,
+    /// Whether the boxes are `[x_center, y_center, width, height]` instead of the corners.
+    pub center_point_box: bool,
+}
+
+/// A parameter of the selection, computed by the graph or constant.
+#[derive(Debug, Clone)]
+pub enum NmsParameter {
+    Input(Type),
+    Int(i64),
+    Float(f32),
+}
+
+impl NonMaxSuppressionNode {
+    fn optional_inputs(&self) -> impl Iterator<Item = &Type> {
+        [
+            &self.max_output_boxes_per_class,
+            &self.iou_threshold,
+            &self.score_threshold,
+        ]
+        .into_iter()
+        .flatten()
+        .filter_map(|parameter| match parameter {
+            NmsParameter::Input(input) => Some(input),
+            NmsParameter::Int(_) | NmsParameter::Float(_) => None,
+        })
+    }
+}
+
+/// The value of a parameter, whose input can be a scalar or a tensor of a single element.
+fn value(
+    parameter: &NmsParameter,
+    elem: TokenStream,
+    scope: &mut Scope,
+    node_position: usize,
+) -> TokenStream {
+    let input = match parameter {
+        NmsParameter::Input(input) => input,
+        NmsParameter::Int(value) => {
+            let value = Literal::i64_suffixed(*value);
+            return quote! { #value };
+        }
+        NmsParameter::Float(value) => {
+            let value = Literal::f32_suffixed(*value);
+            return quote! { #value };
+        }
+    };
+
+    match input {
+        Type::Scalar(scalar) => {
+            let name = &scalar.name;
+            quote! { #name as #elem }
+        }
+        Type::Tensor(tensor) => {
+            let tensor = scope.tensor_use_owned(tensor, node_position);
+            quote! { #tensor.into_data().convert::<#elem>().value[0] }
+        }
+        _ => panic!("NonMaxSuppression: the thresholds must be numbers"),
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for NonMaxSuppressionNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        let mut types = vec![
+            Type::Tensor(self.boxes.clone()),
+            Type::Tensor(self.scores.clone()),
+        ];
+        types.extend(self.optional_inputs().cloned());
+
+        types
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let boxes_name = &self.boxes.name;
+        let scores_name = &self.scores.name;
+        let boxes = scope.tensor_use_owned(&self.boxes, node_position);
+        let scores = scope.tensor_use_owned(&self.scores, node_position);
+        let output = &self.output.name;
+
+        // No box is selected by default.
+        let max_boxes = match &self.max_output_boxes_per_class {
+            Some(NmsParameter::Int(value)) => ((*value).max(0) as usize).to_tokens(),
+            Some(input) => {
+                let value = value(input, quote! { i64 }, scope, node_position);
+                quote! { (#value).max(0) as usize }
+            }
+            None => quote! { 0 },
+        };
+        let iou_threshold = match &self.iou_threshold {
+            Some(input) => value(input, quote! { f32 }, scope, node_position),
+            None => quote! { 0.0 },
+        };
+        let candidates = match &self.score_threshold {
+            Some(input) => {
+                let score_threshold = value(input, quote! { f32 }, scope, node_position);
+                quote! {
+                    (0..num_boxes).filter(|index| scores[*index] > #score_threshold)
+                }
+            }
+            None => quote! { (0..num_boxes) },
+        };
+
+        // The corners are ordered to compute the areas of the boxes.
+        let corners = match self.center_point_box {
+            true => quote! {
+                [
+                    b[1] - b[3] / 2.0,
+                    b[0] - b[2] / 2.0,
+                    b[1] + b[3] / 2.0,
+                    b[0] + b[2] / 2.0,
+                ]
+            },
+            false => quote! {
+                [b[0].min(b[2]), b[1].min(b[3]), b[0].max(b[2]), b[1].max(b[3])]
+            },
+        };
+
+        quote! {
+            let #output = {
+                let [batch_size, num_boxes, _] = #boxes_name.dims();
+                let num_classes = #scores_name.dims()[1];
+                let max_boxes = #max_boxes;
+                let iou_threshold = #iou_threshold;
+                let boxes = #boxes.into_data().convert::<f32>().value;
+                let scores = #scores.into_data().convert::<f32>().value;
+
+                let corners = |index: usize| {
+                    let b = &boxes[index * 4..index * 4 + 4];
+                    #corners
+                };
+                let iou = |a: [f32; 4], b: [f32; 4]| {
+                    let intersection = (a[2].min(b[2]) - a[0].max(b[0])).max(0.0)
+                        * (a[3].min(b[3]) - a[1].max(b[1])).max(0.0);
+                    let union = (a[2] - a[0]) * (a[3] - a[1]) + (b[2] - b[0]) * (b[3] - b[1])
+                        - intersection;
+
+                    if union > 0.0 {
+                        intersection / union
+                    } else {
+                        0.0
+                    }
+                };
+
+                let mut selected = Vec::new();
+                for batch in 0..batch_size {
+                    for class in 0..num_classes {
+                        let offset = (batch * num_classes + class) * num_boxes;
+                        let scores = &scores[offset..offset + num_boxes];
+                        let mut candidates = #candidates.collect::<Vec<_>>();
+                        candidates.sort_by(|a, b| scores[*b].total_cmp(&scores[*a]));
+
+                        let mut kept: Vec<[f32; 4]> = Vec::new();
+                        for index in candidates {
+                            if kept.len() == max_boxes {
+                                break;
+                            }
+
+                            let corners = corners(batch * num_boxes + index);
+                            if kept.iter().all(|other| iou(*other, corners) <= iou_threshold) {
+                                kept.push(corners);
+                                selected.extend([batch as i64, class as i64, index as i64]);
+                            }
+                        }
+                    }
+                }
+
+                let count = selected.len() / 3;
+                Tensor::<B, 2, Int>::from_data(Data::new(selected, Shape::new([count, 3])).convert())
+            };
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::tensor::Data");
+        imports.register("burn::tensor::Int");
+        imports.register("burn::tensor::Shape");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::NonMaxSuppression(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{
+            non_max_suppression::{NmsParameter, NonMaxSuppressionNode},
+            test::assert_tokens,
+        },
+        ScalarKind, ScalarType, TensorType,
+    };
+
+    #[test]
+    fn test_codegen_nodes() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(NonMaxSuppressionNode::new(
+            TensorType::new_float("tensor1", 3),
+            TensorType::new_float("tensor2", 3),
+            Some(NmsParameter::Input(Type::Scalar(ScalarType::new(
+                "scalar1",
+                ScalarKind::Int64,
+            )))),
+            Some(NmsParameter::Input(Type::Scalar(ScalarType::new(
+                "scalar2",
+                ScalarKind::Float32,
+            )))),
+            Some(NmsParameter::Float(0.25)),
+            TensorType::new_int("tensor3", 2),
+            false,
+        ));
+
+        graph.register_input_output(
+            vec![
+                "tensor1".to_string(),
+                "tensor2".to_string(),
+                "scalar1".to_string(),
+                "scalar2".to_string(),
+            ],
+            vec!["tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::tensor::Data;
+            use burn::tensor::Int;
+            use burn::tensor::Shape;
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 3>,
+                    tensor2: Tensor<B, 3>,
+                    scalar1: i64,
+                    scalar2: f32
+                ) -> Tensor<B, 2, Int> {
+                    let tensor3 = {
+                        let [batch_size, num_boxes, _] = tensor1.dims();
+                        let num_classes = tensor2.dims()[1];
+                        let max_boxes = (scalar1 as i64).max(0) as usize;
+                        let iou_threshold = scalar2 as f32;
+                        let boxes = tensor1.into_data().convert::<f32>().value;
+                        let scores = tensor2.into_data().convert::<f32>().value;
+
+                        let corners = |index: usize| {
+                            let b = &boxes[index * 4..index * 4 + 4];
+                            [b[0].min(b[2]), b[1].min(b[3]), b[0].max(b[2]), b[1].max(b[3])]
+                        };
+                        let iou = |a: [f32; 4], b: [f32; 4]| {
+                            let intersection = (a[2].min(b[2]) - a[0].max(b[0])).max(0.0)
+                                * (a[3].min(b[3]) - a[1].max(b[1])).max(0.0);
+                            let union = (a[2] - a[0]) * (a[3] - a[1]) + (b[2] - b[0]) * (b[3] - b[1])
+                                - intersection;
+
+                            if union > 0.0 {
+                                intersection / union
+                            } else {
+                                0.0
+                            }
+                        };
+
+                        let mut selected = Vec::new();
+                        for batch in 0..batch_size {
+                            for class in 0..num_classes {
+                                let offset = (batch * num_classes + class) * num_boxes;
+                                let scores = &scores[offset..offset + num_boxes];
+                                let mut candidates = (0..num_boxes)
+                                    .filter(|index| scores[*index] > 0.25f32)
+                                    .collect::<Vec<_>>();
+                                candidates.sort_by(|a, b| scores[*b].total_cmp(&scores[*a]));
+
+                                let mut kept: Vec<[f32; 4]> = Vec::new();
+                                for index in candidates {
+                                    if kept.len() == max_boxes {
+                                        break;
+                                    }
+
+                                    let corners = corners(batch * num_boxes + index);
+                                    if kept.iter().all(|other| iou(*other, corners) <= iou_threshold) {
+                                        kept.push(corners);
+                                        selected.extend([batch as i64, class as i64, index as i64]);
+                                    }
+                                }
+                            }
+                        }
+
+                        let count = selected.len() / 3;
+                        Tensor::<B, 2, Int>::from_data(Data::new(selected, Shape::new([count, 3])).convert())
+                    };
+
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
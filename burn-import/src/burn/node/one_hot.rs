@@ -0,0 +1,143 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{BurnImports, Scope, TensorKind, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+/// Sets the `on` value at the index of each element along a new axis of size `depth` and the
+/// `off` value elsewhere.
+///
+/// The negative indices count from the end, and the indices out of range only have `off` values.
+#[derive(Debug, Clone, new)]
+pub struct OneHotNode {
+    pub indices: TensorType,
+    pub output: TensorType,
+    pub depth: usize,
+    /// The `off` and `on` values.
+    pub values: [f64; 2],
+    pub axis: usize,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for OneHotNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.indices.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.indices, node_position);
+        let output = &self.output.name;
+
+        let indices = match self.indices.kind {
+            TensorKind::Int => input,
+            TensorKind::Float => quote! { #input.int() },
+            TensorKind::Bool => panic!("OneHot: the indices must be numbers"),
+        };
+
+        let depth = self.depth.to_tokens();
+        let dim = self.output.dim.to_tokens();
+        let [off, on] = self.values.map(|value| match self.output.kind {
+            TensorKind::Float => Literal::f64_unsuffixed(value),
+            TensorKind::Int => Literal::i64_unsuffixed(value as i64),
+            TensorKind::Bool => panic!("OneHot: the values must be numbers"),
+        });
+        let values = match self.output.kind {
+            TensorKind::Int => quote! { Tensor::<B, #dim, Int> },
+            _ => quote! { Tensor::<B, #dim> },
+        };
+
+        // The classes are the last dimension of the one hot masks, moved to the axis.
+        let swaps = (self.axis..self.indices.dim).rev().map(|axis| {
+            let (dim1, dim2) = ((axis + 1).to_tokens(), axis.to_tokens());
+            quote! { .swap_dims(#dim1, #dim2) }
+        });
+
+        quote! {
+            let #output = {
+                let indices = #indices;
+                let indices = indices
+                    .clone()
+                    .mask_where(indices.clone().lower_elem(0), indices.add_scalar(#depth));
+                let mask = indices.one_hot_bool::<#dim>(#depth)#(#swaps)*;
+
+                #values::full(mask.dims(), #off).mask_fill(mask, #on)
+            };
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::tensor::Int");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::OneHot(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{one_hot::OneHotNode, test::assert_tokens},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_nodes() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(OneHotNode::new(
+            TensorType::new_int("tensor1", 2),
+            TensorType::new_float("tensor2", 3),
+            10,
+            [0.0, 1.0],
+            1,
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::tensor::Int;
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 2, Int>) -> Tensor<B, 3> {
+                    let tensor2 = {
+                        let indices = tensor1;
+                        let indices = indices
+                            .clone()
+                            .mask_where(indices.clone().lower_elem(0), indices.add_scalar(10));
+                        let mask = indices.one_hot_bool::<3>(10).swap_dims(2, 1);
+
+                        Tensor::<B, 3>::full(mask.dims(), 0.0).mask_fill(mask, 1.0)
+                    };
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,105 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{BurnImports, Scope, TensorType, ToTokens, Type};
+use burn::{record::PrecisionSettings, tensor::PadMode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Debug, Clone, new)]
+pub struct PadNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub pads: Vec<(usize, usize)>,
+    pub mode: PadMode,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for PadNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let pads = self.pads.to_tokens();
+        let mode = self.mode.to_tokens();
+
+        quote! {
+            let #output = #input.pad(#pads, #mode);
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::tensor::PadMode");
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Pad(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{pad::PadNode, test::assert_tokens},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_nodes() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(PadNode::new(
+            TensorType::new_float("tensor1", 3),
+            TensorType::new_float("tensor2", 3),
+            vec![(0, 0), (1, 2), (0, 1)],
+            PadMode::Constant(0.0),
+        ));
+        graph.register(PadNode::new(
+            TensorType::new_float("tensor2", 3),
+            TensorType::new_float("tensor3", 3),
+            vec![(0, 0), (0, 0), (2, 2)],
+            PadMode::Reflect,
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor3".to_string()]);
+
+        let expected = quote! {
+            use burn::tensor::PadMode;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 3>) -> Tensor<B, 3> {
+                    let tensor2 = tensor1.pad([(0, 0), (1, 2), (0, 1)], PadMode::Constant(0.0));
+                    let tensor3 = tensor2.pad([(0, 0), (0, 0), (2, 2)], PadMode::Reflect);
+
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
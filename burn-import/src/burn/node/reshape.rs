@@ -1,4 +1,4 @@
-use super::{Node, NodeCodegen};
+use super::{shape::ShapeDim, Node, NodeCodegen};
 use crate::burn::{Scope, TensorType, ToTokens, Type};
 use burn::record::PrecisionSettings;
 use proc_macro2::TokenStream;
@@ -8,7 +8,9 @@ use quote::quote;
 pub struct ReshapeNode {
     pub input: TensorType,
     pub output: TensorType,
-    pub shape: Vec<i64>,
+    /// The sizes of the target shape, `0` keeping the size of the input axis and `-1` being
+    /// inferred from the other sizes.
+    pub shape: Vec<ShapeDim>,
 }
 
 impl<PS: PrecisionSettings> NodeCodegen<PS> for ReshapeNode {
@@ -17,16 +19,25 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ReshapeNode {
     }
 
     fn input_types(&self) -> Vec<Type> {
-        vec![Type::Tensor(self.input.clone())]
+        let mut types = vec![Type::Tensor(self.input.clone())];
+        types.extend(ShapeDim::input_types(&self.shape));
+
+        types
     }
 
     fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
         let input = scope.tensor_use_owned(&self.input, node_position);
         let output = &self.output.name;
-        let shape_values = &self.shape.to_tokens();
+        let shape_values = self.shape.iter().map(|dim| match dim {
+            ShapeDim::Static(_) => dim.to_tokens(),
+            ShapeDim::Runtime(..) => {
+                let size = dim.to_tokens();
+                quote! { #size as i32 }
+            }
+        });
 
         quote! {
-            let #output = #input.reshape(#shape_values);
+            let #output = #input.reshape([#(#shape_values),*]);
         }
     }
 
@@ -42,8 +53,8 @@ mod tests {
     use super::*;
     use crate::burn::{
         graph::BurnGraph,
-        node::{reshape::ReshapeNode, test::assert_tokens},
-        TensorType,
+        node::{reshape::ReshapeNode, shape::ShapeDim, test::assert_tokens},
+        ShapeType, TensorType,
     };
 
     #[test]
@@ -53,7 +64,7 @@ mod tests {
         graph.register(ReshapeNode::new(
             TensorType::new_float("tensor1", 4),
             TensorType::new_float("tensor2", 4),
-            [4, 4, 4, 4].into(),
+            [4, 4, 4, 4].map(ShapeDim::Static).into(),
         ));
 
         graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
@@ -87,4 +98,55 @@ mod tests {
 
         assert_tokens(graph.codegen(), expected);
     }
+
+    #[test]
+    fn test_codegen_runtime_shape() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        let shape = ShapeType::new("shape1", 3);
+
+        graph.register(ReshapeNode::new(
+            TensorType::new_float("tensor1", 3),
+            TensorType::new_float("tensor2", 4),
+            vec![
+                ShapeDim::Runtime(shape.clone(), 0),
+                ShapeDim::Static(-1),
+                ShapeDim::Static(8),
+                ShapeDim::Runtime(shape, 2),
+            ],
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "shape1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 3>, shape1: [usize; 3]) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.reshape([shape1[0] as i32, -1, 8, shape1[2] as i32]);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
 }
@@ -0,0 +1,62 @@
+use super::SerializationBackend;
+use burn::{
+    module::{Param, ParamId},
+    nn::{GateControllerRecord, LinearRecord},
+    record::PrecisionSettings,
+    tensor::{DataSerialize, Tensor},
+};
+
+/// The direction in which the sequence is processed by a recurrent node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RnnDirection {
+    Forward,
+    Reverse,
+    Bidirectional,
+}
+
+impl RnnDirection {
+    pub fn num_directions(&self) -> usize {
+        match self {
+            RnnDirection::Bidirectional => 2,
+            _ => 1,
+        }
+    }
+
+    pub(crate) fn is_reverse(&self, direction: usize) -> bool {
+        match self {
+            RnnDirection::Forward => false,
+            RnnDirection::Reverse => true,
+            RnnDirection::Bidirectional => direction == 1,
+        }
+    }
+}
+
+/// The weights of the input and hidden transformations of a gate.
+#[derive(Debug, Clone)]
+pub struct RnnGateData<PS: PrecisionSettings> {
+    pub input_weight: DataSerialize<PS::FloatElem>,
+    pub hidden_weight: DataSerialize<PS::FloatElem>,
+    pub input_bias: Option<DataSerialize<PS::FloatElem>>,
+    pub hidden_bias: Option<DataSerialize<PS::FloatElem>>,
+}
+
+impl<PS: PrecisionSettings> RnnGateData<PS> {
+    pub(crate) fn record(&self) -> GateControllerRecord<SerializationBackend> {
+        GateControllerRecord {
+            input_transform: LinearRecord {
+                weight: Self::param(&self.input_weight),
+                bias: self.input_bias.as_ref().map(Self::param),
+            },
+            hidden_transform: LinearRecord {
+                weight: Self::param(&self.hidden_weight),
+                bias: self.hidden_bias.as_ref().map(Self::param),
+            },
+        }
+    }
+
+    fn param<const D: usize>(
+        data: &DataSerialize<PS::FloatElem>,
+    ) -> Param<Tensor<SerializationBackend, D>> {
+        Param::new(ParamId::new(), Tensor::from_data(data.clone().convert()))
+    }
+}
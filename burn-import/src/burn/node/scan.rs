@@ -0,0 +1,291 @@
+use super::{
+    subgraph::{assign, stack, tuple, use_capture, Subgraph},
+    Node, NodeCodegen,
+};
+use crate::burn::{BurnImports, Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// The axis a scan input is sliced along or a scan output is stacked along, starting from the end
+/// of the axis when reversed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, new)]
+pub struct ScanAxis {
+    pub axis: usize,
+    pub reverse: bool,
+}
+
+/// Runs the body on each slice of the scan inputs along their axis, carrying the states from an
+/// iteration to the next.
+///
+/// The body reads the states and the slices, and returns the next states and the scan outputs,
+/// which are stacked along their axis after the last iteration.
+#[derive(Debug, new)]
+pub struct ScanNode<PS: PrecisionSettings> {
+    pub initial_states: Vec<Type>,
+    pub inputs: Vec<TensorType>,
+    pub body: Subgraph<PS>,
+    pub outputs: Vec<Type>,
+    pub input_axes: Vec<ScanAxis>,
+    pub output_axes: Vec<ScanAxis>,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for ScanNode<PS> {
+    fn output_types(&self) -> Vec<Type> {
+        self.outputs.clone()
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        let mut types = self.initial_states.clone();
+        types.extend(self.inputs.iter().cloned().map(Type::Tensor));
+        types.extend(self.body.captures());
+
+        types
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let initial_states = self
+            .initial_states
+            .iter()
+            .map(|ty| match ty {
+                Type::Tensor(tensor) => scope.tensor_use_owned(tensor, node_position),
+                ty => {
+                    let name = ty.name();
+                    quote! { #name }
+                }
+            })
+            .collect::<Vec<_>>();
+        // The scan inputs are cloned to be sliced at each iteration.
+        self.inputs
+            .iter()
+            .cloned()
+            .map(Type::Tensor)
+            .chain(self.body.captures())
+            .for_each(|capture| use_capture(&capture, scope, node_position));
+
+        let names = |types: &[Type]| {
+            types
+                .iter()
+                .map(|ty| {
+                    let name = ty.name();
+                    quote! { #name }
+                })
+                .collect::<Vec<_>>()
+        };
+        let num_states = self.initial_states.len();
+        let states = names(&self.body.inputs[..num_states]);
+        let slices = names(&self.body.inputs[num_states..]);
+        let scans = names(&self.outputs[num_states..]);
+
+        let length = {
+            let input = &self.inputs[0].name;
+            let axis = self.input_axes[0].axis.to_tokens();
+            quote! { #input.dims()[#axis] }
+        };
+        let slice_values = self
+            .inputs
+            .iter()
+            .zip(self.input_axes.iter())
+            .map(|(input, axis)| slice(input, axis))
+            .collect::<Vec<_>>();
+
+        let (body, mut body_scope) = self.body.forward();
+        let scan_values = (0..scans.len())
+            .map(|index| self.body.output(num_states + index, &mut body_scope))
+            .collect::<Vec<_>>();
+        let next_states = (0..num_states)
+            .map(|index| self.body.output(index, &mut body_scope))
+            .collect::<Vec<_>>();
+
+        // The states passed as is to the next iteration are not mutable.
+        let declarations = states
+            .iter()
+            .zip(next_states.iter())
+            .map(
+                |(state, value)| match state.to_string() == value.to_string() {
+                    true => quote! { let #state },
+                    false => quote! { let mut #state },
+                },
+            )
+            .collect::<Vec<_>>();
+        let next_states = assign(states.clone(), next_states);
+
+        let mut values = states;
+        values.extend(
+            scans
+                .iter()
+                .zip(self.outputs[num_states..].iter())
+                .zip(self.output_axes.iter())
+                .map(|((scan, output), axis)| stack(scan.clone(), output, axis.axis, axis.reverse)),
+        );
+        let values = tuple(values);
+        let outputs = tuple(names(&self.outputs));
+
+        quote! {
+            let #outputs = {
+                #(#declarations = #initial_states;)*
+                let length = #length;
+                #(let mut #scans = Vec::new();)*
+
+                for index in 0..length {
+                    #(let #slices = #slice_values;)*
+                    #body
+                    #(#scans.push(#scan_values);)*
+                    #next_states
+                }
+
+                #values
+            };
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        self.body.register_imports(imports);
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Scan(self)
+    }
+}
+
+/// The slice of a scan input at the current index, without the scanned axis.
+fn slice(input: &TensorType, axis: &ScanAxis) -> TokenStream {
+    if input.dim < 2 {
+        panic!("Scan: the scan inputs must have at least 2 dimensions");
+    }
+
+    let name = &input.name;
+    let dim = (input.dim - 1).to_tokens();
+    let mut statements = quote! {};
+    if axis.reverse {
+        statements.extend(quote! { let index = length - 1 - index; });
+    }
+    if axis.axis > 0 {
+        statements.extend(quote! { let dims = #name.dims(); });
+    }
+
+    let mut ranges = (0..axis.axis)
+        .map(|index| {
+            let index = index.to_tokens();
+            quote! { 0..dims[#index] }
+        })
+        .collect::<Vec<_>>();
+    ranges.push(quote! { index..index + 1 });
+    let value = {
+        let axis = axis.axis.to_tokens();
+        quote! { #name.clone().slice([#(#ranges),*]).squeeze::<#dim>(#axis) }
+    };
+
+    match statements.is_empty() {
+        true => value,
+        false => quote! {
+            {
+                #statements
+                #value
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{binary::BinaryNode, scan::ScanNode, test::assert_tokens},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_nodes() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        let tensor = |name, dim| Type::Tensor(TensorType::new_float(name, dim));
+        graph.register(ScanNode::new(
+            vec![tensor("tensor1", 1)],
+            vec![TensorType::new_float("tensor2", 2)],
+            Subgraph::new(
+                vec![BinaryNode::add(
+                    tensor("scan1_in1", 1),
+                    tensor("scan1_in2", 1),
+                    tensor("add1_out1", 1),
+                )
+                .into_node()],
+                vec![tensor("scan1_in1", 1), tensor("scan1_in2", 1)],
+                vec![tensor("add1_out1", 1), tensor("add1_out1", 1)],
+            ),
+            vec![tensor("tensor3", 1), tensor("tensor4", 2)],
+            vec![ScanAxis::new(1, true)],
+            vec![ScanAxis::new(1, false)],
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["tensor3".to_string(), "tensor4".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 1>,
+                    tensor2: Tensor<B, 2>
+                ) -> (Tensor<B, 1>, Tensor<B, 2>) {
+                    let (tensor3, tensor4) = {
+                        let mut scan1_in1 = tensor1;
+                        let length = tensor2.dims()[1];
+                        let mut tensor4 = Vec::new();
+
+                        for index in 0..length {
+                            let scan1_in2 = {
+                                let index = length - 1 - index;
+                                let dims = tensor2.dims();
+                                tensor2.clone().slice([0..dims[0], index..index + 1]).squeeze::<1>(1)
+                            };
+                            let add1_out1 = scan1_in1.add(scan1_in2);
+                            tensor4.push(add1_out1.clone());
+                            scan1_in1 = add1_out1;
+                        }
+
+                        (
+                            scan1_in1,
+                            Tensor::cat(
+                                tensor4
+                                    .into_iter()
+                                    .map(|tensor| {
+                                        let dims = tensor.dims();
+                                        tensor.reshape([dims[0], 1])
+                                    })
+                                    .collect(),
+                                1,
+                            )
+                        )
+                    };
+
+                    (tensor3, tensor4)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,181 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorKind, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// How the updates are combined with the values of the data at the same position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScatterReduction {
+    /// The values are replaced by the updates.
+    None,
+    /// The updates are added to the values.
+    Add,
+}
+
+/// Writes the updates in the data at the positions given by the indices along an axis.
+///
+/// Since `scatter` adds the updates to the data, the positions written are first set to zero when
+/// the values are replaced. The duplicated indices are summed in that case.
+#[derive(Debug, Clone, new)]
+pub struct ScatterElementsNode {
+    pub data: TensorType,
+    pub indices: TensorType,
+    pub updates: TensorType,
+    pub output: TensorType,
+    pub axis: usize,
+    pub reduction: ScatterReduction,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for ScatterElementsNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![
+            Type::Tensor(self.data.clone()),
+            Type::Tensor(self.indices.clone()),
+            Type::Tensor(self.updates.clone()),
+        ]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        if self.data.kind == TensorKind::Bool {
+            panic!("ScatterElements: the data must be numbers");
+        }
+
+        let data_name = &self.data.name;
+        let data = scope.tensor_use_owned(&self.data, node_position);
+        let indices = scope.tensor_use_owned(&self.indices, node_position);
+        let updates = scope.tensor_use_owned(&self.updates, node_position);
+        let output = &self.output.name;
+
+        let indices = match self.indices.kind {
+            TensorKind::Int => indices,
+            TensorKind::Float => quote! { #indices.int() },
+            TensorKind::Bool => panic!("ScatterElements: the indices must be numbers"),
+        };
+
+        let dim = self.data.dim.to_tokens();
+        let axis = self.axis.to_tokens();
+
+        let data = match self.reduction {
+            ScatterReduction::None => quote! {
+                #data.mask_fill(
+                    Tensor::<B, #dim>::zeros(dims)
+                        .scatter(#axis, indices.clone(), Tensor::ones(indices.dims()))
+                        .greater_elem(0),
+                    0,
+                )
+            },
+            ScatterReduction::Add => data,
+        };
+
+        quote! {
+            let #output = {
+                let dims = #data_name.dims();
+                let indices = #indices;
+                let indices = indices.clone().mask_where(
+                    indices.clone().lower_elem(0),
+                    indices.add_scalar(dims[#axis] as i64),
+                );
+
+                #data.scatter(#axis, indices, #updates)
+            };
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::ScatterElements(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{
+            scatter_elements::{ScatterElementsNode, ScatterReduction},
+            test::assert_tokens,
+        },
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_nodes() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ScatterElementsNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_int("tensor2", 2),
+            TensorType::new_float("tensor3", 2),
+            TensorType::new_float("tensor4", 2),
+            1,
+            ScatterReduction::None,
+        ));
+
+        graph.register_input_output(
+            vec![
+                "tensor1".to_string(),
+                "tensor2".to_string(),
+                "tensor3".to_string(),
+            ],
+            vec!["tensor4".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::tensor::Int;
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 2>,
+                    tensor2: Tensor<B, 2, Int>,
+                    tensor3: Tensor<B, 2>
+                ) -> Tensor<B, 2> {
+                    let tensor4 = {
+                        let dims = tensor1.dims();
+                        let indices = tensor2;
+                        let indices = indices.clone().mask_where(
+                            indices.clone().lower_elem(0),
+                            indices.add_scalar(dims[1] as i64),
+                        );
+
+                        tensor1
+                            .mask_fill(
+                                Tensor::<B, 2>::zeros(dims)
+                                    .scatter(1, indices.clone(), Tensor::ones(indices.dims()))
+                                    .greater_elem(0),
+                                0,
+                            )
+                            .scatter(1, indices, tensor3)
+                    };
+
+                    tensor4
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,194 @@
+use super::{scatter_elements::ScatterReduction, Node, NodeCodegen};
+use crate::burn::{Scope, TensorKind, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Writes the slices of the updates in the data at the positions given by the last axis of the
+/// indices, which index the leading axes of the data.
+///
+/// The indexed axes of the data are flattened in rows and each index is converted to the position
+/// of its row, so that `select_assign` writes the rows of the updates in the data.
+#[derive(Debug, Clone, new)]
+pub struct ScatterNdNode {
+    pub data: TensorType,
+    pub indices: TensorType,
+    pub updates: TensorType,
+    pub output: TensorType,
+    pub reduction: ScatterReduction,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for ScatterNdNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![
+            Type::Tensor(self.data.clone()),
+            Type::Tensor(self.indices.clone()),
+            Type::Tensor(self.updates.clone()),
+        ]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        if self.data.kind == TensorKind::Bool {
+            panic!("ScatterND: the data must be numbers");
+        }
+
+        let data_name = &self.data.name;
+        let indices_name = &self.indices.name;
+        let input = scope.tensor_use_owned(&self.data, node_position);
+        let indices = scope.tensor_use_owned(&self.indices, node_position);
+        let updates = scope.tensor_use_owned(&self.updates, node_position);
+        let output = &self.output.name;
+
+        let indices = match self.indices.kind {
+            TensorKind::Int => indices,
+            TensorKind::Float => quote! { #indices.int() },
+            TensorKind::Bool => panic!("ScatterND: the indices must be numbers"),
+        };
+
+        // The last axis of the indices holds the position in the indexed axes.
+        let last = (self.indices.dim - 1).to_tokens();
+
+        let data = match self.reduction {
+            ScatterReduction::None => quote! {
+                data.mask_fill(
+                    Tensor::<B, 2>::zeros([rows, row_size])
+                        .select_assign(0, indices.clone(), Tensor::ones([count, row_size]))
+                        .greater_elem(0),
+                    0,
+                )
+            },
+            ScatterReduction::Add => quote! { data },
+        };
+
+        quote! {
+            let #output = {
+                let dims = #data_name.dims();
+                let indices_dims = #indices_name.dims();
+                let depth = indices_dims[#last];
+                let count = indices_dims[..#last].iter().product::<usize>();
+                let rows = dims[..depth].iter().product::<usize>();
+                let row_size = dims[depth..].iter().product::<usize>();
+
+                let indices = #indices.reshape([count, depth]);
+                let indices = (0..depth)
+                    .map(|axis| {
+                        let stride = dims[axis + 1..depth].iter().product::<usize>();
+                        indices
+                            .clone()
+                            .slice([0..count, axis..axis + 1])
+                            .mul_scalar(stride as i64)
+                    })
+                    .reduce(|index, offset| index + offset)
+                    .unwrap()
+                    .reshape([count]);
+                let data = #input.reshape([rows, row_size]);
+
+                #data
+                    .select_assign(0, indices, #updates.reshape([count, row_size]))
+                    .reshape(dims)
+            };
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::ScatterNd(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{scatter_nd::ScatterNdNode, test::assert_tokens},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_nodes() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ScatterNdNode::new(
+            TensorType::new_float("tensor1", 3),
+            TensorType::new_int("tensor2", 2),
+            TensorType::new_float("tensor3", 2),
+            TensorType::new_float("tensor4", 3),
+            ScatterReduction::Add,
+        ));
+
+        graph.register_input_output(
+            vec![
+                "tensor1".to_string(),
+                "tensor2".to_string(),
+                "tensor3".to_string(),
+            ],
+            vec!["tensor4".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::tensor::Int;
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 3>,
+                    tensor2: Tensor<B, 2, Int>,
+                    tensor3: Tensor<B, 2>
+                ) -> Tensor<B, 3> {
+                    let tensor4 = {
+                        let dims = tensor1.dims();
+                        let indices_dims = tensor2.dims();
+                        let depth = indices_dims[1];
+                        let count = indices_dims[..1].iter().product::<usize>();
+                        let rows = dims[..depth].iter().product::<usize>();
+                        let row_size = dims[depth..].iter().product::<usize>();
+
+                        let indices = tensor2.reshape([count, depth]);
+                        let indices = (0..depth)
+                            .map(|axis| {
+                                let stride = dims[axis + 1..depth].iter().product::<usize>();
+                                indices
+                                    .clone()
+                                    .slice([0..count, axis..axis + 1])
+                                    .mul_scalar(stride as i64)
+                            })
+                            .reduce(|index, offset| index + offset)
+                            .unwrap()
+                            .reshape([count]);
+                        let data = tensor1.reshape([rows, row_size]);
+
+                        data
+                            .select_assign(0, indices, tensor3.reshape([count, row_size]))
+                            .reshape(dims)
+                    };
+
+                    tensor4
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,226 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, ShapeType, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// A size of a target shape, either known when the model is generated or read from a shape
+/// computed during the forward pass, such as a dynamic batch size.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShapeDim {
+    Static(i64),
+    Runtime(ShapeType, usize),
+}
+
+impl ShapeDim {
+    /// The computed shapes the sizes are read from, without duplicates.
+    pub fn input_types<'a, I: IntoIterator<Item = &'a ShapeDim>>(dims: I) -> Vec<Type> {
+        let mut types: Vec<Type> = Vec::new();
+
+        for dim in dims {
+            if let ShapeDim::Runtime(shape, _) = dim {
+                if !types.iter().any(|ty| ty.name() == &shape.name) {
+                    types.push(Type::Shape(shape.clone()));
+                }
+            }
+        }
+
+        types
+    }
+}
+
+impl From<i64> for ShapeDim {
+    fn from(size: i64) -> Self {
+        ShapeDim::Static(size)
+    }
+}
+
+impl ToTokens for ShapeDim {
+    fn to_tokens(&self) -> TokenStream {
+        match self {
+            ShapeDim::Static(size) => size.to_tokens(),
+            ShapeDim::Runtime(shape, index) => {
+                let name = &shape.name;
+                let index = index.to_tokens();
+                quote! { #name[#index] }
+            }
+        }
+    }
+}
+
+/// A target shape, either known when the model is generated, computed during the forward pass
+/// or made of sizes of both kinds.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShapeValue {
+    Static(Vec<usize>),
+    Runtime(ShapeType),
+    Dims(Vec<ShapeDim>),
+}
+
+impl ShapeValue {
+    /// The shape of the given sizes, static when none of them is computed.
+    pub fn from_dims(dims: Vec<ShapeDim>) -> Self {
+        let sizes = dims
+            .iter()
+            .map(|dim| match dim {
+                ShapeDim::Static(size) if *size >= 0 => Some(*size as usize),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>();
+
+        match sizes {
+            Some(sizes) => ShapeValue::Static(sizes),
+            None => ShapeValue::Dims(dims),
+        }
+    }
+
+    pub fn rank(&self) -> usize {
+        match self {
+            ShapeValue::Static(shape) => shape.len(),
+            ShapeValue::Runtime(shape) => shape.rank,
+            ShapeValue::Dims(dims) => dims.len(),
+        }
+    }
+
+    /// The computed shapes used as inputs of a node.
+    pub fn input_types(&self) -> Vec<Type> {
+        match self {
+            ShapeValue::Static(_) => Vec::new(),
+            ShapeValue::Runtime(shape) => vec![Type::Shape(shape.clone())],
+            ShapeValue::Dims(dims) => ShapeDim::input_types(dims),
+        }
+    }
+}
+
+impl ToTokens for ShapeValue {
+    fn to_tokens(&self) -> TokenStream {
+        match self {
+            ShapeValue::Static(shape) => shape.to_tokens(),
+            ShapeValue::Runtime(shape) => {
+                let name = &shape.name;
+                quote! { #name }
+            }
+            ShapeValue::Dims(dims) => {
+                let dims = dims.iter().map(|dim| dim.to_tokens());
+                quote! { [#(#dims),*] }
+            }
+        }
+    }
+}
+
+/// The sizes of the input dimensions from `start` to `end` excluded.
+#[derive(Debug, Clone, new)]
+pub struct ShapeNode {
+    pub input: TensorType,
+    pub output: ShapeType,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for ShapeNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Shape(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        // The input is only borrowed, but its use is still counted.
+        scope.tensor_use_owned(&self.input, node_position);
+
+        let input = &self.input.name;
+        let output = &self.output.name;
+
+        if self.start == 0 && self.end == self.input.dim {
+            return quote! {
+                let #output = #input.dims();
+            };
+        }
+
+        let sizes = (self.start..self.end).map(|i| {
+            let i = i.to_tokens();
+            quote! { dims[#i] }
+        });
+
+        quote! {
+            let #output = {
+                let dims = #input.dims();
+                [#(#sizes),*]
+            };
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Shape(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{shape::ShapeNode, test::assert_tokens},
+        ShapeType, TensorType,
+    };
+
+    #[test]
+    fn test_codegen_nodes() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ShapeNode::new(
+            TensorType::new_float("tensor1", 3),
+            ShapeType::new("shape1", 3),
+            0,
+            3,
+        ));
+        graph.register(ShapeNode::new(
+            TensorType::new_float("tensor1", 3),
+            ShapeType::new("shape2", 2),
+            1,
+            3,
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string()],
+            vec!["shape1".to_string(), "shape2".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 3>) -> ([usize; 3], [usize; 2]) {
+                    let shape1 = tensor1.dims();
+                    let shape2 = {
+                        let dims = tensor1.dims();
+                        [dims[1], dims[2]]
+                    };
+
+                    (shape1, shape2)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,363 @@
+use super::{shape::ShapeDim, Node, NodeCodegen};
+use crate::burn::{BurnImports, Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+/// Indices at or beyond this magnitude mean "up to the end" of a dimension, in the direction of
+/// the step. Exporters use `i32::MAX` or `i64::MAX` (and their negation) for them.
+const UNBOUNDED: i64 = i32::MAX as i64;
+
+/// The slice of one axis following the ONNX semantics: negative indices count from the end of
+/// the axis and a negative step walks it backward. The bounds of a forward slice may be read from
+/// a shape computed during the forward pass.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SliceRange {
+    pub axis: usize,
+    pub start: ShapeDim,
+    pub end: ShapeDim,
+    pub step: i64,
+}
+
+impl SliceRange {
+    pub fn new(
+        axis: usize,
+        start: impl Into<ShapeDim>,
+        end: impl Into<ShapeDim>,
+        step: i64,
+    ) -> Self {
+        Self {
+            axis,
+            start: start.into(),
+            end: end.into(),
+            step,
+        }
+    }
+
+    /// The selected indices of an axis of the given size, when the bounds are constant.
+    pub fn indices(&self, size: usize) -> Vec<usize> {
+        let (ShapeDim::Static(start), ShapeDim::Static(end)) = (&self.start, &self.end) else {
+            panic!("Slice: the bounds of {self:?} are only known during the forward pass");
+        };
+        let (start, end) = (*start, *end);
+        let size = size as i64;
+        let resolve = |index: i64| if index < 0 { index + size } else { index };
+
+        if self.step > 0 {
+            let start = resolve(start).clamp(0, size);
+            let end = resolve(end).clamp(0, size);
+
+            (start..end)
+                .step_by(self.step as usize)
+                .map(|index| index as usize)
+                .collect()
+        } else {
+            let start = resolve(start).clamp(0, size - 1);
+            let end = resolve(end).clamp(-1, size - 1);
+
+            (end + 1..=start)
+                .rev()
+                .step_by(-self.step as usize)
+                .map(|index| index as usize)
+                .collect()
+        }
+    }
+}
+
+#[derive(Debug, Clone, new)]
+pub struct SliceNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub ranges: Vec<SliceRange>,
+}
+
+// Generates the index expressions, keeping track of whether the `dims` of the input are used.
+#[derive(Default)]
+struct IndexCodegen {
+    uses_dims: bool,
+}
+
+impl IndexCodegen {
+    // The index of the axis, counted from its end when negative, or read from a computed shape
+    // and clamped to the size of the axis.
+    fn bound(&mut self, axis: usize, bound: &ShapeDim) -> TokenStream {
+        match bound {
+            ShapeDim::Static(index) => self.index(axis, *index),
+            ShapeDim::Runtime(..) => {
+                self.uses_dims = true;
+                let axis = axis.to_tokens();
+                let bound = bound.to_tokens();
+                quote! { usize::min(#bound, dims[#axis]) }
+            }
+        }
+    }
+
+    // The index of the axis, counted from its end when negative.
+    fn index(&mut self, axis: usize, index: i64) -> TokenStream {
+        if index >= 0 {
+            return (index as usize).to_tokens();
+        }
+
+        self.uses_dims = true;
+        let axis = axis.to_tokens();
+        let offset = (index.unsigned_abs() as usize).to_tokens();
+        quote! { dims[#axis] - #offset }
+    }
+
+    // The end of a forward range.
+    fn end(&mut self, axis: usize, end: &ShapeDim) -> TokenStream {
+        match end {
+            ShapeDim::Static(end) if *end >= UNBOUNDED => {
+                self.uses_dims = true;
+                let axis = axis.to_tokens();
+                quote! { dims[#axis] }
+            }
+            end => self.bound(axis, end),
+        }
+    }
+
+    // The size of the axis plus the offset when it is relative to the end, or the offset.
+    fn offset(&mut self, axis: usize, from_end: bool, offset: i64) -> TokenStream {
+        if !from_end {
+            return (offset.max(0) as usize).to_tokens();
+        }
+
+        self.uses_dims = true;
+        let axis = axis.to_tokens();
+        let size = (offset.unsigned_abs() as usize).to_tokens();
+        match offset {
+            0 => quote! { dims[#axis] },
+            offset if offset < 0 => quote! { dims[#axis] - #size },
+            _ => quote! { dims[#axis] + #size },
+        }
+    }
+
+    // The indices walking the axis backward from the start to the end excluded.
+    fn backward_indices(&mut self, range: &SliceRange) -> TokenStream {
+        let axis = range.axis;
+        let step = (range.step.unsigned_abs() as usize).to_tokens();
+        let (ShapeDim::Static(start), ShapeDim::Static(end)) = (&range.start, &range.end) else {
+            panic!("Slice: the bounds of a backward slice must be constant");
+        };
+
+        // The start and the first index of the walked range, which is right after the end, as
+        // offsets from the end of the axis or from its start.
+        let start = match *start {
+            start if start >= UNBOUNDED => (true, -1),
+            start => (start < 0, start),
+        };
+        let first = match *end {
+            end if end <= -UNBOUNDED => (false, 0),
+            end => (end < 0, end + 1),
+        };
+
+        let count = match (start.0, first.0) {
+            (false, true) => {
+                let start = self.offset(axis, start.0, start.1);
+                let first = self.offset(axis, first.0, first.1);
+                quote! { #start + 1 - #first }
+            }
+            (start_from_end, first_from_end) => self.offset(
+                axis,
+                start_from_end && !first_from_end,
+                start.1 - first.1 + 1,
+            ),
+        };
+        let start = match start {
+            (false, start) => {
+                let start = Literal::i64_suffixed(start);
+                quote! { #start }
+            }
+            (true, offset) => {
+                let start = self.offset(axis, true, offset);
+                quote! { (#start) as i64 }
+            }
+        };
+
+        quote! {
+            Tensor::<B, 1, Int>::arange_step(0..#count, #step)
+                .mul_scalar(-1)
+                .add_scalar(#start)
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for SliceNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        let bounds = self
+            .ranges
+            .iter()
+            .flat_map(|range| [&range.start, &range.end]);
+        let mut inputs = vec![Type::Tensor(self.input.clone())];
+        inputs.extend(ShapeDim::input_types(bounds));
+
+        inputs
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input_name = &self.input.name;
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+
+        // The unit step ranges are sliced together, the other ones are selected one by one.
+        let mut codegen = IndexCodegen::default();
+        let mut body = quote! { #input };
+        let contiguous = self
+            .ranges
+            .iter()
+            .filter(|range| range.step == 1)
+            .collect::<Vec<_>>();
+
+        if let Some(max_axis) = contiguous.iter().map(|range| range.axis).max() {
+            let mut ranges = quote! {};
+
+            for axis in 0..=max_axis {
+                let range = match contiguous.iter().find(|range| range.axis == axis) {
+                    Some(range) => {
+                        let start = codegen.bound(axis, &range.start);
+                        let end = codegen.end(axis, &range.end);
+                        quote! { #start..#end }
+                    }
+                    None => {
+                        codegen.uses_dims = true;
+                        let axis = axis.to_tokens();
+                        quote! { 0..dims[#axis] }
+                    }
+                };
+                ranges.extend(quote! { #range, });
+            }
+
+            body = quote! { #body.slice([#ranges]) };
+        }
+
+        for range in self.ranges.iter().filter(|range| range.step != 1) {
+            let axis = range.axis;
+            let indices = if range.step > 0 {
+                let start = codegen.bound(axis, &range.start);
+                let end = codegen.end(axis, &range.end);
+                let step = (range.step as usize).to_tokens();
+                quote! { Tensor::<B, 1, Int>::arange_step(#start..#end, #step) }
+            } else {
+                codegen.backward_indices(range)
+            };
+            let axis = axis.to_tokens();
+
+            body = quote! { #body.select(#axis, #indices) };
+        }
+
+        if codegen.uses_dims {
+            quote! {
+                let #output = {
+                    let dims = #input_name.dims();
+                    #body
+                };
+            }
+        } else {
+            quote! {
+                let #output = #body;
+            }
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        // The ranges with a step are selected with integer indices.
+        if self.ranges.iter().any(|range| range.step != 1) {
+            imports.register("burn::tensor::Int");
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Slice(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{slice::SliceNode, test::assert_tokens},
+        TensorType,
+    };
+
+    #[test]
+    fn test_indices() {
+        assert_eq!(SliceRange::new(0, 1, -1, 1).indices(5), vec![1, 2, 3]);
+        assert_eq!(SliceRange::new(0, 0, i64::MAX, 2).indices(5), vec![0, 2, 4]);
+        assert_eq!(
+            SliceRange::new(0, -1, i64::MIN, -1).indices(4),
+            vec![3, 2, 1, 0]
+        );
+        assert_eq!(SliceRange::new(0, 10, 0, -2).indices(5), vec![4, 2]);
+    }
+
+    #[test]
+    fn test_codegen_nodes() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(SliceNode::new(
+            TensorType::new_float("tensor1", 3),
+            TensorType::new_float("tensor2", 3),
+            vec![SliceRange::new(0, 1, 3, 1), SliceRange::new(1, 0, 2, 1)],
+        ));
+        graph.register(SliceNode::new(
+            TensorType::new_float("tensor2", 3),
+            TensorType::new_float("tensor3", 3),
+            vec![
+                SliceRange::new(1, -2, i64::MAX, 1),
+                SliceRange::new(2, 1, 6, 2),
+                SliceRange::new(0, -1, i64::MIN, -1),
+            ],
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor3".to_string()]);
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 3>) -> Tensor<B, 3> {
+                    let tensor2 = tensor1.slice([1..3, 0..2]);
+                    let tensor3 = {
+                        let dims = tensor2.dims();
+                        tensor2
+                            .slice([0..dims[0], dims[1] - 2..dims[1]])
+                            .select(2, Tensor::<B, 1, Int>::arange_step(1..6, 2))
+                            .select(
+                                0,
+                                Tensor::<B, 1, Int>::arange_step(0..dims[0], 1)
+                                    .mul_scalar(-1)
+                                    .add_scalar((dims[0] - 1) as i64)
+                            )
+                    };
+
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,119 @@
+use super::{depth_to_space::permute, Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Rearranges spatial blocks into blocks of channels, ordered by block row, block column and
+/// input channel.
+#[derive(Debug, Clone, new)]
+pub struct SpaceToDepthNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub block_size: usize,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for SpaceToDepthNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        if self.input.dim != 4 {
+            panic!("SpaceToDepth: the input must be of rank 4");
+        }
+
+        let input_name = &self.input.name;
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let block_size = self.block_size.to_tokens();
+        let num_blocks = (self.block_size * self.block_size).to_tokens();
+
+        let split = quote! {
+            [batch_size, channels, height / #block_size, #block_size, width / #block_size, #block_size]
+        };
+        let tensor = permute(quote! { #input.reshape(#split) }, &[0, 3, 5, 1, 2, 4]);
+
+        quote! {
+            let #output = {
+                let [batch_size, channels, height, width] = #input_name.dims();
+                #tensor.reshape([
+                    batch_size,
+                    channels * #num_blocks,
+                    height / #block_size,
+                    width / #block_size,
+                ])
+            };
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::SpaceToDepth(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{space_to_depth::SpaceToDepthNode, test::assert_tokens},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_nodes() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(SpaceToDepthNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 4),
+            2,
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = {
+                        let [batch_size, channels, height, width] = tensor1.dims();
+                        tensor1
+                            .reshape([batch_size, channels, height / 2, 2, width / 2, 2])
+                            .swap_dims(1, 3)
+                            .swap_dims(2, 5)
+                            .swap_dims(4, 5)
+                            .reshape([batch_size, channels * 4, height / 2, width / 2,])
+                    };
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
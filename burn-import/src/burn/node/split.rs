@@ -0,0 +1,258 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Splits the input along an axis into one output per part, either of the given sizes or of
+/// equal sizes when `None`. When the axis is not divisible, the last part is smaller.
+#[derive(Debug, Clone, new)]
+pub struct SplitNode {
+    pub input: TensorType,
+    pub outputs: Vec<TensorType>,
+    pub axis: usize,
+    pub sizes: Option<Vec<usize>>,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for SplitNode {
+    fn output_types(&self) -> Vec<Type> {
+        self.outputs
+            .iter()
+            .map(|output| Type::Tensor(output.clone()))
+            .collect()
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input_name = &self.input.name;
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let axis = self.axis.to_tokens();
+        let num_outputs = self.outputs.len();
+
+        // The ranges of the split axis.
+        let ranges = match &self.sizes {
+            Some(sizes) => {
+                if sizes.len() != num_outputs {
+                    panic!(
+                        "Split: {} sizes are given for {num_outputs} outputs",
+                        sizes.len()
+                    );
+                }
+
+                sizes
+                    .iter()
+                    .scan(0, |start, size| {
+                        let range = (*start).to_tokens();
+                        *start += size;
+                        let end = (*start).to_tokens();
+
+                        Some(quote! { #range..#end })
+                    })
+                    .collect::<Vec<_>>()
+            }
+            None => (0..num_outputs)
+                .map(|i| {
+                    let start = match i {
+                        0 => quote! { 0 },
+                        1 => quote! { size },
+                        _ => {
+                            let i = i.to_tokens();
+                            quote! { #i * size }
+                        }
+                    };
+                    let end = match i + 1 {
+                        end if end == num_outputs => quote! { dims[#axis] },
+                        1 => quote! { size },
+                        end => {
+                            let end = end.to_tokens();
+                            quote! { #end * size }
+                        }
+                    };
+
+                    quote! { #start..#end }
+                })
+                .collect(),
+        };
+
+        // The leading axes are fully kept, which requires their size.
+        let uses_dims = self.axis > 0 || self.sizes.is_none();
+        let leading_ranges = (0..self.axis).map(|axis| {
+            let axis = axis.to_tokens();
+            quote! { 0..dims[#axis], }
+        });
+        let leading_ranges = quote! { #(#leading_ranges)* };
+
+        let last = num_outputs - 1;
+        let parts = ranges.iter().enumerate().map(|(i, range)| {
+            let tensor = match i == last {
+                true => quote! { #input },
+                false => quote! { #input_name.clone() },
+            };
+
+            quote! { #tensor.slice([#leading_ranges #range]) }
+        });
+
+        let outputs = self.outputs.iter().map(|output| &output.name);
+        let (outputs, parts) = match num_outputs {
+            1 => (quote! { #(#outputs)* }, quote! { #(#parts)* }),
+            _ => (quote! { (#(#outputs),*) }, quote! { (#(#parts),*) }),
+        };
+
+        let mut body = quote! {};
+        if uses_dims {
+            body.extend(quote! { let dims = #input_name.dims(); });
+        }
+        if self.sizes.is_none() {
+            let num_outputs = num_outputs.to_tokens();
+            body.extend(quote! { let size = (dims[#axis] + #num_outputs - 1) / #num_outputs; });
+        }
+
+        if uses_dims {
+            quote! {
+                let #outputs = {
+                    #body
+
+                    #parts
+                };
+            }
+        } else {
+            quote! {
+                let #outputs = #parts;
+            }
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Split(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{split::SplitNode, test::assert_tokens},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_sizes() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(SplitNode::new(
+            TensorType::new_float("tensor1", 2),
+            vec![
+                TensorType::new_float("tensor2", 2),
+                TensorType::new_float("tensor3", 2),
+            ],
+            0,
+            Some(vec![1, 3]),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string(), "tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> (Tensor<B, 2>, Tensor<B, 2>) {
+                    let (tensor2, tensor3) = (tensor1.clone().slice([0..1]), tensor1.slice([1..4]));
+
+                    (tensor2, tensor3)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_equal_sizes() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(SplitNode::new(
+            TensorType::new_float("tensor1", 3),
+            vec![
+                TensorType::new_float("tensor2", 3),
+                TensorType::new_float("tensor3", 3),
+                TensorType::new_float("tensor4", 3),
+            ],
+            1,
+            None,
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string()],
+            vec![
+                "tensor2".to_string(),
+                "tensor3".to_string(),
+                "tensor4".to_string(),
+            ],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 3>
+                ) -> (Tensor<B, 3>, Tensor<B, 3>, Tensor<B, 3>) {
+                    let (tensor2, tensor3, tensor4) = {
+                        let dims = tensor1.dims();
+                        let size = (dims[1] + 3 - 1) / 3;
+
+                        (
+                            tensor1.clone().slice([0..dims[0], 0..size]),
+                            tensor1.clone().slice([0..dims[0], size..2 * size]),
+                            tensor1.slice([0..dims[0], 2 * size..dims[1]])
+                        )
+                    };
+
+                    (tensor2, tensor3, tensor4)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
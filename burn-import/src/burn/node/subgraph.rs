@@ -0,0 +1,219 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{BurnImports, Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::HashSet;
+
+/// The nodes of a graph nested in a control flow node, whose forward pass is generated inside the
+/// forward pass of the model.
+///
+/// The subgraph can read the values of the enclosing graph, which are always cloned since the
+/// subgraph may run several times.
+#[derive(Debug, new)]
+pub struct Subgraph<PS: PrecisionSettings> {
+    pub nodes: Vec<Node<PS>>,
+    pub inputs: Vec<Type>,
+    pub outputs: Vec<Type>,
+}
+
+impl<PS: PrecisionSettings> Subgraph<PS> {
+    /// The values of the enclosing graph read by the subgraph.
+    pub fn captures(&self) -> Vec<Type> {
+        let mut defined = self
+            .inputs
+            .iter()
+            .map(|input| input.name().to_string())
+            .collect::<HashSet<_>>();
+        let mut captures = Vec::new();
+
+        for node in self.nodes.iter() {
+            for input in node.input_types() {
+                if defined.insert(input.name().to_string()) {
+                    captures.push(input);
+                }
+            }
+            for output in node.output_types() {
+                defined.insert(output.name().to_string());
+            }
+        }
+        for output in self.outputs.iter() {
+            if defined.insert(output.name().to_string()) {
+                captures.push(output.clone());
+            }
+        }
+
+        captures
+    }
+
+    /// Generate the forward pass of the nodes, and the scope to use the outputs with
+    /// [output](Subgraph::output).
+    pub fn forward(&self) -> (TokenStream, Scope) {
+        let mut scope = Scope::default();
+        let end = self.nodes.len();
+        let captures = tensors(self.captures());
+
+        tensors(self.inputs.clone())
+            .iter()
+            .chain(captures.iter())
+            .for_each(|tensor| scope.tensor_register_variable(tensor, 0));
+        for (position, node) in self.nodes.iter().enumerate() {
+            for tensor in tensors(node.output_types()) {
+                scope.tensor_register_variable(&tensor, position + 1);
+            }
+        }
+        for (position, node) in self.nodes.iter().enumerate() {
+            for tensor in tensors(node.input_types()) {
+                scope.tensor_register_future_use(&tensor, position);
+            }
+        }
+        for tensor in tensors(self.outputs.clone()) {
+            scope.tensor_register_future_use(&tensor, end);
+        }
+        // The captured tensors are never moved.
+        for tensor in captures.iter() {
+            scope.tensor_register_future_use(tensor, end + 1);
+        }
+
+        let mut body = quote! {};
+        self.nodes
+            .iter()
+            .enumerate()
+            .map(|(position, node)| node.forward(&mut scope, position))
+            .for_each(|code| body.extend(code));
+
+        (body, scope)
+    }
+
+    /// Use an output of the subgraph after its forward pass.
+    pub fn output(&self, index: usize, scope: &mut Scope) -> TokenStream {
+        match &self.outputs[index] {
+            Type::Tensor(tensor) => scope.tensor_use_owned(tensor, self.nodes.len()),
+            output => {
+                let name = output.name();
+                quote! { #name }
+            }
+        }
+    }
+
+    /// Generate a block running the nodes and returning the outputs.
+    pub fn block(&self) -> TokenStream {
+        let (body, mut scope) = self.forward();
+        let outputs = (0..self.outputs.len())
+            .map(|index| self.output(index, &mut scope))
+            .collect();
+        let outputs = tuple(outputs);
+
+        quote! {
+            {
+                #body
+                #outputs
+            }
+        }
+    }
+
+    pub fn register_imports(&self, imports: &mut BurnImports) {
+        self.nodes
+            .iter()
+            .for_each(|node| node.register_imports(imports));
+    }
+}
+
+fn tensors(types: Vec<Type>) -> Vec<TensorType> {
+    types
+        .into_iter()
+        .filter_map(|ty| match ty {
+            Type::Tensor(tensor) => Some(tensor),
+            _ => None,
+        })
+        .collect()
+}
+
+/// A single value, or a tuple of the values.
+pub fn tuple(values: Vec<TokenStream>) -> TokenStream {
+    match values.len() {
+        1 => values[0].clone(),
+        _ => quote! { (#(#values),*) },
+    }
+}
+
+/// Assign the values to the variables all at once, since a value can be read from another variable.
+///
+/// The variables assigned to themselves are skipped.
+pub fn assign(variables: Vec<TokenStream>, values: Vec<TokenStream>) -> TokenStream {
+    let (variables, values): (Vec<_>, Vec<_>) = variables
+        .into_iter()
+        .zip(values)
+        .filter(|(variable, value)| variable.to_string() != value.to_string())
+        .unzip();
+
+    match variables.len() {
+        0 => quote! {},
+        _ => {
+            let variables = tuple(variables);
+            let values = tuple(values);
+
+            quote! { #variables = #values; }
+        }
+    }
+}
+
+/// Use a value of the enclosing graph, consuming its reference since the subgraph clones it.
+pub fn use_capture(ty: &Type, scope: &mut Scope, node_position: usize) {
+    if let Type::Tensor(tensor) = ty {
+        scope.tensor_use_owned(tensor, node_position);
+    }
+}
+
+/// The boolean value of a condition, which can also be a tensor of a single element.
+pub fn condition(value: TokenStream, ty: &Type) -> TokenStream {
+    match ty {
+        Type::Scalar(_) => value,
+        Type::Tensor(_) => quote! { #value.into_data().value[0] },
+        _ => panic!("The condition must be a boolean"),
+    }
+}
+
+/// Stack the tensors of a vector along a new axis, the last tensor first when reversed.
+pub fn stack(values: TokenStream, output: &Type, axis: usize, reverse: bool) -> TokenStream {
+    let tensor = match output {
+        Type::Tensor(tensor) => tensor,
+        _ => panic!("The scan outputs must be tensors"),
+    };
+    let reverse = match reverse {
+        true => quote! { .rev() },
+        false => quote! {},
+    };
+
+    // The new axis is added to each tensor before concatenating them along it.
+    let unsqueeze = match axis {
+        0 => {
+            let dim = tensor.dim.to_tokens();
+            quote! { tensor.unsqueeze::<#dim>() }
+        }
+        _ => {
+            let mut sizes = (0..tensor.dim - 1)
+                .map(|index| {
+                    let index = index.to_tokens();
+                    quote! { dims[#index] }
+                })
+                .collect::<Vec<_>>();
+            sizes.insert(axis, quote! { 1 });
+
+            quote! {
+                {
+                    let dims = tensor.dims();
+                    tensor.reshape([#(#sizes),*])
+                }
+            }
+        }
+    };
+    let axis = axis.to_tokens();
+
+    quote! {
+        Tensor::cat(
+            #values.into_iter()#reverse.map(|tensor| #unsqueeze).collect(),
+            #axis,
+        )
+    }
+}
@@ -0,0 +1,216 @@
+use super::{shape::ShapeDim, Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Repeats the whole input the given number of times along each axis.
+///
+/// Since `repeat` only applies to axes of size 1, each tiled axis is first split in an axis of
+/// size 1 followed by the axis itself, the first one is repeated and both are merged back. The
+/// number of times may be read from a shape computed during the forward pass.
+#[derive(Debug, Clone, new)]
+pub struct TileNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub repeats: Vec<ShapeDim>,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for TileNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        let mut inputs = vec![Type::Tensor(self.input.clone())];
+        inputs.extend(ShapeDim::input_types(&self.repeats));
+
+        inputs
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        if self.repeats.len() != self.input.dim {
+            panic!(
+                "Tile: {} repeats are given for an input of rank {}",
+                self.repeats.len(),
+                self.input.dim
+            );
+        }
+
+        let input_name = &self.input.name;
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+
+        // The size of each axis, updated as the axes are tiled.
+        let mut sizes = (0..self.input.dim)
+            .map(|axis| {
+                let axis = axis.to_tokens();
+                quote! { dims[#axis] }
+            })
+            .collect::<Vec<_>>();
+
+        let mut tensor = input;
+        for (axis, times) in self.repeats.iter().enumerate() {
+            if *times == ShapeDim::Static(1) {
+                continue;
+            }
+
+            let mut split = sizes.clone();
+            split.insert(axis, quote! { 1 });
+
+            let times = times.to_tokens();
+            let size = &sizes[axis];
+            sizes[axis] = quote! { #times * #size };
+            let axis = axis.to_tokens();
+
+            tensor = quote! {
+                #tensor
+                    .reshape([#(#split),*])
+                    .repeat(#axis, #times)
+                    .reshape([#(#sizes),*])
+            };
+        }
+
+        if self
+            .repeats
+            .iter()
+            .all(|times| *times == ShapeDim::Static(1))
+        {
+            return quote! {
+                let #output = #tensor;
+            };
+        }
+
+        quote! {
+            let #output = {
+                let dims = #input_name.dims();
+                #tensor
+            };
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Tile(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{test::assert_tokens, tile::TileNode},
+        ShapeType, TensorType,
+    };
+
+    #[test]
+    fn test_codegen_nodes() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(TileNode::new(
+            TensorType::new_float("tensor1", 3),
+            TensorType::new_float("tensor2", 3),
+            vec![
+                ShapeDim::Static(2),
+                ShapeDim::Static(1),
+                ShapeDim::Static(3),
+            ],
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 3>) -> Tensor<B, 3> {
+                    let tensor2 = {
+                        let dims = tensor1.dims();
+                        tensor1
+                            .reshape([1, dims[0], dims[1], dims[2]])
+                            .repeat(0, 2)
+                            .reshape([2 * dims[0], dims[1], dims[2]])
+                            .reshape([2 * dims[0], dims[1], 1, dims[2]])
+                            .repeat(2, 3)
+                            .reshape([2 * dims[0], dims[1], 3 * dims[2]])
+                    };
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_runtime_repeats() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(TileNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_float("tensor2", 2),
+            vec![
+                ShapeDim::Runtime(ShapeType::new("shape1", 2), 0),
+                ShapeDim::Static(1),
+            ],
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "shape1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>, shape1: [usize; 2]) -> Tensor<B, 2> {
+                    let tensor2 = {
+                        let dims = tensor1.dims();
+                        tensor1
+                            .reshape([1, dims[0], dims[1]])
+                            .repeat(0, shape1[0])
+                            .reshape([shape1[0] * dims[0], dims[1]])
+                    };
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
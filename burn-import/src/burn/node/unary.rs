@@ -1,7 +1,7 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{Scope, ToTokens, Type};
+use crate::burn::{BurnImports, Scope, TensorKind, ToTokens, Type};
 use burn::record::PrecisionSettings;
-use proc_macro2::TokenStream;
+use proc_macro2::{Literal, TokenStream};
 use quote::quote;
 use std::sync::Arc;
 
@@ -20,24 +20,52 @@ pub struct UnaryNode {
 /// Type of unary node.
 #[derive(Clone)]
 pub enum UnaryNodeKind {
+    ArgMax,
+    ArgMin,
     Cast,
+    Clip,
+    Erf,
     Flatten,
+    Gelu,
+    HardSigmoid,
+    HardSwish,
+    Gather,
+    Identity,
     LogSoftmax,
+    ReduceMax,
+    ReduceMean,
+    ReduceSum,
     Softmax,
     Relu,
     Sigmoid,
+    Softplus,
+    Tanh,
     Transpose,
 }
 
 impl UnaryNodeKind {
     pub fn as_str(&self) -> &str {
         match self {
+            Self::ArgMax => "argmax",
+            Self::ArgMin => "argmin",
             Self::Cast => "cast",
+            Self::Clip => "clip",
+            Self::Erf => "erf",
             Self::Flatten => "flatten",
+            Self::Gelu => "gelu",
+            Self::HardSigmoid => "hard_sigmoid",
+            Self::HardSwish => "hard_swish",
+            Self::Gather => "gather",
+            Self::Identity => "identity",
             Self::LogSoftmax => "log_softmax",
+            Self::ReduceMax => "reduce_max",
+            Self::ReduceMean => "reduce_mean",
+            Self::ReduceSum => "reduce_sum",
             Self::Softmax => "softmax",
             Self::Relu => "relu",
             Self::Sigmoid => "sigmoid",
+            Self::Softplus => "softplus",
+            Self::Tanh => "tanh",
             Self::Transpose => "transpose",
         }
     }
@@ -74,7 +102,11 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for UnaryNode {
                 let name = scalar.name.clone();
                 quote! { #name }
             }
-            _ => panic!("lhs must be a tensor or scalar"),
+            Type::Shape(shape) => {
+                let name = shape.name.clone();
+                quote! { #name }
+            }
+            _ => panic!("lhs must be a tensor, scalar or shape"),
         };
 
         // let input = scope.tensor_use_owned(&self.input, node_position);
@@ -86,6 +118,12 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for UnaryNode {
         }
     }
 
+    fn register_imports(&self, imports: &mut BurnImports) {
+        if let UnaryNodeKind::Clip | UnaryNodeKind::HardSigmoid = self.kind {
+            imports.register("burn::tensor::ElementConversion");
+        }
+    }
+
     fn into_node(self) -> Node<PS> {
         Node::Unary(self)
     }
@@ -100,16 +138,88 @@ impl UnaryNode {
         Self::new(input, output, UnaryNodeKind::Flatten, Arc::new(function))
     }
 
+    /// Gathers the sizes of a shape, either a single size as an integer or an array of sizes.
+    pub(crate) fn shape_gather(input: Type, output: Type, indices: Vec<usize>) -> Self {
+        let scalar = matches!(output, Type::Scalar(_));
+        let indices = indices
+            .iter()
+            .map(|index| index.to_tokens())
+            .collect::<Vec<_>>();
+        let function = move |input: TokenStream| {
+            let sizes = indices.iter().map(|index| quote! { #input[#index] });
+            match scalar {
+                true => quote! { #(#sizes)* as i64 },
+                false => quote! { [#(#sizes),*] },
+            }
+        };
+
+        Self::new(input, output, UnaryNodeKind::Gather, Arc::new(function))
+    }
+
     pub(crate) fn relu(input: Type, output: Type) -> Self {
         let function = move |input| quote! { burn::tensor::activation::relu(#input) };
         Self::new(input, output, UnaryNodeKind::Relu, Arc::new(function))
     }
 
+    pub(crate) fn identity(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { #input };
+        Self::new(input, output, UnaryNodeKind::Identity, Arc::new(function))
+    }
+
+    pub(crate) fn erf(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { #input.erf() };
+        Self::new(input, output, UnaryNodeKind::Erf, Arc::new(function))
+    }
+
+    /// The exact GELU, or its approximation with tanh.
+    pub(crate) fn gelu(input: Type, output: Type, tanh: bool) -> Self {
+        let function = move |input| match tanh {
+            true => quote! { burn::tensor::activation::gelu_tanh(#input) },
+            false => quote! { burn::tensor::activation::gelu(#input) },
+        };
+        Self::new(input, output, UnaryNodeKind::Gelu, Arc::new(function))
+    }
+
     pub(crate) fn sigmoid(input: Type, output: Type) -> Self {
         let function = move |input| quote! { burn::tensor::activation::sigmoid(#input) };
         Self::new(input, output, UnaryNodeKind::Sigmoid, Arc::new(function))
     }
 
+    pub(crate) fn tanh(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { #input.tanh() };
+        Self::new(input, output, UnaryNodeKind::Tanh, Arc::new(function))
+    }
+
+    pub(crate) fn softplus(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { burn::tensor::activation::softplus(#input) };
+        Self::new(input, output, UnaryNodeKind::Softplus, Arc::new(function))
+    }
+
+    /// `max(0, min(1, alpha * x + beta))`
+    pub(crate) fn hard_sigmoid(input: Type, output: Type, alpha: f32, beta: f32) -> Self {
+        let alpha = Literal::f32_unsuffixed(alpha);
+        let beta = Literal::f32_unsuffixed(beta);
+        let function = move |input| {
+            quote! {
+                #input
+                    .mul_scalar(#alpha)
+                    .add_scalar(#beta)
+                    .clamp(0.0.elem(), 1.0.elem())
+            }
+        };
+        Self::new(
+            input,
+            output,
+            UnaryNodeKind::HardSigmoid,
+            Arc::new(function),
+        )
+    }
+
+    pub(crate) fn hard_swish(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { burn::tensor::activation::hard_swish(#input) };
+        Self::new(input, output, UnaryNodeKind::HardSwish, Arc::new(function))
+    }
+
     pub(crate) fn log_softmax(input: Type, output: Type, dim: usize) -> Self {
         let dim = dim.to_tokens();
         let function = move |input| quote! { burn::tensor::activation::log_softmax(#input, #dim) };
@@ -122,6 +232,205 @@ impl UnaryNode {
         Self::new(input, output, UnaryNodeKind::Softmax, Arc::new(function))
     }
 
+    pub(crate) fn clip(input: Type, output: Type, min: Option<f64>, max: Option<f64>) -> Self {
+        // Negative literals are wrapped, otherwise the negation applies after the conversion.
+        let literal = |value: f64| {
+            let literal = Literal::f64_unsuffixed(value);
+            match value < 0.0 {
+                true => quote! { (#literal) },
+                false => quote! { #literal },
+            }
+        };
+        let min = min.map(literal);
+        let max = max.map(literal);
+        let function = move |input| match (&min, &max) {
+            (Some(min), Some(max)) => quote! { #input.clamp(#min.elem(), #max.elem()) },
+            (Some(min), None) => quote! { #input.clamp_min(#min.elem()) },
+            (None, Some(max)) => quote! { #input.clamp_max(#max.elem()) },
+            (None, None) => input,
+        };
+
+        Self::new(input, output, UnaryNodeKind::Clip, Arc::new(function))
+    }
+
+    pub(crate) fn reduce_max(
+        input: Type,
+        output: Type,
+        axes: Option<Vec<usize>>,
+        keepdims: bool,
+    ) -> Self {
+        Self::reduce(input, output, UnaryNodeKind::ReduceMax, axes, keepdims)
+    }
+
+    pub(crate) fn reduce_mean(
+        input: Type,
+        output: Type,
+        axes: Option<Vec<usize>>,
+        keepdims: bool,
+    ) -> Self {
+        Self::reduce(input, output, UnaryNodeKind::ReduceMean, axes, keepdims)
+    }
+
+    pub(crate) fn reduce_sum(
+        input: Type,
+        output: Type,
+        axes: Option<Vec<usize>>,
+        keepdims: bool,
+    ) -> Self {
+        Self::reduce(input, output, UnaryNodeKind::ReduceSum, axes, keepdims)
+    }
+
+    /// Reduces the input along the given axes, or all of them when `None`.
+    ///
+    /// The reduced axes are kept with a size of 1 by the `*_dim` operations and squeezed
+    /// afterwards, from the last one, unless `keepdims` is set. Without axes nor `keepdims`, the
+    /// output is a tensor of a single element since tensors of rank 0 are not supported.
+    fn reduce(
+        input: Type,
+        output: Type,
+        kind: UnaryNodeKind,
+        axes: Option<Vec<usize>>,
+        keepdims: bool,
+    ) -> Self {
+        let (reduce, reduce_dim) = match kind {
+            UnaryNodeKind::ReduceMax => (quote! { max }, quote! { max_dim }),
+            UnaryNodeKind::ReduceMean => (quote! { mean }, quote! { mean_dim }),
+            UnaryNodeKind::ReduceSum => (quote! { sum }, quote! { sum_dim }),
+            _ => panic!("{} is not a reduction", kind.as_str()),
+        };
+        let dim = match &input {
+            Type::Tensor(tensor) => tensor.dim,
+            _ => panic!("Reduction is supported for tensor only"),
+        };
+        let axes = match (axes, keepdims) {
+            (None, false) => {
+                let function = move |input| quote! { #input.#reduce() };
+                return Self::new(input, output, kind, Arc::new(function));
+            }
+            (None, true) => (0..dim).collect(),
+            (Some(axes), _) => axes,
+        };
+
+        let function = move |input| {
+            let mut tokens = quote! { #input };
+
+            for axis in axes.iter() {
+                let axis = axis.to_tokens();
+                tokens = quote! { #tokens.#reduce_dim(#axis) };
+            }
+
+            if !keepdims {
+                for (i, axis) in axes.iter().enumerate().rev() {
+                    let output_dim = (dim - axes.len() + i).to_tokens();
+                    let axis = axis.to_tokens();
+                    tokens = quote! { #tokens.squeeze::<#output_dim>(#axis) };
+                }
+            }
+
+            tokens
+        };
+
+        Self::new(input, output, kind, Arc::new(function))
+    }
+
+    pub(crate) fn argmax(
+        input: Type,
+        output: Type,
+        axis: usize,
+        keepdims: bool,
+        select_last_index: bool,
+    ) -> Self {
+        Self::arg_reduce(
+            input,
+            output,
+            UnaryNodeKind::ArgMax,
+            axis,
+            keepdims,
+            select_last_index,
+        )
+    }
+
+    pub(crate) fn argmin(
+        input: Type,
+        output: Type,
+        axis: usize,
+        keepdims: bool,
+        select_last_index: bool,
+    ) -> Self {
+        Self::arg_reduce(
+            input,
+            output,
+            UnaryNodeKind::ArgMin,
+            axis,
+            keepdims,
+            select_last_index,
+        )
+    }
+
+    /// Returns the index of the maximum or minimum along an axis.
+    ///
+    /// The `argmax` and `argmin` operations return the first index of the extremum. The last one
+    /// is selected by keeping the indices of the elements equal to the extremum and taking their
+    /// maximum. The reduced axis is squeezed unless `keepdims` is set or the input is a vector.
+    fn arg_reduce(
+        input: Type,
+        output: Type,
+        kind: UnaryNodeKind,
+        axis: usize,
+        keepdims: bool,
+        select_last_index: bool,
+    ) -> Self {
+        let (arg, reduce_dim) = match kind {
+            UnaryNodeKind::ArgMax => (quote! { argmax }, quote! { max_dim }),
+            UnaryNodeKind::ArgMin => (quote! { argmin }, quote! { min_dim }),
+            _ => panic!("{} is not an index reduction", kind.as_str()),
+        };
+        let dim = match &input {
+            Type::Tensor(tensor) => tensor.dim,
+            _ => panic!("Index reduction is supported for tensor only"),
+        };
+
+        // The shape of the indices along the axis, broadcast to the shape of the input.
+        let indices_shape = (0..dim).map(|i| match i == axis {
+            true => {
+                let axis = axis.to_tokens();
+                quote! { dims[#axis] }
+            }
+            false => quote! { 1 },
+        });
+        let indices_shape = quote! { [#(#indices_shape),*] };
+        let dim_tokens = dim.to_tokens();
+        let output_dim = (dim - 1).to_tokens();
+        let axis = axis.to_tokens();
+
+        let function = move |input| {
+            let tokens = match select_last_index {
+                false => quote! { #input.#arg(#axis) },
+                true => quote! {
+                    {
+                        let tensor = #input;
+                        let dims = tensor.dims();
+                        let extremum = tensor.clone().#reduce_dim(#axis).repeat(#axis, dims[#axis]);
+                        let indices = Tensor::<B, 1, burn::tensor::Int>::arange(0..dims[#axis])
+                            .reshape(#indices_shape)
+                            .expand(dims);
+
+                        Tensor::<B, #dim_tokens, burn::tensor::Int>::zeros(dims)
+                            .mask_where(tensor.equal(extremum), indices)
+                            .max_dim(#axis)
+                    }
+                },
+            };
+
+            match keepdims || dim == 1 {
+                true => tokens,
+                false => quote! { #tokens.squeeze::<#output_dim>(#axis) },
+            }
+        };
+
+        Self::new(input, output, kind, Arc::new(function))
+    }
+
     pub(crate) fn transpose(input: Type, output: Type) -> Self {
         let function = move |input| quote! { #input.transpose() };
         Self::new(input, output, UnaryNodeKind::Transpose, Arc::new(function))
@@ -129,40 +438,41 @@ impl UnaryNode {
 
     /// Casts the input to the output type.
     ///
-    /// Currently this function only supports the following conversions:
-    /// 1) scalar -> scalar
-    ///
-    /// TODO: Implement the following conversions:
-    /// 2) tensor int -> tensor float
-    /// 3) tensor float -> tensor int
-    /// 4) tensor -> scalar
-    /// 5) scalar -> tensor
+    /// Scalars are cast to the output scalar type, and tensors are converted to the kind of the
+    /// output tensor. Tensors are converted to booleans by comparing their elements to zero.
     pub(crate) fn cast(input: Type, output: Type) -> Self {
-        let function = match output.clone() {
-            Type::Scalar(scalar) => {
+        let function: FnPointer = match (&input, &output) {
+            (Type::Scalar(_), Type::Scalar(scalar)) => {
                 let ty = scalar.ty();
-                move |input| quote! { #input as #ty }
+                Arc::new(move |input| quote! { #input as #ty })
             }
-            Type::Tensor(_tensor) => {
-                // TODO: Implement this after tensor Int is implemented (@antimora 8/2/2023)
-                // TODO: If the input is scalar and the output type is a tensor,
-                // we should generate another code block. (@antimora 8/4/2023)
-                // Tensor::from_data(Data::from([#input]).convert()).unsqueeze();
-                todo!()
-            }
-
-            _ => panic!("output must be a tensor"),
+            (Type::Tensor(tensor), Type::Tensor(output)) => match (tensor.kind, output.kind) {
+                (TensorKind::Float, TensorKind::Float)
+                | (TensorKind::Int, TensorKind::Int)
+                | (TensorKind::Bool, TensorKind::Bool) => Arc::new(|input| quote! { #input }),
+                (TensorKind::Float | TensorKind::Bool, TensorKind::Int) => {
+                    Arc::new(|input| quote! { #input.int() })
+                }
+                (TensorKind::Int | TensorKind::Bool, TensorKind::Float) => {
+                    Arc::new(|input| quote! { #input.float() })
+                }
+                (TensorKind::Float | TensorKind::Int, TensorKind::Bool) => {
+                    Arc::new(|input| quote! { #input.equal_elem(0).int().equal_elem(0) })
+                }
+            },
+            _ => panic!("Cast is supported between scalars or between tensors only"),
         };
 
-        Self::new(input, output, UnaryNodeKind::Cast, Arc::new(function))
+        Self::new(input, output, UnaryNodeKind::Cast, function)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::burn::node::tests::one_node_graph;
-    use crate::burn::{ScalarKind, ScalarType, TensorType};
+    use crate::burn::node::{test::assert_tokens, tests::one_node_graph};
+    use crate::burn::{graph::BurnGraph, ScalarKind, ScalarType, ShapeType, TensorType};
+    use burn::record::FullPrecisionSettings;
 
     #[test]
     fn test_unary_codegen_flatten() {
@@ -185,6 +495,206 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unary_codegen_clip() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(UnaryNode::clip(
+            Type::Tensor(TensorType::new_float("tensor1", 4)),
+            Type::Tensor(TensorType::new_float("tensor2", 4)),
+            Some(0.0),
+            Some(6.0),
+        ));
+        graph.register(UnaryNode::clip(
+            Type::Tensor(TensorType::new_float("tensor2", 4)),
+            Type::Tensor(TensorType::new_float("tensor3", 4)),
+            None,
+            Some(-0.5),
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor3".to_string()]);
+
+        let expected = quote! {
+            use burn::tensor::ElementConversion;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.clamp(0.0.elem(), 6.0.elem());
+                    let tensor3 = tensor2.clamp_max((-0.5).elem());
+
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_unary_codegen_gelu() {
+        one_node_graph(
+            UnaryNode::gelu(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+                false,
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = burn::tensor::activation::gelu(tensor1);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_tanh() {
+        one_node_graph(
+            UnaryNode::tanh(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1.tanh();
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_hard_sigmoid() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(UnaryNode::hard_sigmoid(
+            Type::Tensor(TensorType::new_float("tensor1", 4)),
+            Type::Tensor(TensorType::new_float("tensor2", 4)),
+            0.2,
+            0.5,
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::tensor::ElementConversion;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = tensor1
+                        .mul_scalar(0.2)
+                        .add_scalar(0.5)
+                        .clamp(0.0.elem(), 1.0.elem());
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_unary_codegen_reduce_mean() {
+        one_node_graph(
+            UnaryNode::reduce_mean(
+                Type::Tensor(TensorType::new_float("tensor1", 3)),
+                Type::Tensor(TensorType::new_float("tensor2", 3)),
+                Some(vec![2]),
+                true,
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 3>) -> Tensor<B, 3> {
+                    let tensor2 = tensor1.mean_dim(2);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_reduce_sum() {
+        one_node_graph(
+            UnaryNode::reduce_sum(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 2)),
+                Some(vec![1, 3]),
+                false,
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 2> {
+                    let tensor2 = tensor1.sum_dim(1).sum_dim(3).squeeze::<3>(3).squeeze::<2>(1);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_reduce_max() {
+        one_node_graph(
+            UnaryNode::reduce_max(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 1)),
+                None,
+                false,
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 1> {
+                    let tensor2 = tensor1.max();
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
     #[test]
     fn test_unary_codegen_relu() {
         one_node_graph(
@@ -204,6 +714,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unary_codegen_identity() {
+        one_node_graph(
+            UnaryNode::identity(
+                Type::Scalar(ScalarType::new("scalar1", ScalarKind::Bool)),
+                Type::Scalar(ScalarType::new("scalar2", ScalarKind::Bool)),
+            ),
+            quote! {
+                pub fn forward(&self, scalar1: bool) -> bool {
+                    let scalar2 = scalar1;
+
+                    scalar2
+                }
+            },
+            vec!["scalar1".to_string()],
+            vec!["scalar2".to_string()],
+        );
+    }
+
     #[test]
     fn test_unary_codegen_sigmoid() {
         one_node_graph(
@@ -315,4 +844,172 @@ mod tests {
             vec!["scalar2".to_string()],
         );
     }
+
+    #[test]
+    fn test_unary_codegen_cast_tensor() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(UnaryNode::cast(
+            Type::Tensor(TensorType::new_float("tensor1", 2)),
+            Type::Tensor(TensorType::new_int("tensor2", 2)),
+        ));
+        graph.register(UnaryNode::cast(
+            Type::Tensor(TensorType::new_int("tensor2", 2)),
+            Type::Tensor(TensorType::new_bool("tensor3", 2)),
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor3".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::tensor::Bool;
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> Tensor<B, 2, Bool> {
+                    let tensor2 = tensor1.int();
+                    let tensor3 = tensor2.equal_elem(0).int().equal_elem(0);
+
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_unary_codegen_shape_gather() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(UnaryNode::shape_gather(
+            Type::Shape(ShapeType::new("shape1", 3)),
+            Type::Scalar(ScalarType::new("scalar1", ScalarKind::Int64)),
+            vec![1],
+        ));
+        graph.register(UnaryNode::shape_gather(
+            Type::Shape(ShapeType::new("shape1", 3)),
+            Type::Shape(ShapeType::new("shape2", 2)),
+            vec![2, 0],
+        ));
+
+        graph.register_input_output(
+            vec!["shape1".to_string()],
+            vec!["scalar1".to_string(), "shape2".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(&self, shape1: [usize; 3]) -> (i64, [usize; 2]) {
+                    let scalar1 = shape1[1] as i64;
+                    let shape2 = [shape1[2], shape1[0]];
+
+                    (scalar1, shape2)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_unary_codegen_argmax_argmin() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(UnaryNode::argmax(
+            Type::Tensor(TensorType::new_float("tensor1", 2)),
+            Type::Tensor(TensorType::new_int("tensor2", 1)),
+            1,
+            false,
+            false,
+        ));
+        graph.register(UnaryNode::argmin(
+            Type::Tensor(TensorType::new_float("tensor1", 2)),
+            Type::Tensor(TensorType::new_int("tensor3", 2)),
+            0,
+            true,
+            true,
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string(), "tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::tensor::Int;
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 2>
+                ) -> (Tensor<B, 1, Int>, Tensor<B, 2, Int>) {
+                    let tensor2 = tensor1.clone().argmax(1).squeeze::<1>(1);
+                    let tensor3 = {
+                        let tensor = tensor1;
+                        let dims = tensor.dims();
+                        let extremum = tensor.clone().min_dim(0).repeat(0, dims[0]);
+                        let indices = Tensor::<B, 1, burn::tensor::Int>::arange(0..dims[0])
+                            .reshape([dims[0], 1])
+                            .expand(dims);
+
+                        Tensor::<B, 2, burn::tensor::Int>::zeros(dims)
+                            .mask_where(tensor.equal(extremum), indices)
+                            .max_dim(0)
+                    };
+
+                    (tensor2, tensor3)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
 }
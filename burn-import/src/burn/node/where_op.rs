@@ -0,0 +1,129 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Selects the elements of `x` where the condition is true and the ones of `y` elsewhere.
+///
+/// The inputs of a lower rank are unsqueezed to the rank of the output, and their dimensions of
+/// size 1 are broadcast by the mask operation.
+#[derive(Debug, Clone, new)]
+pub struct WhereNode {
+    pub condition: TensorType,
+    pub x: TensorType,
+    pub y: TensorType,
+    pub output: TensorType,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for WhereNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![
+            Type::Tensor(self.condition.clone()),
+            Type::Tensor(self.x.clone()),
+            Type::Tensor(self.y.clone()),
+        ]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let dim = self.output.dim;
+        let mut use_input = |tensor: &TensorType| {
+            let input = scope.tensor_use_owned(tensor, node_position);
+
+            match tensor.dim < dim {
+                true => {
+                    let dim = dim.to_tokens();
+                    quote! { #input.unsqueeze::<#dim>() }
+                }
+                false => input,
+            }
+        };
+
+        let condition = use_input(&self.condition);
+        let x = use_input(&self.x);
+        let y = use_input(&self.y);
+        let output = &self.output.name;
+
+        quote! {
+            let #output = #y.mask_where(#condition, #x);
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Where(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{test::assert_tokens, where_op::WhereNode},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_nodes() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(WhereNode::new(
+            TensorType::new_bool("tensor1", 2),
+            TensorType::new_float("tensor2", 3),
+            TensorType::new_float("tensor3", 1),
+            TensorType::new_float("tensor4", 3),
+        ));
+
+        graph.register_input_output(
+            vec![
+                "tensor1".to_string(),
+                "tensor2".to_string(),
+                "tensor3".to_string(),
+            ],
+            vec!["tensor4".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::tensor::Bool;
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 2, Bool>,
+                    tensor2: Tensor<B, 3>,
+                    tensor3: Tensor<B, 1>
+                ) -> Tensor<B, 3> {
+                    let tensor4 = tensor3
+                        .unsqueeze::<3>()
+                        .mask_where(tensor1.unsqueeze::<3>(), tensor2);
+
+                    tensor4
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -13,7 +13,7 @@ pub struct TensorType {
     pub shape: Option<Vec<usize>>,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum TensorKind {
     Int,
     Float,
@@ -35,6 +35,13 @@ pub struct ScalarType {
     pub kind: ScalarKind,
 }
 
+/// The shape of a tensor computed during the forward pass, as an array of sizes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShapeType {
+    pub name: Ident,
+    pub rank: usize,
+}
+
 #[derive(Debug, Clone)]
 pub struct OtherType {
     pub name: Ident,
@@ -49,6 +56,9 @@ pub enum Type {
     /// Scalar type.
     Scalar(ScalarType),
 
+    /// Shape type.
+    Shape(ShapeType),
+
     // Other type (more flexible type).
     Other(OtherType),
 }
@@ -58,6 +68,7 @@ impl Type {
         match self {
             Type::Tensor(tensor) => &tensor.name,
             Type::Scalar(scalar) => &scalar.name,
+            Type::Shape(shape) => &shape.name,
             Type::Other(other) => &other.name,
         }
     }
@@ -65,6 +76,7 @@ impl Type {
         match self {
             Type::Tensor(tensor) => tensor.ty(),
             Type::Scalar(scalar) => scalar.ty(),
+            Type::Shape(shape) => shape.ty(),
             Type::Other(other) => other.ty(),
         }
     }
@@ -117,11 +129,26 @@ impl TensorType {
     pub fn ty(&self) -> TokenStream {
         let dim = self.dim.to_tokens();
 
-        // TODO use passed elem kind and do not assume float (@antimora 8/1/2023)
-        quote! {
-            Tensor<B, #dim>
+        match self.kind {
+            TensorKind::Float => quote! { Tensor<B, #dim> },
+            TensorKind::Int => quote! { Tensor<B, #dim, Int> },
+            TensorKind::Bool => quote! { Tensor<B, #dim, Bool> },
+        }
+    }
+}
+
+impl ShapeType {
+    pub fn new<S: AsRef<str>>(name: S, rank: usize) -> Self {
+        Self {
+            name: Ident::new(name.as_ref(), Span::call_site()),
+            rank,
         }
     }
+    pub fn ty(&self) -> TokenStream {
+        let rank = self.rank.to_tokens();
+
+        quote! { [usize; #rank] }
+    }
 }
 
 impl OtherType {
@@ -1,16 +1,76 @@
-use burn_import::onnx::ModelGen;
+use burn_import::onnx::{ModelGen, Optimizations, RecordType};
+
+const USAGE: &str = "Usage: burn-import <model.onnx> <out_dir> [options]
+
+Options:
+    --record-type <type>   Record file format: pretty-json, json-gz, named-mpk-gz, bin or bin-gz
+    --half                 Save the states of the model in half precision
+    --model-name <name>    Name of the generated model struct, `Model` by default
+    --no-optimization      Generate the model without optimizing its graph
+    --verify               Generate a verification model comparing the values of the nodes with
+                           the values computed by ONNX Runtime
+    --reference <file>     Verify the model against the reference values of the given file
+    --development          Save the debug graph, and the record as pretty json by default";
 
 /// Takes an ONNX file and generates a model from it
 fn main() {
-    let onnx_file = std::env::args().nth(1).expect("No input file provided");
-    let output_dir = std::env::args()
-        .nth(2)
-        .expect("No output directory provided");
+    let mut args = std::env::args().skip(1);
+    let mut files = Vec::new();
+    let mut model_gen = ModelGen::new();
+
+    while let Some(arg) = args.next() {
+        let mut value = |option: &str| {
+            args.next()
+                .unwrap_or_else(|| exit(&format!("No value provided for {option}")))
+        };
+
+        match arg.as_str() {
+            "--record-type" => {
+                let record_type = value(&arg);
+                let record_type = record_type
+                    .parse::<RecordType>()
+                    .unwrap_or_else(|_| exit(&format!("Unknown record type {record_type}")));
+                model_gen.record_type(record_type);
+            }
+            "--half" => {
+                model_gen.half_precision(true);
+            }
+            "--model-name" => {
+                model_gen.model_name(&value(&arg));
+            }
+            "--no-optimization" => {
+                model_gen.optimizations(Optimizations::none());
+            }
+            "--verify" => {
+                model_gen.verify(true);
+            }
+            "--reference" => {
+                model_gen.reference_values(&value(&arg));
+            }
+            "--development" => {
+                model_gen.development(true);
+            }
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return;
+            }
+            option if option.starts_with("--") => exit(&format!("Unknown option {option}")),
+            _ => files.push(arg),
+        }
+    }
+
+    let [onnx_file, output_dir] = files.as_slice() else {
+        exit("An input file and an output directory must be provided");
+    };
 
     // Generate the model code from the ONNX file.
-    ModelGen::new()
+    model_gen
         .input(onnx_file.as_str())
-        .development(true)
         .out_dir(output_dir.as_str())
         .run_from_cli();
 }
+
+fn exit(message: &str) -> ! {
+    eprintln!("{message}\n\n{USAGE}");
+    std::process::exit(1)
+}
@@ -1,72 +1,986 @@
 use burn::tensor::Tensor;
 use burn_ndarray::NdArrayBackend;
 
-use super::ir::{AttributeValue, Node, NodeType, StateType, TensorData};
+use super::ir::{self, AttributeValue, ElementType, Node, NodeType, State, StateType, TensorData};
+use super::op_configuration::{gemm_config, slice_config};
+use super::quantization::constant_node;
 
 type B = NdArrayBackend<f32>;
 
 /// The function transforms the graph into a new one where the nodes are coalesced into a single node.
 pub fn coalesce(nodes: &mut Vec<Node>) {
+    let mut constants = Vec::<Node>::new();
+
     for node in nodes.iter_mut() {
         match node.node_type {
-            NodeType::Gemm => convert_gemm(node),
+            NodeType::Clip => convert_clip(node),
+            NodeType::ConstantOfShape | NodeType::Expand => convert_shape(node),
+            NodeType::Gather => convert_gather(node),
+            NodeType::Gemm => {
+                for constant in convert_gemm(node) {
+                    if constants.iter().all(|other| other.name != constant.name) {
+                        constants.push(constant);
+                    }
+                }
+            }
+            NodeType::GRU => convert_gru(node),
+            NodeType::LSTM => convert_lstm(node),
+            NodeType::NonMaxSuppression => convert_non_max_suppression(node),
+            NodeType::Pad => remove_empty_inputs(node),
+            NodeType::ReduceMax | NodeType::ReduceMean | NodeType::ReduceSum => {
+                convert_reduce(node)
+            }
+            NodeType::Slice => convert_slice(node),
+            NodeType::Split => convert_split(node),
+            NodeType::Tile => convert_tile(node),
             _ => {}
         }
     }
+
+    nodes.splice(0..0, constants);
 }
 
-/// This function converts a Gemm node into a Linear node
-///
-///  Warning: This function is not complete yet.
-///  It only supports the case where the Gemm node is a straight linear transformation.
-fn convert_gemm(node: &mut Node) {
-    if node.inputs.len() != 1 {
-        panic!("Gemm node must have 1 input");
+/// This function removes the optional inputs that are omitted with an empty name.
+fn remove_empty_inputs(node: &mut Node) {
+    node.inputs.retain(|input| !input.name.is_empty());
+}
+
+/// This function moves the constant min and max of a Clip node to its attributes, as they were
+/// before opset 11.
+fn convert_clip(node: &mut Node) {
+    // Only the min can be skipped before the max.
+    let names: &[&str] = match node.inputs.iter().any(|input| input.name.is_empty()) {
+        true => &["max"],
+        false => &["min", "max"],
+    };
+    remove_empty_inputs(node);
+
+    for (name, state) in names.iter().zip(core::mem::take(&mut node.states)) {
+        let StateType::Tensor(tensor) = state.ty;
+        let value = match tensor.data {
+            Some(TensorData::Float16(values)) => values[0].to_f32(),
+            Some(TensorData::Float32(values)) => values[0],
+            Some(TensorData::Float64(values)) => values[0] as f32,
+            Some(TensorData::Int32(values)) => values[0] as f32,
+            Some(TensorData::Int64(values)) => values[0] as f32,
+            _ => panic!("Clip: {name} must be a number"),
+        };
+        node.attrs
+            .insert(name.to_string(), AttributeValue::Float32(value));
     }
+}
+
+/// This function moves the constant axes of a reduction to its attributes, as they were before
+/// opset 13 for ReduceSum and opset 18 for the others.
+fn convert_reduce(node: &mut Node) {
+    remove_empty_inputs(node);
+
+    if let Some(state) = node.states.pop() {
+        let StateType::Tensor(tensor) = state.ty;
+        let axes = match tensor.data {
+            Some(TensorData::Int64(values)) => values,
+            Some(TensorData::Int32(values)) => {
+                values.into_iter().map(|value| value as i64).collect()
+            }
+            _ => panic!("{}: axes must be integers", node.node_type),
+        };
+        node.attrs
+            .insert("axes".to_string(), AttributeValue::Int64s(axes));
+    }
+}
+
+/// This function moves the constant shape of an Expand or a ConstantOfShape node to its
+/// attributes.
+fn convert_shape(node: &mut Node) {
+    if let Some(state) = node.states.pop() {
+        let StateType::Tensor(tensor) = state.ty;
+        let shape = match tensor.data {
+            Some(TensorData::Int64(values)) => values,
+            Some(TensorData::Int32(values)) => {
+                values.into_iter().map(|value| value as i64).collect()
+            }
+            _ => panic!("{}: shape must be integers", node.node_type),
+        };
+        node.attrs
+            .insert("shape".to_string(), AttributeValue::Int64s(shape));
+    }
+}
 
+/// This function converts a Gather node into an Embedding node when the data is an initializer
+/// holding the weights, whose rows are gathered along the first axis.
+fn convert_gather(node: &mut Node) {
+    if node.inputs.len() != 1 || node.states.len() != 1 {
+        return;
+    }
+
+    let StateType::Tensor(tensor) = &node.states[0].ty;
+
+    // Integer initializers are constant indices gathered from a computed tensor.
+    if matches!(tensor.elem_type, ElementType::Int32 | ElementType::Int64) {
+        return;
+    }
+
+    let axis = match node.attrs.get("axis") {
+        Some(AttributeValue::Int64(axis)) => *axis,
+        _ => 0,
+    };
+
+    if tensor.dim != 2 || (axis != 0 && axis != -2) {
+        panic!(
+            "Gather: constant data is only supported as 2D embedding weights gathered along the \
+            first axis (got {} dimensions and axis {})",
+            tensor.dim, axis
+        );
+    }
+
+    node.node_type = NodeType::Embedding;
+    node.attrs.remove("axis");
+}
+
+/// This function converts a Gemm node into a Linear node when A is computed by the graph, B is
+/// an initializer holding the weights and C, if present, is an initializer holding a bias
+/// broadcast over the rows.
+///
+/// The scaling factors are folded into the weights and the bias. Otherwise, the node is kept and
+/// converted to a matmul followed by an addition, and its initializers are returned as the
+/// Constant nodes it reads.
+fn convert_gemm(node: &mut Node) -> Vec<Node> {
     if node.outputs.len() != 1 {
         panic!("Gemm node must have 1 output");
     }
-    let straight_linear = match (
-        node.attrs.get("alpha"),
-        node.attrs.get("beta"),
-        node.attrs.get("transB"),
-    ) {
-        (
-            Some(AttributeValue::Float32(alpha)),
-            Some(AttributeValue::Float32(beta)),
-            Some(AttributeValue::Int64(trans_b)),
-        ) => *alpha == 1.0 && *beta == 1.0 && *trans_b == 1,
-        _ => false,
+
+    remove_empty_inputs(node);
+
+    let (alpha, beta, trans_a, trans_b) = gemm_config(node);
+
+    if !gemm_is_linear(node, trans_a, trans_b) {
+        return core::mem::take(&mut node.states)
+            .into_iter()
+            .map(|state| {
+                let StateType::Tensor(tensor) = state.ty;
+                constant_node(&state.name, tensor)
+            })
+            .collect();
+    }
+
+    // The initializers are read from the states
+    node.inputs.truncate(1);
+    node.node_type = NodeType::Linear;
+    node.attrs.remove("alpha");
+    node.attrs.remove("beta");
+    node.attrs.remove("transA");
+    node.attrs.remove("transB");
+
+    let StateType::Tensor(node_weight) = &mut node.states[0].ty;
+    let mut weight: Tensor<B, 2> = (&*node_weight).try_into().unwrap();
+
+    if trans_b {
+        weight = weight.transpose();
+    }
+    if alpha != 1.0 {
+        weight = weight.mul_scalar(alpha);
+    }
+
+    let [_d_input, d_output] = weight.dims();
+    set_tensor_data(node_weight, weight);
+
+    if let Some(state) = node.states.get_mut(1) {
+        let StateType::Tensor(node_bias) = &mut state.ty;
+        let TensorData::Float32(values) = node_bias.data.clone().unwrap() else {
+            panic!("Gemm bias must be float32s");
+        };
+
+        let bias: Tensor<B, 1> = match values.len() {
+            1 => Tensor::ones([d_output]).mul_scalar(values[0]),
+            _ => Tensor::from_data(values.as_slice()),
+        };
+
+        set_tensor_data(node_bias, bias.mul_scalar(beta));
+    }
+
+    Vec::new()
+}
+
+/// Whether a Gemm node, whose initializers are both kept as inputs and moved to its states, is a
+/// linear layer: A is computed by the graph and isn't transposed, B is a matrix initializer and C,
+/// if present, is an initializer broadcast over the rows of the output.
+fn gemm_is_linear(node: &Node, trans_a: bool, trans_b: bool) -> bool {
+    let initializers = node.states.iter().map(|state| state.name.as_str());
+    let inputs = node.inputs.iter().skip(1).map(|input| input.name.as_str());
+
+    if trans_a || node.states.is_empty() || !initializers.eq(inputs) {
+        return false;
+    }
+
+    let shape = |index: usize| {
+        let StateType::Tensor(tensor) = &node.states[index].ty;
+        tensor.shape.clone().unwrap_or_default()
     };
 
-    if straight_linear {
-        node.node_type = NodeType::Linear;
-        node.attrs.remove("alpha");
-        node.attrs.remove("beta");
-        node.attrs.remove("transB");
+    let weight_shape = shape(0);
+    if weight_shape.len() != 2 {
+        return false;
+    }
+    let d_output = weight_shape[usize::from(!trans_b)];
+
+    match node.states.get(1) {
+        // C is unidirectionally broadcastable to [M, N], only the broadcast over M is folded.
+        Some(_) => match shape(1).split_last() {
+            Some((last, rest)) => {
+                (*last == 1 || *last == d_output) && rest.iter().all(|size| *size == 1)
+            }
+            None => true,
+        },
+        None => true,
+    }
+}
+
+/// This function moves the constant starts, ends, axes and steps of a Slice node to its
+/// attributes, as they were before opset 10.
+///
+/// When the sliced data is constant as well, the slice is computed and the node is converted
+/// into a Constant node.
+fn convert_slice(node: &mut Node) {
+    let data_is_constant = node.inputs.iter().all(|input| input.name.is_empty());
+    // Only the axes can be skipped before the steps.
+    let axes_skipped = node.inputs.iter().any(|input| input.name.is_empty());
+    remove_empty_inputs(node);
 
-        // Transpose the weights
-        transpose_linear_node_weights(node);
+    let mut states = core::mem::take(&mut node.states).into_iter();
+    let data = data_is_constant.then(|| states.next()).flatten();
+    let names: &[&str] = if axes_skipped {
+        &["starts", "ends", "steps"]
     } else {
-        panic!("Full Gemm node not supported yet.");
+        &["starts", "ends", "axes", "steps"]
+    };
+
+    for (name, state) in names.iter().zip(states) {
+        let StateType::Tensor(tensor) = state.ty;
+        let values = match tensor.data {
+            Some(TensorData::Int64(values)) => values,
+            Some(TensorData::Int32(values)) => {
+                values.into_iter().map(|value| value as i64).collect()
+            }
+            _ => panic!("Slice: {name} must be integers"),
+        };
+        node.attrs
+            .insert(name.to_string(), AttributeValue::Int64s(values));
+    }
+
+    if let Some(data) = data {
+        node.states.push(data);
+        fold_slice(node);
     }
 }
 
-// Transpose linear weights (required for Gemm -> Linear conversion)
-fn transpose_linear_node_weights(node: &mut Node) {
+/// This function moves the constant parameters of a NonMaxSuppression node to its attributes.
+///
+/// Exporters give all the parameters as constants, which are the states of the node in the order
+/// of the inputs.
+fn convert_non_max_suppression(node: &mut Node) {
     if node.states.is_empty() {
-        panic!("Linear node must have at least 1 state.");
+        return;
+    }
+    if node.inputs.len() > 2 {
+        panic!("NonMaxSuppression: the parameters must be either all constant or all computed");
+    }
+
+    let names = [
+        "max_output_boxes_per_class",
+        "iou_threshold",
+        "score_threshold",
+    ];
+    for (name, state) in names.iter().zip(core::mem::take(&mut node.states)) {
+        let StateType::Tensor(tensor) = state.ty;
+        let value = match tensor.data {
+            Some(TensorData::Int64(values)) if values.len() == 1 => {
+                AttributeValue::Int64(values[0])
+            }
+            Some(TensorData::Float32(values)) if values.len() == 1 => {
+                AttributeValue::Float32(values[0])
+            }
+            _ => panic!("NonMaxSuppression: {name} must be a single number"),
+        };
+        node.attrs.insert(name.to_string(), value);
+    }
+}
+
+/// This function moves the constant split sizes of a Split node to its attributes, as they were
+/// before opset 13.
+fn convert_split(node: &mut Node) {
+    remove_empty_inputs(node);
+
+    if let Some(state) = node.states.pop() {
+        let StateType::Tensor(tensor) = state.ty;
+        let sizes = match tensor.data {
+            Some(TensorData::Int64(values)) => values,
+            Some(TensorData::Int32(values)) => {
+                values.into_iter().map(|value| value as i64).collect()
+            }
+            _ => panic!("Split: split must be integers"),
+        };
+        node.attrs
+            .insert("split".to_string(), AttributeValue::Int64s(sizes));
     }
+}
 
-    let StateType::Tensor(node_weight) = &node.states[0].ty;
+/// This function moves the constant repeats of a Tile node to its attributes.
+fn convert_tile(node: &mut Node) {
+    if let Some(state) = node.states.pop() {
+        let StateType::Tensor(tensor) = state.ty;
+        let repeats = match tensor.data {
+            Some(TensorData::Int64(values)) => values,
+            _ => panic!("Tile: repeats must be Int64"),
+        };
+        node.attrs
+            .insert("repeats".to_string(), AttributeValue::Int64s(repeats));
+    }
+}
 
-    let weight: Tensor<B, 2> = node_weight.try_into().unwrap();
+// Compute the slice of the constant data, and convert the node into a Constant node holding it.
+fn fold_slice(node: &mut Node) {
+    let ranges = slice_config(node);
+    let StateType::Tensor(mut tensor) = node.states.remove(0).ty;
+    let mut shape = tensor
+        .shape
+        .take()
+        .expect("Slice: constant data must have a shape");
 
-    let weight = weight.transpose();
+    // The selected index of every axis.
+    let indices = shape
+        .iter()
+        .enumerate()
+        .map(
+            |(axis, size)| match ranges.iter().find(|range| range.axis == axis) {
+                Some(range) => range.indices(*size),
+                None => (0..*size).collect(),
+            },
+        )
+        .collect::<Vec<_>>();
 
-    let StateType::Tensor(node_weight) = &mut node.states[0].ty;
+    tensor.data = match tensor.data.take() {
+        Some(TensorData::Float16(values)) => {
+            Some(TensorData::Float16(select_values(values, &shape, &indices)))
+        }
+        Some(TensorData::Float32(values)) => {
+            Some(TensorData::Float32(select_values(values, &shape, &indices)))
+        }
+        Some(TensorData::Float64(values)) => {
+            Some(TensorData::Float64(select_values(values, &shape, &indices)))
+        }
+        Some(TensorData::Int32(values)) => {
+            Some(TensorData::Int32(select_values(values, &shape, &indices)))
+        }
+        Some(TensorData::Int64(values)) => {
+            Some(TensorData::Int64(select_values(values, &shape, &indices)))
+        }
+        Some(TensorData::Bool(values)) => {
+            Some(TensorData::Bool(select_values(values, &shape, &indices)))
+        }
+        _ => panic!("Slice: unsupported constant data"),
+    };
+
+    for (size, axis_indices) in shape.iter_mut().zip(indices.iter()) {
+        *size = axis_indices.len();
+    }
+    tensor.shape = Some(shape);
+
+    node.node_type = NodeType::Constant;
+    node.attrs = [("value".to_string(), AttributeValue::Tensor(tensor))]
+        .into_iter()
+        .collect();
+}
+
+// Select the values of a row-major tensor at the given indices of every axis.
+fn select_values<T: Clone>(values: Vec<T>, shape: &[usize], indices: &[Vec<usize>]) -> Vec<T> {
+    let mut strides = vec![1; shape.len()];
+    for axis in (0..shape.len().saturating_sub(1)).rev() {
+        strides[axis] = strides[axis + 1] * shape[axis + 1];
+    }
+
+    let mut offsets = vec![0];
+    for (axis_indices, stride) in indices.iter().zip(strides) {
+        offsets = offsets
+            .into_iter()
+            .flat_map(|offset| {
+                axis_indices
+                    .iter()
+                    .map(move |index| offset + index * stride)
+            })
+            .collect();
+    }
+
+    offsets
+        .into_iter()
+        .map(|offset| values[offset].clone())
+        .collect()
+}
+
+/// This function unpacks the initializers of an LSTM node into the weights of the burn LSTM module.
+///
+/// ONNX packs the gates of every direction into `W` (`[num_directions, 4 * hidden_size,
+/// input_size]`), `R` (`[num_directions, 4 * hidden_size, hidden_size]`) and `B`
+/// (`[num_directions, 8 * hidden_size]`) in the `iofc` order. After the conversion, the states
+/// hold for every direction and every gate, in the input, forget, output and cell order of the
+/// module, the transposed input and hidden weights followed by their biases when `B` is present.
+///
+/// The optional inputs and outputs skipped with an empty name are removed, and zero initial
+/// states stored as initializers are dropped since they are the default of the module.
+fn convert_lstm(node: &mut Node) {
+    unpack_rnn_states(node, "LSTM", &[0, 2, 1, 3], &["initial_h", "initial_c"]);
+}
+
+/// This function unpacks the initializers of a GRU node into the weights of the burn GRU module.
+///
+/// Same as [convert_lstm], with the gates packed in the `zrh` order, which is the update, reset
+/// and new gate order of the module.
+fn convert_gru(node: &mut Node) {
+    unpack_rnn_states(node, "GRU", &[0, 1, 2], &["initial_h"]);
+}
+
+// Split the packed W, R and B initializers of a recurrent node per direction and per gate,
+// following the ONNX gate indices of the module gates.
+fn unpack_rnn_states(node: &mut Node, op: &str, gate_order: &[usize], initial_states: &[&str]) {
+    node.inputs.retain(|input| !input.name.is_empty());
+    while matches!(node.outputs.last(), Some(output) if output.name.is_empty()) {
+        node.outputs.pop();
+    }
+
+    if node.inputs.len() != 1 && node.inputs.len() != 1 + initial_states.len() {
+        panic!(
+            "{op}: sequence_lens is not supported and {} must be provided together",
+            initial_states.join(" and ")
+        );
+    }
+
+    if node.states.len() < 2 {
+        panic!("{op}: W and R must be initializers");
+    }
+
+    let mut states = node.states.drain(..).map(|state| {
+        let StateType::Tensor(tensor) = state.ty;
+        tensor
+    });
+
+    let input_weights: Tensor<B, 3> = (&states.next().unwrap()).try_into().unwrap();
+    let hidden_weights: Tensor<B, 3> = (&states.next().unwrap()).try_into().unwrap();
+    let [num_directions, gates_size, hidden_size] = hidden_weights.dims();
+    let num_gates = gate_order.len();
+
+    if gates_size != num_gates * hidden_size {
+        panic!(
+            "{op}: R must have a shape of [num_directions, {num_gates} * hidden_size, hidden_size]"
+        );
+    }
+
+    let mut biases: Option<Tensor<B, 2>> = None;
+
+    for tensor in states {
+        let is_biases = biases.is_none()
+            && tensor.shape == Some(vec![num_directions, 2 * num_gates * hidden_size]);
+        let is_zeros = tensor.dim == 3
+            && matches!(&tensor.data, Some(TensorData::Float32(values)) if values.iter().all(|value| *value == 0.0));
+
+        if is_biases {
+            biases = Some((&tensor).try_into().unwrap());
+        } else if !is_zeros {
+            panic!(
+                "{op}: only W, R, B and zero {} initializers are supported",
+                initial_states.join(" and ")
+            );
+        }
+    }
+
+    let mut states = Vec::new();
+
+    for direction in 0..num_directions {
+        for &gate in gate_order {
+            let gate_range = gate * hidden_size..(gate + 1) * hidden_size;
+
+            for (kind, weights) in [("input", &input_weights), ("hidden", &hidden_weights)] {
+                let weight: Tensor<B, 2> = weights
+                    .clone()
+                    .slice([direction..direction + 1, gate_range.clone()])
+                    .squeeze(0);
+
+                states.push(float_state(
+                    format!("{}_{direction}_{gate}_{kind}_weight", node.name),
+                    weight.transpose(),
+                ));
+            }
 
-    node_weight.data = Some(TensorData::Float32(weight.clone().into_data().value));
-    node_weight.shape = Some(weight.shape().dims.to_vec());
+            if let Some(biases) = &biases {
+                let hidden_offset = num_gates * hidden_size;
+
+                for (kind, offset) in [("input", 0), ("hidden", hidden_offset)] {
+                    let bias: Tensor<B, 2> = biases.clone().slice([
+                        direction..direction + 1,
+                        offset + gate_range.start..offset + gate_range.end,
+                    ]);
+
+                    states.push(float_state(
+                        format!("{}_{direction}_{gate}_{kind}_bias", node.name),
+                        bias.reshape([hidden_size]),
+                    ));
+                }
+            }
+        }
+    }
+
+    node.states = states;
+}
+
+// Create a float state holding the data of the tensor
+fn float_state<const D: usize>(name: String, tensor: Tensor<B, D>) -> State {
+    let mut node_tensor = ir::Tensor {
+        elem_type: ElementType::Float32,
+        dim: D,
+        data: None,
+        shape: None,
+    };
+    set_tensor_data(&mut node_tensor, tensor);
+
+    State {
+        name,
+        ty: StateType::Tensor(node_tensor),
+    }
+}
+
+// Replace the data of the intermediate representation of a tensor
+fn set_tensor_data<const D: usize>(node_tensor: &mut ir::Tensor, tensor: Tensor<B, D>) {
+    node_tensor.dim = D;
+    node_tensor.shape = Some(tensor.shape().dims.to_vec());
+    node_tensor.data = Some(TensorData::Float32(tensor.into_data().value));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::onnx::ir::{ArgType, Argument, AttributeValue, ElementType, State, TensorArg};
+
+    fn state(name: &str, shape: Vec<usize>, values: Vec<f32>) -> State {
+        State {
+            name: name.to_string(),
+            ty: StateType::Tensor(ir::Tensor {
+                elem_type: ElementType::Float32,
+                dim: shape.len(),
+                data: Some(TensorData::Float32(values)),
+                shape: Some(shape),
+            }),
+        }
+    }
+
+    fn argument(name: &str) -> Argument {
+        Argument {
+            name: name.to_string(),
+            ty: ArgType::Tensor(TensorArg::new(2, ElementType::Float32)),
+        }
+    }
+
+    fn gemm_node(states: Vec<State>, attrs: Vec<(&str, AttributeValue)>) -> Node {
+        // The initializers are both kept as inputs and moved to the states
+        let initializers = states.iter().map(|state| argument(&state.name));
+
+        Node {
+            node_type: NodeType::Gemm,
+            name: "gemm1".to_string(),
+            inputs: [argument("input")]
+                .into_iter()
+                .chain(initializers)
+                .collect(),
+            outputs: vec![argument("output")],
+            states,
+            attrs: attrs
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value))
+                .collect(),
+        }
+    }
+
+    fn state_data(node: &Node, index: usize) -> (Vec<usize>, Vec<f32>) {
+        let StateType::Tensor(tensor) = &node.states[index].ty;
+        let TensorData::Float32(values) = tensor.data.clone().unwrap() else {
+            panic!("Expected float32s");
+        };
+
+        (tensor.shape.clone().unwrap(), values)
+    }
+
+    #[test]
+    fn gemm_with_initializers_should_become_linear() {
+        let mut node = gemm_node(
+            vec![
+                state("weight", vec![3, 2], vec![1., 2., 3., 4., 5., 6.]),
+                state("bias", vec![1, 3], vec![1., 2., 3.]),
+            ],
+            vec![
+                ("alpha", AttributeValue::Float32(2.0)),
+                ("beta", AttributeValue::Float32(0.5)),
+                ("transB", AttributeValue::Int64(1)),
+            ],
+        );
+
+        convert_gemm(&mut node);
+
+        assert_eq!(node.node_type, NodeType::Linear);
+        assert!(node.attrs.is_empty());
+        assert_eq!(
+            state_data(&node, 0),
+            (vec![2, 3], vec![2., 6., 10., 4., 8., 12.])
+        );
+        assert_eq!(state_data(&node, 1), (vec![3], vec![0.5, 1., 1.5]));
+    }
+
+    #[test]
+    fn gemm_with_initializers_should_use_default_attributes() {
+        let mut node = gemm_node(
+            vec![
+                state("weight", vec![2, 3], vec![1., 2., 3., 4., 5., 6.]),
+                state("bias", vec![], vec![2.]),
+            ],
+            vec![],
+        );
+
+        convert_gemm(&mut node);
+
+        assert_eq!(node.node_type, NodeType::Linear);
+        assert_eq!(
+            state_data(&node, 0),
+            (vec![2, 3], vec![1., 2., 3., 4., 5., 6.])
+        );
+        assert_eq!(state_data(&node, 1), (vec![3], vec![2., 2., 2.]));
+    }
+
+    #[test]
+    fn gemm_with_transposed_input_should_read_the_initializers_as_constants() {
+        let mut node = gemm_node(
+            vec![
+                state("weight", vec![2, 3], vec![1., 2., 3., 4., 5., 6.]),
+                state("bias", vec![3], vec![1., 2., 3.]),
+            ],
+            vec![("transA", AttributeValue::Int64(1))],
+        );
+
+        let constants = convert_gemm(&mut node);
+
+        assert_eq!(node.node_type, NodeType::Gemm);
+        assert!(node.states.is_empty());
+        let inputs = node.inputs.iter().map(|input| input.name.as_str());
+        assert!(inputs.eq(["input", "weight", "bias"]));
+        let outputs = constants.iter().map(|node| node.outputs[0].name.as_str());
+        assert!(outputs.eq(["weight", "bias"]));
+        assert!(constants
+            .iter()
+            .all(|node| node.node_type == NodeType::Constant));
+    }
+
+    #[test]
+    fn gemm_with_full_shape_bias_should_read_the_initializers_as_constants() {
+        let mut node = gemm_node(
+            vec![
+                state("weight", vec![2, 3], vec![1., 2., 3., 4., 5., 6.]),
+                state("bias", vec![2, 3], vec![1., 2., 3., 4., 5., 6.]),
+            ],
+            vec![],
+        );
+
+        let constants = convert_gemm(&mut node);
+
+        assert_eq!(node.node_type, NodeType::Gemm);
+        assert_eq!(node.inputs.len(), 3);
+        assert_eq!(constants.len(), 2);
+    }
+
+    #[test]
+    fn gemm_with_initializer_input_should_read_it_as_constant() {
+        let mut node = gemm_node(vec![], vec![]);
+        node.inputs.insert(0, argument("lhs"));
+        node.states = vec![state("lhs", vec![1, 2], vec![1., 2.])];
+
+        let constants = convert_gemm(&mut node);
+
+        assert_eq!(node.node_type, NodeType::Gemm);
+        assert_eq!(node.inputs.len(), 2);
+        assert_eq!(constants.len(), 1);
+    }
+
+    #[test]
+    fn gemm_without_initializers_should_be_kept() {
+        let mut node = gemm_node(vec![], vec![("transA", AttributeValue::Int64(1))]);
+        node.inputs.push(argument("weight"));
+
+        convert_gemm(&mut node);
+
+        assert_eq!(node.node_type, NodeType::Gemm);
+        assert_eq!(node.inputs.len(), 2);
+    }
+
+    fn rnn_node(node_type: NodeType, states: Vec<State>) -> Node {
+        Node {
+            name: format!("{node_type}1").to_lowercase(),
+            node_type,
+            inputs: vec![argument("input"), argument(""), argument("")],
+            outputs: vec![argument("output"), argument(""), argument("")],
+            states,
+            attrs: Default::default(),
+        }
+    }
+
+    #[test]
+    fn lstm_initializers_should_be_unpacked_per_gate() {
+        let mut node = rnn_node(
+            NodeType::LSTM,
+            vec![
+                state("W", vec![1, 4, 2], vec![1., 2., 3., 4., 5., 6., 7., 8.]),
+                state("R", vec![1, 4, 1], vec![9., 10., 11., 12.]),
+                state(
+                    "B",
+                    vec![1, 8],
+                    (13..21).map(|value| value as f32).collect(),
+                ),
+                state("initial_h", vec![1, 1, 1], vec![0.]),
+            ],
+        );
+
+        convert_lstm(&mut node);
+
+        assert_eq!(node.inputs.len(), 1);
+        assert_eq!(node.outputs.len(), 1);
+        assert_eq!(node.states.len(), 16);
+
+        // Input, forget, output and cell gates from the ONNX iofc order
+        let gates = [
+            (vec![1., 2.], 9., 13., 17.),
+            (vec![5., 6.], 11., 15., 19.),
+            (vec![3., 4.], 10., 14., 18.),
+            (vec![7., 8.], 12., 16., 20.),
+        ];
+
+        for (gate, (input, hidden, input_bias, hidden_bias)) in gates.into_iter().enumerate() {
+            let index = gate * 4;
+
+            assert_eq!(state_data(&node, index), (vec![2, 1], input));
+            assert_eq!(state_data(&node, index + 1), (vec![1, 1], vec![hidden]));
+            assert_eq!(state_data(&node, index + 2), (vec![1], vec![input_bias]));
+            assert_eq!(state_data(&node, index + 3), (vec![1], vec![hidden_bias]));
+        }
+    }
+
+    #[test]
+    #[should_panic = "zero initial_h and initial_c"]
+    fn lstm_non_zero_initial_state_initializer_should_panic() {
+        let mut node = rnn_node(
+            NodeType::LSTM,
+            vec![
+                state("W", vec![1, 4, 1], vec![1.; 4]),
+                state("R", vec![1, 4, 1], vec![1.; 4]),
+                state("initial_h", vec![1, 1, 1], vec![1.]),
+            ],
+        );
+
+        convert_lstm(&mut node);
+    }
+
+    #[test]
+    fn gru_initializers_should_be_unpacked_per_gate() {
+        let mut node = rnn_node(
+            NodeType::GRU,
+            vec![
+                state("W", vec![1, 3, 1], vec![1., 2., 3.]),
+                state("R", vec![1, 3, 1], vec![4., 5., 6.]),
+                state("B", vec![1, 6], vec![7., 8., 9., 10., 11., 12.]),
+            ],
+        );
+
+        convert_gru(&mut node);
+
+        assert_eq!(node.inputs.len(), 1);
+        assert_eq!(node.outputs.len(), 1);
+        assert_eq!(node.states.len(), 12);
+
+        // Update, reset and new gates from the ONNX zrh order
+        for (gate, (input, hidden, input_bias, hidden_bias)) in
+            [(1., 4., 7., 10.), (2., 5., 8., 11.), (3., 6., 9., 12.)]
+                .into_iter()
+                .enumerate()
+        {
+            let index = gate * 4;
+
+            assert_eq!(state_data(&node, index), (vec![1, 1], vec![input]));
+            assert_eq!(state_data(&node, index + 1), (vec![1, 1], vec![hidden]));
+            assert_eq!(state_data(&node, index + 2), (vec![1], vec![input_bias]));
+            assert_eq!(state_data(&node, index + 3), (vec![1], vec![hidden_bias]));
+        }
+    }
+
+    fn int_state(name: &str, values: Vec<i64>) -> State {
+        State {
+            name: name.to_string(),
+            ty: StateType::Tensor(ir::Tensor {
+                elem_type: ElementType::Int64,
+                dim: 1,
+                shape: Some(vec![values.len()]),
+                data: Some(TensorData::Int64(values)),
+            }),
+        }
+    }
+
+    fn slice_node(inputs: Vec<Argument>, states: Vec<State>) -> Node {
+        Node {
+            node_type: NodeType::Slice,
+            name: "slice1".to_string(),
+            inputs,
+            outputs: vec![argument("output")],
+            states,
+            attrs: Default::default(),
+        }
+    }
+
+    #[test]
+    fn slice_inputs_should_be_moved_to_attributes() {
+        let mut node = slice_node(
+            vec![argument("input"), argument("")],
+            vec![
+                int_state("starts", vec![0]),
+                int_state("ends", vec![-1]),
+                int_state("steps", vec![2]),
+            ],
+        );
+
+        convert_slice(&mut node);
+
+        assert_eq!(node.node_type, NodeType::Slice);
+        assert_eq!(node.inputs.len(), 1);
+        assert!(node.states.is_empty());
+        assert!(matches!(&node.attrs["ends"], AttributeValue::Int64s(ends) if ends == &[-1]));
+        assert!(matches!(&node.attrs["steps"], AttributeValue::Int64s(steps) if steps == &[2]));
+        assert!(!node.attrs.contains_key("axes"));
+    }
+
+    #[test]
+    fn slice_of_constant_data_should_be_folded() {
+        let mut node = slice_node(
+            vec![],
+            vec![
+                state("data", vec![2, 3], vec![1., 2., 3., 4., 5., 6.]),
+                int_state("starts", vec![-1, 0]),
+                int_state("ends", vec![i64::MIN, i64::MAX]),
+                int_state("axes", vec![0, -1]),
+                int_state("steps", vec![-1, 2]),
+            ],
+        );
+
+        convert_slice(&mut node);
+
+        assert_eq!(node.node_type, NodeType::Constant);
+        assert!(node.states.is_empty());
+        let AttributeValue::Tensor(tensor) = &node.attrs["value"] else {
+            panic!("Expected a tensor value");
+        };
+        assert_eq!(tensor.shape, Some(vec![2, 2]));
+        assert!(
+            matches!(&tensor.data, Some(TensorData::Float32(values)) if values == &[4., 6., 1., 3.])
+        );
+    }
+
+    fn gather_node(states: Vec<State>) -> Node {
+        Node {
+            node_type: NodeType::Gather,
+            name: "gather1".to_string(),
+            inputs: vec![argument("input")],
+            outputs: vec![argument("output")],
+            states,
+            attrs: Default::default(),
+        }
+    }
+
+    #[test]
+    fn gather_of_weights_should_be_converted_to_embedding() {
+        let mut node = gather_node(vec![state("weight", vec![3, 2], vec![0.; 6])]);
+
+        convert_gather(&mut node);
+
+        assert_eq!(node.node_type, NodeType::Embedding);
+    }
+
+    #[test]
+    fn gather_with_constant_indices_should_be_kept() {
+        let mut node = gather_node(vec![int_state("indices", vec![0, 2])]);
+
+        convert_gather(&mut node);
+
+        assert_eq!(node.node_type, NodeType::Gather);
+    }
+
+    #[test]
+    fn clip_inputs_should_be_moved_to_attributes() {
+        let mut node = Node {
+            node_type: NodeType::Clip,
+            name: "clip1".to_string(),
+            inputs: vec![argument("input"), argument("")],
+            outputs: vec![argument("output")],
+            states: vec![state("max", vec![], vec![6.])],
+            attrs: Default::default(),
+        };
+
+        convert_clip(&mut node);
+
+        assert_eq!(node.inputs.len(), 1);
+        assert!(node.states.is_empty());
+        assert!(!node.attrs.contains_key("min"));
+        assert!(matches!(node.attrs["max"], AttributeValue::Float32(max) if max == 6.));
+    }
+
+    #[test]
+    fn reduce_axes_should_be_moved_to_attributes() {
+        let mut node = Node {
+            node_type: NodeType::ReduceSum,
+            name: "reducesum1".to_string(),
+            inputs: vec![argument("input")],
+            outputs: vec![argument("output")],
+            states: vec![int_state("axes", vec![-1])],
+            attrs: Default::default(),
+        };
+
+        convert_reduce(&mut node);
+
+        assert!(node.states.is_empty());
+        assert!(matches!(&node.attrs["axes"], AttributeValue::Int64s(axes) if axes == &[-1]));
+    }
+
+    #[test]
+    fn split_sizes_should_be_moved_to_attributes() {
+        let mut node = Node {
+            node_type: NodeType::Split,
+            name: "split1".to_string(),
+            inputs: vec![argument("input")],
+            outputs: vec![argument("output1"), argument("output2")],
+            states: vec![int_state("split", vec![2, 3])],
+            attrs: Default::default(),
+        };
+
+        convert_split(&mut node);
+
+        assert!(node.states.is_empty());
+        assert!(matches!(&node.attrs["split"], AttributeValue::Int64s(sizes) if sizes == &[2, 3]));
+    }
+
+    #[test]
+    fn expand_shape_should_be_moved_to_attributes() {
+        let mut node = Node {
+            node_type: NodeType::Expand,
+            name: "expand1".to_string(),
+            inputs: vec![argument("input")],
+            outputs: vec![argument("output")],
+            states: vec![int_state("shape", vec![2, 1, 3])],
+            attrs: Default::default(),
+        };
+
+        convert_shape(&mut node);
+
+        assert!(node.states.is_empty());
+        assert!(
+            matches!(&node.attrs["shape"], AttributeValue::Int64s(shape) if shape == &[2, 1, 3])
+        );
+    }
 }
@@ -2,12 +2,17 @@ use std::collections::HashMap;
 
 use protobuf::Enum;
 
+use crate::burn::node::constant_of_shape::FillValue;
+
 use super::{
     ir::{
-        ArgType, Argument, AttributeValue, ElementType, Node, NodeType, StateType, TensorArg,
-        TensorData,
+        ArgType, Argument, AttributeValue, ElementType, Node, NodeType, ONNXGraph, StateType,
+        TensorArg,
+    },
+    op_configuration::{
+        arg_reduce_config, constant_of_shape_config, einsum_config, expand_config, flatten_config,
+        one_hot_config, reduce_config, reshape_config, shape_config,
     },
-    op_configuration::flatten_config,
     protos::tensor_proto::DataType,
 };
 
@@ -63,45 +68,100 @@ pub fn dim_inference(
 
     for node in nodes.iter_mut() {
         updater.update_tensor_inputs(node);
+        let static_shapes = node
+            .outputs
+            .iter()
+            .map(|output| match &output.ty {
+                ArgType::Tensor(tensor) => tensor.static_shape.clone(),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
 
         match node.node_type {
             NodeType::Conv1d => conv1d_update_outputs(node),
             NodeType::Conv2d => conv2d_update_outputs(node),
+            NodeType::ConvTranspose1d => same_as_input(node),
+            NodeType::ConvTranspose2d => same_as_input(node),
             NodeType::MaxPool2d => same_as_input(node),
             NodeType::Linear => linear_update_outputs(node),
+            NodeType::Gemm => gemm_update_outputs(node),
+            NodeType::Gather => gather_update_outputs(node),
+            NodeType::Embedding => embedding_update_outputs(node),
+            NodeType::LSTM => rnn_update_outputs(node),
+            NodeType::GRU => rnn_update_outputs(node),
             NodeType::Flatten => flatten_update_outputs(node),
+            NodeType::Pad => same_as_input(node),
+            NodeType::OneHot => one_hot_update_outputs(node),
+            NodeType::NonMaxSuppression => non_max_suppression_update_outputs(node),
             NodeType::Relu => same_as_input(node),
+            NodeType::LeakyRelu => same_as_input(node),
+            NodeType::PRelu => same_as_input(node),
+            NodeType::Clip => same_as_input(node),
             NodeType::LogSoftmax => same_as_input(node),
             NodeType::BatchNormalization => same_as_input(node),
+            NodeType::GroupNormalization => same_as_input(node),
+            NodeType::LayerNormalization => same_as_input(node),
             NodeType::Add => same_as_input(node),
             NodeType::Sub => same_as_input(node),
             NodeType::Pow => same_as_input(node),
             NodeType::Mul => same_as_input(node),
             NodeType::Cast => cast_update_outputs(node),
+            NodeType::ArgMax | NodeType::ArgMin => arg_reduce_update_outputs(node),
             NodeType::Div => same_as_input(node),
             NodeType::Sqrt => same_as_input(node),
             NodeType::Softmax => same_as_input(node),
             NodeType::Erf => same_as_input(node),
-            NodeType::ReduceMean => mean_update_outputs(node),
+            NodeType::ReduceMax | NodeType::ReduceMean | NodeType::ReduceSum => {
+                reduce_update_outputs(node)
+            }
             NodeType::Constant => constant_update_outputs(node),
-            NodeType::Equal => same_as_input(node),
+            NodeType::Equal => comparison_update_outputs(node),
             NodeType::Shape => shape_update_outputs(node),
+            NodeType::ConstantOfShape => constant_of_shape_update_outputs(node),
             NodeType::Unsqueeze => unsqueeze_update_outputs(node),
             NodeType::Slice => slice_update_outputs(node),
-            NodeType::MatMul => same_as_input(node),
+            NodeType::Split => split_update_outputs(node),
+            NodeType::Tile => same_as_input(node),
+            NodeType::Expand => expand_update_outputs(node),
+            NodeType::MatMul => matmul_update_outputs(node),
             NodeType::Sigmoid => same_as_input(node),
+            NodeType::Tanh => same_as_input(node),
+            NodeType::Softplus => same_as_input(node),
+            NodeType::HardSigmoid => same_as_input(node),
+            NodeType::HardSwish => same_as_input(node),
+            NodeType::Gelu => same_as_input(node),
             NodeType::Transpose => same_as_input(node),
             NodeType::Concat => concat_update_outputs(node),
+            NodeType::Where => where_update_outputs(node),
             NodeType::Reshape => reshape_update_outputs(node),
             NodeType::Dropout => same_as_input(node),
+            NodeType::Einsum => einsum_update_outputs(node),
+            NodeType::DepthToSpace => same_as_input(node),
+            NodeType::Scatter => same_as_input(node),
+            NodeType::ScatterElements => same_as_input(node),
+            NodeType::ScatterND => same_as_input(node),
+            NodeType::SpaceToDepth => same_as_input(node),
             NodeType::GlobalAveragePool => same_as_input(node),
             NodeType::AveragePool2d => same_as_input(node),
+            NodeType::Identity => same_as_input(node),
+            NodeType::If => if_update_outputs(node),
+            NodeType::Loop => loop_update_outputs(node),
+            NodeType::Scan => scan_update_outputs(node),
+            NodeType::Custom(_) => custom_update_outputs(node),
             _ => todo!(
                 "shape inference for {:?} is not implemented",
                 node.node_type
             ),
         }
 
+        // The outputs only keep the sizes declared in the model, of the inferred rank.
+        for (output, static_shape) in node.outputs.iter_mut().zip(static_shapes) {
+            if let ArgType::Tensor(tensor) = &mut output.ty {
+                tensor.static_shape =
+                    static_shape.filter(|static_shape| static_shape.len() == tensor.dim);
+            }
+        }
+
         updater.update_tensor_outputs(node);
     }
 
@@ -114,7 +174,7 @@ fn constant_update_outputs(node: &mut Node) {
     match node.attrs.get("value") {
         Some(value) => match &value {
             AttributeValue::Tensor(tensor) => {
-                output.ty = ArgType::Tensor(TensorArg { dim: tensor.dim });
+                output.ty = ArgType::Tensor(TensorArg::new(tensor.dim, tensor.elem_type.clone()));
             }
             _ => {}
         },
@@ -122,6 +182,67 @@ fn constant_update_outputs(node: &mut Node) {
     };
 }
 
+/// Infer the shape of the output tensor of a Gather node
+fn gather_update_outputs(node: &mut Node) {
+    let (dim, elem_type) = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => (tensor.dim, tensor.elem_type.clone()),
+        ArgType::Shape(_) => return shape_gather_update_outputs(node),
+        _ => panic!("Gather: only tensor input is valid"),
+    };
+
+    // The indices are either computed or constant.
+    let indices_dim = match (node.inputs.get(1), node.states.first()) {
+        (Some(indices), _) => match &indices.ty {
+            ArgType::Tensor(tensor) => tensor.dim,
+            _ => panic!("Gather: only tensor indices are valid"),
+        },
+        (None, Some(state)) => match &state.ty {
+            StateType::Tensor(tensor) => tensor.dim,
+        },
+        (None, None) => panic!("Gather: missing indices"),
+    };
+
+    if dim + indices_dim < 2 {
+        panic!("Gather: scalar output is not supported");
+    }
+
+    node.outputs[0].ty = ArgType::Tensor(TensorArg::new(dim + indices_dim - 1, elem_type));
+}
+
+/// The sizes gathered from a shape with a constant index are an integer, or a shape of the
+/// number of indices.
+fn shape_gather_update_outputs(node: &mut Node) {
+    let indices = match (node.inputs.get(1), node.states.first()) {
+        (None, Some(state)) => match &state.ty {
+            StateType::Tensor(tensor) => tensor,
+        },
+        _ => panic!("Gather: the indices of a shape must be constant"),
+    };
+
+    node.outputs[0].ty = match indices.dim {
+        0 => ArgType::Scalar(ElementType::Int64),
+        1 => ArgType::Shape(
+            indices
+                .shape
+                .as_ref()
+                .and_then(|shape| shape.first().copied())
+                .expect("Gather: the shape of the indices must be known"),
+        ),
+        _ => panic!("Gather: the indices of a shape must be a scalar or a vector"),
+    };
+}
+
+/// Infer the shape of the output tensor of an Embedding node
+fn embedding_update_outputs(node: &mut Node) {
+    match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => {
+            node.outputs[0].ty =
+                ArgType::Tensor(TensorArg::new(tensor.dim + 1, ElementType::Float32));
+        }
+        _ => panic!("Embedding: only tensor input is valid"),
+    }
+}
+
 /// Infer the shape of the output tensor of a Conv2d node
 fn linear_update_outputs(node: &mut Node) {
     if node.inputs.len() != 1 {
@@ -133,18 +254,67 @@ fn linear_update_outputs(node: &mut Node) {
 
     if let ArgType::Tensor(tensor) = node_input.clone().ty {
         // Update the output tensor
-        node.outputs[0].ty = ArgType::Tensor(TensorArg { dim: tensor.dim });
+        node.outputs[0].ty = ArgType::Tensor(TensorArg::new(tensor.dim, tensor.elem_type.clone()));
     } else {
         panic!("Only tensor input is valid");
     }
 }
 
+/// Infer the dimension of the output of a MatMul node following the numpy semantics
+fn matmul_update_outputs(node: &mut Node) {
+    if node.inputs.len() != 2 {
+        panic!("MatMul: initializer inputs are not supported");
+    }
+
+    let (ArgType::Tensor(lhs), ArgType::Tensor(rhs)) = (&node.inputs[0].ty, &node.inputs[1].ty)
+    else {
+        panic!("MatMul: only tensor inputs are valid");
+    };
+
+    // Vectors are promoted to matrices, and the added dimension is then removed. The scalar dot
+    // product of two vectors is a tensor of one element.
+    let dim = (usize::max(lhs.dim, rhs.dim).max(2)
+        - usize::from(lhs.dim == 1)
+        - usize::from(rhs.dim == 1))
+    .max(1);
+
+    node.outputs[0].ty = ArgType::Tensor(TensorArg::new(dim, lhs.elem_type.clone()));
+}
+
+/// Infer the dimension of the output of a Gemm node, which is always a matrix
+fn gemm_update_outputs(node: &mut Node) {
+    node.outputs[0].ty = ArgType::Tensor(TensorArg::new(2, ElementType::Float32));
+}
+
+/// Y is [seq_length, num_directions, batch_size, hidden_size] while Y_h and the LSTM Y_c are
+/// [num_directions, batch_size, hidden_size], or batch first with a layout of 1.
+fn rnn_update_outputs(node: &mut Node) {
+    for (output, dim) in node.outputs.iter_mut().zip([4, 3, 3]) {
+        output.ty = ArgType::Tensor(TensorArg::new(dim, ElementType::Float32));
+    }
+}
+
+fn expand_update_outputs(node: &mut Node) {
+    let elem_type = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.elem_type.clone(),
+        _ => panic!("Expand: only tensor input is valid"),
+    };
+    let dim = expand_config(node).rank();
+
+    node.outputs[0].ty = ArgType::Tensor(TensorArg::new(dim, elem_type));
+}
+
+fn split_update_outputs(node: &mut Node) {
+    for output in node.outputs.iter_mut() {
+        output.ty = node.inputs[0].ty.clone();
+    }
+}
+
 /// Update the output type using "to" attribute
 fn cast_update_outputs(node: &mut Node) {
     if node.inputs.len() != 1 {
         panic!("Cast: multiple inputs are not supported");
     }
-    let output = &mut node.outputs[0];
 
     // Extract cast type and update the output tensor
     let elem_type = match node.attrs.get("to") {
@@ -154,32 +324,22 @@ fn cast_update_outputs(node: &mut Node) {
                 DataType::INT32 => ElementType::Int32,
                 DataType::INT64 => ElementType::Int64,
                 DataType::DOUBLE => ElementType::Float64,
+                DataType::FLOAT16 => ElementType::Float16,
+                DataType::BOOL => ElementType::Bool,
                 _ => panic!("Cast: unsupported type"),
             },
             _ => panic!("'to' attribute must be an Int64"),
         },
-        None => panic!("Constant node must have a value attribute"),
+        None => panic!("Cast node must have a 'to' attribute"),
     };
 
-    match output.ty.clone() {
-        ArgType::Tensor(tensor) => {
-            if tensor.dim == 0 {
-                // treat 0-dim tensor as scalar
-                output.ty = ArgType::Scalar(elem_type);
-            } else {
-                todo!("Cast: update tensor type");
-                // TODO track the type of the tensor elements (@antimora 8/1/2023)
-                // output.ty = ArgType::Tensor(TensorArg {
-                //     dim: tensor.dim,
-                //     elem_type,
-                // });
-            }
-        }
-        ArgType::Scalar(_scalar) => {
-            output.ty = ArgType::Scalar(elem_type);
-        }
-        _ => panic!("Only tensor input is valid"),
-    }
+    node.outputs[0].ty = match &node.inputs[0].ty {
+        // treat 0-dim tensor as scalar
+        ArgType::Tensor(tensor) if tensor.dim == 0 => ArgType::Scalar(elem_type),
+        ArgType::Tensor(tensor) => ArgType::Tensor(TensorArg::new(tensor.dim, elem_type)),
+        ArgType::Scalar(_) => ArgType::Scalar(elem_type),
+        ArgType::Shape(_) => panic!("Cast: shape input is not supported"),
+    };
 }
 
 fn concat_update_outputs(node: &mut Node) {
@@ -192,53 +352,96 @@ fn concat_update_outputs(node: &mut Node) {
         })
         .unwrap();
 
-    node.outputs[0].ty = ArgType::Tensor(TensorArg { dim: tensor.dim });
+    node.outputs[0].ty = ArgType::Tensor(TensorArg::new(tensor.dim, tensor.elem_type.clone()));
 }
 
-fn reshape_update_outputs(node: &mut Node) {
-    // Extract the shape information from the state
-    let shape = match node.states.first() {
-        Some(state) => match &state.ty {
-            StateType::Tensor(tensor) => match tensor.data.as_ref() {
-                Some(TensorData::Int64(data)) => data.clone(),
-                _ => panic!("Reshape: invalid state data for shape"),
-            },
-        },
-        None => panic!("Reshape: missing state required for shape"),
+/// The inputs are broadcast to the highest rank, and the elements are the ones of X and Y.
+fn where_update_outputs(node: &mut Node) {
+    let dim = node
+        .inputs
+        .iter()
+        .map(|input| match &input.ty {
+            ArgType::Tensor(tensor) => tensor.dim,
+            _ => panic!("Where: only tensor inputs are valid"),
+        })
+        .max()
+        .unwrap();
+    let elem_type = match &node.inputs[1].ty {
+        ArgType::Tensor(tensor) => tensor.elem_type.clone(),
+        _ => panic!("Where: only tensor inputs are valid"),
     };
 
+    node.outputs[0].ty = ArgType::Tensor(TensorArg::new(dim, elem_type));
+}
+
+fn reshape_update_outputs(node: &mut Node) {
     // The output dimension is the same as the shape length
-    let dim = shape.len();
+    let dim = reshape_config(node).len();
+    let elem_type = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.elem_type.clone(),
+        _ => panic!("Reshape: only tensor input is valid"),
+    };
 
-    node.outputs[0].ty = ArgType::Tensor(TensorArg { dim });
+    node.outputs[0].ty = ArgType::Tensor(TensorArg::new(dim, elem_type));
 }
 
-fn mean_update_outputs(node: &mut Node) {
-    if node.inputs.len() != 1 {
-        panic!("Mean: multiple inputs are not supported");
-    }
+/// The indices are integers, and the reduced axis is kept unless the input is a vector.
+fn arg_reduce_update_outputs(node: &mut Node) {
+    let tensor = match node.inputs[0].clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("{}: only tensor input is valid", node.node_type),
+    };
 
-    // Extract the configuration of the linear layer (inputs are known)
-    let node_input = &mut node.inputs[0];
-    let tensor = match node_input.clone().ty {
+    let dim = match arg_reduce_config(node) {
+        (_, true, _) => tensor.dim,
+        // Tensors of rank 0 are not supported, the result is a tensor of one element
+        (_, false, _) => (tensor.dim - 1).max(1),
+    };
+
+    node.outputs[0].ty = ArgType::Tensor(TensorArg::new(dim, ElementType::Int64));
+}
+
+fn einsum_update_outputs(node: &mut Node) {
+    let tensor = match node.inputs[0].clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("Einsum: only tensor inputs are valid"),
+    };
+
+    // Tensors of rank 0 are not supported, a scalar is a tensor of one element
+    let (_, output_labels) = einsum_config(node);
+    let dim = output_labels.len().max(1);
+
+    node.outputs[0].ty = ArgType::Tensor(TensorArg::new(dim, tensor.elem_type));
+}
+
+fn one_hot_update_outputs(node: &mut Node) {
+    let tensor = match node.inputs[0].clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("OneHot: only tensor input is valid"),
+    };
+
+    // The output is of the type of the values, which are checked first
+    one_hot_config(node);
+    let StateType::Tensor(values) = &node.states[1].ty;
+    let elem_type = values.elem_type.clone();
+
+    node.outputs[0].ty = ArgType::Tensor(TensorArg::new(tensor.dim + 1, elem_type));
+}
+
+fn reduce_update_outputs(node: &mut Node) {
+    let tensor = match node.inputs[0].clone().ty {
         ArgType::Tensor(tensor) => tensor,
         _ => panic!("Only tensor input is valid"),
     };
 
-    let dim_only = match node.attrs.get("axes") {
-        Some(value) => match &value {
-            AttributeValue::Int64(_) => true,
-            AttributeValue::Int64s(ints) => ints.len() == 1,
-            _ => false,
-        },
-        None => false,
+    let dim = match reduce_config(node) {
+        (_, true) => tensor.dim,
+        (Some(axes), false) => (tensor.dim - axes.len()).max(1),
+        // Tensors of rank 0 are not supported, the result is a tensor of one element
+        (None, false) => 1,
     };
 
-    if dim_only {
-        node.outputs[0].ty = ArgType::Tensor(TensorArg { dim: tensor.dim });
-    } else {
-        node.outputs[0].ty = ArgType::Tensor(TensorArg { dim: 1 });
-    }
+    node.outputs[0].ty = ArgType::Tensor(TensorArg::new(dim, tensor.elem_type));
 }
 
 fn unsqueeze_update_outputs(node: &mut Node) {
@@ -247,13 +450,13 @@ fn unsqueeze_update_outputs(node: &mut Node) {
     }
 
     let node_input = &mut node.inputs[0];
-    let dim = match node_input.clone().ty {
-        ArgType::Tensor(tensor) => tensor.dim,
-        ArgType::Shape(dim) => dim,
+    let (dim, elem_type) = match node_input.clone().ty {
+        ArgType::Tensor(tensor) => (tensor.dim, tensor.elem_type),
+        ArgType::Shape(dim) => (dim, ElementType::Int64),
         ArgType::Scalar(_) => panic!("Needs shape or tensor"),
     };
 
-    node.outputs[0].ty = ArgType::Tensor(TensorArg { dim: dim + 1 });
+    node.outputs[0].ty = ArgType::Tensor(TensorArg::new(dim + 1, elem_type));
 }
 
 fn slice_update_outputs(node: &mut Node) {
@@ -270,28 +473,172 @@ fn slice_update_outputs(node: &mut Node) {
         })
         .unwrap();
 
-    node.outputs[0].ty = ArgType::Tensor(TensorArg { dim: tensor.dim });
+    node.outputs[0].ty = ArgType::Tensor(TensorArg::new(tensor.dim, tensor.elem_type.clone()));
 }
 
 fn same_as_input(node: &mut Node) {
     node.outputs[0].ty = node.inputs[0].ty.clone();
 }
 
-fn shape_update_outputs(node: &mut Node) {
-    if node.inputs.len() != 1 {
-        panic!("Gather: multiple inputs are not supported: {:?}", node);
+/// The shapes of the outputs of a custom operator are unknown, so they have the type of the first
+/// input.
+fn custom_update_outputs(node: &mut Node) {
+    let Some(input) = node.inputs.first() else {
+        panic!("{}: a custom operator must have an input", node.node_type);
+    };
+    let ty = input.ty.clone();
+
+    node.outputs
+        .iter_mut()
+        .for_each(|output| output.ty = ty.clone());
+}
+
+/// The graph stored in an attribute of a control flow node.
+fn subgraph_mut<'a>(node: &'a mut Node, name: &str) -> &'a mut ONNXGraph {
+    let node_type = node.node_type.clone();
+
+    match node.attrs.get_mut(name) {
+        Some(AttributeValue::Graph(graph)) => graph,
+        _ => panic!("{node_type}: the {name} graph is missing"),
     }
+}
 
-    // Extract the configuration of the linear layer (inputs are known)
-    let node_input = &mut node.inputs[0];
-    if let ArgType::Tensor(tensor) = node_input.clone().ty {
-        // Update the output tensor
-        node.outputs[0].ty = ArgType::Shape(tensor.dim);
-    } else {
-        panic!("Only tensor input is valid");
+/// Infer the types of a subgraph, which can read the values of the enclosing graph passed as the
+/// inputs of its control flow node.
+fn subgraph_update_outputs(graph: &mut ONNXGraph, captures: &[Argument]) {
+    let inputs = graph.inputs.iter().chain(captures).cloned().collect();
+
+    dim_inference(&mut graph.nodes, &inputs, &mut graph.outputs);
+}
+
+/// The type of the scan outputs, stacking the values of each iteration along a new axis.
+fn stacked_type(ty: &ArgType, node_type: &NodeType) -> ArgType {
+    match ty {
+        ArgType::Tensor(tensor) => {
+            ArgType::Tensor(TensorArg::new(tensor.dim + 1, tensor.elem_type.clone()))
+        }
+        _ => panic!("{node_type}: only tensor scan outputs are supported"),
+    }
+}
+
+/// The outputs of both branches must have the same types.
+fn if_update_outputs(node: &mut Node) {
+    let captures = node.inputs.clone();
+    let mut branch_outputs = |name| {
+        let branch = subgraph_mut(node, name);
+        subgraph_update_outputs(branch, &captures);
+        branch.outputs.clone()
+    };
+    let then_outputs = branch_outputs("then_branch");
+    let else_outputs = branch_outputs("else_branch");
+
+    for ((output, then_output), else_output) in
+        node.outputs.iter_mut().zip(then_outputs).zip(else_outputs)
+    {
+        let matching = match (&then_output.ty, &else_output.ty) {
+            (ArgType::Tensor(then_tensor), ArgType::Tensor(else_tensor)) => {
+                then_tensor.dim == else_tensor.dim && then_tensor.elem_type == else_tensor.elem_type
+            }
+            (ArgType::Scalar(then_elem), ArgType::Scalar(else_elem)) => then_elem == else_elem,
+            (ArgType::Shape(then_dim), ArgType::Shape(else_dim)) => then_dim == else_dim,
+            _ => false,
+        };
+        if !matching {
+            panic!("If: the branches must return the same types");
+        }
+
+        output.ty = then_output.ty;
+    }
+}
+
+/// The body reads the iteration number, the condition and the carried values, and returns the
+/// condition, the carried values and the scan outputs.
+fn loop_update_outputs(node: &mut Node) {
+    let captures = node.inputs.clone();
+    let body = subgraph_mut(node, "body");
+    let num_carried = body.inputs.len() - 2;
+
+    body.inputs[0].ty = ArgType::Scalar(ElementType::Int64);
+    body.inputs[1].ty = ArgType::Scalar(ElementType::Bool);
+    for (input, initial_value) in body.inputs[2..].iter_mut().zip(captures[2..].iter()) {
+        input.ty = initial_value.ty.clone();
+    }
+    subgraph_update_outputs(body, &captures);
+    let body_outputs = body.outputs[1..].to_vec();
+
+    for (index, (output, body_output)) in node.outputs.iter_mut().zip(body_outputs).enumerate() {
+        output.ty = match index < num_carried {
+            true => body_output.ty,
+            false => stacked_type(&body_output.ty, &NodeType::Loop),
+        };
+    }
+}
+
+/// The body reads the states and a slice of each scan input, and returns the states and the scan
+/// outputs.
+fn scan_update_outputs(node: &mut Node) {
+    let num_scan_inputs = match node.attrs.get("num_scan_inputs") {
+        Some(AttributeValue::Int64(num_scan_inputs)) => *num_scan_inputs as usize,
+        _ => panic!("Scan: the num_scan_inputs attribute is missing"),
+    };
+    let captures = node.inputs.clone();
+    let body = subgraph_mut(node, "body");
+    let num_states = body.inputs.len() - num_scan_inputs;
+
+    for (index, (input, value)) in body.inputs.iter_mut().zip(captures.iter()).enumerate() {
+        input.ty = match (index < num_states, &value.ty) {
+            (true, ty) => ty.clone(),
+            (false, ArgType::Tensor(tensor)) => {
+                ArgType::Tensor(TensorArg::new(tensor.dim - 1, tensor.elem_type.clone()))
+            }
+            (false, _) => panic!("Scan: only tensor scan inputs are supported"),
+        };
+    }
+    subgraph_update_outputs(body, &captures);
+    let body_outputs = body.outputs.clone();
+
+    for (index, (output, body_output)) in node.outputs.iter_mut().zip(body_outputs).enumerate() {
+        output.ty = match index < num_states {
+            true => body_output.ty,
+            false => stacked_type(&body_output.ty, &NodeType::Scan),
+        };
     }
 }
 
+/// The selected indices are `[batch, class, box]` rows.
+fn non_max_suppression_update_outputs(node: &mut Node) {
+    node.outputs[0].ty = ArgType::Tensor(TensorArg::new(2, ElementType::Int64));
+}
+
+/// The output of a comparison has the dimension of the input and boolean elements.
+fn comparison_update_outputs(node: &mut Node) {
+    node.outputs[0].ty = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => ArgType::Tensor(TensorArg::new(tensor.dim, ElementType::Bool)),
+        ArgType::Scalar(_) => ArgType::Scalar(ElementType::Bool),
+        ArgType::Shape(_) => panic!("Comparison: shape input is not supported"),
+    };
+}
+
+/// The output of a Shape node has the number of dimensions in its range.
+fn shape_update_outputs(node: &mut Node) {
+    let (start, end) = shape_config(node);
+
+    node.outputs[0].ty = ArgType::Shape(end - start);
+}
+
+/// The rank of the output of a ConstantOfShape node is the length of its shape.
+fn constant_of_shape_update_outputs(node: &mut Node) {
+    let (shape, value) = constant_of_shape_config(node);
+    let dim = shape.rank();
+    let elem_type = match value {
+        FillValue::Float(_) => ElementType::Float32,
+        FillValue::Int(_) => ElementType::Int64,
+        FillValue::Bool(_) => ElementType::Bool,
+    };
+
+    node.outputs[0].ty = ArgType::Tensor(TensorArg::new(dim, elem_type));
+}
+
 /// Infers the shape of a Flatten node and replaces the shape of the output tensor.
 fn flatten_update_outputs(node: &mut Node) {
     if node.inputs.len() != 1 {
@@ -313,7 +660,7 @@ fn flatten_update_outputs(node: &mut Node) {
     let collapsed_dims = end_dim - start_dim;
     let output_dim = input_dim - collapsed_dims;
 
-    node.outputs[0].ty = ArgType::Tensor(TensorArg { dim: output_dim });
+    node.outputs[0].ty = ArgType::Tensor(TensorArg::new(output_dim, tensor.elem_type.clone()));
 }
 
 /// Infers the shape of a Conv1d node and replaces the shape of the output tensor.
@@ -325,7 +672,7 @@ fn conv1d_update_outputs(node: &mut Node) {
 
     // extract the channels from the weight tensor's shape [out_channels, in_channels, ...]
     if let ArgType::Tensor(tensor) = node.inputs[0].clone().ty {
-        node.outputs[0].ty = ArgType::Tensor(TensorArg { dim: tensor.dim });
+        node.outputs[0].ty = ArgType::Tensor(TensorArg::new(tensor.dim, tensor.elem_type.clone()));
     } else {
         panic!("Only tensor input is valid");
     }
@@ -340,7 +687,7 @@ fn conv2d_update_outputs(node: &mut Node) {
 
     // extract the channels from the weight tensor's shape [out_channels, in_channels, ...]
     if let ArgType::Tensor(tensor) = node.inputs[0].clone().ty {
-        node.outputs[0].ty = ArgType::Tensor(TensorArg { dim: tensor.dim });
+        node.outputs[0].ty = ArgType::Tensor(TensorArg::new(tensor.dim, tensor.elem_type.clone()));
     } else {
         panic!("Only tensor input is valid");
     }
@@ -1,6 +1,7 @@
 use std::{
     collections::{HashMap, HashSet},
     fs::File,
+    io::{Read, Seek, SeekFrom},
     path::Path,
     str::{from_utf8, FromStr},
 };
@@ -11,22 +12,48 @@ use super::ir::{
     Tensor, TensorArg, TensorData,
 };
 use super::protos::{
-    attribute_proto::AttributeType, tensor_proto::DataType, tensor_shape_proto::dimension::Value,
-    type_proto, AttributeProto, ModelProto, NodeProto, TensorProto, TensorShapeProto,
+    attribute_proto::AttributeType,
+    tensor_proto::{DataLocation, DataType},
+    tensor_shape_proto::dimension::Value,
+    type_proto, AttributeProto, GraphProto, ModelProto, NodeProto, TensorProto, TensorShapeProto,
     ValueInfoProto,
 };
-use super::{coalesce::coalesce, ir::StateType};
+use super::{
+    coalesce::coalesce, fusion::fuse_nodes, ir::StateType, quantization::dequantize_nodes,
+};
 
 use bytemuck::cast_slice;
 use protobuf::{Enum, Message};
 
-const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 4] = [
+const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 20] = [
+    NodeType::Clip,
+    NodeType::ConstantOfShape,
     NodeType::Conv1d,
     NodeType::Conv2d,
+    NodeType::ConvTranspose1d,
+    NodeType::ConvTranspose2d,
     NodeType::Dropout,
+    NodeType::Expand,
+    NodeType::Gather,
+    NodeType::LayerNormalization,
+    NodeType::OneHot,
+    NodeType::Pad,
+    NodeType::PRelu,
+    NodeType::ReduceMax,
+    NodeType::ReduceMean,
+    NodeType::ReduceSum,
     NodeType::Reshape,
+    NodeType::Slice,
+    NodeType::Split,
+    NodeType::Tile,
 ];
 
+/// The node types running the nodes of graphs stored in their attributes.
+const CONTROL_FLOW_NODE_TYPES: [NodeType; 3] = [NodeType::If, NodeType::Loop, NodeType::Scan];
+
+/// The attributes of the control flow nodes storing a graph, in the order of the ONNX spec.
+const SUBGRAPH_ATTRIBUTES: [&str; 3] = ["then_branch", "else_branch", "body"];
+
 /// Error type for parsing ONNX model
 #[derive(Debug)]
 pub enum ParseError {
@@ -47,15 +74,19 @@ pub enum ParseError {
 ///
 /// * If the file cannot be opened
 /// * If the file cannot be parsed
+/// * If an external data file cannot be read
 /// * If the nodes are not topologically sorted
 pub fn parse_onnx(onnx_path: &Path) -> ONNXGraph {
     log::info!("Parsing ONNX file: {}", onnx_path.display());
 
     // Open the file
     let mut file = File::open(onnx_path).expect("Unable to open file");
-    let onnx_model: ModelProto =
+    let mut onnx_model: ModelProto =
         Message::parse_from_reader(&mut file).expect("Unable to parse ONNX file");
 
+    // The external data files are stored relative to the onnx file
+    let base_dir = onnx_path.parent().unwrap_or(Path::new(""));
+
     log::debug!("Number of nodes: {:?}", onnx_model.graph.node.len());
     log::debug!("Number of inputs: {:?}", onnx_model.graph.input.len());
 
@@ -66,21 +97,41 @@ pub fn parse_onnx(onnx_path: &Path) -> ONNXGraph {
 
     log::debug!("Number of outputs: {:?}", onnx_model.graph.output.len());
 
-    // Convert the nodes
-    let mut nodes: Vec<Node> = vec![];
-    for onnx_node in onnx_model.graph.node.iter() {
-        nodes.push(convert_node_proto(onnx_node));
-    }
+    // Read the tensors of the attributes stored in external data files
+    load_attributes_external_data(onnx_model.graph.mut_or_insert_default(), base_dir);
+
+    // Convert the nodes, parsing the graphs of the control flow nodes
+    let mut nodes = convert_nodes(&onnx_model.graph, base_dir);
+    lift_captured_initializers(&mut nodes, &onnx_model.graph.initializer, base_dir);
 
     // ONNX nodes must be topologically sorted per spec:
     // https://github.com/onnx/onnx/blob/main/docs/IR.md#graphs
     assert!(nodes.is_top_sorted(), "Nodes are not topologically sorted");
 
+    // Dequantize the quantized operators to run them in floating point
+    dequantize_nodes(&mut nodes, &onnx_model.graph.initializer, base_dir);
+
+    // Fuse the decomposed operators
+    fuse_nodes(
+        &mut nodes,
+        &onnx_model.graph.initializer,
+        &onnx_model.graph.output,
+    );
+
+    // Fold the shape computations on constants
+    fold_constants(&mut nodes, &onnx_model.graph.output);
+
+    // Fold the computations on the dynamic sizes into the nodes reading them at runtime
+    fold_shape_dims(&mut nodes, &onnx_model.graph.output);
+
     // Lift constants to initializers
     lift_constants(&mut nodes);
 
+    // Attach the shapes declared in the model to the arguments of the nodes
+    apply_static_shapes(&mut nodes, &onnx_model.graph);
+
     // Move inputs with initializers to states
-    move_inputs_to_state(&mut nodes, &onnx_model.graph.initializer);
+    move_inputs_to_state(&mut nodes, &onnx_model.graph.initializer, base_dir);
 
     // Coalesce and transform nodes
     coalesce(&mut nodes);
@@ -176,6 +227,20 @@ impl TryFrom<TensorProto> for Tensor {
                     TensorData::Float64(tensor.double_data)
                 },
             ),
+            // The 8-bit integers of the quantized tensors are widened
+            DataType::INT8 | DataType::UINT8 => (
+                ElementType::Int32,
+                if !tensor.raw_data.is_empty() {
+                    TensorData::Int32(match DataType::from_i32(tensor.data_type) {
+                        Some(DataType::INT8) => {
+                            tensor.raw_data.iter().map(|x| *x as i8 as i32).collect()
+                        }
+                        _ => tensor.raw_data.iter().map(|x| *x as i32).collect(),
+                    })
+                } else {
+                    TensorData::Int32(tensor.int32_data)
+                },
+            ),
             DataType::BOOL => (ElementType::Bool, {
                 assert!(!tensor.raw_data.is_empty());
                 TensorData::Bool(tensor.raw_data.iter().map(|x| *x != 0).collect())
@@ -260,7 +325,7 @@ impl TryFrom<AttributeProto> for AttributeValue {
             // warning: tensor can be empty TODO: check if it is empty
             AttributeType::TENSOR => AttributeValue::Tensor(Tensor::try_from(attr.t.unwrap())?),
 
-            // Graph is not supported for now
+            // Graphs are parsed with the nodes of the graph, see `convert_nodes`
             // AttributeType::GRAPH => AttributeValue::Graph(attr.g),
             AttributeType::FLOATS => AttributeValue::Float32s(attr.floats),
             AttributeType::INTS => AttributeValue::Int64s(attr.ints),
@@ -313,9 +378,20 @@ pub fn convert_node_proto(node: &NodeProto) -> Node {
             ty: ArgType::Tensor(TensorArg::default()),
         })
         .collect();
-    let attrs = convert_vec_attrs_proto(node.attribute.clone());
+    // The graphs of the control flow nodes are parsed separately
+    let attrs = convert_vec_attrs_proto(
+        node.attribute
+            .iter()
+            .filter(|attr| attr.type_.enum_value_or_default() != AttributeType::GRAPH)
+            .cloned()
+            .collect(),
+    );
 
-    let node_type = NodeType::from_str(node.op_type.as_str()).expect("Unknown node type");
+    // The unknown operators and the operators of the custom domains are custom operators
+    let node_type = match node.domain.as_str() {
+        "" | "ai.onnx" => NodeType::from_str(node.op_type.as_str()).expect("Unknown node type"),
+        _ => NodeType::Custom(node.op_type.clone()),
+    };
 
     let mut node = Node {
         node_type,
@@ -331,6 +407,168 @@ pub fn convert_node_proto(node: &NodeProto) -> Node {
     node
 }
 
+/// Convert the nodes of a graph, parsing the graphs stored in the attributes of the control flow
+/// nodes.
+///
+/// A subgraph can read the values of the enclosing graphs, which are added to the inputs of its
+/// control flow node. The constants of a subgraph are moved before its control flow node, so that
+/// they are fields of the model.
+fn convert_nodes(graph: &GraphProto, base_dir: &Path) -> Vec<Node> {
+    let mut nodes = Vec::new();
+
+    for onnx_node in graph.node.iter() {
+        let mut node = convert_node_proto(onnx_node);
+
+        for attr in onnx_node.attribute.iter() {
+            if attr.type_.enum_value_or_default() != AttributeType::GRAPH {
+                continue;
+            }
+
+            let subgraph = parse_subgraph(&attr.g, base_dir, &mut nodes);
+            for capture in subgraph_captures(&subgraph) {
+                if !node.inputs.iter().any(|input| input.name == capture) {
+                    node.inputs.push(Argument {
+                        name: capture,
+                        ty: ArgType::Tensor(TensorArg::default()),
+                    });
+                }
+            }
+            node.attrs
+                .insert(attr.name.clone(), AttributeValue::Graph(subgraph));
+        }
+
+        nodes.push(node);
+    }
+
+    nodes
+}
+
+/// Parse a graph stored in an attribute of a control flow node, moving its constants into the
+/// nodes of the enclosing graph.
+fn parse_subgraph(graph: &GraphProto, base_dir: &Path, outer_nodes: &mut Vec<Node>) -> ONNXGraph {
+    let mut nodes = convert_nodes(graph, base_dir);
+    lift_captured_initializers(&mut nodes, &graph.initializer, base_dir);
+
+    dequantize_nodes(&mut nodes, &graph.initializer, base_dir);
+    fuse_nodes(&mut nodes, &graph.initializer, &graph.output);
+    fold_constants(&mut nodes, &graph.output);
+    fold_shape_dims(&mut nodes, &graph.output);
+    lift_constants(&mut nodes);
+    move_inputs_to_state(&mut nodes, &graph.initializer, base_dir);
+    coalesce(&mut nodes);
+
+    let (constants, nodes) = nodes
+        .into_iter()
+        .partition::<Vec<_>, _>(|node| node.node_type == NodeType::Constant);
+    outer_nodes.extend(constants);
+
+    // The types are inferred with the types of the enclosing graph
+    let arguments = |values: &[ValueInfoProto]| {
+        values
+            .iter()
+            .map(|value| Argument {
+                name: value.name.clone(),
+                ty: ArgType::Tensor(TensorArg::default()),
+            })
+            .collect()
+    };
+
+    ONNXGraph {
+        nodes,
+        inputs: arguments(&graph.input),
+        outputs: arguments(&graph.output),
+        old_node_names: HashMap::new(),
+        old_input_names: HashMap::new(),
+    }
+}
+
+/// The names of the values of the enclosing graphs read by a subgraph.
+fn subgraph_captures(graph: &ONNXGraph) -> Vec<String> {
+    let mut defined = graph
+        .inputs
+        .iter()
+        .map(|input| input.name.clone())
+        .collect::<HashSet<_>>();
+    let mut captures = Vec::new();
+
+    for node in graph.nodes.iter() {
+        for input in node.inputs.iter() {
+            if !input.name.is_empty() && defined.insert(input.name.clone()) {
+                captures.push(input.name.clone());
+            }
+        }
+        defined.extend(node.outputs.iter().map(|output| output.name.clone()));
+    }
+    for output in graph.outputs.iter() {
+        if defined.insert(output.name.clone()) {
+            captures.push(output.name.clone());
+        }
+    }
+
+    captures
+}
+
+/// The graphs stored in the attributes of a control flow node, in the order of the ONNX spec.
+fn subgraphs_mut(node: &mut Node) -> Vec<&mut ONNXGraph> {
+    let mut graphs = node
+        .attrs
+        .iter_mut()
+        .filter_map(|(name, value)| match value {
+            AttributeValue::Graph(graph) => Some((name, graph)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    graphs.sort_by_key(|(name, _)| {
+        SUBGRAPH_ATTRIBUTES
+            .iter()
+            .position(|attribute| attribute == name)
+    });
+
+    graphs.into_iter().map(|(_, graph)| graph).collect()
+}
+
+/// Convert the initializers read by the subgraphs into Constant nodes, since the control flow nodes
+/// have no states.
+fn lift_captured_initializers(
+    nodes: &mut Vec<Node>,
+    initializers: &[TensorProto],
+    base_dir: &Path,
+) {
+    let captured = nodes
+        .iter()
+        .filter(|node| CONTROL_FLOW_NODE_TYPES.contains(&node.node_type))
+        .flat_map(|node| node.inputs.iter().map(|input| input.name.as_str()))
+        .collect::<HashSet<_>>();
+
+    let constants = initializers
+        .iter()
+        .filter(|init| captured.contains(init.name.as_str()))
+        .map(|init| {
+            let mut tensor = init.clone();
+            load_external_data(&mut tensor, base_dir);
+
+            Node {
+                node_type: NodeType::Constant,
+                name: init.name.clone(),
+                inputs: vec![],
+                outputs: vec![Argument {
+                    name: init.name.clone(),
+                    ty: ArgType::Tensor(TensorArg::default()),
+                }],
+                states: vec![],
+                attrs: [(
+                    "value".to_string(),
+                    AttributeValue::Tensor(tensor.try_into().unwrap()),
+                )]
+                .into_iter()
+                .collect(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    nodes.splice(0..0, constants);
+}
+
 /// Remap node type using kernel shape
 fn remap_node_with_kernel_shape<F>(node: &mut Node, new_node_type: F)
 where
@@ -351,6 +589,11 @@ fn remap_node_type(node: &mut Node) {
             2 => NodeType::Conv2d,
             _ => panic!("Only conv 1d and 2d are supported"),
         }),
+        NodeType::ConvTranspose => remap_node_with_kernel_shape(node, |ints| match ints.len() {
+            1 => NodeType::ConvTranspose1d,
+            2 => NodeType::ConvTranspose2d,
+            _ => panic!("Only conv_transpose 1d and 2d are supported"),
+        }),
         NodeType::MaxPool => remap_node_with_kernel_shape(node, |ints| match ints.len() {
             1 => NodeType::MaxPool1d,
             2 => NodeType::MaxPool2d,
@@ -377,7 +620,27 @@ impl TryFrom<ValueInfoProto> for Argument {
         }
 
         let tensor_proto = proto_type.tensor_type();
-        let tensor: TensorArg = TensorArg::new(tensor_proto.shape.dim.len());
+        let elem_type = match DataType::from_i32(tensor_proto.elem_type).unwrap() {
+            DataType::FLOAT => ElementType::Float32,
+            DataType::INT32 => ElementType::Int32,
+            DataType::INT64 => ElementType::Int64,
+            DataType::DOUBLE => ElementType::Float64,
+            DataType::FLOAT16 => ElementType::Float16,
+            DataType::BOOL => ElementType::Bool,
+            _ => return Err(ParseError::VariantNotFound),
+        };
+        let mut tensor: TensorArg = TensorArg::new(tensor_proto.shape.dim.len(), elem_type);
+        tensor.static_shape = Some(
+            tensor_proto
+                .shape
+                .dim
+                .iter()
+                .map(|dim| match dim.has_dim_value() && dim.dim_value() > 0 {
+                    true => Some(dim.dim_value() as usize),
+                    false => None,
+                })
+                .collect(),
+        );
         let ty = ArgType::Tensor(tensor);
 
         Ok(Argument { ty, name })
@@ -403,11 +666,64 @@ impl TryFrom<ValueInfoProto> for State {
     }
 }
 
+/// Attach the sizes declared by the inputs, outputs and value infos of the graph to the
+/// arguments of the nodes, which are kept by the dimension inference.
+fn apply_static_shapes(nodes: &mut [Node], graph: &GraphProto) {
+    let shapes = graph
+        .input
+        .iter()
+        .chain(graph.value_info.iter())
+        .chain(graph.output.iter())
+        .filter(|value| value.type_.as_ref().is_some_and(|ty| ty.has_tensor_type()))
+        .filter_map(|value| Argument::try_from(value.clone()).ok())
+        .filter_map(|argument| match argument.ty {
+            ArgType::Tensor(tensor) => Some((argument.name, tensor.static_shape)),
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+
+    for node in nodes.iter_mut() {
+        for argument in node.inputs.iter_mut().chain(node.outputs.iter_mut()) {
+            if let (ArgType::Tensor(tensor), Some(shape)) =
+                (&mut argument.ty, shapes.get(&argument.name))
+            {
+                tensor.static_shape = shape.clone();
+            }
+        }
+    }
+}
+
 // This function moves inputs that are also present in the initializer to the node's states vector.
 // It also removes inputs that are already present in the states vector.
-fn move_inputs_to_state(nodes: &mut Vec<Node>, initializer: &[TensorProto]) {
+// Each initializer is read and converted once, the initializers stored in external data files
+// being read when first used, and only kept until their last use.
+fn move_inputs_to_state(nodes: &mut Vec<Node>, initializer: &[TensorProto], base_dir: &Path) {
+    let initializer = initializer
+        .iter()
+        .map(|init| (init.name.as_str(), init))
+        .collect::<HashMap<_, _>>();
+
+    // The remaining uses of each initializer, and the converted ones still used.
+    let mut uses = HashMap::<String, usize>::new();
+    for node in nodes.iter() {
+        if CONTROL_FLOW_NODE_TYPES.contains(&node.node_type) {
+            continue;
+        }
+        for input in node.inputs.iter() {
+            if initializer.contains_key(input.name.as_str()) {
+                *uses.entry(input.name.clone()).or_default() += 1;
+            }
+        }
+    }
+    let mut tensors = HashMap::<String, Tensor>::new();
+
     // Iterate over each node in the graph
     nodes.iter_mut().for_each(|node| {
+        // The initializers read by the subgraphs are Constant nodes
+        if CONTROL_FLOW_NODE_TYPES.contains(&node.node_type) {
+            return;
+        }
+
         // Create a new vector to hold the node's states
         let mut node_states = Vec::new();
         // Create a new vector to hold the node's inputs
@@ -415,17 +731,36 @@ fn move_inputs_to_state(nodes: &mut Vec<Node>, initializer: &[TensorProto]) {
 
         // Iterate over each input in the node's inputs vector
         for input in node.inputs.iter() {
-            // Iterate over each tensor in the initializer
-            for init in initializer.iter() {
-                // If the input name matches the tensor name in the initializer
-                if init.name == input.name {
-                    // Add the tensor to the node's states vector
-                    node_states.push(State {
-                        name: init.name.clone(),
-                        ty: StateType::Tensor(init.clone().try_into().unwrap()),
-                    });
+            // If the input name matches the tensor name in the initializer
+            let Some(init) = initializer.get(input.name.as_str()) else {
+                continue;
+            };
+            let tensor = match tensors.remove(&input.name) {
+                Some(tensor) => tensor,
+                None => {
+                    let mut init = (*init).clone();
+                    load_external_data(&mut init, base_dir);
+                    init.try_into().unwrap()
                 }
+            };
+            let remaining = uses.get_mut(&input.name).unwrap();
+            *remaining -= 1;
+            if *remaining > 0 {
+                tensors.insert(input.name.clone(), tensor.clone());
             }
+
+            // Add the tensor to the node's states vector
+            node_states.push(State {
+                name: input.name.clone(),
+                ty: StateType::Tensor(tensor),
+            });
+        }
+
+        // The Gemm inputs are kept, their positions telling the coalesce step whether the
+        // initializers are the weights and the bias of a linear layer
+        if node.node_type == NodeType::Gemm {
+            node.states.append(&mut node_states);
+            return;
         }
 
         // Swap the node's inputs vector with the temporary inputs vector
@@ -450,6 +785,575 @@ fn move_inputs_to_state(nodes: &mut Vec<Node>, initializer: &[TensorProto]) {
     });
 }
 
+/// Read the tensors of the attributes of the nodes stored in external data files, including the
+/// nodes of the subgraphs.
+fn load_attributes_external_data(graph: &mut GraphProto, base_dir: &Path) {
+    for node in graph.node.iter_mut() {
+        for attr in node.attribute.iter_mut() {
+            attr.t
+                .as_mut()
+                .into_iter()
+                .chain(attr.tensors.iter_mut())
+                .for_each(|tensor| load_external_data(tensor, base_dir));
+            attr.g
+                .as_mut()
+                .into_iter()
+                .chain(attr.graphs.iter_mut())
+                .for_each(|graph| load_attributes_external_data(graph, base_dir));
+        }
+    }
+}
+
+/// Read the data of a tensor stored in an external file into its raw data.
+///
+/// The location of the file is relative to the directory of the onnx file, and the data starts at
+/// the optional offset and spans the optional length, or the rest of the file.
+/// (see https://onnx.ai/onnx/repo-docs/ExternalData.html)
+pub(super) fn load_external_data(tensor: &mut TensorProto, base_dir: &Path) {
+    if tensor.data_location.enum_value_or_default() != DataLocation::EXTERNAL {
+        return;
+    }
+
+    let mut location = None;
+    let mut offset = 0;
+    let mut length = None;
+
+    for entry in tensor.external_data.iter() {
+        let parse = |value: &str| {
+            value
+                .parse::<u64>()
+                .unwrap_or_else(|_| panic!("Invalid external data {}: {value}", entry.key))
+        };
+
+        match entry.key.as_str() {
+            "location" => location = Some(base_dir.join(&entry.value)),
+            "offset" => offset = parse(&entry.value),
+            "length" => length = Some(parse(&entry.value)),
+            _ => {}
+        }
+    }
+
+    let location = location.unwrap_or_else(|| {
+        panic!(
+            "Missing external data location for tensor '{}'",
+            tensor.name
+        )
+    });
+    log::debug!(
+        "Reading external data of tensor '{}' from {}",
+        tensor.name,
+        location.display()
+    );
+
+    let mut file = File::open(&location)
+        .unwrap_or_else(|_| panic!("Unable to open external data file {}", location.display()));
+    file.seek(SeekFrom::Start(offset))
+        .expect("Unable to seek in external data file");
+
+    let mut data = Vec::new();
+    match length {
+        Some(length) => {
+            data.resize(length as usize, 0);
+            file.read_exact(&mut data)
+        }
+        None => file.read_to_end(&mut data).map(|_| ()),
+    }
+    .expect("Unable to read external data file");
+
+    tensor.raw_data = data;
+    tensor.external_data.clear();
+    tensor.data_location = DataLocation::DEFAULT.into();
+}
+
+/// Fold the shape computations on constants into Constant nodes.
+///
+/// Exporters build the target shapes of nodes such as Expand or Reshape with Concat, Unsqueeze,
+/// Gather and integer arithmetic nodes, whose inputs are constant when the shapes are static.
+/// Once folded, the shape is a single constant that can be lifted into the states of the node
+/// using it. The constants that are no longer used are removed.
+fn fold_constants(nodes: &mut Vec<Node>, graph_outputs: &[ValueInfoProto]) {
+    let mut constants = nodes
+        .iter()
+        .filter(|node| node.node_type == NodeType::Constant)
+        .filter_map(|node| match node.attrs.get("value") {
+            Some(AttributeValue::Tensor(tensor)) => Some((node.outputs[0].name.clone(), tensor)),
+            _ => None,
+        })
+        .map(|(name, tensor)| (name, tensor.clone()))
+        .collect::<HashMap<String, Tensor>>();
+
+    for node in nodes.iter_mut() {
+        if node.node_type == NodeType::Constant || node.inputs.is_empty() {
+            continue;
+        }
+
+        let Some(tensors) = node
+            .inputs
+            .iter()
+            .map(|input| constants.get(&input.name))
+            .collect::<Option<Vec<_>>>()
+        else {
+            continue;
+        };
+
+        let tensor = match node.node_type {
+            NodeType::Concat if tensors.iter().all(|tensor| tensor.dim == 1) => {
+                concat_vectors(&tensors)
+            }
+            NodeType::Unsqueeze => unsqueeze_constant(&tensors, &node.attrs),
+            NodeType::Gather => gather_vector(&tensors, &node.attrs),
+            NodeType::Add | NodeType::Sub | NodeType::Mul | NodeType::Div => {
+                int_binary(&node.node_type, &tensors)
+            }
+            _ => None,
+        };
+        let Some(tensor) = tensor else {
+            continue;
+        };
+
+        log::debug!("Folding the constant {} node {}", node.node_type, node.name);
+
+        constants.insert(node.outputs[0].name.clone(), tensor.clone());
+        node.node_type = NodeType::Constant;
+        node.inputs.clear();
+        node.attrs = [("value".to_string(), AttributeValue::Tensor(tensor))]
+            .into_iter()
+            .collect();
+    }
+
+    // Remove the constants that are no longer used
+    remove_unused_nodes(nodes, graph_outputs, |node| {
+        node.node_type == NodeType::Constant
+    });
+}
+
+/// Remove the given nodes when their outputs are not used.
+///
+/// The nodes are visited in reverse order, so the nodes only used by removed nodes are removed too.
+fn remove_unused_nodes<F>(nodes: &mut Vec<Node>, graph_outputs: &[ValueInfoProto], removable: F)
+where
+    F: Fn(&Node) -> bool,
+{
+    let mut used = graph_outputs
+        .iter()
+        .map(|output| output.name.clone())
+        .collect::<HashSet<String>>();
+    let mut removed = HashSet::new();
+
+    for node in nodes.iter().rev() {
+        if removable(node)
+            && !node
+                .outputs
+                .iter()
+                .any(|output| used.contains(&output.name))
+        {
+            removed.insert(node.name.clone());
+            continue;
+        }
+
+        used.extend(node.inputs.iter().map(|input| input.name.clone()));
+    }
+
+    nodes.retain(|node| !removed.contains(&node.name));
+}
+
+/// A size of a shape computed by the graph.
+#[derive(Debug, Clone)]
+enum ShapeDim {
+    /// A size known when the model is imported.
+    Static(i64),
+    /// A size read from the output of a Shape node, the index counting from the end when negative.
+    Runtime { shape: String, index: i64 },
+}
+
+/// The sizes of a vector computed from shapes, and whether it is a scalar holding a single size.
+type ShapeDims = (Vec<ShapeDim>, bool);
+
+/// Fold the computations on the sizes of Shape nodes into the Reshape, Expand, Tile,
+/// ConstantOfShape and Slice nodes using them.
+///
+/// Exporters build the target shape of a dynamic axis, such as the batch size, by gathering its
+/// size from a Shape node, unsqueezing it and concatenating it with the constant sizes. The sizes
+/// are tracked through those nodes, so that the node reads them from the output of the Shape node
+/// during the forward pass instead of requiring a constant input. The sizes are recorded in an
+/// attribute named after the input, and the position of the input each size is read from in its
+/// `<name>_inputs` attribute, -1 meaning a constant size. The nodes that are no longer used are
+/// removed.
+///
+/// Arithmetic on the sizes, such as halving a computed size, is not tracked, so those shapes must
+/// still be constant.
+fn fold_shape_dims(nodes: &mut Vec<Node>, graph_outputs: &[ValueInfoProto]) {
+    let mut dims = HashMap::<String, ShapeDims>::new();
+    let mut shapes = HashSet::<String>::new();
+    let mut folded = HashSet::<String>::new();
+
+    for node in nodes.iter_mut() {
+        let inputs = node
+            .inputs
+            .iter()
+            .map(|input| dims.get(&input.name).cloned())
+            .collect::<Vec<_>>();
+
+        let value = match node.node_type {
+            NodeType::Constant => constant_dims(node),
+            NodeType::Shape => {
+                shapes.insert(node.outputs[0].name.clone());
+                None
+            }
+            NodeType::Gather if shapes.contains(&node.inputs[0].name) => {
+                gather_shape_dims(node, &inputs)
+            }
+            NodeType::Unsqueeze => unsqueeze_dims(node, &inputs),
+            NodeType::Concat => concat_dims(node, &inputs),
+            NodeType::Reshape | NodeType::Expand | NodeType::Tile => {
+                let name = match node.node_type {
+                    NodeType::Tile => "repeats",
+                    _ => "shape",
+                };
+                if let Some(Some((dims, false))) = inputs.get(1) {
+                    with_runtime_dims(node, 1, vec![(name, dims.clone())]);
+                }
+                None
+            }
+            NodeType::ConstantOfShape => {
+                if let Some(Some((dims, false))) = inputs.first() {
+                    with_runtime_dims(node, 0, vec![("shape", dims.clone())]);
+                }
+                None
+            }
+            NodeType::Slice => {
+                slice_with_dims(node, &inputs);
+                None
+            }
+            _ => None,
+        };
+        let Some(value) = value else {
+            continue;
+        };
+
+        if node.node_type != NodeType::Constant {
+            folded.insert(node.name.clone());
+        }
+        dims.insert(node.outputs[0].name.clone(), value);
+    }
+
+    remove_unused_nodes(nodes, graph_outputs, |node| {
+        node.node_type == NodeType::Constant || folded.contains(&node.name)
+    });
+}
+
+/// The sizes of an integer scalar or vector constant.
+fn constant_dims(node: &Node) -> Option<ShapeDims> {
+    let Some(AttributeValue::Tensor(tensor)) = node.attrs.get("value") else {
+        return None;
+    };
+    let values = match &tensor.data {
+        Some(TensorData::Int64(values)) => values.clone(),
+        Some(TensorData::Int32(values)) => values.iter().map(|value| *value as i64).collect(),
+        _ => return None,
+    };
+
+    match tensor.dim {
+        0 | 1 => Some((
+            values.into_iter().map(ShapeDim::Static).collect(),
+            tensor.dim == 0,
+        )),
+        _ => None,
+    }
+}
+
+/// The sizes gathered from the output of a Shape node at constant indices.
+fn gather_shape_dims(node: &Node, inputs: &[Option<ShapeDims>]) -> Option<ShapeDims> {
+    if !matches!(
+        node.attrs.get("axis"),
+        None | Some(AttributeValue::Int64(0))
+    ) {
+        return None;
+    }
+
+    let (indices, scalar) = inputs.get(1)?.as_ref()?;
+    let dims = indices
+        .iter()
+        .map(|index| match index {
+            ShapeDim::Static(index) => Some(ShapeDim::Runtime {
+                shape: node.inputs[0].name.clone(),
+                index: *index,
+            }),
+            ShapeDim::Runtime { .. } => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    Some((dims, *scalar))
+}
+
+/// A scalar size unsqueezed into a vector, the axes being an attribute or the second input.
+fn unsqueeze_dims(node: &Node, inputs: &[Option<ShapeDims>]) -> Option<ShapeDims> {
+    let (dims, true) = inputs.first()?.as_ref()? else {
+        return None;
+    };
+    let axes = match (node.attrs.get("axes"), inputs.get(1)) {
+        (Some(AttributeValue::Int64s(axes)), _) => axes.clone(),
+        (None, Some(Some((axes, false)))) => axes
+            .iter()
+            .map(|axis| match axis {
+                ShapeDim::Static(axis) => Some(*axis),
+                ShapeDim::Runtime { .. } => None,
+            })
+            .collect::<Option<Vec<_>>>()?,
+        _ => return None,
+    };
+
+    match axes.as_slice() {
+        [0] | [-1] => Some((dims.clone(), false)),
+        _ => None,
+    }
+}
+
+/// The sizes of vectors concatenated.
+fn concat_dims(node: &Node, inputs: &[Option<ShapeDims>]) -> Option<ShapeDims> {
+    if !matches!(
+        node.attrs.get("axis"),
+        Some(AttributeValue::Int64(0)) | Some(AttributeValue::Int64(-1))
+    ) {
+        return None;
+    }
+
+    let mut dims = Vec::new();
+    for input in inputs {
+        let (input, false) = input.as_ref()? else {
+            return None;
+        };
+        dims.extend(input.iter().cloned());
+    }
+
+    Some((dims, false))
+}
+
+/// Make a Slice read its starts and ends from the outputs of the Shape nodes, when its axes and
+/// steps are constant.
+fn slice_with_dims(node: &mut Node, inputs: &[Option<ShapeDims>]) {
+    let (Some(Some((starts, false))), Some(Some((ends, false)))) = (inputs.get(1), inputs.get(2))
+    else {
+        return;
+    };
+
+    let mut attrs = vec![("starts", starts.clone()), ("ends", ends.clone())];
+    for (position, name) in [(3, "axes"), (4, "steps")] {
+        match (node.inputs.get(position), inputs.get(position)) {
+            (None, _) => {}
+            (Some(input), _) if input.name.is_empty() => {}
+            (Some(_), Some(Some((values, false))))
+                if values.iter().all(|dim| matches!(dim, ShapeDim::Static(_))) =>
+            {
+                attrs.push((name, values.clone()));
+            }
+            _ => return,
+        }
+    }
+
+    with_runtime_dims(node, 1, attrs);
+}
+
+/// Make a node read the sizes of the given attributes from the outputs of the Shape nodes, in
+/// place of its inputs following the data inputs, when some of them are only known at runtime.
+fn with_runtime_dims(node: &mut Node, data_inputs: usize, attrs: Vec<(&str, Vec<ShapeDim>)>) {
+    if attrs
+        .iter()
+        .flat_map(|(_, dims)| dims)
+        .all(|dim| matches!(dim, ShapeDim::Static(_)))
+    {
+        return;
+    }
+
+    let mut shape_inputs = Vec::<String>::new();
+    for (name, dims) in attrs {
+        let (values, positions): (Vec<i64>, Vec<i64>) = dims
+            .iter()
+            .map(|dim| match dim {
+                ShapeDim::Static(size) => (*size, -1),
+                ShapeDim::Runtime { shape, index } => {
+                    let position = match shape_inputs.iter().position(|input| input == shape) {
+                        Some(position) => position,
+                        None => {
+                            shape_inputs.push(shape.clone());
+                            shape_inputs.len() - 1
+                        }
+                    };
+                    (*index, (data_inputs + position) as i64)
+                }
+            })
+            .unzip();
+
+        node.attrs
+            .insert(name.to_string(), AttributeValue::Int64s(values));
+        if positions.iter().any(|position| *position >= 0) {
+            node.attrs
+                .insert(format!("{name}_inputs"), AttributeValue::Int64s(positions));
+        }
+    }
+
+    log::debug!("Reading the sizes of {} at runtime", node.name);
+
+    node.inputs.truncate(data_inputs);
+    node.inputs
+        .extend(shape_inputs.into_iter().map(|name| Argument {
+            name,
+            ty: ArgType::Shape(0),
+        }));
+}
+
+/// Insert dimensions of size 1 at the given axes, from an attribute or the second input.
+fn unsqueeze_constant(tensors: &[&Tensor], attrs: &Attributes) -> Option<Tensor> {
+    let axes = match (attrs.get("axes"), tensors.get(1)) {
+        (Some(AttributeValue::Int64s(axes)), _) => axes.clone(),
+        (None, Some(axes)) => match &axes.data {
+            Some(TensorData::Int64(axes)) => axes.clone(),
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    let tensor = tensors[0];
+    let mut shape = tensor.shape.clone()?;
+    let dim = shape.len() + axes.len();
+    let mut axes = axes
+        .into_iter()
+        .map(|axis| match axis < 0 {
+            true => (axis + dim as i64) as usize,
+            false => axis as usize,
+        })
+        .collect::<Vec<_>>();
+    axes.sort();
+
+    for axis in axes {
+        if axis > shape.len() {
+            return None;
+        }
+        shape.insert(axis, 1);
+    }
+
+    Some(Tensor {
+        dim,
+        shape: Some(shape),
+        ..tensor.clone()
+    })
+}
+
+/// Gather the elements of an integer vector at a scalar or vector index.
+fn gather_vector(tensors: &[&Tensor], attrs: &Attributes) -> Option<Tensor> {
+    if !matches!(attrs.get("axis"), None | Some(AttributeValue::Int64(0))) {
+        return None;
+    }
+
+    let (Some(TensorData::Int64(values)), Some(TensorData::Int64(indices))) =
+        (&tensors[0].data, &tensors.get(1)?.data)
+    else {
+        return None;
+    };
+    if tensors[0].dim != 1 || tensors[1].dim > 1 {
+        return None;
+    }
+
+    let len = values.len() as i64;
+    let values = indices
+        .iter()
+        .map(|index| match *index < 0 {
+            true => index + len,
+            false => *index,
+        })
+        .map(|index| values.get(usize::try_from(index).ok()?).copied())
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(Tensor {
+        elem_type: ElementType::Int64,
+        dim: tensors[1].dim,
+        shape: tensors[1].shape.clone(),
+        data: Some(TensorData::Int64(values)),
+    })
+}
+
+/// Apply an arithmetic operation on integer tensors of the same shape, or with a tensor of one
+/// element.
+fn int_binary(node_type: &NodeType, tensors: &[&Tensor]) -> Option<Tensor> {
+    let [lhs, rhs] = tensors else {
+        return None;
+    };
+    let (Some(TensorData::Int64(lhs_values)), Some(TensorData::Int64(rhs_values))) =
+        (&lhs.data, &rhs.data)
+    else {
+        return None;
+    };
+
+    let output = match (lhs_values.len(), rhs_values.len()) {
+        (len, 1) if lhs.dim >= rhs.dim && len > 0 => lhs,
+        (1, _) if rhs.dim >= lhs.dim => rhs,
+        (lhs_len, rhs_len) if lhs_len == rhs_len && lhs.shape == rhs.shape => lhs,
+        _ => return None,
+    };
+    let len = lhs_values.len().max(rhs_values.len());
+    let value = |values: &Vec<i64>, i: usize| values[i % values.len()];
+
+    let values = (0..len)
+        .map(|i| {
+            let (lhs, rhs) = (value(lhs_values, i), value(rhs_values, i));
+            match node_type {
+                NodeType::Add => lhs.checked_add(rhs),
+                NodeType::Sub => lhs.checked_sub(rhs),
+                NodeType::Mul => lhs.checked_mul(rhs),
+                NodeType::Div => lhs.checked_div(rhs),
+                _ => None,
+            }
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(Tensor {
+        data: Some(TensorData::Int64(values)),
+        ..(*output).clone()
+    })
+}
+
+/// Concatenate vectors with the same type of elements.
+fn concat_vectors(tensors: &[&Tensor]) -> Option<Tensor> {
+    let mut data = tensors
+        .iter()
+        .map(|tensor| tensor.data.clone())
+        .collect::<Option<Vec<_>>>()?
+        .into_iter();
+
+    let data = match data.next()? {
+        TensorData::Int64(mut values) => {
+            for other in data {
+                let TensorData::Int64(other) = other else {
+                    return None;
+                };
+                values.extend(other);
+            }
+            TensorData::Int64(values)
+        }
+        TensorData::Float32(mut values) => {
+            for other in data {
+                let TensorData::Float32(other) = other else {
+                    return None;
+                };
+                values.extend(other);
+            }
+            TensorData::Float32(values)
+        }
+        _ => return None,
+    };
+    let len = tensors
+        .iter()
+        .map(|tensor| tensor.shape.as_ref().map(|shape| shape[0]))
+        .sum::<Option<usize>>()?;
+
+    Some(Tensor {
+        elem_type: tensors[0].elem_type.clone(),
+        dim: 1,
+        data: Some(data),
+        shape: Some(vec![len]),
+    })
+}
+
 /// Lift constants from the graph into the states vector for known node types.
 ///
 /// The primary reason to move constants into the states vector is to reduce the number of nodes in the graph,
@@ -525,8 +1429,14 @@ fn lift_constants(nodes: &mut Vec<Node>) {
         node.inputs.retain(|x| !inputs_to_remove.contains(&x.name))
     }
 
-    // remove the constants that were moved to the states vector
-    nodes.retain(|node| !constant_to_removed.contains(&node.name));
+    // remove the constants that were moved to the states vector, unless other nodes still read them
+    let used = nodes
+        .iter()
+        .flat_map(|node| node.inputs.iter().map(|input| input.name.clone()))
+        .collect::<HashSet<_>>();
+    nodes.retain(|node| {
+        !constant_to_removed.contains(&node.name) || used.contains(&node.outputs[0].name)
+    });
 
     log::debug!(
         "The number of constants removed: {}",
@@ -535,10 +1445,22 @@ fn lift_constants(nodes: &mut Vec<Node>) {
 }
 
 /// Rename the nodes in the graph to be unique and return a map of the old names to the new names.
+///
+/// The nodes of the subgraphs are numbered along the nodes of the graph.
 fn rename_nodes(nodes: &mut Vec<Node>) -> HashMap<String, String> {
     let mut old_names = HashMap::new();
     let mut counter: HashMap<NodeType, usize> = HashMap::new();
 
+    rename_nodes_with_counter(nodes, &mut counter, &mut old_names);
+
+    old_names
+}
+
+fn rename_nodes_with_counter(
+    nodes: &mut [Node],
+    counter: &mut HashMap<NodeType, usize>,
+    old_names: &mut HashMap<String, String>,
+) {
     for node in nodes.iter_mut() {
         // keep track of the number of nodes of each type
         counter
@@ -552,9 +1474,11 @@ fn rename_nodes(nodes: &mut Vec<Node>) -> HashMap<String, String> {
         node.name = new_name.clone();
 
         old_names.insert(old_name, new_name);
-    }
 
-    old_names
+        for graph in subgraphs_mut(node) {
+            rename_nodes_with_counter(&mut graph.nodes, counter, old_names);
+        }
+    }
 }
 
 /// Rename the inputs and output in the graph and return a map of the old names to the new names.
@@ -594,13 +1518,20 @@ fn rename_inputs(
 
     for node in nodes.iter_mut() {
         // loop through node inputs and rename them with previously replaced names
-        for input in node.inputs.iter_mut() {
+        // (the optional inputs left out have an empty name)
+        for input in node
+            .inputs
+            .iter_mut()
+            .filter(|input| !input.name.is_empty())
+        {
             if let Some(new_name) = old_names.get(&input.name) {
                 input.name = new_name.clone();
             } else {
                 panic!("Input {} not found in old_names", input.name);
             }
         }
+
+        rename_subgraph_inputs(node, &old_names);
     }
 
     // Rename the graph outputs
@@ -615,6 +1546,54 @@ fn rename_inputs(
     old_names
 }
 
+/// Rename the inputs and outputs of the subgraphs of a control flow node, and the values they read
+/// from the enclosing graphs with the names of `outer_names`.
+///
+/// The inputs of a subgraph are renamed after the control flow node, as in loop1_in1, loop1_in2, etc.
+fn rename_subgraph_inputs(node: &mut Node, outer_names: &HashMap<String, String>) {
+    let node_name = node.name.clone();
+
+    for graph in subgraphs_mut(node) {
+        let mut names = outer_names.clone();
+
+        for (index, input) in graph.inputs.iter_mut().enumerate() {
+            let new_name = format!("{}_in{}", node_name, index + 1);
+            names.insert(input.name.clone(), new_name.clone());
+            input.name = new_name;
+        }
+
+        for node in graph.nodes.iter_mut() {
+            for (index, output) in node.outputs.iter_mut().enumerate() {
+                let new_name = format!("{}_out{}", node.name, index + 1);
+                names.insert(output.name.clone(), new_name.clone());
+                output.name = new_name;
+            }
+        }
+
+        for node in graph.nodes.iter_mut() {
+            for input in node
+                .inputs
+                .iter_mut()
+                .filter(|input| !input.name.is_empty())
+            {
+                match names.get(&input.name) {
+                    Some(new_name) => input.name = new_name.clone(),
+                    None => panic!("Input {} not found in old_names", input.name),
+                }
+            }
+
+            rename_subgraph_inputs(node, &names);
+        }
+
+        for output in graph.outputs.iter_mut() {
+            match names.get(&output.name) {
+                Some(new_name) => output.name = new_name.clone(),
+                None => panic!("Output {} not found in old_names", output.name),
+            }
+        }
+    }
+}
+
 /// Removes the graph inputs/output that are not used by any node.
 ///
 /// In older ONNX models, the inputs and outputs are not always used by the nodes.
@@ -686,3 +1665,167 @@ impl TopologicalSortable for Vec<Node> {
         true
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::onnx::protos::StringStringEntryProto;
+
+    fn entry(key: &str, value: &str) -> StringStringEntryProto {
+        let mut entry = StringStringEntryProto::new();
+        entry.key = key.to_string();
+        entry.value = value.to_string();
+        entry
+    }
+
+    #[test]
+    fn load_external_data_reads_range() {
+        let dir = tempfile::tempdir().unwrap();
+        let base_dir = dir.path();
+        let data = [0.0f32, 1.0, 2.0, 3.0, 4.0];
+        std::fs::write(base_dir.join("weights.bin"), cast_slice::<f32, u8>(&data)).unwrap();
+
+        let mut tensor = TensorProto::new();
+        tensor.name = "weight".to_string();
+        tensor.data_type = DataType::FLOAT as i32;
+        tensor.dims = vec![3];
+        tensor.data_location = DataLocation::EXTERNAL.into();
+        tensor.external_data = vec![
+            entry("location", "weights.bin"),
+            entry("offset", "4"),
+            entry("length", "12"),
+        ];
+
+        load_external_data(&mut tensor, base_dir);
+        let tensor: Tensor = tensor.try_into().unwrap();
+
+        assert!(matches!(tensor.data, Some(TensorData::Float32(data)) if data == [1.0, 2.0, 3.0]));
+    }
+
+    fn node(node_type: NodeType, inputs: &[&str], output: &str, attrs: Attributes) -> Node {
+        let argument = |name: &str| Argument {
+            name: name.to_string(),
+            ty: ArgType::Tensor(TensorArg::new(1, ElementType::Int64)),
+        };
+
+        Node {
+            name: output.to_string(),
+            node_type,
+            inputs: inputs.iter().map(|name| argument(name)).collect(),
+            outputs: vec![argument(output)],
+            states: vec![],
+            attrs,
+        }
+    }
+
+    fn constant(output: &str, values: Vec<i64>, dim: usize) -> Node {
+        let tensor = Tensor {
+            elem_type: ElementType::Int64,
+            dim,
+            shape: Some(vec![values.len()]),
+            data: Some(TensorData::Int64(values)),
+        };
+        let attrs = [("value".to_string(), AttributeValue::Tensor(tensor))].into();
+
+        node(NodeType::Constant, &[], output, attrs)
+    }
+
+    #[test]
+    fn fold_shape_dims_reads_the_gathered_sizes_at_runtime() {
+        let mut nodes = vec![
+            node(NodeType::Shape, &["x"], "shape", Attributes::new()),
+            constant("index", vec![0], 0),
+            node(
+                NodeType::Gather,
+                &["shape", "index"],
+                "batch",
+                Attributes::new(),
+            ),
+            node(
+                NodeType::Unsqueeze,
+                &["batch"],
+                "batch_vec",
+                [("axes".to_string(), AttributeValue::Int64s(vec![0]))].into(),
+            ),
+            constant("size", vec![4], 1),
+            node(
+                NodeType::Concat,
+                &["batch_vec", "size"],
+                "target",
+                [("axis".to_string(), AttributeValue::Int64(0))].into(),
+            ),
+            node(
+                NodeType::Expand,
+                &["y", "target"],
+                "expanded",
+                Attributes::new(),
+            ),
+            constant("starts", vec![0], 1),
+            constant("axes", vec![1], 1),
+            node(
+                NodeType::Slice,
+                &["expanded", "starts", "batch_vec", "axes"],
+                "sliced",
+                Attributes::new(),
+            ),
+        ];
+        let mut output = ValueInfoProto::new();
+        output.name = "sliced".to_string();
+
+        fold_shape_dims(&mut nodes, &[output]);
+
+        let names = nodes
+            .iter()
+            .map(|node| node.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["shape", "expanded", "sliced"]);
+
+        let expand = &nodes[1];
+        let inputs = expand.inputs.iter().map(|input| input.name.as_str());
+        assert!(inputs.eq(["y", "shape"]));
+        assert!(
+            matches!(expand.attrs.get("shape"), Some(AttributeValue::Int64s(shape)) if shape == &[0, 4])
+        );
+        assert!(
+            matches!(expand.attrs.get("shape_inputs"), Some(AttributeValue::Int64s(inputs)) if inputs == &[1, -1])
+        );
+
+        let slice = &nodes[2];
+        let inputs = slice.inputs.iter().map(|input| input.name.as_str());
+        assert!(inputs.eq(["expanded", "shape"]));
+        assert!(
+            matches!(slice.attrs.get("axes"), Some(AttributeValue::Int64s(axes)) if axes == &[1])
+        );
+        assert!(!slice.attrs.contains_key("starts_inputs"));
+        assert!(
+            matches!(slice.attrs.get("ends_inputs"), Some(AttributeValue::Int64s(inputs)) if inputs == &[1])
+        );
+    }
+
+    #[test]
+    fn move_inputs_to_state_shares_external_initializers() {
+        let dir = tempfile::tempdir().unwrap();
+        let base_dir = dir.path();
+        let data = [1.0f32, 2.0];
+        std::fs::write(base_dir.join("shared.bin"), cast_slice::<f32, u8>(&data)).unwrap();
+
+        let mut tensor = TensorProto::new();
+        tensor.name = "weight".to_string();
+        tensor.data_type = DataType::FLOAT as i32;
+        tensor.dims = vec![2];
+        tensor.data_location = DataLocation::EXTERNAL.into();
+        tensor.external_data = vec![entry("location", "shared.bin")];
+
+        let mut nodes = vec![
+            node(NodeType::Mul, &["x", "weight"], "y", Attributes::new()),
+            node(NodeType::Add, &["y", "weight"], "z", Attributes::new()),
+        ];
+        move_inputs_to_state(&mut nodes, &[tensor], base_dir);
+
+        for node in nodes.iter() {
+            assert_eq!(node.inputs.len(), 1);
+            let StateType::Tensor(tensor) = &node.states[0].ty;
+            assert!(matches!(&tensor.data, Some(TensorData::Float32(values)) if values == &data));
+        }
+    }
+}
@@ -0,0 +1,678 @@
+use std::collections::{HashMap, HashSet};
+
+use super::{
+    ir::{Argument, AttributeValue, Attributes, Node, NodeType, Tensor, TensorData},
+    protos::{TensorProto, ValueInfoProto},
+};
+
+/// Fuse the patterns of nodes emitted by exporters for operators that are decomposed in ONNX.
+///
+/// The fused nodes are replaced by a single node of the operator, which is both faster and
+/// closer to the original model. The constants that are no longer used are removed afterwards
+/// when the constants are folded.
+pub fn fuse_nodes(
+    nodes: &mut Vec<Node>,
+    initializers: &[TensorProto],
+    graph_outputs: &[ValueInfoProto],
+) {
+    let patterns = Patterns::new(nodes, initializers, graph_outputs);
+
+    let fusions = nodes
+        .iter()
+        .enumerate()
+        .filter_map(|(i, node)| match node.node_type {
+            NodeType::Erf => patterns.match_gelu(i),
+            NodeType::ReduceMean => patterns.match_layer_norm(i),
+            NodeType::InstanceNormalization => patterns.match_group_norm(i),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let mut removed = HashSet::new();
+    for fusion in fusions {
+        log::debug!(
+            "Fusing the nodes {:?} into a {} node",
+            fusion.removed,
+            fusion.node_type
+        );
+
+        let node = &mut nodes[fusion.output];
+        node.node_type = fusion.node_type;
+        node.inputs = fusion.inputs;
+        node.attrs = fusion.attrs;
+
+        removed.extend(fusion.removed);
+    }
+
+    let mut index = 0;
+    nodes.retain(|_| {
+        index += 1;
+        !removed.contains(&(index - 1))
+    });
+}
+
+/// The nodes of a fused pattern.
+struct Fusion {
+    /// The fused operator.
+    node_type: NodeType,
+    /// The input arguments of the fused operator.
+    inputs: Vec<Argument>,
+    /// The attributes of the fused operator.
+    attrs: Attributes,
+    /// The node producing the output of the pattern, which becomes the fused node.
+    output: usize,
+    /// The other nodes of the pattern.
+    removed: Vec<usize>,
+}
+
+/// The producers and consumers of each argument of the graph, used to match patterns.
+struct Patterns<'a> {
+    nodes: &'a [Node],
+    producers: HashMap<&'a str, usize>,
+    consumers: HashMap<&'a str, Vec<usize>>,
+    values: HashMap<String, Tensor>,
+    scalars: HashMap<String, f64>,
+    constants: HashSet<String>,
+    graph_outputs: HashSet<&'a str>,
+}
+
+impl<'a> Patterns<'a> {
+    fn new(
+        nodes: &'a [Node],
+        initializers: &[TensorProto],
+        graph_outputs: &'a [ValueInfoProto],
+    ) -> Self {
+        let mut producers = HashMap::new();
+        let mut consumers = HashMap::<_, Vec<_>>::new();
+
+        for (i, node) in nodes.iter().enumerate() {
+            for output in node.outputs.iter() {
+                producers.insert(output.name.as_str(), i);
+            }
+            for input in node.inputs.iter() {
+                consumers.entry(input.name.as_str()).or_default().push(i);
+            }
+        }
+
+        // The constants, from Constant nodes or initializers.
+        let constants = nodes
+            .iter()
+            .filter(|node| node.node_type == NodeType::Constant)
+            .filter_map(|node| match node.attrs.get("value") {
+                Some(AttributeValue::Tensor(tensor)) => {
+                    Some((node.outputs[0].name.clone(), tensor.clone()))
+                }
+                _ => None,
+            })
+            .map(|(name, tensor)| (name, Some(tensor)))
+            .chain(initializers.iter().map(|initializer| {
+                // Only the small initializers are converted, the weights and the data stored in
+                // external files are read when the initializers are moved to the states.
+                let small = initializer.dims.iter().product::<i64>() <= MAX_VALUES as i64;
+                let tensor = match small && initializer.external_data.is_empty() {
+                    true => initializer.clone().try_into().ok(),
+                    false => None,
+                };
+                (initializer.name.clone(), tensor)
+            }))
+            .collect::<Vec<_>>();
+        let values = constants
+            .iter()
+            .filter_map(|(name, tensor)| Some((name.clone(), tensor.clone()?)))
+            .collect::<HashMap<_, _>>();
+        let scalars = values
+            .iter()
+            .filter_map(|(name, tensor)| Some((name.clone(), float_scalar(tensor)?)))
+            .collect();
+
+        Self {
+            nodes,
+            producers,
+            consumers,
+            values,
+            scalars,
+            constants: constants.into_iter().map(|(name, _)| name).collect(),
+            graph_outputs: graph_outputs
+                .iter()
+                .map(|output| output.name.as_str())
+                .collect(),
+        }
+    }
+
+    /// Match `x * 0.5 * (1 + erf(x / sqrt(2)))` from its Erf node.
+    ///
+    /// The division may be a multiplication by `1 / sqrt(2)`, and the product of `x`, `0.5` and
+    /// the sum may be computed in any order.
+    fn match_gelu(&self, erf: usize) -> Option<Fusion> {
+        let scaled = self.nodes[erf].inputs.first()?;
+        let scale = self.sole_producer(&scaled.name)?;
+        let input = match self.nodes[scale].node_type {
+            NodeType::Div if self.is_scalar(&self.nodes[scale].inputs[1].name, SQRT_2) => {
+                &self.nodes[scale].inputs[0]
+            }
+            NodeType::Mul => self.nodes[scale]
+                .inputs
+                .iter()
+                .find(|input| !self.is_scalar(&input.name, FRAC_1_SQRT_2))
+                .filter(|_| self.has_scalar_input(scale, FRAC_1_SQRT_2))?,
+            _ => return None,
+        };
+        let x = input.name.as_str();
+
+        let add = self.sole_consumer(erf, NodeType::Add)?;
+        if !self.has_scalar_input(add, 1.0) {
+            return None;
+        }
+
+        let mul = self.sole_consumer(add, NodeType::Mul)?;
+        let factor = &other_input(&self.nodes[mul], &self.nodes[add].outputs[0].name)?.name;
+        let mut removed = vec![scale, erf, add];
+
+        let output = if factor == x {
+            // (x * (1 + erf)) * 0.5
+            let output = self.sole_consumer(mul, NodeType::Mul)?;
+            self.has_scalar_input(output, 0.5).then_some(())?;
+            removed.push(mul);
+            output
+        } else if self.is_scalar(factor, 0.5) {
+            // ((1 + erf) * 0.5) * x
+            let output = self.sole_consumer(mul, NodeType::Mul)?;
+            (other_input(&self.nodes[output], &self.nodes[mul].outputs[0].name)?.name == x)
+                .then_some(())?;
+            removed.push(mul);
+            output
+        } else {
+            // (x * 0.5) * (1 + erf)
+            let half = self.sole_producer(factor)?;
+            let is_half = self.nodes[half].node_type == NodeType::Mul
+                && self.has_scalar_input(half, 0.5)
+                && self.nodes[half].inputs.iter().any(|input| input.name == x);
+            is_half.then_some(())?;
+            removed.push(half);
+            mul
+        };
+
+        Some(Fusion {
+            node_type: NodeType::Gelu,
+            inputs: vec![input.clone()],
+            attrs: Attributes::new(),
+            output,
+            removed,
+        })
+    }
+
+    /// Match `(x - mean(x)) / sqrt(mean((x - mean(x))^2) + epsilon) * scale + bias` from its
+    /// first ReduceMean node, where the means are over the last axis.
+    ///
+    /// The scale and bias must be constants, and the operands of the Add and Mul nodes may be in
+    /// any order.
+    fn match_layer_norm(&self, mean: usize) -> Option<Fusion> {
+        let input = self.last_axis_mean(mean)?;
+
+        let sub = self.sole_consumer(mean, NodeType::Sub)?;
+        (self.nodes[sub].inputs[0].name == input.name).then_some(())?;
+
+        // The centered input is both squared and divided by the standard deviation.
+        let centered = self.nodes[sub].outputs.first()?.name.as_str();
+        if self.graph_outputs.contains(centered) {
+            return None;
+        }
+        let (pow, div) = match self.consumers.get(centered)?.as_slice() {
+            [first, second] if self.nodes[*first].node_type == NodeType::Pow => (*first, *second),
+            [first, second] => (*second, *first),
+            _ => return None,
+        };
+        let is_square = self.nodes[pow].node_type == NodeType::Pow
+            && self.nodes[pow].inputs[0].name == centered
+            && self.is_scalar(&self.nodes[pow].inputs[1].name, 2.0);
+        is_square.then_some(())?;
+
+        let var = self.sole_consumer(pow, NodeType::ReduceMean)?;
+        self.last_axis_mean(var)?;
+
+        let add_epsilon = self.sole_consumer(var, NodeType::Add)?;
+        let epsilon = other_input(&self.nodes[add_epsilon], &self.nodes[var].outputs[0].name)?;
+        let epsilon = *self.scalars.get(&epsilon.name)?;
+
+        let sqrt = self.sole_consumer(add_epsilon, NodeType::Sqrt)?;
+        let is_normalized = self.sole_consumer(sqrt, NodeType::Div) == Some(div)
+            && self.nodes[div].inputs[0].name == centered;
+        is_normalized.then_some(())?;
+
+        let mul = self.sole_consumer(div, NodeType::Mul)?;
+        let scale = other_input(&self.nodes[mul], &self.nodes[div].outputs[0].name)?;
+        let add = self.sole_consumer(mul, NodeType::Add)?;
+        let bias = other_input(&self.nodes[add], &self.nodes[mul].outputs[0].name)?;
+
+        if !self.constants.contains(&scale.name) || !self.constants.contains(&bias.name) {
+            return None;
+        }
+
+        let mut attrs = Attributes::new();
+        attrs.insert("axis".to_string(), AttributeValue::Int64(-1));
+        attrs.insert(
+            "epsilon".to_string(),
+            AttributeValue::Float32(epsilon as f32),
+        );
+
+        Some(Fusion {
+            node_type: NodeType::LayerNormalization,
+            inputs: vec![input.clone(), scale.clone(), bias.clone()],
+            attrs,
+            output: add,
+            removed: vec![mean, sub, pow, var, add_epsilon, sqrt, div, mul],
+        })
+    }
+
+    /// Match `reshape(instance_norm(reshape(x, [0, num_groups, -1])), shape(x)) * scale + bias`
+    /// from its InstanceNormalization node, as exported for the group normalization before
+    /// opset 18.
+    ///
+    /// The instance normalization must have a unit scale and a zero bias, while the scale and
+    /// bias of each channel must be constants.
+    fn match_group_norm(&self, norm: usize) -> Option<Fusion> {
+        let [grouped, unit, zero] = self.nodes[norm].inputs.as_slice() else {
+            return None;
+        };
+        (self.is_filled(&unit.name, 1.0) && self.is_filled(&zero.name, 0.0)).then_some(())?;
+        let epsilon = match self.nodes[norm].attrs.get("epsilon") {
+            Some(AttributeValue::Float32(epsilon)) => *epsilon,
+            None => 1e-5,
+            _ => return None,
+        };
+
+        // The input is split in groups by the first reshape and restored by the second one.
+        let group = self.sole_producer(&grouped.name)?;
+        let [input, group_shape] = self.reshape_inputs(group)?;
+        let num_groups = match self.int_values(&group_shape.name)?.as_slice() {
+            [batch, num_groups, -1] if *batch >= 0 && *num_groups > 0 => *num_groups,
+            _ => return None,
+        };
+
+        let ungroup = self.sole_consumer(norm, NodeType::Reshape)?;
+        let [_, shape] = self.reshape_inputs(ungroup)?;
+        let mut removed = vec![group, norm, ungroup];
+        match self.sole_producer(&shape.name) {
+            Some(node)
+                if self.nodes[node].node_type == NodeType::Shape
+                    && self.nodes[node].attrs.is_empty()
+                    && self.nodes[node].inputs[0].name == input.name =>
+            {
+                removed.push(node)
+            }
+            _ if self.constants.contains(&shape.name) => {}
+            _ => return None,
+        }
+
+        let mul = self.sole_consumer(ungroup, NodeType::Mul)?;
+        let scale = other_input(&self.nodes[mul], &self.nodes[ungroup].outputs[0].name)?;
+        let add = self.sole_consumer(mul, NodeType::Add)?;
+        let bias = other_input(&self.nodes[add], &self.nodes[mul].outputs[0].name)?;
+        removed.push(mul);
+
+        if !self.constants.contains(&scale.name) || !self.constants.contains(&bias.name) {
+            return None;
+        }
+
+        let mut attrs = Attributes::new();
+        attrs.insert("num_groups".to_string(), AttributeValue::Int64(num_groups));
+        attrs.insert("epsilon".to_string(), AttributeValue::Float32(epsilon));
+
+        Some(Fusion {
+            node_type: NodeType::GroupNormalization,
+            inputs: vec![input.clone(), scale.clone(), bias.clone()],
+            attrs,
+            output: add,
+            removed,
+        })
+    }
+
+    /// The data and shape inputs of a Reshape node.
+    fn reshape_inputs(&self, node: usize) -> Option<[&Argument; 2]> {
+        let node = &self.nodes[node];
+        let allowzero = matches!(node.attrs.get("allowzero"), Some(AttributeValue::Int64(1)));
+
+        match node.inputs.as_slice() {
+            [input, shape] if node.node_type == NodeType::Reshape && !allowzero => {
+                Some([input, shape])
+            }
+            _ => None,
+        }
+    }
+
+    /// The input of a ReduceMean node over the last axis keeping the dimensions.
+    fn last_axis_mean(&self, node: usize) -> Option<&Argument> {
+        let node = &self.nodes[node];
+        let is_last_axis = node.node_type == NodeType::ReduceMean
+            && node.inputs.len() == 1
+            && matches!(node.attrs.get("axes"), Some(AttributeValue::Int64s(axes)) if axes == &[-1])
+            && !matches!(node.attrs.get("keepdims"), Some(AttributeValue::Int64(0)));
+
+        is_last_axis.then(|| &node.inputs[0])
+    }
+
+    /// The node producing an argument only used by a single node.
+    fn sole_producer(&self, name: &str) -> Option<usize> {
+        self.is_used_once(name)
+            .then(|| self.producers.get(name).copied())
+            .flatten()
+    }
+
+    /// The node of the given type that is the only user of the output of a node.
+    fn sole_consumer(&self, node: usize, node_type: NodeType) -> Option<usize> {
+        let output = &self.nodes[node].outputs.first()?.name;
+        if !self.is_used_once(output) {
+            return None;
+        }
+
+        let consumer = self.consumers[output.as_str()][0];
+        (self.nodes[consumer].node_type == node_type).then_some(consumer)
+    }
+
+    fn is_used_once(&self, name: &str) -> bool {
+        !self.graph_outputs.contains(name) && self.consumers.get(name).map(Vec::len) == Some(1)
+    }
+
+    /// Whether the argument is a float constant with all its elements equal to the value.
+    fn is_filled(&self, name: &str, value: f64) -> bool {
+        let values = match self
+            .values
+            .get(name)
+            .and_then(|tensor| tensor.data.as_ref())
+        {
+            Some(TensorData::Float16(values)) => values.iter().map(|v| v.to_f64()).collect(),
+            Some(TensorData::Float32(values)) => values.iter().map(|v| *v as f64).collect(),
+            Some(TensorData::Float64(values)) => values.clone(),
+            _ => return false,
+        };
+
+        !values.is_empty() && values.iter().all(|v| *v == value)
+    }
+
+    fn int_values(&self, name: &str) -> Option<Vec<i64>> {
+        match self.values.get(name)?.data.as_ref()? {
+            TensorData::Int64(values) => Some(values.clone()),
+            TensorData::Int32(values) => Some(values.iter().map(|v| *v as i64).collect()),
+            _ => None,
+        }
+    }
+
+    fn is_scalar(&self, name: &str, value: f64) -> bool {
+        self.scalars
+            .get(name)
+            .is_some_and(|scalar| (scalar - value).abs() <= 1e-4 * value.abs())
+    }
+
+    fn has_scalar_input(&self, node: usize, value: f64) -> bool {
+        let node = &self.nodes[node];
+        node.inputs.len() == 2
+            && node
+                .inputs
+                .iter()
+                .any(|input| self.is_scalar(&input.name, value))
+    }
+}
+
+/// The maximum number of elements of the initializers read to match the patterns.
+const MAX_VALUES: usize = 1024;
+const SQRT_2: f64 = std::f64::consts::SQRT_2;
+const FRAC_1_SQRT_2: f64 = std::f64::consts::FRAC_1_SQRT_2;
+
+/// The other input of a binary node.
+fn other_input<'a>(node: &'a Node, name: &str) -> Option<&'a Argument> {
+    match node.inputs.as_slice() {
+        [lhs, rhs] if lhs.name == name => Some(rhs),
+        [lhs, rhs] if rhs.name == name => Some(lhs),
+        _ => None,
+    }
+}
+
+/// The value of a float tensor of a single element.
+fn float_scalar(tensor: &Tensor) -> Option<f64> {
+    match tensor.data.as_ref()? {
+        TensorData::Float16(values) if values.len() == 1 => Some(values[0].to_f64()),
+        TensorData::Float32(values) if values.len() == 1 => Some(values[0] as f64),
+        TensorData::Float64(values) if values.len() == 1 => Some(values[0]),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::onnx::ir::{ArgType, ElementType};
+    use crate::onnx::test_utils::{self};
+
+    fn node(node_type: NodeType, inputs: &[&str], output: &str) -> Node {
+        let argument = |name: &str| Argument {
+            name: name.to_string(),
+            ty: ArgType::Tensor(Default::default()),
+        };
+
+        Node {
+            node_type,
+            name: output.to_string(),
+            inputs: inputs.iter().map(|name| argument(name)).collect(),
+            outputs: vec![argument(output)],
+            states: vec![],
+            attrs: Default::default(),
+        }
+    }
+
+    fn constant(output: &str, value: f32) -> Node {
+        let mut node = node(NodeType::Constant, &[], output);
+        node.attrs.insert(
+            "value".to_string(),
+            AttributeValue::Tensor(Tensor {
+                elem_type: ElementType::Float32,
+                dim: 0,
+                data: Some(TensorData::Float32(vec![value])),
+                shape: Some(vec![]),
+            }),
+        );
+        node
+    }
+
+    #[test]
+    fn gelu_pattern_should_be_fused() {
+        let mut nodes = vec![
+            constant("sqrt2", std::f32::consts::SQRT_2),
+            node(NodeType::Div, &["x", "sqrt2"], "div"),
+            node(NodeType::Erf, &["div"], "erf"),
+            constant("one", 1.0),
+            node(NodeType::Add, &["erf", "one"], "add"),
+            node(NodeType::Mul, &["x", "add"], "mul"),
+            constant("half", 0.5),
+            node(NodeType::Mul, &["mul", "half"], "output"),
+        ];
+
+        fuse_nodes(&mut nodes, &[], &[]);
+
+        let node_types = nodes
+            .iter()
+            .map(|node| node.node_type.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            node_types,
+            vec![
+                NodeType::Constant,
+                NodeType::Constant,
+                NodeType::Constant,
+                NodeType::Gelu
+            ]
+        );
+        let gelu = nodes.last().unwrap();
+        assert_eq!(gelu.inputs.len(), 1);
+        assert_eq!(gelu.inputs[0].name, "x");
+        assert_eq!(gelu.outputs[0].name, "output");
+    }
+
+    #[test]
+    fn gelu_pattern_with_shared_intermediate_should_not_be_fused() {
+        let mut nodes = vec![
+            constant("sqrt2", std::f32::consts::SQRT_2),
+            node(NodeType::Div, &["x", "sqrt2"], "div"),
+            node(NodeType::Erf, &["div"], "erf"),
+            constant("one", 1.0),
+            node(NodeType::Add, &["erf", "one"], "add"),
+            node(NodeType::Mul, &["x", "add"], "mul"),
+            constant("half", 0.5),
+            node(NodeType::Mul, &["mul", "half"], "output"),
+            node(NodeType::Relu, &["erf"], "relu"),
+        ];
+
+        fuse_nodes(&mut nodes, &[], &[]);
+
+        assert_eq!(nodes.len(), 9);
+        assert!(nodes.iter().all(|node| node.node_type != NodeType::Gelu));
+    }
+
+    fn reduce_mean(input: &str, output: &str, axis: i64) -> Node {
+        let mut node = node(NodeType::ReduceMean, &[input], output);
+        node.attrs
+            .insert("axes".to_string(), AttributeValue::Int64s(vec![axis]));
+        node
+    }
+
+    fn layer_norm_nodes(axis: i64) -> Vec<Node> {
+        vec![
+            reduce_mean("x", "mean", axis),
+            node(NodeType::Sub, &["x", "mean"], "sub"),
+            constant("two", 2.0),
+            node(NodeType::Pow, &["sub", "two"], "pow"),
+            reduce_mean("pow", "var", axis),
+            constant("epsilon", 1e-5),
+            node(NodeType::Add, &["var", "epsilon"], "add_epsilon"),
+            node(NodeType::Sqrt, &["add_epsilon"], "sqrt"),
+            node(NodeType::Div, &["sub", "sqrt"], "div"),
+            node(NodeType::Mul, &["div", "scale"], "mul"),
+            node(NodeType::Add, &["bias", "mul"], "output"),
+        ]
+    }
+
+    fn initializer(name: &str) -> TensorProto {
+        filled_initializer(name, 4, 1.0)
+    }
+
+    fn filled_initializer(name: &str, size: usize, value: f32) -> TensorProto {
+        let mut initializer = TensorProto::new();
+        initializer.name = name.to_string();
+        initializer.data_type = 1;
+        initializer.dims = vec![size as i64];
+        initializer.float_data = vec![value; size];
+        initializer
+    }
+
+    #[test]
+    fn layer_norm_pattern_should_be_fused() {
+        let mut nodes = layer_norm_nodes(-1);
+
+        fuse_nodes(
+            &mut nodes,
+            &[initializer("scale"), initializer("bias")],
+            &[],
+        );
+
+        assert_eq!(nodes.len(), 3);
+        let layer_norm = nodes.last().unwrap();
+        assert_eq!(layer_norm.node_type, NodeType::LayerNormalization);
+        let inputs = layer_norm
+            .inputs
+            .iter()
+            .map(|input| input.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(inputs, vec!["x", "scale", "bias"]);
+        assert_eq!(layer_norm.outputs[0].name, "output");
+        assert!(matches!(
+            layer_norm.attrs.get("epsilon"),
+            Some(AttributeValue::Float32(epsilon)) if *epsilon == 1e-5
+        ));
+    }
+
+    #[test]
+    fn layer_norm_pattern_not_over_last_axis_should_not_be_fused() {
+        let mut nodes = layer_norm_nodes(1);
+
+        fuse_nodes(
+            &mut nodes,
+            &[initializer("scale"), initializer("bias")],
+            &[],
+        );
+
+        assert_eq!(nodes.len(), 11);
+    }
+
+    #[test]
+    fn layer_norm_pattern_with_computed_scale_should_not_be_fused() {
+        let mut nodes = layer_norm_nodes(-1);
+
+        fuse_nodes(&mut nodes, &[initializer("bias")], &[]);
+
+        assert_eq!(nodes.len(), 11);
+    }
+
+    fn group_norm_nodes() -> Vec<Node> {
+        vec![
+            test_utils::constant("group_shape", vec![3], TensorData::Int64(vec![0, 2, -1])),
+            node(NodeType::Reshape, &["x", "group_shape"], "grouped"),
+            node(
+                NodeType::InstanceNormalization,
+                &["grouped", "unit", "zero"],
+                "norm",
+            ),
+            node(NodeType::Shape, &["x"], "shape"),
+            node(NodeType::Reshape, &["norm", "shape"], "ungrouped"),
+            node(NodeType::Mul, &["ungrouped", "scale"], "mul"),
+            node(NodeType::Add, &["mul", "bias"], "output"),
+        ]
+    }
+
+    #[test]
+    fn group_norm_pattern_should_be_fused() {
+        let mut nodes = group_norm_nodes();
+
+        fuse_nodes(
+            &mut nodes,
+            &[
+                filled_initializer("unit", 2, 1.0),
+                filled_initializer("zero", 2, 0.0),
+                initializer("scale"),
+                initializer("bias"),
+            ],
+            &[],
+        );
+
+        assert_eq!(nodes.len(), 2);
+        let group_norm = nodes.last().unwrap();
+        assert_eq!(group_norm.node_type, NodeType::GroupNormalization);
+        let inputs = group_norm
+            .inputs
+            .iter()
+            .map(|input| input.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(inputs, vec!["x", "scale", "bias"]);
+        assert_eq!(group_norm.outputs[0].name, "output");
+        assert!(matches!(
+            group_norm.attrs.get("num_groups"),
+            Some(AttributeValue::Int64(2))
+        ));
+    }
+
+    #[test]
+    fn group_norm_pattern_with_instance_scale_should_not_be_fused() {
+        let mut nodes = group_norm_nodes();
+
+        fuse_nodes(
+            &mut nodes,
+            &[
+                filled_initializer("unit", 2, 2.0),
+                filled_initializer("zero", 2, 0.0),
+                initializer("scale"),
+                initializer("bias"),
+            ],
+            &[],
+        );
+
+        assert_eq!(nodes.len(), 7);
+    }
+}
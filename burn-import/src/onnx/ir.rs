@@ -22,28 +22,48 @@ pub enum ArgType {
 #[derive(new, Default, Debug, Clone)]
 pub struct TensorArg {
     pub dim: usize,
+    pub elem_type: ElementType,
+    /// The sizes of the dimensions declared in the model, `None` for the dynamic ones.
+    #[new(default)]
+    pub static_shape: Option<Vec<Option<usize>>>,
 }
 
 #[derive(Debug, Clone)]
 pub struct SparseTensor(Tensor, Tensor, Shape);
 
+/// The value of an attribute of a node.
 #[derive(Debug, Clone)]
 pub enum AttributeValue {
+    /// A float.
     Float32(f32),
+    /// An integer.
     Int64(i64),
+    /// A string.
     String(String),
+    /// A tensor.
     Tensor(Tensor),
+    /// A sparse tensor.
     SparseTensor(SparseTensor),
+    /// A graph nested in a control flow node.
+    Graph(ONNXGraph),
+    /// A list of floats.
     Float32s(Vec<f32>),
+    /// A list of integers.
     Int64s(Vec<i64>),
+    /// A list of strings.
     Strings(Vec<String>),
+    /// A list of tensors.
     Tensors(Vec<Tensor>),
+    /// A list of sparse tensors.
     SparseTensors(Vec<SparseTensor>),
 }
+
+/// The attributes of a node, by name.
 pub type Attributes = HashMap<String, AttributeValue>;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub enum ElementType {
+    #[default]
     Float32,
     Float64,
     Int32,
@@ -190,6 +210,8 @@ pub enum NodeType {
     Conv2d,
     ConvInteger,
     ConvTranspose,
+    ConvTranspose1d,
+    ConvTranspose2d,
     Cos,
     Cosh,
     CumSum,
@@ -201,6 +223,7 @@ pub enum NodeType {
     Dropout,
     DynamicQuantizeLinear,
     Einsum,
+    Embedding,
     Elu,
     Equal,
     Erf,
@@ -345,6 +368,10 @@ pub enum NodeType {
     Upsample,
     Where,
     Xor,
+    /// An operator unknown to burn-import, or of a custom domain, generated by the handler
+    /// registered for it (see [ModelGen::custom_op](crate::onnx::ModelGen::custom_op)).
+    #[strum(default)]
+    Custom(String),
 }
 
 /// Truncate the vector display for debug display
@@ -1,12 +1,21 @@
 mod coalesce;
 mod dim_inference;
 mod from_onnx;
+mod fusion;
 mod ir;
 mod op_configuration;
+mod optimization;
 mod protos;
+mod quantization;
+#[cfg(test)]
+mod test_utils;
 mod to_burn;
+mod verification;
 
 pub use to_burn::*;
 
+pub use crate::burn::graph::RecordType;
+
 pub use from_onnx::parse_onnx;
-pub use ir::ONNXGraph;
+pub use ir::{AttributeValue, Attributes, ONNXGraph};
+pub use optimization::Optimizations;
@@ -1,13 +1,26 @@
 use burn::nn::{
     conv::Conv1dConfig,
     conv::Conv2dConfig,
+    conv::{ConvTranspose1dConfig, ConvTranspose2dConfig},
+    gru::GruConfig,
     pool::{AvgPool2dConfig, MaxPool2dConfig},
-    BatchNormConfig, DropoutConfig, LinearConfig, PaddingConfig1d, PaddingConfig2d,
+    BatchNormConfig, DropoutConfig, EmbeddingConfig, GroupNormConfig, LayerNormConfig,
+    LeakyReLUConfig, LinearConfig, LstmConfig, PReLUConfig, PaddingConfig1d, PaddingConfig2d,
+};
+use burn::tensor::PadMode;
+
+use crate::burn::node::{
+    constant_of_shape::FillValue,
+    depth_to_space::DepthToSpaceMode,
+    rnn::RnnDirection,
+    scan::ScanAxis,
+    scatter_elements::ScatterReduction,
+    shape::{ShapeDim, ShapeValue},
+    slice::SliceRange,
 };
-
 use crate::onnx::ir::TensorData;
 
-use super::ir::{ArgType, AttributeValue, Node, StateType};
+use super::ir::{ArgType, Argument, AttributeValue, Node, StateType};
 
 #[inline(always)]
 pub fn attr_value_vec_i64(value: &AttributeValue, target: &mut Vec<i64>) {
@@ -44,11 +57,6 @@ pub fn conv1d_config(curr: &Node) -> Conv1dConfig {
     // check if the bias is present
     let bias = curr.states.len() == 2;
 
-    // the channels are inverted in the weight tensor
-    let shape = tensor.shape.unwrap();
-    let channels_in = shape[1];
-    let channels_out = shape[0];
-
     for (key, value) in curr.attrs.iter() {
         match key.as_str() {
             "kernel_shape" => attr_value_i64(value, &mut kernel_shape),
@@ -60,6 +68,12 @@ pub fn conv1d_config(curr: &Node) -> Conv1dConfig {
         }
     }
 
+    // the channels are inverted in the weight tensor, which only holds the input channels of
+    // a single group
+    let shape = tensor.shape.unwrap();
+    let channels_in = shape[1] * group as usize;
+    let channels_out = shape[0];
+
     let padding = padding_config_1d(&pads);
 
     Conv1dConfig::new(channels_in, channels_out, kernel_shape as usize)
@@ -84,10 +98,6 @@ pub fn conv2d_config(curr: &Node) -> Conv2dConfig {
     // check if the bias is present
     let bias = curr.states.len() == 2;
 
-    // the channels are inverted in the weight tensor
-    let shape = tensor.shape.unwrap();
-    let channels: [usize; 2] = [shape[1], shape[0]];
-
     for (key, value) in curr.attrs.iter() {
         match key.as_str() {
             "kernel_shape" => attr_value_vec_i64(value, &mut kernel_shape),
@@ -99,6 +109,11 @@ pub fn conv2d_config(curr: &Node) -> Conv2dConfig {
         }
     }
 
+    // the channels are inverted in the weight tensor, which only holds the input channels of
+    // a single group
+    let shape = tensor.shape.unwrap();
+    let channels: [usize; 2] = [shape[1] * group as usize, shape[0]];
+
     let padding = padding_config(&pads);
 
     Conv2dConfig::new(
@@ -112,6 +127,106 @@ pub fn conv2d_config(curr: &Node) -> Conv2dConfig {
     .with_padding(padding)
 }
 
+/// The attributes of a ConvTranspose node, with one value per spatial axis.
+struct ConvTransposeAttributes {
+    channels: [usize; 2],
+    kernel_shape: Vec<usize>,
+    strides: Vec<usize>,
+    dilations: Vec<usize>,
+    pads: Vec<usize>,
+    output_padding: Vec<usize>,
+    group: usize,
+    bias: bool,
+}
+
+fn conv_transpose_attributes(curr: &Node, spatial_dims: usize) -> ConvTransposeAttributes {
+    // the weight tensor's shape is [in_channels, out_channels / group, ...]
+    let StateType::Tensor(tensor) = &curr
+        .states
+        .first()
+        .expect("ConvTranspose: the weights must be an initializer or a constant")
+        .ty;
+    let shape = tensor.shape.clone().unwrap();
+
+    let mut kernel_shape = shape[2..].iter().map(|size| *size as i64).collect();
+    let mut strides = vec![1; spatial_dims];
+    let mut dilations = vec![1; spatial_dims];
+    let mut pads = vec![0; 2 * spatial_dims];
+    let mut output_padding = vec![0; spatial_dims];
+    let mut group = 1;
+
+    for (key, value) in curr.attrs.iter() {
+        match key.as_str() {
+            "kernel_shape" => attr_value_vec_i64(value, &mut kernel_shape),
+            "strides" => attr_value_vec_i64(value, &mut strides),
+            "dilations" => attr_value_vec_i64(value, &mut dilations),
+            "pads" => attr_value_vec_i64(value, &mut pads),
+            "output_padding" => attr_value_vec_i64(value, &mut output_padding),
+            "group" => attr_value_i64(value, &mut group),
+            "output_shape" => panic!("ConvTranspose: output_shape is not supported, use pads"),
+            "auto_pad" => match value {
+                AttributeValue::String(auto_pad) if auto_pad == "NOTSET" => {}
+                _ => panic!("ConvTranspose: auto_pad is not supported, use pads"),
+            },
+            _ => {}
+        }
+    }
+
+    // the pads are the begin of each axis followed by the end of each axis
+    let (begin, end) = pads.split_at(spatial_dims);
+    if begin != end {
+        panic!("ConvTranspose: asymmetric padding {pads:?} is not supported");
+    }
+
+    let to_usize = |values: Vec<i64>| -> Vec<usize> {
+        values
+            .into_iter()
+            .map(|value| {
+                usize::try_from(value)
+                    .unwrap_or_else(|_| panic!("ConvTranspose: negative value {value}"))
+            })
+            .collect()
+    };
+
+    ConvTransposeAttributes {
+        channels: [shape[0], shape[1] * group as usize],
+        kernel_shape: to_usize(kernel_shape),
+        strides: to_usize(strides),
+        dilations: to_usize(dilations),
+        pads: to_usize(begin.to_vec()),
+        output_padding: to_usize(output_padding),
+        group: group as usize,
+        bias: curr.states.len() == 2,
+    }
+}
+
+/// Create a ConvTranspose1dConfig from the attributes of the node
+pub fn conv_transpose1d_config(curr: &Node) -> ConvTranspose1dConfig {
+    let attrs = conv_transpose_attributes(curr, 1);
+
+    ConvTranspose1dConfig::new(attrs.channels, attrs.kernel_shape[0])
+        .with_stride(attrs.strides[0])
+        .with_dilation(attrs.dilations[0])
+        .with_groups(attrs.group)
+        .with_padding(attrs.pads[0])
+        .with_padding_out(attrs.output_padding[0])
+        .with_bias(attrs.bias)
+}
+
+/// Create a ConvTranspose2dConfig from the attributes of the node
+pub fn conv_transpose2d_config(curr: &Node) -> ConvTranspose2dConfig {
+    let attrs = conv_transpose_attributes(curr, 2);
+    let pair = |values: Vec<usize>| [values[0], values[1]];
+
+    ConvTranspose2dConfig::new(attrs.channels, pair(attrs.kernel_shape))
+        .with_stride(pair(attrs.strides))
+        .with_dilation(pair(attrs.dilations))
+        .with_groups(attrs.group)
+        .with_padding(pair(attrs.pads))
+        .with_padding_out(pair(attrs.output_padding))
+        .with_bias(attrs.bias)
+}
+
 /// Create a MaxPool2dConfig from the attributes of the node
 pub fn max_pool2d_config(curr: &Node) -> MaxPool2dConfig {
     let mut kernel_shape = Vec::new();
@@ -247,6 +362,88 @@ pub fn linear_config(node: &Node) -> LinearConfig {
     LinearConfig::new(in_size, out_size).with_bias(bias)
 }
 
+/// Extract the alpha, beta, transA and transB attributes of a Gemm node
+pub fn gemm_config(node: &Node) -> (f64, f64, bool, bool) {
+    // Default values from the ONNX specification
+    let mut alpha = 1f32;
+    let mut beta = 1f32;
+    let mut trans_a = 0i64;
+    let mut trans_b = 0i64;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "alpha" => attr_value_f32(value, &mut alpha),
+            "beta" => attr_value_f32(value, &mut beta),
+            "transA" => attr_value_i64(value, &mut trans_a),
+            "transB" => attr_value_i64(value, &mut trans_b),
+            _ => {}
+        }
+    }
+
+    (alpha as f64, beta as f64, trans_a != 0, trans_b != 0)
+}
+
+/// Create the alpha and beta of a HardSigmoid node.
+pub fn hard_sigmoid_config(node: &Node) -> (f32, f32) {
+    let mut alpha = 0.2;
+    let mut beta = 0.5;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "alpha" => attr_value_f32(value, &mut alpha),
+            "beta" => attr_value_f32(value, &mut beta),
+            _ => {}
+        }
+    }
+
+    (alpha, beta)
+}
+
+/// Create a LeakyReLUConfig from the alpha attribute of the node
+pub fn leaky_relu_config(node: &Node) -> LeakyReLUConfig {
+    let mut alpha = 0.01;
+
+    if let Some(value) = node.attrs.get("alpha") {
+        attr_value_f32(value, &mut alpha);
+    }
+
+    LeakyReLUConfig::new().with_negative_slope(alpha as f64)
+}
+
+/// Create a PReLUConfig from the slope state of the node
+///
+/// The slope is broadcast to the input, so it is either shared by all the elements or holds a
+/// value per channel, on the second axis of the input.
+pub fn prelu_config(node: &Node) -> PReLUConfig {
+    let tensor = match node.inputs.first().unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("PRelu: only tensor input is valid"),
+    };
+
+    if node.states.len() != 1 {
+        panic!("PRelu: the slope must be an initializer or a constant");
+    }
+    let StateType::Tensor(slope) = &node.states[0].ty;
+    let shape = slope.shape.clone().unwrap_or_default();
+    let num_parameters = shape.iter().product::<usize>();
+
+    if num_parameters > 1 {
+        // The slope is aligned with the last axes of the input.
+        let axis = shape
+            .iter()
+            .position(|size| *size == num_parameters)
+            .and_then(|axis| (tensor.dim + axis).checked_sub(shape.len()));
+
+        if axis != Some(1) {
+            panic!(
+                "PRelu: the slope of shape {shape:?} must be shared or per channel on the second axis"
+            );
+        }
+    }
+
+    PReLUConfig::new().with_num_parameters(num_parameters)
+}
+
 /// Create a DropoutConfig from an attribute and state of the node
 pub fn dropout_config(node: &Node) -> DropoutConfig {
     // Opset 7 and older store probability as an attribute
@@ -380,8 +577,9 @@ pub fn batch_norm_config(node: &Node) -> BatchNormConfig {
 
     let num_features: usize = tensor.shape.unwrap()[0];
 
-    let mut epsilon = 0f32;
-    let mut momentum = 0f32;
+    // Default values from the ONNX specification
+    let mut epsilon = 1e-5f32;
+    let mut momentum = 0.9f32;
 
     for (key, value) in node.attrs.iter() {
         match key.as_str() {
@@ -391,9 +589,118 @@ pub fn batch_norm_config(node: &Node) -> BatchNormConfig {
         }
     }
 
+    // ONNX weights the running statistics by the momentum, while burn weights the new batch
+    // statistics by it: running = running * momentum + batch * (1 - momentum)
+    //
+    // In training mode, the node normalizes the input with the batch statistics, as the module
+    // does when the running statistics aren't tracked.
     BatchNormConfig::new(num_features)
         .with_epsilon(epsilon as f64)
-        .with_momentum(momentum as f64)
+        .with_momentum(1.0 - momentum as f64)
+        .with_track_running_stats(!batch_norm_training_mode(node))
+}
+
+/// Check if the BatchNormalization node is exported in training mode
+pub fn batch_norm_training_mode(node: &Node) -> bool {
+    let mut training_mode = 0i64;
+
+    if let Some(value) = node.attrs.get("training_mode") {
+        attr_value_i64(value, &mut training_mode);
+    }
+
+    training_mode != 0
+}
+
+/// Create a GroupNormConfig from the attributes of the node
+///
+/// The scale and bias hold a value per group before opset 21 and a value per channel
+/// afterwards, so the number of channels is taken from the shape of the input when it is
+/// declared in the model.
+pub fn group_norm_config(node: &Node) -> GroupNormConfig {
+    let input = match node.inputs.first().unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("GroupNormalization: only tensor input is valid"),
+    };
+    let StateType::Tensor(scale) = node.states.get(0).unwrap().clone().ty;
+    let num_scales: usize = scale.shape.unwrap().iter().product();
+
+    let mut epsilon = 1e-5f32;
+    let mut num_groups = None;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "epsilon" => attr_value_f32(value, &mut epsilon),
+            "num_groups" => match value {
+                AttributeValue::Int64(num) => num_groups = Some(*num as usize),
+                _ => panic!("GroupNormalization: the num_groups attribute must be an integer"),
+            },
+            _ => {}
+        }
+    }
+
+    let Some(num_groups) = num_groups else {
+        panic!("GroupNormalization: the num_groups attribute is required");
+    };
+
+    let num_channels = input
+        .static_shape
+        .and_then(|shape| shape.get(1).copied().flatten());
+    let num_channels = match num_channels {
+        Some(num_channels) => num_channels,
+        None if num_scales != num_groups => num_scales,
+        None => panic!(
+            "GroupNormalization: the number of channels of the input must be declared in the model to expand the scale and bias of each group"
+        ),
+    };
+
+    if num_scales != num_channels && num_scales != num_groups {
+        panic!(
+            "GroupNormalization: expected a scale of {num_groups} groups or {num_channels} channels, got {num_scales} values"
+        );
+    }
+
+    GroupNormConfig::new(num_groups, num_channels).with_epsilon(epsilon as f64)
+}
+
+/// Create a LayerNormConfig from the attributes and scale of the node
+pub fn layer_norm_config(node: &Node) -> LayerNormConfig {
+    let tensor = match node.inputs.first().unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("LayerNormalization: only tensor input is valid"),
+    };
+
+    let mut axis = -1;
+    let mut epsilon = 1e-5f32;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "axis" => attr_value_i64(value, &mut axis),
+            "epsilon" => attr_value_f32(value, &mut epsilon),
+            _ => {}
+        }
+    }
+
+    if axis < 0 {
+        axis += tensor.dim as i64;
+    }
+    if axis != tensor.dim as i64 - 1 {
+        panic!("LayerNormalization: only the normalization over the last axis is supported, got axis {axis}");
+    }
+
+    // The scale holds one value per feature of the last axis.
+    let StateType::Tensor(scale) = &node
+        .states
+        .first()
+        .expect("LayerNormalization: the scale must be an initializer or a constant")
+        .ty;
+    let shape = scale.shape.clone().unwrap_or_default();
+    let d_model = shape.last().copied().unwrap_or(1);
+
+    if shape.iter().product::<usize>() != d_model {
+        panic!("LayerNormalization: the scale of shape {shape:?} must be a vector");
+    }
+
+    LayerNormConfig::new(d_model).with_epsilon(epsilon as f64)
 }
 
 /// Calculate the padding configuration for a 2D operations such as Convolution and Pooling.
@@ -434,7 +741,111 @@ fn padding_config(pads: &[i64]) -> PaddingConfig2d {
     }
 }
 
-pub fn reshape_config(node: &Node) -> Vec<i64> {
+/// Create a LstmConfig from the attributes and the states of the node, along with the direction
+/// and whether the layout is batch first.
+///
+/// The states must have been unpacked per gate, so the first state is the input weight of the
+/// input gate with a shape of [input_size, hidden_size].
+pub fn lstm_config(node: &Node) -> (LstmConfig, RnnDirection, bool) {
+    let mut hidden_size = 0i64;
+    let mut layout = 0i64;
+    let mut input_forget = 0i64;
+    let mut direction = RnnDirection::Forward;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "hidden_size" => attr_value_i64(value, &mut hidden_size),
+            "layout" => attr_value_i64(value, &mut layout),
+            "input_forget" => attr_value_i64(value, &mut input_forget),
+            "direction" => direction = rnn_direction("LSTM", value),
+            "activations" => check_rnn_activations("LSTM", value, &["Sigmoid", "Tanh", "Tanh"]),
+            "clip" => panic!("LSTM: clip is not supported"),
+            _ => {}
+        }
+    }
+
+    if input_forget != 0 {
+        panic!("LSTM: input_forget is not supported");
+    }
+
+    let StateType::Tensor(tensor) = node.states.get(0).expect("LSTM: W is required").clone().ty;
+    let d_input = tensor.shape.unwrap()[0];
+
+    // Each gate of each direction has an input and a hidden weight, and optionally their biases
+    let bias = node.states.len() == direction.num_directions() * 4 * 4;
+
+    let config = LstmConfig::new(d_input, hidden_size as usize, bias);
+
+    (config, direction, layout != 0)
+}
+
+/// Create a GruConfig from the attributes and the states of the node, along with the direction
+/// and whether the layout is batch first.
+///
+/// The states must have been unpacked per gate, so the first state is the input weight of the
+/// update gate with a shape of [input_size, hidden_size].
+pub fn gru_config(node: &Node) -> (GruConfig, RnnDirection, bool) {
+    let mut hidden_size = 0i64;
+    let mut layout = 0i64;
+    let mut linear_before_reset = 0i64;
+    let mut direction = RnnDirection::Forward;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "hidden_size" => attr_value_i64(value, &mut hidden_size),
+            "layout" => attr_value_i64(value, &mut layout),
+            "linear_before_reset" => attr_value_i64(value, &mut linear_before_reset),
+            "direction" => direction = rnn_direction("GRU", value),
+            "activations" => check_rnn_activations("GRU", value, &["Sigmoid", "Tanh"]),
+            "clip" => panic!("GRU: clip is not supported"),
+            _ => {}
+        }
+    }
+
+    let StateType::Tensor(tensor) = node.states.get(0).expect("GRU: W is required").clone().ty;
+    let d_input = tensor.shape.unwrap()[0];
+
+    // Each gate of each direction has an input and a hidden weight, and optionally their biases
+    let bias = node.states.len() == direction.num_directions() * 3 * 4;
+
+    // With linear_before_reset, the reset gate is applied after the hidden transformation
+    let config = GruConfig::new(d_input, hidden_size as usize, bias)
+        .with_reset_after(linear_before_reset != 0);
+
+    (config, direction, layout != 0)
+}
+
+fn rnn_direction(op: &str, value: &AttributeValue) -> RnnDirection {
+    match value {
+        AttributeValue::String(direction) => match direction.as_str() {
+            "forward" => RnnDirection::Forward,
+            "reverse" => RnnDirection::Reverse,
+            "bidirectional" => RnnDirection::Bidirectional,
+            _ => panic!("{op}: unknown direction {direction}"),
+        },
+        _ => panic!("{op}: direction must be a string"),
+    }
+}
+
+// The activations are listed for every direction
+fn check_rnn_activations(op: &str, value: &AttributeValue, defaults: &[&str]) {
+    let AttributeValue::Strings(activations) = value else {
+        panic!("{op}: activations must be strings");
+    };
+
+    if activations
+        .chunks(defaults.len())
+        .any(|activations| activations != defaults)
+    {
+        panic!("{op}: only the default activations are supported (got {activations:?})");
+    }
+}
+
+/// Create the target shape of a Reshape node.
+///
+/// The shape is either constant, or its sizes are read from the shapes computed by the graph, as
+/// recorded by the folding of the shape computations.
+pub fn reshape_config(node: &Node) -> Vec<ShapeDim> {
     let mut allowzero = 0;
 
     for (key, value) in node.attrs.iter() {
@@ -450,17 +861,52 @@ pub fn reshape_config(node: &Node) -> Vec<i64> {
         panic!("Zero shape size is not supported");
     }
 
-    let shape = match node.states.first() {
-        Some(state) => match &state.ty {
+    match (shape_dims(node, "shape"), node.states.first()) {
+        (Some(shape), _) => shape,
+        (None, Some(state)) => match &state.ty {
             StateType::Tensor(tensor) => match tensor.data.as_ref() {
-                Some(TensorData::Int64(data)) => data.clone(),
+                Some(TensorData::Int64(data)) => {
+                    data.iter().copied().map(ShapeDim::Static).collect()
+                }
                 _ => panic!("Reshape: invalid state data for shape"),
             },
         },
-        None => panic!("Reshape: missing state required for shape"),
+        _ => panic!("Reshape: missing state required for shape"),
+    }
+}
+
+/// The sizes of a list attribute, the ones at the positions listed in its `<name>_inputs`
+/// attribute being read during the forward pass from the computed shape given by that input of
+/// the node, and -1 meaning a constant size.
+///
+/// The sizes are read from the computed shapes when they are recorded by the folding of the shape
+/// computations.
+fn shape_dims(node: &Node, name: &str) -> Option<Vec<ShapeDim>> {
+    let Some(AttributeValue::Int64s(values)) = node.attrs.get(name) else {
+        return None;
+    };
+    let inputs = match node.attrs.get(&format!("{name}_inputs")) {
+        Some(AttributeValue::Int64s(inputs)) => inputs.clone(),
+        _ => vec![-1; values.len()],
     };
 
-    shape
+    let dims = values
+        .iter()
+        .zip(inputs)
+        .map(|(value, input)| match input {
+            -1 => ShapeDim::Static(*value),
+            input => {
+                let shape = node.inputs[input as usize].to_shape_type();
+                let index = match *value < 0 {
+                    true => value + shape.rank as i64,
+                    false => *value,
+                };
+                ShapeDim::Runtime(shape, index as usize)
+            }
+        })
+        .collect();
+
+    Some(dims)
 }
 
 /// Calculate the padding configuration for a 1D operations such as Convolution and Pooling.
@@ -500,3 +946,1633 @@ fn padding_config_1d(pads: &[i64]) -> PaddingConfig1d {
         panic!("Padding configuration ({:?}) not supported", pads);
     }
 }
+
+/// Create the min and max of a Clip node, which are unbounded when missing.
+///
+/// They are attributes before opset 11 and constant inputs afterwards, which are moved to the
+/// attributes when the graph is coalesced.
+pub fn clip_config(node: &Node) -> (Option<f64>, Option<f64>) {
+    let mut min = None;
+    let mut max = None;
+
+    if node.inputs.len() != 1 {
+        panic!("Clip: the min and max inputs must be constant");
+    }
+
+    for (key, value) in node.attrs.iter() {
+        let mut bound = f32::NAN;
+        attr_value_f32(value, &mut bound);
+        let bound = bound.is_finite().then_some(bound as f64);
+
+        match key.as_str() {
+            "min" => min = bound,
+            "max" => max = bound,
+            _ => {}
+        }
+    }
+
+    (min, max)
+}
+
+/// Create the axes and keepdims flag of a reduction (ReduceMean, ReduceSum, ReduceMax).
+///
+/// The axes are `None` when all of them are reduced, and empty when the reduction is a no-op.
+pub fn reduce_config(node: &Node) -> (Option<Vec<usize>>, bool) {
+    let mut axes = Vec::new();
+    let mut keepdims = 1;
+    let mut noop_with_empty_axes = 0;
+
+    if node.inputs.len() != 1 {
+        panic!("{}: the axes input must be constant", node.node_type);
+    }
+
+    let tensor = match node.inputs.first().unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("{}: only tensor input is valid", node.node_type),
+    };
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "axes" => attr_value_vec_i64(value, &mut axes),
+            "keepdims" => attr_value_i64(value, &mut keepdims),
+            "noop_with_empty_axes" => attr_value_i64(value, &mut noop_with_empty_axes),
+            _ => {}
+        }
+    }
+
+    if axes.is_empty() {
+        let axes = (noop_with_empty_axes != 0).then(Vec::new);
+        return (axes, keepdims != 0);
+    }
+
+    // if an axis is negative, it is counted from the end
+    let mut axes = axes
+        .into_iter()
+        .map(|axis| match axis < 0 {
+            true => (tensor.dim as i64 + axis) as usize,
+            false => axis as usize,
+        })
+        .collect::<Vec<_>>();
+    axes.sort_unstable();
+    axes.dedup();
+
+    (Some(axes), keepdims != 0)
+}
+
+/// Create the axis, keepdims and select_last_index flags of an index reduction (ArgMax, ArgMin).
+pub fn arg_reduce_config(node: &Node) -> (usize, bool, bool) {
+    let mut axis: i64 = 0;
+    let mut keepdims = 1;
+    let mut select_last_index = 0;
+
+    if node.inputs.len() != 1 {
+        panic!("{}: multiple inputs are not supported", node.node_type);
+    }
+
+    let tensor = match node.inputs.first().unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("{}: only tensor input is valid", node.node_type),
+    };
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "axis" => attr_value_i64(value, &mut axis),
+            "keepdims" => attr_value_i64(value, &mut keepdims),
+            "select_last_index" => attr_value_i64(value, &mut select_last_index),
+            _ => {}
+        }
+    }
+
+    // if axis is negative, it is counted from the end
+    if axis < 0 {
+        axis += tensor.dim as i64;
+    }
+
+    (axis as usize, keepdims != 0, select_last_index != 0)
+}
+
+/// Whether a Gelu node is approximated with tanh.
+pub fn gelu_config(node: &Node) -> bool {
+    match node.attrs.get("approximate") {
+        Some(AttributeValue::String(approximate)) => match approximate.as_str() {
+            "none" => false,
+            "tanh" => true,
+            _ => panic!("Gelu: unsupported approximation {approximate}"),
+        },
+        _ => false,
+    }
+}
+
+/// Create the axis and the sizes of a Split node.
+///
+/// The sizes are an attribute before opset 13 and a constant input afterwards, which is moved to
+/// the attributes when the graph is coalesced. Without them, the parts have equal sizes.
+pub fn split_config(node: &Node) -> (usize, Option<Vec<usize>>) {
+    let mut axis: i64 = 0;
+    let mut sizes = None;
+
+    if node.inputs.len() != 1 {
+        panic!("Split: the split input must be constant");
+    }
+
+    let tensor = match node.inputs.first().unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("Split: only tensor input is valid"),
+    };
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "axis" => attr_value_i64(value, &mut axis),
+            "split" => {
+                let mut values = Vec::new();
+                attr_value_vec_i64(value, &mut values);
+                sizes = Some(values.into_iter().map(|size| size as usize).collect());
+            }
+            _ => {}
+        }
+    }
+
+    // if axis is negative, it is counted from the end
+    if axis < 0 {
+        axis += tensor.dim as i64;
+    }
+
+    (axis as usize, sizes)
+}
+
+/// Create the target shape of an Expand node, with the rank of the output.
+///
+/// The shape is either computed by a Shape node, or a constant input, which is moved to the
+/// attributes when the graph is coalesced, whose sizes may be read from the computed shapes. A
+/// shape of a lower rank than the input is broadcast to it, which prepends sizes of 1.
+pub fn expand_config(node: &Node) -> ShapeValue {
+    let tensor = match node.inputs.first().unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("Expand: only tensor input is valid"),
+    };
+
+    let shape = match (shape_dims(node, "shape"), node.inputs.get(1)) {
+        (Some(shape), _) => shape,
+        (None, Some(shape)) if matches!(shape.ty, ArgType::Shape(_)) => {
+            let shape = shape.to_shape_type();
+            if shape.rank < tensor.dim {
+                panic!("Expand: the computed shape must have at least the rank of the input");
+            }
+
+            return ShapeValue::Runtime(shape);
+        }
+        (None, Some(_)) => panic!("Expand: the shape input must be constant"),
+        (None, None) => Vec::new(),
+    };
+
+    if shape
+        .iter()
+        .any(|size| matches!(size, ShapeDim::Static(size) if *size < 0))
+    {
+        panic!("Expand: negative sizes are not valid: {shape:?}");
+    }
+
+    let mut target = vec![ShapeDim::Static(1); tensor.dim.saturating_sub(shape.len())];
+    target.extend(shape);
+
+    ShapeValue::from_dims(target)
+}
+
+/// Create the range of dimensions `[start, end)` returned by a Shape node.
+pub fn shape_config(node: &Node) -> (usize, usize) {
+    if node.inputs.len() != 1 {
+        panic!("Shape: multiple inputs are not supported: {:?}", node);
+    }
+
+    let tensor = match node.inputs.first().unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("Shape: only tensor input is valid"),
+    };
+
+    let rank = tensor.dim as i64;
+    let mut start: i64 = 0;
+    let mut end: i64 = rank;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "start" => attr_value_i64(value, &mut start),
+            "end" => attr_value_i64(value, &mut end),
+            _ => {}
+        }
+    }
+
+    // Negative values count from the last dimension and the range is clamped to the rank.
+    let clamp = |value: i64| match value < 0 {
+        true => (value + rank).max(0),
+        false => value.min(rank),
+    } as usize;
+    let (start, end) = (clamp(start), clamp(end));
+
+    (start, end.max(start))
+}
+
+/// Create the shape and the value of a ConstantOfShape node.
+///
+/// The shape is either computed by a Shape node, or constant with sizes that may be read from the
+/// computed shapes.
+pub fn constant_of_shape_config(node: &Node) -> (ShapeValue, FillValue) {
+    let shape = match (shape_dims(node, "shape"), node.inputs.first()) {
+        (Some(shape), _) => {
+            if shape
+                .iter()
+                .any(|size| matches!(size, ShapeDim::Static(size) if *size < 0))
+            {
+                panic!("ConstantOfShape: negative sizes are not valid: {shape:?}");
+            }
+            ShapeValue::from_dims(shape)
+        }
+        (None, Some(shape)) if matches!(shape.ty, ArgType::Shape(_)) => {
+            ShapeValue::Runtime(shape.to_shape_type())
+        }
+        _ => panic!("ConstantOfShape: the shape must be constant or computed by a Shape node"),
+    };
+
+    // The value is a tensor of one element, zero of type float by default.
+    let value = match node.attrs.get("value") {
+        Some(AttributeValue::Tensor(tensor)) => match &tensor.data {
+            Some(TensorData::Float16(values)) => FillValue::Float(values[0].to_f64()),
+            Some(TensorData::Float32(values)) => FillValue::Float(values[0] as f64),
+            Some(TensorData::Float64(values)) => FillValue::Float(values[0]),
+            Some(TensorData::Int32(values)) => FillValue::Int(values[0] as i64),
+            Some(TensorData::Int64(values)) => FillValue::Int(values[0]),
+            Some(TensorData::Bool(values)) => FillValue::Bool(values[0]),
+            _ => panic!("ConstantOfShape: unsupported value {:?}", tensor),
+        },
+        Some(_) => panic!("ConstantOfShape: the value must be a tensor"),
+        None => FillValue::Float(0.0),
+    };
+
+    (shape, value)
+}
+
+/// Create the labels of the inputs and of the output from the equation of an Einsum node.
+///
+/// Without an explicit output, the output labels are the ones appearing once, in alphabetical
+/// order.
+pub fn einsum_config(node: &Node) -> (Vec<Vec<char>>, Vec<char>) {
+    let equation = match node.attrs.get("equation") {
+        Some(AttributeValue::String(equation)) => equation.replace(' ', ""),
+        _ => panic!("Einsum: the equation is required"),
+    };
+
+    if equation.contains("...") {
+        panic!("Einsum: the ellipsis in {equation} is not supported");
+    }
+
+    let (inputs, output) = match equation.split_once("->") {
+        Some((inputs, output)) => (inputs, Some(output)),
+        None => (equation.as_str(), None),
+    };
+
+    let input_labels = inputs
+        .split(',')
+        .map(|labels| labels.chars().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let all_labels = input_labels.concat();
+
+    let output_labels = match output {
+        Some(output) => output.chars().collect::<Vec<_>>(),
+        None => {
+            let mut labels = all_labels
+                .iter()
+                .filter(|label| all_labels.iter().filter(|l| l == label).count() == 1)
+                .copied()
+                .collect::<Vec<_>>();
+            labels.sort();
+            labels
+        }
+    };
+
+    if input_labels.len() != node.inputs.len() {
+        panic!(
+            "Einsum: the equation {equation} has {} inputs instead of {}",
+            input_labels.len(),
+            node.inputs.len()
+        );
+    }
+
+    for labels in input_labels.iter().chain([&output_labels]) {
+        let mut unique = labels.clone();
+        unique.sort();
+        unique.dedup();
+
+        if unique.len() != labels.len() {
+            panic!("Einsum: the repeated labels in {equation} are not supported");
+        }
+        if labels.iter().any(|label| !label.is_ascii_alphabetic()) {
+            panic!("Einsum: invalid equation {equation}");
+        }
+    }
+
+    if output_labels
+        .iter()
+        .any(|label| !all_labels.contains(label))
+    {
+        panic!("Einsum: the output labels of {equation} must be in the inputs");
+    }
+
+    (input_labels, output_labels)
+}
+
+/// Create the depth, the `off` and `on` values and the axis of a OneHot node.
+pub fn one_hot_config(node: &Node) -> (usize, [f64; 2], usize) {
+    let tensor = match node.inputs.first().unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("OneHot: only tensor input is valid"),
+    };
+
+    if node.inputs.len() != 1 || node.states.len() != 2 {
+        panic!("OneHot: the depth and values inputs must be constant");
+    }
+
+    let values = node
+        .states
+        .iter()
+        .map(|state| {
+            let StateType::Tensor(tensor) = &state.ty;
+            match tensor.data.as_ref() {
+                Some(TensorData::Float16(values)) => values.iter().map(|v| v.to_f64()).collect(),
+                Some(TensorData::Float32(values)) => values.iter().map(|v| *v as f64).collect(),
+                Some(TensorData::Float64(values)) => values.clone(),
+                Some(TensorData::Int32(values)) => values.iter().map(|v| *v as f64).collect(),
+                Some(TensorData::Int64(values)) => values.iter().map(|v| *v as f64).collect(),
+                _ => panic!("OneHot: the depth and values must be numbers"),
+            }
+        })
+        .collect::<Vec<Vec<f64>>>();
+
+    let depth = match values[0].as_slice() {
+        [depth] if *depth >= 1.0 => *depth as usize,
+        depth => panic!("OneHot: the depth must be a positive scalar, got {depth:?}"),
+    };
+    let values = match values[1].as_slice() {
+        [off, on] => [*off, *on],
+        values => panic!("OneHot: the values must be [off, on], got {values:?}"),
+    };
+
+    // The axis is in the output, which has one more dimension than the indices.
+    let mut axis = -1;
+    if let Some(value) = node.attrs.get("axis") {
+        attr_value_i64(value, &mut axis);
+    }
+    if axis < 0 {
+        axis += tensor.dim as i64 + 1;
+    }
+    if axis < 0 || axis > tensor.dim as i64 {
+        panic!("OneHot: the axis is out of range");
+    }
+
+    (depth, values, axis as usize)
+}
+
+/// Create the axis and the reduction of a ScatterElements node.
+pub fn scatter_elements_config(node: &Node) -> (usize, ScatterReduction) {
+    let tensor = match node.inputs.first().unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("{}: only tensor input is valid", node.node_type),
+    };
+
+    let mut axis = 0;
+    if let Some(value) = node.attrs.get("axis") {
+        attr_value_i64(value, &mut axis);
+    }
+    if axis < 0 {
+        axis += tensor.dim as i64;
+    }
+    if axis < 0 || axis >= tensor.dim as i64 {
+        panic!("{}: the axis is out of range", node.node_type);
+    }
+
+    (axis as usize, scatter_reduction(node))
+}
+
+/// Create the reduction of a ScatterND node.
+pub fn scatter_nd_config(node: &Node) -> ScatterReduction {
+    scatter_reduction(node)
+}
+
+fn scatter_reduction(node: &Node) -> ScatterReduction {
+    match node.attrs.get("reduction") {
+        None => ScatterReduction::None,
+        Some(AttributeValue::String(reduction)) if reduction == "none" => ScatterReduction::None,
+        Some(AttributeValue::String(reduction)) if reduction == "add" => ScatterReduction::Add,
+        Some(value) => panic!("{}: unsupported reduction {value:?}", node.node_type),
+    }
+}
+
+/// Create the box format of a NonMaxSuppression node, which is whether the boxes are given by their
+/// center and size instead of their corners.
+pub fn non_max_suppression_config(node: &Node) -> bool {
+    match node.attrs.get("center_point_box") {
+        None | Some(AttributeValue::Int64(0)) => false,
+        Some(AttributeValue::Int64(1)) => true,
+        Some(value) => panic!("NonMaxSuppression: unsupported center_point_box {value:?}"),
+    }
+}
+
+/// Create the axes the scan inputs of a Scan node are sliced along and the axes the scan outputs are
+/// stacked along.
+pub fn scan_config(node: &Node) -> (Vec<ScanAxis>, Vec<ScanAxis>) {
+    if node.attrs.contains_key("directions") {
+        panic!("Scan: the directions attribute of opset 8 is not supported");
+    }
+
+    let num_scan_inputs = match node.attrs.get("num_scan_inputs") {
+        Some(AttributeValue::Int64(num_scan_inputs)) => *num_scan_inputs as usize,
+        _ => panic!("Scan: the num_scan_inputs attribute is missing"),
+    };
+    let num_states = match node.attrs.get("body") {
+        Some(AttributeValue::Graph(body)) => body.inputs.len() - num_scan_inputs,
+        _ => panic!("Scan: the body graph is missing"),
+    };
+
+    // The axes count from the end when negative, and the directions are reversed when 1
+    let axes = |kind: &str, values: &[Argument]| {
+        let attr = |name: String| match node.attrs.get(&name) {
+            Some(AttributeValue::Int64s(ints)) => ints.clone(),
+            _ => vec![0; values.len()],
+        };
+        let axes = attr(format!("scan_{kind}_axes"));
+        let directions = attr(format!("scan_{kind}_directions"));
+
+        values
+            .iter()
+            .zip(axes)
+            .zip(directions)
+            .map(|((value, axis), direction)| {
+                let rank = match &value.ty {
+                    ArgType::Tensor(tensor) => tensor.dim as i64,
+                    _ => panic!("Scan: only tensor scan {kind}s are supported"),
+                };
+                let axis = match axis < 0 {
+                    true => axis + rank,
+                    false => axis,
+                };
+
+                ScanAxis::new(axis as usize, direction == 1)
+            })
+            .collect::<Vec<_>>()
+    };
+
+    (
+        axes(
+            "input",
+            &node.inputs[num_states..num_states + num_scan_inputs],
+        ),
+        axes("output", &node.outputs[num_states..]),
+    )
+}
+
+/// Create the block size and mode of a DepthToSpace node.
+pub fn depth_to_space_config(node: &Node) -> (usize, DepthToSpaceMode) {
+    let mut mode = DepthToSpaceMode::Dcr;
+
+    if let Some(value) = node.attrs.get("mode") {
+        mode = match value {
+            AttributeValue::String(mode) if mode == "DCR" => DepthToSpaceMode::Dcr,
+            AttributeValue::String(mode) if mode == "CRD" => DepthToSpaceMode::Crd,
+            _ => panic!("DepthToSpace: unsupported mode {value:?}"),
+        };
+    }
+
+    (block_size_config(node), mode)
+}
+
+/// Create the block size of a SpaceToDepth node.
+pub fn space_to_depth_config(node: &Node) -> usize {
+    block_size_config(node)
+}
+
+fn block_size_config(node: &Node) -> usize {
+    match node.attrs.get("blocksize") {
+        Some(AttributeValue::Int64(block_size)) if *block_size > 0 => *block_size as usize,
+        _ => panic!(
+            "{}: the blocksize must be a positive integer",
+            node.node_type
+        ),
+    }
+}
+
+/// Create the number of repetitions of each axis of a Tile node.
+pub fn tile_config(node: &Node) -> Vec<ShapeDim> {
+    let tensor = match node.inputs.first().unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("Tile: only tensor input is valid"),
+    };
+
+    let repeats = match shape_dims(node, "repeats") {
+        Some(repeats) => repeats,
+        None if node.inputs.len() != 1 => panic!("Tile: the repeats input must be constant"),
+        None => Vec::new(),
+    };
+
+    if repeats.len() != tensor.dim {
+        panic!(
+            "Tile: {} repeats are given for an input of rank {}",
+            repeats.len(),
+            tensor.dim
+        );
+    }
+    if repeats
+        .iter()
+        .any(|times| matches!(times, ShapeDim::Static(times) if *times < 0))
+    {
+        panic!("Tile: negative repeats are not valid: {repeats:?}");
+    }
+
+    repeats
+}
+
+/// Create the axis of a Gather node.
+pub fn gather_config(node: &Node) -> usize {
+    let mut axis: i64 = 0;
+
+    let tensor = match node.inputs.get(0).unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("Gather: only tensor input is valid"),
+    };
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "axis" => attr_value_i64(value, &mut axis),
+            _ => {}
+        }
+    }
+
+    // if axis is negative, it is counted from the end
+    if axis < 0 {
+        axis += tensor.dim as i64;
+    }
+
+    axis as usize
+}
+
+/// Create an EmbeddingConfig from the weights of an Embedding node.
+pub fn embedding_config(node: &Node) -> EmbeddingConfig {
+    let StateType::Tensor(tensor) = &node.states.get(0).expect("Embedding: no state found").ty;
+
+    match tensor.shape.as_deref() {
+        Some([n_embedding, d_model]) => EmbeddingConfig::new(*n_embedding, *d_model),
+        shape => panic!(
+            "Embedding: weights must have 2 dimensions (got {:?})",
+            shape
+        ),
+    }
+}
+
+/// Create the paddings `(before, after)` of every dimension and the pad mode from a Pad node.
+///
+/// The paddings, the constant value and the axes are attributes before opset 11 and constant
+/// inputs afterwards.
+pub fn pad_config(node: &Node) -> (Vec<(usize, usize)>, PadMode) {
+    let mut mode = "constant".to_string();
+    let mut pads = None;
+    let mut value = 0.0;
+    let mut axes = None;
+
+    let dim = match node.inputs.get(0).unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor.dim,
+        _ => panic!("Pad: only tensor input is valid"),
+    };
+
+    if node.inputs.len() != 1 {
+        panic!("Pad: the pads, constant_value and axes inputs must be constant");
+    }
+
+    for (key, value_attr) in node.attrs.iter() {
+        match key.as_str() {
+            "mode" => {
+                if let AttributeValue::String(val) = value_attr {
+                    mode = val.clone();
+                }
+            }
+            "pads" => {
+                let mut val = Vec::new();
+                attr_value_vec_i64(value_attr, &mut val);
+                pads = Some(val);
+            }
+            "value" => {
+                let mut val = 0.0;
+                attr_value_f32(value_attr, &mut val);
+                value = val as f64;
+            }
+            _ => {}
+        }
+    }
+
+    // The constant value is a scalar, while the axes are a 1D tensor.
+    let mut states = node.states.iter().map(|state| match &state.ty {
+        StateType::Tensor(tensor) => tensor,
+    });
+
+    if let Some(tensor) = states.next() {
+        pads = Some(tensor_data_i64(tensor.data.as_ref(), "pads"));
+    }
+
+    let (value_state, axes_state) = match states.collect::<Vec<_>>().as_slice() {
+        [] => (None, None),
+        [axes] if axes.dim == 1 => (None, Some(*axes)),
+        [value] => (Some(*value), None),
+        [value, axes] => (Some(*value), Some(*axes)),
+        _ => panic!("Pad: too many inputs"),
+    };
+
+    if let Some(tensor) = value_state {
+        value = match tensor.data.as_ref() {
+            Some(TensorData::Float16(data)) => data[0].to_f64(),
+            Some(TensorData::Float32(data)) => data[0] as f64,
+            Some(TensorData::Float64(data)) => data[0],
+            Some(TensorData::Int32(data)) => data[0] as f64,
+            Some(TensorData::Int64(data)) => data[0] as f64,
+            _ => panic!("Pad: invalid constant_value data"),
+        };
+    }
+
+    if let Some(tensor) = axes_state {
+        axes = Some(tensor_data_i64(tensor.data.as_ref(), "axes"));
+    }
+
+    let pads = pads.expect("Pad: missing pads");
+    let axes = axes
+        .unwrap_or_else(|| (0..dim as i64).collect())
+        .into_iter()
+        .map(|axis| if axis < 0 { axis + dim as i64 } else { axis } as usize)
+        .collect::<Vec<_>>();
+
+    if pads.len() != 2 * axes.len() {
+        panic!(
+            "Pad: expected {} pads for {} axes, got {:?}",
+            2 * axes.len(),
+            axes.len(),
+            pads
+        );
+    }
+
+    if pads.iter().any(|pad| *pad < 0) {
+        panic!("Pad: negative pads are not supported, got {:?}", pads);
+    }
+
+    // The pads are ordered as [x1_begin, x2_begin, ..., x1_end, x2_end, ...].
+    let mut paddings = vec![(0, 0); dim];
+    for (i, axis) in axes.iter().enumerate() {
+        paddings[*axis] = (pads[i] as usize, pads[i + axes.len()] as usize);
+    }
+
+    let mode = match mode.as_str() {
+        "constant" => PadMode::Constant(value),
+        "reflect" => PadMode::Reflect,
+        "edge" => PadMode::Edge,
+        _ => panic!("Pad: mode {mode} is not supported"),
+    };
+
+    (paddings, mode)
+}
+
+/// Create the slice ranges of a Slice node.
+///
+/// The starts, ends, axes and steps are attributes before opset 10 and constant inputs
+/// afterwards, which are moved to the attributes when the graph is coalesced.
+pub fn slice_config(node: &Node) -> Vec<SliceRange> {
+    let mut axes = None;
+    let mut steps = None;
+
+    let dim = match node.inputs.get(0) {
+        Some(input) => match &input.ty {
+            ArgType::Tensor(tensor) => tensor.dim,
+            _ => panic!("Slice: only tensor input is valid"),
+        },
+        // The data is constant when the node is folded.
+        None => match &node.states.get(0).expect("Slice: missing data").ty {
+            StateType::Tensor(tensor) => tensor.dim,
+        },
+    };
+
+    // The starts and ends may be read from the computed shapes.
+    let starts = shape_dims(node, "starts").unwrap_or_default();
+    let ends = shape_dims(node, "ends").unwrap_or_default();
+    let runtime = starts
+        .iter()
+        .chain(ends.iter())
+        .any(|bound| matches!(bound, ShapeDim::Runtime(..)));
+
+    if node.inputs.len() > 1 && !runtime {
+        panic!("Slice: the starts, ends, axes and steps inputs must be constant");
+    }
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "axes" => {
+                let mut val = Vec::new();
+                attr_value_vec_i64(value, &mut val);
+                axes = Some(val);
+            }
+            "steps" => {
+                let mut val = Vec::new();
+                attr_value_vec_i64(value, &mut val);
+                steps = Some(val);
+            }
+            _ => {}
+        }
+    }
+
+    let axes = axes.unwrap_or_else(|| (0..starts.len() as i64).collect());
+    let steps = steps.unwrap_or_else(|| vec![1; starts.len()]);
+
+    if ends.len() != starts.len() || axes.len() != starts.len() || steps.len() != starts.len() {
+        panic!(
+            "Slice: starts {:?}, ends {:?}, axes {:?} and steps {:?} must have the same length",
+            starts, ends, axes, steps
+        );
+    }
+
+    let mut ranges = starts
+        .into_iter()
+        .zip(ends)
+        .zip(axes)
+        .zip(steps)
+        .map(|(((start, end), axis), step)| {
+            if step == 0 {
+                panic!("Slice: step cannot be 0");
+            }
+            if step < 0
+                && matches!(
+                    (&start, &end),
+                    (ShapeDim::Runtime(..), _) | (_, ShapeDim::Runtime(..))
+                )
+            {
+                panic!("Slice: the bounds of a backward slice must be constant");
+            }
+
+            let axis = if axis < 0 { axis + dim as i64 } else { axis };
+            SliceRange::new(axis as usize, start, end, step)
+        })
+        .collect::<Vec<_>>();
+    ranges.sort_by_key(|range| range.axis);
+
+    ranges
+}
+
+fn tensor_data_i64(data: Option<&TensorData>, name: &str) -> Vec<i64> {
+    match data {
+        Some(TensorData::Int64(data)) => data.clone(),
+        Some(TensorData::Int32(data)) => data.iter().map(|value| *value as i64).collect(),
+        _ => panic!("Pad: invalid {name} data"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::ShapeType;
+    use crate::onnx::ir::{ElementType, NodeType, ONNXGraph, State, Tensor, TensorArg};
+    use crate::onnx::test_utils::{config_node, float_input, float_tensor_shape, state};
+    use std::collections::HashMap;
+
+    fn batch_norm_node(attrs: Vec<(&str, AttributeValue)>) -> Node {
+        Node {
+            node_type: NodeType::BatchNormalization,
+            name: "batch_norm1".to_string(),
+            inputs: vec![],
+            outputs: vec![],
+            states: vec![State {
+                name: "scale".to_string(),
+                ty: StateType::Tensor(Tensor {
+                    elem_type: ElementType::Float32,
+                    dim: 1,
+                    data: None,
+                    shape: Some(vec![3]),
+                }),
+            }],
+            attrs: attrs
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn batch_norm_config_default_attributes() {
+        let config = batch_norm_config(&batch_norm_node(vec![]));
+
+        assert_eq!(config.num_features, 3);
+        assert!((config.epsilon - 1e-5).abs() < 1e-9);
+        assert!((config.momentum - 0.1).abs() < 1e-6);
+    }
+
+    #[test]
+    fn batch_norm_config_training_mode_uses_batch_statistics() {
+        let config = batch_norm_config(&batch_norm_node(vec![]));
+        assert!(config.track_running_stats);
+
+        let node = batch_norm_node(vec![("training_mode", AttributeValue::Int64(1))]);
+        let config = batch_norm_config(&node);
+
+        assert!(!config.track_running_stats);
+    }
+
+    #[test]
+    fn batch_norm_config_converts_momentum() {
+        let node = batch_norm_node(vec![
+            ("epsilon", AttributeValue::Float32(1e-3)),
+            ("momentum", AttributeValue::Float32(0.99)),
+            ("training_mode", AttributeValue::Int64(1)),
+        ]);
+
+        let config = batch_norm_config(&node);
+
+        assert!((config.epsilon - 1e-3).abs() < 1e-9);
+        assert!((config.momentum - 0.01).abs() < 1e-6);
+        assert!(batch_norm_training_mode(&node));
+        assert!(!batch_norm_training_mode(&batch_norm_node(vec![])));
+    }
+
+    fn group_norm_node(static_shape: Option<Vec<Option<usize>>>, num_scales: usize) -> Node {
+        let mut input = float_input(4);
+        if let ArgType::Tensor(tensor) = &mut input.ty {
+            tensor.static_shape = static_shape;
+        }
+        let scale = state("scale", float_tensor_shape(vec![num_scales]));
+
+        config_node(
+            NodeType::GroupNormalization,
+            "group_norm1",
+            vec![input],
+            vec![scale],
+            vec![("num_groups", AttributeValue::Int64(2))],
+        )
+    }
+
+    #[test]
+    fn group_norm_config_per_group_scale() {
+        let node = group_norm_node(Some(vec![None, Some(6), Some(8), Some(8)]), 2);
+
+        let config = group_norm_config(&node);
+
+        assert_eq!(config.num_groups, 2);
+        assert_eq!(config.num_channels, 6);
+    }
+
+    #[test]
+    fn group_norm_config_per_channel_scale() {
+        let config = group_norm_config(&group_norm_node(None, 6));
+
+        assert_eq!(config.num_groups, 2);
+        assert_eq!(config.num_channels, 6);
+    }
+
+    #[test]
+    #[should_panic(expected = "number of channels")]
+    fn group_norm_config_per_group_scale_without_input_shape() {
+        group_norm_config(&group_norm_node(None, 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "num_groups")]
+    fn group_norm_config_without_num_groups() {
+        let mut node = group_norm_node(None, 6);
+        node.attrs.clear();
+
+        group_norm_config(&node);
+    }
+
+    #[test]
+    fn gru_config_linear_before_reset() {
+        let weight = State {
+            name: "weight".to_string(),
+            ty: StateType::Tensor(Tensor {
+                elem_type: ElementType::Float32,
+                dim: 2,
+                data: None,
+                shape: Some(vec![4, 3]),
+            }),
+        };
+        let node = Node {
+            node_type: NodeType::GRU,
+            name: "gru1".to_string(),
+            inputs: vec![],
+            outputs: vec![],
+            // Input and hidden weights for the 3 gates of both directions, without biases
+            states: vec![weight; 12],
+            attrs: [
+                ("hidden_size", AttributeValue::Int64(3)),
+                ("linear_before_reset", AttributeValue::Int64(1)),
+                (
+                    "direction",
+                    AttributeValue::String("bidirectional".to_string()),
+                ),
+            ]
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect(),
+        };
+
+        let (config, direction, batch_first) = gru_config(&node);
+
+        assert_eq!(config.d_input, 4);
+        assert_eq!(config.d_hidden, 3);
+        assert!(!config.bias);
+        assert!(config.reset_after);
+        assert_eq!(direction, RnnDirection::Bidirectional);
+        assert!(!batch_first);
+    }
+
+    fn pad_node(states: Vec<(usize, TensorData)>, attrs: Vec<(&str, AttributeValue)>) -> Node {
+        Node {
+            node_type: NodeType::Pad,
+            name: "pad1".to_string(),
+            inputs: vec![Argument {
+                name: "input".to_string(),
+                ty: ArgType::Tensor(TensorArg::new(3, ElementType::Float32)),
+            }],
+            outputs: vec![],
+            states: states
+                .into_iter()
+                .map(|(dim, data)| State {
+                    name: "state".to_string(),
+                    ty: StateType::Tensor(Tensor {
+                        elem_type: ElementType::Int64,
+                        dim,
+                        data: Some(data),
+                        shape: None,
+                    }),
+                })
+                .collect(),
+            attrs: attrs
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn pad_config_attributes() {
+        let node = pad_node(
+            vec![],
+            vec![
+                ("pads", AttributeValue::Int64s(vec![0, 1, 2, 0, 3, 4])),
+                ("value", AttributeValue::Float32(1.5)),
+            ],
+        );
+
+        let (pads, mode) = pad_config(&node);
+
+        assert_eq!(pads, vec![(0, 0), (1, 3), (2, 4)]);
+        assert_eq!(mode, PadMode::Constant(1.5));
+    }
+
+    #[test]
+    fn pad_config_inputs_with_axes() {
+        let node = pad_node(
+            vec![
+                (1, TensorData::Int64(vec![1, 2, 3, 4])),
+                (1, TensorData::Int64(vec![-1, 1])),
+            ],
+            vec![("mode", AttributeValue::String("reflect".to_string()))],
+        );
+
+        let (pads, mode) = pad_config(&node);
+
+        assert_eq!(pads, vec![(0, 0), (2, 4), (1, 3)]);
+        assert_eq!(mode, PadMode::Reflect);
+    }
+
+    #[test]
+    fn pad_config_inputs_with_constant_value() {
+        let node = pad_node(
+            vec![
+                (1, TensorData::Int64(vec![0, 0, 1, 0, 0, 1])),
+                (0, TensorData::Int64(vec![7])),
+            ],
+            vec![],
+        );
+
+        let (pads, mode) = pad_config(&node);
+
+        assert_eq!(pads, vec![(0, 0), (0, 0), (1, 1)]);
+        assert_eq!(mode, PadMode::Constant(7.0));
+    }
+
+    #[test]
+    fn slice_config_attributes() {
+        let node = Node {
+            node_type: NodeType::Slice,
+            name: "slice1".to_string(),
+            inputs: vec![Argument {
+                name: "input".to_string(),
+                ty: ArgType::Tensor(TensorArg::new(3, ElementType::Float32)),
+            }],
+            outputs: vec![],
+            states: vec![],
+            attrs: [
+                ("starts", AttributeValue::Int64s(vec![1, -3])),
+                ("ends", AttributeValue::Int64s(vec![i64::MAX, -1])),
+                ("axes", AttributeValue::Int64s(vec![-1, 0])),
+            ]
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect(),
+        };
+
+        let ranges = slice_config(&node);
+
+        assert_eq!(
+            ranges,
+            vec![
+                SliceRange::new(0, -3, -1, 1),
+                SliceRange::new(2, 1, i64::MAX, 1)
+            ]
+        );
+    }
+
+    fn reduce_node(attrs: Vec<(&str, AttributeValue)>) -> Node {
+        Node {
+            node_type: NodeType::ReduceMean,
+            name: "reducemean1".to_string(),
+            inputs: vec![Argument {
+                name: "input".to_string(),
+                ty: ArgType::Tensor(TensorArg::new(3, ElementType::Float32)),
+            }],
+            outputs: vec![],
+            states: vec![],
+            attrs: attrs
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn reduce_config_negative_axes() {
+        let node = reduce_node(vec![("axes", AttributeValue::Int64s(vec![-1, 0, 2]))]);
+
+        assert_eq!(reduce_config(&node), (Some(vec![0, 2]), true));
+    }
+
+    #[test]
+    fn reduce_config_empty_axes() {
+        let node = reduce_node(vec![("keepdims", AttributeValue::Int64(0))]);
+        assert_eq!(reduce_config(&node), (None, false));
+
+        let node = reduce_node(vec![("noop_with_empty_axes", AttributeValue::Int64(1))]);
+        assert_eq!(reduce_config(&node), (Some(vec![]), true));
+    }
+
+    #[test]
+    fn split_config_negative_axis() {
+        let node = Node {
+            node_type: NodeType::Split,
+            name: "split1".to_string(),
+            inputs: vec![Argument {
+                name: "input".to_string(),
+                ty: ArgType::Tensor(TensorArg::new(3, ElementType::Float32)),
+            }],
+            outputs: vec![],
+            states: vec![],
+            attrs: [
+                ("axis".to_string(), AttributeValue::Int64(-1)),
+                ("split".to_string(), AttributeValue::Int64s(vec![1, 2])),
+            ]
+            .into_iter()
+            .collect(),
+        };
+
+        assert_eq!(split_config(&node), (2, Some(vec![1, 2])));
+    }
+
+    #[test]
+    fn expand_config_broadcasts_the_shape() {
+        let node = Node {
+            node_type: NodeType::Expand,
+            name: "expand1".to_string(),
+            inputs: vec![Argument {
+                name: "input".to_string(),
+                ty: ArgType::Tensor(TensorArg::new(3, ElementType::Float32)),
+            }],
+            outputs: vec![],
+            states: vec![],
+            attrs: [("shape".to_string(), AttributeValue::Int64s(vec![1, 4]))]
+                .into_iter()
+                .collect(),
+        };
+
+        assert_eq!(expand_config(&node), ShapeValue::Static(vec![1, 1, 4]));
+    }
+
+    #[test]
+    fn shape_config_resolves_negative_bounds() {
+        let node = Node {
+            node_type: NodeType::Shape,
+            name: "shape1".to_string(),
+            inputs: vec![Argument {
+                name: "input".to_string(),
+                ty: ArgType::Tensor(TensorArg::new(4, ElementType::Float32)),
+            }],
+            outputs: vec![],
+            states: vec![],
+            attrs: [("start".to_string(), AttributeValue::Int64(-3))]
+                .into_iter()
+                .chain([("end".to_string(), AttributeValue::Int64(-1))])
+                .collect(),
+        };
+
+        assert_eq!(shape_config(&node), (1, 3));
+    }
+
+    #[test]
+    fn constant_of_shape_config_reads_the_value() {
+        let node = Node {
+            node_type: NodeType::ConstantOfShape,
+            name: "constant_of_shape1".to_string(),
+            inputs: vec![],
+            outputs: vec![],
+            states: vec![],
+            attrs: [
+                ("shape".to_string(), AttributeValue::Int64s(vec![2, 3])),
+                (
+                    "value".to_string(),
+                    AttributeValue::Tensor(Tensor {
+                        elem_type: ElementType::Int64,
+                        dim: 1,
+                        data: Some(TensorData::Int64(vec![7])),
+                        shape: Some(vec![1]),
+                    }),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+        };
+
+        let (shape, value) = constant_of_shape_config(&node);
+
+        assert_eq!(shape, ShapeValue::Static(vec![2, 3]));
+        assert!(matches!(value, FillValue::Int(7)));
+    }
+
+    #[test]
+    #[should_panic(expected = "2 repeats are given for an input of rank 3")]
+    fn tile_config_requires_repeats_for_each_axis() {
+        let node = Node {
+            node_type: NodeType::Tile,
+            name: "tile1".to_string(),
+            inputs: vec![Argument {
+                name: "input".to_string(),
+                ty: ArgType::Tensor(TensorArg::new(3, ElementType::Float32)),
+            }],
+            outputs: vec![],
+            states: vec![],
+            attrs: [("repeats".to_string(), AttributeValue::Int64s(vec![2, 3]))]
+                .into_iter()
+                .collect(),
+        };
+
+        tile_config(&node);
+    }
+
+    #[test]
+    fn arg_reduce_config_negative_axis() {
+        let node = Node {
+            node_type: NodeType::ArgMax,
+            name: "argmax1".to_string(),
+            inputs: vec![Argument {
+                name: "input".to_string(),
+                ty: ArgType::Tensor(TensorArg::new(3, ElementType::Float32)),
+            }],
+            outputs: vec![],
+            states: vec![],
+            attrs: [
+                ("axis".to_string(), AttributeValue::Int64(-1)),
+                ("keepdims".to_string(), AttributeValue::Int64(0)),
+                ("select_last_index".to_string(), AttributeValue::Int64(1)),
+            ]
+            .into_iter()
+            .collect(),
+        };
+
+        assert_eq!(arg_reduce_config(&node), (2, false, true));
+    }
+
+    fn prelu_node(input_dim: usize, slope_shape: Vec<usize>) -> Node {
+        Node {
+            node_type: NodeType::PRelu,
+            name: "prelu1".to_string(),
+            inputs: vec![Argument {
+                name: "input".to_string(),
+                ty: ArgType::Tensor(TensorArg::new(input_dim, ElementType::Float32)),
+            }],
+            outputs: vec![],
+            states: vec![State {
+                name: "slope".to_string(),
+                ty: StateType::Tensor(Tensor {
+                    elem_type: ElementType::Float32,
+                    dim: slope_shape.len(),
+                    data: None,
+                    shape: Some(slope_shape),
+                }),
+            }],
+            attrs: Default::default(),
+        }
+    }
+
+    #[test]
+    fn prelu_config_per_channel() {
+        assert_eq!(
+            prelu_config(&prelu_node(4, vec![3, 1, 1])).num_parameters,
+            3
+        );
+        assert_eq!(prelu_config(&prelu_node(2, vec![3])).num_parameters, 3);
+        assert_eq!(prelu_config(&prelu_node(4, vec![1])).num_parameters, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "per channel on the second axis")]
+    fn prelu_config_last_axis_is_not_supported() {
+        prelu_config(&prelu_node(4, vec![3]));
+    }
+
+    #[test]
+    fn hard_sigmoid_config_default_and_attributes() {
+        let mut node = Node {
+            node_type: NodeType::HardSigmoid,
+            name: "hardsigmoid1".to_string(),
+            inputs: vec![],
+            outputs: vec![],
+            states: vec![],
+            attrs: Default::default(),
+        };
+        assert_eq!(hard_sigmoid_config(&node), (0.2, 0.5));
+
+        node.attrs
+            .insert("alpha".to_string(), AttributeValue::Float32(0.25));
+        assert_eq!(hard_sigmoid_config(&node), (0.25, 0.5));
+    }
+
+    fn layer_norm_node(axis: i64, scale_shape: Vec<usize>) -> Node {
+        Node {
+            node_type: NodeType::LayerNormalization,
+            name: "layernormalization1".to_string(),
+            inputs: vec![Argument {
+                name: "input".to_string(),
+                ty: ArgType::Tensor(TensorArg::new(3, ElementType::Float32)),
+            }],
+            outputs: vec![],
+            states: vec![State {
+                name: "scale".to_string(),
+                ty: StateType::Tensor(Tensor {
+                    elem_type: ElementType::Float32,
+                    dim: scale_shape.len(),
+                    data: None,
+                    shape: Some(scale_shape),
+                }),
+            }],
+            attrs: [("axis".to_string(), AttributeValue::Int64(axis))].into(),
+        }
+    }
+
+    #[test]
+    fn layer_norm_config_last_axis() {
+        let config = layer_norm_config(&layer_norm_node(-1, vec![8]));
+        assert_eq!(config.d_model, 8);
+
+        let config = layer_norm_config(&layer_norm_node(2, vec![1, 8]));
+        assert_eq!(config.d_model, 8);
+    }
+
+    #[test]
+    #[should_panic(expected = "only the normalization over the last axis is supported")]
+    fn layer_norm_config_over_several_axes_is_not_supported() {
+        layer_norm_config(&layer_norm_node(1, vec![4, 8]));
+    }
+
+    fn conv_transpose_node(weight_shape: Vec<usize>, attrs: Vec<(&str, AttributeValue)>) -> Node {
+        Node {
+            node_type: NodeType::ConvTranspose2d,
+            name: "convtranspose2d1".to_string(),
+            inputs: vec![Argument {
+                name: "input".to_string(),
+                ty: ArgType::Tensor(TensorArg::new(4, ElementType::Float32)),
+            }],
+            outputs: vec![],
+            states: vec![State {
+                name: "weight".to_string(),
+                ty: StateType::Tensor(Tensor {
+                    elem_type: ElementType::Float32,
+                    dim: weight_shape.len(),
+                    data: None,
+                    shape: Some(weight_shape),
+                }),
+            }],
+            attrs: attrs
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn conv_transpose2d_config_with_groups_and_output_padding() {
+        let node = conv_transpose_node(
+            vec![4, 3, 3, 5],
+            vec![
+                ("strides", AttributeValue::Int64s(vec![2, 3])),
+                ("pads", AttributeValue::Int64s(vec![1, 2, 1, 2])),
+                ("output_padding", AttributeValue::Int64s(vec![1, 0])),
+                ("group", AttributeValue::Int64(2)),
+            ],
+        );
+
+        let config = conv_transpose2d_config(&node);
+
+        assert_eq!(config.channels, [4, 6]);
+        assert_eq!(config.kernel_size, [3, 5]);
+        assert_eq!(config.stride, [2, 3]);
+        assert_eq!(config.padding, [1, 2]);
+        assert_eq!(config.padding_out, [1, 0]);
+        assert_eq!(config.groups, 2);
+        assert!(!config.bias);
+    }
+
+    #[test]
+    #[should_panic(expected = "asymmetric padding")]
+    fn conv_transpose2d_config_asymmetric_padding_is_not_supported() {
+        let node = conv_transpose_node(
+            vec![4, 3, 3, 3],
+            vec![("pads", AttributeValue::Int64s(vec![1, 1, 0, 1]))],
+        );
+
+        conv_transpose2d_config(&node);
+    }
+
+    fn depth_to_space_node(attrs: Vec<(&str, AttributeValue)>) -> Node {
+        Node {
+            node_type: NodeType::DepthToSpace,
+            name: "depthtospace1".to_string(),
+            inputs: vec![],
+            outputs: vec![],
+            states: vec![],
+            attrs: attrs
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn depth_to_space_config_mode() {
+        let node = depth_to_space_node(vec![("blocksize", AttributeValue::Int64(2))]);
+        assert_eq!(depth_to_space_config(&node), (2, DepthToSpaceMode::Dcr));
+
+        let node = depth_to_space_node(vec![
+            ("blocksize", AttributeValue::Int64(3)),
+            ("mode", AttributeValue::String("CRD".to_string())),
+        ]);
+        assert_eq!(depth_to_space_config(&node), (3, DepthToSpaceMode::Crd));
+    }
+
+    #[test]
+    #[should_panic(expected = "DepthToSpace: the blocksize must be a positive integer")]
+    fn depth_to_space_config_requires_block_size() {
+        depth_to_space_config(&depth_to_space_node(vec![]));
+    }
+
+    fn einsum_node(equation: &str, num_inputs: usize) -> Node {
+        let input = Argument {
+            name: "input".to_string(),
+            ty: ArgType::Tensor(TensorArg::new(2, ElementType::Float32)),
+        };
+
+        Node {
+            node_type: NodeType::Einsum,
+            name: "einsum1".to_string(),
+            inputs: vec![input; num_inputs],
+            outputs: vec![],
+            states: vec![],
+            attrs: [(
+                "equation".to_string(),
+                AttributeValue::String(equation.to_string()),
+            )]
+            .into(),
+        }
+    }
+
+    #[test]
+    fn einsum_config_explicit_and_implicit_output() {
+        let (inputs, output) = einsum_config(&einsum_node("bij, bjk -> bik", 2));
+        assert_eq!(inputs, vec![vec!['b', 'i', 'j'], vec!['b', 'j', 'k']]);
+        assert_eq!(output, vec!['b', 'i', 'k']);
+
+        let (_, output) = einsum_config(&einsum_node("kj,ji", 2));
+        assert_eq!(output, vec!['i', 'k']);
+    }
+
+    #[test]
+    #[should_panic(expected = "ellipsis")]
+    fn einsum_config_ellipsis_is_not_supported() {
+        einsum_config(&einsum_node("...ij,...jk->...ik", 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "repeated labels")]
+    fn einsum_config_diagonal_is_not_supported() {
+        einsum_config(&einsum_node("ii->i", 1));
+    }
+
+    #[test]
+    fn one_hot_config_constant_depth_and_values() {
+        let state = |name: &str, data: TensorData, shape: Vec<usize>| State {
+            name: name.to_string(),
+            ty: StateType::Tensor(Tensor {
+                elem_type: ElementType::Int64,
+                dim: shape.len(),
+                data: Some(data),
+                shape: Some(shape),
+            }),
+        };
+        let node = Node {
+            node_type: NodeType::OneHot,
+            name: "onehot1".to_string(),
+            inputs: vec![Argument {
+                name: "indices".to_string(),
+                ty: ArgType::Tensor(TensorArg::new(2, ElementType::Int64)),
+            }],
+            outputs: vec![],
+            states: vec![
+                state("depth", TensorData::Int64(vec![10]), vec![]),
+                state("values", TensorData::Float32(vec![-1.0, 2.0]), vec![2]),
+            ],
+            attrs: [("axis".to_string(), AttributeValue::Int64(-2))].into(),
+        };
+
+        assert_eq!(one_hot_config(&node), (10, [-1.0, 2.0], 1));
+    }
+
+    fn scatter_node(node_type: NodeType, attrs: Vec<(&str, AttributeValue)>) -> Node {
+        Node {
+            node_type,
+            name: "scatter1".to_string(),
+            inputs: vec![Argument {
+                name: "data".to_string(),
+                ty: ArgType::Tensor(TensorArg::new(3, ElementType::Float32)),
+            }],
+            outputs: vec![],
+            states: vec![],
+            attrs: attrs
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn scatter_elements_config_axis_and_reduction() {
+        let node = scatter_node(NodeType::ScatterElements, vec![]);
+        assert_eq!(scatter_elements_config(&node), (0, ScatterReduction::None));
+
+        let node = scatter_node(
+            NodeType::ScatterElements,
+            vec![
+                ("axis", AttributeValue::Int64(-1)),
+                ("reduction", AttributeValue::String("add".to_string())),
+            ],
+        );
+        assert_eq!(scatter_elements_config(&node), (2, ScatterReduction::Add));
+    }
+
+    #[test]
+    #[should_panic(expected = "ScatterND: unsupported reduction")]
+    fn scatter_nd_config_unsupported_reduction() {
+        let node = scatter_node(
+            NodeType::ScatterND,
+            vec![("reduction", AttributeValue::String("mul".to_string()))],
+        );
+        scatter_nd_config(&node);
+    }
+
+    #[test]
+    fn non_max_suppression_config_box_format() {
+        let mut node = Node {
+            node_type: NodeType::NonMaxSuppression,
+            name: "nms1".to_string(),
+            inputs: vec![],
+            outputs: vec![],
+            states: vec![],
+            attrs: Default::default(),
+        };
+        assert!(!non_max_suppression_config(&node));
+
+        node.attrs
+            .insert("center_point_box".to_string(), AttributeValue::Int64(1));
+        assert!(non_max_suppression_config(&node));
+    }
+
+    #[test]
+    fn reshape_config_runtime_sizes() {
+        let node = Node {
+            node_type: NodeType::Reshape,
+            name: "reshape1".to_string(),
+            inputs: vec![
+                Argument {
+                    name: "input".to_string(),
+                    ty: ArgType::Tensor(TensorArg::new(3, ElementType::Float32)),
+                },
+                Argument {
+                    name: "shape".to_string(),
+                    ty: ArgType::Shape(3),
+                },
+            ],
+            outputs: vec![],
+            states: vec![],
+            attrs: [
+                ("shape".to_string(), AttributeValue::Int64s(vec![0, -1, 8])),
+                (
+                    "shape_inputs".to_string(),
+                    AttributeValue::Int64s(vec![1, -1, -1]),
+                ),
+            ]
+            .into(),
+        };
+
+        let dims = reshape_config(&node);
+        assert_eq!(dims.len(), 3);
+        assert!(matches!(&dims[0], ShapeDim::Runtime(shape, 0) if shape.rank == 3));
+        assert!(matches!(dims[1], ShapeDim::Static(-1)));
+        assert!(matches!(dims[2], ShapeDim::Static(8)));
+    }
+
+    #[test]
+    fn slice_config_runtime_ends() {
+        let node = Node {
+            node_type: NodeType::Slice,
+            name: "slice1".to_string(),
+            inputs: vec![
+                Argument {
+                    name: "input".to_string(),
+                    ty: ArgType::Tensor(TensorArg::new(3, ElementType::Float32)),
+                },
+                Argument {
+                    name: "shape".to_string(),
+                    ty: ArgType::Shape(2),
+                },
+            ],
+            outputs: vec![],
+            states: vec![],
+            attrs: [
+                ("starts".to_string(), AttributeValue::Int64s(vec![0])),
+                ("ends".to_string(), AttributeValue::Int64s(vec![-1])),
+                ("ends_inputs".to_string(), AttributeValue::Int64s(vec![1])),
+                ("axes".to_string(), AttributeValue::Int64s(vec![1])),
+            ]
+            .into(),
+        };
+
+        let ranges = slice_config(&node);
+        let shape = ShapeType::new("shape", 2);
+        assert_eq!(
+            ranges,
+            vec![SliceRange::new(1, 0, ShapeDim::Runtime(shape, 1), 1)]
+        );
+    }
+
+    #[test]
+    fn scan_config_normalizes_axes() {
+        let argument = |name: &str, dim| Argument {
+            name: name.to_string(),
+            ty: ArgType::Tensor(TensorArg::new(dim, ElementType::Float32)),
+        };
+        let body = ONNXGraph {
+            nodes: vec![],
+            inputs: vec![argument("state", 1), argument("slice", 2)],
+            outputs: vec![argument("state", 1), argument("slice", 2)],
+            old_node_names: HashMap::new(),
+            old_input_names: HashMap::new(),
+        };
+        let node = Node {
+            node_type: NodeType::Scan,
+            name: "scan1".to_string(),
+            inputs: vec![argument("initial_state", 1), argument("input", 3)],
+            outputs: vec![argument("final_state", 1), argument("output", 3)],
+            states: vec![],
+            attrs: [
+                ("body".to_string(), AttributeValue::Graph(body)),
+                ("num_scan_inputs".to_string(), AttributeValue::Int64(1)),
+                (
+                    "scan_input_axes".to_string(),
+                    AttributeValue::Int64s(vec![-1]),
+                ),
+                (
+                    "scan_output_directions".to_string(),
+                    AttributeValue::Int64s(vec![1]),
+                ),
+            ]
+            .into(),
+        };
+
+        let (input_axes, output_axes) = scan_config(&node);
+        assert_eq!(input_axes, vec![ScanAxis::new(2, false)]);
+        assert_eq!(output_axes, vec![ScanAxis::new(0, true)]);
+    }
+}
@@ -0,0 +1,713 @@
+use std::collections::{HashMap, HashSet};
+
+use super::{
+    ir::{
+        ArgType, AttributeValue, ElementType, Node, NodeType, ONNXGraph, State, StateType, Tensor,
+        TensorData,
+    },
+    op_configuration::batch_norm_training_mode,
+};
+
+/// The graph optimization passes run on an imported model before generating its code, making the
+/// generated model faster and smaller.
+///
+/// All the passes are enabled by default. They only rewrite the nodes of the model graph, not the
+/// nodes of the subgraphs of the control flow nodes.
+#[derive(Debug, Clone)]
+pub struct Optimizations {
+    /// Fold the floating point computations on constants into Constant nodes.
+    pub constant_folding: bool,
+    /// Remove the Identity nodes, and the Dropout nodes which do nothing during inference.
+    pub identity_removal: bool,
+    /// Fold the BatchNormalization nodes following convolutions into the weights and bias of the
+    /// convolutions.
+    pub conv_batch_norm_folding: bool,
+    /// Remove the Transpose nodes cancelling each other, or not permuting any axis.
+    pub transpose_elimination: bool,
+}
+
+impl Default for Optimizations {
+    fn default() -> Self {
+        Self {
+            constant_folding: true,
+            identity_removal: true,
+            conv_batch_norm_folding: true,
+            transpose_elimination: true,
+        }
+    }
+}
+
+impl Optimizations {
+    /// No optimization pass, the generated code mapping each ONNX node to a node of the model.
+    pub fn none() -> Self {
+        Self {
+            constant_folding: false,
+            identity_removal: false,
+            conv_batch_norm_folding: false,
+            transpose_elimination: false,
+        }
+    }
+}
+
+impl ONNXGraph {
+    /// Run the enabled optimization passes on the graph.
+    pub fn optimize(&mut self, optimizations: &Optimizations) {
+        log::info!("Optimizing the graph with {:?}", optimizations);
+
+        if optimizations.constant_folding {
+            fold_float_constants(self);
+        }
+        if optimizations.identity_removal {
+            remove_identities(self);
+        }
+        if optimizations.conv_batch_norm_folding {
+            fold_conv_batch_norms(self);
+        }
+        if optimizations.transpose_elimination {
+            eliminate_transposes(self);
+        }
+
+        remove_unused_constants(self);
+    }
+}
+
+/// Fold the floating point unary and binary operators whose inputs are all constant.
+fn fold_float_constants(graph: &mut ONNXGraph) {
+    let mut constants = HashMap::<String, Tensor>::new();
+
+    for node in graph.nodes.iter_mut() {
+        if node.node_type == NodeType::Constant {
+            if let Some(AttributeValue::Tensor(tensor)) = node.attrs.get("value") {
+                constants.insert(node.outputs[0].name.clone(), tensor.clone());
+            }
+            continue;
+        }
+        if node.inputs.is_empty() || !node.states.is_empty() {
+            continue;
+        }
+
+        let Some(tensors) = node
+            .inputs
+            .iter()
+            .map(|input| constants.get(&input.name))
+            .collect::<Option<Vec<_>>>()
+        else {
+            continue;
+        };
+        let Some(tensor) = fold_float_node(&node.node_type, &tensors) else {
+            continue;
+        };
+
+        log::debug!("Folding the constant {} node {}", node.node_type, node.name);
+
+        constants.insert(node.outputs[0].name.clone(), tensor.clone());
+        node.node_type = NodeType::Constant;
+        node.inputs.clear();
+        node.attrs = [("value".to_string(), AttributeValue::Tensor(tensor))]
+            .into_iter()
+            .collect();
+    }
+}
+
+/// Compute an operator on float constants, the binary operators broadcasting single values.
+fn fold_float_node(node_type: &NodeType, tensors: &[&Tensor]) -> Option<Tensor> {
+    let floats = |tensor: &Tensor| match &tensor.data {
+        Some(TensorData::Float32(values)) => Some(values.clone()),
+        _ => None,
+    };
+
+    let (output, values) = match tensors {
+        [input] => {
+            let unary: fn(f32) -> f32 = match node_type {
+                NodeType::Identity => |x| x,
+                NodeType::Neg => |x| -x,
+                NodeType::Reciprocal => |x| 1.0 / x,
+                NodeType::Sqrt => f32::sqrt,
+                NodeType::Exp => f32::exp,
+                NodeType::Log => f32::ln,
+                NodeType::Tanh => f32::tanh,
+                NodeType::Sigmoid => |x| 1.0 / (1.0 + (-x).exp()),
+                NodeType::Relu => |x| x.max(0.0),
+                _ => return None,
+            };
+
+            (*input, floats(input)?.into_iter().map(unary).collect())
+        }
+        [lhs, rhs] => {
+            let binary: fn(f32, f32) -> f32 = match node_type {
+                NodeType::Add => |a, b| a + b,
+                NodeType::Sub => |a, b| a - b,
+                NodeType::Mul => |a, b| a * b,
+                NodeType::Div => |a, b| a / b,
+                NodeType::Pow => f32::powf,
+                _ => return None,
+            };
+            let (lhs_values, rhs_values) = (floats(lhs)?, floats(rhs)?);
+
+            let output = match (lhs_values.len(), rhs_values.len()) {
+                (len, 1) if lhs.dim >= rhs.dim && len > 0 => lhs,
+                (1, _) if rhs.dim >= lhs.dim => rhs,
+                (lhs_len, rhs_len) if lhs_len == rhs_len && lhs.shape == rhs.shape => lhs,
+                _ => return None,
+            };
+            let len = lhs_values.len().max(rhs_values.len());
+            let value = |values: &Vec<f32>, i: usize| values[i % values.len()];
+
+            let values = (0..len)
+                .map(|i| binary(value(&lhs_values, i), value(&rhs_values, i)))
+                .collect();
+
+            (*output, values)
+        }
+        _ => return None,
+    };
+
+    Some(Tensor {
+        data: Some(TensorData::Float32(values)),
+        ..output.clone()
+    })
+}
+
+/// Remove the Identity nodes, and the Dropout nodes which are not in training mode.
+fn remove_identities(graph: &mut ONNXGraph) {
+    let mut index = 0;
+
+    while index < graph.nodes.len() {
+        let node = &graph.nodes[index];
+        let removable = match node.node_type {
+            NodeType::Identity => true,
+            NodeType::Dropout => dropout_is_identity(node, graph),
+            _ => false,
+        };
+
+        if removable && bypass(graph, index, 0) {
+            let node = graph.nodes.remove(index);
+            log::debug!("Removing the {} node {}", node.node_type, node.name);
+        } else {
+            index += 1;
+        }
+    }
+}
+
+/// A Dropout node does nothing unless it is in training mode, and its mask must not be used.
+fn dropout_is_identity(node: &Node, graph: &ONNXGraph) -> bool {
+    let training = node.states.iter().any(|state| {
+        let StateType::Tensor(tensor) = &state.ty;
+        matches!(&tensor.data, Some(TensorData::Bool(values)) if values.iter().any(|value| *value))
+    });
+    let mask_used = node
+        .outputs
+        .get(1)
+        .is_some_and(|mask| uses(graph).contains_key(&mask.name));
+
+    // The ratio and the training mode can only be given at runtime
+    node.inputs.len() == 1 && !training && !mask_used
+}
+
+/// Bypass the node at the given index, so that its output is read from the input at the given
+/// position, and return whether it can be removed.
+///
+/// When the output is a graph output, the input is renamed after it instead, unless the input is
+/// also an input or an output of the graph.
+fn bypass(graph: &mut ONNXGraph, index: usize, input: usize) -> bool {
+    let node = &graph.nodes[index];
+    let input = node.inputs[input].name.clone();
+    let output = node.outputs[0].name.clone();
+
+    if !graph.outputs.iter().any(|arg| arg.name == output) {
+        rename_uses(&mut graph.nodes, &output, &input);
+        return true;
+    }
+
+    let graph_value = graph
+        .inputs
+        .iter()
+        .chain(graph.outputs.iter())
+        .any(|arg| arg.name == input);
+    let Some(producer) = graph.nodes[..index]
+        .iter()
+        .position(|node| node.outputs.iter().any(|arg| arg.name == input))
+    else {
+        return false;
+    };
+    if graph_value {
+        return false;
+    }
+
+    for arg in graph.nodes[producer].outputs.iter_mut() {
+        if arg.name == input {
+            arg.name = output.clone();
+        }
+    }
+    rename_uses(&mut graph.nodes, &input, &output);
+
+    true
+}
+
+/// Rename the uses of a value by the nodes, including the nodes of the subgraphs.
+fn rename_uses(nodes: &mut [Node], from: &str, to: &str) {
+    for node in nodes.iter_mut() {
+        for input in node.inputs.iter_mut().filter(|input| input.name == from) {
+            input.name = to.to_string();
+        }
+
+        for value in node.attrs.values_mut() {
+            if let AttributeValue::Graph(graph) = value {
+                rename_uses(&mut graph.nodes, from, to);
+                for output in graph
+                    .outputs
+                    .iter_mut()
+                    .filter(|output| output.name == from)
+                {
+                    output.name = to.to_string();
+                }
+            }
+        }
+    }
+}
+
+/// The number of uses of each value by the nodes and the outputs of the graph.
+fn uses(graph: &ONNXGraph) -> HashMap<String, usize> {
+    let mut uses = HashMap::new();
+
+    graph
+        .nodes
+        .iter()
+        .flat_map(|node| node.inputs.iter())
+        .chain(graph.outputs.iter())
+        .for_each(|arg| *uses.entry(arg.name.clone()).or_insert(0) += 1);
+
+    uses
+}
+
+/// Fold the BatchNormalization nodes into the convolutions whose output they only read.
+///
+/// The normalization `(x - mean) * gamma / sqrt(var + epsilon) + beta` is applied to the weights
+/// and bias of each output channel.
+fn fold_conv_batch_norms(graph: &mut ONNXGraph) {
+    let uses = uses(graph);
+    let producers = graph
+        .nodes
+        .iter()
+        .enumerate()
+        .map(|(index, node)| (node.outputs[0].name.clone(), index))
+        .collect::<HashMap<_, _>>();
+    let mut removed = HashSet::new();
+
+    for index in 0..graph.nodes.len() {
+        let batch_norm = &graph.nodes[index];
+        // In training mode, the node normalizes with the batch statistics, not the running ones
+        if batch_norm.node_type != NodeType::BatchNormalization
+            || batch_norm.outputs.len() > 1
+            || batch_norm_training_mode(batch_norm)
+        {
+            continue;
+        }
+
+        let input = &batch_norm.inputs[0].name;
+        let Some(&conv) = producers.get(input) else {
+            continue;
+        };
+        let conv_node = &graph.nodes[conv];
+        if !matches!(conv_node.node_type, NodeType::Conv1d | NodeType::Conv2d)
+            || uses.get(input) != Some(&1)
+        {
+            continue;
+        }
+
+        let Some(states) = fold_conv_batch_norm(conv_node, batch_norm) else {
+            continue;
+        };
+
+        log::debug!(
+            "Folding the BatchNormalization node {} into the {} node {}",
+            batch_norm.name,
+            conv_node.node_type,
+            conv_node.name
+        );
+
+        let outputs = batch_norm.outputs.clone();
+        removed.insert(index);
+        let conv_node = &mut graph.nodes[conv];
+        conv_node.states = states;
+        conv_node.outputs = outputs;
+    }
+
+    let mut index = 0;
+    graph.nodes.retain(|_| {
+        index += 1;
+        !removed.contains(&(index - 1))
+    });
+}
+
+/// The weights and bias of the convolution followed by the normalization.
+fn fold_conv_batch_norm(conv: &Node, batch_norm: &Node) -> Option<Vec<State>> {
+    let floats = |state: Option<&State>| {
+        let StateType::Tensor(tensor) = &state?.ty;
+        match &tensor.data {
+            Some(TensorData::Float32(values)) => Some((tensor.clone(), values.clone())),
+            _ => None,
+        }
+    };
+
+    let (weight, weights) = floats(conv.states.first())?;
+    let bias = match conv.states.get(1) {
+        Some(state) => Some(floats(Some(state))?.1),
+        None => None,
+    };
+    let [gamma, beta, mean, var] = [0, 1, 2, 3].map(|i| floats(batch_norm.states.get(i)));
+    let (gamma, beta, mean, var) = (gamma?.1, beta?.1, mean?.1, var?.1);
+    let epsilon = match batch_norm.attrs.get("epsilon") {
+        Some(AttributeValue::Float32(epsilon)) => *epsilon,
+        _ => 1e-5,
+    };
+
+    let channels = gamma.len();
+    if weights.len() % channels != 0 {
+        return None;
+    }
+    let channel_size = weights.len() / channels;
+    let factors = (0..channels)
+        .map(|c| gamma[c] / (var[c] + epsilon).sqrt())
+        .collect::<Vec<_>>();
+
+    let weights = weights
+        .iter()
+        .enumerate()
+        .map(|(i, weight)| weight * factors[i / channel_size])
+        .collect();
+    let bias = (0..channels)
+        .map(|c| {
+            let bias = bias.as_ref().map(|bias| bias[c]).unwrap_or(0.0);
+            (bias - mean[c]) * factors[c] + beta[c]
+        })
+        .collect();
+
+    let state = |name: String, tensor: Tensor| State {
+        name,
+        ty: StateType::Tensor(tensor),
+    };
+
+    Some(vec![
+        state(
+            conv.states[0].name.clone(),
+            Tensor {
+                data: Some(TensorData::Float32(weights)),
+                ..weight
+            },
+        ),
+        state(
+            format!("{}_bias", conv.name),
+            Tensor {
+                elem_type: ElementType::Float32,
+                dim: 1,
+                data: Some(TensorData::Float32(bias)),
+                shape: Some(vec![channels]),
+            },
+        ),
+    ])
+}
+
+/// Remove the Transpose nodes not permuting any axis, and the pairs of consecutive Transpose nodes
+/// whose permutations cancel each other.
+fn eliminate_transposes(graph: &mut ONNXGraph) {
+    let mut index = 0;
+
+    while index < graph.nodes.len() {
+        let node = &graph.nodes[index];
+        if node.node_type != NodeType::Transpose {
+            index += 1;
+            continue;
+        }
+        let Some(perm) = transpose_perm(node) else {
+            index += 1;
+            continue;
+        };
+
+        if perm.iter().enumerate().all(|(i, axis)| i == *axis) && bypass(graph, index, 0) {
+            let node = graph.nodes.remove(index);
+            log::debug!("Removing the identity Transpose node {}", node.name);
+            continue;
+        }
+
+        // The output must only be read by the next Transpose node
+        let node = &graph.nodes[index];
+        let output = &node.outputs[0].name;
+        let consumer = graph
+            .nodes
+            .iter()
+            .position(|other| other.inputs.iter().any(|input| &input.name == output));
+        let cancelled = match consumer {
+            Some(consumer) if uses(graph).get(output) == Some(&1) => {
+                let other = &graph.nodes[consumer];
+                other.node_type == NodeType::Transpose
+                    && transpose_perm(other).is_some_and(|other_perm| {
+                        other_perm.len() == perm.len()
+                            && other_perm
+                                .iter()
+                                .enumerate()
+                                .all(|(i, axis)| perm[*axis] == i)
+                    })
+            }
+            _ => false,
+        };
+
+        let consumer = consumer.unwrap_or_default();
+        if cancelled {
+            // The second node reads the input of the first one, and is then an identity
+            let input = node.inputs[0].clone();
+            graph.nodes[consumer].inputs[0] = input;
+
+            if bypass(graph, consumer, 0) {
+                log::debug!(
+                    "Removing the Transpose nodes {} and {}",
+                    graph.nodes[index].name,
+                    graph.nodes[consumer].name
+                );
+                graph.nodes.remove(consumer);
+                graph.nodes.remove(index);
+                continue;
+            }
+
+            // The output of the first node is read again when the second node is kept
+            let output = graph.nodes[index].outputs[0].clone();
+            graph.nodes[consumer].inputs[0] = output;
+        }
+
+        index += 1;
+    }
+}
+
+/// The permutation of a Transpose node, reversing the axes by default.
+fn transpose_perm(node: &Node) -> Option<Vec<usize>> {
+    match node.attrs.get("perm") {
+        Some(AttributeValue::Int64s(perm)) => {
+            Some(perm.iter().map(|axis| *axis as usize).collect())
+        }
+        _ => match &node.inputs[0].ty {
+            ArgType::Tensor(tensor) => Some((0..tensor.dim).rev().collect()),
+            _ => None,
+        },
+    }
+}
+
+/// Remove the Constant nodes whose output is not used anymore.
+fn remove_unused_constants(graph: &mut ONNXGraph) {
+    let uses = uses(graph);
+
+    graph.nodes.retain(|node| {
+        node.node_type != NodeType::Constant || uses.contains_key(&node.outputs[0].name)
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::onnx::ir::{Argument, TensorArg};
+
+    fn argument(name: &str, dim: usize) -> Argument {
+        Argument {
+            name: name.to_string(),
+            ty: ArgType::Tensor(TensorArg::new(dim, ElementType::Float32)),
+        }
+    }
+
+    fn node(node_type: NodeType, inputs: &[&str], output: &str) -> Node {
+        Node {
+            node_type,
+            name: output.to_string(),
+            inputs: inputs.iter().map(|name| argument(name, 2)).collect(),
+            outputs: vec![argument(output, 2)],
+            states: vec![],
+            attrs: Default::default(),
+        }
+    }
+
+    fn float_tensor(shape: Vec<usize>, values: Vec<f32>) -> Tensor {
+        Tensor {
+            elem_type: ElementType::Float32,
+            dim: shape.len(),
+            shape: Some(shape),
+            data: Some(TensorData::Float32(values)),
+        }
+    }
+
+    fn constant(output: &str, shape: Vec<usize>, values: Vec<f32>) -> Node {
+        let mut node = node(NodeType::Constant, &[], output);
+        node.attrs.insert(
+            "value".to_string(),
+            AttributeValue::Tensor(float_tensor(shape, values)),
+        );
+        node
+    }
+
+    fn graph(nodes: Vec<Node>, inputs: &[&str], outputs: &[&str]) -> ONNXGraph {
+        ONNXGraph {
+            nodes,
+            inputs: inputs.iter().map(|name| argument(name, 2)).collect(),
+            outputs: outputs.iter().map(|name| argument(name, 2)).collect(),
+            old_node_names: HashMap::new(),
+            old_input_names: HashMap::new(),
+        }
+    }
+
+    fn node_types(graph: &ONNXGraph) -> Vec<NodeType> {
+        graph
+            .nodes
+            .iter()
+            .map(|node| node.node_type.clone())
+            .collect()
+    }
+
+    fn floats(state: &State) -> Vec<f32> {
+        let StateType::Tensor(tensor) = &state.ty;
+        match &tensor.data {
+            Some(TensorData::Float32(values)) => values.clone(),
+            _ => panic!("Expected float data"),
+        }
+    }
+
+    #[test]
+    fn constants_should_be_folded() {
+        let mut graph = graph(
+            vec![
+                constant("a", vec![2], vec![1.0, 4.0]),
+                constant("b", vec![], vec![2.0]),
+                node(NodeType::Mul, &["a", "b"], "c"),
+                node(NodeType::Sqrt, &["c"], "d"),
+                node(NodeType::Add, &["x", "d"], "y"),
+            ],
+            &["x"],
+            &["y"],
+        );
+
+        graph.optimize(&Optimizations::default());
+
+        assert_eq!(node_types(&graph), vec![NodeType::Constant, NodeType::Add]);
+        match graph.nodes[0].attrs.get("value") {
+            Some(AttributeValue::Tensor(tensor)) => {
+                assert!(
+                    matches!(&tensor.data, Some(TensorData::Float32(values)) if *values == [2.0f32.sqrt(), 8.0f32.sqrt()])
+                )
+            }
+            _ => panic!("Expected a constant tensor"),
+        }
+    }
+
+    #[test]
+    fn identities_should_be_removed() {
+        let mut graph = graph(
+            vec![
+                node(NodeType::Relu, &["x"], "a"),
+                node(NodeType::Identity, &["a"], "b"),
+                node(NodeType::Dropout, &["b"], "c"),
+                node(NodeType::Identity, &["c"], "y"),
+            ],
+            &["x"],
+            &["y"],
+        );
+
+        graph.optimize(&Optimizations::default());
+
+        assert_eq!(node_types(&graph), vec![NodeType::Relu]);
+        assert_eq!(graph.nodes[0].inputs[0].name, "x");
+        assert_eq!(graph.nodes[0].outputs[0].name, "y");
+    }
+
+    #[test]
+    fn identity_between_graph_input_and_output_should_be_kept() {
+        let mut graph = graph(vec![node(NodeType::Identity, &["x"], "y")], &["x"], &["y"]);
+
+        graph.optimize(&Optimizations::default());
+
+        assert_eq!(node_types(&graph), vec![NodeType::Identity]);
+    }
+
+    fn conv_batch_norm_graph(attrs: Vec<(&str, AttributeValue)>) -> ONNXGraph {
+        let state = |name: &str, shape, values| State {
+            name: name.to_string(),
+            ty: StateType::Tensor(float_tensor(shape, values)),
+        };
+        let mut conv = node(NodeType::Conv1d, &["x"], "a");
+        conv.states = vec![state("weight", vec![2, 1, 1], vec![1.0, 2.0])];
+        let mut batch_norm = node(NodeType::BatchNormalization, &["a"], "y");
+        batch_norm.states = vec![
+            state("gamma", vec![2], vec![1.0, 2.0]),
+            state("beta", vec![2], vec![0.5, 0.0]),
+            state("mean", vec![2], vec![1.0, -1.0]),
+            state("var", vec![2], vec![4.0, 1.0]),
+        ];
+        batch_norm
+            .attrs
+            .insert("epsilon".to_string(), AttributeValue::Float32(0.0));
+        for (name, value) in attrs {
+            batch_norm.attrs.insert(name.to_string(), value);
+        }
+        graph(vec![conv, batch_norm], &["x"], &["y"])
+    }
+
+    #[test]
+    fn batch_norm_should_be_folded_into_conv() {
+        let mut graph = conv_batch_norm_graph(vec![]);
+
+        graph.optimize(&Optimizations::default());
+
+        assert_eq!(node_types(&graph), vec![NodeType::Conv1d]);
+        let conv = &graph.nodes[0];
+        assert_eq!(conv.outputs[0].name, "y");
+        assert_eq!(floats(&conv.states[0]), vec![0.5, 4.0]);
+        assert_eq!(floats(&conv.states[1]), vec![0.0, 2.0]);
+    }
+
+    #[test]
+    fn training_mode_batch_norm_should_not_be_folded_into_conv() {
+        let mut graph = conv_batch_norm_graph(vec![("training_mode", AttributeValue::Int64(1))]);
+
+        graph.optimize(&Optimizations::default());
+
+        assert_eq!(
+            node_types(&graph),
+            vec![NodeType::Conv1d, NodeType::BatchNormalization]
+        );
+    }
+
+    #[test]
+    fn cancelling_transposes_should_be_removed() {
+        let transpose = |input, output, perm: Vec<i64>| {
+            let mut node = node(NodeType::Transpose, &[input], output);
+            node.attrs
+                .insert("perm".to_string(), AttributeValue::Int64s(perm));
+            node
+        };
+        let mut graph = graph(
+            vec![
+                transpose("x", "a", vec![1, 2, 0]),
+                transpose("a", "b", vec![2, 0, 1]),
+                node(NodeType::Relu, &["b"], "y"),
+            ],
+            &["x"],
+            &["y"],
+        );
+
+        graph.optimize(&Optimizations::default());
+
+        assert_eq!(node_types(&graph), vec![NodeType::Relu]);
+        assert_eq!(graph.nodes[0].inputs[0].name, "x");
+    }
+
+    #[test]
+    fn disabled_passes_should_not_run() {
+        let mut graph = graph(
+            vec![
+                node(NodeType::Relu, &["x"], "a"),
+                node(NodeType::Identity, &["a"], "y"),
+            ],
+            &["x"],
+            &["y"],
+        );
+
+        graph.optimize(&Optimizations::none());
+
+        assert_eq!(node_types(&graph), vec![NodeType::Relu, NodeType::Identity]);
+    }
+}
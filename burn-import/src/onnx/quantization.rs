@@ -0,0 +1,450 @@
+use std::{collections::HashMap, path::Path};
+
+use super::{
+    from_onnx::load_external_data,
+    ir::{
+        ArgType, Argument, AttributeValue, ElementType, Node, NodeType, Tensor, TensorArg,
+        TensorData,
+    },
+    protos::TensorProto,
+};
+
+/// Dequantize the statically quantized operators, so that the model runs in floating point.
+///
+/// The quantized values are represented by their dequantized values: the QuantizeLinear and
+/// DequantizeLinear nodes of the activations become Identity nodes, the quantized constants are
+/// dequantized into Constant nodes, and the QLinearConv and QLinearMatMul nodes become Conv and
+/// MatMul nodes on the dequantized weights. The rounding of the quantized activations is not
+/// reproduced, so the outputs are close to, but not exactly, those of the quantized model.
+pub fn dequantize_nodes(nodes: &mut Vec<Node>, initializers: &[TensorProto], base_dir: &Path) {
+    let quantized = [
+        NodeType::QuantizeLinear,
+        NodeType::DequantizeLinear,
+        NodeType::QLinearConv,
+        NodeType::QLinearMatMul,
+    ];
+    if !nodes.iter().any(|node| quantized.contains(&node.node_type)) {
+        return;
+    }
+
+    let mut constants = Constants::new(nodes, initializers, base_dir);
+    let mut dequantized_nodes = Vec::with_capacity(nodes.len());
+
+    for mut node in nodes.drain(..) {
+        log::debug!("Dequantizing the {} node {}", node.node_type, node.name);
+
+        match node.node_type {
+            NodeType::QuantizeLinear => identity(&mut node),
+            NodeType::DequantizeLinear => dequantize_linear(&mut node, &constants),
+            NodeType::QLinearMatMul => {
+                qlinear_matmul(&mut node, &mut constants, &mut dequantized_nodes)
+            }
+            NodeType::QLinearConv => {
+                qlinear_conv(&mut node, &mut constants, &mut dequantized_nodes)
+            }
+            _ => {}
+        }
+
+        dequantized_nodes.push(node);
+    }
+
+    *nodes = dequantized_nodes;
+}
+
+/// The constant tensors of the graph, read from the Constant nodes and the initializers.
+struct Constants<'a> {
+    tensors: HashMap<String, Tensor>,
+    initializers: HashMap<&'a str, &'a TensorProto>,
+    base_dir: &'a Path,
+    /// The names of the Constant nodes already holding a dequantized tensor.
+    dequantized: HashMap<String, String>,
+}
+
+impl<'a> Constants<'a> {
+    fn new(nodes: &[Node], initializers: &'a [TensorProto], base_dir: &'a Path) -> Self {
+        let tensors = nodes
+            .iter()
+            .filter(|node| node.node_type == NodeType::Constant)
+            .filter_map(|node| match node.attrs.get("value") {
+                Some(AttributeValue::Tensor(tensor)) => {
+                    Some((node.outputs[0].name.clone(), tensor.clone()))
+                }
+                _ => None,
+            })
+            .collect();
+        let initializers = initializers
+            .iter()
+            .map(|init| (init.name.as_str(), init))
+            .collect();
+
+        Self {
+            tensors,
+            initializers,
+            base_dir,
+            dequantized: HashMap::new(),
+        }
+    }
+
+    fn contains(&self, name: &str) -> bool {
+        self.tensors.contains_key(name) || self.initializers.contains_key(name)
+    }
+
+    fn get(&self, name: &str) -> Option<Tensor> {
+        if let Some(tensor) = self.tensors.get(name) {
+            return Some(tensor.clone());
+        }
+
+        self.initializers.get(name).map(|init| {
+            let mut init = (*init).clone();
+            load_external_data(&mut init, self.base_dir);
+
+            init.try_into()
+                .unwrap_or_else(|_| panic!("Unsupported data type of the quantized tensor {name}"))
+        })
+    }
+
+    /// The scales, which must be constant.
+    fn floats(&self, node: &Node, index: usize) -> Vec<f32> {
+        let name = &node.inputs[index].name;
+        match self.get(name).and_then(|tensor| tensor.data) {
+            Some(TensorData::Float32(values)) => values,
+            Some(TensorData::Float64(values)) => values.into_iter().map(|v| v as f32).collect(),
+            _ => panic!(
+                "{}: the scale {name} must be a float constant",
+                node.node_type
+            ),
+        }
+    }
+
+    /// The quantized values, which must be constant. The zero points are optional and default to 0.
+    fn ints(&self, node: &Node, index: usize) -> Vec<i32> {
+        let Some(input) = node
+            .inputs
+            .get(index)
+            .filter(|input| !input.name.is_empty())
+        else {
+            return vec![0];
+        };
+
+        match self.get(&input.name).and_then(|tensor| tensor.data) {
+            Some(TensorData::Int32(values)) => values,
+            _ => panic!(
+                "{}: the quantized tensor {} must be an integer constant",
+                node.node_type, input.name
+            ),
+        }
+    }
+
+    /// Dequantize the constant input of a node with the scale and zero point at the given inputs.
+    fn dequantize(&self, node: &Node, input: usize, scale: usize, axis: i64) -> Tensor {
+        let tensor = self.get(&node.inputs[input].name).unwrap();
+        let shape = tensor.shape.clone().unwrap_or_default();
+        let axis = match axis < 0 {
+            true => (axis + shape.len() as i64) as usize,
+            false => axis as usize,
+        };
+
+        dequantize(
+            &shape,
+            &self.ints(node, input),
+            &self.floats(node, scale),
+            &self.ints(node, scale + 1),
+            axis,
+        )
+    }
+}
+
+/// Compute `(value - zero_point) * scale`, per slice along the axis when the scale is a vector.
+fn dequantize(
+    shape: &[usize],
+    values: &[i32],
+    scale: &[f32],
+    zero_point: &[i32],
+    axis: usize,
+) -> Tensor {
+    let stride = shape.iter().skip(axis + 1).product::<usize>();
+    let channel = |index: usize, params: usize| match params {
+        1 => 0,
+        _ => (index / stride) % shape[axis],
+    };
+
+    let data = values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let scale = scale[channel(index, scale.len())];
+            let zero_point = zero_point[channel(index, zero_point.len())];
+
+            (value - zero_point) as f32 * scale
+        })
+        .collect();
+
+    Tensor {
+        elem_type: ElementType::Float32,
+        dim: shape.len(),
+        shape: Some(shape.to_vec()),
+        data: Some(TensorData::Float32(data)),
+    }
+}
+
+/// The quantized activations are represented by their floating point values, so the node passes
+/// its input as is.
+fn identity(node: &mut Node) {
+    node.node_type = NodeType::Identity;
+    node.inputs.truncate(1);
+    node.attrs.clear();
+}
+
+/// A constant quantized tensor is dequantized at import time.
+fn dequantize_linear(node: &mut Node, constants: &Constants) {
+    if !constants.contains(&node.inputs[0].name) {
+        return identity(node);
+    }
+
+    let axis = match node.attrs.get("axis") {
+        Some(AttributeValue::Int64(axis)) => *axis,
+        _ => 1,
+    };
+    let tensor = constants.dequantize(node, 0, 1, axis);
+
+    node.node_type = NodeType::Constant;
+    node.inputs.clear();
+    node.attrs = [("value".to_string(), AttributeValue::Tensor(tensor))]
+        .into_iter()
+        .collect();
+}
+
+/// The input of a quantized operator, which is dequantized into a new Constant node when constant.
+///
+/// The inputs of the node are the quantized tensor, its scale and its zero point.
+fn dequantized_input(
+    node: &Node,
+    input: usize,
+    axis: impl Fn(usize) -> i64,
+    constants: &mut Constants,
+    nodes: &mut Vec<Node>,
+) -> Argument {
+    let name = &node.inputs[input].name;
+    if !constants.contains(name) {
+        return node.inputs[input].clone();
+    }
+
+    let output = match constants.dequantized.get(name) {
+        Some(output) => output.clone(),
+        None => {
+            let rank = constants.get(name).unwrap().dim;
+            let tensor = constants.dequantize(node, input, input + 1, axis(rank));
+            let output = format!("{name}_dequantized");
+            nodes.push(constant_node(&output, tensor));
+            constants.dequantized.insert(name.clone(), output.clone());
+
+            output
+        }
+    };
+
+    Argument {
+        name: output,
+        ty: ArgType::Tensor(TensorArg::default()),
+    }
+}
+
+pub(super) fn constant_node(name: &str, tensor: Tensor) -> Node {
+    Node {
+        node_type: NodeType::Constant,
+        name: name.to_string(),
+        inputs: vec![],
+        outputs: vec![Argument {
+            name: name.to_string(),
+            ty: ArgType::Tensor(TensorArg::default()),
+        }],
+        states: vec![],
+        attrs: [("value".to_string(), AttributeValue::Tensor(tensor))]
+            .into_iter()
+            .collect(),
+    }
+}
+
+/// The inputs are `[a, a_scale, a_zero_point, b, b_scale, b_zero_point, y_scale, y_zero_point]`,
+/// the scales of `a` being per row and those of `b` per column.
+fn qlinear_matmul(node: &mut Node, constants: &mut Constants, nodes: &mut Vec<Node>) {
+    let lhs = dequantized_input(node, 0, |rank| rank as i64 - 2, constants, nodes);
+    let rhs = dequantized_input(node, 3, |rank| rank as i64 - 1, constants, nodes);
+
+    node.node_type = NodeType::MatMul;
+    node.inputs = vec![lhs, rhs];
+    node.attrs.clear();
+}
+
+/// The inputs are `[x, x_scale, x_zero_point, w, w_scale, w_zero_point, y_scale, y_zero_point, B]`,
+/// the scales of `w` being per output channel and the bias being quantized with the scale
+/// `x_scale * w_scale`.
+fn qlinear_conv(node: &mut Node, constants: &mut Constants, nodes: &mut Vec<Node>) {
+    let weight_name = &node.inputs[3].name;
+    let Some(weight) = constants.get(weight_name) else {
+        panic!("QLinearConv: the weights {weight_name} must be constant");
+    };
+    let kernel_shape = weight.shape.unwrap()[2..]
+        .iter()
+        .map(|size| *size as i64)
+        .collect::<Vec<_>>();
+
+    let mut inputs = vec![
+        node.inputs[0].clone(),
+        dequantized_input(node, 3, |_| 0, constants, nodes),
+    ];
+
+    if let Some(bias) = node.inputs.get(8).filter(|bias| !bias.name.is_empty()) {
+        let input_scale = constants.floats(node, 1);
+        let bias_scale = constants
+            .floats(node, 4)
+            .into_iter()
+            .map(|scale| scale * input_scale[0])
+            .collect::<Vec<_>>();
+        let values = constants.ints(node, 8);
+        let tensor = dequantize(&[values.len()], &values, &bias_scale, &[0], 0);
+
+        let output = format!("{}_dequantized", bias.name);
+        nodes.push(constant_node(&output, tensor));
+        inputs.push(Argument {
+            name: output,
+            ty: ArgType::Tensor(TensorArg::default()),
+        });
+    }
+
+    node.node_type = match kernel_shape.len() {
+        1 => NodeType::Conv1d,
+        2 => NodeType::Conv2d,
+        _ => panic!("QLinearConv: only conv 1d and 2d are supported"),
+    };
+    node.inputs = inputs;
+    node.attrs
+        .entry("kernel_shape".to_string())
+        .or_insert(AttributeValue::Int64s(kernel_shape));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn argument(name: &str) -> Argument {
+        Argument {
+            name: name.to_string(),
+            ty: ArgType::Tensor(TensorArg::default()),
+        }
+    }
+
+    fn node(node_type: NodeType, inputs: &[&str], output: &str) -> Node {
+        Node {
+            node_type,
+            name: output.to_string(),
+            inputs: inputs.iter().map(|name| argument(name)).collect(),
+            outputs: vec![argument(output)],
+            states: vec![],
+            attrs: Default::default(),
+        }
+    }
+
+    fn constant(output: &str, shape: Vec<usize>, data: TensorData) -> Node {
+        let elem_type = match data {
+            TensorData::Float32(_) => ElementType::Float32,
+            _ => ElementType::Int32,
+        };
+
+        constant_node(
+            output,
+            Tensor {
+                elem_type,
+                dim: shape.len(),
+                shape: Some(shape),
+                data: Some(data),
+            },
+        )
+    }
+
+    fn value(node: &Node) -> Vec<f32> {
+        match node.attrs.get("value") {
+            Some(AttributeValue::Tensor(Tensor {
+                data: Some(TensorData::Float32(values)),
+                ..
+            })) => values.clone(),
+            _ => panic!("Expected a float constant"),
+        }
+    }
+
+    #[test]
+    fn dequantize_linear_on_constant_is_folded_per_axis() {
+        let mut nodes = vec![
+            constant("q", vec![2, 2], TensorData::Int32(vec![1, 2, 3, 4])),
+            constant("scale", vec![2], TensorData::Float32(vec![0.5, 2.0])),
+            constant("zero_point", vec![2], TensorData::Int32(vec![1, 0])),
+            node(
+                NodeType::DequantizeLinear,
+                &["q", "scale", "zero_point"],
+                "output",
+            ),
+        ];
+        nodes[3]
+            .attrs
+            .insert("axis".to_string(), AttributeValue::Int64(0));
+
+        dequantize_nodes(&mut nodes, &[], Path::new(""));
+
+        assert_eq!(nodes[3].node_type, NodeType::Constant);
+        assert!(nodes[3].inputs.is_empty());
+        assert_eq!(value(&nodes[3]), vec![0.0, 0.5, 6.0, 8.0]);
+    }
+
+    #[test]
+    fn quantized_activations_pass_through() {
+        let mut nodes = vec![
+            constant("scale", vec![], TensorData::Float32(vec![0.1])),
+            node(NodeType::QuantizeLinear, &["x", "scale"], "q"),
+            node(NodeType::DequantizeLinear, &["q", "scale"], "output"),
+        ];
+
+        dequantize_nodes(&mut nodes, &[], Path::new(""));
+
+        assert_eq!(nodes[1].node_type, NodeType::Identity);
+        assert_eq!(nodes[1].inputs.len(), 1);
+        assert_eq!(nodes[2].node_type, NodeType::Identity);
+        assert_eq!(nodes[2].inputs[0].name, "q");
+    }
+
+    #[test]
+    fn qlinear_matmul_becomes_matmul_on_dequantized_weights() {
+        let mut nodes = vec![
+            constant("scale", vec![], TensorData::Float32(vec![0.5])),
+            constant("zero_point", vec![], TensorData::Int32(vec![0])),
+            constant("b", vec![2, 1], TensorData::Int32(vec![2, -4])),
+            node(
+                NodeType::QLinearMatMul,
+                &[
+                    "a",
+                    "scale",
+                    "zero_point",
+                    "b",
+                    "scale",
+                    "zero_point",
+                    "scale",
+                    "zero_point",
+                ],
+                "output",
+            ),
+        ];
+
+        dequantize_nodes(&mut nodes, &[], Path::new(""));
+
+        let matmul = nodes.last().unwrap();
+        assert_eq!(matmul.node_type, NodeType::MatMul);
+        let inputs = matmul
+            .inputs
+            .iter()
+            .map(|input| input.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(inputs, vec!["a", "b_dequantized"]);
+
+        let weights = &nodes[nodes.len() - 2];
+        assert_eq!(weights.outputs[0].name, "b_dequantized");
+        assert_eq!(value(weights), vec![1.0, -2.0]);
+    }
+}
@@ -0,0 +1,110 @@
+//! Builders of the intermediate representation shared by the unit tests of the ONNX import.
+
+use super::ir::{
+    ArgType, Argument, AttributeValue, Attributes, ElementType, Node, NodeType, State, StateType,
+    Tensor, TensorArg, TensorData,
+};
+
+/// A tensor argument of the given rank and element type.
+pub fn argument(name: &str, dim: usize, elem_type: ElementType) -> Argument {
+    Argument {
+        name: name.to_string(),
+        ty: ArgType::Tensor(TensorArg::new(dim, elem_type)),
+    }
+}
+
+/// The float tensor argument of rank `dim` named `input`.
+pub fn float_input(dim: usize) -> Argument {
+    argument("input", dim, ElementType::Float32)
+}
+
+/// A node named after its single output, with tensor arguments of unknown rank.
+pub fn node(node_type: NodeType, inputs: &[&str], output: &str) -> Node {
+    let argument = |name: &str| Argument {
+        name: name.to_string(),
+        ty: ArgType::Tensor(TensorArg::default()),
+    };
+
+    Node {
+        node_type,
+        name: output.to_string(),
+        inputs: inputs.iter().map(|name| argument(name)).collect(),
+        outputs: vec![argument(output)],
+        states: vec![],
+        attrs: Attributes::new(),
+    }
+}
+
+/// A node without outputs, as read by the configuration of its operator.
+pub fn config_node(
+    node_type: NodeType,
+    name: &str,
+    inputs: Vec<Argument>,
+    states: Vec<State>,
+    attrs: Vec<(&str, AttributeValue)>,
+) -> Node {
+    Node {
+        node_type,
+        name: name.to_string(),
+        inputs,
+        outputs: vec![],
+        states,
+        attrs: self::attrs(attrs),
+    }
+}
+
+/// The attributes with the given names and values.
+pub fn attrs(attrs: Vec<(&str, AttributeValue)>) -> Attributes {
+    attrs
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value))
+        .collect()
+}
+
+/// A tensor holding the data, with the element type of the data.
+pub fn tensor(shape: Vec<usize>, data: TensorData) -> Tensor {
+    let elem_type = match data {
+        TensorData::Float16(_) => ElementType::Float16,
+        TensorData::Float32(_) => ElementType::Float32,
+        TensorData::Float64(_) => ElementType::Float64,
+        TensorData::Int32(_) => ElementType::Int32,
+        TensorData::Int64(_) => ElementType::Int64,
+        TensorData::String(_) => ElementType::String,
+        TensorData::Bool(_) => ElementType::Bool,
+    };
+
+    Tensor {
+        elem_type,
+        dim: shape.len(),
+        shape: Some(shape),
+        data: Some(data),
+    }
+}
+
+/// A float tensor of which only the shape is known.
+pub fn float_tensor_shape(shape: Vec<usize>) -> Tensor {
+    Tensor {
+        elem_type: ElementType::Float32,
+        dim: shape.len(),
+        shape: Some(shape),
+        data: None,
+    }
+}
+
+/// A state holding the tensor.
+pub fn state(name: &str, tensor: Tensor) -> State {
+    State {
+        name: name.to_string(),
+        ty: StateType::Tensor(tensor),
+    }
+}
+
+/// A constant node whose value is a tensor holding the data.
+pub fn constant(output: &str, shape: Vec<usize>, data: TensorData) -> Node {
+    let mut node = node(NodeType::Constant, &[], output);
+    node.attrs.insert(
+        "value".to_string(),
+        AttributeValue::Tensor(tensor(shape, data)),
+    );
+    node
+}
@@ -1,54 +1,125 @@
 use std::{
+    collections::HashMap,
     env,
     fs::{self, create_dir_all},
     path::{Path, PathBuf},
 };
 
 use burn::{
-    record::{FullPrecisionSettings, PrecisionSettings},
-    tensor::{DataSerialize, Element},
+    record::{FullPrecisionSettings, HalfPrecisionSettings, PrecisionSettings},
+    tensor::{Data, DataSerialize, Element},
 };
+use proc_macro2::TokenStream;
 
 use crate::{
     burn::{
-        graph::BurnGraph,
+        graph::{BurnGraph, RecordType},
         node::{
             avg_pool2d::AvgPool2dNode,
             batch_norm::BatchNormNode,
             binary::BinaryNode,
             concat::ConcatNode,
             constant::{ConstantNode, ConstantValue, TensorValue},
+            constant_of_shape::ConstantOfShapeNode,
             conv1d::Conv1dNode,
             conv2d::Conv2dNode,
+            conv_transpose1d::ConvTranspose1dNode,
+            conv_transpose2d::ConvTranspose2dNode,
+            custom::CustomNode,
+            depth_to_space::DepthToSpaceNode,
             dropout::DropoutNode,
+            einsum::EinsumNode,
+            embedding::EmbeddingNode,
+            expand::ExpandNode,
+            gather::{GatherIndices, GatherNode},
+            gemm::GemmNode,
             global_avg_pool::GlobalAvgPoolNode,
+            group_norm::GroupNormNode,
+            gru::{GruNode, GruWeights},
+            if_op::IfNode,
+            layer_norm::LayerNormNode,
+            leaky_relu::LeakyReluNode,
             linear::LinearNode,
+            loop_op::LoopNode,
+            lstm::{LstmNode, LstmWeights},
             matmul::MatmulNode,
             max_pool2d::MaxPool2dNode,
+            non_max_suppression::{NmsParameter, NonMaxSuppressionNode},
+            one_hot::OneHotNode,
+            pad::PadNode,
+            prelu::PReluNode,
             reshape::ReshapeNode,
+            rnn::RnnGateData,
+            scan::ScanNode,
+            scatter_elements::ScatterElementsNode,
+            scatter_nd::ScatterNdNode,
+            shape::ShapeNode,
+            slice::SliceNode,
+            space_to_depth::SpaceToDepthNode,
+            split::SplitNode,
+            subgraph::Subgraph,
+            tile::TileNode,
             unary::UnaryNode,
+            where_op::WhereNode,
+            NodeCodegen,
         },
-        ScalarKind, ScalarType, TensorKind, TensorType, Type,
+        ScalarKind, ScalarType, ShapeType, TensorKind, TensorType, Type,
     },
     format_tokens,
     logger::init_log,
     onnx::{
         ir::{AttributeValue, Node, NodeType},
         op_configuration::{
-            batch_norm_config, conv1d_config, conv2d_config, flatten_config, linear_config,
-            log_softmax_config, max_pool2d_config,
+            arg_reduce_config, batch_norm_config, batch_norm_training_mode, clip_config,
+            constant_of_shape_config, conv1d_config, conv2d_config, conv_transpose1d_config,
+            conv_transpose2d_config, depth_to_space_config, einsum_config, embedding_config,
+            expand_config, flatten_config, gather_config, gelu_config, gemm_config,
+            group_norm_config, gru_config, hard_sigmoid_config, layer_norm_config,
+            leaky_relu_config, linear_config, log_softmax_config, lstm_config, max_pool2d_config,
+            non_max_suppression_config, one_hot_config, pad_config, prelu_config, reduce_config,
+            scan_config, scatter_elements_config, scatter_nd_config, shape_config, slice_config,
+            space_to_depth_config, split_config, tile_config,
         },
     },
 };
 
 use super::{
     from_onnx::parse_onnx,
-    ir::{ArgType, Argument, ElementType, ONNXGraph, State, StateType, Tensor, TensorData},
+    ir::{
+        ArgType, Argument, Attributes, ElementType, ONNXGraph, State, StateType, Tensor, TensorData,
+    },
     op_configuration::{
         avg_pool2d_config, concat_config, dropout_config, reshape_config, softmax_config,
     },
+    optimization::Optimizations,
+    verification::{verify_codegen, Reference},
 };
 
+/// A node of a custom operator, passed to the handler registered for its operator type with
+/// [ModelGen::custom_op].
+#[derive(Debug, Clone)]
+pub struct CustomOp {
+    /// The type of the operator.
+    pub op_type: String,
+    /// The name of the node in the generated code.
+    pub name: String,
+    /// The variables of the inputs, the optional inputs left out having an empty name.
+    pub inputs: Vec<String>,
+    /// The variables the outputs must be declared with.
+    pub outputs: Vec<String>,
+    /// The attributes of the node.
+    pub attrs: Attributes,
+}
+
+/// Generate the forward pass of a node of a custom operator, as Rust statements reading the
+/// variables of the inputs and declaring the variables of the outputs with `let`.
+///
+/// The outputs must have the type of the first input.
+pub type CustomOpHandler = fn(&CustomOp) -> String;
+
+/// The handlers of the custom operators, by operator type.
+pub type CustomOps = HashMap<String, CustomOpHandler>;
+
 /// Generate code and states from `.onnx` files and save them to the `out_dir`.
 #[derive(Debug, Default)]
 pub struct ModelGen {
@@ -56,6 +127,13 @@ pub struct ModelGen {
     /// List of onnx files to generate source code from.
     inputs: Vec<PathBuf>,
     development: bool,
+    custom_ops: CustomOps,
+    optimizations: Optimizations,
+    record_type: Option<RecordType>,
+    half_precision: bool,
+    model_name: Option<String>,
+    verification: bool,
+    reference_values: Option<PathBuf>,
 }
 
 impl ModelGen {
@@ -86,6 +164,109 @@ impl ModelGen {
         self
     }
 
+    /// Register the handler generating the nodes of a custom operator, such as an operator of a
+    /// custom domain, instead of failing on it.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use burn_import::onnx::{CustomOp, ModelGen};
+    ///
+    /// fn fused_gelu(op: &CustomOp) -> String {
+    ///     format!(
+    ///         "let {} = burn::tensor::activation::gelu({});",
+    ///         op.outputs[0], op.inputs[0]
+    ///     )
+    /// }
+    ///
+    /// ModelGen::new()
+    ///     .input("model.onnx")
+    ///     .out_dir("model/")
+    ///     .custom_op("FusedGelu", fused_gelu)
+    ///     .run_from_script();
+    /// ```
+    pub fn custom_op(&mut self, op_type: &str, handler: CustomOpHandler) -> &mut Self {
+        self.custom_ops.insert(op_type.to_string(), handler);
+        self
+    }
+
+    /// Set the optimization passes run on the model graph before generating its code.
+    ///
+    /// All the passes are enabled by default.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use burn_import::onnx::{ModelGen, Optimizations};
+    ///
+    /// ModelGen::new()
+    ///     .input("model.onnx")
+    ///     .out_dir("model/")
+    ///     .optimizations(Optimizations {
+    ///         transpose_elimination: false,
+    ///         ..Default::default()
+    ///     })
+    ///     .run_from_script();
+    /// ```
+    pub fn optimizations(&mut self, optimizations: Optimizations) -> &mut Self {
+        self.optimizations = optimizations;
+        self
+    }
+
+    /// Set the file format of the record saving the states of the model.
+    ///
+    /// By default, the record is saved as [pretty json](RecordType::PrettyJson) in development
+    /// mode and as [named msgpack](RecordType::NamedMpkGz) otherwise.
+    pub fn record_type(&mut self, record_type: RecordType) -> &mut Self {
+        self.record_type = Some(record_type);
+        self
+    }
+
+    /// Save the states of the model in half precision, halving the size of the record.
+    pub fn half_precision(&mut self, half_precision: bool) -> &mut Self {
+        self.half_precision = half_precision;
+        self
+    }
+
+    /// Set the name of the generated model struct, `Model` by default.
+    pub fn model_name(&mut self, model_name: &str) -> &mut Self {
+        self.model_name = Some(model_name.to_string());
+        self
+    }
+
+    /// Generate a verification model next to the model, in the `<name>_verification.rs` file.
+    ///
+    /// The ONNX model is run by ONNX Runtime on random inputs, through a python script requiring
+    /// the `onnx` and `onnxruntime` packages. The verification model computes the values of the
+    /// nodes on the same inputs, and its `verify` method compares them with the values of ONNX
+    /// Runtime, reporting the nodes whose values differ by more than a tolerance:
+    ///
+    /// ```ignore
+    /// mod verification {
+    ///     include!(concat!(env!("OUT_DIR"), "/model/mnist_verification.rs"));
+    /// }
+    ///
+    /// #[test]
+    /// fn model_matches_onnx_runtime() {
+    ///     let model = verification::Model::<NdArrayBackend<f32>>::default();
+    ///     model.verify(1e-4).unwrap();
+    /// }
+    /// ```
+    pub fn verify(&mut self, verification: bool) -> &mut Self {
+        self.verification = verification;
+        self
+    }
+
+    /// Verify the model against reference values computed ahead of time with the
+    /// `scripts/onnx_reference.py` script, instead of running ONNX Runtime.
+    ///
+    /// See [verify](Self::verify).
+    pub fn reference_values(&mut self, file: &str) -> &mut Self {
+        self.verification = true;
+        self.reference_values = Some(file.into());
+        self
+    }
+
     /// Run code generation.
     ///
     /// This function is intended to be called from `build.rs` script.
@@ -128,21 +309,22 @@ impl ModelGen {
             log::debug!("Input file name: {:?}", file_name);
             log::debug!("Output file: {:?}", out_file);
 
-            Self::generate_model(self.development, input, out_file);
+            self.generate_model(input, out_file);
         }
 
         log::info!("Finished converting ONNX to Burn");
     }
 
     /// Generate model source code and model state.
-    fn generate_model(development: bool, input: &PathBuf, out_file: PathBuf) {
+    fn generate_model(&self, input: &PathBuf, out_file: PathBuf) {
         log::info!("Generating model from {:?}", input);
-        log::debug!("Development mode: {:?}", development);
+        log::debug!("Development mode: {:?}", self.development);
         log::debug!("Output file: {:?}", out_file);
 
-        let graph = parse_onnx(input.as_ref());
+        let mut graph = parse_onnx(input.as_ref());
+        graph.optimize(&self.optimizations);
 
-        if development {
+        if self.development {
             // export the graph
             let debug_graph = format!("{:#?}", graph);
             let graph_file = out_file.with_extension("graph.txt");
@@ -150,63 +332,105 @@ impl ModelGen {
             fs::write(graph_file, debug_graph).unwrap();
         }
 
-        let graph = graph
-            .into_burn::<FullPrecisionSettings>()
-            .with_record(
-                out_file.clone(),
-                development,
+        if self.verification {
+            self.generate_verification(graph.clone(), input, &out_file);
+        }
+
+        let code = self.codegen_with_precision(graph, input, &out_file);
+        let code_str = format_tokens(code);
+        fs::write(out_file.with_extension("rs"), code_str).unwrap();
+
+        log::info!("Model generated");
+    }
+
+    /// Generate the verification model, computing the model on the reference inputs and
+    /// comparing its values with the reference values.
+    fn generate_verification(&self, graph: ONNXGraph, input: &PathBuf, out_file: &Path) {
+        let reference = match &self.reference_values {
+            Some(file) => Reference::load(file),
+            None => Reference::compute(input, &out_file.with_extension("reference.json")),
+        };
+        let (graph, values) = graph.into_verification(&reference);
+
+        let mut file_name = out_file.file_name().unwrap().to_os_string();
+        file_name.push("_verification");
+        let out_file = out_file.with_file_name(file_name);
+
+        let mut code = self.codegen_with_precision(graph, input, &out_file);
+        code.extend(verify_codegen(&values));
+        let code_str = format_tokens(code);
+        fs::write(out_file.with_extension("rs"), code_str).unwrap();
+
+        log::info!("Verification model generated");
+    }
+
+    /// Generate the model source code, and save the model state with the precision set.
+    fn codegen_with_precision(
+        &self,
+        graph: ONNXGraph,
+        input: &PathBuf,
+        out_file: &Path,
+    ) -> TokenStream {
+        match self.half_precision {
+            true => self.codegen::<HalfPrecisionSettings>(
+                graph,
+                input,
+                out_file,
+                "burn::record::HalfPrecisionSettings",
+            ),
+            false => self.codegen::<FullPrecisionSettings>(
+                graph,
+                input,
+                out_file,
                 "burn::record::FullPrecisionSettings",
-            )
+            ),
+        }
+    }
+
+    /// Generate the model source code, and save the model state with the given precision.
+    fn codegen<PS: PrecisionSettings + 'static>(
+        &self,
+        graph: ONNXGraph,
+        input: &PathBuf,
+        out_file: &Path,
+        precision_ty_str: &str,
+    ) -> TokenStream {
+        let record_type = self.record_type.unwrap_or(match self.development {
+            true => RecordType::PrettyJson,
+            false => RecordType::NamedMpkGz,
+        });
+        let mut graph = graph
+            .into_burn_with_custom_ops::<PS>(&self.custom_ops)
+            .with_record_type(out_file.to_path_buf(), record_type, precision_ty_str)
             .with_new_fn(true)
             .with_blank_space(true)
             .with_top_comment(Some(format!(
                 "Generated from ONNX {input:?} by burn-import"
             )));
+        if let Some(model_name) = &self.model_name {
+            graph = graph.with_model_name(model_name);
+        }
 
-        let code_str = format_tokens(graph.codegen());
-        fs::write(out_file.with_extension("rs"), code_str).unwrap();
-
-        log::info!("Model generated");
+        graph.codegen()
     }
 }
 
 impl ONNXGraph {
     /// Converts ONNX graph to Burn graph.
     pub fn into_burn<PS: PrecisionSettings + 'static>(self) -> BurnGraph<PS> {
+        self.into_burn_with_custom_ops(&CustomOps::new())
+    }
+
+    /// Converts ONNX graph to Burn graph, generating the nodes of the custom operators with the
+    /// handlers registered for their operator types.
+    pub fn into_burn_with_custom_ops<PS: PrecisionSettings + 'static>(
+        self,
+        custom_ops: &CustomOps,
+    ) -> BurnGraph<PS> {
         let mut graph = BurnGraph::<PS>::default();
 
         for node in self.nodes {
-            match node.node_type {
-                NodeType::Add => graph.register(Self::add_conversion(node)),
-                NodeType::Sub => graph.register(Self::sub_conversion(node)),
-                NodeType::Mul => graph.register(Self::mul_conversion(node)),
-                NodeType::Div => graph.register(Self::div_conversion(node)),
-                NodeType::Equal => graph.register(Self::equal_conversion(node)),
-                NodeType::Conv1d => graph.register(Self::conv1d_conversion::<PS>(node)),
-                NodeType::Conv2d => graph.register(Self::conv2d_conversion::<PS>(node)),
-                NodeType::MaxPool2d => graph.register(Self::max_pool2d_conversion(node)),
-                NodeType::AveragePool2d => graph.register(Self::avg_pool_2d_conversion(node)),
-                NodeType::MatMul => graph.register(Self::matmul_conversion(node)),
-                NodeType::Linear => graph.register(Self::linear_conversion::<PS>(node)),
-                NodeType::BatchNormalization => {
-                    graph.register(Self::batch_norm_conversion::<PS>(node))
-                }
-                NodeType::Relu => graph.register(Self::relu_conversion(node)),
-                NodeType::Flatten => graph.register(Self::flatten_conversion(node)),
-                NodeType::LogSoftmax => graph.register(Self::log_softmax_conversion(node)),
-                NodeType::Softmax => graph.register(Self::softmax_conversion(node)),
-                NodeType::Constant => graph.register(Self::constant_conversion::<PS>(node)),
-                NodeType::Reshape => graph.register(Self::reshape_conversion(node)),
-                NodeType::Sigmoid => graph.register(Self::sigmoid_conversion(node)),
-                NodeType::Transpose => graph.register(Self::transpose_conversion(node)),
-                NodeType::Concat => graph.register(Self::concat_conversion(node)),
-                NodeType::Cast => graph.register(Self::cast_conversion(node)),
-                NodeType::Dropout => graph.register(Self::dropout_conversion(node)),
-                NodeType::GlobalAveragePool => {
-                    graph.register(Self::global_avg_pool_conversion(node))
-                }
-                _ => panic!("Unsupported node conversion {}", node.node_type),
-            }
+            Self::register_node(&mut graph, node, custom_ops);
         }
 
         // Get input and output names
@@ -227,6 +451,94 @@ impl ONNXGraph {
         graph
     }
 
+    fn register_node<PS: PrecisionSettings + 'static>(
+        graph: &mut BurnGraph<PS>,
+        node: Node,
+        custom_ops: &CustomOps,
+    ) {
+        match node.node_type {
+            NodeType::Add => graph.register(Self::add_conversion(node)),
+            NodeType::Sub => graph.register(Self::sub_conversion(node)),
+            NodeType::Mul => graph.register(Self::mul_conversion(node)),
+            NodeType::Div => graph.register(Self::div_conversion(node)),
+            NodeType::Equal => graph.register(Self::equal_conversion(node)),
+            NodeType::Conv1d => graph.register(Self::conv1d_conversion::<PS>(node)),
+            NodeType::Conv2d => graph.register(Self::conv2d_conversion::<PS>(node)),
+            NodeType::ConvTranspose1d => {
+                graph.register(Self::conv_transpose1d_conversion::<PS>(node))
+            }
+            NodeType::ConvTranspose2d => {
+                graph.register(Self::conv_transpose2d_conversion::<PS>(node))
+            }
+            NodeType::MaxPool2d => graph.register(Self::max_pool2d_conversion(node)),
+            NodeType::AveragePool2d => graph.register(Self::avg_pool_2d_conversion(node)),
+            NodeType::MatMul => graph.register(Self::matmul_conversion(node)),
+            NodeType::Linear => graph.register(Self::linear_conversion::<PS>(node)),
+            NodeType::Gemm => graph.register(Self::gemm_conversion(node)),
+            NodeType::Gather if matches!(node.inputs[0].ty, ArgType::Shape(_)) => {
+                graph.register(Self::shape_gather_conversion(node))
+            }
+            NodeType::Gather => graph.register(Self::gather_conversion(node)),
+            NodeType::Embedding => graph.register(Self::embedding_conversion::<PS>(node)),
+            NodeType::LSTM => graph.register(Self::lstm_conversion::<PS>(node)),
+            NodeType::GRU => graph.register(Self::gru_conversion::<PS>(node)),
+            NodeType::BatchNormalization => graph.register(Self::batch_norm_conversion::<PS>(node)),
+            NodeType::GroupNormalization => graph.register(Self::group_norm_conversion::<PS>(node)),
+            NodeType::LayerNormalization => graph.register(Self::layer_norm_conversion::<PS>(node)),
+            NodeType::Relu => graph.register(Self::relu_conversion(node)),
+            NodeType::LeakyRelu => graph.register(Self::leaky_relu_conversion(node)),
+            NodeType::PRelu => graph.register(Self::prelu_conversion::<PS>(node)),
+            NodeType::Clip => graph.register(Self::clip_conversion(node)),
+            NodeType::Flatten => graph.register(Self::flatten_conversion(node)),
+            NodeType::LogSoftmax => graph.register(Self::log_softmax_conversion(node)),
+            NodeType::Softmax => graph.register(Self::softmax_conversion(node)),
+            NodeType::Constant => graph.register(Self::constant_conversion::<PS>(node)),
+            NodeType::Reshape => graph.register(Self::reshape_conversion(node)),
+            NodeType::Pad => graph.register(Self::pad_conversion(node)),
+            NodeType::OneHot => graph.register(Self::one_hot_conversion(node)),
+            NodeType::NonMaxSuppression => {
+                graph.register(Self::non_max_suppression_conversion(node))
+            }
+            NodeType::Scatter | NodeType::ScatterElements => {
+                graph.register(Self::scatter_elements_conversion(node))
+            }
+            NodeType::ScatterND => graph.register(Self::scatter_nd_conversion(node)),
+            NodeType::ArgMax => graph.register(Self::argmax_conversion(node)),
+            NodeType::ArgMin => graph.register(Self::argmin_conversion(node)),
+            NodeType::ReduceMax => graph.register(Self::reduce_max_conversion(node)),
+            NodeType::ReduceMean => graph.register(Self::reduce_mean_conversion(node)),
+            NodeType::ReduceSum => graph.register(Self::reduce_sum_conversion(node)),
+            NodeType::Slice => graph.register(Self::slice_conversion(node)),
+            NodeType::Expand => graph.register(Self::expand_conversion(node)),
+            NodeType::Shape => graph.register(Self::shape_conversion(node)),
+            NodeType::ConstantOfShape => graph.register(Self::constant_of_shape_conversion(node)),
+            NodeType::Split => graph.register(Self::split_conversion(node)),
+            NodeType::Tile => graph.register(Self::tile_conversion(node)),
+            NodeType::Sigmoid => graph.register(Self::sigmoid_conversion(node)),
+            NodeType::Tanh => graph.register(Self::tanh_conversion(node)),
+            NodeType::Softplus => graph.register(Self::softplus_conversion(node)),
+            NodeType::HardSigmoid => graph.register(Self::hard_sigmoid_conversion(node)),
+            NodeType::HardSwish => graph.register(Self::hard_swish_conversion(node)),
+            NodeType::Erf => graph.register(Self::erf_conversion(node)),
+            NodeType::Gelu => graph.register(Self::gelu_conversion(node)),
+            NodeType::Transpose => graph.register(Self::transpose_conversion(node)),
+            NodeType::Concat => graph.register(Self::concat_conversion(node)),
+            NodeType::Where => graph.register(Self::where_conversion(node)),
+            NodeType::Cast => graph.register(Self::cast_conversion(node)),
+            NodeType::Dropout => graph.register(Self::dropout_conversion(node)),
+            NodeType::Einsum => graph.register(Self::einsum_conversion(node)),
+            NodeType::DepthToSpace => graph.register(Self::depth_to_space_conversion(node)),
+            NodeType::SpaceToDepth => graph.register(Self::space_to_depth_conversion(node)),
+            NodeType::GlobalAveragePool => graph.register(Self::global_avg_pool_conversion(node)),
+            NodeType::Identity => graph.register(Self::identity_conversion(node)),
+            NodeType::If => graph.register(Self::if_conversion::<PS>(node, custom_ops)),
+            NodeType::Loop => graph.register(Self::loop_conversion::<PS>(node, custom_ops)),
+            NodeType::Scan => graph.register(Self::scan_conversion::<PS>(node, custom_ops)),
+            NodeType::Custom(_) => graph.register(Self::custom_conversion(node, custom_ops)),
+            _ => panic!("Unsupported node conversion {}", node.node_type),
+        }
+    }
+
     fn constant_conversion<PS: PrecisionSettings>(mut node: Node) -> ConstantNode<PS> {
         let output = node.outputs.get(0).unwrap();
 
@@ -255,7 +567,7 @@ impl ONNXGraph {
                         ElementType::Float32 | ElementType::Float64 => TensorValue::Float(
                             tensor.clone().into_data_serialize::<PS::FloatElem>(),
                         ),
-                        ElementType::Int32 | ElementType::Int64 => {
+                        ElementType::Int32 | ElementType::Int64 | ElementType::Bool => {
                             TensorValue::Int(tensor.clone().into_data_serialize::<PS::IntElem>())
                         }
                         _ => panic!("Unsupported constant tensor type: {:?} ", tensor.elem_type),
@@ -318,6 +630,16 @@ impl ONNXGraph {
         MatmulNode::new(lhs, rhs, output)
     }
 
+    fn gemm_conversion(node: Node) -> GemmNode {
+        let a = node.inputs.get(0).unwrap().to_tensor_type();
+        let b = node.inputs.get(1).unwrap().to_tensor_type();
+        let c = node.inputs.get(2).map(Argument::to_type);
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+        let (alpha, beta, trans_a, trans_b) = gemm_config(&node);
+
+        GemmNode::new(a, b, c, output, alpha, beta, trans_a, trans_b)
+    }
+
     fn equal_conversion(node: Node) -> BinaryNode {
         let lhs = node.inputs.get(0).unwrap().to_type();
         let rhs = node.inputs.get(1).unwrap().to_type();
@@ -333,6 +655,54 @@ impl ONNXGraph {
         UnaryNode::relu(input, output)
     }
 
+    fn clip_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.get(0).unwrap().to_type();
+        let output = node.outputs.get(0).unwrap().to_type();
+        let (min, max) = clip_config(&node);
+
+        UnaryNode::clip(input, output, min, max)
+    }
+
+    fn argmax_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+        let (axis, keepdims, select_last_index) = arg_reduce_config(&node);
+
+        UnaryNode::argmax(input, output, axis, keepdims, select_last_index)
+    }
+
+    fn argmin_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+        let (axis, keepdims, select_last_index) = arg_reduce_config(&node);
+
+        UnaryNode::argmin(input, output, axis, keepdims, select_last_index)
+    }
+
+    fn reduce_max_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.get(0).unwrap().to_type();
+        let output = node.outputs.get(0).unwrap().to_type();
+        let (axes, keepdims) = reduce_config(&node);
+
+        UnaryNode::reduce_max(input, output, axes, keepdims)
+    }
+
+    fn reduce_mean_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.get(0).unwrap().to_type();
+        let output = node.outputs.get(0).unwrap().to_type();
+        let (axes, keepdims) = reduce_config(&node);
+
+        UnaryNode::reduce_mean(input, output, axes, keepdims)
+    }
+
+    fn reduce_sum_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.get(0).unwrap().to_type();
+        let output = node.outputs.get(0).unwrap().to_type();
+        let (axes, keepdims) = reduce_config(&node);
+
+        UnaryNode::reduce_sum(input, output, axes, keepdims)
+    }
+
     fn flatten_conversion(node: Node) -> UnaryNode {
         let input = node.inputs.get(0).unwrap().to_type();
         let output = node.outputs.get(0).unwrap().to_type();
@@ -355,6 +725,208 @@ impl ONNXGraph {
         UnaryNode::cast(input, output)
     }
 
+    fn pad_conversion(node: Node) -> PadNode {
+        let input = node.inputs.get(0).unwrap().to_tensor_type();
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+        let (pads, mode) = pad_config(&node);
+
+        PadNode::new(input, output, pads, mode)
+    }
+
+    fn slice_conversion(node: Node) -> SliceNode {
+        let input = node.inputs.get(0).unwrap().to_tensor_type();
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+        let ranges = slice_config(&node);
+
+        SliceNode::new(input, output, ranges)
+    }
+
+    fn expand_conversion(node: Node) -> ExpandNode {
+        let input = node.inputs.get(0).unwrap().to_tensor_type();
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+        let shape = expand_config(&node);
+
+        ExpandNode::new(input, output, shape)
+    }
+
+    fn shape_conversion(node: Node) -> ShapeNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_shape_type();
+        let (start, end) = shape_config(&node);
+
+        ShapeNode::new(input, output, start, end)
+    }
+
+    fn constant_of_shape_conversion(node: Node) -> ConstantOfShapeNode {
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let (shape, value) = constant_of_shape_config(&node);
+
+        ConstantOfShapeNode::new(shape, output, value)
+    }
+
+    /// The sizes gathered from a computed shape with constant indices.
+    fn shape_gather_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        let StateType::Tensor(tensor) = &node.states.first().unwrap().ty;
+        let indices = match tensor.data.as_ref() {
+            Some(TensorData::Int64(values)) => values.clone(),
+            _ => panic!("Gather: the indices of a shape must be Int64"),
+        };
+        let ArgType::Shape(rank) = node.inputs[0].ty else {
+            unreachable!();
+        };
+        let indices = indices
+            .into_iter()
+            .map(|index| match index < 0 {
+                true => (index + rank as i64) as usize,
+                false => index as usize,
+            })
+            .collect();
+
+        UnaryNode::shape_gather(input, output, indices)
+    }
+
+    fn split_conversion(node: Node) -> SplitNode {
+        let input = node.inputs.get(0).unwrap().to_tensor_type();
+        let outputs = node.outputs.iter().map(Argument::to_tensor_type).collect();
+        let (axis, sizes) = split_config(&node);
+
+        SplitNode::new(input, outputs, axis, sizes)
+    }
+
+    fn tile_conversion(node: Node) -> TileNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let repeats = tile_config(&node);
+
+        TileNode::new(input, output, repeats)
+    }
+
+    fn einsum_conversion(node: Node) -> EinsumNode {
+        let inputs = node
+            .inputs
+            .iter()
+            .map(|input| input.to_tensor_type())
+            .collect();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let (input_labels, output_labels) = einsum_config(&node);
+
+        EinsumNode::new(inputs, output, input_labels, output_labels)
+    }
+
+    fn depth_to_space_conversion(node: Node) -> DepthToSpaceNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let (block_size, mode) = depth_to_space_config(&node);
+
+        DepthToSpaceNode::new(input, output, block_size, mode)
+    }
+
+    fn space_to_depth_conversion(node: Node) -> SpaceToDepthNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let block_size = space_to_depth_config(&node);
+
+        SpaceToDepthNode::new(input, output, block_size)
+    }
+
+    fn non_max_suppression_conversion(node: Node) -> NonMaxSuppressionNode {
+        let boxes = node.inputs.first().unwrap().to_tensor_type();
+        let scores = node.inputs.get(1).unwrap().to_tensor_type();
+        // The constant parameters are attributes, see the coalesce step, and the optional inputs
+        // can be omitted with an empty name.
+        let parameter = |index: usize, name: &str| match node.attrs.get(name) {
+            Some(AttributeValue::Int64(value)) => Some(NmsParameter::Int(*value)),
+            Some(AttributeValue::Float32(value)) => Some(NmsParameter::Float(*value)),
+            _ => node
+                .inputs
+                .get(index)
+                .filter(|input| !input.name.is_empty())
+                .map(|input| NmsParameter::Input(input.to_type())),
+        };
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let center_point_box = non_max_suppression_config(&node);
+
+        NonMaxSuppressionNode::new(
+            boxes,
+            scores,
+            parameter(2, "max_output_boxes_per_class"),
+            parameter(3, "iou_threshold"),
+            parameter(4, "score_threshold"),
+            output,
+            center_point_box,
+        )
+    }
+
+    fn one_hot_conversion(node: Node) -> OneHotNode {
+        let indices = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let (depth, values, axis) = one_hot_config(&node);
+
+        OneHotNode::new(indices, output, depth, values, axis)
+    }
+
+    fn scatter_elements_conversion(node: Node) -> ScatterElementsNode {
+        let data = node.inputs.first().unwrap().to_tensor_type();
+        let indices = node.inputs.get(1).unwrap().to_tensor_type();
+        let updates = node.inputs.get(2).unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let (axis, reduction) = scatter_elements_config(&node);
+
+        ScatterElementsNode::new(data, indices, updates, output, axis, reduction)
+    }
+
+    fn scatter_nd_conversion(node: Node) -> ScatterNdNode {
+        let data = node.inputs.first().unwrap().to_tensor_type();
+        let indices = node.inputs.get(1).unwrap().to_tensor_type();
+        let updates = node.inputs.get(2).unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let reduction = scatter_nd_config(&node);
+
+        ScatterNdNode::new(data, indices, updates, output, reduction)
+    }
+
+    fn gather_conversion(node: Node) -> GatherNode {
+        let input = node.inputs.get(0).unwrap().to_tensor_type();
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+        let dim = gather_config(&node);
+
+        let indices = match (node.inputs.get(1), node.states.first()) {
+            (Some(indices), _) => GatherIndices::Tensor(indices.to_tensor_type()),
+            (None, Some(state)) => {
+                let StateType::Tensor(tensor) = &state.ty;
+                let values = match tensor.data.as_ref() {
+                    Some(TensorData::Int64(values)) => values.clone(),
+                    Some(TensorData::Int32(values)) => {
+                        values.iter().map(|value| *value as i64).collect()
+                    }
+                    _ => panic!("Gather: constant indices must be integers"),
+                };
+
+                if values.iter().any(|value| *value < 0) {
+                    panic!("Gather: negative constant indices are not supported");
+                }
+
+                GatherIndices::Constant(values, tensor.shape.clone().unwrap_or_default())
+            }
+            (None, None) => panic!("Gather: missing indices"),
+        };
+
+        GatherNode::new(input, indices, output, dim)
+    }
+
+    fn embedding_conversion<PS: PrecisionSettings>(mut node: Node) -> EmbeddingNode<PS> {
+        let name = &node.name;
+        let input = node.inputs.get(0).unwrap().to_tensor_type();
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+        let config = embedding_config(&node);
+        let weight = node.states.remove(0).into_data_serialize::<PS::FloatElem>();
+
+        EmbeddingNode::new(name, input, output, weight, config)
+    }
+
     fn reshape_conversion(node: Node) -> ReshapeNode {
         let input = node.inputs.get(0).unwrap().to_tensor_type();
         let output = node.outputs.get(0).unwrap().to_tensor_type();
@@ -370,6 +942,50 @@ impl ONNXGraph {
         UnaryNode::sigmoid(input, output)
     }
 
+    fn tanh_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        UnaryNode::tanh(input, output)
+    }
+
+    fn softplus_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        UnaryNode::softplus(input, output)
+    }
+
+    fn hard_sigmoid_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+        let (alpha, beta) = hard_sigmoid_config(&node);
+
+        UnaryNode::hard_sigmoid(input, output, alpha, beta)
+    }
+
+    fn hard_swish_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        UnaryNode::hard_swish(input, output)
+    }
+
+    fn erf_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        UnaryNode::erf(input, output)
+    }
+
+    fn gelu_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+        let tanh = gelu_config(&node);
+
+        UnaryNode::gelu(input, output, tanh)
+    }
+
     fn log_softmax_conversion(node: Node) -> UnaryNode {
         let input = node.inputs.get(0).unwrap().to_type();
         let output = node.outputs.get(0).unwrap().to_type();
@@ -386,6 +1002,15 @@ impl ONNXGraph {
         UnaryNode::softmax(input, output, dim)
     }
 
+    fn where_conversion(node: Node) -> WhereNode {
+        let condition = node.inputs.get(0).unwrap().to_tensor_type();
+        let x = node.inputs.get(1).unwrap().to_tensor_type();
+        let y = node.inputs.get(2).unwrap().to_tensor_type();
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+
+        WhereNode::new(condition, x, y, output)
+    }
+
     fn concat_conversion(node: Node) -> ConcatNode {
         let inputs = node
             .inputs
@@ -416,6 +1041,82 @@ impl ONNXGraph {
         LinearNode::new(name, input, output, weight, bias, config)
     }
 
+    fn lstm_conversion<PS: PrecisionSettings>(mut node: Node) -> LstmNode<PS> {
+        let (config, direction, batch_first) = lstm_config(&node);
+
+        let input = node.inputs.get(0).unwrap().to_tensor_type();
+        let initial_state = node.inputs.get(1).map(|hidden| {
+            let cell = node.inputs.get(2).expect("LSTM: initial_c is required");
+            (hidden.to_tensor_type(), cell.to_tensor_type())
+        });
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+        let output_hidden = node.outputs.get(1).map(Argument::to_tensor_type);
+        let output_cell = node.outputs.get(2).map(Argument::to_tensor_type);
+
+        // The states are unpacked per direction and per gate, see the coalesce step
+        let bias = config.bias;
+        let mut gate = || extract_rnn_gate_data::<PS>(&mut node, bias);
+
+        let weights = (0..direction.num_directions())
+            .map(|_| LstmWeights {
+                input_gate: gate(),
+                forget_gate: gate(),
+                output_gate: gate(),
+                cell_gate: gate(),
+            })
+            .collect();
+
+        let name = &node.name;
+
+        LstmNode::new(
+            name,
+            input,
+            initial_state,
+            output,
+            output_hidden,
+            output_cell,
+            weights,
+            config,
+            direction,
+            batch_first,
+        )
+    }
+
+    fn gru_conversion<PS: PrecisionSettings>(mut node: Node) -> GruNode<PS> {
+        let (config, direction, batch_first) = gru_config(&node);
+
+        let input = node.inputs.get(0).unwrap().to_tensor_type();
+        let initial_state = node.inputs.get(1).map(Argument::to_tensor_type);
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+        let output_hidden = node.outputs.get(1).map(Argument::to_tensor_type);
+
+        // The states are unpacked per direction and per gate, see the coalesce step
+        let bias = config.bias;
+        let mut gate = || extract_rnn_gate_data::<PS>(&mut node, bias);
+
+        let weights = (0..direction.num_directions())
+            .map(|_| GruWeights {
+                update_gate: gate(),
+                reset_gate: gate(),
+                new_gate: gate(),
+            })
+            .collect();
+
+        let name = &node.name;
+
+        GruNode::new(
+            name,
+            input,
+            initial_state,
+            output,
+            output_hidden,
+            weights,
+            config,
+            direction,
+            batch_first,
+        )
+    }
+
     fn dropout_conversion(node: Node) -> DropoutNode {
         let name = &node.name;
         let input = node.inputs.get(0).unwrap().to_tensor_type();
@@ -427,6 +1128,16 @@ impl ONNXGraph {
 
     fn batch_norm_conversion<PS: PrecisionSettings>(mut node: Node) -> BatchNormNode<PS> {
         let config = batch_norm_config(&node);
+
+        // In training mode, the module normalizes with the batch statistics as the node does,
+        // but the updated running statistics outputs can't be imported.
+        if batch_norm_training_mode(&node) && node.outputs.len() > 1 {
+            log::warn!(
+                "BatchNormalization {} is exported in training mode, the running mean and variance outputs are ignored",
+                node.name
+            );
+        }
+
         let input = node.inputs.get(0).unwrap().to_tensor_type();
         let output = node.outputs.get(0).unwrap().to_tensor_type();
         let dim = input.dim - 2;
@@ -434,7 +1145,7 @@ impl ONNXGraph {
         let gamma =
             extract_next_data_serialize::<PS::FloatElem>(&mut node).expect("Gamma is required");
         let beta =
-            extract_next_data_serialize::<PS::FloatElem>(&mut node).expect("Gamma is required");
+            extract_next_data_serialize::<PS::FloatElem>(&mut node).expect("Beta is required");
         let running_mean = extract_next_data_serialize::<PS::FloatElem>(&mut node)
             .expect("Running mean is required");
         let running_var = extract_next_data_serialize::<PS::FloatElem>(&mut node)
@@ -455,6 +1166,84 @@ impl ONNXGraph {
         )
     }
 
+    fn group_norm_conversion<PS: PrecisionSettings>(mut node: Node) -> GroupNormNode<PS> {
+        let config = group_norm_config(&node);
+        let input = node.inputs.get(0).unwrap().to_tensor_type();
+        let output = node.outputs.get(0).unwrap().to_tensor_type();
+
+        // The values of each group are repeated for each of its channels.
+        let per_channel = |data: DataSerialize<PS::FloatElem>| {
+            let repeat = config.num_channels / data.value.len();
+            let value = data
+                .value
+                .into_iter()
+                .flat_map(|value| core::iter::repeat_n(value, repeat))
+                .collect();
+
+            DataSerialize::new(value, vec![config.num_channels])
+        };
+
+        let gamma =
+            extract_next_data_serialize::<PS::FloatElem>(&mut node).expect("Gamma is required");
+        let beta =
+            extract_next_data_serialize::<PS::FloatElem>(&mut node).expect("Beta is required");
+
+        let name = &node.name;
+
+        GroupNormNode::new(
+            name,
+            input,
+            output,
+            per_channel(gamma),
+            per_channel(beta),
+            config,
+        )
+    }
+
+    fn layer_norm_conversion<PS: PrecisionSettings>(mut node: Node) -> LayerNormNode<PS> {
+        let config = layer_norm_config(&node);
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+
+        // The scale and bias are flattened, the module broadcasts them on the last axis.
+        let shape = vec![config.d_model];
+        let gamma =
+            extract_next_data_serialize::<PS::FloatElem>(&mut node).expect("Scale is required");
+        let gamma = DataSerialize::new(gamma.value, shape.clone());
+        let beta = match extract_next_data_serialize::<PS::FloatElem>(&mut node) {
+            Some(beta) => DataSerialize::new(beta.value, shape),
+            None => Data::<PS::FloatElem, 1>::zeros([config.d_model]).serialize(),
+        };
+
+        let name = &node.name;
+
+        LayerNormNode::new(name, input, output, gamma, beta, config)
+    }
+
+    fn leaky_relu_conversion(node: Node) -> LeakyReluNode {
+        let config = leaky_relu_config(&node);
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let name = &node.name;
+
+        LeakyReluNode::new(name, input, output, config)
+    }
+
+    fn prelu_conversion<PS: PrecisionSettings>(mut node: Node) -> PReluNode<PS> {
+        let config = prelu_config(&node);
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+
+        // The slope is flattened, the module reshapes it to the channel axis.
+        let alpha =
+            extract_next_data_serialize::<PS::FloatElem>(&mut node).expect("Slope is required");
+        let alpha = DataSerialize::new(alpha.value, vec![config.num_parameters]);
+
+        let name = &node.name;
+
+        PReluNode::new(name, input, output, alpha, config)
+    }
+
     fn conv1d_conversion<PS: PrecisionSettings>(mut node: Node) -> Conv1dNode<PS> {
         let input = node.inputs.get(0).unwrap().to_tensor_type();
         let output = node.outputs.get(0).unwrap().to_tensor_type();
@@ -487,6 +1276,34 @@ impl ONNXGraph {
         Conv2dNode::<PS>::new(name, input, output, weight, bias, config)
     }
 
+    fn conv_transpose1d_conversion<PS: PrecisionSettings>(
+        mut node: Node,
+    ) -> ConvTranspose1dNode<PS> {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let config = conv_transpose1d_config(&node);
+
+        let weight = extract_next_data_serialize::<PS::FloatElem>(&mut node).unwrap();
+        let bias = extract_next_data_serialize::<PS::FloatElem>(&mut node);
+
+        let name = &node.name;
+        ConvTranspose1dNode::<PS>::new(name, input, output, weight, bias, config)
+    }
+
+    fn conv_transpose2d_conversion<PS: PrecisionSettings>(
+        mut node: Node,
+    ) -> ConvTranspose2dNode<PS> {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let config = conv_transpose2d_config(&node);
+
+        let weight = extract_next_data_serialize::<PS::FloatElem>(&mut node).unwrap();
+        let bias = extract_next_data_serialize::<PS::FloatElem>(&mut node);
+
+        let name = &node.name;
+        ConvTranspose2dNode::<PS>::new(name, input, output, weight, bias, config)
+    }
+
     fn max_pool2d_conversion(node: Node) -> MaxPool2dNode {
         let input = node.inputs.get(0).unwrap().to_tensor_type();
         let output = node.outputs.get(0).unwrap().to_tensor_type();
@@ -513,6 +1330,145 @@ impl ONNXGraph {
 
         GlobalAvgPoolNode::new(name, input, output)
     }
+
+    fn identity_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.get(0).unwrap().to_type();
+        let output = node.outputs.get(0).unwrap().to_type();
+
+        UnaryNode::identity(input, output)
+    }
+
+    /// Converts the graph stored in an attribute of a control flow node.
+    fn subgraph_conversion<PS: PrecisionSettings + 'static>(
+        node: &mut Node,
+        name: &str,
+        custom_ops: &CustomOps,
+    ) -> Subgraph<PS> {
+        let graph = match node.attrs.remove(name) {
+            Some(AttributeValue::Graph(graph)) => graph,
+            _ => panic!("{}: the {name} graph is missing", node.node_type),
+        };
+
+        let mut burn_graph = BurnGraph::<PS>::default();
+        for node in graph.nodes {
+            Self::register_node(&mut burn_graph, node, custom_ops);
+        }
+        let nodes = burn_graph.into_nodes();
+
+        // The fields are only declared by the nodes of the model graph
+        if let Some(subnode) = nodes.iter().find(|subnode| subnode.field_type().is_some()) {
+            panic!(
+                "{}: the {} node of a subgraph can't have weights",
+                node.node_type,
+                subnode.name()
+            );
+        }
+
+        Subgraph::new(
+            nodes,
+            graph.inputs.iter().map(Argument::to_type).collect(),
+            graph.outputs.iter().map(Argument::to_type).collect(),
+        )
+    }
+
+    fn if_conversion<PS: PrecisionSettings + 'static>(
+        mut node: Node,
+        custom_ops: &CustomOps,
+    ) -> IfNode<PS> {
+        let condition = node.inputs.get(0).unwrap().to_type();
+        let then_branch = Self::subgraph_conversion(&mut node, "then_branch", custom_ops);
+        let else_branch = Self::subgraph_conversion(&mut node, "else_branch", custom_ops);
+        let outputs = node.outputs.iter().map(Argument::to_type).collect();
+
+        IfNode::new(condition, then_branch, else_branch, outputs)
+    }
+
+    fn loop_conversion<PS: PrecisionSettings + 'static>(
+        mut node: Node,
+        custom_ops: &CustomOps,
+    ) -> LoopNode<PS> {
+        let body = Self::subgraph_conversion(&mut node, "body", custom_ops);
+        let num_carried = body.inputs.len() - 2;
+
+        // The maximum trip count and the condition are optional
+        let optional_input = |index: usize| {
+            node.inputs
+                .get(index)
+                .filter(|input| !input.name.is_empty())
+                .map(Argument::to_type)
+        };
+        let max_trip_count = optional_input(0);
+        let condition = optional_input(1);
+        let initial_values = node.inputs[2..2 + num_carried]
+            .iter()
+            .map(Argument::to_type)
+            .collect();
+        let outputs = node.outputs.iter().map(Argument::to_type).collect();
+
+        LoopNode::new(max_trip_count, condition, initial_values, body, outputs)
+    }
+
+    fn scan_conversion<PS: PrecisionSettings + 'static>(
+        mut node: Node,
+        custom_ops: &CustomOps,
+    ) -> ScanNode<PS> {
+        let (input_axes, output_axes) = scan_config(&node);
+        let body = Self::subgraph_conversion(&mut node, "body", custom_ops);
+        let num_states = body.inputs.len() - input_axes.len();
+
+        let initial_states = node.inputs[..num_states]
+            .iter()
+            .map(Argument::to_type)
+            .collect();
+        let inputs = node.inputs[num_states..num_states + input_axes.len()]
+            .iter()
+            .map(Argument::to_tensor_type)
+            .collect();
+        let outputs = node.outputs.iter().map(Argument::to_type).collect();
+
+        ScanNode::new(
+            initial_states,
+            inputs,
+            body,
+            outputs,
+            input_axes,
+            output_axes,
+        )
+    }
+
+    fn custom_conversion(node: Node, custom_ops: &CustomOps) -> CustomNode {
+        let NodeType::Custom(op_type) = &node.node_type else {
+            panic!("Expected a custom operator, found {}", node.node_type);
+        };
+        let Some(handler) = custom_ops.get(op_type) else {
+            panic!(
+                "Unsupported node conversion {op_type}, a handler can be registered with ModelGen::custom_op"
+            );
+        };
+
+        let names = |arguments: &[Argument]| arguments.iter().map(|arg| arg.name.clone()).collect();
+        let custom_op = CustomOp {
+            op_type: op_type.clone(),
+            name: node.name.clone(),
+            inputs: names(&node.inputs),
+            outputs: names(&node.outputs),
+            attrs: node.attrs.clone(),
+        };
+        let code = handler(&custom_op)
+            .parse::<TokenStream>()
+            .unwrap_or_else(|err| panic!("{op_type}: invalid generated code: {err}"));
+
+        // The optional inputs left out have an empty name
+        let inputs = node
+            .inputs
+            .iter()
+            .filter(|input| !input.name.is_empty())
+            .map(Argument::to_type)
+            .collect();
+        let outputs = node.outputs.iter().map(Argument::to_type).collect();
+
+        CustomNode::new(inputs, outputs, code)
+    }
 }
 
 fn extract_next_data_serialize<E: Element>(node: &mut Node) -> Option<DataSerialize<E>> {
@@ -523,6 +1479,27 @@ fn extract_next_data_serialize<E: Element>(node: &mut Node) -> Option<DataSerial
     Some(node.states.remove(0).into_data_serialize::<E>())
 }
 
+fn extract_rnn_gate_data<PS: PrecisionSettings>(node: &mut Node, bias: bool) -> RnnGateData<PS> {
+    let mut next = || {
+        extract_next_data_serialize::<PS::FloatElem>(node)
+            .unwrap_or_else(|| panic!("{}: missing gate weights", node.node_type))
+    };
+
+    let input_weight = next();
+    let hidden_weight = next();
+    let (input_bias, hidden_bias) = match bias {
+        true => (Some(next()), Some(next())),
+        false => (None, None),
+    };
+
+    RnnGateData {
+        input_weight,
+        hidden_weight,
+        input_bias,
+        hidden_bias,
+    }
+}
+
 impl State {
     pub fn into_data_serialize<E: Element>(self) -> DataSerialize<E> {
         match self.ty {
@@ -534,7 +1511,12 @@ impl State {
 impl Argument {
     pub fn to_tensor_type(&self) -> TensorType {
         match &self.ty {
-            ArgType::Tensor(tensor) => TensorType::new_float(self.name.clone(), tensor.dim),
+            ArgType::Tensor(tensor) => TensorType::new(
+                self.name.clone(),
+                tensor.dim,
+                tensor.elem_type.clone().into(),
+                None,
+            ),
             _ => panic!("Can't transform to tensor."),
         }
     }
@@ -544,18 +1526,26 @@ impl Argument {
             ArgType::Tensor(tensor) => {
                 // Treat tensor with dim 0 as scalar
                 if tensor.dim == 0 {
-                    // FIXME Convert to correct scalar type (@antimora 8/1/2023)
-                    // Currently it's not dangerous because we don't use specific scalar type
-                    Type::Scalar(ScalarType::new(self.name.clone(), ScalarKind::Float64))
+                    Type::Scalar(ScalarType::new(
+                        self.name.clone(),
+                        (&tensor.elem_type).into(),
+                    ))
                 } else {
-                    Type::Tensor(TensorType::new_float(self.name.clone(), tensor.dim))
+                    Type::Tensor(self.to_tensor_type())
                 }
             }
 
             ArgType::Scalar(elem_type) => {
                 Type::Scalar(ScalarType::new(self.name.clone(), elem_type.into()))
             }
-            ArgType::Shape(_shape) => panic!("Can't transform shape to tensor."),
+            ArgType::Shape(_) => Type::Shape(self.to_shape_type()),
+        }
+    }
+
+    pub fn to_shape_type(&self) -> ShapeType {
+        match &self.ty {
+            ArgType::Shape(rank) => ShapeType::new(self.name.clone(), *rank),
+            _ => panic!("Can't transform to shape."),
         }
     }
 }
@@ -579,6 +1569,7 @@ impl From<ElementType> for TensorKind {
         match elem_type {
             ElementType::Float32 => TensorKind::Float,
             ElementType::Float64 => TensorKind::Float,
+            ElementType::Float16 => TensorKind::Float,
             ElementType::Int32 => TensorKind::Int,
             ElementType::Int64 => TensorKind::Int,
             ElementType::Bool => TensorKind::Bool,
@@ -598,7 +1589,10 @@ impl Tensor {
             TensorData::Int32(val) => DataSerialize::new(val, self.shape.unwrap()).convert(),
             TensorData::Int64(val) => DataSerialize::new(val, self.shape.unwrap()).convert(),
             TensorData::String(_) => panic!("String tensor unsupported"),
-            TensorData::Bool(_) => panic!("Bool tensor unsupported"),
+            TensorData::Bool(val) => {
+                let val = val.into_iter().map(i64::from).collect::<Vec<_>>();
+                DataSerialize::new(val, self.shape.unwrap()).convert()
+            }
         }
     }
 }
@@ -0,0 +1,356 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use serde::Deserialize;
+
+use super::ir::{
+    ArgType, Argument, AttributeValue, ElementType, Node, NodeType, ONNXGraph, Tensor, TensorData,
+};
+
+/// The script computing the reference values of a model with ONNX Runtime.
+const REFERENCE_SCRIPT: &str = include_str!("../../scripts/onnx_reference.py");
+
+/// The values of a model computed by a reference runtime on sample inputs, by ONNX name.
+#[derive(Debug, Deserialize)]
+pub(crate) struct Reference {
+    inputs: HashMap<String, ReferenceTensor>,
+    values: HashMap<String, ReferenceTensor>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReferenceTensor {
+    shape: Vec<usize>,
+    data: Vec<f32>,
+}
+
+/// A value computed by a node of the model, compared with its reference value.
+#[derive(Debug, Clone)]
+pub(crate) struct VerifiedValue {
+    node: String,
+    onnx_node: String,
+    value: String,
+    onnx_value: String,
+}
+
+impl Reference {
+    /// Read the reference values saved by the reference script.
+    pub(crate) fn load(file: &Path) -> Self {
+        log::info!("Reading the reference values from {:?}", file);
+
+        let json = fs::read_to_string(file).unwrap_or_else(|error| {
+            panic!("Unable to read the reference values {file:?}: {error}")
+        });
+
+        serde_json::from_str(&json)
+            .unwrap_or_else(|error| panic!("Invalid reference values {file:?}: {error}"))
+    }
+
+    /// Run the model with ONNX Runtime through the reference script, saving the reference values
+    /// to the given file.
+    pub(crate) fn compute(model: &Path, file: &PathBuf) -> Self {
+        log::info!("Computing the reference values of {:?}", model);
+
+        let script = file.with_extension("py");
+        fs::write(&script, REFERENCE_SCRIPT).unwrap();
+
+        let status = Command::new("python3")
+            .arg(&script)
+            .arg(model)
+            .arg(file)
+            .status()
+            .unwrap_or_else(|error| panic!("Unable to run the reference script: {error}"));
+        if !status.success() {
+            panic!(
+                "The reference script failed ({status}), it requires the onnx and onnxruntime \
+                 python packages"
+            );
+        }
+
+        Self::load(file)
+    }
+}
+
+impl ONNXGraph {
+    /// The graph computing the model on the reference inputs, returning each value computed by its
+    /// nodes followed by the reference value.
+    pub(crate) fn into_verification(mut self, reference: &Reference) -> (Self, Vec<VerifiedValue>) {
+        let onnx_values = self
+            .old_input_names
+            .iter()
+            .map(|(old, new)| (new.clone(), old.clone()))
+            .collect::<HashMap<_, _>>();
+        let onnx_nodes = self
+            .old_node_names
+            .iter()
+            .map(|(old, new)| (new.clone(), old.clone()))
+            .collect::<HashMap<_, _>>();
+        let onnx_name = |names: &HashMap<String, String>, name: &String| {
+            names.get(name).cloned().unwrap_or_else(|| name.clone())
+        };
+
+        // The inputs are the constant reference inputs
+        let mut nodes = self
+            .inputs
+            .iter()
+            .map(|input| {
+                let name = onnx_name(&onnx_values, &input.name);
+                let Some(tensor) = reference.inputs.get(&name) else {
+                    panic!("No reference value for the input {name}");
+                };
+                if !is_float(input) {
+                    panic!("Only the models with float inputs can be verified, not {name}");
+                }
+
+                reference_constant(format!("reference_{}", input.name), input.clone(), tensor)
+            })
+            .collect::<Vec<_>>();
+
+        let mut values = Vec::new();
+        let mut expected = Vec::new();
+        for node in self.nodes.iter() {
+            for output in node
+                .outputs
+                .iter()
+                .filter(|output| is_float(output) && dim(output) > 0)
+            {
+                let onnx_value = onnx_name(&onnx_values, &output.name);
+                let Some(tensor) = reference.values.get(&onnx_value) else {
+                    continue;
+                };
+
+                let value = VerifiedValue {
+                    node: node.name.clone(),
+                    onnx_node: onnx_name(&onnx_nodes, &node.name),
+                    value: output.name.clone(),
+                    onnx_value,
+                };
+                let name = format!("expected_{}", output.name);
+                let argument = Argument {
+                    name: name.clone(),
+                    ty: output.ty.clone(),
+                };
+
+                expected.push(reference_constant(name, argument, tensor));
+                values.push(value);
+            }
+        }
+
+        if values.is_empty() {
+            panic!("No value of the model has a reference value to be verified against");
+        }
+
+        self.outputs = values
+            .iter()
+            .zip(expected.iter())
+            .flat_map(|(value, expected)| {
+                let output = self
+                    .nodes
+                    .iter()
+                    .flat_map(|node| node.outputs.iter())
+                    .find(|output| output.name == value.value)
+                    .unwrap();
+
+                [output.clone(), expected.outputs[0].clone()]
+            })
+            .collect();
+        nodes.append(&mut self.nodes);
+        nodes.append(&mut expected);
+        self.nodes = nodes;
+        self.inputs.clear();
+
+        (self, values)
+    }
+}
+
+/// Whether the argument is a float tensor or scalar.
+fn is_float(argument: &Argument) -> bool {
+    let elem_type = match &argument.ty {
+        ArgType::Tensor(tensor) => &tensor.elem_type,
+        ArgType::Scalar(elem_type) => elem_type,
+        ArgType::Shape(_) => return false,
+    };
+
+    matches!(elem_type, ElementType::Float32 | ElementType::Float64)
+}
+
+/// The number of dimensions of the argument, scalars having none.
+fn dim(argument: &Argument) -> usize {
+    match &argument.ty {
+        ArgType::Tensor(tensor) => tensor.dim,
+        ArgType::Scalar(_) => 0,
+        ArgType::Shape(_) => 1,
+    }
+}
+
+/// A Constant node outputting the reference value.
+fn reference_constant(name: String, output: Argument, tensor: &ReferenceTensor) -> Node {
+    let dim = dim(&output);
+    if tensor.shape.len() != dim {
+        panic!(
+            "The reference value of {} has {} dimensions instead of {}",
+            output.name,
+            tensor.shape.len(),
+            dim
+        );
+    }
+
+    let value = Tensor {
+        elem_type: ElementType::Float32,
+        dim,
+        data: Some(TensorData::Float32(tensor.data.clone())),
+        shape: Some(tensor.shape.clone()),
+    };
+
+    Node {
+        node_type: NodeType::Constant,
+        name,
+        inputs: vec![],
+        outputs: vec![output],
+        states: vec![],
+        attrs: [("value".to_string(), AttributeValue::Tensor(value))]
+            .into_iter()
+            .collect(),
+    }
+}
+
+/// The `verify` method of the verification model, comparing each value with its reference value.
+pub(crate) fn verify_codegen(values: &[VerifiedValue]) -> TokenStream {
+    let ident = |name: &str| Ident::new(name, Span::call_site());
+    let outputs = values.iter().map(|value| {
+        let name = ident(&value.value);
+        let expected = ident(&format!("expected_{}", value.value));
+        quote! { #name, #expected }
+    });
+    let comparisons = values.iter().map(|value| {
+        let name = ident(&value.value);
+        let expected = ident(&format!("expected_{}", value.value));
+        let description = format!(
+            "{} (ONNX node {:?}) output {} (ONNX value {:?})",
+            value.node, value.onnx_node, value.value, value.onnx_value
+        );
+
+        quote! {
+            report.extend(verify_value(#description, #name, #expected, tolerance));
+        }
+    });
+
+    quote! {
+        _blank_!();
+        impl<B: Backend> Model<B> {
+            /// Compare each value computed by the model on the reference inputs with the value
+            /// computed by the reference runtime.
+            ///
+            /// Returns the report of the values differing by more than the tolerance, in the order
+            /// of the nodes, the first one being the node to look at.
+            pub fn verify(&self, tolerance: f64) -> Result<(), String> {
+                let (#(#outputs),*) = self.forward();
+                let mut report = Vec::new();
+
+                #(#comparisons)*
+
+                match report.is_empty() {
+                    true => Ok(()),
+                    false => Err(report.join("\n")),
+                }
+            }
+        }
+        _blank_!();
+
+        fn verify_value<B: Backend, const D: usize>(
+            description: &str,
+            value: Tensor<B, D>,
+            expected: Tensor<B, D>,
+            tolerance: f64,
+        ) -> Option<String> {
+            if value.dims() != expected.dims() {
+                return Some(format!(
+                    "{description}: shape {:?} instead of {:?}",
+                    value.dims(),
+                    expected.dims()
+                ));
+            }
+
+            let diff = value.sub(expected).abs().max().into_scalar();
+            let diff = burn::tensor::ElementConversion::elem::<f64>(diff);
+
+            match diff.is_nan() || diff > tolerance {
+                true => Some(format!("{description}: max difference {diff}")),
+                false => None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::onnx::ir::TensorArg;
+
+    fn argument(name: &str) -> Argument {
+        Argument {
+            name: name.to_string(),
+            ty: ArgType::Tensor(TensorArg::new(2, ElementType::Float32)),
+        }
+    }
+
+    fn reference_tensor() -> ReferenceTensor {
+        ReferenceTensor {
+            shape: vec![1, 2],
+            data: vec![1.0, -1.0],
+        }
+    }
+
+    #[test]
+    fn verification_graph_should_return_the_values_and_the_reference_values() {
+        let graph = ONNXGraph {
+            nodes: vec![Node {
+                node_type: NodeType::Relu,
+                name: "relu1".to_string(),
+                inputs: vec![argument("input1")],
+                outputs: vec![argument("relu1_out1")],
+                states: vec![],
+                attrs: Default::default(),
+            }],
+            inputs: vec![argument("input1")],
+            outputs: vec![argument("relu1_out1")],
+            old_node_names: [("Relu_0".to_string(), "relu1".to_string())].into(),
+            old_input_names: [
+                ("x".to_string(), "input1".to_string()),
+                ("y".to_string(), "relu1_out1".to_string()),
+            ]
+            .into(),
+        };
+        let reference = Reference {
+            inputs: [("x".to_string(), reference_tensor())].into(),
+            values: [("y".to_string(), reference_tensor())].into(),
+        };
+
+        let (graph, values) = graph.into_verification(&reference);
+
+        let node_types = graph
+            .nodes
+            .iter()
+            .map(|node| node.node_type.clone())
+            .collect::<Vec<_>>();
+        let outputs = graph
+            .outputs
+            .iter()
+            .map(|output| output.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            node_types,
+            vec![NodeType::Constant, NodeType::Relu, NodeType::Constant]
+        );
+        assert_eq!(graph.nodes[0].outputs[0].name, "input1");
+        assert!(graph.inputs.is_empty());
+        assert_eq!(outputs, vec!["relu1_out1", "expected_relu1_out1"]);
+        assert_eq!(values[0].onnx_node, "Relu_0");
+        assert_eq!(values[0].onnx_value, "y");
+    }
+}
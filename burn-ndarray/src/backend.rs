@@ -6,16 +6,6 @@ use crate::NdArrayTensor;
 
 use burn_tensor::backend::Backend;
 
-use rand::{rngs::StdRng, SeedableRng};
-
-#[cfg(feature = "std")]
-use std::sync::Mutex;
-
-#[cfg(not(feature = "std"))]
-use burn_common::stub::Mutex;
-
-pub(crate) static SEED: Mutex<Option<StdRng>> = Mutex::new(None);
-
 /// The device type for the ndarray backend.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum NdArrayDevice {
@@ -57,8 +47,6 @@ impl<E: FloatNdArrayElement> Backend for NdArrayBackend<E> {
     }
 
     fn seed(seed: u64) {
-        let rng = StdRng::seed_from_u64(seed);
-        let mut seed = SEED.lock().unwrap();
-        *seed = Some(rng);
+        burn_common::rand::seed(seed);
     }
 }
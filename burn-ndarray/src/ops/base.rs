@@ -1,6 +1,5 @@
 use alloc::vec::Vec;
 use burn_tensor::Data;
-use burn_tensor::ElementConversion;
 use core::cmp::Ordering;
 use core::{marker::PhantomData, ops::Range};
 use ndarray::s;
@@ -11,6 +10,7 @@ use ndarray::Axis;
 use ndarray::Dim;
 use ndarray::IxDyn;
 use ndarray::SliceInfoElem;
+use ndarray::Zip;
 
 use crate::element::NdArrayElement;
 use crate::ops::macros::{keepdim, mean_dim, sum_dim};
@@ -171,6 +171,30 @@ where
         NdArrayTensor { array }
     }
 
+    /// Applies the function to each pair of elements, the tensors being broadcasted to a
+    /// common shape.
+    pub fn elementwise_op<const D: usize, F>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+        func: F,
+    ) -> NdArrayTensor<E, D>
+    where
+        F: Fn(&E, &E) -> E,
+    {
+        let shape: Vec<usize> = lhs
+            .array
+            .shape()
+            .iter()
+            .zip(rhs.array.shape())
+            .map(|(lhs, rhs)| usize::max(*lhs, *rhs))
+            .collect();
+        let lhs = lhs.array.broadcast(shape.clone()).unwrap();
+        let rhs = rhs.array.broadcast(shape).unwrap();
+        let array = Zip::from(&lhs).and(&rhs).map_collect(func).into_shared();
+
+        NdArrayTensor { array }
+    }
+
     pub fn mean<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, 1> {
         let data = Data::from([tensor.array.mean().unwrap()]);
         NdArrayTensor::from_data(data)
@@ -298,15 +322,27 @@ where
         mask: NdArrayTensor<bool, D>,
         source: NdArrayTensor<E, D>,
     ) -> NdArrayTensor<E, D> {
-        let mask_mul_4tensor = mask.array.mapv(|x| match x {
-            true => 0.elem(),
-            false => 1.elem(),
-        });
-        let mask_mul_4source = mask.array.mapv(|x| match x {
-            true => 1.elem(),
-            false => 0.elem(),
-        });
-        let array = (tensor.array * mask_mul_4tensor) + (source.array * mask_mul_4source);
+        // Select the elements instead of multiplying by the mask, so the NaN and infinite values
+        // are not propagated.
+        let shape: Vec<usize> = tensor
+            .array
+            .shape()
+            .iter()
+            .zip(mask.array.shape())
+            .zip(source.array.shape())
+            .map(|((tensor, mask), source)| usize::max(usize::max(*tensor, *mask), *source))
+            .collect();
+        let tensor = tensor.array.broadcast(shape.clone()).unwrap();
+        let mask = mask.array.broadcast(shape.clone()).unwrap();
+        let source = source.array.broadcast(shape).unwrap();
+        let array = Zip::from(&tensor)
+            .and(&mask)
+            .and(&source)
+            .map_collect(|tensor, mask, source| match mask {
+                true => *source,
+                false => *tensor,
+            })
+            .into_shared();
 
         NdArrayTensor::new(array)
     }
@@ -316,15 +352,13 @@ where
         mask: NdArrayTensor<bool, D>,
         value: E,
     ) -> NdArrayTensor<E, D> {
-        let mask_mul = mask.array.mapv(|x| match x {
-            true => 0.elem(),
-            false => 1.elem(),
-        });
-        let mask_add = mask.array.mapv(|x| match x {
-            true => value,
-            false => 0.elem(),
-        });
-        let array = (tensor.array * mask_mul) + mask_add;
+        let array = Zip::from(&tensor.array)
+            .and_broadcast(&mask.array)
+            .map_collect(|tensor, mask| match mask {
+                true => value,
+                false => *tensor,
+            })
+            .into_shared();
 
         NdArrayTensor::new(array)
     }
@@ -410,7 +444,7 @@ where
     }
 
     pub fn clamp_min<const D: usize>(tensor: NdArrayTensor<E, D>, min: E) -> NdArrayTensor<E, D> {
-        let array = tensor.array.mapv(|x| match x < min {
+        let array = tensor.array.mapv_into(|x| match x < min {
             true => min,
             false => x,
         });
@@ -419,7 +453,7 @@ where
     }
 
     pub fn clamp_max<const D: usize>(tensor: NdArrayTensor<E, D>, max: E) -> NdArrayTensor<E, D> {
-        let array = tensor.array.mapv(|x| match x > max {
+        let array = tensor.array.mapv_into(|x| match x > max {
             true => max,
             false => x,
         });
@@ -432,7 +466,7 @@ where
         min: E,
         max: E,
     ) -> NdArrayTensor<E, D> {
-        let array = tensor.array.mapv(|x| match x < min {
+        let array = tensor.array.mapv_into(|x| match x < min {
             true => min,
             false => match x > max {
                 true => max,
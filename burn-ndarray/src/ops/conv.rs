@@ -1,5 +1,8 @@
 use burn_tensor::{
-    ops::{conv::calculate_conv_output_size, ConvOptions, ConvTransposeOptions},
+    ops::{
+        conv::{calculate_conv_output_size, calculate_conv_transpose_output_size},
+        ConvOptions, ConvTransposeOptions,
+    },
     ElementConversion,
 };
 use ndarray::{Array4, Dim};
@@ -93,15 +96,22 @@ pub(crate) fn conv_transpose2d<E: FloatNdArrayElement>(
     let [batch_size, _in_channels, in_height, in_width] = x.shape().dims;
     let [in_channels, out_channels, kernel_height, kernel_width] = weight.shape().dims;
 
-    let out_height = (in_height - 1) * stride_height
-        + dilation_height * (kernel_height - 1)
-        + out_padding_height
-        - 2 * padding_height
-        + 1;
-    let out_width =
-        (in_width - 1) * stride_width + dilation_width * (kernel_width - 1) + out_padding_width
-            - 2 * padding_width
-            + 1;
+    let out_height = calculate_conv_transpose_output_size(
+        kernel_height,
+        stride_height,
+        padding_height,
+        out_padding_height,
+        dilation_height,
+        in_height,
+    );
+    let out_width = calculate_conv_transpose_output_size(
+        kernel_width,
+        stride_width,
+        padding_width,
+        out_padding_width,
+        dilation_width,
+        in_width,
+    );
 
     let x = x.array;
     let mut output = Array4::zeros(Dim([
@@ -0,0 +1,128 @@
+use crate::{element::FloatNdArrayElement, tensor::NdArrayTensor};
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_tensor::ElementConversion;
+use ndarray::{Array, ArrayView1, ArrayViewMut1, Axis, IxDyn};
+use rustfft::{num_complex::Complex, FftPlanner};
+
+pub(crate) fn rfft<E: FloatNdArrayElement, const D: usize>(
+    tensor: NdArrayTensor<E, D>,
+    dim: usize,
+) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+    let size = tensor.array.shape()[dim];
+    let frequencies = size / 2 + 1;
+    let fft = FftPlanner::<f64>::new().plan_fft_forward(size);
+    let mut buffer = vec![Complex::default(); size];
+
+    transform(
+        &[&tensor],
+        dim,
+        frequencies,
+        |inputs, mut real, mut imag| {
+            for (value, input) in buffer.iter_mut().zip(inputs[0].iter()) {
+                *value = Complex::new(input.elem(), 0.0);
+            }
+            fft.process(&mut buffer);
+
+            for (k, value) in buffer.iter().take(frequencies).enumerate() {
+                real[k] = value.re.elem();
+                imag[k] = value.im.elem();
+            }
+        },
+    )
+}
+
+pub(crate) fn irfft<E: FloatNdArrayElement, const D: usize>(
+    real: NdArrayTensor<E, D>,
+    imag: NdArrayTensor<E, D>,
+    dim: usize,
+    size: usize,
+) -> NdArrayTensor<E, D> {
+    let frequencies = real.array.shape()[dim];
+    let fft = FftPlanner::<f64>::new().plan_fft_inverse(size);
+    let mut buffer = vec![Complex::default(); size];
+
+    let (output, _) = transform(&[&real, &imag], dim, size, |inputs, mut output, _| {
+        for k in 0..frequencies {
+            buffer[k] = Complex::new(inputs[0][k].elem(), inputs[1][k].elem());
+        }
+        // The other frequencies are the conjugates of the given ones
+        for k in frequencies..size {
+            buffer[k] = buffer[size - k].conj();
+        }
+        fft.process(&mut buffer);
+
+        for (t, value) in buffer.iter().enumerate() {
+            output[t] = (value.re / size as f64).elem();
+        }
+    });
+
+    output
+}
+
+pub(crate) fn fft<E: FloatNdArrayElement, const D: usize>(
+    real: NdArrayTensor<E, D>,
+    imag: NdArrayTensor<E, D>,
+    dim: usize,
+    inverse: bool,
+) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+    let size = real.array.shape()[dim];
+    let mut planner = FftPlanner::<f64>::new();
+    let (fft, scale) = match inverse {
+        true => (planner.plan_fft_inverse(size), 1.0 / size as f64),
+        false => (planner.plan_fft_forward(size), 1.0),
+    };
+    let mut buffer = vec![Complex::default(); size];
+
+    transform(&[&real, &imag], dim, size, |inputs, mut real, mut imag| {
+        for (k, value) in buffer.iter_mut().enumerate() {
+            *value = Complex::new(inputs[0][k].elem(), inputs[1][k].elem());
+        }
+        fft.process(&mut buffer);
+
+        for (k, value) in buffer.iter().enumerate() {
+            real[k] = (value.re * scale).elem();
+            imag[k] = (value.im * scale).elem();
+        }
+    })
+}
+
+/// Apply the function to the lanes of the inputs along the dimension, writing the lanes of the
+/// two outputs, whose dimension has the given size.
+fn transform<E, const D: usize, F>(
+    inputs: &[&NdArrayTensor<E, D>],
+    dim: usize,
+    size: usize,
+    mut func: F,
+) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>)
+where
+    E: FloatNdArrayElement,
+    F: FnMut(&[ArrayView1<E>], ArrayViewMut1<E>, ArrayViewMut1<E>),
+{
+    let mut shape = inputs[0].array.shape().to_vec();
+    shape[dim] = size;
+    let mut first = Array::zeros(IxDyn(&shape));
+    let mut second = Array::zeros(IxDyn(&shape));
+
+    let mut lanes = inputs
+        .iter()
+        .map(|input| input.array.lanes(Axis(dim)).into_iter())
+        .collect::<Vec<_>>();
+
+    for (first, second) in first
+        .lanes_mut(Axis(dim))
+        .into_iter()
+        .zip(second.lanes_mut(Axis(dim)))
+    {
+        let inputs = lanes
+            .iter_mut()
+            .map(|lanes| lanes.next().unwrap())
+            .collect::<Vec<_>>();
+        func(&inputs, first, second);
+    }
+
+    (
+        NdArrayTensor::new(first.into_shared()),
+        NdArrayTensor::new(second.into_shared()),
+    )
+}
@@ -365,6 +365,47 @@ impl<E: FloatNdArrayElement> IntTensorOps<NdArrayBackend<E>> for NdArrayBackend<
         NdArrayTensor::new(array)
     }
 
+    fn int_bitwise_and<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::elementwise_op(lhs, rhs, |a, b| a & b)
+    }
+
+    fn int_bitwise_or<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::elementwise_op(lhs, rhs, |a, b| a | b)
+    }
+
+    fn int_bitwise_xor<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::elementwise_op(lhs, rhs, |a, b| a ^ b)
+    }
+
+    fn int_bitwise_not<const D: usize>(tensor: NdArrayTensor<i64, D>) -> NdArrayTensor<i64, D> {
+        let array = tensor.array.mapv_into(|a| !a).into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn int_bitwise_left_shift<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::elementwise_op(lhs, rhs, |a, b| a.wrapping_shl(*b as u32))
+    }
+
+    fn int_bitwise_right_shift<const D: usize>(
+        lhs: NdArrayTensor<i64, D>,
+        rhs: NdArrayTensor<i64, D>,
+    ) -> NdArrayTensor<i64, D> {
+        NdArrayMathOps::elementwise_op(lhs, rhs, |a, b| a.wrapping_shr(*b as u32))
+    }
+
     fn int_into_float<const D: usize>(
         tensor: <NdArrayBackend<E> as Backend>::IntTensorPrimitive<D>,
     ) -> <NdArrayBackend<E> as Backend>::TensorPrimitive<D> {
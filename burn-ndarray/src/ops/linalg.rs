@@ -0,0 +1,191 @@
+//! Linear algebra decompositions, computed with LAPACK when one of the `linalg-*` features is
+//! enabled, and with the pure Rust implementations of burn-tensor otherwise.
+
+use crate::{element::FloatNdArrayElement, tensor::NdArrayTensor};
+
+#[cfg(not(feature = "ndarray-linalg"))]
+pub(crate) use host::*;
+#[cfg(feature = "ndarray-linalg")]
+pub(crate) use lapack::*;
+
+#[cfg(not(feature = "ndarray-linalg"))]
+mod host {
+    use super::*;
+    use crate::NdArrayBackend;
+    use burn_tensor::ops::{cholesky_data, eigh_data, qr_data, svd_data, TensorOps};
+
+    // The data of the tensors is already on the host, so it is only copied to be decomposed.
+
+    pub(crate) fn cholesky<E: FloatNdArrayElement, const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        NdArrayTensor::from_data(cholesky_data(&NdArrayBackend::into_data(tensor)))
+    }
+
+    pub(crate) fn qr<E: FloatNdArrayElement, const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+        let (q, r) = qr_data(&NdArrayBackend::into_data(tensor));
+
+        (NdArrayTensor::from_data(q), NdArrayTensor::from_data(r))
+    }
+
+    pub(crate) fn svd<E: FloatNdArrayElement, const D: usize, const D2: usize>(
+        tensor: NdArrayTensor<E, D>,
+    ) -> (
+        NdArrayTensor<E, D>,
+        NdArrayTensor<E, D2>,
+        NdArrayTensor<E, D>,
+    ) {
+        let (u, s, vt) = svd_data::<_, D, D2>(&NdArrayBackend::into_data(tensor));
+
+        (
+            NdArrayTensor::from_data(u),
+            NdArrayTensor::from_data(s),
+            NdArrayTensor::from_data(vt),
+        )
+    }
+
+    pub(crate) fn eigh<E: FloatNdArrayElement, const D: usize, const D2: usize>(
+        tensor: NdArrayTensor<E, D>,
+    ) -> (NdArrayTensor<E, D2>, NdArrayTensor<E, D>) {
+        let (values, vectors) = eigh_data::<_, D, D2>(&NdArrayBackend::into_data(tensor));
+
+        (
+            NdArrayTensor::from_data(values),
+            NdArrayTensor::from_data(vectors),
+        )
+    }
+}
+
+#[cfg(feature = "ndarray-linalg")]
+mod lapack {
+    use super::*;
+    use alloc::vec::Vec;
+    use burn_tensor::ElementConversion;
+    use ndarray::{Array, Array2, Axis, Dimension, IxDyn};
+    use ndarray_linalg::{Cholesky, Eigh, JobSvd, QR, SVDDC, UPLO};
+
+    pub(crate) fn cholesky<E: FloatNdArrayElement, const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        let (batch, [_, n]) = batch_shape(&tensor);
+        let factors = matrices(&tensor)
+            .map(|matrix| {
+                matrix.cholesky(UPLO::Lower).expect(
+                    "Can't compute the Cholesky decomposition, the matrix is not positive-definite.",
+                )
+            })
+            .collect();
+
+        stack(&batch, &[n, n], factors)
+    }
+
+    pub(crate) fn qr<E: FloatNdArrayElement, const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+        let (batch, [m, n]) = batch_shape(&tensor);
+        let k = usize::min(m, n);
+        let (q, r): (Vec<_>, Vec<_>) = matrices(&tensor)
+            .map(|matrix| {
+                let (mut q, mut r) = matrix.qr().expect("Can't compute the QR decomposition.");
+
+                // Same convention as the other backends, with the diagonal of R non-negative.
+                for i in 0..k {
+                    if r[[i, i]] < 0.0 {
+                        r.row_mut(i).mapv_inplace(|v| -v);
+                        q.column_mut(i).mapv_inplace(|v| -v);
+                    }
+                }
+
+                (q, r)
+            })
+            .unzip();
+
+        (stack(&batch, &[m, k], q), stack(&batch, &[k, n], r))
+    }
+
+    pub(crate) fn svd<E: FloatNdArrayElement, const D: usize, const D2: usize>(
+        tensor: NdArrayTensor<E, D>,
+    ) -> (
+        NdArrayTensor<E, D>,
+        NdArrayTensor<E, D2>,
+        NdArrayTensor<E, D>,
+    ) {
+        let (batch, [m, n]) = batch_shape(&tensor);
+        let k = usize::min(m, n);
+        let mut u = Vec::new();
+        let mut s = Vec::new();
+        let mut vt = Vec::new();
+
+        for matrix in matrices(&tensor) {
+            let (u_matrix, s_matrix, vt_matrix) = matrix
+                .svddc(JobSvd::Some)
+                .expect("Can't compute the singular value decomposition.");
+
+            u.push(u_matrix.unwrap());
+            s.push(s_matrix);
+            vt.push(vt_matrix.unwrap());
+        }
+
+        (
+            stack(&batch, &[m, k], u),
+            stack(&batch, &[k], s),
+            stack(&batch, &[k, n], vt),
+        )
+    }
+
+    pub(crate) fn eigh<E: FloatNdArrayElement, const D: usize, const D2: usize>(
+        tensor: NdArrayTensor<E, D>,
+    ) -> (NdArrayTensor<E, D2>, NdArrayTensor<E, D>) {
+        let (batch, [_, n]) = batch_shape(&tensor);
+        let (values, vectors): (Vec<_>, Vec<_>) = matrices(&tensor)
+            .map(|matrix| {
+                matrix
+                    .eigh(UPLO::Lower)
+                    .expect("Can't compute the eigendecomposition.")
+            })
+            .unzip();
+
+        (stack(&batch, &[n], values), stack(&batch, &[n, n], vectors))
+    }
+
+    /// The batch dimensions and the shape of the matrices of the tensor.
+    fn batch_shape<E, const D: usize>(tensor: &NdArrayTensor<E, D>) -> (Vec<usize>, [usize; 2]) {
+        let shape = tensor.array.shape();
+
+        (shape[..D - 2].to_vec(), [shape[D - 2], shape[D - 1]])
+    }
+
+    /// The matrices of the tensor converted to `f64`, the only precision used with LAPACK.
+    fn matrices<E: FloatNdArrayElement, const D: usize>(
+        tensor: &NdArrayTensor<E, D>,
+    ) -> impl Iterator<Item = Array2<f64>> {
+        let (batch, [m, n]) = batch_shape(tensor);
+        let values = tensor.array.iter().map(|v| v.elem::<f64>()).collect();
+        let batch_size = batch.iter().product::<usize>();
+        let matrices = Array::from_shape_vec((batch_size, m, n), values).unwrap();
+
+        matrices
+            .axis_iter(Axis(0))
+            .map(|matrix| matrix.to_owned())
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    /// Stacks the outputs of each matrix into a tensor with the batch dimensions.
+    fn stack<E: FloatNdArrayElement, Dim: Dimension, const D: usize>(
+        batch: &[usize],
+        output_shape: &[usize],
+        outputs: Vec<Array<f64, Dim>>,
+    ) -> NdArrayTensor<E, D> {
+        let shape = [batch, output_shape].concat();
+        let values = outputs
+            .iter()
+            .flat_map(|output| output.iter().map(|v| v.elem::<E>()))
+            .collect();
+        let array = Array::from_shape_vec(IxDyn(&shape), values).unwrap();
+
+        NdArrayTensor::new(array.into_shared())
+    }
+}
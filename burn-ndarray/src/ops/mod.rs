@@ -8,6 +8,9 @@ mod tensor;
 pub(crate) mod adaptive_avgpool;
 pub(crate) mod avgpool;
 pub(crate) mod conv;
+#[cfg(feature = "std")]
+pub(crate) mod fft;
+pub(crate) mod linalg;
 pub(crate) mod macros;
 pub(crate) mod matmul;
 pub(crate) mod maxpool;
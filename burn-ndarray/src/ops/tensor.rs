@@ -3,18 +3,20 @@ use alloc::vec::Vec;
 use core::ops::Range;
 
 // Current crate
-use super::{matmul::matmul, NdArrayMathOps, NdArrayOps};
+#[cfg(feature = "std")]
+use super::fft;
+use super::{linalg, matmul::matmul, NdArrayMathOps, NdArrayOps};
 use crate::element::FloatNdArrayElement;
+use crate::NdArrayDevice;
 use crate::{tensor::NdArrayTensor, NdArrayBackend};
-use crate::{NdArrayDevice, SEED};
 
 // Workspace crates
-use burn_common::rand::get_seeded_rng;
+use burn_common::rand::with_device_rng;
 use burn_tensor::Distribution;
 use burn_tensor::{backend::Backend, ops::TensorOps, Data, ElementConversion, Shape};
 
 // External crates
-use libm::{cos, erf, sin, tanh};
+use libm::{acos, asin, atan, atan2, cos, cosh, erf, erfc, lgamma, sin, sinh, tan, tanh};
 
 #[cfg(not(feature = "std"))]
 #[allow(unused_imports)]
@@ -30,15 +32,9 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
         distribution: Distribution<E>,
         device: &NdArrayDevice,
     ) -> NdArrayTensor<E, D> {
-        let mut seed = SEED.lock().unwrap();
-        let mut rng = if let Some(rng_seeded) = seed.as_ref() {
-            rng_seeded.clone()
-        } else {
-            get_seeded_rng()
-        };
-        let tensor = Self::from_data(Data::random(shape, distribution, &mut rng), device);
-        *seed = Some(rng);
-        tensor
+        let data = with_device_rng(device, |rng| Data::random(shape, distribution, rng));
+
+        Self::from_data(data, device)
     }
 
     fn shape<const D: usize>(tensor: &NdArrayTensor<E, D>) -> Shape<D> {
@@ -232,6 +228,24 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
         NdArrayTensor::new(array)
     }
 
+    fn is_nan<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<bool, D> {
+        let array = tensor
+            .array
+            .mapv(|a| a.to_f64().unwrap().is_nan())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn is_inf<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<bool, D> {
+        let array = tensor
+            .array
+            .mapv(|a| a.to_f64().unwrap().is_infinite())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
     fn greater<const D: usize>(
         lhs: NdArrayTensor<E, D>,
         rhs: NdArrayTensor<E, D>,
@@ -404,6 +418,69 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
         NdArrayTensor::new(array)
     }
 
+    fn atan<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| atan(a.to_f64().unwrap()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn tan<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| tan(a.to_f64().unwrap()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn asin<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| asin(a.to_f64().unwrap()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn acos<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| acos(a.to_f64().unwrap()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn atan2<const D: usize>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::elementwise_op(lhs, rhs, |a, b| {
+            atan2(a.to_f64().unwrap(), b.to_f64().unwrap()).elem()
+        })
+    }
+
+    fn sinh<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| sinh(a.to_f64().unwrap()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn cosh<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| cosh(a.to_f64().unwrap()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
     fn tanh<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
         let array = tensor
             .array
@@ -422,6 +499,24 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
         NdArrayTensor::new(array)
     }
 
+    fn erfc<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| erfc(a.to_f64().unwrap()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
+    fn lgamma<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .mapv_into(|a| lgamma(a.to_f64().unwrap()).elem())
+            .into_shared();
+
+        NdArrayTensor::new(array)
+    }
+
     fn cat<const D: usize>(tensors: Vec<NdArrayTensor<E, D>>, dim: usize) -> NdArrayTensor<E, D> {
         NdArrayOps::cat(tensors, dim)
     }
@@ -444,4 +539,58 @@ impl<E: FloatNdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E>
         let array = tensor.array.mapv(|a| a.elem()).into_shared();
         NdArrayTensor { array }
     }
+
+    #[cfg(feature = "std")]
+    fn rfft<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+        fft::rfft(tensor, dim)
+    }
+
+    #[cfg(feature = "std")]
+    fn irfft<const D: usize>(
+        real: NdArrayTensor<E, D>,
+        imag: NdArrayTensor<E, D>,
+        dim: usize,
+        size: usize,
+    ) -> NdArrayTensor<E, D> {
+        fft::irfft(real, imag, dim, size)
+    }
+
+    #[cfg(feature = "std")]
+    fn fft<const D: usize>(
+        real: NdArrayTensor<E, D>,
+        imag: NdArrayTensor<E, D>,
+        dim: usize,
+        inverse: bool,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+        fft::fft(real, imag, dim, inverse)
+    }
+
+    fn cholesky<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        linalg::cholesky(tensor)
+    }
+
+    fn qr<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+    ) -> (NdArrayTensor<E, D>, NdArrayTensor<E, D>) {
+        linalg::qr(tensor)
+    }
+
+    fn svd<const D: usize, const D2: usize>(
+        tensor: NdArrayTensor<E, D>,
+    ) -> (
+        NdArrayTensor<E, D>,
+        NdArrayTensor<E, D2>,
+        NdArrayTensor<E, D>,
+    ) {
+        linalg::svd(tensor)
+    }
+
+    fn eigh<const D: usize, const D2: usize>(
+        tensor: NdArrayTensor<E, D>,
+    ) -> (NdArrayTensor<E, D2>, NdArrayTensor<E, D>) {
+        linalg::eigh(tensor)
+    }
 }
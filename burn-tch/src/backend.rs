@@ -55,6 +55,45 @@ impl From<tch::Device> for TchDevice {
     }
 }
 
+impl TchDevice {
+    /// Whether the device is available on the system.
+    pub fn is_available(&self) -> bool {
+        match self {
+            TchDevice::Cpu => true,
+            TchDevice::Cuda(index) => (*index as i64) < tch::Cuda::device_count(),
+            TchDevice::Mps => tch::utils::has_mps(),
+            TchDevice::Vulkan => tch::utils::has_vulkan(),
+        }
+    }
+
+    /// Whether the device has a kernel for the given operations.
+    ///
+    /// The operations without a kernel on the device run on the CPU, their inputs being copied to
+    /// the CPU and their outputs copied back to the device. The Metal Performance Shaders of
+    /// libtorch don't implement the FFT, the matrix decompositions and grid sampling, nor the
+    /// `f64` elements, so `TchBackend<f32>` should be used on that device.
+    pub fn supports(&self, kernel: TchKernel) -> bool {
+        !matches!(
+            (self, kernel),
+            (
+                TchDevice::Mps,
+                TchKernel::Fft | TchKernel::Linalg | TchKernel::GridSample
+            )
+        )
+    }
+}
+
+/// The groups of operations whose kernels are missing on some [devices](TchDevice).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TchKernel {
+    /// The fast Fourier transforms.
+    Fft,
+    /// The linear system solver and the matrix decompositions.
+    Linalg,
+    /// The grid sampling.
+    GridSample,
+}
+
 impl Default for TchDevice {
     fn default() -> Self {
         Self::Cpu
@@ -81,7 +120,7 @@ impl<E: TchElement> Backend for TchBackend<E> {
     type BoolTensorPrimitive<const D: usize> = TchTensor<bool, D>;
 
     fn seed(seed: u64) {
-        tch::manual_seed(seed as i64);
+        burn_common::rand::seed(seed);
     }
 
     fn ad_enabled() -> bool {
@@ -92,3 +131,17 @@ impl<E: TchElement> Backend for TchBackend<E> {
         "tch".to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_run_unsupported_kernels_on_cpu() {
+        assert!(TchDevice::Cpu.is_available());
+        assert!(TchDevice::Cpu.supports(TchKernel::Linalg));
+        assert!(TchDevice::Cuda(0).supports(TchKernel::Fft));
+        assert!(!TchDevice::Mps.supports(TchKernel::Fft));
+        assert!(!TchDevice::Mps.supports(TchKernel::GridSample));
+    }
+}
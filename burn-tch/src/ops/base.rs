@@ -1,9 +1,18 @@
 use burn_tensor::Shape;
 use tch::Scalar;
 
-use crate::{TchShape, TchTensor};
+use crate::{TchDevice, TchKernel, TchShape, TchTensor};
 use std::{marker::PhantomData, ops::Range};
 
+/// The device running the kernel for the tensors on the given device, the CPU when the device
+/// doesn't [support](TchDevice::supports) the kernel.
+pub(crate) fn kernel_device(device: tch::Device, kernel: TchKernel) -> tch::Device {
+    match TchDevice::from(device).supports(kernel) {
+        true => device,
+        false => tch::Device::Cpu,
+    }
+}
+
 pub struct TchOps<E: tch::kind::Element + Copy + Default> {
     e: PhantomData<E>,
 }
@@ -92,6 +101,20 @@ impl<E: tch::kind::Element + Copy + Default> TchOps<E> {
         TchTensor::from_existing(tensor, storage)
     }
 
+    pub fn scatter_assign<const D: usize>(
+        dim: usize,
+        tensor: TchTensor<E, D>,
+        indices: TchTensor<i64, D>,
+        value: TchTensor<E, D>,
+    ) -> TchTensor<E, D> {
+        let storage = tensor.storage.clone();
+        let tensor = tensor
+            .tensor
+            .scatter(dim as i64, &indices.tensor, &value.tensor);
+
+        TchTensor::from_existing(tensor, storage)
+    }
+
     pub fn index_select_dim<const D: usize>(
         tensor: TchTensor<E, D>,
         dim: usize,
@@ -350,6 +373,22 @@ impl<E: tch::kind::Element + Copy + Default> TchOps<E> {
         (tensor, indices)
     }
 
+    pub fn topk<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+        k: usize,
+        largest: bool,
+        sorted: bool,
+    ) -> (TchTensor<E, D>, TchTensor<i64, D>) {
+        let storage = tensor.storage.clone();
+        let (tensor, indices) = tensor.tensor.topk(k as i64, dim as i64, largest, sorted);
+
+        let tensor = TchTensor::from_existing(tensor, storage);
+        let indices = TchTensor::new(indices);
+
+        (tensor, indices)
+    }
+
     pub fn min_dim<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
         let storage = tensor.storage.clone();
         let (tensor, _indices) = tensor.tensor.min_dim(dim as i64, true);
@@ -271,6 +271,15 @@ impl<E: TchElement> IntTensorOps<TchBackend<E>> for TchBackend<E> {
         TchOps::scatter(dim, tensor, indices, value)
     }
 
+    fn int_scatter_assign<const D: usize>(
+        dim: usize,
+        tensor: TchTensor<i64, D>,
+        indices: TchTensor<i64, D>,
+        value: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        TchOps::scatter_assign(dim, tensor, indices, value)
+    }
+
     fn int_select<const D: usize>(
         tensor: TchTensor<i64, D>,
         dim: usize,
@@ -331,6 +340,16 @@ impl<E: TchElement> IntTensorOps<TchBackend<E>> for TchBackend<E> {
         TchOps::max_dim_with_indices(tensor, dim)
     }
 
+    fn int_topk<const D: usize>(
+        tensor: TchTensor<i64, D>,
+        dim: usize,
+        k: usize,
+        largest: bool,
+        sorted: bool,
+    ) -> (TchTensor<i64, D>, TchTensor<i64, D>) {
+        TchOps::topk(tensor, dim, k, largest, sorted)
+    }
+
     fn int_min_dim<const D: usize>(tensor: TchTensor<i64, D>, dim: usize) -> TchTensor<i64, D> {
         TchOps::min_dim(tensor, dim)
     }
@@ -362,6 +381,78 @@ impl<E: TchElement> IntTensorOps<TchBackend<E>> for TchBackend<E> {
         tensor.unary_ops(|mut tensor| tensor.abs_(), |tensor| tensor.abs())
     }
 
+    fn int_bitwise_and<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.bitwise_and_tensor_(rhs),
+            |lhs, rhs| lhs.bitwise_and_tensor(rhs),
+            |lhs, rhs| lhs.bitwise_and_tensor(rhs),
+        )
+    }
+
+    fn int_bitwise_or<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.bitwise_or_tensor_(rhs),
+            |lhs, rhs| lhs.bitwise_or_tensor(rhs),
+            |lhs, rhs| lhs.bitwise_or_tensor(rhs),
+        )
+    }
+
+    fn int_bitwise_xor<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.bitwise_xor_tensor_(rhs),
+            |lhs, rhs| lhs.bitwise_xor_tensor(rhs),
+            |lhs, rhs| lhs.bitwise_xor_tensor(rhs),
+        )
+    }
+
+    fn int_bitwise_not<const D: usize>(tensor: TchTensor<i64, D>) -> TchTensor<i64, D> {
+        tensor.unary_ops(
+            |mut tensor| tensor.bitwise_not_(),
+            |tensor| tensor.bitwise_not(),
+        )
+    }
+
+    fn int_bitwise_left_shift<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.bitwise_left_shift_(rhs),
+            |lhs, rhs| lhs.bitwise_left_shift(rhs),
+            |lhs, rhs| lhs.bitwise_left_shift(rhs),
+        )
+    }
+
+    fn int_bitwise_right_shift<const D: usize>(
+        lhs: TchTensor<i64, D>,
+        rhs: TchTensor<i64, D>,
+    ) -> TchTensor<i64, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.bitwise_right_shift_(rhs),
+            |lhs, rhs| lhs.bitwise_right_shift(rhs),
+            |lhs, rhs| lhs.bitwise_right_shift(rhs),
+        )
+    }
+
     fn int_into_float<const D: usize>(tensor: TchTensor<i64, D>) -> TchTensor<E, D> {
         let tensor = tensor.tensor.to_kind(E::KIND);
         TchTensor::new(tensor)
@@ -1,7 +1,8 @@
-use crate::{element::TchElement, TchBackend, TchTensor};
+use super::kernel_device;
+use crate::{element::TchElement, TchBackend, TchKernel, TchTensor};
 use burn_tensor::ops::{
-    ConvOptions, ConvTransposeOptions, MaxPool1dWithIndices, MaxPool2dBackward,
-    MaxPool2dWithIndices, ModuleOps,
+    ConvOptions, ConvTransposeOptions, GridSampleMode, GridSampleOptions, GridSamplePaddingMode,
+    MaxPool1dWithIndices, MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps,
 };
 
 impl<E: TchElement> ModuleOps<TchBackend<E>> for TchBackend<E> {
@@ -67,6 +68,25 @@ impl<E: TchElement> ModuleOps<TchBackend<E>> for TchBackend<E> {
         TchTensor::new(tensor)
     }
 
+    fn conv3d(
+        x: TchTensor<E, 5>,
+        weight: TchTensor<E, 5>,
+        bias: Option<TchTensor<E, 1>>,
+        options: ConvOptions<3>,
+    ) -> TchTensor<E, 5> {
+        let tensor = tch::Tensor::conv3d(
+            &x.tensor,
+            &weight.tensor,
+            bias.map(|t| t.tensor),
+            options.stride.map(|i| i as i64),
+            options.padding.map(|i| i as i64),
+            options.dilation.map(|i| i as i64),
+            options.groups as i64,
+        );
+
+        TchTensor::new(tensor)
+    }
+
     fn conv_transpose2d(
         x: TchTensor<E, 4>,
         weight: TchTensor<E, 4>,
@@ -275,4 +295,29 @@ impl<E: TchElement> ModuleOps<TchBackend<E>> for TchBackend<E> {
 
         TchTensor::new(tensor)
     }
+
+    fn grid_sample_2d(
+        x: TchTensor<E, 4>,
+        grid: TchTensor<E, 4>,
+        options: GridSampleOptions,
+    ) -> TchTensor<E, 4> {
+        let mode = match options.mode {
+            GridSampleMode::Bilinear => 0,
+            GridSampleMode::Nearest => 1,
+        };
+        let padding_mode = match options.padding_mode {
+            GridSamplePaddingMode::Zeros => 0,
+            GridSamplePaddingMode::Border => 1,
+        };
+        let device = x.tensor.device();
+        let kernel_device = kernel_device(device, TchKernel::GridSample);
+        let tensor = x.tensor.to(kernel_device).grid_sampler_2d(
+            &grid.tensor.to(kernel_device),
+            mode,
+            padding_mode,
+            options.align_corners,
+        );
+
+        TchTensor::new(tensor.to(device))
+    }
 }
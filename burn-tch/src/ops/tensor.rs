@@ -1,7 +1,26 @@
-use super::TchOps;
-use crate::{element::TchElement, TchBackend, TchDevice, TchShape, TchTensor};
+use super::{kernel_device, TchOps};
+use crate::{element::TchElement, TchBackend, TchDevice, TchKernel, TchShape, TchTensor};
+use burn_common::rand::{generation, is_device_seeded, with_device_rng, Rng};
 use burn_tensor::{backend::Backend, ops::TensorOps, Data, Distribution, ElementConversion, Shape};
 use std::ops::Range;
+use std::sync::Mutex;
+
+/// The generators libtorch was last seeded from: the generation of the generators of Burn, and
+/// the device when it has its own generator.
+static LIBTORCH_SEED_SOURCE: Mutex<Option<(usize, Option<TchDevice>)>> = Mutex::new(None);
+
+/// Seeds the generator of libtorch from the generator of Burn used for the device, only when it
+/// has been seeded or restored since libtorch was last seeded, so the sequence of libtorch isn't
+/// restarted by every random tensor.
+fn seed_libtorch(device: &TchDevice) {
+    let source = (generation(), is_device_seeded(device).then_some(*device));
+    let mut last_source = LIBTORCH_SEED_SOURCE.lock().unwrap();
+
+    if *last_source != Some(source) {
+        tch::manual_seed(with_device_rng(device, |rng| rng.gen()));
+        *last_source = Some(source);
+    }
+}
 
 impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
     fn from_data<const D: usize>(data: Data<E, D>, device: &TchDevice) -> TchTensor<E, D> {
@@ -13,6 +32,8 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
         distribution: Distribution<E>,
         device: &TchDevice,
     ) -> TchTensor<E, D> {
+        seed_libtorch(device);
+
         match distribution {
             Distribution::Default => {
                 let mut tensor = TchTensor::<E, D>::empty(shape, *device);
@@ -206,6 +227,15 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
         TchOps::scatter(dim, tensor, indices, value)
     }
 
+    fn scatter_assign<const D: usize>(
+        dim: usize,
+        tensor: TchTensor<E, D>,
+        indices: TchTensor<i64, D>,
+        value: TchTensor<E, D>,
+    ) -> TchTensor<E, D> {
+        TchOps::scatter_assign(dim, tensor, indices, value)
+    }
+
     fn select<const D: usize>(
         tensor: TchTensor<E, D>,
         dim: usize,
@@ -269,6 +299,14 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
         TchOps::equal_elem(lhs, rhs.elem::<f64>())
     }
 
+    fn is_nan<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<bool, D> {
+        TchTensor::new(tensor.tensor.isnan())
+    }
+
+    fn is_inf<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<bool, D> {
+        TchTensor::new(tensor.tensor.isinf())
+    }
+
     fn greater<const D: usize>(lhs: TchTensor<E, D>, rhs: TchTensor<E, D>) -> TchTensor<bool, D> {
         TchOps::greater(lhs, rhs)
     }
@@ -356,6 +394,16 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
         TchOps::max_dim_with_indices(tensor, dim)
     }
 
+    fn topk<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+        k: usize,
+        largest: bool,
+        sorted: bool,
+    ) -> (TchTensor<E, D>, TchTensor<i64, D>) {
+        TchOps::topk(tensor, dim, k, largest, sorted)
+    }
+
     fn min_dim<const D: usize>(tensor: TchTensor<E, D>, dim: usize) -> TchTensor<E, D> {
         TchOps::min_dim(tensor, dim)
     }
@@ -402,6 +450,40 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
         tensor.unary_ops(|mut tensor| tensor.sin_(), |tensor| tensor.sin())
     }
 
+    fn atan<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        tensor.unary_ops(|mut tensor| tensor.atan_(), |tensor| tensor.atan())
+    }
+
+    fn tan<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        tensor.unary_ops(|mut tensor| tensor.tan_(), |tensor| tensor.tan())
+    }
+
+    fn asin<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        tensor.unary_ops(|mut tensor| tensor.asin_(), |tensor| tensor.asin())
+    }
+
+    fn acos<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        tensor.unary_ops(|mut tensor| tensor.acos_(), |tensor| tensor.acos())
+    }
+
+    fn atan2<const D: usize>(lhs: TchTensor<E, D>, rhs: TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor::binary_ops_tensor(
+            lhs,
+            rhs,
+            |lhs, rhs| lhs.atan2_(rhs),
+            |lhs, rhs| lhs.atan2(rhs),
+            |lhs, rhs| lhs.atan2(rhs),
+        )
+    }
+
+    fn sinh<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        tensor.unary_ops(|mut tensor| tensor.sinh_(), |tensor| tensor.sinh())
+    }
+
+    fn cosh<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        tensor.unary_ops(|mut tensor| tensor.cosh_(), |tensor| tensor.cosh())
+    }
+
     fn tanh<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
         tensor.unary_ops(|mut tensor| tensor.tanh_(), |tensor| tensor.tanh())
     }
@@ -410,6 +492,14 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
         tensor.unary_ops(|mut tensor| tensor.erf_(), |tensor| tensor.erf())
     }
 
+    fn erfc<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        tensor.unary_ops(|mut tensor| tensor.erfc_(), |tensor| tensor.erfc())
+    }
+
+    fn lgamma<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        tensor.unary_ops(|mut tensor| tensor.lgamma_(), |tensor| tensor.lgamma())
+    }
+
     fn cat<const D: usize>(tensors: Vec<TchTensor<E, D>>, dim: usize) -> TchTensor<E, D> {
         TchOps::cat(tensors, dim)
     }
@@ -440,4 +530,117 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
         let tensor = tensor.tensor.to_kind(tch::Kind::Int64);
         TchTensor::new(tensor)
     }
+
+    fn rfft<const D: usize>(
+        tensor: TchTensor<E, D>,
+        dim: usize,
+    ) -> (TchTensor<E, D>, TchTensor<E, D>) {
+        let device = tensor.tensor.device();
+        let tensor = tensor.tensor.to(kernel_device(device, TchKernel::Fft));
+        let tensor = tensor.fft_rfft(None, dim as i64, "backward");
+
+        (
+            TchTensor::new(tensor.real().to(device)),
+            TchTensor::new(tensor.imag().to(device)),
+        )
+    }
+
+    fn irfft<const D: usize>(
+        real: TchTensor<E, D>,
+        imag: TchTensor<E, D>,
+        dim: usize,
+        size: usize,
+    ) -> TchTensor<E, D> {
+        let device = real.tensor.device();
+        let kernel_device = kernel_device(device, TchKernel::Fft);
+        let tensor = tch::Tensor::complex(
+            &real.tensor.to(kernel_device),
+            &imag.tensor.to(kernel_device),
+        );
+
+        TchTensor::new(
+            tensor
+                .fft_irfft(size as i64, dim as i64, "backward")
+                .to(device),
+        )
+    }
+
+    fn fft<const D: usize>(
+        real: TchTensor<E, D>,
+        imag: TchTensor<E, D>,
+        dim: usize,
+        inverse: bool,
+    ) -> (TchTensor<E, D>, TchTensor<E, D>) {
+        let device = real.tensor.device();
+        let kernel_device = kernel_device(device, TchKernel::Fft);
+        let tensor = tch::Tensor::complex(
+            &real.tensor.to(kernel_device),
+            &imag.tensor.to(kernel_device),
+        );
+        let tensor = match inverse {
+            true => tensor.fft_ifft(None, dim as i64, "backward"),
+            false => tensor.fft_fft(None, dim as i64, "backward"),
+        };
+
+        (
+            TchTensor::new(tensor.real().to(device)),
+            TchTensor::new(tensor.imag().to(device)),
+        )
+    }
+
+    fn solve<const D: usize>(lhs: TchTensor<E, D>, rhs: TchTensor<E, D>) -> TchTensor<E, D> {
+        let device = lhs.tensor.device();
+        let kernel_device = kernel_device(device, TchKernel::Linalg);
+        let output = tch::Tensor::linalg_solve(
+            &lhs.tensor.to(kernel_device),
+            &rhs.tensor.to(kernel_device),
+            true,
+        );
+
+        TchTensor::new(output.to(device))
+    }
+
+    fn cholesky<const D: usize>(tensor: TchTensor<E, D>) -> TchTensor<E, D> {
+        let device = tensor.tensor.device();
+        let tensor = tensor.tensor.to(kernel_device(device, TchKernel::Linalg));
+
+        TchTensor::new(tensor.linalg_cholesky(false).to(device))
+    }
+
+    fn qr<const D: usize>(tensor: TchTensor<E, D>) -> (TchTensor<E, D>, TchTensor<E, D>) {
+        let device = tensor.tensor.device();
+        let tensor = tensor.tensor.to(kernel_device(device, TchKernel::Linalg));
+        let (q, r) = tch::Tensor::linalg_qr(&tensor, "reduced");
+
+        (TchTensor::new(q.to(device)), TchTensor::new(r.to(device)))
+    }
+
+    fn svd<const D: usize, const D2: usize>(
+        tensor: TchTensor<E, D>,
+    ) -> (TchTensor<E, D>, TchTensor<E, D2>, TchTensor<E, D>) {
+        // The driver of `linalg_svd` can't be omitted, it is only supported on CUDA.
+        let device = tensor.tensor.device();
+        let tensor = tensor.tensor.to(kernel_device(device, TchKernel::Linalg));
+        let (u, s, v) = tensor.svd(true, true);
+        let vt = v.transpose(-2, -1);
+
+        (
+            TchTensor::new(u.to(device)),
+            TchTensor::new(s.to(device)),
+            TchTensor::new(vt.to(device)),
+        )
+    }
+
+    fn eigh<const D: usize, const D2: usize>(
+        tensor: TchTensor<E, D>,
+    ) -> (TchTensor<E, D2>, TchTensor<E, D>) {
+        let device = tensor.tensor.device();
+        let tensor = tensor.tensor.to(kernel_device(device, TchKernel::Linalg));
+        let (values, vectors) = tensor.linalg_eigh("L");
+
+        (
+            TchTensor::new(values.to(device)),
+            TchTensor::new(vectors.to(device)),
+        )
+    }
 }
@@ -1,18 +1,44 @@
 use crate::backend::Backend;
 use crate::check::TensorCheck;
 use crate::{check, Tensor};
-use crate::{ElementPrecision, Precision};
+use crate::{ElementConversion, ElementPrecision, Precision};
 
 /// Applies the rectified linear unit function.
 pub fn relu<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
     tensor.relu()
 }
 
+/// Applies the leaky rectified linear unit function.
+///
+/// `leaky_relu(x) = max(0, x) + negative_slope * min(0, x)`
+pub fn leaky_relu<const D: usize, B: Backend>(
+    tensor: Tensor<B, D>,
+    negative_slope: f64,
+) -> Tensor<B, D> {
+    let negative = tensor.clone().neg().relu().mul_scalar(negative_slope);
+
+    tensor.relu().sub(negative)
+}
+
 /// Applies the Gaussian Error Linear Units function as described in the paper in [Gaussian Error Linear Units (GELUs)](https://arxiv.org/pdf/1606.08415v3.pdf).
 pub fn gelu<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
     Tensor::from_primitive(B::gelu(tensor.primitive))
 }
 
+/// Applies the tanh approximation of the Gaussian Error Linear Units function.
+///
+/// `gelu_tanh(x) = 0.5 * x * (1 + tanh(sqrt(2 / pi) * (x + 0.044715 * x^3)))`
+pub fn gelu_tanh<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    let inner = tensor
+        .clone()
+        .powf(3.0)
+        .mul_scalar(0.044715)
+        .add(tensor.clone())
+        .mul_scalar(core::f64::consts::FRAC_2_SQRT_PI * core::f64::consts::FRAC_1_SQRT_2);
+
+    tensor.mul(inner.tanh().add_scalar(1.0)).mul_scalar(0.5)
+}
+
 /// Applies the softmax function on the input tensor along the given dimension.
 ///
 /// `softmax(x_i) = exp(x_i) / sum_j(exp(x_j))`
@@ -65,7 +91,41 @@ pub fn log_sigmoid<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B
     }
 }
 
-/// Applies the silu function
+/// Applies the silu function, also known as swish.
+///
+/// `silu(x) = x * sigmoid(x)`
 pub fn silu<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
     tensor.clone().mul(sigmoid(tensor))
 }
+
+/// Applies the softplus function.
+///
+/// `softplus(x) = log(1 + exp(x))`
+pub fn softplus<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    // Numerically stable: log(1 + exp(x)) = max(0, x) + log(1 + exp(-|x|))
+    tensor.clone().abs().neg().exp().log1p().add(tensor.relu())
+}
+
+/// Applies the mish function as described in the paper [Mish: A Self Regularized Non-Monotonic Activation Function](https://arxiv.org/abs/1908.08681).
+///
+/// `mish(x) = x * tanh(softplus(x))`
+pub fn mish<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    tensor.clone().mul(softplus(tensor).tanh())
+}
+
+/// Applies the hard sigmoid function.
+///
+/// `hard_sigmoid(x) = max(0, min(1, x / 6 + 0.5))`
+pub fn hard_sigmoid<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    tensor
+        .div_scalar(6.0)
+        .add_scalar(0.5)
+        .clamp(0.0.elem(), 1.0.elem())
+}
+
+/// Applies the hard swish function as described in the paper [Searching for MobileNetV3](https://arxiv.org/abs/1905.02244).
+///
+/// `hard_swish(x) = x * hard_sigmoid(x)`
+pub fn hard_swish<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    tensor.clone().mul(hard_sigmoid(tensor))
+}
@@ -0,0 +1,111 @@
+use crate::{backend::Backend, Element, ElementConversion, Numeric, Tensor};
+
+/// The in-place variants of the element wise operations.
+///
+/// The operations reuse the buffer of the tensor when it is uniquely owned, the tensor being
+/// copied when its buffer is shared with other tensors, e.g. with a clone or when the tensor is
+/// tracked by the autodiff graph and needed for the backward pass.
+impl<B, const D: usize, K> Tensor<B, D, K>
+where
+    B: Backend,
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    /// Applies element wise addition operation in place.
+    ///
+    /// `x1 = x1 + x2`
+    ///
+    /// # Panics
+    ///
+    /// If the shapes of the tensors can't be broadcasted.
+    pub fn add_assign(&mut self, other: Self) {
+        self.assign(|tensor| tensor.add(other));
+    }
+
+    /// Applies element wise subtraction operation in place.
+    ///
+    /// `x1 = x1 - x2`
+    ///
+    /// # Panics
+    ///
+    /// If the shapes of the tensors can't be broadcasted.
+    pub fn sub_assign(&mut self, other: Self) {
+        self.assign(|tensor| tensor.sub(other));
+    }
+
+    /// Applies element wise multiplication operation in place.
+    ///
+    /// `x1 = x1 * x2`
+    ///
+    /// # Panics
+    ///
+    /// If the shapes of the tensors can't be broadcasted.
+    pub fn mul_assign(&mut self, other: Self) {
+        self.assign(|tensor| tensor.mul(other));
+    }
+
+    /// Applies element wise division operation in place.
+    ///
+    /// `x1 = x1 / x2`
+    ///
+    /// # Panics
+    ///
+    /// If the shapes of the tensors can't be broadcasted.
+    pub fn div_assign(&mut self, other: Self) {
+        self.assign(|tensor| tensor.div(other));
+    }
+
+    /// Applies element wise addition operation with a scalar in place.
+    ///
+    /// `x = x + s`
+    pub fn add_scalar_assign<E: ElementConversion>(&mut self, other: E) {
+        self.assign(|tensor| tensor.add_scalar(other));
+    }
+
+    /// Applies element wise subtraction operation with a scalar in place.
+    ///
+    /// `x = x - s`
+    pub fn sub_scalar_assign<E: ElementConversion>(&mut self, other: E) {
+        self.assign(|tensor| tensor.sub_scalar(other));
+    }
+
+    /// Applies element wise multiplication operation with a scalar in place.
+    ///
+    /// `x = x * s`
+    pub fn mul_scalar_assign<E: ElementConversion>(&mut self, other: E) {
+        self.assign(|tensor| tensor.mul_scalar(other));
+    }
+
+    /// Applies element wise division operation with a scalar in place.
+    ///
+    /// `x = x / s`
+    pub fn div_scalar_assign<E: ElementConversion>(&mut self, other: E) {
+        self.assign(|tensor| tensor.div_scalar(other));
+    }
+
+    /// Clamps the tensor between the given min and max values in place.
+    pub fn clamp_assign(&mut self, min: K::Elem, max: K::Elem) {
+        self.assign(|tensor| tensor.clamp(min, max));
+    }
+
+    /// Clamps the tensor under a minimum value in place.
+    pub fn clamp_min_assign(&mut self, min: K::Elem) {
+        self.assign(|tensor| tensor.clamp_min(min));
+    }
+
+    /// Clamps the tensor over a maximum value in place.
+    pub fn clamp_max_assign(&mut self, max: K::Elem) {
+        self.assign(|tensor| tensor.clamp_max(max));
+    }
+
+    /// Replaces the tensor with the result of the operation, the tensor being moved into the
+    /// operation so its buffer can be reused.
+    fn assign<F: FnOnce(Self) -> Self>(&mut self, func: F) {
+        // The tensor is swapped with an empty placeholder instead of being cloned, since a clone
+        // would share the buffer and force a copy.
+        let placeholder = Self::empty_device([0; D], &self.device());
+        let tensor = core::mem::replace(self, placeholder);
+
+        *self = func(tensor);
+    }
+}
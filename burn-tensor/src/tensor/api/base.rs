@@ -1,11 +1,10 @@
-use alloc::format;
-use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
 use core::{fmt::Debug, ops::Range};
 
 use crate::{
-    backend::Backend, check, check::TensorCheck, Bool, Data, Float, Int, Shape, TensorKind,
+    backend::Backend, check, check::TensorCheck, Bool, DType, Data, Element, Float, Int, Shape,
+    TensorKind,
 };
 
 /// A tensor with a given backend, shape and data type.
@@ -55,6 +54,29 @@ where
         K::shape(&self.primitive)
     }
 
+    /// Returns the data type of the tensor elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{DType, Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 1, Int>::from_ints([1, 2, 3]);
+    ///     println!("{:?} {}", tensor.dtype(), tensor.elem_size());
+    ///     // I64 8, with a backend using 64-bit integers
+    /// }
+    /// ```
+    pub fn dtype(&self) -> DType {
+        K::dtype()
+    }
+
+    /// Returns the size of the tensor elements in bytes.
+    pub fn elem_size(&self) -> usize {
+        K::dtype().size()
+    }
+
     /// Reshape the tensor to have the given shape.
     ///
     /// A `-1` in the shape is used to infer the remaining dimensions, e.g.: `[2, -1]`
@@ -281,6 +303,87 @@ where
         Self::new(K::slice_assign(self.primitive, ranges, values.primitive))
     }
 
+    /// Split the tensor into chunks of the same size along the given dimension.
+    ///
+    /// Each chunk has `ceil(size / chunks)` elements on the dimension, the last one being smaller
+    /// when the size isn't divisible by the number of chunks. Fewer chunks are returned when the
+    /// dimension has fewer elements than requested. The chunks are slices of the tensor, sharing
+    /// its memory when the backend allows it.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension is higher than the tensor rank, or the number of chunks is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::ones([2, 5]);
+    ///     let chunks = tensor.chunk(3, 1);
+    ///     for chunk in chunks {
+    ///         println!("{:?}", chunk.dims());
+    ///     }
+    ///     // [2, 2]
+    ///     // [2, 2]
+    ///     // [2, 1]
+    /// }
+    /// ```
+    pub fn chunk(self, chunks: usize, dim: usize) -> Vec<Self> {
+        check!(TensorCheck::chunk::<D>(dim, chunks));
+
+        let size = self.dims()[dim];
+        let chunk_size = size.div_ceil(chunks);
+        let sizes = (0..size)
+            .step_by(usize::max(chunk_size, 1))
+            .map(|start| usize::min(chunk_size, size - start))
+            .collect::<Vec<_>>();
+
+        self.split(&sizes, dim)
+    }
+
+    /// Split the tensor along the given dimension into parts of the given sizes.
+    ///
+    /// The parts are slices of the tensor, sharing its memory when the backend allows it.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension is higher than the tensor rank, or the sizes don't sum to the size of the
+    /// dimension.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 1>::from_floats([0.0, 1.0, 2.0, 3.0, 4.0]);
+    ///     let parts = tensor.split(&[1, 4], 0);
+    ///     println!("{} {}", parts[0], parts[1]);
+    ///     // [0.0] [1.0, 2.0, 3.0, 4.0]
+    /// }
+    /// ```
+    pub fn split(self, sizes: &[usize], dim: usize) -> Vec<Self> {
+        check!(TensorCheck::split(&self.shape(), sizes, dim));
+
+        let shape = self.shape();
+        let mut start = 0;
+
+        sizes
+            .iter()
+            .map(|size| {
+                let mut ranges: [Range<usize>; D] = shape.dims.map(|dim| 0..dim);
+                ranges[dim] = start..start + size;
+                start += size;
+
+                Self::new(K::slice(self.primitive.clone(), ranges))
+            })
+            .collect()
+    }
+
     /// Returns the device of the current tensor.
     pub fn device(&self) -> B::Device {
         K::device(&self.primitive)
@@ -326,6 +429,105 @@ where
         Self::new(K::repeat(self.primitive, dim, times))
     }
 
+    /// Broadcast the tensor to the given shape, following the numpy broadcasting rules.
+    ///
+    /// The tensor is unsqueezed to the rank of the shape, then its dimensions of size 1 are
+    /// repeated to the size of the shape. A size of 1 in the shape keeps the size of the tensor.
+    ///
+    /// # Panics
+    ///
+    /// If a dimension of the tensor is neither 1 nor the size of the shape.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::ones(Shape::new([3, 1]));
+    ///     let tensor = tensor.expand([2, 3, 4]);
+    ///     println!("{:?}", tensor.shape());
+    ///     // Shape { dims: [2, 3, 4] }
+    /// }
+    /// ```
+    pub fn expand<const D2: usize, S: Into<Shape<D2>>>(self, shape: S) -> Tensor<B, D2, K> {
+        let shape = shape.into();
+        let tensor = self.unsqueeze::<D2>();
+        let dims = tensor.shape().dims;
+
+        check!(TensorCheck::expand(&dims, &shape.dims));
+
+        dims.into_iter().zip(shape.dims).enumerate().fold(
+            tensor,
+            |tensor, (dim, (size, target))| match size == target || target == 1 {
+                true => tensor,
+                false => tensor.repeat(dim, target),
+            },
+        )
+    }
+
+    /// Shift the elements along the given dimensions, the elements shifted past the last position
+    /// of a dimension being moved to the first positions.
+    ///
+    /// A negative shift moves the elements towards the first positions.
+    ///
+    /// # Panics
+    ///
+    /// - If the number of shifts isn't the number of dimensions.
+    /// - If a dimension is not smaller than the number of dimensions of the tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+    ///     let tensor = tensor.roll(&[1, -1], &[0, 1]);
+    ///     println!("{}", tensor);
+    ///     // [[4.0, 5.0, 3.0], [1.0, 2.0, 0.0]]
+    /// }
+    /// ```
+    pub fn roll(self, shifts: &[i64], dims: &[usize]) -> Self {
+        check!(TensorCheck::roll::<D>(shifts, dims));
+
+        shifts
+            .iter()
+            .zip(dims)
+            .fold(self, |tensor, (shift, dim)| tensor.roll_dim(*shift, *dim))
+    }
+
+    /// Shift the elements along the given dimension, the elements shifted past the last position
+    /// being moved to the first positions.
+    ///
+    /// See [roll](Tensor::roll).
+    pub fn roll_dim(self, shift: i64, dim: usize) -> Self {
+        check!(TensorCheck::roll::<D>(&[shift], &[dim]));
+
+        let dims = self.dims();
+        let size = dims[dim];
+        if size == 0 {
+            return self;
+        }
+
+        let shift = shift.rem_euclid(size as i64) as usize;
+        if shift == 0 {
+            return self;
+        }
+
+        let ranges = |range: Range<usize>| {
+            let mut ranges = dims.map(|size| 0..size);
+            ranges[dim] = range;
+            ranges
+        };
+        let end = self.clone().slice(ranges(size - shift..size));
+        let start = self.slice(ranges(0..size - shift));
+
+        Self::cat(vec![end, start], dim)
+    }
+
     /// Applies element wise equal comparison and returns a boolean tensor.
     ///
     /// # Panics
@@ -351,61 +553,10 @@ where
     }
 }
 
-impl<B, const D: usize, K> Tensor<B, D, K>
-where
-    B: Backend,
-    K: BasicOps<B>,
-    <K as BasicOps<B>>::Elem: Debug,
-{
-    /// Recursively formats the tensor data for display and appends it to the provided accumulator string.
-    ///
-    /// This function is designed to work with tensors of any dimensionality.
-    /// It traverses the tensor dimensions recursively, converting the elements
-    /// to strings and appending them to the accumulator string with the
-    /// appropriate formatting.
-    ///
-    /// # Arguments
-    ///
-    /// * `acc` - A mutable reference to a `String` used as an accumulator for the formatted output.
-    /// * `depth` - The current depth of the tensor dimensions being processed.
-    /// * `multi_index` - A mutable slice of `usize` representing the current indices in each dimension.
-    fn display_recursive(&self, acc: &mut String, depth: usize, multi_index: &mut [usize]) {
-        if depth == 0 {
-            acc.push('[');
-        }
-
-        if depth == self.dims().len() - 1 {
-            // if we are at the innermost dimension, just push its elements into the accumulator
-            for i in 0..self.dims()[depth] {
-                if i > 0 {
-                    acc.push_str(", ");
-                }
-                multi_index[depth] = i;
-                let range: [core::ops::Range<usize>; D] =
-                    core::array::from_fn(|i| multi_index[i]..multi_index[i] + 1);
-                let elem = &self.clone().slice(range).to_data().value[0];
-                acc.push_str(&format!("{elem:?}"));
-            }
-        } else {
-            // otherwise, iterate through the current dimension and recursively display the inner tensors
-            for i in 0..self.dims()[depth] {
-                if i > 0 {
-                    acc.push_str(", ");
-                }
-                acc.push('[');
-                multi_index[depth] = i;
-                self.display_recursive(acc, depth + 1, multi_index);
-                acc.push(']');
-            }
-        }
-
-        if depth == 0 {
-            acc.push(']');
-        }
-    }
-}
-
 /// Pretty print tensors
+///
+/// The tensor is displayed with the default [print options](crate::PrintOptions), the precision
+/// of the formatter overriding the precision of the float elements, e.g. `{:.2}`.
 impl<B, const D: usize, K> core::fmt::Display for Tensor<B, D, K>
 where
     B: Backend,
@@ -414,20 +565,7 @@ where
     <K as BasicOps<B>>::Elem: Debug,
 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        writeln!(f, "Tensor {{")?;
-        write!(f, "  data: ")?;
-
-        let mut acc = String::new();
-        let mut multi_index = vec![0; D];
-        self.display_recursive(&mut acc, 0, &mut multi_index);
-        write!(f, "{acc}")?;
-        writeln!(f, ",")?;
-        writeln!(f, "  shape:  {:?},", self.dims())?;
-        writeln!(f, "  device:  {:?},", self.device())?;
-        writeln!(f, "  backend:  {:?},", B::name())?;
-        writeln!(f, "  kind:  {:?},", K::name())?;
-        writeln!(f, "  dtype:  {:?},", K::elem_type_name())?;
-        write!(f, "}}")
+        core::fmt::Display::fmt(&self.display(crate::PrintOptions::default()), f)
     }
 }
 
@@ -733,6 +871,9 @@ pub trait BasicOps<B: Backend>: TensorKind<B> {
         rhs: Self::Primitive<D>,
     ) -> Tensor<B, D, Bool>;
 
+    /// Returns the data type of the tensor elements.
+    fn dtype() -> DType;
+
     /// Returns the name of the element type.
     fn elem_type_name() -> &'static str {
         core::any::type_name::<Self::Elem>()
@@ -811,6 +952,10 @@ impl<B: Backend> BasicOps<B> for Float {
     ) -> Tensor<B, D, Bool> {
         Tensor::new(B::equal(lhs, rhs))
     }
+
+    fn dtype() -> DType {
+        B::FloatElem::dtype()
+    }
 }
 
 impl<B: Backend> BasicOps<B> for Int {
@@ -885,6 +1030,10 @@ impl<B: Backend> BasicOps<B> for Int {
     fn cat<const D: usize>(vectors: Vec<Self::Primitive<D>>, dim: usize) -> Self::Primitive<D> {
         B::int_cat(vectors, dim)
     }
+
+    fn dtype() -> DType {
+        B::IntElem::dtype()
+    }
 }
 
 impl<B: Backend> BasicOps<B> for Bool {
@@ -959,6 +1108,10 @@ impl<B: Backend> BasicOps<B> for Bool {
     fn cat<const D: usize>(vectors: Vec<Self::Primitive<D>>, dim: usize) -> Self::Primitive<D> {
         B::bool_cat(vectors, dim)
     }
+
+    fn dtype() -> DType {
+        DType::Bool
+    }
 }
 
 /// Trait used for reshape arguments.
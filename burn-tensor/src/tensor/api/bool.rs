@@ -1,4 +1,4 @@
-use crate::{backend::Backend, Bool, Data, Int, Tensor};
+use crate::{backend::Backend, check, check::TensorCheck, Bool, Data, Int, Shape, Tensor};
 
 impl<B, const D: usize> Tensor<B, D, Bool>
 where
@@ -23,4 +23,96 @@ where
     pub fn float(self) -> Tensor<B, D> {
         Tensor::new(B::bool_into_float(self.primitive))
     }
+
+    /// Create the mask of the elements above the diagonal of the last two dimensions, to be
+    /// filled to keep the lower triangular part of a tensor.
+    ///
+    /// The mask is true where the column index minus the row index is greater than `diagonal`, a
+    /// positive `diagonal` excluding the diagonals above the main diagonal from the mask.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor has less than two dimensions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Bool, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let mask = Tensor::<B, 2, Bool>::tril_mask([3, 3], 0, &B::Device::default());
+    ///     println!("{}", mask);
+    ///     // [[false, true, true], [false, false, true], [false, false, false]]
+    /// }
+    /// ```
+    pub fn tril_mask<S: Into<Shape<D>>>(shape: S, diagonal: i64, device: &B::Device) -> Self {
+        Self::triangular_mask(shape.into(), diagonal, device, true)
+    }
+
+    /// Create the mask of the elements below the diagonal of the last two dimensions, to be
+    /// filled to keep the upper triangular part of a tensor.
+    ///
+    /// The mask is true where the column index minus the row index is lower than `diagonal`.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor has less than two dimensions.
+    pub fn triu_mask<S: Into<Shape<D>>>(shape: S, diagonal: i64, device: &B::Device) -> Self {
+        Self::triangular_mask(shape.into(), diagonal, device, false)
+    }
+
+    fn triangular_mask(shape: Shape<D>, diagonal: i64, device: &B::Device, lower: bool) -> Self {
+        check!(TensorCheck::triangular::<D>());
+
+        let (rows, cols) = (shape.dims[D - 2], shape.dims[D - 1]);
+        let row_indices = Tensor::<B, 1, Int>::arange_device(0..rows, device).reshape([rows, 1]);
+        let col_indices = Tensor::<B, 1, Int>::arange_device(0..cols, device).reshape([1, cols]);
+        let offsets = col_indices.sub(row_indices);
+
+        let mask = match lower {
+            true => offsets.greater_elem(diagonal),
+            false => offsets.lower_elem(diagonal),
+        };
+
+        mask.unsqueeze::<D>().expand(shape)
+    }
+
+    /// Test if any element of the tensor is true.
+    pub fn any(self) -> Tensor<B, 1, Bool> {
+        self.count_nonzero().greater_elem(0)
+    }
+
+    /// Test if any element along the given dimension is true, keeping the dimension with a size
+    /// of one.
+    pub fn any_dim(self, dim: usize) -> Self {
+        self.count_nonzero_dim(dim).greater_elem(0)
+    }
+
+    /// Test if all the elements of the tensor are true.
+    ///
+    /// A tensor without elements is considered to only have true elements.
+    pub fn all(self) -> Tensor<B, 1, Bool> {
+        let num_elements = self.shape().num_elements();
+        self.count_nonzero().equal_elem(num_elements as i64)
+    }
+
+    /// Test if all the elements along the given dimension are true, keeping the dimension with a
+    /// size of one.
+    pub fn all_dim(self, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_dim::<D>("All", dim));
+        let size = self.dims()[dim];
+        self.count_nonzero_dim(dim).equal_elem(size as i64)
+    }
+
+    /// Count the true elements of the tensor.
+    pub fn count_nonzero(self) -> Tensor<B, 1, Int> {
+        self.int().sum()
+    }
+
+    /// Count the true elements along the given dimension, keeping the dimension with a size of
+    /// one.
+    pub fn count_nonzero_dim(self, dim: usize) -> Tensor<B, D, Int> {
+        self.int().sum_dim(dim)
+    }
 }
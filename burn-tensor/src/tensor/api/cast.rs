@@ -0,0 +1,66 @@
+use crate::{backend::Backend, BasicOps, Bool, Float, Int, Tensor, TensorKind};
+
+/// The conversion of the tensors of a kind to another kind.
+///
+/// The float elements are truncated toward zero when converted to integers, and the elements
+/// which are not zero are true when converted to booleans.
+pub trait Cast<B: Backend, K: TensorKind<B>>: TensorKind<B> + Sized {
+    /// Converts the tensor to the other kind.
+    fn cast<const D: usize>(tensor: Tensor<B, D, Self>) -> Tensor<B, D, K>;
+}
+
+impl<B, const D: usize, K> Tensor<B, D, K>
+where
+    B: Backend,
+    K: BasicOps<B>,
+{
+    /// Converts the tensor to another kind, with the same shape and device.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Bool, Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 1>::from_floats([0.0, 1.5, -2.7]);
+    ///     println!("{}", tensor.clone().cast::<Int>());
+    ///     // [0, 1, -2]
+    ///     println!("{}", tensor.cast::<Bool>());
+    ///     // [false, true, true]
+    /// }
+    /// ```
+    pub fn cast<K2: TensorKind<B>>(self) -> Tensor<B, D, K2>
+    where
+        K: Cast<B, K2>,
+    {
+        K::cast(self)
+    }
+}
+
+macro_rules! cast {
+    ($kind:ident) => {
+        impl<B: Backend> Cast<B, $kind> for $kind {
+            fn cast<const D: usize>(tensor: Tensor<B, D, Self>) -> Tensor<B, D, $kind> {
+                tensor
+            }
+        }
+    };
+    ($from:ident => $to:ident, $method:ident) => {
+        impl<B: Backend> Cast<B, $to> for $from {
+            fn cast<const D: usize>(tensor: Tensor<B, D, Self>) -> Tensor<B, D, $to> {
+                tensor.$method()
+            }
+        }
+    };
+}
+
+cast!(Float);
+cast!(Float => Int, int);
+cast!(Float => Bool, bool);
+cast!(Int => Float, float);
+cast!(Int);
+cast!(Int => Bool, bool);
+cast!(Bool => Float, float);
+cast!(Bool => Int, int);
+cast!(Bool);
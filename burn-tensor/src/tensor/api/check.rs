@@ -1,4 +1,5 @@
-use crate::{backend::Backend, BasicOps, Shape, Tensor};
+use super::einsum::EinsumEquation;
+use crate::{backend::Backend, BasicOps, PadMode, Shape, Tensor};
 use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec;
@@ -466,6 +467,653 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn expand(tensor_dims: &[usize], shape_dims: &[usize]) -> Self {
+        let mut check = Self::Ok;
+
+        for (i, (size, target)) in tensor_dims.iter().zip(shape_dims).enumerate() {
+            if size != target && *size != 1 && *target != 1 {
+                check = check.register(
+                    "Expand",
+                    TensorError::new("Only the dimensions of size 1 can be expanded.").details(
+                        format!(
+                            "The dimension {i} of size {size} can't be expanded to {target}. \
+                            Tensor shape {tensor_dims:?}, target shape {shape_dims:?}.",
+                        ),
+                    ),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn roll<const D: usize>(shifts: &[i64], dims: &[usize]) -> Self {
+        let mut check = Self::Ok;
+
+        if shifts.len() != dims.len() {
+            check = check.register(
+                "Roll",
+                TensorError::new("One shift is required per dimension.").details(format!(
+                    "Rolling ({}) dimensions with ({}) shifts.",
+                    dims.len(),
+                    shifts.len()
+                )),
+            );
+        }
+
+        for dim in dims.iter().filter(|dim| **dim >= D) {
+            check = check.register(
+                "Roll",
+                TensorError::new(format!(
+                    "Can't roll a tensor with ({D}) dimensions on axis ({dim})"
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn diagonal<const D: usize, const D2: usize>() -> Self {
+        let mut check = Self::Ok;
+
+        if D < 2 || D2 + 1 != D {
+            check = check.register(
+                "Diagonal",
+                TensorError::new(format!(
+                    "The diagonal of a tensor with ({D}) dimensions can't have ({D2}) dimensions, \
+                     the input requires at least two dimensions and the output one less"
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn one_hot<const D: usize, const D2: usize>() -> Self {
+        let mut check = Self::Ok;
+
+        if D2 != D + 1 {
+            check = check.register(
+                "One Hot",
+                TensorError::new(format!(
+                    "The one hot encoding of a tensor with ({D}) dimensions can't have ({D2}) \
+                     dimensions, the output requires one more dimension"
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn diag_embed<const D: usize, const D2: usize>() -> Self {
+        let mut check = Self::Ok;
+
+        if D2 != D + 1 {
+            check = check.register(
+                "Diag Embed",
+                TensorError::new(format!(
+                    "The matrices built from a tensor with ({D}) dimensions can't have ({D2}) \
+                     dimensions, the output requires one more dimension"
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn fft<const D: usize>(
+        ops: &str,
+        dim: usize,
+        shape_real: &Shape<D>,
+        shape_imag: &Shape<D>,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if dim >= D {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "Can't compute the transform of a tensor with ({D}) dimensions on axis ({dim})"
+                )),
+            );
+        }
+
+        if shape_real != shape_imag {
+            check = check.register(
+                ops,
+                TensorError::new("The real and imaginary parts should have the same shape.")
+                    .details(format!(
+                        "Real part shape {:?}, imaginary part shape {:?}.",
+                        shape_real.dims, shape_imag.dims
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn solve<const D: usize>(shape_lhs: &Shape<D>, shape_rhs: &Shape<D>) -> Self {
+        let mut check = Self::Ok;
+
+        if D < 2 {
+            return check.register(
+                "Solve",
+                TensorError::new(format!(
+                    "Can't solve linear systems with tensors of ({D}) dimensions, the matrices \
+                     being the last two dimensions."
+                )),
+            );
+        }
+
+        if shape_lhs.dims[D - 2] != shape_lhs.dims[D - 1] {
+            check = check.register(
+                "Solve",
+                TensorError::new("The matrices should be square.").details(format!(
+                    "The matrices have a shape of {:?}.",
+                    &shape_lhs.dims[D - 2..]
+                )),
+            );
+        }
+
+        if shape_lhs.dims[..D - 2] != shape_rhs.dims[..D - 2]
+            || shape_lhs.dims[D - 2] != shape_rhs.dims[D - 2]
+        {
+            check = check.register(
+                "Solve",
+                TensorError::new(
+                    "The shapes of the matrices and of the right-hand sides don't match.",
+                )
+                .details(format!(
+                    "Matrices shape {:?}, right-hand sides shape {:?}.",
+                    shape_lhs.dims, shape_rhs.dims
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn decomposition<const D: usize>(ops: &str, shape: &Shape<D>, square: bool) -> Self {
+        let mut check = Self::Ok;
+
+        if D < 2 {
+            return check.register(
+                ops,
+                TensorError::new(format!(
+                    "Can't decompose tensors of ({D}) dimensions, the matrices being the last two \
+                     dimensions."
+                )),
+            );
+        }
+
+        if square && shape.dims[D - 2] != shape.dims[D - 1] {
+            check = check.register(
+                ops,
+                TensorError::new("The matrices should be square.").details(format!(
+                    "The matrices have a shape of {:?}.",
+                    &shape.dims[D - 2..]
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn decomposition_values<const D: usize, const D2: usize>(ops: &str) -> Self {
+        let mut check = Self::Ok;
+
+        if D2 + 1 != D {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "The values of the decomposition of a tensor with ({D}) dimensions can't have \
+                     ({D2}) dimensions, they require one dimension less"
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn grid_sample_2d(shape_x: &Shape<4>, shape_grid: &Shape<4>) -> Self {
+        let mut check = Self::Ok;
+
+        if shape_grid.dims[3] != 2 {
+            check = check.register(
+                "Grid Sample",
+                TensorError::new("The last dimension of the grid should be the two coordinates.")
+                    .details(format!("The grid has a shape of {:?}.", shape_grid.dims)),
+            );
+        }
+
+        if shape_x.dims[0] != shape_grid.dims[0] {
+            check = check.register(
+                "Grid Sample",
+                TensorError::new("The input and the grid should have the same batch size.")
+                    .details(format!(
+                        "Input batch size ({}), grid batch size ({}).",
+                        shape_x.dims[0], shape_grid.dims[0]
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn complex<const D: usize>(shape_real: &Shape<D>, shape_imag: &Shape<D>) -> Self {
+        let mut check = Self::Ok;
+
+        if shape_real != shape_imag {
+            check = check.register(
+                "Complex",
+                TensorError::new("The real and imaginary parts should have the same shape.")
+                    .details(format!(
+                        "Real part shape {:?}, imaginary part shape {:?}.",
+                        shape_real.dims, shape_imag.dims
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn multinomial<const D: usize>(
+        shape: &Shape<D>,
+        num_samples: usize,
+        replacement: bool,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if num_samples == 0 {
+            check = check.register(
+                "Multinomial",
+                TensorError::new("The number of samples should be greater than zero."),
+            );
+        }
+
+        if !replacement && num_samples > shape.dims[D - 1] {
+            check = check.register(
+                "Multinomial",
+                TensorError::new("Can't draw more samples than categories without replacement.")
+                    .details(format!(
+                        "{num_samples} samples are drawn from {} categories.",
+                        shape.dims[D - 1]
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn irfft_size<const D: usize>(
+        self,
+        dim: usize,
+        shape: &Shape<D>,
+        size: usize,
+    ) -> Self {
+        let mut check = self;
+
+        if dim < D && shape.dims[dim] != size / 2 + 1 {
+            check = check.register(
+                "Irfft",
+                TensorError::new("The number of frequencies doesn't match the size of the output.")
+                    .details(format!(
+                        "A size of {size} requires {} frequencies, {} are given.",
+                        size / 2 + 1,
+                        shape.dims[dim]
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn triangular<const D: usize>() -> Self {
+        let mut check = Self::Ok;
+
+        if D < 2 {
+            check = check.register(
+                "Triangular",
+                TensorError::new(format!(
+                    "Can't find the triangular part of a tensor with ({D}) dimensions, at \
+                     least two are required"
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn flip<const D: usize>(dims: &[usize]) -> Self {
+        let mut check = Self::Ok;
+
+        for dim in dims.iter().filter(|dim| **dim >= D) {
+            check = check.register(
+                "Flip",
+                TensorError::new(format!(
+                    "Can't flip a tensor with ({D}) dimensions on axis ({dim})"
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn chunk<const D: usize>(dim: usize, chunks: usize) -> Self {
+        let mut check = Self::dim_ops::<D>("Chunk", dim);
+
+        if chunks == 0 {
+            check = check.register(
+                "Chunk",
+                TensorError::new("The number of chunks should be greater than zero."),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn split<const D: usize>(shape: &Shape<D>, sizes: &[usize], dim: usize) -> Self {
+        let mut check = Self::dim_ops::<D>("Split", dim);
+
+        if dim < D {
+            let total: usize = sizes.iter().sum();
+
+            if total != shape.dims[dim] {
+                check = check.register(
+                    "Split",
+                    TensorError::new(
+                        "The split sizes should sum to the size of the split dimension.",
+                    )
+                    .details(format!(
+                        "Split sizes: {sizes:?}, sum: '{total}', dimension size: '{}'.",
+                        shape.dims[dim]
+                    )),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn repeat_interleave<const D: usize>(
+        shape: &Shape<D>,
+        repeats: &[i64],
+        dim: usize,
+    ) -> Self {
+        let mut check = Self::dim_ops::<D>("RepeatInterleave", dim);
+
+        if dim < D && repeats.len() != shape.dims[dim] {
+            check = check.register(
+                "RepeatInterleave",
+                TensorError::new(
+                    "The number of repeat counts should match the size of the dimension.",
+                )
+                .details(format!(
+                    "Repeat counts: '{}', dimension size: '{}'.",
+                    repeats.len(),
+                    shape.dims[dim]
+                )),
+            );
+        }
+
+        if let Some(count) = repeats.iter().find(|count| **count < 0) {
+            check = check.register(
+                "RepeatInterleave",
+                TensorError::new("The repeat counts should not be negative.")
+                    .details(format!("Found the repeat count '{count}'.")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn arange_step(start: f64, end: f64, step: f64) -> Self {
+        let mut check = Self::Ok;
+
+        if step == 0.0 || !step.is_finite() {
+            check = check.register(
+                "ArangeStep",
+                TensorError::new("The step should be finite and non-zero.")
+                    .details(format!("Given step: '{step}'.")),
+            );
+        } else if (end - start) * step < 0.0 {
+            check = check.register(
+                "ArangeStep",
+                TensorError::new("The step should go from the start toward the end.")
+                    .details(format!("Start: '{start}', end: '{end}', step: '{step}'.")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn quantize(scale: f64) -> Self {
+        let mut check = Self::Ok;
+
+        if !(scale > 0.0 && scale.is_finite()) {
+            check = check.register(
+                "Quantize",
+                TensorError::new("The scale should be positive and finite.")
+                    .details(format!("Given scale: '{scale}'.")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn quantize_per_channel<const D: usize>(
+        shape: &Shape<D>,
+        shape_scales: &Shape<1>,
+        shape_zero_points: &Shape<1>,
+        axis: usize,
+    ) -> Self {
+        let mut check = Self::dim_ops::<D>("QuantizePerChannel", axis);
+
+        if axis < D
+            && (shape_scales.dims[0] != shape.dims[axis]
+                || shape_zero_points.dims[0] != shape.dims[axis])
+        {
+            check = check.register(
+                "QuantizePerChannel",
+                TensorError::new(
+                    "The number of scales and zero points should match the size of the axis.",
+                )
+                .details(format!(
+                    "Scales: '{}', zero points: '{}', axis size: '{}'.",
+                    shape_scales.dims[0], shape_zero_points.dims[0], shape.dims[axis]
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn sparse<const D: usize>(
+        shape_indices: &Shape<2>,
+        shape_values: &Shape<1>,
+    ) -> Self {
+        let mut check = Self::Ok;
+        let [nnz, rank] = shape_indices.dims;
+
+        if rank != D {
+            check = check.register(
+                "Sparse",
+                TensorError::new("The indices should have one column per dimension.")
+                    .details(format!("Tensor rank: '{D}', index columns: '{rank}'.")),
+            );
+        }
+
+        if shape_values.dims[0] != nnz {
+            check = check.register(
+                "Sparse",
+                TensorError::new("The indices and the values should have the same length.")
+                    .details(format!(
+                        "Indices: '{nnz}', values: '{}'.",
+                        shape_values.dims[0]
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn sparse_matmul(lhs: &Shape<2>, rhs: &Shape<2>) -> Self {
+        let mut check = Self::Ok;
+
+        if lhs.dims[1] != rhs.dims[0] {
+            check = check.register(
+                "SparseMatmul",
+                TensorError::new("The inner dimension of matmul should be the same.").details(
+                    format!("Lhs shape {:?}, rhs shape {:?}.", lhs.dims, rhs.dims),
+                ),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn pad<const D: usize>(
+        shape: &Shape<D>,
+        pads: &[(usize, usize); D],
+        mode: &PadMode,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if let PadMode::Reflect = mode {
+            for (i, (before, after)) in pads.iter().enumerate() {
+                let d_tensor = shape.dims[i];
+
+                if *before >= d_tensor || *after >= d_tensor {
+                    check = check.register(
+                        "Pad",
+                        TensorError::new(
+                            "Reflect padding must be smaller than the size of the padded dimension.",
+                        )
+                        .details(format!(
+                            "The padding ({before}, {after}) at dimension {i} is not smaller than \
+                            its size ({d_tensor}). Tensor shape {:?}.",
+                            shape.dims,
+                        )),
+                    );
+                }
+            }
+        }
+
+        if let PadMode::Edge = mode {
+            for (i, (before, after)) in pads.iter().enumerate() {
+                if shape.dims[i] == 0 && before + after > 0 {
+                    check = check.register(
+                        "Pad",
+                        TensorError::new("Edge padding requires a non-empty padded dimension.")
+                            .details(format!(
+                                "Dimension {i} is empty. Tensor shape {:?}.",
+                                shape.dims,
+                            )),
+                    );
+                }
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn einsum(equation: &str, lhs: &[usize], rhs: &[usize], output_rank: usize) -> Self {
+        let mut check = Self::Ok;
+
+        let parsed = match EinsumEquation::parse(equation) {
+            Ok(parsed) => parsed,
+            Err(error) => {
+                return check.register(
+                    "Einsum",
+                    TensorError::new("Invalid equation.")
+                        .details(format!("Equation '{equation}': {error}")),
+                )
+            }
+        };
+
+        let operands = [("lhs", &parsed.lhs, lhs), ("rhs", &parsed.rhs, rhs)];
+        for (name, labels, dims) in operands {
+            if labels.len() != dims.len() {
+                check = check.register(
+                    "Einsum",
+                    TensorError::new(
+                        "The number of labels of each operand must match its number of dimensions.",
+                    )
+                    .details(format!(
+                        "Equation '{equation}': the {name} operand has {} labels and {} dimensions.",
+                        labels.len(),
+                        dims.len(),
+                    )),
+                );
+            }
+
+            for (index, label) in labels.iter().enumerate() {
+                if labels[..index].contains(label) {
+                    check = check.register(
+                        "Einsum",
+                        TensorError::new("The labels can't be repeated in an operand.").details(
+                            format!(
+                            "Equation '{equation}': '{label}' is repeated in the {name} operand."
+                        ),
+                        ),
+                    );
+                }
+            }
+        }
+
+        for (index, label) in parsed.output.iter().enumerate() {
+            if !parsed.lhs.contains(label) && !parsed.rhs.contains(label) {
+                check = check.register(
+                    "Einsum",
+                    TensorError::new("The output labels must be labels of the operands.").details(
+                        format!("Equation '{equation}': '{label}' is not a label of the operands."),
+                    ),
+                );
+            } else if parsed.output[..index].contains(label) {
+                check = check.register(
+                    "Einsum",
+                    TensorError::new("The labels can't be repeated in the output.").details(
+                        format!("Equation '{equation}': '{label}' is repeated in the output."),
+                    ),
+                );
+            }
+        }
+
+        if parsed.output.is_empty() || parsed.output.len() != output_rank {
+            check = check.register(
+                "Einsum",
+                TensorError::new(
+                    "The number of output labels must match the number of dimensions of the output.",
+                )
+                .details(format!(
+                    "Equation '{equation}': the output has {} labels and {output_rank} dimensions.",
+                    parsed.output.len(),
+                )),
+            );
+        }
+
+        for (label, lhs_size) in parsed.lhs.iter().zip(lhs.iter()) {
+            let rhs_size = parsed
+                .rhs
+                .iter()
+                .zip(rhs.iter())
+                .find_map(|(l, size)| (l == label).then_some(size));
+
+            if let Some(rhs_size) = rhs_size.filter(|rhs_size| *rhs_size != lhs_size) {
+                check = check.register(
+                    "Einsum",
+                    TensorError::new("The dimensions with the same label must have the same size.")
+                        .details(format!(
+                            "Equation '{equation}': '{label}' has the size {lhs_size} in the lhs \
+                            operand and {rhs_size} in the rhs operand."
+                        )),
+                );
+            }
+        }
+
+        check
+    }
+
     pub(crate) fn select<const D: usize>(dim: usize) -> Self {
         Self::check_select_basic::<D>(Self::Ok, "select", dim)
     }
@@ -543,6 +1191,82 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn topk<const D: usize>(shape: &Shape<D>, k: usize, dim: usize) -> Self {
+        let mut check = Self::Ok;
+
+        if dim >= D {
+            check = check.register(
+                "Topk",
+                TensorError::new(format!(
+                    "Can't find values in a tensor with ({D}) dimensions on axis ({dim})"
+                )),
+            );
+        } else if k > shape.dims[dim] {
+            check = check.register(
+                "Topk",
+                TensorError::new("Can't find more values than the size of the dimension.").details(
+                    format!(
+                        "Finding {k} values along dimension {dim} of size {}.",
+                        shape.dims[dim]
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn where_cond<const D: usize>(
+        shape_condition: &Shape<D>,
+        shape: &Shape<D>,
+        shape_other: &Shape<D>,
+    ) -> Self {
+        Self::Ok
+            .binary_ops_ew_shape("Where", shape, shape_other)
+            .binary_ops_ew_shape("Where", shape_condition, shape)
+            .binary_ops_ew_shape("Where", shape_condition, shape_other)
+    }
+
+    pub(crate) fn masked_select<const D: usize>(shape: &Shape<D>, shape_mask: &Shape<D>) -> Self {
+        let mut check = Self::Ok;
+
+        if shape != shape_mask {
+            check = check.register(
+                "Masked Select",
+                TensorError::new("The mask should have the same shape as the tensor.").details(
+                    format!(
+                        "Tensor shape {:?}, mask shape {:?}.",
+                        shape.dims, shape_mask.dims
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn index<const D: usize, const D2: usize>(shapes: &[Shape<D2>]) -> Self {
+        let mut check = Self::Ok;
+
+        if shapes.len() != D {
+            check = check.register(
+                "Index",
+                TensorError::new("One index tensor is required per dimension.").details(format!(
+                    "Indexing a tensor with ({D}) dimensions with ({}) index tensors.",
+                    shapes.len()
+                )),
+            );
+        } else if shapes.iter().any(|shape| *shape != shapes[0]) {
+            check = check.register(
+                "Index",
+                TensorError::new("The index tensors should have the same shape.")
+                    .details(format!("Index tensors of shapes {shapes:?}.")),
+            );
+        }
+
+        check
+    }
+
     /// The goal is to minimize the cost of checks when there are no error, but it's way less
     /// important when an error occurred, crafting a comprehensive error message is more important
     /// than optimizing string manipulation.
@@ -0,0 +1,357 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use num_complex::Complex as ComplexElem;
+
+use crate::{
+    backend::Backend, check, check::TensorCheck, BasicOps, Bool, Complex, DType, Data, Element,
+    ElementConversion, Shape, Tensor,
+};
+
+pub use num_complex::{Complex32, Complex64};
+
+/// The primitive of a complex tensor, made of the float primitives of its real and imaginary
+/// parts.
+#[derive(Clone, Debug)]
+pub struct ComplexPrimitive<B: Backend, const D: usize> {
+    /// The real part.
+    pub real: B::TensorPrimitive<D>,
+    /// The imaginary part.
+    pub imag: B::TensorPrimitive<D>,
+}
+
+impl<B: Backend, const D: usize> ComplexPrimitive<B, D> {
+    fn map<const D2: usize, F>(self, func: F) -> ComplexPrimitive<B, D2>
+    where
+        F: Fn(B::TensorPrimitive<D>) -> B::TensorPrimitive<D2>,
+    {
+        ComplexPrimitive {
+            real: func(self.real),
+            imag: func(self.imag),
+        }
+    }
+}
+
+impl<B, const D: usize> Tensor<B, D, Complex>
+where
+    B: Backend,
+{
+    /// Create a complex tensor from its real and imaginary parts.
+    ///
+    /// # Panics
+    ///
+    /// If the two parts don't have the same shape.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Complex, ComplexArithmetic, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let real = Tensor::<B, 1>::from_floats([1.0, 0.0]);
+    ///     let imag = Tensor::<B, 1>::from_floats([1.0, -2.0]);
+    ///     let tensor = Tensor::<B, 1, Complex>::from_parts(real, imag);
+    ///     println!("{}", tensor.abs());
+    ///     // [1.4142, 2.0]
+    /// }
+    /// ```
+    pub fn from_parts(real: Tensor<B, D>, imag: Tensor<B, D>) -> Self {
+        check!(TensorCheck::complex(&real.shape(), &imag.shape()));
+
+        Self::new(ComplexPrimitive {
+            real: real.primitive,
+            imag: imag.primitive,
+        })
+    }
+
+    /// Create a complex tensor with the given real part and a null imaginary part.
+    pub fn from_real(real: Tensor<B, D>) -> Self {
+        let imag = real.zeros_like();
+
+        Self::from_parts(real, imag)
+    }
+
+    /// Create a complex tensor from its absolute value and its angle in radians.
+    ///
+    /// # Panics
+    ///
+    /// If the two tensors don't have the same shape.
+    pub fn from_polar(abs: Tensor<B, D>, angle: Tensor<B, D>) -> Self {
+        let real = abs.clone().mul(angle.clone().cos());
+        let imag = abs.mul(angle.sin());
+
+        Self::from_parts(real, imag)
+    }
+
+    /// Returns the real and imaginary parts of the tensor.
+    pub fn into_parts(self) -> (Tensor<B, D>, Tensor<B, D>) {
+        (
+            Tensor::new(self.primitive.real),
+            Tensor::new(self.primitive.imag),
+        )
+    }
+
+    /// Returns the real part of the tensor.
+    pub fn real(self) -> Tensor<B, D> {
+        Tensor::new(self.primitive.real)
+    }
+
+    /// Returns the imaginary part of the tensor.
+    pub fn imag(self) -> Tensor<B, D> {
+        Tensor::new(self.primitive.imag)
+    }
+
+    /// Returns the complex conjugate of each element, negating the imaginary part.
+    pub fn conj(self) -> Self {
+        let (real, imag) = self.into_parts();
+
+        Self::from_parts(real, imag.neg())
+    }
+
+    /// Returns the angle, or argument, of each element in radians, in `(-pi, pi]`.
+    ///
+    /// The angle of zero is zero, like `atan2(0, 0)`.
+    pub fn angle(self) -> Tensor<B, D> {
+        let (real, imag) = self.into_parts();
+        let origin = real
+            .clone()
+            .equal_elem(0.0)
+            .int()
+            .add(imag.clone().equal_elem(0.0).int())
+            .equal_elem(2);
+
+        // The arctangent of the ratio is in (-pi/2, pi/2), the angles of the elements with a
+        // negative real part are turned by pi toward the sign of the imaginary part.
+        let turn = imag
+            .clone()
+            .greater_equal_elem(0.0)
+            .float()
+            .mul_scalar(2.0)
+            .sub_scalar(1.0)
+            .mul(real.clone().lower_elem(0.0).float())
+            .mul_scalar(core::f64::consts::PI);
+
+        imag.div(real).atan().add(turn).mask_fill(origin, 0.0)
+    }
+
+    fn abs_squared(self) -> Tensor<B, D> {
+        let (real, imag) = self.into_parts();
+
+        real.powf(2.0).add(imag.powf(2.0))
+    }
+
+    fn div_real(self, other: Tensor<B, D>) -> Self {
+        let (real, imag) = self.into_parts();
+
+        Self::from_parts(real.div(other.clone()), imag.div(other))
+    }
+}
+
+impl<B: Backend> BasicOps<B> for Complex {
+    type Elem = ComplexElem<B::FloatElem>;
+
+    fn empty<const D: usize>(shape: Shape<D>, device: &B::Device) -> Self::Primitive<D> {
+        ComplexPrimitive {
+            real: B::empty(shape.clone(), device),
+            imag: B::empty(shape, device),
+        }
+    }
+    fn shape<const D: usize>(tensor: &Self::Primitive<D>) -> Shape<D> {
+        B::shape(&tensor.real)
+    }
+
+    fn reshape<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        shape: Shape<D2>,
+    ) -> Self::Primitive<D2> {
+        tensor.map(|tensor| B::reshape(tensor, shape.clone()))
+    }
+
+    fn slice<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        ranges: [Range<usize>; D2],
+    ) -> Self::Primitive<D1> {
+        tensor.map(|tensor| B::slice(tensor, ranges.clone()))
+    }
+
+    fn slice_assign<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        ranges: [Range<usize>; D2],
+        value: Self::Primitive<D1>,
+    ) -> Self::Primitive<D1> {
+        ComplexPrimitive {
+            real: B::slice_assign(tensor.real, ranges.clone(), value.real),
+            imag: B::slice_assign(tensor.imag, ranges, value.imag),
+        }
+    }
+
+    fn device<const D: usize>(tensor: &Self::Primitive<D>) -> <B as Backend>::Device {
+        B::device(&tensor.real)
+    }
+
+    fn to_device<const D: usize>(
+        tensor: Self::Primitive<D>,
+        device: &<B as Backend>::Device,
+    ) -> Self::Primitive<D> {
+        tensor.map(|tensor| B::to_device(tensor, device))
+    }
+
+    fn into_data<const D: usize>(tensor: Self::Primitive<D>) -> Data<Self::Elem, D> {
+        let real = B::into_data(tensor.real);
+        let imag = B::into_data(tensor.imag);
+        let value = real
+            .value
+            .into_iter()
+            .zip(imag.value)
+            .map(|(real, imag)| ComplexElem::new(real, imag))
+            .collect();
+
+        Data::new(value, real.shape)
+    }
+
+    fn from_data<const D: usize>(
+        data: Data<Self::Elem, D>,
+        device: &B::Device,
+    ) -> Self::Primitive<D> {
+        let (real, imag): (Vec<_>, Vec<_>) = data
+            .value
+            .into_iter()
+            .map(|value| (value.re, value.im))
+            .unzip();
+
+        ComplexPrimitive {
+            real: B::from_data(Data::new(real, data.shape.clone()), device),
+            imag: B::from_data(Data::new(imag, data.shape), device),
+        }
+    }
+
+    fn repeat<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        times: usize,
+    ) -> Self::Primitive<D> {
+        tensor.map(|tensor| B::repeat(tensor, dim, times))
+    }
+
+    fn cat<const D: usize>(vectors: Vec<Self::Primitive<D>>, dim: usize) -> Self::Primitive<D> {
+        let (real, imag): (Vec<_>, Vec<_>) = vectors
+            .into_iter()
+            .map(|tensor| (tensor.real, tensor.imag))
+            .unzip();
+
+        ComplexPrimitive {
+            real: B::cat(real, dim),
+            imag: B::cat(imag, dim),
+        }
+    }
+
+    fn equal<const D: usize>(
+        lhs: Self::Primitive<D>,
+        rhs: Self::Primitive<D>,
+    ) -> Tensor<B, D, Bool> {
+        let real = Tensor::<B, D, Bool>::new(B::equal(lhs.real, rhs.real));
+        let imag = Tensor::<B, D, Bool>::new(B::equal(lhs.imag, rhs.imag));
+
+        real.int().add(imag.int()).equal_elem(2)
+    }
+
+    fn dtype() -> DType {
+        match B::FloatElem::dtype() {
+            DType::F64 => DType::ComplexF64,
+            DType::F32 => DType::ComplexF32,
+            _ => DType::ComplexF16,
+        }
+    }
+}
+
+/// The arithmetic operations of complex tensors.
+///
+/// The operations are in a trait since the [numeric](crate::Numeric) tensors have methods with the
+/// same names.
+pub trait ComplexArithmetic<B: Backend, const D: usize> {
+    /// Applies element wise addition operation.
+    ///
+    /// `y = x2 + x1`
+    fn add(self, other: Self) -> Self;
+
+    /// Applies element wise subtraction operation.
+    ///
+    /// `y = x2 - x1`
+    fn sub(self, other: Self) -> Self;
+
+    /// Applies element wise multiplication operation.
+    ///
+    /// `y = x2 * x1`
+    fn mul(self, other: Self) -> Self;
+
+    /// Applies element wise division operation.
+    ///
+    /// `y = x2 / x1`
+    fn div(self, other: Self) -> Self;
+
+    /// Applies element wise multiplication operation with a real scalar.
+    ///
+    /// `y = x2 * x1`
+    fn mul_scalar<E: ElementConversion>(self, other: E) -> Self;
+
+    /// Switch sign of each element in the tensor.
+    ///
+    /// `y = -x`
+    fn neg(self) -> Self;
+
+    /// Returns the absolute value, or modulus, of each element.
+    fn abs(self) -> Tensor<B, D>;
+}
+
+impl<B, const D: usize> ComplexArithmetic<B, D> for Tensor<B, D, Complex>
+where
+    B: Backend,
+{
+    fn add(self, other: Self) -> Self {
+        let (real, imag) = self.into_parts();
+        let (other_real, other_imag) = other.into_parts();
+
+        Self::from_parts(real.add(other_real), imag.add(other_imag))
+    }
+
+    fn sub(self, other: Self) -> Self {
+        let (real, imag) = self.into_parts();
+        let (other_real, other_imag) = other.into_parts();
+
+        Self::from_parts(real.sub(other_real), imag.sub(other_imag))
+    }
+
+    fn mul(self, other: Self) -> Self {
+        let (a, b) = self.into_parts();
+        let (c, d) = other.into_parts();
+
+        let real = a.clone().mul(c.clone()).sub(b.clone().mul(d.clone()));
+        let imag = a.mul(d).add(b.mul(c));
+
+        Self::from_parts(real, imag)
+    }
+
+    fn div(self, other: Self) -> Self {
+        let denominator = other.clone().abs_squared();
+
+        self.mul(other.conj()).div_real(denominator)
+    }
+
+    fn mul_scalar<E: ElementConversion>(self, other: E) -> Self {
+        let other = other.elem::<f64>();
+
+        Self::new(
+            self.primitive
+                .map(|tensor| B::mul_scalar(tensor, other.elem())),
+        )
+    }
+
+    fn neg(self) -> Self {
+        Self::new(self.primitive.map(B::neg))
+    }
+
+    fn abs(self) -> Tensor<B, D> {
+        self.abs_squared().sqrt()
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{backend::Backend, check, check::TensorCheck, Bool, Element, Numeric, Tensor};
+
+impl<B, const D: usize, K> Tensor<B, D, K>
+where
+    B: Backend,
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    /// Select the elements of the tensor where the condition is true, and the elements of the
+    /// other tensor where it is false.
+    ///
+    /// The condition and the two tensors are broadcasted to a common shape, following the numpy
+    /// broadcasting rules, so the gradients of a broadcasted tensor are summed over the broadcasted
+    /// dimensions.
+    ///
+    /// # Panics
+    ///
+    /// If the shapes of the condition and the tensors can't be broadcasted together.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Bool, Data, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let condition = Tensor::<B, 2, Bool>::from_bool(Data::from([[true], [false]]));
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+    ///     let other = Tensor::<B, 2>::zeros([1, 2]);
+    ///     let output = tensor.where_cond(condition, other);
+    ///     println!("{}", output);
+    ///     // [[1.0, 2.0], [0.0, 0.0]]
+    /// }
+    /// ```
+    pub fn where_cond(self, condition: Tensor<B, D, Bool>, other: Self) -> Self {
+        let (shape_condition, shape, shape_other) =
+            (condition.shape(), self.shape(), other.shape());
+        check!(TensorCheck::where_cond::<D>(
+            &shape_condition,
+            &shape,
+            &shape_other
+        ));
+
+        let mut dims = shape_condition.dims;
+        for (dim, (size, size_other)) in dims
+            .iter_mut()
+            .zip(shape.dims.into_iter().zip(shape_other.dims))
+        {
+            *dim = usize::max(*dim, usize::max(size, size_other));
+        }
+
+        let expand = |tensor: Self| match tensor.dims() == dims {
+            true => tensor,
+            false => tensor.expand(dims),
+        };
+        let condition = match condition.dims() == dims {
+            true => condition,
+            false => condition.expand(dims),
+        };
+
+        expand(other).mask_where(condition, expand(self))
+    }
+}
+
+/// Select the elements of `lhs` where the condition is true, and the elements of `rhs` where it is
+/// false.
+///
+/// See [Tensor::where_cond](Tensor::where_cond).
+pub fn where_<B, const D: usize, K>(
+    condition: Tensor<B, D, Bool>,
+    lhs: Tensor<B, D, K>,
+    rhs: Tensor<B, D, K>,
+) -> Tensor<B, D, K>
+where
+    B: Backend,
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    lhs.where_cond(condition, rhs)
+}
@@ -0,0 +1,139 @@
+use crate::{
+    backend::Backend, check, check::TensorCheck, Data, Element, Int, Numeric, Shape, Tensor,
+};
+use alloc::vec::Vec;
+
+impl<B, const D: usize, K> Tensor<B, D, K>
+where
+    B: Backend,
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    /// The diagonal of the last two dimensions, which is replaced by a dimension of the size of the
+    /// diagonal.
+    ///
+    /// A positive `offset` selects a diagonal above the main diagonal, and a negative `offset` a
+    /// diagonal below it.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor has less than two dimensions, or if `D2` isn't `D - 1`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    ///     let diagonal: Tensor<B, 1> = tensor.diagonal(1);
+    ///     println!("{}", diagonal);
+    ///     // [2.0, 6.0]
+    /// }
+    /// ```
+    pub fn diagonal<const D2: usize>(self, offset: i64) -> Tensor<B, D2, K> {
+        check!(TensorCheck::diagonal::<D, D2>());
+
+        let dims = self.dims();
+        let (rows, cols) = (dims[D - 2], dims[D - 1]);
+        let (row, col) = start(offset);
+        let length = usize::min(rows.saturating_sub(row), cols.saturating_sub(col));
+        let positions = positions(length, row, col, cols, &self.device());
+
+        self.flatten::<D2>(D - 2, D - 1).select(D2 - 1, positions)
+    }
+
+    /// Build matrices from the last dimension, the elements being placed on the diagonal of the
+    /// new last two dimensions and the other elements being zeros.
+    ///
+    /// A positive `offset` places the elements above the main diagonal, and a negative `offset`
+    /// below it, the matrices having the size of the last dimension plus the absolute offset.
+    ///
+    /// # Panics
+    ///
+    /// If `D2` isn't `D + 1`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+    ///     let matrices: Tensor<B, 3> = tensor.diag_embed(0);
+    ///     println!("{}", matrices);
+    ///     // [[[1.0, 0.0], [0.0, 2.0]], [[3.0, 0.0], [0.0, 4.0]]]
+    /// }
+    /// ```
+    pub fn diag_embed<const D2: usize>(self, offset: i64) -> Tensor<B, D2, K> {
+        check!(TensorCheck::diag_embed::<D, D2>());
+
+        let device = self.device();
+        let dims = self.dims();
+        let length = dims[D - 1];
+        let size = length + offset.unsigned_abs() as usize;
+        let (row, col) = start(offset);
+        let positions = positions(length, row, col, size, &device);
+
+        let mut flat_dims = dims;
+        flat_dims[D - 1] = size * size;
+        let mut shape = [0; D2];
+        shape[..D - 1].copy_from_slice(&dims[..D - 1]);
+        shape[D2 - 2] = size;
+        shape[D2 - 1] = size;
+
+        Self::zeros_device(flat_dims, &device)
+            .select_assign(D - 1, positions, self)
+            .reshape(shape)
+    }
+}
+
+impl<B, K> Tensor<B, 1, K>
+where
+    B: Backend,
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    /// Build the matrix with the elements of the vector on the diagonal, see
+    /// [diag_embed](Tensor::diag_embed).
+    pub fn diag(self, offset: i64) -> Tensor<B, 2, K> {
+        self.diag_embed(offset)
+    }
+}
+
+impl<B, K> Tensor<B, 2, K>
+where
+    B: Backend,
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    /// The diagonal of the matrix, see [diagonal](Tensor::diagonal).
+    pub fn diag(self, offset: i64) -> Tensor<B, 1, K> {
+        self.diagonal(offset)
+    }
+}
+
+/// The row and the column of the first element of the diagonal.
+fn start(offset: i64) -> (usize, usize) {
+    match offset >= 0 {
+        true => (0, offset as usize),
+        false => (offset.unsigned_abs() as usize, 0),
+    }
+}
+
+/// The positions of the elements of the diagonal in the flattened matrices.
+fn positions<B: Backend>(
+    length: usize,
+    row: usize,
+    col: usize,
+    cols: usize,
+    device: &B::Device,
+) -> Tensor<B, 1, Int> {
+    let positions = (0..length)
+        .map(|i| ((row + i) * cols + col + i) as i64)
+        .collect::<Vec<_>>();
+
+    Tensor::from_data_device(Data::new(positions, Shape::new([length])).convert(), device)
+}
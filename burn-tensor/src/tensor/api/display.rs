@@ -0,0 +1,280 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+use crate::{backend::Backend, BasicOps, Tensor};
+
+/// The options of the [display](Tensor::display) of a tensor, like numpy's print options.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrintOptions {
+    /// The number of digits after the decimal point of the float elements, all the digits needed
+    /// to represent an element exactly being shown when `None`.
+    pub precision: Option<usize>,
+    /// The number of elements above which the tensor is summarized, only the edge items of each
+    /// dimension being shown.
+    pub threshold: usize,
+    /// The number of elements shown at the start and the end of each summarized dimension.
+    pub edge_items: usize,
+    /// Whether the float elements are shown in scientific notation, the notation being chosen from
+    /// the range of the shown elements when `None`.
+    pub sci_mode: Option<bool>,
+}
+
+impl Default for PrintOptions {
+    fn default() -> Self {
+        Self {
+            precision: None,
+            threshold: 1000,
+            edge_items: 3,
+            sci_mode: None,
+        }
+    }
+}
+
+impl PrintOptions {
+    /// Set the number of digits after the decimal point of the float elements.
+    pub fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Set the number of elements above which the tensor is summarized.
+    pub fn with_threshold(mut self, threshold: usize) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Set the number of elements shown at the start and the end of each summarized dimension.
+    pub fn with_edge_items(mut self, edge_items: usize) -> Self {
+        self.edge_items = edge_items;
+        self
+    }
+
+    /// Set whether the float elements are shown in scientific notation.
+    pub fn with_sci_mode(mut self, sci_mode: bool) -> Self {
+        self.sci_mode = Some(sci_mode);
+        self
+    }
+}
+
+/// A tensor displayed with the given [options](PrintOptions), returned by
+/// [display](Tensor::display).
+pub struct TensorDisplay<'a, B: Backend, const D: usize, K: BasicOps<B>> {
+    tensor: &'a Tensor<B, D, K>,
+    options: PrintOptions,
+}
+
+impl<B, const D: usize, K> Tensor<B, D, K>
+where
+    B: Backend,
+    K: BasicOps<B>,
+    K::Elem: Debug,
+{
+    /// Returns a value displaying the tensor with the given options.
+    ///
+    /// The tensor is read once on the host. When it has more elements than the threshold, only the
+    /// edge items of each dimension are shown, the others being replaced by `...`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{PrintOptions, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 1>::from_floats([0.12345, 2.0, 3.0, 4.0, 5.0]);
+    ///     let options = PrintOptions::default()
+    ///         .with_precision(2)
+    ///         .with_threshold(4)
+    ///         .with_edge_items(1);
+    ///     println!("{}", tensor.display(options));
+    ///     // Tensor {
+    ///     //   data: [0.12, ..., 5.00],
+    ///     //   ...
+    ///     // }
+    /// }
+    /// ```
+    pub fn display(&self, options: PrintOptions) -> TensorDisplay<'_, B, D, K> {
+        TensorDisplay {
+            tensor: self,
+            options,
+        }
+    }
+
+    /// Returns a one line summary of the tensor for logging, with its shape, kind, element type
+    /// and device, and the two edge items of each dimension.
+    pub fn to_string_summary(&self) -> String {
+        let options = PrintOptions::default().with_threshold(0).with_edge_items(2);
+
+        format!(
+            "Tensor {{ shape: {:?}, kind: {:?}, dtype: {:?}, device: {:?}, data: {} }}",
+            self.dims(),
+            K::name(),
+            K::elem_type_name(),
+            self.device(),
+            format_data(self, &options)
+        )
+    }
+}
+
+impl<'a, B, const D: usize, K> core::fmt::Display for TensorDisplay<'a, B, D, K>
+where
+    B: Backend,
+    K: BasicOps<B>,
+    K::Elem: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut options = self.options.clone();
+        if let Some(precision) = f.precision() {
+            options.precision = Some(precision);
+        }
+
+        let tensor = self.tensor;
+        writeln!(f, "Tensor {{")?;
+        writeln!(f, "  data: {},", format_data(tensor, &options))?;
+        writeln!(f, "  shape:  {:?},", tensor.dims())?;
+        writeln!(f, "  device:  {:?},", tensor.device())?;
+        writeln!(f, "  backend:  {:?},", B::name())?;
+        writeln!(f, "  kind:  {:?},", K::name())?;
+        writeln!(f, "  dtype:  {:?},", K::elem_type_name())?;
+        write!(f, "}}")
+    }
+}
+
+/// Formats the elements of the tensor as nested lists.
+fn format_data<B, const D: usize, K>(tensor: &Tensor<B, D, K>, options: &PrintOptions) -> String
+where
+    B: Backend,
+    K: BasicOps<B>,
+    K::Elem: Debug,
+{
+    let data = tensor.to_data();
+    let dims = data.shape.dims;
+    let summarize = data.value.len() > options.threshold;
+
+    let mut strides = [1; D];
+    for dim in (0..D.saturating_sub(1)).rev() {
+        strides[dim] = strides[dim + 1] * dims[dim + 1];
+    }
+    let layout = Layout {
+        dims: &dims,
+        strides: &strides,
+        edge_items: options.edge_items,
+        summarize,
+    };
+
+    // The scientific notation is chosen from the shown elements only.
+    let mut shown = Vec::new();
+    layout.write(&mut String::new(), 0, 0, &mut |_, index| shown.push(index));
+    let sci = options.sci_mode.unwrap_or_else(|| {
+        use_sci(
+            shown
+                .iter()
+                .filter_map(|index| parse_float(&format!("{:?}", data.value[*index]))),
+        )
+    });
+
+    let mut acc = String::new();
+    layout.write(&mut acc, 0, 0, &mut |acc, index| {
+        acc.push_str(&format_elem(&data.value[index], options.precision, sci))
+    });
+
+    acc
+}
+
+struct Layout<'a> {
+    dims: &'a [usize],
+    strides: &'a [usize],
+    edge_items: usize,
+    summarize: bool,
+}
+
+impl<'a> Layout<'a> {
+    /// Writes the dimension at the given depth, starting at the given offset in the data, the
+    /// elements being written by the given function.
+    fn write<F: FnMut(&mut String, usize)>(
+        &self,
+        acc: &mut String,
+        depth: usize,
+        offset: usize,
+        write_elem: &mut F,
+    ) {
+        acc.push('[');
+
+        let size = self.dims[depth];
+        let skipped = self.summarize && size > 2 * self.edge_items;
+
+        for i in 0..size {
+            if skipped && i >= self.edge_items && i < size - self.edge_items {
+                if i == self.edge_items {
+                    acc.push_str("..., ");
+                }
+                continue;
+            }
+
+            let offset = offset + i * self.strides[depth];
+            if depth == self.dims.len() - 1 {
+                write_elem(acc, offset);
+            } else {
+                self.write(acc, depth + 1, offset, write_elem);
+            }
+
+            if i < size - 1 {
+                acc.push_str(", ");
+            }
+        }
+
+        acc.push(']');
+    }
+}
+
+fn format_elem<E: Debug>(elem: &E, precision: Option<usize>, sci: bool) -> String {
+    let debug = format!("{elem:?}");
+
+    match (parse_float(&debug), precision) {
+        (Some(value), Some(precision)) if sci => format!("{value:.precision$e}"),
+        (Some(value), None) if sci => format!("{value:e}"),
+        (_, Some(precision)) => format!("{elem:.precision$?}"),
+        // The debug representation switches to the scientific notation for very small and very
+        // large values, unlike the display one.
+        (Some(value), None) => {
+            let fixed = format!("{value}");
+            match value.is_finite() && !fixed.contains('.') {
+                true => fixed + ".0",
+                false => fixed,
+            }
+        }
+        (None, None) => debug,
+    }
+}
+
+/// Parses the debug representation of a float element, the integers being left out.
+fn parse_float(debug: &str) -> Option<f64> {
+    if !debug.contains(['.', 'e', 'E', 'N', 'i']) {
+        return None;
+    }
+
+    debug.parse().ok()
+}
+
+/// Whether the scientific notation is used for the given values, when they span more than three
+/// orders of magnitude or are very large or very small.
+fn use_sci<I: Iterator<Item = f64>>(values: I) -> bool {
+    let mut range: Option<(f64, f64)> = None;
+
+    for value in values
+        .map(f64::abs)
+        .filter(|value| value.is_finite() && *value != 0.0)
+    {
+        range = Some(match range {
+            Some((min, max)) => (min.min(value), max.max(value)),
+            None => (value, value),
+        });
+    }
+
+    match range {
+        Some((min, max)) => max >= 1e8 || min < 1e-4 || max / min > 1e3,
+        None => false,
+    }
+}
@@ -0,0 +1,229 @@
+use crate::{backend::Backend, check, check::TensorCheck, Tensor};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// The labels of the dimensions of the operands and of the output of an einsum equation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct EinsumEquation {
+    pub(crate) lhs: Vec<char>,
+    pub(crate) rhs: Vec<char>,
+    pub(crate) output: Vec<char>,
+}
+
+impl EinsumEquation {
+    /// Parse an equation of two operands such as `bqd,bkd->bqk`.
+    ///
+    /// Without `->`, the output has the labels appearing once in the operands, in alphabetical
+    /// order.
+    pub(crate) fn parse(equation: &str) -> Result<Self, String> {
+        let equation = equation
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>();
+        if equation.contains("...") {
+            return Err("Ellipsis are not supported.".into());
+        }
+
+        let (inputs, output) = match equation.split_once("->") {
+            Some((inputs, output)) => (inputs, Some(output)),
+            None => (equation.as_str(), None),
+        };
+        let labels = |labels: &str| match labels.chars().all(|c| c.is_ascii_alphabetic()) {
+            true => Ok(labels.chars().collect::<Vec<_>>()),
+            false => Err(format!(
+                "Invalid labels '{labels}', only letters are supported."
+            )),
+        };
+
+        let operands = inputs.split(',').collect::<Vec<_>>();
+        let [lhs, rhs] = operands[..] else {
+            return Err(format!(
+                "Two operands are expected, {} are given.",
+                operands.len()
+            ));
+        };
+        let (lhs, rhs) = (labels(lhs)?, labels(rhs)?);
+
+        let output = match output {
+            Some(output) => labels(output)?,
+            None => {
+                let count =
+                    |label: &char| lhs.iter().chain(rhs.iter()).filter(|l| *l == label).count();
+                let mut output = lhs
+                    .iter()
+                    .chain(rhs.iter())
+                    .filter(|label| count(label) == 1)
+                    .copied()
+                    .collect::<Vec<_>>();
+                output.sort();
+                output
+            }
+        };
+
+        Ok(Self { lhs, rhs, output })
+    }
+}
+
+impl<B: Backend, const D: usize> Tensor<B, D> {
+    /// Evaluate an einsum equation on two tensors.
+    ///
+    /// The equation labels each dimension of the two operands and of the output with a letter,
+    /// such as `bqd,bkd->bqk` for the attention scores. The dimensions of the output are the
+    /// dimensions with the same label in the operands, the dimensions of the operands missing from
+    /// the output being summed. Without `->`, the output has the labels appearing once in the
+    /// operands, in alphabetical order.
+    ///
+    /// The operands are permuted and reshaped to be multiplied with a single batched
+    /// [matmul](Tensor::matmul), without permutation for the common contractions such as
+    /// `bhqd,bhdk->bhqk`.
+    ///
+    /// # Panics
+    ///
+    /// - If the equation is invalid, or a dimension label is repeated in an operand.
+    /// - If the number of labels of an operand or of the output doesn't match its number of
+    ///   dimensions.
+    /// - If the dimensions with the same label have different sizes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let query = Tensor::<B, 3>::ones([2, 4, 8]);
+    ///     let key = Tensor::<B, 3>::ones([2, 6, 8]);
+    ///     let scores: Tensor<B, 3> = Tensor::einsum("bqd,bkd->bqk", query, key);
+    ///     println!("{:?}", scores.shape());
+    ///     // Shape { dims: [2, 4, 6] }
+    /// }
+    /// ```
+    pub fn einsum<const D2: usize, const D3: usize>(
+        equation: &str,
+        lhs: Self,
+        rhs: Tensor<B, D2>,
+    ) -> Tensor<B, D3> {
+        check!(TensorCheck::einsum(equation, &lhs.dims(), &rhs.dims(), D3));
+        let equation = EinsumEquation::parse(equation).unwrap();
+
+        let (lhs_dims, rhs_dims) = (lhs.dims(), rhs.dims());
+        let size_of = |label: &char| match equation.lhs.iter().position(|l| l == label) {
+            Some(index) => lhs_dims[index],
+            None => rhs_dims[equation.rhs.iter().position(|l| l == label).unwrap()],
+        };
+        let size = |labels: &[char]| labels.iter().map(size_of).product::<usize>();
+
+        let in_lhs = |label: &char| equation.lhs.contains(label);
+        let in_rhs = |label: &char| equation.rhs.contains(label);
+        let in_output = |label: &char| equation.output.contains(label);
+        let labels = |labels: &[char], filter: &dyn Fn(&char) -> bool| {
+            labels.iter().copied().filter(filter).collect::<Vec<_>>()
+        };
+
+        let batch = labels(&equation.output, &|l| in_lhs(l) && in_rhs(l));
+        let lhs_free = labels(&equation.output, &|l| in_lhs(l) && !in_rhs(l));
+        let rhs_free = labels(&equation.output, &|l| !in_lhs(l) && in_rhs(l));
+        let contracted = labels(&equation.lhs, &|l| in_rhs(l) && !in_output(l));
+        let lhs_summed = labels(&equation.lhs, &|l| !in_rhs(l) && !in_output(l));
+        let rhs_summed = labels(&equation.rhs, &|l| !in_lhs(l) && !in_output(l));
+
+        let lhs = permute(
+            lhs,
+            &equation.lhs,
+            &[&batch[..], &lhs_free, &contracted, &lhs_summed].concat(),
+        );
+        let lhs = matrices(
+            lhs,
+            [size(&batch), size(&lhs_free), size(&contracted)],
+            size(&lhs_summed),
+        );
+        let rhs = permute(
+            rhs,
+            &equation.rhs,
+            &[&batch[..], &contracted, &rhs_free, &rhs_summed].concat(),
+        );
+        let rhs = matrices(
+            rhs,
+            [size(&batch), size(&contracted), size(&rhs_free)],
+            size(&rhs_summed),
+        );
+
+        let order = [&batch[..], &lhs_free, &rhs_free].concat();
+        let mut shape = [0; D3];
+        shape
+            .iter_mut()
+            .zip(order.iter())
+            .for_each(|(dim, label)| *dim = size_of(label));
+
+        let output = lhs.matmul(rhs).reshape(shape);
+        permute(output, &order, &equation.output)
+    }
+}
+
+/// Reorder the dimensions of the tensor, from the order of the labels to the given order.
+fn permute<B: Backend, const D: usize>(
+    mut tensor: Tensor<B, D>,
+    labels: &[char],
+    order: &[char],
+) -> Tensor<B, D> {
+    let mut labels = labels.to_vec();
+
+    for (index, label) in order.iter().enumerate() {
+        let position = labels.iter().position(|l| l == label).unwrap();
+
+        if position != index {
+            tensor = tensor.swap_dims(index, position);
+            labels.swap(index, position);
+        }
+    }
+
+    tensor
+}
+
+/// Reshape the permuted operand into a batch of matrices, summing the trailing dimensions missing
+/// from the output.
+fn matrices<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    shape: [usize; 3],
+    summed: usize,
+) -> Tensor<B, 3> {
+    if summed == 1 {
+        return tensor.reshape(shape);
+    }
+
+    let [batch, rows, cols] = shape;
+    tensor
+        .reshape([batch, rows, cols, summed])
+        .sum_dim(3)
+        .reshape(shape)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn should_parse_explicit_equation() {
+        let equation = EinsumEquation::parse("bqd, bkd -> bqk").unwrap();
+
+        assert_eq!(equation.lhs, vec!['b', 'q', 'd']);
+        assert_eq!(equation.rhs, vec!['b', 'k', 'd']);
+        assert_eq!(equation.output, vec!['b', 'q', 'k']);
+    }
+
+    #[test]
+    fn should_parse_implicit_equation() {
+        let equation = EinsumEquation::parse("kj,ij").unwrap();
+
+        assert_eq!(equation.output, vec!['i', 'k']);
+    }
+
+    #[test]
+    fn should_not_parse_invalid_equations() {
+        assert!(EinsumEquation::parse("ij->i").is_err());
+        assert!(EinsumEquation::parse("i1,ij->j").is_err());
+        assert!(EinsumEquation::parse("...ij,...jk->...ik").is_err());
+    }
+}
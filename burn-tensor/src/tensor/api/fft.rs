@@ -0,0 +1,140 @@
+use crate::{backend::Backend, check, check::TensorCheck, Tensor};
+
+impl<B, const D: usize> Tensor<B, D>
+where
+    B: Backend,
+{
+    /// The discrete Fourier transform of the tensor along the given dimension.
+    ///
+    /// Returns the real and imaginary parts of the `n / 2 + 1` non-redundant frequencies, `n`
+    /// being the size of the dimension, like `numpy.fft.rfft`.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension is not smaller than the number of dimensions of the tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let signal = Tensor::<B, 1>::from_floats([1.0, 0.0, -1.0, 0.0]);
+    ///     let (real, imag) = signal.rfft(0);
+    ///     println!("{} {}", real, imag);
+    ///     // [0.0, 2.0, 0.0] [0.0, 0.0, 0.0]
+    /// }
+    /// ```
+    pub fn rfft(self, dim: usize) -> (Self, Self) {
+        check!(TensorCheck::fft::<D>(
+            "Rfft",
+            dim,
+            &self.shape(),
+            &self.shape()
+        ));
+
+        let (real, imag) = B::rfft(self.primitive, dim);
+        (Self::new(real), Self::new(imag))
+    }
+
+    /// The real tensor whose [rfft](Tensor::rfft) along the given dimension has the given real and
+    /// imaginary parts, the dimension having the given size.
+    ///
+    /// # Panics
+    ///
+    /// - If the dimension is not smaller than the number of dimensions of the tensors.
+    /// - If the real and imaginary parts have different shapes.
+    /// - If the size of the dimension of the parts isn't `size / 2 + 1`.
+    pub fn irfft(real: Self, imag: Self, dim: usize, size: usize) -> Self {
+        check!(
+            TensorCheck::fft::<D>("Irfft", dim, &real.shape(), &imag.shape()).irfft_size(
+                dim,
+                &real.shape(),
+                size
+            )
+        );
+
+        Self::new(B::irfft(real.primitive, imag.primitive, dim, size))
+    }
+
+    /// The discrete Fourier transform along the given dimension of the complex tensor with the
+    /// given real and imaginary parts.
+    ///
+    /// Returns the real and imaginary parts of the transform.
+    ///
+    /// # Panics
+    ///
+    /// - If the dimension is not smaller than the number of dimensions of the tensors.
+    /// - If the real and imaginary parts have different shapes.
+    pub fn fft(real: Self, imag: Self, dim: usize) -> (Self, Self) {
+        check!(TensorCheck::fft::<D>(
+            "Fft",
+            dim,
+            &real.shape(),
+            &imag.shape()
+        ));
+
+        let (real, imag) = B::fft(real.primitive, imag.primitive, dim, false);
+        (Self::new(real), Self::new(imag))
+    }
+
+    /// The inverse of [fft](Tensor::fft), scaled by `1 / n`, `n` being the size of the dimension.
+    ///
+    /// # Panics
+    ///
+    /// - If the dimension is not smaller than the number of dimensions of the tensors.
+    /// - If the real and imaginary parts have different shapes.
+    pub fn ifft(real: Self, imag: Self, dim: usize) -> (Self, Self) {
+        check!(TensorCheck::fft::<D>(
+            "Ifft",
+            dim,
+            &real.shape(),
+            &imag.shape()
+        ));
+
+        let (real, imag) = B::fft(real.primitive, imag.primitive, dim, true);
+        (Self::new(real), Self::new(imag))
+    }
+
+    /// The two-dimensional discrete Fourier transform over the last two dimensions of the complex
+    /// tensor with the given real and imaginary parts.
+    ///
+    /// # Panics
+    ///
+    /// - If the tensors have less than two dimensions.
+    /// - If the real and imaginary parts have different shapes.
+    pub fn fft2(real: Self, imag: Self) -> (Self, Self) {
+        // Without two dimensions, the axis D is rejected by the check
+        let dim = D.checked_sub(2).unwrap_or(D);
+        check!(TensorCheck::fft::<D>(
+            "Fft2",
+            dim,
+            &real.shape(),
+            &imag.shape()
+        ));
+
+        let (real, imag) = Self::fft(real, imag, dim);
+        Self::fft(real, imag, D - 1)
+    }
+
+    /// The inverse of [fft2](Tensor::fft2).
+    ///
+    /// # Panics
+    ///
+    /// - If the tensors have less than two dimensions.
+    /// - If the real and imaginary parts have different shapes.
+    pub fn ifft2(real: Self, imag: Self) -> (Self, Self) {
+        // Without two dimensions, the axis D is rejected by the check
+        let dim = D.checked_sub(2).unwrap_or(D);
+        check!(TensorCheck::fft::<D>(
+            "Ifft2",
+            dim,
+            &real.shape(),
+            &imag.shape()
+        ));
+
+        let (real, imag) = Self::ifft(real, imag, dim);
+        Self::ifft(real, imag, D - 1)
+    }
+}
@@ -7,8 +7,8 @@ use crate::check::TensorCheck;
 use crate::tensor::backend::Backend;
 use crate::tensor::stats;
 use crate::tensor::{Data, Distribution, Shape};
-use crate::Int;
 use crate::Tensor;
+use crate::{Bool, ElementConversion, Int};
 
 impl<const D: usize, B> Tensor<B, D>
 where
@@ -60,6 +60,71 @@ where
         Self::new(B::erf(self.primitive))
     }
 
+    /// Applies the complementary error function element wise.
+    ///
+    /// `y = erfc(x) = 1 - erf(x)`
+    pub fn erfc(self) -> Self {
+        Self::new(B::erfc(self.primitive))
+    }
+
+    /// Applies the natural logarithm of the absolute value of the
+    /// [gamma function](https://en.wikipedia.org/wiki/Gamma_function) element wise.
+    ///
+    /// `y = log(|gamma(x)|)`
+    pub fn lgamma(self) -> Self {
+        Self::new(B::lgamma(self.primitive))
+    }
+
+    /// Returns a boolean tensor indicating which elements are NaN.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 1>::from_floats([1.0, f32::NAN, f32::INFINITY]);
+    ///     println!("{}", tensor.is_nan());
+    ///     // [false, true, false]
+    /// }
+    /// ```
+    pub fn is_nan(self) -> Tensor<B, D, Bool> {
+        Tensor::new(B::is_nan(self.primitive))
+    }
+
+    /// Returns a boolean tensor indicating which elements are positive or negative infinity.
+    pub fn is_inf(self) -> Tensor<B, D, Bool> {
+        Tensor::new(B::is_inf(self.primitive))
+    }
+
+    /// Replace the NaN, positive infinity and negative infinity elements with the given values.
+    ///
+    /// The gradients of the replaced elements are zeros.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 1>::from_floats([f32::NAN, f32::INFINITY, f32::NEG_INFINITY]);
+    ///     let tensor = tensor.nan_to_num(0.0, 1e6, -1e6);
+    ///     println!("{}", tensor);
+    ///     // [0.0, 1000000.0, -1000000.0]
+    /// }
+    /// ```
+    pub fn nan_to_num<E: ElementConversion>(self, nan: E, posinf: E, neginf: E) -> Self {
+        let nan_mask = self.clone().is_nan();
+        let posinf_mask = self.clone().equal_elem(f64::INFINITY);
+        let neginf_mask = self.clone().equal_elem(f64::NEG_INFINITY);
+
+        self.mask_fill(nan_mask, nan)
+            .mask_fill(posinf_mask, posinf)
+            .mask_fill(neginf_mask, neginf)
+    }
+
     /// Applies element wise power operation.
     ///
     /// `y = x^a`
@@ -82,6 +147,49 @@ where
         Self::new(B::sin(self.primitive))
     }
 
+    /// Applies element wise tangent operation.
+    pub fn tan(self) -> Self {
+        Self::new(B::tan(self.primitive))
+    }
+
+    /// Applies element wise arcsine operation.
+    pub fn asin(self) -> Self {
+        Self::new(B::asin(self.primitive))
+    }
+
+    /// Applies element wise arccosine operation.
+    pub fn acos(self) -> Self {
+        Self::new(B::acos(self.primitive))
+    }
+
+    /// Applies element wise arctangent operation.
+    pub fn atan(self) -> Self {
+        Self::new(B::atan(self.primitive))
+    }
+
+    /// Applies element wise arctangent operation of `self / other`, using the signs of both
+    /// tensors to determine the quadrant.
+    ///
+    /// `y = atan2(x2, x1)`
+    ///
+    /// # Panics
+    ///
+    /// If the shapes of the tensors can't be broadcasted.
+    pub fn atan2(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Atan2", &self, &other));
+        Self::new(B::atan2(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise hyperbolic sine operation.
+    pub fn sinh(self) -> Self {
+        Self::new(B::sinh(self.primitive))
+    }
+
+    /// Applies element wise hyperbolic cosine operation.
+    pub fn cosh(self) -> Self {
+        Self::new(B::cosh(self.primitive))
+    }
+
     /// Applies element wise hyperbolic tangent operation.
     pub fn tanh(self) -> Self {
         Self::new(B::tanh(self.primitive))
@@ -122,6 +230,14 @@ where
         Tensor::new(B::into_int(self.primitive))
     }
 
+    /// Returns a new tensor with the same shape and device as the current tensor, the elements
+    /// being true when they are not zero.
+    ///
+    /// The NaN elements are true.
+    pub fn bool(self) -> Tensor<B, D, Bool> {
+        self.equal_elem(0.0).int().equal_elem(0)
+    }
+
     /// Returns a new tensor with the same shape and device as the current tensor filled with zeros.
     pub fn zeros_like(&self) -> Self {
         Tensor::new(B::zeros(self.shape(), &self.device()))
@@ -248,6 +364,29 @@ where
         Self::new(B::from_full_precision(tensor.primitive))
     }
 
+    /// Returns a tensor on a backend with another float precision, on the same device.
+    ///
+    /// The elements are converted through the host memory, the conversion to the
+    /// [full precision](Tensor::to_full_precision) of the backend staying on the device.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend, B2: Backend<Device = B::Device>>() {
+    ///     let tensor = Tensor::<B, 1>::from_floats([1.0, 2.5]);
+    ///     let tensor = tensor.cast_float::<B2>();
+    ///     println!("{:?}", tensor.dtype());
+    /// }
+    /// ```
+    pub fn cast_float<B2: Backend<Device = B::Device>>(self) -> Tensor<B2, D> {
+        let device = self.device();
+
+        Tensor::from_data_device(self.into_data().convert(), &device)
+    }
+
     /// Detach the current tensor from the autodiff graph.
     /// This function does nothing when autodiff is not enabled.
     /// This can be used in batchers or elsewhere to ensure that previous operations are not
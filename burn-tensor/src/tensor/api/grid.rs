@@ -0,0 +1,146 @@
+use crate::{backend::Backend, check, check::TensorCheck, BasicOps, Int, Tensor};
+
+/// How the dimensions of the grids returned by [meshgrid](Tensor::meshgrid) are ordered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GridIndexing {
+    /// The dimension `i` of the grids follows the tensor `i`, also known as `ij` indexing.
+    #[default]
+    Matrix,
+    /// Like [matrix](GridIndexing::Matrix) indexing with the first two dimensions swapped, the
+    /// first tensor following the columns, also known as `xy` indexing.
+    Cartesian,
+}
+
+impl<B> Tensor<B, 1>
+where
+    B: Backend,
+{
+    /// Returns the values from `start` to `end`, excluded, spaced by `step`, on the default device.
+    ///
+    /// # Panics
+    ///
+    /// If the step is zero or not finite, or goes away from the end.
+    pub fn arange_step(start: f64, end: f64, step: f64) -> Self {
+        Self::arange_step_device(start, end, step, &B::Device::default())
+    }
+
+    /// Returns the values from `start` to `end`, excluded, spaced by `step`, on the given device.
+    ///
+    /// The values are computed on the device as `start + i * step`.
+    ///
+    /// # Panics
+    ///
+    /// If the step is zero or not finite, or goes away from the end.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 1>::arange_step_device(1.0, 0.0, -0.25, &B::Device::default());
+    ///     println!("{}", tensor);
+    ///     // [1.0, 0.75, 0.5, 0.25]
+    /// }
+    /// ```
+    pub fn arange_step_device(start: f64, end: f64, step: f64, device: &B::Device) -> Self {
+        check!(TensorCheck::arange_step(start, end, step));
+
+        let length = libm::ceil((end - start) / step).max(0.0) as usize;
+
+        Tensor::<B, 1, Int>::arange_device(0..length, device)
+            .float()
+            .mul_scalar(step)
+            .add_scalar(start)
+    }
+
+    /// Returns `num` values evenly spaced from `start` to `end`, both included, on the default
+    /// device.
+    pub fn linspace(start: f64, end: f64, num: usize) -> Self {
+        Self::linspace_device(start, end, num, &B::Device::default())
+    }
+
+    /// Returns `num` values evenly spaced from `start` to `end`, both included, on the given
+    /// device.
+    ///
+    /// A single value is `start`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 1>::linspace_device(-1.0, 1.0, 5, &B::Device::default());
+    ///     println!("{}", tensor);
+    ///     // [-1.0, -0.5, 0.0, 0.5, 1.0]
+    /// }
+    /// ```
+    pub fn linspace_device(start: f64, end: f64, num: usize, device: &B::Device) -> Self {
+        let step = match num {
+            0 | 1 => 0.0,
+            _ => (end - start) / (num - 1) as f64,
+        };
+
+        Tensor::<B, 1, Int>::arange_device(0..num, device)
+            .float()
+            .mul_scalar(step)
+            .add_scalar(start)
+    }
+}
+
+impl<B, K> Tensor<B, 1, K>
+where
+    B: Backend,
+    K: BasicOps<B>,
+{
+    /// Returns the coordinate grids spanned by the given tensors, one grid per tensor.
+    ///
+    /// With [matrix](GridIndexing::Matrix) indexing, the grids have the shape `[n0, n1, ...]`,
+    /// `ni` being the size of the tensor `i`, and the grid `i` repeats the tensor `i` along the
+    /// dimension `i`. With [cartesian](GridIndexing::Cartesian) indexing, the first two dimensions
+    /// of the grids are swapped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{GridIndexing, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let x = Tensor::<B, 1>::from_floats([0.0, 1.0, 2.0]);
+    ///     let y = Tensor::<B, 1>::from_floats([0.0, 1.0]);
+    ///     let [grid_x, grid_y] = Tensor::meshgrid([x, y], GridIndexing::Cartesian);
+    ///     println!("{}", grid_x);
+    ///     // [[0.0, 1.0, 2.0], [0.0, 1.0, 2.0]]
+    ///     println!("{}", grid_y);
+    ///     // [[0.0, 0.0, 0.0], [1.0, 1.0, 1.0]]
+    /// }
+    /// ```
+    pub fn meshgrid<const D: usize>(
+        tensors: [Self; D],
+        indexing: GridIndexing,
+    ) -> [Tensor<B, D, K>; D] {
+        let position = |index: usize| match (indexing, index) {
+            (GridIndexing::Cartesian, 0) if D > 1 => 1,
+            (GridIndexing::Cartesian, 1) => 0,
+            _ => index,
+        };
+
+        let mut dims = [1; D];
+        for (index, tensor) in tensors.iter().enumerate() {
+            dims[position(index)] = tensor.dims()[0];
+        }
+
+        let mut index = 0;
+        tensors.map(|tensor| {
+            let mut shape = [1; D];
+            shape[position(index)] = dims[position(index)];
+            index += 1;
+
+            tensor.reshape(shape).expand(dims)
+        })
+    }
+}
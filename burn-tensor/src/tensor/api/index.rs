@@ -0,0 +1,69 @@
+use crate::{backend::Backend, check, check::TensorCheck, Element, Int, Numeric, Tensor};
+use alloc::vec::Vec;
+
+impl<B, const D: usize, K> Tensor<B, D, K>
+where
+    B: Backend,
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    /// Select the elements at the positions given by one index tensor per dimension.
+    ///
+    /// The index tensors all have the same shape, which is the shape of the output.
+    ///
+    /// Example using a 2D tensor:
+    ///
+    /// `output[i, j] = input[rows[i, j], columns[i, j]]`
+    ///
+    /// The tensor is flattened and the elements are selected with [select](Tensor::select), so the
+    /// gradients flow to the selected elements of the tensor.
+    ///
+    /// # Panics
+    ///
+    /// - If the number of index tensors isn't the number of dimensions of the tensor.
+    /// - If the index tensors have different shapes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+    ///     let rows = Tensor::<B, 1, Int>::from_ints([1, 0]);
+    ///     let columns = Tensor::<B, 1, Int>::from_ints([2, 1]);
+    ///     let output = tensor.index([rows, columns]);
+    ///     println!("{}", output);
+    ///     // [5.0, 1.0]
+    /// }
+    /// ```
+    pub fn index<const D2: usize>(self, indices: [Tensor<B, D2, Int>; D]) -> Tensor<B, D2, K> {
+        check!(TensorCheck::index::<D, D2>(
+            &indices
+                .iter()
+                .map(|indices| indices.shape())
+                .collect::<Vec<_>>()
+        ));
+
+        let dims = self.dims();
+        let shape = indices[0].shape();
+        let num_elements = shape.num_elements();
+
+        // Row-major position of the elements in the flattened tensor
+        let mut stride = 1;
+        let mut positions: Option<Tensor<B, D2, Int>> = None;
+        for (indices, size) in indices.into_iter().zip(dims).rev() {
+            let offsets = indices.mul_scalar(stride as i64);
+            positions = Some(match positions {
+                Some(positions) => positions.add(offsets),
+                None => offsets,
+            });
+            stride *= size;
+        }
+
+        let positions = positions.unwrap().reshape([num_elements]);
+
+        self.reshape([stride]).select(0, positions).reshape(shape)
+    }
+}
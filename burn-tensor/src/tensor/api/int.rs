@@ -1,4 +1,6 @@
-use crate::{backend::Backend, Data, Float, Int, Tensor};
+use crate::{
+    backend::Backend, check, check::TensorCheck, Bool, Data, ElementConversion, Float, Int, Tensor,
+};
 use core::ops::Range;
 
 impl<B> Tensor<B, 1, Int>
@@ -83,4 +85,205 @@ where
     pub fn float(self) -> Tensor<B, D, Float> {
         Tensor::new(B::int_into_float(self.primitive))
     }
+
+    /// Returns a new tensor with the same shape and device as the current tensor, the elements
+    /// being true when they are not zero.
+    pub fn bool(self) -> Tensor<B, D, Bool> {
+        self.nonzero()
+    }
+
+    /// Test if any element of the tensor is not zero.
+    pub fn any(self) -> Tensor<B, 1, Bool> {
+        self.nonzero().any()
+    }
+
+    /// Test if any element along the given dimension is not zero, keeping the dimension with a
+    /// size of one.
+    pub fn any_dim(self, dim: usize) -> Tensor<B, D, Bool> {
+        self.nonzero().any_dim(dim)
+    }
+
+    /// Test if all the elements of the tensor are not zero.
+    pub fn all(self) -> Tensor<B, 1, Bool> {
+        self.nonzero().all()
+    }
+
+    /// Test if all the elements along the given dimension are not zero, keeping the dimension
+    /// with a size of one.
+    pub fn all_dim(self, dim: usize) -> Tensor<B, D, Bool> {
+        self.nonzero().all_dim(dim)
+    }
+
+    /// Count the elements of the tensor which are not zero.
+    pub fn count_nonzero(self) -> Tensor<B, 1, Int> {
+        self.nonzero().count_nonzero()
+    }
+
+    /// Count the elements along the given dimension which are not zero, keeping the dimension
+    /// with a size of one.
+    pub fn count_nonzero_dim(self, dim: usize) -> Self {
+        self.nonzero().count_nonzero_dim(dim)
+    }
+
+    /// Encode the indices as one hot vectors of the given number of classes, along a new last
+    /// dimension.
+    ///
+    /// The element of each vector at the index is one and the others are zeros, the indices out
+    /// of `0..num_classes` having vectors of zeros.
+    ///
+    /// # Panics
+    ///
+    /// If the output doesn't have one more dimension than the tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let indices = Tensor::<B, 1, Int>::from_ints([2, 0]);
+    ///     let one_hot: Tensor<B, 2> = indices.one_hot(3);
+    ///     println!("{}", one_hot);
+    ///     // [[0.0, 0.0, 1.0], [1.0, 0.0, 0.0]]
+    /// }
+    /// ```
+    pub fn one_hot<const D2: usize>(self, num_classes: usize) -> Tensor<B, D2, Float> {
+        self.one_hot_bool(num_classes).float()
+    }
+
+    /// Encode the indices as one hot masks of the given number of classes, along a new last
+    /// dimension.
+    ///
+    /// See [one_hot](Tensor::one_hot).
+    pub fn one_hot_bool<const D2: usize>(self, num_classes: usize) -> Tensor<B, D2, Bool> {
+        check!(TensorCheck::one_hot::<D, D2>());
+
+        let mut dims = [1; D2];
+        dims[..D].copy_from_slice(&self.dims());
+        let indices = self.reshape(dims);
+        dims[D] = num_classes;
+
+        let mut unit = [1; D2];
+        unit[D] = num_classes;
+        let classes = Tensor::<B, 1, Int>::arange_device(0..num_classes, &indices.device())
+            .reshape(unit)
+            .expand(dims);
+
+        classes.equal(indices.expand(dims))
+    }
+
+    /// Applies element wise bitwise and of the tensors.
+    ///
+    /// The tensors are broadcasted to a common shape.
+    ///
+    /// # Panics
+    ///
+    /// If the shapes of the tensors can't be broadcasted.
+    pub fn bitwise_and(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("BitwiseAnd", &self, &other));
+        Self::new(B::int_bitwise_and(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise bitwise and of the tensor and a scalar.
+    pub fn bitwise_and_scalar<E: ElementConversion>(self, other: E) -> Self {
+        let other = Self::full_device([1; D], other, &self.device());
+
+        Self::new(B::int_bitwise_and(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise bitwise or of the tensors.
+    ///
+    /// The tensors are broadcasted to a common shape.
+    ///
+    /// # Panics
+    ///
+    /// If the shapes of the tensors can't be broadcasted.
+    pub fn bitwise_or(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("BitwiseOr", &self, &other));
+        Self::new(B::int_bitwise_or(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise bitwise or of the tensor and a scalar.
+    pub fn bitwise_or_scalar<E: ElementConversion>(self, other: E) -> Self {
+        let other = Self::full_device([1; D], other, &self.device());
+
+        Self::new(B::int_bitwise_or(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise bitwise xor of the tensors.
+    ///
+    /// The tensors are broadcasted to a common shape.
+    ///
+    /// # Panics
+    ///
+    /// If the shapes of the tensors can't be broadcasted.
+    pub fn bitwise_xor(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("BitwiseXor", &self, &other));
+        Self::new(B::int_bitwise_xor(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise bitwise xor of the tensor and a scalar.
+    pub fn bitwise_xor_scalar<E: ElementConversion>(self, other: E) -> Self {
+        let other = Self::full_device([1; D], other, &self.device());
+
+        Self::new(B::int_bitwise_xor(self.primitive, other.primitive))
+    }
+
+    /// Applies element wise bitwise not, flipping all the bits of the elements.
+    pub fn bitwise_not(self) -> Self {
+        Self::new(B::int_bitwise_not(self.primitive))
+    }
+
+    /// Shifts the bits of the tensor by the number of bits of the other tensor to the left.
+    ///
+    /// The tensors are broadcasted to a common shape.
+    ///
+    /// # Panics
+    ///
+    /// If the shapes of the tensors can't be broadcasted.
+    pub fn bitwise_left_shift(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew(
+            "BitwiseLeftShift",
+            &self,
+            &other
+        ));
+        Self::new(B::int_bitwise_left_shift(self.primitive, other.primitive))
+    }
+
+    /// Shifts the bits of the tensor by the given number of bits to the left.
+    pub fn bitwise_left_shift_scalar<E: ElementConversion>(self, other: E) -> Self {
+        let other = Self::full_device([1; D], other, &self.device());
+
+        Self::new(B::int_bitwise_left_shift(self.primitive, other.primitive))
+    }
+
+    /// Shifts the bits of the tensor by the number of bits of the other tensor to the right,
+    /// preserving the sign.
+    ///
+    /// The tensors are broadcasted to a common shape.
+    ///
+    /// # Panics
+    ///
+    /// If the shapes of the tensors can't be broadcasted.
+    pub fn bitwise_right_shift(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew(
+            "BitwiseRightShift",
+            &self,
+            &other
+        ));
+        Self::new(B::int_bitwise_right_shift(self.primitive, other.primitive))
+    }
+
+    /// Shifts the bits of the tensor by the given number of bits to the right, preserving the sign.
+    pub fn bitwise_right_shift_scalar<E: ElementConversion>(self, other: E) -> Self {
+        let other = Self::full_device([1; D], other, &self.device());
+
+        Self::new(B::int_bitwise_right_shift(self.primitive, other.primitive))
+    }
+
+    /// The mask of the elements which are not zero.
+    fn nonzero(self) -> Tensor<B, D, Bool> {
+        self.equal_elem(0).int().equal_elem(0)
+    }
 }
@@ -1,12 +1,33 @@
 pub(crate) mod check;
 
+mod assign;
 mod base;
 mod bool;
+mod cast;
+mod complex;
+mod condition;
+mod diagonal;
+mod display;
+mod einsum;
+mod fft;
 mod float;
+mod grid;
+mod index;
 mod int;
 mod kind;
 mod numeric;
+mod pad;
+mod quantization;
+mod random;
+mod unique;
 
 pub use base::*;
+pub use cast::*;
+pub use complex::*;
+pub use condition::*;
+pub use display::*;
+pub use grid::*;
 pub use kind::*;
 pub use numeric::*;
+pub use pad::*;
+pub use quantization::*;
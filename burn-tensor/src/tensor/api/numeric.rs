@@ -1,7 +1,8 @@
 use crate::{
-    backend::Backend, check, check::TensorCheck, BasicOps, Bool, Element, ElementConversion, Float,
-    Int, Shape, Tensor, TensorKind,
+    backend::Backend, check, check::TensorCheck, BasicOps, Bool, Data, Element, ElementConversion,
+    Float, Int, Shape, Tensor, TensorKind,
 };
+use alloc::vec::Vec;
 
 impl<B, const D: usize, K> Tensor<B, D, K>
 where
@@ -228,6 +229,44 @@ where
         Self::new(K::mask_fill(self.primitive, mask, value.elem()))
     }
 
+    /// Update the given tensor with the value where the mask is true.
+    ///
+    /// This is the same operation as [mask_fill](Tensor::mask_fill), under the name used by
+    /// PyTorch.
+    pub fn masked_fill<E: ElementConversion>(self, mask: Tensor<B, D, Bool>, value: E) -> Self {
+        self.mask_fill(mask, value)
+    }
+
+    /// Select the elements where the mask is true, returning them in a 1-D tensor in row-major
+    /// order.
+    ///
+    /// The mask is read on the host to find the number of selected elements, and the elements are
+    /// selected with [select](Tensor::select), so the gradients flow to the selected elements.
+    pub fn masked_select(self, mask: Tensor<B, D, Bool>) -> Tensor<B, 1, K> {
+        check!(TensorCheck::masked_select::<D>(
+            &self.shape(),
+            &mask.shape()
+        ));
+
+        let device = self.device();
+        let positions = mask
+            .into_data()
+            .value
+            .into_iter()
+            .enumerate()
+            .filter(|(_, selected)| *selected)
+            .map(|(position, _)| position as i64)
+            .collect::<Vec<_>>();
+        let length = positions.len();
+        let positions = Tensor::<B, 1, Int>::from_data_device(
+            Data::new(positions, Shape::new([length])).convert(),
+            &device,
+        );
+        let num_elements = self.shape().num_elements();
+
+        self.reshape([num_elements]).select(0, positions)
+    }
+
     /// Gather tensor elements corresponding to the given indices from the specified dim.
     ///
     /// Example using a 3D tensor:
@@ -276,6 +315,38 @@ where
         Self::new(K::scatter(dim, self.primitive, indices, values.primitive))
     }
 
+    /// Assign the gathered elements corresponding to the given indices along the specified dimension
+    /// from the value tensor to the original tensor, replacing the original elements.
+    ///
+    /// Example using a 3D tensor:
+    ///
+    /// `input[indices[i, j, k], j, k] = values[i, j, k]; // dim = 0`
+    /// `input[i, indices[i, j, k], k] = values[i, j, k]; // dim = 1`
+    /// `input[i, j, indices[i, j, k]] = values[i, j, k]; // dim = 2`
+    ///
+    /// # Notes
+    ///
+    /// The index tensor should have the same shape as the original tensor except for the specified
+    /// dimension. The value and index tensors should have the same shape.
+    ///
+    /// The indices should not contain the same position twice along the specified dimension, the
+    /// value assigned to such a position being backend specific.
+    pub fn scatter_assign(self, dim: usize, indices: Tensor<B, D, Int>, values: Self) -> Self {
+        check!(TensorCheck::scatter::<D>(
+            dim,
+            &self.shape(),
+            &indices.shape(),
+            &values.shape()
+        ));
+
+        Self::new(K::scatter_assign(
+            dim,
+            self.primitive,
+            indices,
+            values.primitive,
+        ))
+    }
+
     /// Select the tensor elements along the given dimension corresponding to the given indices.
     ///
     /// Example using a 3D tensor:
@@ -288,6 +359,99 @@ where
         Self::new(K::select(self.primitive, dim, indices))
     }
 
+    /// Select the tensor elements along the given dimension corresponding to the given indices.
+    ///
+    /// This is the same operation as [select](Tensor::select), under the name used by PyTorch.
+    pub fn index_select(self, dim: usize, indices: Tensor<B, 1, Int>) -> Self {
+        self.select(dim, indices)
+    }
+
+    /// Repeat each element of the tensor along the given dimension the given number of times, the
+    /// copies of an element being next to each other.
+    ///
+    /// The elements are repeated with [select](Tensor::select), so the gradients of the copies are
+    /// summed back to their element.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension is higher than the tensor rank.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+    ///     let tensor = tensor.repeat_interleave(2, 1);
+    ///     println!("{}", tensor);
+    ///     // [[1.0, 1.0, 2.0, 2.0], [3.0, 3.0, 4.0, 4.0]]
+    /// }
+    /// ```
+    pub fn repeat_interleave(self, repeats: usize, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("RepeatInterleave", dim));
+
+        let size = self.dims()[dim];
+        let repeats = alloc::vec![repeats as i64; size];
+
+        self.repeat_interleave_with(&repeats, dim)
+    }
+
+    /// Repeat each element of the tensor along the given dimension the number of times given by
+    /// the corresponding element of the repeat counts.
+    ///
+    /// The counts are read on the host to find the size of the output, and the elements are
+    /// repeated with [select](Tensor::select), so the gradients of the copies are summed back to
+    /// their element.
+    ///
+    /// # Panics
+    ///
+    /// - If the dimension is higher than the tensor rank.
+    /// - If the number of counts doesn't match the size of the dimension.
+    /// - If a count is negative.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let labels = Tensor::<B, 1, Int>::from_ints([7, 8, 9]);
+    ///     let counts = Tensor::<B, 1, Int>::from_ints([2, 0, 3]);
+    ///     println!("{}", labels.repeat_interleave_counts(counts, 0));
+    ///     // [7, 7, 9, 9, 9]
+    /// }
+    /// ```
+    pub fn repeat_interleave_counts(self, repeats: Tensor<B, 1, Int>, dim: usize) -> Self {
+        let repeats = repeats
+            .into_data()
+            .convert::<i64>()
+            .value
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        self.repeat_interleave_with(&repeats, dim)
+    }
+
+    fn repeat_interleave_with(self, repeats: &[i64], dim: usize) -> Self {
+        check!(TensorCheck::repeat_interleave(&self.shape(), repeats, dim));
+
+        let indices = repeats
+            .iter()
+            .enumerate()
+            .flat_map(|(index, count)| core::iter::repeat_n(index as i64, *count as usize))
+            .collect::<Vec<_>>();
+        let length = indices.len();
+        let indices = Tensor::<B, 1, Int>::from_data_device(
+            Data::new(indices, Shape::new([length])).convert(),
+            &self.device(),
+        );
+
+        self.select(dim, indices)
+    }
+
     /// Assign the selected elements along the given dimension corresponding to the given indices
     /// from the value tensor to the original tensor using sum reduction.
     ///
@@ -312,6 +476,75 @@ where
         ))
     }
 
+    /// Reverse the order of the elements along the given dimensions.
+    ///
+    /// The elements are reordered with [select](Tensor::select), so the gradients flow back to
+    /// their original positions.
+    ///
+    /// # Panics
+    ///
+    /// If a dimension is not smaller than the number of dimensions of the tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+    ///     let tensor = tensor.flip(&[1]);
+    ///     println!("{}", tensor);
+    ///     // [[2.0, 1.0, 0.0], [5.0, 4.0, 3.0]]
+    /// }
+    /// ```
+    pub fn flip(self, dims: &[usize]) -> Self {
+        check!(TensorCheck::flip::<D>(dims));
+
+        let sizes = self.dims();
+        let device = self.device();
+
+        dims.iter().fold(self, |tensor, dim| {
+            let size = sizes[*dim];
+            let indices = (0..size)
+                .rev()
+                .map(|index| index as i64)
+                .collect::<Vec<_>>();
+            let indices = Tensor::<B, 1, Int>::from_data_device(
+                Data::new(indices, Shape::new([size])).convert(),
+                &device,
+            );
+
+            tensor.select(*dim, indices)
+        })
+    }
+
+    /// The lower triangular part of the last two dimensions, the other elements being zeros.
+    ///
+    /// The elements where the column index minus the row index is greater than `diagonal` are
+    /// zeros, see [tril_mask](Tensor::tril_mask).
+    ///
+    /// # Panics
+    ///
+    /// If the tensor has less than two dimensions.
+    pub fn tril(self, diagonal: i64) -> Self {
+        let mask = Tensor::<B, D, Bool>::tril_mask(self.shape(), diagonal, &self.device());
+        self.mask_fill(mask, 0)
+    }
+
+    /// The upper triangular part of the last two dimensions, the other elements being zeros.
+    ///
+    /// The elements where the column index minus the row index is lower than `diagonal` are
+    /// zeros, see [triu_mask](Tensor::triu_mask).
+    ///
+    /// # Panics
+    ///
+    /// If the tensor has less than two dimensions.
+    pub fn triu(self, diagonal: i64) -> Self {
+        let mask = Tensor::<B, D, Bool>::triu_mask(self.shape(), diagonal, &self.device());
+        self.mask_fill(mask, 0)
+    }
+
     /// Applies the argmax function along the given dimension and returns an integer tensor.
     ///
     /// # Example
@@ -401,6 +634,66 @@ where
         (tensor, index)
     }
 
+    /// Find the `k` largest values along the given dimension, sorted from the largest.
+    ///
+    /// Also returns the indices.
+    ///
+    /// # Panics
+    ///
+    /// If `k` is greater than the size of the dimension.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 5.0, 3.0], [4.0, 2.0, 6.0]]);
+    ///     let (values, indices) = tensor.topk(2, 1);
+    ///     println!("{}", values);
+    ///     // [[5.0, 3.0], [6.0, 4.0]]
+    ///     println!("{}", indices);
+    ///     // [[1, 2], [2, 0]]
+    /// }
+    /// ```
+    pub fn topk(self, k: usize, dim: usize) -> (Tensor<B, D, K>, Tensor<B, D, Int>) {
+        self.topk_with_options(k, dim, true, true)
+    }
+
+    /// Find the `k` largest or smallest values along the given dimension.
+    ///
+    /// Also returns the indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - The number of values to find.
+    /// * `dim` - The dimension along which to find the values.
+    /// * `largest` - Whether to find the largest or the smallest values.
+    /// * `sorted` - Whether the values are sorted, from the largest or the smallest, or kept in
+    ///   the order of the tensor.
+    ///
+    /// # Notes
+    ///
+    /// The ties are broken by index, and `NaN` is greater than any other value.
+    ///
+    /// # Panics
+    ///
+    /// If `k` is greater than the size of the dimension.
+    pub fn topk_with_options(
+        self,
+        k: usize,
+        dim: usize,
+        largest: bool,
+        sorted: bool,
+    ) -> (Tensor<B, D, K>, Tensor<B, D, Int>) {
+        check!(TensorCheck::topk::<D>(&self.shape(), k, dim));
+
+        let (tensor, index) = K::topk(self.primitive, dim, k, largest, sorted);
+
+        (Tensor::new(tensor), Tensor::new(index))
+    }
+
     /// Clamp the tensor between the given min and max values.
     ///
     /// # Arguments
@@ -444,12 +737,82 @@ where
         Self::new(K::clamp_max(self.primitive, max))
     }
 
+    /// Applies element wise maximum operation between two tensors, which are broadcasted to a
+    /// common shape.
+    ///
+    /// The gradient flows back to the tensor with the maximum, the current tensor when the values
+    /// are equal.
+    ///
+    /// # Panics
+    ///
+    /// If the shapes of the tensors can't be broadcasted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let lhs = Tensor::<B, 2>::from_floats([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]]);
+    ///     let rhs = Tensor::<B, 2>::from_floats([[2.0, 4.0, 1.0]]);
+    ///     let tensor = lhs.max_pair(rhs);
+    ///     println!("{}", tensor);
+    ///     // [[2.0, 4.0, 3.0], [5.0, 9.0, 6.0]]
+    /// }
+    /// ```
+    pub fn max_pair(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Max Pair", &self, &other));
+
+        let (lhs, rhs) = broadcast_pair(self, other);
+        let mask = lhs.clone().lower(rhs.clone());
+
+        lhs.mask_where(mask, rhs)
+    }
+
+    /// Applies element wise minimum operation between two tensors, which are broadcasted to a
+    /// common shape.
+    ///
+    /// The gradient flows back to the tensor with the minimum, the current tensor when the values
+    /// are equal.
+    ///
+    /// # Panics
+    ///
+    /// If the shapes of the tensors can't be broadcasted.
+    pub fn min_pair(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Min Pair", &self, &other));
+
+        let (lhs, rhs) = broadcast_pair(self, other);
+        let mask = lhs.clone().greater(rhs.clone());
+
+        lhs.mask_where(mask, rhs)
+    }
+
     /// Apply element wise absolute value operation
     pub fn abs(self) -> Self {
         Self::new(K::abs(self.primitive))
     }
 }
 
+/// Expand the two tensors to their common broadcasted shape.
+fn broadcast_pair<B, const D: usize, K>(
+    lhs: Tensor<B, D, K>,
+    rhs: Tensor<B, D, K>,
+) -> (Tensor<B, D, K>, Tensor<B, D, K>)
+where
+    B: Backend,
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    let (shape_lhs, shape_rhs) = (lhs.shape(), rhs.shape());
+    let mut dims = [0; D];
+    for (i, dim) in dims.iter_mut().enumerate() {
+        *dim = usize::max(shape_lhs.dims[i], shape_rhs.dims[i]);
+    }
+
+    (lhs.expand(dims), rhs.expand(dims))
+}
+
 /// Trait that list all operations that can be applied on all numerical tensors.
 ///
 /// # Warnings
@@ -1148,6 +1511,36 @@ where
         values: Self::Primitive<D>,
     ) -> Self::Primitive<D>;
 
+    /// Scatters elements into a tensor along an axis, replacing the elements at the indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The axis along which to scatter elements.
+    /// * `tensor` - The tensor to scatter elements into.
+    /// * `indices` - The indices of the elements to scatter.
+    /// * `values` - The values to scatter into the tensor.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where the elements at the specified
+    /// indices are replaced by the corresponding elements of the values tensor.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For scattering elements into a tensor along an axis, users should prefer the
+    /// [Tensor::scatter_assign](Tensor::scatter_assign) function, which is more high-level and
+    /// designed for public use.
+    fn scatter_assign<const D: usize>(
+        dim: usize,
+        tensor: Self::Primitive<D>,
+        indices: Tensor<B, D, Int>,
+        values: Self::Primitive<D>,
+    ) -> Self::Primitive<D>;
+
     /// Select tensor elements along the given dimension corresponding for the given indices.
     ///
     /// # Arguments
@@ -1318,6 +1711,37 @@ where
         dim: usize,
     ) -> (Self::Primitive<D>, B::IntTensorPrimitive<D>);
 
+    /// Gets the `k` largest or smallest elements of a tensor along an axis and their indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to get the elements from.
+    /// * `dim` - The axis along which to get the elements.
+    /// * `k` - The number of elements to get.
+    /// * `largest` - Whether to get the largest or the smallest elements.
+    /// * `sorted` - Whether the elements are sorted by value or kept in the order of the tensor.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the elements, and a tensor with the same shape where each element is the
+    /// index of the element along the specified axis.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For getting the top elements of a tensor along an axis, users should prefer the
+    /// [Tensor::topk](Tensor::topk) function, which is more high-level and designed for public use.
+    fn topk<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        k: usize,
+        largest: bool,
+        sorted: bool,
+    ) -> (Self::Primitive<D>, B::IntTensorPrimitive<D>);
+
     /// Gets the minimum elements of a tensor along an axis.
     ///
     /// # Arguments
@@ -1655,6 +2079,15 @@ impl<B: Backend> Numeric<B> for Int {
         B::int_scatter(dim, tensor, indices.primitive, values)
     }
 
+    fn scatter_assign<const D: usize>(
+        dim: usize,
+        tensor: Self::Primitive<D>,
+        indices: Tensor<B, D, Int>,
+        values: Self::Primitive<D>,
+    ) -> Self::Primitive<D> {
+        B::int_scatter_assign(dim, tensor, indices.primitive, values)
+    }
+
     fn argmax<const D: usize>(
         tensor: Self::Primitive<D>,
         dim: usize,
@@ -1684,6 +2117,16 @@ impl<B: Backend> Numeric<B> for Int {
         B::int_max_dim_with_indices(tensor, dim)
     }
 
+    fn topk<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        k: usize,
+        largest: bool,
+        sorted: bool,
+    ) -> (Self::Primitive<D>, <B as Backend>::IntTensorPrimitive<D>) {
+        B::int_topk(tensor, dim, k, largest, sorted)
+    }
+
     fn min<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<1> {
         B::int_min(tensor)
     }
@@ -1910,6 +2353,15 @@ impl<B: Backend> Numeric<B> for Float {
         B::scatter(dim, tensor, indices.primitive, values)
     }
 
+    fn scatter_assign<const D: usize>(
+        dim: usize,
+        tensor: Self::Primitive<D>,
+        indices: Tensor<B, D, Int>,
+        values: Self::Primitive<D>,
+    ) -> Self::Primitive<D> {
+        B::scatter_assign(dim, tensor, indices.primitive, values)
+    }
+
     fn argmax<const D: usize>(
         tensor: Self::Primitive<D>,
         dim: usize,
@@ -1939,6 +2391,16 @@ impl<B: Backend> Numeric<B> for Float {
         B::max_dim_with_indices(tensor, dim)
     }
 
+    fn topk<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        k: usize,
+        largest: bool,
+        sorted: bool,
+    ) -> (Self::Primitive<D>, <B as Backend>::IntTensorPrimitive<D>) {
+        B::topk(tensor, dim, k, largest, sorted)
+    }
+
     fn min<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<1> {
         B::min(tensor)
     }
@@ -0,0 +1,119 @@
+use crate::{
+    backend::Backend, check, check::TensorCheck, Data, Element, ElementConversion, Int, Numeric,
+    Shape, Tensor,
+};
+use alloc::vec::Vec;
+
+/// How the values added by [pad](Tensor::pad) are computed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PadMode {
+    /// Fill the padded area with the given value.
+    Constant(f64),
+    /// Mirror the values at the border, excluding the border value itself.
+    Reflect,
+    /// Repeat the border value, also known as replicate padding.
+    Edge,
+}
+
+impl Default for PadMode {
+    fn default() -> Self {
+        Self::Constant(0.0)
+    }
+}
+
+impl<B, const D: usize, K> Tensor<B, D, K>
+where
+    B: Backend,
+    K: Numeric<B>,
+    K::Elem: Element,
+{
+    /// Pad the tensor along every dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `pads` - The number of values to add `(before, after)` each dimension.
+    /// * `mode` - How the padded values are computed.
+    ///
+    /// # Panics
+    ///
+    /// With [reflect](PadMode::Reflect) mode, when a padding is not smaller than the size of its
+    /// dimension.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{PadMode, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::ones([2, 3]);
+    ///     let tensor = tensor.pad([(1, 1), (0, 2)], PadMode::Constant(0.0));
+    ///     println!("{:?}", tensor.shape());
+    ///     // Shape { dims: [4, 5] }
+    /// }
+    /// ```
+    pub fn pad(self, pads: [(usize, usize); D], mode: PadMode) -> Self {
+        check!(TensorCheck::pad(&self.shape(), &pads, &mode));
+
+        match mode {
+            PadMode::Constant(value) => self.pad_constant(pads, value),
+            PadMode::Reflect => self.pad_indices(pads, |index, size| {
+                let period = 2 * (size - 1);
+                let index = index.rem_euclid(period as i64) as usize;
+
+                if index < size {
+                    index
+                } else {
+                    period - index
+                }
+            }),
+            PadMode::Edge => {
+                self.pad_indices(pads, |index, size| index.clamp(0, size as i64 - 1) as usize)
+            }
+        }
+    }
+
+    fn pad_constant(self, pads: [(usize, usize); D], value: f64) -> Self {
+        let dims = self.dims();
+        let device = self.device();
+
+        let mut padded_dims = dims;
+        let ranges = core::array::from_fn(|i| {
+            let (before, after) = pads[i];
+            padded_dims[i] = before + dims[i] + after;
+            before..before + dims[i]
+        });
+
+        Self::full_device(Shape::new(padded_dims), value.elem::<K::Elem>(), &device)
+            .slice_assign::<D>(ranges, self)
+    }
+
+    fn pad_indices<F>(self, pads: [(usize, usize); D], source_index: F) -> Self
+    where
+        F: Fn(i64, usize) -> usize,
+    {
+        let dims = self.dims();
+        let device = self.device();
+        let mut tensor = self;
+
+        for (dim, (before, after)) in pads.into_iter().enumerate() {
+            if before == 0 && after == 0 {
+                continue;
+            }
+
+            let size = dims[dim];
+            let indices = (-(before as i64)..(size + after) as i64)
+                .map(|index| source_index(index, size) as i32)
+                .collect::<Vec<_>>();
+            let length = indices.len();
+            let indices = Tensor::<B, 1, Int>::from_data_device(
+                Data::new(indices, Shape::new([length])).convert(),
+                &device,
+            );
+
+            tensor = tensor.select(dim, indices);
+        }
+
+        tensor
+    }
+}
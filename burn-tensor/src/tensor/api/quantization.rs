@@ -0,0 +1,226 @@
+use crate::{
+    backend::Backend, check, check::TensorCheck, Data, ElementConversion, Int, Shape, Tensor,
+};
+
+/// The smallest value of a quantized tensor.
+pub const QUANTIZED_MIN: i64 = i8::MIN as i64;
+/// The largest value of a quantized tensor.
+pub const QUANTIZED_MAX: i64 = i8::MAX as i64;
+
+/// The affine parameters mapping the values of a [quantized tensor](QuantizedTensor) to floats,
+/// `x = (q - zero_point) * scale`.
+#[derive(Clone, Debug)]
+pub enum QuantizationParameters<B: Backend> {
+    /// The same parameters for all the values.
+    PerTensor {
+        /// The scale.
+        scale: f64,
+        /// The quantized value of zero.
+        zero_point: i8,
+    },
+    /// Parameters for each index of the axis, usually the output channels of a weight.
+    PerChannel {
+        /// The scale of each channel.
+        scales: Tensor<B, 1>,
+        /// The quantized value of zero of each channel.
+        zero_points: Tensor<B, 1, Int>,
+        /// The axis of the channels.
+        axis: usize,
+    },
+}
+
+/// A tensor of int8 values with the [affine parameters](QuantizationParameters) to dequantize
+/// them.
+///
+/// The backends have no int8 tensor, so the values are kept in an int tensor of the backend, whose
+/// element is usually wider. They are always in the int8 range though, and are read as `i8` with
+/// [into_data](QuantizedTensor::into_data).
+#[derive(Clone, Debug)]
+pub struct QuantizedTensor<B: Backend, const D: usize> {
+    values: Tensor<B, D, Int>,
+    parameters: QuantizationParameters<B>,
+}
+
+impl<B, const D: usize> Tensor<B, D>
+where
+    B: Backend,
+{
+    /// Quantize the tensor to int8 values with the given affine parameters.
+    ///
+    /// `q = clamp(round(x / scale) + zero_point, -128, 127)`, the values halfway between two
+    /// integers being rounded up.
+    ///
+    /// # Panics
+    ///
+    /// If the scale is not positive.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 1>::from_floats([-1.0, 0.0, 0.26, 100.0]);
+    ///     let quantized = tensor.quantize(0.1, 2);
+    ///     println!("{:?}", quantized.clone().into_data());
+    ///     // [-8, 2, 5, 127]
+    ///     println!("{}", quantized.dequantize());
+    ///     // [-1.0, 0.0, 0.3, 12.5]
+    /// }
+    /// ```
+    pub fn quantize(self, scale: f64, zero_point: i8) -> QuantizedTensor<B, D> {
+        check!(TensorCheck::quantize(scale));
+
+        let values = round(self.div_scalar(scale))
+            .add_scalar(zero_point)
+            .clamp(QUANTIZED_MIN.elem(), QUANTIZED_MAX.elem());
+
+        QuantizedTensor {
+            values,
+            parameters: QuantizationParameters::PerTensor { scale, zero_point },
+        }
+    }
+
+    /// Quantize the tensor to int8 values with affine parameters given for each index of the
+    /// axis, usually the output channels of a weight.
+    ///
+    /// `q = clamp(round(x / scales[c]) + zero_points[c], -128, 127)`, `c` being the index on the
+    /// axis.
+    ///
+    /// # Panics
+    ///
+    /// - If the axis is higher than the tensor rank.
+    /// - If the number of scales or zero points doesn't match the size of the axis.
+    pub fn quantize_per_channel(
+        self,
+        scales: Tensor<B, 1>,
+        zero_points: Tensor<B, 1, Int>,
+        axis: usize,
+    ) -> QuantizedTensor<B, D> {
+        check!(TensorCheck::quantize_per_channel::<D>(
+            &self.shape(),
+            &scales.shape(),
+            &zero_points.shape(),
+            axis
+        ));
+
+        let shape = channel_shape::<D>(self.dims()[axis], axis);
+        let values = round(self.div(scales.clone().reshape(shape)))
+            .add(zero_points.clone().reshape(shape))
+            .clamp(QUANTIZED_MIN.elem(), QUANTIZED_MAX.elem());
+
+        QuantizedTensor {
+            values,
+            parameters: QuantizationParameters::PerChannel {
+                scales,
+                zero_points,
+                axis,
+            },
+        }
+    }
+}
+
+impl<B, const D: usize> QuantizedTensor<B, D>
+where
+    B: Backend,
+{
+    /// Create a quantized tensor from int8 values and their affine parameters.
+    ///
+    /// # Panics
+    ///
+    /// - If the scale is not positive.
+    /// - If the axis of the per-channel parameters is higher than the tensor rank, or if their
+    ///   number doesn't match the size of the axis.
+    pub fn from_data(
+        data: Data<i8, D>,
+        parameters: QuantizationParameters<B>,
+        device: &B::Device,
+    ) -> Self {
+        let values = Tensor::from_data_device(data.convert(), device);
+
+        match &parameters {
+            QuantizationParameters::PerTensor { scale, .. } => {
+                check!(TensorCheck::quantize(*scale))
+            }
+            QuantizationParameters::PerChannel {
+                scales,
+                zero_points,
+                axis,
+            } => check!(TensorCheck::quantize_per_channel::<D>(
+                &values.shape(),
+                &scales.shape(),
+                &zero_points.shape(),
+                *axis
+            )),
+        }
+
+        Self { values, parameters }
+    }
+
+    /// Dequantize the values with their affine parameters.
+    ///
+    /// `x = (q - zero_point) * scale`, with the parameters of the channel of each value when they
+    /// are given per channel.
+    pub fn dequantize(self) -> Tensor<B, D> {
+        match self.parameters {
+            QuantizationParameters::PerTensor { scale, zero_point } => {
+                self.values.sub_scalar(zero_point).float().mul_scalar(scale)
+            }
+            QuantizationParameters::PerChannel {
+                scales,
+                zero_points,
+                axis,
+            } => {
+                let shape = channel_shape::<D>(self.values.dims()[axis], axis);
+
+                self.values
+                    .sub(zero_points.reshape(shape))
+                    .float()
+                    .mul(scales.reshape(shape))
+            }
+        }
+    }
+
+    /// The int8 values.
+    pub fn into_data(self) -> Data<i8, D> {
+        self.values.into_data().convert()
+    }
+
+    /// The int8 values, stored in an int tensor of the backend.
+    pub fn values(&self) -> &Tensor<B, D, Int> {
+        &self.values
+    }
+
+    /// The affine parameters of the values.
+    pub fn parameters(&self) -> &QuantizationParameters<B> {
+        &self.parameters
+    }
+
+    /// The shape of the tensor.
+    pub fn shape(&self) -> Shape<D> {
+        self.values.shape()
+    }
+
+    /// The device of the tensor.
+    pub fn device(&self) -> B::Device {
+        self.values.device()
+    }
+}
+
+/// The shape broadcasting the channel parameters along the axis.
+fn channel_shape<const D: usize>(channels: usize, axis: usize) -> [usize; D] {
+    let mut shape = [1; D];
+    shape[axis] = channels;
+
+    shape
+}
+
+/// Round to the nearest integers, the values halfway between two integers being rounded up.
+fn round<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D, Int> {
+    let shifted = tensor.add_scalar(0.5);
+    let truncated = shifted.clone().int();
+    let correction = shifted.lower(truncated.clone().float()).int();
+
+    truncated.sub(correction)
+}
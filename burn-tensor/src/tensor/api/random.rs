@@ -0,0 +1,132 @@
+use crate::{backend::Backend, check, check::TensorCheck, Distribution, Int, Shape, Tensor};
+
+impl<B, const D: usize> Tensor<B, D>
+where
+    B: Backend,
+{
+    /// Create a tensor where each element is sampled from a normal distribution, with the mean and
+    /// the standard deviation of the corresponding elements of the given tensors.
+    ///
+    /// The tensors are broadcasted to a common shape. The samples are computed as
+    /// `mean + std * N(0, 1)`, so the gradients flow back to the mean and the standard deviation.
+    ///
+    /// # Panics
+    ///
+    /// If the shapes of the tensors can't be broadcasted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let mean = Tensor::<B, 2>::zeros([2, 3]);
+    ///     let std = Tensor::<B, 2>::from_floats([[1.0, 2.0, 3.0]]);
+    ///     let samples = Tensor::normal(mean, std);
+    ///     println!("{:?}", samples.shape());
+    ///     // Shape { dims: [2, 3] }
+    /// }
+    /// ```
+    pub fn normal(mean: Self, std: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Normal", &mean, &std));
+
+        let (shape_mean, shape_std) = (mean.shape(), std.shape());
+        let mut dims = [0; D];
+        for (i, dim) in dims.iter_mut().enumerate() {
+            *dim = usize::max(shape_mean.dims[i], shape_std.dims[i]);
+        }
+        let noise = Tensor::random_device(dims, Distribution::Normal(0.0, 1.0), &mean.device());
+
+        mean.add(std.mul(noise))
+    }
+
+    /// Sample each element from a Bernoulli distribution, the tensor being the probabilities of
+    /// drawing a one.
+    ///
+    /// Returns a tensor of zeros and ones with the same shape as the tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let probabilities = Tensor::<B, 1>::from_floats([0.0, 0.5, 1.0]);
+    ///     let samples = probabilities.bernoulli();
+    ///     println!("{}", samples);
+    ///     // [0.0, 0.0 or 1.0, 1.0]
+    /// }
+    /// ```
+    pub fn bernoulli(self) -> Self {
+        self.random_like(Distribution::Default).lower(self).float()
+    }
+
+    /// Draw samples from the categorical distributions given by the last dimension of the tensor.
+    ///
+    /// The tensor contains the non-negative weights of the categories, which don't have to sum to
+    /// one. Returns the indices of the sampled categories, the last dimension being the samples.
+    /// Without replacement, a category is drawn at most once in each distribution, the categories
+    /// with a null weight being drawn only when there are not enough other categories.
+    ///
+    /// The samples are drawn with the Gumbel-max trick, the categories with the highest perturbed
+    /// log weights being selected.
+    ///
+    /// # Panics
+    ///
+    /// - If the number of samples is zero.
+    /// - If the samples are drawn without replacement and the number of samples is greater than the
+    ///   number of categories.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let weights = Tensor::<B, 2>::from_floats([[0.0, 1.0, 3.0], [1.0, 0.0, 0.0]]);
+    ///     let samples = weights.multinomial(4, true);
+    ///     println!("{}", samples);
+    ///     // [[2, 1, 2, 2], [0, 0, 0, 0]]
+    /// }
+    /// ```
+    pub fn multinomial(self, num_samples: usize, replacement: bool) -> Tensor<B, D, Int> {
+        check!(TensorCheck::multinomial(
+            &self.shape(),
+            num_samples,
+            replacement
+        ));
+
+        let mut dims = self.dims();
+        let categories = dims[D - 1];
+        dims[D - 1] = num_samples;
+        let log_weights = self.log();
+
+        if !replacement {
+            let keys = log_weights.clone().add(gumbel(log_weights));
+            let (_, indices) = keys.topk(num_samples, D - 1);
+
+            return indices;
+        }
+
+        let distributions = Shape::<D>::new(dims).num_elements() / num_samples;
+        let keys = log_weights
+            .reshape([distributions, 1, categories])
+            .repeat(1, num_samples);
+        let keys = keys.clone().add(gumbel(keys));
+
+        keys.argmax(2).reshape(dims)
+    }
+}
+
+/// Noise sampled from the standard Gumbel distribution, `-log(-log(U))` with `U` uniform.
+fn gumbel<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    tensor
+        .random_like(Distribution::Default)
+        .log()
+        .neg()
+        .log()
+        .neg()
+}
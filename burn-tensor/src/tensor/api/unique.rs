@@ -0,0 +1,115 @@
+use crate::{backend::Backend, Data, Int, Shape, Tensor};
+use alloc::vec;
+use alloc::vec::Vec;
+
+impl<B, const D: usize> Tensor<B, D, Int>
+where
+    B: Backend,
+{
+    /// The unique elements of the tensor, in ascending order.
+    ///
+    /// The tensor is read on the host, the number of unique elements being known only once the
+    /// elements are read.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2, Int>::from_ints([[3, 1], [3, 2]]);
+    ///     let values = tensor.unique();
+    ///     println!("{}", values);
+    ///     // [1, 2, 3]
+    /// }
+    /// ```
+    pub fn unique(self) -> Tensor<B, 1, Int> {
+        self.unique_with_inverse_and_counts().0
+    }
+
+    /// The unique elements of the tensor, in ascending order.
+    ///
+    /// Also returns the position of each element of the tensor in the unique elements, and the
+    /// number of occurrences of each unique element.
+    pub fn unique_with_inverse_and_counts(
+        self,
+    ) -> (Tensor<B, 1, Int>, Tensor<B, D, Int>, Tensor<B, 1, Int>) {
+        let device = self.device();
+        let shape = self.shape();
+        let values = self.into_data().convert::<i64>().value;
+
+        let mut unique = values.clone();
+        unique.sort_unstable();
+        unique.dedup();
+
+        let mut counts = vec![0; unique.len()];
+        let inverse = values
+            .iter()
+            .map(|value| {
+                let position = unique.binary_search(value).unwrap();
+                counts[position] += 1;
+                position as i64
+            })
+            .collect();
+
+        into_tensors(unique, inverse, counts, shape, &device)
+    }
+
+    /// The elements of the tensor, keeping only the first element of each group of consecutive
+    /// equal elements.
+    ///
+    /// The tensor is read on the host in row-major order, and its elements aren't sorted.
+    pub fn unique_consecutive(self) -> Tensor<B, 1, Int> {
+        self.unique_consecutive_with_inverse_and_counts().0
+    }
+
+    /// The elements of the tensor, keeping only the first element of each group of consecutive
+    /// equal elements.
+    ///
+    /// Also returns the position of each element of the tensor in the unique elements, and the
+    /// number of elements in each group.
+    pub fn unique_consecutive_with_inverse_and_counts(
+        self,
+    ) -> (Tensor<B, 1, Int>, Tensor<B, D, Int>, Tensor<B, 1, Int>) {
+        let device = self.device();
+        let shape = self.shape();
+        let values = self.into_data().convert::<i64>().value;
+
+        let mut unique: Vec<i64> = Vec::new();
+        let mut counts = Vec::new();
+        let mut inverse = Vec::with_capacity(values.len());
+        for value in values {
+            match unique.last() {
+                Some(last) if *last == value => *counts.last_mut().unwrap() += 1,
+                _ => {
+                    unique.push(value);
+                    counts.push(1);
+                }
+            }
+            inverse.push(unique.len() as i64 - 1);
+        }
+
+        into_tensors(unique, inverse, counts, shape, &device)
+    }
+}
+
+/// The unique elements, the inverse indices and the counts as tensors on the device.
+fn into_tensors<B: Backend, const D: usize>(
+    unique: Vec<i64>,
+    inverse: Vec<i64>,
+    counts: Vec<i64>,
+    shape: Shape<D>,
+    device: &B::Device,
+) -> (Tensor<B, 1, Int>, Tensor<B, D, Int>, Tensor<B, 1, Int>) {
+    let tensor = |values: Vec<i64>| {
+        let length = values.len();
+        Tensor::<B, 1, Int>::from_data_device(
+            Data::new(values, Shape::new([length])).convert(),
+            device,
+        )
+    };
+    let inverse = Tensor::from_data_device(Data::new(inverse, shape).convert(), device);
+
+    (tensor(unique), inverse, tensor(counts))
+}
@@ -93,6 +93,10 @@ pub trait Backend:
     fn name() -> String;
 
     /// Seed the backend.
+    ///
+    /// The backends sample with the global random number generator of Burn, or with the generator
+    /// of the device when it has been seeded with `seed_device`. Backends with their own
+    /// generator, such as libtorch, seed it from the generator of Burn.
     fn seed(seed: u64);
 }
 
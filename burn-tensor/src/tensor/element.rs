@@ -17,6 +17,8 @@ pub trait Element:
     + Copy
     + 'static
 {
+    /// Returns the data type of the element.
+    fn dtype() -> DType;
 }
 
 /// Element conversion trait for tensor.
@@ -53,6 +55,72 @@ pub trait ElementRandom {
         Self: Sized;
 }
 
+/// The data type of the elements of a tensor.
+#[derive(Clone, PartialEq, Eq, Copy, Debug)]
+pub enum DType {
+    /// 64-bit floating point.
+    F64,
+    /// 32-bit floating point.
+    F32,
+    /// 16-bit floating point.
+    F16,
+    /// 16-bit brain floating point.
+    BF16,
+    /// 64-bit signed integer.
+    I64,
+    /// 32-bit signed integer.
+    I32,
+    /// 16-bit signed integer.
+    I16,
+    /// 8-bit signed integer.
+    I8,
+    /// 8-bit unsigned integer.
+    U8,
+    /// Boolean.
+    Bool,
+    /// Complex number made of two 64-bit floating points.
+    ComplexF64,
+    /// Complex number made of two 32-bit floating points.
+    ComplexF32,
+    /// Complex number made of two 16-bit floating points, either regular or brain floating
+    /// points.
+    ComplexF16,
+}
+
+impl DType {
+    /// Returns the size of an element in bytes.
+    pub fn size(&self) -> usize {
+        match self {
+            DType::ComplexF64 => 16,
+            DType::F64 | DType::I64 | DType::ComplexF32 => 8,
+            DType::F32 | DType::I32 | DType::ComplexF16 => 4,
+            DType::F16 | DType::BF16 | DType::I16 => 2,
+            DType::I8 | DType::U8 | DType::Bool => 1,
+        }
+    }
+
+    /// Returns true if the data type is a floating point.
+    pub fn is_float(&self) -> bool {
+        matches!(self, DType::F64 | DType::F32 | DType::F16 | DType::BF16)
+    }
+
+    /// Returns true if the data type is an integer.
+    pub fn is_int(&self) -> bool {
+        matches!(
+            self,
+            DType::I64 | DType::I32 | DType::I16 | DType::I8 | DType::U8
+        )
+    }
+
+    /// Returns true if the data type is a complex number.
+    pub fn is_complex(&self) -> bool {
+        matches!(
+            self,
+            DType::ComplexF64 | DType::ComplexF32 | DType::ComplexF16
+        )
+    }
+}
+
 /// Element precision trait for tensor.
 #[derive(Clone, PartialEq, Eq, Copy, Debug)]
 pub enum Precision {
@@ -80,11 +148,16 @@ pub trait ElementPrecision {
 macro_rules! make_element {
     (
         ty $type:ident $precision:expr,
+        dtype $dtype:expr,
         convert $convert:expr,
         random $random:expr
 
     ) => {
-        impl Element for $type {}
+        impl Element for $type {
+            fn dtype() -> DType {
+                $dtype
+            }
+        }
 
         impl ElementConversion for $type {
             fn from_elem<E: ToPrimitive>(elem: E) -> Self {
@@ -111,48 +184,56 @@ macro_rules! make_element {
 
 make_element!(
     ty f64 Precision::Double,
+    dtype DType::F64,
     convert |elem: &dyn ToPrimitive| elem.to_f64().unwrap(),
     random |distribution: Distribution<f64>, rng: &mut R| distribution.sampler(rng).sample()
 );
 
 make_element!(
     ty f32 Precision::Full,
+    dtype DType::F32,
     convert |elem: &dyn ToPrimitive| elem.to_f32().unwrap(),
     random |distribution: Distribution<f32>, rng: &mut R| distribution.sampler(rng).sample()
 );
 
 make_element!(
     ty i64 Precision::Double,
+    dtype DType::I64,
     convert |elem: &dyn ToPrimitive| elem.to_i64().unwrap(),
     random |distribution: Distribution<i64>, rng: &mut R| distribution.sampler(rng).sample()
 );
 
 make_element!(
     ty i32 Precision::Full,
+    dtype DType::I32,
     convert |elem: &dyn ToPrimitive| elem.to_i32().unwrap(),
     random |distribution: Distribution<i32>, rng: &mut R| distribution.sampler(rng).sample()
 );
 
 make_element!(
     ty i16 Precision::Half,
+    dtype DType::I16,
     convert |elem: &dyn ToPrimitive| elem.to_i16().unwrap(),
     random |distribution: Distribution<i16>, rng: &mut R| distribution.sampler(rng).sample()
 );
 
 make_element!(
     ty i8 Precision::Other,
+    dtype DType::I8,
     convert |elem: &dyn ToPrimitive| elem.to_i8().unwrap(),
     random |distribution: Distribution<i8>, rng: &mut R| distribution.sampler(rng).sample()
 );
 
 make_element!(
     ty u8 Precision::Other,
+    dtype DType::U8,
     convert |elem: &dyn ToPrimitive| elem.to_u8().unwrap(),
     random |distribution: Distribution<u8>, rng: &mut R| distribution.sampler(rng).sample()
 );
 
 make_element!(
     ty f16 Precision::Half,
+    dtype DType::F16,
     convert |elem: &dyn ToPrimitive| f16::from_f32(elem.to_f32().unwrap()),
     random |distribution: Distribution<f16>, rng: &mut R| {
         let distribution: Distribution<f32> = distribution.convert();
@@ -162,6 +243,7 @@ make_element!(
 );
 make_element!(
     ty bf16 Precision::Half,
+    dtype DType::BF16,
     convert |elem: &dyn ToPrimitive| bf16::from_f32(elem.to_f32().unwrap()),
     random |distribution: Distribution<bf16>, rng: &mut R| {
         let distribution: Distribution<f32> = distribution.convert();
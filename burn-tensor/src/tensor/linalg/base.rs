@@ -0,0 +1,65 @@
+use crate::backend::Backend;
+use crate::check::TensorCheck;
+use crate::{check, Data, Shape, Tensor};
+use alloc::vec::Vec;
+
+/// Solves the linear systems `A X = B` for `X`, the matrices being the last two dimensions of the
+/// tensors.
+///
+/// # Arguments
+///
+/// * `lhs` - The square matrices `A`, of shape `[..., n, n]`.
+/// * `rhs` - The right-hand sides `B`, of shape `[..., n, k]`.
+///
+/// # Returns
+///
+/// The solutions `X`, with the same shape as `rhs`.
+///
+/// # Panics
+///
+/// - If the matrices are not square, or the shapes of the tensors don't match.
+/// - If a matrix is singular.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{linalg, Tensor};
+///
+/// fn example<B: Backend>() {
+///     let lhs = Tensor::<B, 2>::from_floats([[2.0, 1.0], [1.0, 3.0]]);
+///     let rhs = Tensor::<B, 2>::from_floats([[3.0], [5.0]]);
+///     let solution = linalg::solve(lhs, rhs);
+///     println!("{}", solution);
+///     // [[0.8], [1.4]]
+/// }
+/// ```
+pub fn solve<B: Backend, const D: usize>(lhs: Tensor<B, D>, rhs: Tensor<B, D>) -> Tensor<B, D> {
+    check!(TensorCheck::solve(&lhs.shape(), &rhs.shape()));
+
+    Tensor::new(B::solve(lhs.primitive, rhs.primitive))
+}
+
+/// Computes the inverse of the square matrices, the matrices being the last two dimensions of
+/// the tensor.
+///
+/// # Panics
+///
+/// - If the matrices are not square.
+/// - If a matrix is singular.
+pub fn inverse<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    let shape = tensor.shape();
+    check!(TensorCheck::solve(&shape, &shape));
+
+    let size = shape.dims[D - 1];
+    let mut identity = Vec::with_capacity(size * size);
+    for row in 0..size {
+        identity.extend((0..size).map(|col| if row == col { 1.0 } else { 0.0 }));
+    }
+    let identity = Tensor::<B, 2>::from_data_device(
+        Data::new(identity, Shape::new([size, size])).convert(),
+        &tensor.device(),
+    );
+
+    solve(tensor, identity.expand(shape))
+}
@@ -0,0 +1,124 @@
+use crate::backend::Backend;
+use crate::check::TensorCheck;
+use crate::{check, Tensor};
+
+/// Computes the Cholesky decomposition `A = L L^T` of symmetric positive-definite matrices, the
+/// matrices being the last two dimensions of the tensor.
+///
+/// # Returns
+///
+/// The lower triangular factors `L`, with the same shape as the tensor.
+///
+/// # Panics
+///
+/// - If the matrices are not square.
+/// - If a matrix is not positive-definite.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{linalg, Tensor};
+///
+/// fn example<B: Backend>() {
+///     let tensor = Tensor::<B, 2>::from_floats([[4.0, 2.0], [2.0, 5.0]]);
+///     let factor = linalg::cholesky(tensor);
+///     println!("{}", factor);
+///     // [[2.0, 0.0], [1.0, 2.0]]
+/// }
+/// ```
+pub fn cholesky<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    check!(TensorCheck::decomposition(
+        "Cholesky",
+        &tensor.shape(),
+        true
+    ));
+
+    Tensor::new(B::cholesky(tensor.primitive))
+}
+
+/// Computes the reduced QR decomposition `A = Q R`, the matrices being the last two dimensions of
+/// the tensor.
+///
+/// # Returns
+///
+/// A tuple with the matrices `Q` with orthonormal columns, of shape `[..., m, k]`, and the upper
+/// triangular matrices `R`, of shape `[..., k, n]`, with `k = min(m, n)`.
+///
+/// # Panics
+///
+/// If the tensor has less than two dimensions.
+pub fn qr<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::decomposition("QR", &tensor.shape(), false));
+
+    let (q, r) = B::qr(tensor.primitive);
+
+    (Tensor::new(q), Tensor::new(r))
+}
+
+/// Computes the reduced singular value decomposition `A = U diag(S) V^T`, the matrices being the
+/// last two dimensions of the tensor.
+///
+/// The singular vectors are only unique up to their sign, the gradients being undefined when
+/// singular values are repeated.
+///
+/// # Returns
+///
+/// A tuple with the matrices `U`, of shape `[..., m, k]`, the singular values `S` in descending
+/// order, of shape `[..., k]`, and the matrices `V^T`, of shape `[..., k, n]`, with
+/// `k = min(m, n)`.
+///
+/// # Panics
+///
+/// If the tensor has less than two dimensions, or the singular values don't have one dimension
+/// less than the tensor.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{linalg, Tensor};
+///
+/// fn example<B: Backend>() {
+///     let tensor = Tensor::<B, 3>::ones([8, 4, 3]);
+///     let (u, s, vt) = linalg::svd::<B, 3, 2>(tensor);
+///     println!("{:?} {:?} {:?}", u.shape(), s.shape(), vt.shape());
+///     // Shape { dims: [8, 4, 3] } Shape { dims: [8, 3] } Shape { dims: [8, 3, 3] }
+/// }
+/// ```
+pub fn svd<B: Backend, const D: usize, const D2: usize>(
+    tensor: Tensor<B, D>,
+) -> (Tensor<B, D>, Tensor<B, D2>, Tensor<B, D>) {
+    check!(TensorCheck::decomposition("SVD", &tensor.shape(), false));
+    check!(TensorCheck::decomposition_values::<D, D2>("SVD"));
+
+    let (u, s, vt) = B::svd(tensor.primitive);
+
+    (Tensor::new(u), Tensor::new(s), Tensor::new(vt))
+}
+
+/// Computes the eigendecomposition `A = V diag(W) V^T` of symmetric matrices, the matrices being
+/// the last two dimensions of the tensor.
+///
+/// Only the lower triangular part of the matrices is used. The eigenvectors are only unique up to
+/// their sign.
+///
+/// # Returns
+///
+/// A tuple with the eigenvalues `W` in ascending order, of shape `[..., n]`, and the matrices `V`
+/// with the eigenvectors as columns, of shape `[..., n, n]`.
+///
+/// # Panics
+///
+/// If the matrices are not square, or the eigenvalues don't have one dimension less than the
+/// tensor.
+pub fn eigh<B: Backend, const D: usize, const D2: usize>(
+    tensor: Tensor<B, D>,
+) -> (Tensor<B, D2>, Tensor<B, D>) {
+    check!(TensorCheck::decomposition("Eigh", &tensor.shape(), true));
+    check!(TensorCheck::decomposition_values::<D, D2>("Eigh"));
+
+    let (values, vectors) = B::eigh(tensor.primitive);
+
+    (Tensor::new(values), Tensor::new(vectors))
+}
@@ -0,0 +1,5 @@
+mod base;
+mod decomposition;
+
+pub use base::*;
+pub use decomposition::*;
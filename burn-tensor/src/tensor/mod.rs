@@ -19,6 +19,9 @@ pub mod backend;
 /// The container module.
 pub mod container;
 
+/// The linear algebra module.
+pub mod linalg;
+
 /// The loss module.
 pub mod loss;
 
@@ -28,6 +31,9 @@ pub mod module;
 /// Operations on tensors module.
 pub mod ops;
 
+/// The sparse tensor module.
+pub mod sparse;
+
 #[cfg(feature = "experimental-named-tensor")]
 mod named;
 #[cfg(feature = "experimental-named-tensor")]
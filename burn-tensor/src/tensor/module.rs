@@ -1,6 +1,8 @@
 use crate::{
     backend::Backend,
-    ops::{ConvOptions, ConvTransposeOptions},
+    check,
+    check::TensorCheck,
+    ops::{ConvOptions, ConvTransposeOptions, GridSampleOptions},
     Int, Tensor,
 };
 
@@ -48,6 +50,24 @@ where
     ))
 }
 
+/// Applies a [3D convolution](crate::ops::ModuleOps::conv3d).
+pub fn conv3d<B>(
+    x: Tensor<B, 5>,
+    weight: Tensor<B, 5>,
+    bias: Option<Tensor<B, 1>>,
+    options: ConvOptions<3>,
+) -> Tensor<B, 5>
+where
+    B: Backend,
+{
+    Tensor::new(B::conv3d(
+        x.primitive,
+        weight.primitive,
+        bias.map(|b| b.primitive),
+        options,
+    ))
+}
+
 /// Applies a [1D transposed convolution](crate::ops::ModuleOps::conv_transpose1d).
 pub fn conv_transpose1d<B>(
     x: Tensor<B, 3>,
@@ -181,3 +201,22 @@ where
 {
     Tensor::new(B::adaptive_avg_pool1d(x.primitive, output_size))
 }
+
+/// Applies a [2D grid sampling](crate::ops::ModuleOps::grid_sample_2d).
+///
+/// # Panics
+///
+/// If the last dimension of the grid is not 2, or the input and the grid have different batch
+/// sizes.
+pub fn grid_sample_2d<B>(
+    x: Tensor<B, 4>,
+    grid: Tensor<B, 4>,
+    options: GridSampleOptions,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    check!(TensorCheck::grid_sample_2d(&x.shape(), &grid.shape()));
+
+    Tensor::new(B::grid_sample_2d(x.primitive, grid.primitive, options))
+}
@@ -0,0 +1,121 @@
+use crate::{backend::Backend, Data, ElementConversion, Shape};
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+use libm::{cos, sin};
+
+/// The real and imaginary parts of the discrete Fourier transform of a real tensor along the
+/// given dimension, keeping the `n / 2 + 1` non-redundant frequencies.
+///
+/// The transform is computed with a matrix multiplication by the DFT matrix, so it runs on any
+/// backend and is differentiable.
+pub(crate) fn dft_real<B: Backend, const D: usize>(
+    tensor: B::TensorPrimitive<D>,
+    dim: usize,
+) -> (B::TensorPrimitive<D>, B::TensorPrimitive<D>) {
+    let size = B::shape(&tensor).dims[dim];
+    let device = B::device(&tensor);
+    let frequencies = size / 2 + 1;
+    let angle = |j: usize, k: usize| 2.0 * PI * (j * k) as f64 / size as f64;
+
+    let cos = matrix::<B>(size, frequencies, &device, |j, k| cos(angle(j, k)));
+    let sin = matrix::<B>(size, frequencies, &device, |j, k| -sin(angle(j, k)));
+
+    (
+        matmul_dim::<B, D>(tensor.clone(), dim, cos),
+        matmul_dim::<B, D>(tensor, dim, sin),
+    )
+}
+
+/// The inverse of [dft_real](dft_real), the real tensor of the given size along the dimension
+/// from the real and imaginary parts of its `size / 2 + 1` non-redundant frequencies.
+pub(crate) fn idft_real<B: Backend, const D: usize>(
+    real: B::TensorPrimitive<D>,
+    imag: B::TensorPrimitive<D>,
+    dim: usize,
+    size: usize,
+) -> B::TensorPrimitive<D> {
+    let frequencies = B::shape(&real).dims[dim];
+    let device = B::device(&real);
+    let angle = |k: usize, t: usize| 2.0 * PI * (k * t) as f64 / size as f64;
+    // The frequencies other than 0 and n / 2 stand for their conjugate frequency as well
+    let weight = |k: usize| match k == 0 || 2 * k == size {
+        true => 1.0 / size as f64,
+        false => 2.0 / size as f64,
+    };
+
+    let cos = matrix::<B>(frequencies, size, &device, |k, t| {
+        weight(k) * cos(angle(k, t))
+    });
+    let sin = matrix::<B>(frequencies, size, &device, |k, t| {
+        -weight(k) * sin(angle(k, t))
+    });
+
+    B::add(
+        matmul_dim::<B, D>(real, dim, cos),
+        matmul_dim::<B, D>(imag, dim, sin),
+    )
+}
+
+/// The real and imaginary parts of the discrete Fourier transform of a complex tensor along the
+/// given dimension, or of its inverse transform.
+pub(crate) fn dft<B: Backend, const D: usize>(
+    real: B::TensorPrimitive<D>,
+    imag: B::TensorPrimitive<D>,
+    dim: usize,
+    inverse: bool,
+) -> (B::TensorPrimitive<D>, B::TensorPrimitive<D>) {
+    let size = B::shape(&real).dims[dim];
+    let device = B::device(&real);
+    let angle = |j: usize, k: usize| 2.0 * PI * (j * k) as f64 / size as f64;
+    let (sign, scale) = match inverse {
+        true => (1.0, 1.0 / size as f64),
+        false => (-1.0, 1.0),
+    };
+
+    let cos = matrix::<B>(size, size, &device, |j, k| scale * cos(angle(j, k)));
+    let sin = matrix::<B>(size, size, &device, |j, k| sign * scale * sin(angle(j, k)));
+
+    let real_out = B::sub(
+        matmul_dim::<B, D>(real.clone(), dim, cos.clone()),
+        matmul_dim::<B, D>(imag.clone(), dim, sin.clone()),
+    );
+    let imag_out = B::add(
+        matmul_dim::<B, D>(real, dim, sin),
+        matmul_dim::<B, D>(imag, dim, cos),
+    );
+
+    (real_out, imag_out)
+}
+
+fn matrix<B: Backend>(
+    rows: usize,
+    cols: usize,
+    device: &B::Device,
+    value: impl Fn(usize, usize) -> f64,
+) -> B::TensorPrimitive<2> {
+    let values = (0..rows)
+        .flat_map(|row| (0..cols).map(move |col| (row, col)))
+        .map(|(row, col)| value(row, col).elem())
+        .collect::<Vec<_>>();
+
+    B::from_data(Data::new(values, Shape::new([rows, cols])), device)
+}
+
+/// Multiply the given dimension of the tensor by the matrix, the size of the dimension becoming
+/// the number of columns of the matrix.
+fn matmul_dim<B: Backend, const D: usize>(
+    tensor: B::TensorPrimitive<D>,
+    dim: usize,
+    matrix: B::TensorPrimitive<2>,
+) -> B::TensorPrimitive<D> {
+    let tensor = B::swap_dims(tensor, dim, D - 1);
+    let mut dims = B::shape(&tensor).dims;
+    let [size, cols] = B::shape(&matrix).dims;
+    let rows = dims.iter().product::<usize>() / usize::max(size, 1);
+
+    let tensor = B::reshape(tensor, Shape::new([rows, size]));
+    dims[D - 1] = cols;
+    let tensor = B::reshape(B::matmul(tensor, matrix), Shape::new(dims));
+
+    B::swap_dims(tensor, dim, D - 1)
+}
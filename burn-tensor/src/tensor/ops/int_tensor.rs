@@ -1,6 +1,7 @@
 use alloc::vec::Vec;
 use core::ops::Range;
 
+use super::topk_indices;
 use crate::{backend::Backend, tensor::Shape, Data, ElementConversion};
 
 /// Int Tensor API for basic and numeric operations, see [tensor](crate::Tensor)
@@ -209,6 +210,44 @@ pub trait IntTensorOps<B: Backend> {
         value: B::IntTensorPrimitive<D>,
     ) -> B::IntTensorPrimitive<D>;
 
+    /// Scatter a given value to the tensor into the given indices, replacing the elements at the
+    /// given indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to scatter to.
+    /// * `tensor` - The tensor.
+    /// * `indices` - The indices.
+    /// * `value` - The value.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the values scattered.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation fills the scattered positions with zeros before adding the values
+    /// with [int_scatter](IntTensorOps::int_scatter), so the values scattered to the same position
+    /// are summed.
+    fn int_scatter_assign<const D: usize>(
+        dim: usize,
+        tensor: B::IntTensorPrimitive<D>,
+        indices: B::IntTensorPrimitive<D>,
+        value: B::IntTensorPrimitive<D>,
+    ) -> B::IntTensorPrimitive<D> {
+        let device = B::int_device(&tensor);
+        let positions = B::int_scatter(
+            dim,
+            B::int_zeros(B::int_shape(&tensor), &device),
+            indices.clone(),
+            B::int_ones(B::int_shape(&indices), &device),
+        );
+        let mask = B::int_greater_elem(positions, 0.elem());
+        let tensor = B::int_mask_fill(tensor, mask, 0.elem());
+
+        B::int_scatter(dim, tensor, indices, value)
+    }
+
     /// Select tensor elements along the given dimension corresponding to the given indices.
     ///
     /// # Arguments
@@ -823,6 +862,34 @@ pub trait IntTensorOps<B: Backend> {
         (values, index)
     }
 
+    /// Gets the `k` largest or smallest elements along a dimension and their indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to get the elements of.
+    /// * `dim` - The dimension to get the elements along.
+    /// * `k` - The number of elements to get.
+    /// * `largest` - Whether to get the largest or the smallest elements.
+    /// * `sorted` - Whether the elements are sorted by value or kept in the order of the tensor.
+    ///
+    /// # Returns
+    ///
+    /// The `k` elements along the dimension and their indices.
+    fn int_topk<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+        dim: usize,
+        k: usize,
+        largest: bool,
+        sorted: bool,
+    ) -> (B::IntTensorPrimitive<D>, B::IntTensorPrimitive<D>) {
+        let data = B::int_to_data(&tensor);
+        let indices = topk_indices(&data, dim, k, largest, sorted);
+        let indices = B::int_from_data(indices, &B::int_device(&tensor));
+        let values = B::int_gather(dim, tensor, indices.clone());
+
+        (values, indices)
+    }
+
     /// Gets the minimum element in the tensor.
     ///
     /// # Arguments
@@ -888,4 +955,94 @@ pub trait IntTensorOps<B: Backend> {
     ///
     /// A tensor with the same shape as `tensor` with absolute values.
     fn int_abs<const D: usize>(tensor: B::IntTensorPrimitive<D>) -> B::IntTensorPrimitive<D>;
+
+    /// Element-wise bitwise and of two tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The result of the bitwise and.
+    fn int_bitwise_and<const D: usize>(
+        lhs: B::IntTensorPrimitive<D>,
+        rhs: B::IntTensorPrimitive<D>,
+    ) -> B::IntTensorPrimitive<D>;
+
+    /// Element-wise bitwise or of two tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The result of the bitwise or.
+    fn int_bitwise_or<const D: usize>(
+        lhs: B::IntTensorPrimitive<D>,
+        rhs: B::IntTensorPrimitive<D>,
+    ) -> B::IntTensorPrimitive<D>;
+
+    /// Element-wise bitwise xor of two tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The result of the bitwise xor.
+    fn int_bitwise_xor<const D: usize>(
+        lhs: B::IntTensorPrimitive<D>,
+        rhs: B::IntTensorPrimitive<D>,
+    ) -> B::IntTensorPrimitive<D>;
+
+    /// Element-wise bitwise not of a tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with all its bits flipped.
+    fn int_bitwise_not<const D: usize>(
+        tensor: B::IntTensorPrimitive<D>,
+    ) -> B::IntTensorPrimitive<D>;
+
+    /// Element-wise left shift of the bits of a tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The tensor to shift.
+    /// * `rhs` - The number of bits to shift by.
+    ///
+    /// # Returns
+    ///
+    /// The shifted tensor, the result being backend dependent when the number of bits is negative
+    /// or not lower than the bit width of the elements.
+    fn int_bitwise_left_shift<const D: usize>(
+        lhs: B::IntTensorPrimitive<D>,
+        rhs: B::IntTensorPrimitive<D>,
+    ) -> B::IntTensorPrimitive<D>;
+
+    /// Element-wise arithmetic right shift of the bits of a tensor, preserving the sign.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The tensor to shift.
+    /// * `rhs` - The number of bits to shift by.
+    ///
+    /// # Returns
+    ///
+    /// The shifted tensor, the result being backend dependent when the number of bits is negative
+    /// or not lower than the bit width of the elements.
+    fn int_bitwise_right_shift<const D: usize>(
+        lhs: B::IntTensorPrimitive<D>,
+        rhs: B::IntTensorPrimitive<D>,
+    ) -> B::IntTensorPrimitive<D>;
 }
@@ -0,0 +1,492 @@
+use crate::{Data, Element, ElementConversion, Shape};
+use alloc::vec;
+use alloc::vec::Vec;
+use libm::sqrt;
+
+/// The threshold under which the off-diagonal elements are considered null by the Jacobi
+/// algorithms.
+const EPSILON: f64 = 1e-12;
+
+/// The maximum number of sweeps of the Jacobi algorithms.
+const MAX_SWEEPS: usize = 64;
+
+/// Panics in the decompositions the backend doesn't implement, when the host fallback isn't
+/// enabled.
+#[cfg(not(feature = "linalg-host-fallback"))]
+pub(crate) fn host_fallback_disabled<B: crate::backend::Backend>(op: &str) -> ! {
+    panic!(
+        "The {} backend doesn't implement `{op}`, enable the `linalg-host-fallback` feature of \
+         burn-tensor to compute it on the host.",
+        B::name()
+    )
+}
+
+/// Solve the batched linear systems `A X = B` with the Gaussian elimination with partial pivoting,
+/// `A` being square matrices of shape `[..., n, n]` and `B` the right-hand sides of shape
+/// `[..., n, k]`.
+///
+/// # Panics
+///
+/// If a matrix is singular.
+pub(crate) fn solve_data<E: Element, const D: usize>(
+    lhs: &Data<E, D>,
+    rhs: &Data<E, D>,
+) -> Data<E, D> {
+    let n = rhs.shape.dims[D - 2];
+    let k = rhs.shape.dims[D - 1];
+    let mut solution = Vec::with_capacity(rhs.value.len());
+
+    for (matrix, values) in lhs.value.chunks(n * n).zip(rhs.value.chunks(n * k)) {
+        let mut matrix = matrix.iter().map(|v| v.elem::<f64>()).collect::<Vec<_>>();
+        let mut values = values.iter().map(|v| v.elem::<f64>()).collect::<Vec<_>>();
+
+        for col in 0..n {
+            let pivot = (col..n)
+                .max_by(|a, b| {
+                    let a = matrix[a * n + col].abs();
+                    let b = matrix[b * n + col].abs();
+                    a.total_cmp(&b)
+                })
+                .unwrap();
+            if matrix[pivot * n + col] == 0.0 {
+                panic!("Can't solve the linear system, the matrix is singular.");
+            }
+
+            for c in 0..n {
+                matrix.swap(col * n + c, pivot * n + c);
+            }
+            for c in 0..k {
+                values.swap(col * k + c, pivot * k + c);
+            }
+
+            for row in col + 1..n {
+                let factor = matrix[row * n + col] / matrix[col * n + col];
+                for c in col..n {
+                    matrix[row * n + c] -= factor * matrix[col * n + c];
+                }
+                for c in 0..k {
+                    values[row * k + c] -= factor * values[col * k + c];
+                }
+            }
+        }
+
+        for row in (0..n).rev() {
+            for c in 0..k {
+                let sum = (row + 1..n)
+                    .map(|i| matrix[row * n + i] * values[i * k + c])
+                    .sum::<f64>();
+                values[row * k + c] = (values[row * k + c] - sum) / matrix[row * n + row];
+            }
+        }
+
+        solution.extend(values.into_iter().map(|v| v.elem::<E>()));
+    }
+
+    Data::new(solution, rhs.shape.clone())
+}
+
+/// Compute the Cholesky decomposition `A = L L^T` of the batched symmetric positive-definite
+/// matrices of shape `[..., n, n]`, returning the lower triangular factors `L`.
+///
+/// # Panics
+///
+/// If a matrix is not positive-definite.
+pub fn cholesky_data<E: Element, const D: usize>(data: &Data<E, D>) -> Data<E, D> {
+    let n = data.shape.dims[D - 1];
+    let mut factors = Vec::with_capacity(data.value.len());
+
+    for matrix in matrices(data, n * n) {
+        let mut factor = vec![0.0; n * n];
+
+        for row in 0..n {
+            for col in 0..=row {
+                let sum = (0..col)
+                    .map(|i| factor[row * n + i] * factor[col * n + i])
+                    .sum::<f64>();
+                let value = matrix[row * n + col] - sum;
+
+                if row == col {
+                    if value <= 0.0 {
+                        panic!(
+                            "Can't compute the Cholesky decomposition, the matrix is not \
+                             positive-definite."
+                        );
+                    }
+                    factor[row * n + col] = sqrt(value);
+                } else {
+                    factor[row * n + col] = value / factor[col * n + col];
+                }
+            }
+        }
+
+        factors.extend(factor.into_iter().map(|v| v.elem::<E>()));
+    }
+
+    Data::new(factors, data.shape.clone())
+}
+
+/// Compute the reduced QR decomposition `A = Q R` of the batched matrices of shape `[..., m, n]`
+/// with Householder reflections.
+///
+/// Returns `Q` of shape `[..., m, k]` with orthonormal columns and the upper triangular `R` of
+/// shape `[..., k, n]`, with `k = min(m, n)` and the diagonal of `R` non-negative.
+pub fn qr_data<E: Element, const D: usize>(data: &Data<E, D>) -> (Data<E, D>, Data<E, D>) {
+    let (m, n) = (data.shape.dims[D - 2], data.shape.dims[D - 1]);
+    let k = usize::min(m, n);
+    let mut q_values = Vec::new();
+    let mut r_values = Vec::new();
+
+    for mut r in matrices(data, m * n) {
+        let mut q = identity(m);
+
+        for j in 0..k {
+            let norm = sqrt((j..m).map(|i| r[i * n + j] * r[i * n + j]).sum::<f64>());
+            if norm == 0.0 {
+                continue;
+            }
+
+            let alpha = if r[j * n + j] > 0.0 { -norm } else { norm };
+            let mut v = (j..m).map(|i| r[i * n + j]).collect::<Vec<_>>();
+            v[0] -= alpha;
+            let v_norm = sqrt(v.iter().map(|v| v * v).sum::<f64>());
+            if v_norm == 0.0 {
+                continue;
+            }
+            v.iter_mut().for_each(|v| *v /= v_norm);
+
+            // R = H R and Q = Q H, with the reflection H = I - 2 v v^T.
+            for col in j..n {
+                let dot = (j..m).map(|i| v[i - j] * r[i * n + col]).sum::<f64>();
+                (j..m).for_each(|i| r[i * n + col] -= 2.0 * v[i - j] * dot);
+            }
+            for row in 0..m {
+                let dot = (j..m).map(|i| q[row * m + i] * v[i - j]).sum::<f64>();
+                (j..m).for_each(|i| q[row * m + i] -= 2.0 * dot * v[i - j]);
+            }
+        }
+
+        for i in 0..k {
+            if r[i * n + i] < 0.0 {
+                (0..n).for_each(|col| r[i * n + col] = -r[i * n + col]);
+                (0..m).for_each(|row| q[row * m + i] = -q[row * m + i]);
+            }
+        }
+
+        for row in 0..m {
+            q_values.extend(q[row * m..row * m + k].iter().map(|v| v.elem::<E>()));
+        }
+        for row in 0..k {
+            for col in 0..n {
+                let value = if col < row { 0.0 } else { r[row * n + col] };
+                r_values.push(value.elem::<E>());
+            }
+        }
+    }
+
+    (
+        Data::new(q_values, matrix_shape(&data.shape, m, k)),
+        Data::new(r_values, matrix_shape(&data.shape, k, n)),
+    )
+}
+
+/// Compute the eigendecomposition `A = V diag(w) V^T` of the batched symmetric matrices of shape
+/// `[..., n, n]` with the cyclic Jacobi algorithm, only the lower triangular part being used.
+///
+/// Returns the eigenvalues `w` in ascending order, of shape `[..., n]`, and the eigenvectors `V`
+/// as the columns of matrices of shape `[..., n, n]`.
+pub fn eigh_data<E: Element, const D: usize, const D2: usize>(
+    data: &Data<E, D>,
+) -> (Data<E, D2>, Data<E, D>) {
+    let n = data.shape.dims[D - 1];
+    let mut values = Vec::new();
+    let mut vectors = Vec::new();
+
+    for mut a in matrices(data, n * n) {
+        for row in 0..n {
+            for col in row + 1..n {
+                a[row * n + col] = a[col * n + row];
+            }
+        }
+        let mut v = identity(n);
+
+        for _ in 0..MAX_SWEEPS {
+            let off = (0..n)
+                .flat_map(|row| {
+                    (0..n)
+                        .filter(move |col| *col != row)
+                        .map(move |col| (row, col))
+                })
+                .map(|(row, col)| a[row * n + col] * a[row * n + col])
+                .sum::<f64>();
+            if off <= EPSILON * EPSILON {
+                break;
+            }
+
+            for p in 0..n {
+                for q in p + 1..n {
+                    if a[p * n + q] == 0.0 {
+                        continue;
+                    }
+
+                    let theta = (a[q * n + q] - a[p * n + p]) / (2.0 * a[p * n + q]);
+                    let (c, s) = rotation(theta);
+
+                    // A = J^T A J and V = V J, with the rotation J of the plane (p, q).
+                    for i in 0..n {
+                        let (ip, iq) = (a[i * n + p], a[i * n + q]);
+                        a[i * n + p] = c * ip - s * iq;
+                        a[i * n + q] = s * ip + c * iq;
+                    }
+                    for i in 0..n {
+                        let (pi, qi) = (a[p * n + i], a[q * n + i]);
+                        a[p * n + i] = c * pi - s * qi;
+                        a[q * n + i] = s * pi + c * qi;
+                    }
+                    for i in 0..n {
+                        let (ip, iq) = (v[i * n + p], v[i * n + q]);
+                        v[i * n + p] = c * ip - s * iq;
+                        v[i * n + q] = s * ip + c * iq;
+                    }
+                }
+            }
+        }
+
+        let mut order = (0..n).collect::<Vec<_>>();
+        order.sort_by(|i, j| a[i * n + i].total_cmp(&a[j * n + j]));
+
+        values.extend(order.iter().map(|i| a[i * n + i].elem::<E>()));
+        for row in 0..n {
+            vectors.extend(order.iter().map(|col| v[row * n + col].elem::<E>()));
+        }
+    }
+
+    let mut shape = [0; D2];
+    shape.copy_from_slice(&data.shape.dims[..D2]);
+
+    (
+        Data::new(values, Shape::new(shape)),
+        Data::new(vectors, data.shape.clone()),
+    )
+}
+
+/// Compute the reduced singular value decomposition `A = U diag(s) V^T` of the batched matrices
+/// of shape `[..., m, n]` with the one-sided Jacobi algorithm.
+///
+/// Returns `U` of shape `[..., m, k]`, the singular values `s` in descending order, of shape
+/// `[..., k]`, and `V^T` of shape `[..., k, n]`, with `k = min(m, n)`.
+#[allow(clippy::type_complexity)]
+pub fn svd_data<E: Element, const D: usize, const D2: usize>(
+    data: &Data<E, D>,
+) -> (Data<E, D>, Data<E, D2>, Data<E, D>) {
+    let (m, n) = (data.shape.dims[D - 2], data.shape.dims[D - 1]);
+    let k = usize::min(m, n);
+    let mut u_values = Vec::new();
+    let mut s_values = Vec::new();
+    let mut vt_values = Vec::new();
+
+    for matrix in matrices(data, m * n) {
+        // The matrices with more columns than rows are decomposed as their transpose.
+        let (rows, cols, a) = match m >= n {
+            true => (m, n, matrix),
+            false => (n, m, transpose(&matrix, m, n)),
+        };
+        let (u, s, v) = svd_tall(a, rows, cols);
+
+        s_values.extend(s.iter().map(|v| v.elem::<E>()));
+        let (u, vt) = match m >= n {
+            true => (u, transpose(&v, k, k)),
+            false => (v, transpose(&u, n, k)),
+        };
+        u_values.extend(u.into_iter().map(|v| v.elem::<E>()));
+        vt_values.extend(vt.into_iter().map(|v| v.elem::<E>()));
+    }
+
+    let mut shape = [0; D2];
+    shape.copy_from_slice(&data.shape.dims[..D2]);
+    shape[D2 - 1] = k;
+
+    (
+        Data::new(u_values, matrix_shape(&data.shape, m, k)),
+        Data::new(s_values, Shape::new(shape)),
+        Data::new(vt_values, matrix_shape(&data.shape, k, n)),
+    )
+}
+
+/// The singular value decomposition of a matrix of shape `[m, n]` with `m >= n`, returning `U` of
+/// shape `[m, n]`, the singular values and `V` of shape `[n, n]`.
+fn svd_tall(mut u: Vec<f64>, m: usize, n: usize) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+    let mut v = identity(n);
+    let column_dot =
+        |u: &[f64], p: usize, q: usize| (0..m).map(|i| u[i * n + p] * u[i * n + q]).sum::<f64>();
+
+    for _ in 0..MAX_SWEEPS {
+        let mut converged = true;
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let alpha = column_dot(&u, p, p);
+                let beta = column_dot(&u, q, q);
+                let gamma = column_dot(&u, p, q);
+                if gamma == 0.0 || gamma.abs() <= EPSILON * sqrt(alpha * beta) {
+                    continue;
+                }
+                converged = false;
+
+                let (c, s) = rotation((beta - alpha) / (2.0 * gamma));
+                for i in 0..m {
+                    let (ip, iq) = (u[i * n + p], u[i * n + q]);
+                    u[i * n + p] = c * ip - s * iq;
+                    u[i * n + q] = s * ip + c * iq;
+                }
+                for i in 0..n {
+                    let (ip, iq) = (v[i * n + p], v[i * n + q]);
+                    v[i * n + p] = c * ip - s * iq;
+                    v[i * n + q] = s * ip + c * iq;
+                }
+            }
+        }
+
+        if converged {
+            break;
+        }
+    }
+
+    let norms = (0..n)
+        .map(|col| sqrt(column_dot(&u, col, col)))
+        .collect::<Vec<_>>();
+    let mut order = (0..n).collect::<Vec<_>>();
+    order.sort_by(|i, j| norms[*j].total_cmp(&norms[*i]));
+
+    let mut u_sorted = vec![0.0; m * n];
+    let mut v_sorted = vec![0.0; n * n];
+    for (index, col) in order.iter().enumerate() {
+        for row in 0..m {
+            if norms[*col] > 0.0 {
+                u_sorted[row * n + index] = u[row * n + col] / norms[*col];
+            }
+        }
+        for row in 0..n {
+            v_sorted[row * n + index] = v[row * n + col];
+        }
+    }
+
+    // The columns of the null singular values are completed to an orthonormal basis with the
+    // Gram-Schmidt process.
+    for index in 0..n {
+        if norms[order[index]] > 0.0 {
+            continue;
+        }
+
+        for basis in 0..m {
+            let mut column = (0..m)
+                .map(|row| if row == basis { 1.0 } else { 0.0 })
+                .collect::<Vec<_>>();
+            for other in 0..index {
+                let dot = (0..m)
+                    .map(|row| u_sorted[row * n + other] * column[row])
+                    .sum::<f64>();
+                (0..m).for_each(|row| column[row] -= dot * u_sorted[row * n + other]);
+            }
+
+            let norm = sqrt(column.iter().map(|v| v * v).sum::<f64>());
+            if norm > 1e-6 {
+                (0..m).for_each(|row| u_sorted[row * n + index] = column[row] / norm);
+                break;
+            }
+        }
+    }
+
+    let singular_values = order.iter().map(|col| norms[*col]).collect();
+
+    (u_sorted, singular_values, v_sorted)
+}
+
+/// The cosine and the sine of the Jacobi rotation annihilating an off-diagonal element, given
+/// the ratio `theta = (a_qq - a_pp) / (2 a_pq)`.
+fn rotation(theta: f64) -> (f64, f64) {
+    let sign = if theta >= 0.0 { 1.0 } else { -1.0 };
+    let t = sign / (theta.abs() + sqrt(theta * theta + 1.0));
+    let c = 1.0 / sqrt(t * t + 1.0);
+
+    (c, t * c)
+}
+
+/// The matrices of the batch, converted to `f64`.
+fn matrices<E: Element, const D: usize>(
+    data: &Data<E, D>,
+    size: usize,
+) -> impl Iterator<Item = Vec<f64>> + '_ {
+    data.value
+        .chunks(usize::max(size, 1))
+        .map(|matrix| matrix.iter().map(|v| v.elem::<f64>()).collect())
+}
+
+fn identity(size: usize) -> Vec<f64> {
+    let mut matrix = vec![0.0; size * size];
+    (0..size).for_each(|i| matrix[i * size + i] = 1.0);
+
+    matrix
+}
+
+fn transpose(matrix: &[f64], rows: usize, cols: usize) -> Vec<f64> {
+    (0..cols)
+        .flat_map(|col| (0..rows).map(move |row| matrix[row * cols + col]))
+        .collect()
+}
+
+/// The shape of the batch of matrices of shape `[rows, cols]`.
+fn matrix_shape<const D: usize>(shape: &Shape<D>, rows: usize, cols: usize) -> Shape<D> {
+    let mut dims = shape.dims;
+    dims[D - 2] = rows;
+    dims[D - 1] = cols;
+
+    Shape::new(dims)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_solve_with_pivoting() {
+        let lhs = Data::from([[0.0, 2.0], [1.0, 1.0]]);
+        let rhs = Data::from([[4.0], [3.0]]);
+
+        let solution = solve_data::<f64, 2>(&lhs, &rhs);
+
+        solution.assert_approx_eq(&Data::from([[1.0], [2.0]]), 6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_the_matrix_is_singular() {
+        let lhs = Data::from([[1.0, 2.0], [2.0, 4.0]]);
+        let rhs = Data::from([[1.0], [1.0]]);
+
+        solve_data::<f64, 2>(&lhs, &rhs);
+    }
+
+    #[test]
+    fn should_complete_the_singular_vectors_of_null_singular_values() {
+        let data = Data::<f64, 2>::from([[1.0, 1.0], [1.0, 1.0], [0.0, 0.0]]);
+
+        let (u, s, _) = svd_data::<f64, 2, 1>(&data);
+
+        s.assert_approx_eq(&Data::from([2.0, 0.0]), 6);
+        let [a, b] = [0, 1].map(|col| [u.value[col], u.value[2 + col], u.value[4 + col]]);
+        let dot = |x: [f64; 3], y: [f64; 3]| x.iter().zip(y).map(|(x, y)| x * y).sum::<f64>();
+        assert!((dot(a, a) - 1.0).abs() < 1e-6);
+        assert!((dot(b, b) - 1.0).abs() < 1e-6);
+        assert!(dot(a, b).abs() < 1e-6);
+    }
+
+    #[test]
+    fn should_compute_qr_with_non_negative_diagonal() {
+        let data = Data::<f64, 2>::from([[-2.0, 1.0], [0.0, -3.0]]);
+
+        let (q, r) = qr_data(&data);
+
+        q.assert_approx_eq(&Data::from([[-1.0, 0.0], [0.0, -1.0]]), 6);
+        r.assert_approx_eq(&Data::from([[2.0, -1.0], [0.0, 3.0]]), 6);
+    }
+}
@@ -1,11 +1,19 @@
 mod activation;
 mod bool_tensor;
+mod fft;
 mod int_tensor;
+mod linalg;
 mod modules;
 mod tensor;
+mod topk;
 
 pub use activation::*;
 pub use bool_tensor::*;
 pub use int_tensor::*;
+pub use linalg::{cholesky_data, eigh_data, qr_data, svd_data};
 pub use modules::*;
 pub use tensor::*;
+
+pub(crate) use fft::*;
+pub(crate) use linalg::*;
+pub(crate) use topk::*;
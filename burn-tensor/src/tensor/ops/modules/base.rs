@@ -1,4 +1,4 @@
-use super::{conv, pool};
+use super::{conv, grid_sample, pool};
 use crate::{backend::Backend, Shape};
 
 /// Gradient computed during the backward pass for each tensor used by [conv2d](ModuleOps::conv2d).
@@ -14,6 +14,19 @@ pub struct Conv2dBackward<B: Backend> {
     pub bias_grad: Option<B::TensorPrimitive<1>>,
 }
 
+/// Gradient computed during the backward pass for each tensor used by [conv3d](ModuleOps::conv3d).
+#[derive(new)]
+pub struct Conv3dBackward<B: Backend> {
+    /// Gradient.
+    pub x_grad: B::TensorPrimitive<5>,
+
+    /// Weights gradient.
+    pub weights_grad: B::TensorPrimitive<5>,
+
+    /// Bias gradient.
+    pub bias_grad: Option<B::TensorPrimitive<1>>,
+}
+
 /// Gradient computed during the backward pass for each tensor used by [max_pool1d](ModuleOps::max_pool1d).
 #[derive(new)]
 pub struct MaxPool1dBackward<B: Backend> {
@@ -96,6 +109,40 @@ pub struct ConvTransposeOptions<const N: usize> {
     pub groups: usize,
 }
 
+/// Grid sampling options.
+#[derive(new, Debug, Clone)]
+pub struct GridSampleOptions {
+    /// The interpolation mode.
+    pub mode: GridSampleMode,
+
+    /// How the locations outside of the input are sampled.
+    pub padding_mode: GridSamplePaddingMode,
+
+    /// Whether the coordinates `-1` and `1` are the centers of the corner pixels, instead of
+    /// their outer edges.
+    pub align_corners: bool,
+}
+
+/// The interpolation mode of the grid sampling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridSampleMode {
+    /// Bilinear interpolation of the four nearest pixels.
+    Bilinear,
+
+    /// Value of the nearest pixel.
+    Nearest,
+}
+
+/// How the grid sampling handles the locations outside of the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridSamplePaddingMode {
+    /// The pixels outside of the input are zeros.
+    Zeros,
+
+    /// The locations are clamped to the border of the input.
+    Border,
+}
+
 /// Module operations trait.
 pub trait ModuleOps<B: Backend> {
     /// Embedding operation.
@@ -195,6 +242,31 @@ pub trait ModuleOps<B: Backend> {
     ) -> Conv2dBackward<B> {
         conv::conv2d_backward(x, weight, bias, output_grad, options)
     }
+    /// Three dimensional convolution.
+    ///
+    /// # Shapes
+    ///
+    /// x:      `[batch_size, channels_in, depth, height, width]`,
+    /// weight: `[channels_out, channels_in, kernel_size_1, kernel_size_2, kernel_size_3]`,
+    /// bias:   `[channels_out]`,
+    fn conv3d(
+        x: B::TensorPrimitive<5>,
+        weight: B::TensorPrimitive<5>,
+        bias: Option<B::TensorPrimitive<1>>,
+        options: ConvOptions<3>,
+    ) -> B::TensorPrimitive<5> {
+        conv::conv3d_from_conv2d::<B>(x, weight, bias, options)
+    }
+    /// Backward pass for the [conv3d](ModuleOps::conv3d) operation.
+    fn conv3d_backward(
+        x: B::TensorPrimitive<5>,
+        weight: B::TensorPrimitive<5>,
+        bias: Option<B::TensorPrimitive<1>>,
+        output_grad: B::TensorPrimitive<5>,
+        options: ConvOptions<3>,
+    ) -> Conv3dBackward<B> {
+        conv::conv3d_backward(x, weight, bias, output_grad, options)
+    }
     /// One dimensional transposed convolution.
     ///
     /// # Shapes
@@ -394,4 +466,30 @@ pub trait ModuleOps<B: Backend> {
         output_grad: B::TensorPrimitive<4>,
         indices: B::IntTensorPrimitive<4>,
     ) -> MaxPool2dBackward<B>;
+
+    /// Two dimensional grid sampling, the output being the input sampled at the locations of the
+    /// grid.
+    ///
+    /// The locations are normalized, `(-1, -1)` being the top-left corner of the input and
+    /// `(1, 1)` the bottom-right corner.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, height_in, width_in],
+    /// grid: [batch_size, height_out, width_out, 2], the last dimension being the `x` and `y`
+    /// coordinates,
+    /// output: [batch_size, channels, height_out, width_out].
+    ///
+    /// # Notes
+    ///
+    /// The default implementation gathers the pixels with tensor operations, so the gradients flow
+    /// back to the input and the grid on every backend. The nearest pixel of a location halfway
+    /// between two pixels is the one after it.
+    fn grid_sample_2d(
+        x: B::TensorPrimitive<4>,
+        grid: B::TensorPrimitive<4>,
+        options: GridSampleOptions,
+    ) -> B::TensorPrimitive<4> {
+        grid_sample::grid_sample_2d_from_gather::<B>(x, grid, &options)
+    }
 }
@@ -1,5 +1,6 @@
-use super::{Conv1dBackward, Conv2dBackward, ConvOptions, ConvTransposeOptions};
-use crate::{backend::Backend, Shape};
+use super::{Conv1dBackward, Conv2dBackward, Conv3dBackward, ConvOptions, ConvTransposeOptions};
+use crate::{backend::Backend, Data, ElementConversion, Shape};
+use alloc::vec::Vec;
 use libm::ceilf;
 
 /// Calculate the expected padding size required when applying a convolution.
@@ -31,6 +32,18 @@ pub fn calculate_conv_output_size(
     (size_in + 2 * padding - dilation * (kernel_size - 1) - 1) / stride + 1
 }
 
+/// Calculate the expected output size when doing a transposed convolution operation.
+pub fn calculate_conv_transpose_output_size(
+    kernel_size: usize,
+    stride: usize,
+    padding: usize,
+    padding_out: usize,
+    dilation: usize,
+    size_in: usize,
+) -> usize {
+    (size_in - 1) * stride + dilation * (kernel_size - 1) + padding_out - 2 * padding + 1
+}
+
 /// Calculate the [1D convolution](crate::ops::ModuleOps::conv1d) backward pass using convolutions.
 pub(crate) fn conv1d_backward<B: Backend>(
     x: B::TensorPrimitive<3>,
@@ -162,6 +175,91 @@ pub(crate) fn conv2d_backward<B: Backend>(
     )
 }
 
+/// Calculate the [3D convolution](crate::ops::ModuleOps::conv3d) backward pass using the
+/// [2D convolution](crate::ops::ModuleOps::conv2d) backward pass on each kernel depth.
+pub(crate) fn conv3d_backward<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    weight: B::TensorPrimitive<5>,
+    bias: Option<B::TensorPrimitive<1>>,
+    output_grad: B::TensorPrimitive<5>,
+    options: ConvOptions<3>,
+) -> Conv3dBackward<B> {
+    let device = B::device(&x);
+    let [batch_size, channels_in, depth_in, height_in, width_in] = B::shape(&x).dims;
+    let [_, channels_out, depth_out, height_out, width_out] = B::shape(&output_grad).dims;
+    let [_, channels_per_group, kernel_size_1, kernel_size_2, kernel_size_3] =
+        B::shape(&weight).dims;
+
+    let x = conv3d_pad_depth::<B>(x, options.padding[0]);
+    let depth_padded = depth_in + 2 * options.padding[0];
+    let options_2d = conv3d_options_2d(&options);
+
+    let grad = B::swap_dims(output_grad.clone(), 1, 2);
+    let grad = B::reshape(
+        grad,
+        Shape::new([batch_size * depth_out, channels_out, height_out, width_out]),
+    );
+
+    let mut x_grad = B::zeros(
+        Shape::new([batch_size, channels_in, depth_padded, height_in, width_in]),
+        &device,
+    );
+    let mut weight_grads = Vec::with_capacity(kernel_size_1);
+
+    for k in 0..kernel_size_1 {
+        let indices = conv3d_depth_indices::<B>(k, depth_out, &options, &device);
+        let x_k = conv3d_select_depth::<B>(x.clone(), indices.clone());
+        let weight_k = conv3d_weight_depth::<B>(weight.clone(), k);
+
+        let backward = B::conv2d_backward(x_k, weight_k, None, grad.clone(), options_2d.clone());
+
+        let x_grad_k = B::reshape(
+            backward.x_grad,
+            Shape::new([batch_size, depth_out, channels_in, height_in, width_in]),
+        );
+        x_grad = B::select_assign(x_grad, 2, indices, B::swap_dims(x_grad_k, 1, 2));
+        weight_grads.push(B::reshape(
+            backward.weights_grad,
+            Shape::new([
+                channels_out,
+                channels_per_group,
+                1,
+                kernel_size_2,
+                kernel_size_3,
+            ]),
+        ));
+    }
+
+    let x_grad = B::slice(
+        x_grad,
+        [
+            0..batch_size,
+            0..channels_in,
+            options.padding[0]..options.padding[0] + depth_in,
+            0..height_in,
+            0..width_in,
+        ],
+    );
+
+    Conv3dBackward::new(
+        x_grad,
+        B::cat(weight_grads, 2),
+        bias.map(|b| {
+            let grad = B::swap_dims(output_grad, 0, 1);
+            let grad = B::reshape(
+                grad,
+                Shape::new([
+                    channels_out,
+                    batch_size * depth_out * height_out * width_out,
+                ]),
+            );
+            let grad = B::sum_dim(grad, 1);
+
+            B::reshape(grad, B::shape(&b))
+        }),
+    )
+}
+
 /// Calculate the [2D convolution transpose](crate::ops::ModuleOps::conv_transpose2d) backward pass using convolutions.
 pub(crate) fn conv_transpose2d_backward<B: Backend>(
     x: B::TensorPrimitive<4>,
@@ -304,6 +402,151 @@ pub(crate) fn conv1d_from_conv2d<B: Backend>(
     B::reshape(tensor, Shape::from([batch_size, channels_out, height_out]))
 }
 
+/// Execute a 3D convolution using a 2D convolution for each kernel depth.
+///
+/// The output depth positions are folded into the batch dimension, so only `kernel_size_1`
+/// 2D convolutions are executed.
+pub(crate) fn conv3d_from_conv2d<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    weight: B::TensorPrimitive<5>,
+    bias: Option<B::TensorPrimitive<1>>,
+    options: ConvOptions<3>,
+) -> B::TensorPrimitive<5> {
+    let device = B::device(&x);
+    let [channels_out, _, kernel_size_1, _, _] = B::shape(&weight).dims;
+    let [batch_size, _, depth_in, _, _] = B::shape(&x).dims;
+
+    let depth_out = calculate_conv_output_size(
+        kernel_size_1,
+        options.stride[0],
+        options.padding[0],
+        options.dilation[0],
+        depth_in,
+    );
+    let x = conv3d_pad_depth::<B>(x, options.padding[0]);
+    let options_2d = conv3d_options_2d(&options);
+
+    let mut output = None;
+
+    for k in 0..kernel_size_1 {
+        let indices = conv3d_depth_indices::<B>(k, depth_out, &options, &device);
+        let x_k = conv3d_select_depth::<B>(x.clone(), indices);
+        let weight_k = conv3d_weight_depth::<B>(weight.clone(), k);
+        let output_k = B::conv2d(x_k, weight_k, None, options_2d.clone());
+
+        output = Some(match output {
+            Some(output) => B::add(output, output_k),
+            None => output_k,
+        });
+    }
+
+    let output = output.expect("The kernel should have at least one element in depth.");
+    let [_, _, height_out, width_out] = B::shape(&output).dims;
+    let output = B::reshape(
+        output,
+        Shape::new([batch_size, depth_out, channels_out, height_out, width_out]),
+    );
+    let output = B::swap_dims(output, 1, 2);
+
+    match bias {
+        Some(bias) => B::add(
+            output,
+            B::reshape(bias, Shape::new([1, channels_out, 1, 1, 1])),
+        ),
+        None => output,
+    }
+}
+
+fn conv3d_options_2d(options: &ConvOptions<3>) -> ConvOptions<2> {
+    ConvOptions::new(
+        [options.stride[1], options.stride[2]],
+        [options.padding[1], options.padding[2]],
+        [options.dilation[1], options.dilation[2]],
+        options.groups,
+    )
+}
+
+fn conv3d_pad_depth<B: Backend>(x: B::TensorPrimitive<5>, padding: usize) -> B::TensorPrimitive<5> {
+    if padding == 0 {
+        return x;
+    }
+
+    let [batch_size, channels_in, depth, height, width] = B::shape(&x).dims;
+    let output = B::zeros(
+        Shape::new([batch_size, channels_in, depth + 2 * padding, height, width]),
+        &B::device(&x),
+    );
+
+    B::slice_assign(
+        output,
+        [
+            0..batch_size,
+            0..channels_in,
+            padding..padding + depth,
+            0..height,
+            0..width,
+        ],
+        x,
+    )
+}
+
+/// Indices of the padded input depth positions covered by the kernel depth `k` for each output
+/// depth position.
+fn conv3d_depth_indices<B: Backend>(
+    k: usize,
+    depth_out: usize,
+    options: &ConvOptions<3>,
+    device: &B::Device,
+) -> B::IntTensorPrimitive<1> {
+    let indices = (0..depth_out)
+        .map(|d| ((d * options.stride[0] + k * options.dilation[0]) as i64).elem())
+        .collect();
+
+    B::int_from_data(Data::new(indices, Shape::new([depth_out])), device)
+}
+
+/// Select the given depth positions and fold them into the batch dimension.
+fn conv3d_select_depth<B: Backend>(
+    x: B::TensorPrimitive<5>,
+    indices: B::IntTensorPrimitive<1>,
+) -> B::TensorPrimitive<4> {
+    let x = B::swap_dims(B::select(x, 2, indices), 1, 2);
+    let [batch_size, depth, channels_in, height, width] = B::shape(&x).dims;
+
+    B::reshape(
+        x,
+        Shape::new([batch_size * depth, channels_in, height, width]),
+    )
+}
+
+fn conv3d_weight_depth<B: Backend>(
+    weight: B::TensorPrimitive<5>,
+    k: usize,
+) -> B::TensorPrimitive<4> {
+    let [channels_out, channels_per_group, _, kernel_size_2, kernel_size_3] =
+        B::shape(&weight).dims;
+    let weight = B::slice(
+        weight,
+        [
+            0..channels_out,
+            0..channels_per_group,
+            k..k + 1,
+            0..kernel_size_2,
+            0..kernel_size_3,
+        ],
+    );
+
+    B::reshape(
+        weight,
+        Shape::new([
+            channels_out,
+            channels_per_group,
+            kernel_size_2,
+            kernel_size_3,
+        ]),
+    )
+}
+
 /// Execute a 1D transposed convolution using a 2D transposed convolution.
 pub(crate) fn conv_transpose1d_from_conv_transpose2d<B: Backend>(
     x: B::TensorPrimitive<3>,
@@ -661,6 +904,27 @@ fn calculate_padding_out(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_calculate_conv_transpose_output_size() {
+        let kernel_size = 3;
+        let stride = 2;
+        let padding = 1;
+        let padding_out = 1;
+        let dilation = 1;
+        let size_in = 4;
+
+        let size_out = calculate_conv_transpose_output_size(
+            kernel_size,
+            stride,
+            padding,
+            padding_out,
+            dilation,
+            size_in,
+        );
+
+        assert_eq!(size_out, 8);
+    }
+
     #[test]
     fn test_calculate_output_size_1() {
         let kernel_size = 3;
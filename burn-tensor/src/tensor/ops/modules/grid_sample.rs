@@ -0,0 +1,123 @@
+use crate::{backend::Backend, ElementConversion, Tensor};
+
+use super::{GridSampleMode, GridSampleOptions, GridSamplePaddingMode};
+
+/// Sample the input at the locations of the grid with gather operations, the gradients flowing
+/// back to both the input and the grid.
+pub(crate) fn grid_sample_2d_from_gather<B: Backend>(
+    x: B::TensorPrimitive<4>,
+    grid: B::TensorPrimitive<4>,
+    options: &GridSampleOptions,
+) -> B::TensorPrimitive<4> {
+    let x = Tensor::<B, 4>::new(x);
+    let grid = Tensor::<B, 4>::new(grid);
+    let [batch_size, channels, height_in, width_in] = x.dims();
+    let [_, height_out, width_out, _] = grid.dims();
+    let length = height_out * width_out;
+
+    let coordinate = |index: usize, size: usize| {
+        let coordinate = grid
+            .clone()
+            .slice([0..batch_size, 0..height_out, 0..width_out, index..index + 1])
+            .reshape([batch_size, 1, length]);
+
+        unnormalize(coordinate, size, options)
+    };
+    let ix = coordinate(0, width_in);
+    let iy = coordinate(1, height_in);
+
+    let sampler = Sampler {
+        x: x.reshape([batch_size, channels, height_in * width_in]),
+        channels,
+        height: height_in,
+        width: width_in,
+    };
+
+    let output = match options.mode {
+        GridSampleMode::Nearest => {
+            sampler.sample(floor(ix.add_scalar(0.5)), floor(iy.add_scalar(0.5)))
+        }
+        GridSampleMode::Bilinear => {
+            let (x0, y0) = (floor(ix.clone()), floor(iy.clone()));
+            let (x1, y1) = (x0.clone().add_scalar(1.0), y0.clone().add_scalar(1.0));
+            let (wx1, wy1) = (ix.sub(x0.clone()), iy.sub(y0.clone()));
+            let (wx0, wy0) = (
+                wx1.ones_like().sub(wx1.clone()),
+                wy1.ones_like().sub(wy1.clone()),
+            );
+
+            sampler
+                .sample(x0.clone(), y0.clone())
+                .mul(wx0.clone().mul(wy0.clone()))
+                .add(sampler.sample(x1.clone(), y0).mul(wx1.clone().mul(wy0)))
+                .add(sampler.sample(x0, y1.clone()).mul(wx0.mul(wy1.clone())))
+                .add(sampler.sample(x1, y1).mul(wx1.mul(wy1)))
+        }
+    };
+
+    output
+        .reshape([batch_size, channels, height_out, width_out])
+        .primitive
+}
+
+/// The input flattened to `[batch_size, channels, height * width]`.
+struct Sampler<B: Backend> {
+    x: Tensor<B, 3>,
+    channels: usize,
+    height: usize,
+    width: usize,
+}
+
+impl<B: Backend> Sampler<B> {
+    /// The values at the given integral coordinates, of shape `[batch_size, 1, length]`, the
+    /// values outside of the input being zeros.
+    fn sample(&self, ix: Tensor<B, 3>, iy: Tensor<B, 3>) -> Tensor<B, 3> {
+        let (x_max, y_max) = ((self.width - 1) as f32, (self.height - 1) as f32);
+        let inside = |coordinate: Tensor<B, 3>, max: f32| {
+            coordinate
+                .clone()
+                .greater_equal_elem(0.0)
+                .float()
+                .mul(coordinate.lower_equal_elem(max).float())
+        };
+        let mask = inside(ix.clone(), x_max).mul(inside(iy.clone(), y_max));
+
+        let indices = iy
+            .clamp(0.0.elem(), y_max.elem())
+            .mul_scalar(self.width as f32)
+            .add(ix.clamp(0.0.elem(), x_max.elem()))
+            .int()
+            .repeat(1, self.channels);
+
+        self.x.clone().gather(2, indices).mul(mask)
+    }
+}
+
+/// Convert the normalized coordinates in `[-1, 1]` to pixel coordinates.
+fn unnormalize<B: Backend>(
+    coordinate: Tensor<B, 3>,
+    size: usize,
+    options: &GridSampleOptions,
+) -> Tensor<B, 3> {
+    let size = size as f32;
+    let coordinate = match options.align_corners {
+        true => coordinate.add_scalar(1.0).mul_scalar((size - 1.0) / 2.0),
+        false => coordinate
+            .add_scalar(1.0)
+            .mul_scalar(size / 2.0)
+            .sub_scalar(0.5),
+    };
+
+    match options.padding_mode {
+        GridSamplePaddingMode::Zeros => coordinate,
+        GridSamplePaddingMode::Border => coordinate.clamp(0.0.elem(), (size - 1.0).elem()),
+    }
+}
+
+/// The largest integral values lower or equal to the elements.
+fn floor<B: Backend>(tensor: Tensor<B, 3>) -> Tensor<B, 3> {
+    let truncated = tensor.clone().int().float();
+    let correction = tensor.lower(truncated.clone()).float();
+
+    truncated.sub(correction)
+}
@@ -1,6 +1,9 @@
 /// Module with convolution operations.
 pub mod conv;
 
+/// Module with grid sampling operations.
+pub mod grid_sample;
+
 /// Module with pooling operations.
 pub mod pool;
 
@@ -1,6 +1,9 @@
 use alloc::vec::Vec;
 use core::ops::Range;
 
+#[cfg(feature = "linalg-host-fallback")]
+use super::{cholesky_data, eigh_data, qr_data, svd_data};
+use super::{dft, dft_real, idft_real, solve_data, topk_indices};
 use crate::{backend::Backend, tensor::Shape, Data, Distribution, ElementConversion};
 
 /// Operations on float tensors.
@@ -521,6 +524,42 @@ pub trait TensorOps<B: Backend> {
         value: B::TensorPrimitive<D>,
     ) -> B::TensorPrimitive<D>;
 
+    /// Scatter elements into a tensor, replacing the elements at the given indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension to scatter into.
+    /// * `tensor` - The tensor to scatter into.
+    /// * `indices` - The indices to scatter into.
+    /// * `value` - The value to scatter.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the scattered elements.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation fills the scattered positions with zeros before adding the values
+    /// with [scatter](TensorOps::scatter), so the values scattered to the same position are summed.
+    fn scatter_assign<const D: usize>(
+        dim: usize,
+        tensor: B::TensorPrimitive<D>,
+        indices: B::IntTensorPrimitive<D>,
+        value: B::TensorPrimitive<D>,
+    ) -> B::TensorPrimitive<D> {
+        let device = B::device(&tensor);
+        let positions = B::int_scatter(
+            dim,
+            B::int_zeros(B::shape(&tensor), &device),
+            indices.clone(),
+            B::int_ones(B::int_shape(&indices), &device),
+        );
+        let mask = B::int_greater_elem(positions, 0.elem());
+        let tensor = B::mask_fill(tensor, mask, 0.elem());
+
+        B::scatter(dim, tensor, indices, value)
+    }
+
     /// Select tensor elements along the given dimension corresponding for the given indices.
     ///
     /// # Arguments
@@ -774,6 +813,37 @@ pub trait TensorOps<B: Backend> {
         rhs: B::FloatElem,
     ) -> B::BoolTensorPrimitive<D>;
 
+    /// Returns a boolean tensor indicating which elements are NaN.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor where the NaN elements are true.
+    fn is_nan<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::BoolTensorPrimitive<D> {
+        // NaN is the only value that is neither greater or equal, nor lower than zero.
+        let greater_equal = B::greater_equal_elem(tensor.clone(), 0.elem());
+        let lower = B::lower_elem(tensor, 0.elem());
+        let ordered = B::int_add(B::bool_into_int(greater_equal), B::bool_into_int(lower));
+
+        B::int_equal_elem(ordered, 0.elem())
+    }
+
+    /// Returns a boolean tensor indicating which elements are positive or negative infinity.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor where the infinite elements are true.
+    fn is_inf<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::BoolTensorPrimitive<D> {
+        B::equal_elem(B::abs(tensor), f64::INFINITY.elem())
+    }
+
     /// Detaches a tensor from the computation graph.
     fn detach<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
         // Should only be overridden by autodiff backends.
@@ -960,6 +1030,88 @@ pub trait TensorOps<B: Backend> {
     /// A tensor with the same shape as `tensor` with sine values.
     fn sin<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
 
+    /// Returns a new tensor with arctangent values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the arctangent of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with arctangent values, in `[-pi/2, pi/2]`.
+    fn atan<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+
+    /// Returns a new tensor with tangent values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the tangent of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with tangent values.
+    fn tan<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+
+    /// Returns a new tensor with arcsine values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the arcsine of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with arcsine values, in `[-pi/2, pi/2]`.
+    fn asin<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+
+    /// Returns a new tensor with arccosine values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the arccosine of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with arccosine values, in `[0, pi]`.
+    fn acos<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+
+    /// Returns a new tensor with the arctangent of `lhs / rhs`, using the signs of both tensors
+    /// to determine the quadrant.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The tensor of the y coordinates.
+    /// * `rhs` - The tensor of the x coordinates.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the angles in radians, in `[-pi, pi]`.
+    fn atan2<const D: usize>(
+        lhs: B::TensorPrimitive<D>,
+        rhs: B::TensorPrimitive<D>,
+    ) -> B::TensorPrimitive<D>;
+
+    /// Returns a new tensor with hyperbolic sine values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the hyperbolic sine of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with hyperbolic sine values.
+    fn sinh<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+
+    /// Returns a new tensor with hyperbolic cosine values.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the hyperbolic cosine of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with hyperbolic cosine values.
+    fn cosh<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+
     /// Returns a new tensor with tangent values.
     ///
     /// # Arguments
@@ -982,6 +1134,29 @@ pub trait TensorOps<B: Backend> {
     /// A tensor with the same shape as `tensor` with error function values.
     fn erf<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
 
+    /// Returns a new tensor with the complementary error function values, `1 - erf(x)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the complementary error function of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with complementary error function values.
+    fn erfc<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+
+    /// Returns a new tensor with the natural logarithm of the absolute value of the gamma
+    /// function.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to take the log-gamma function of.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with log-gamma function values.
+    fn lgamma<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+
     /// Catcatenates tensors along a dimension.
     ///
     /// # Arguments
@@ -1079,6 +1254,39 @@ pub trait TensorOps<B: Backend> {
         (values, index)
     }
 
+    /// Gets the `k` largest or smallest elements of a tensor along an axis and their indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to get the elements of.
+    /// * `dim` - The dimension along which to get the elements.
+    /// * `k` - The number of elements to get.
+    /// * `largest` - Whether to get the largest or the smallest elements.
+    /// * `sorted` - Whether the elements are sorted by value or kept in the order of the tensor.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the `k` elements of `tensor` along `dim` and their indices.
+    ///
+    /// # Notes
+    ///
+    /// The default implementation selects the indices from the data of the tensor, and gathers the
+    /// elements on the backend.
+    fn topk<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        dim: usize,
+        k: usize,
+        largest: bool,
+        sorted: bool,
+    ) -> (B::TensorPrimitive<D>, B::IntTensorPrimitive<D>) {
+        let data = B::to_data(&tensor);
+        let indices = topk_indices(&data, dim, k, largest, sorted);
+        let indices = B::int_from_data(indices, &B::device(&tensor));
+        let values = B::gather(dim, tensor, indices.clone());
+
+        (values, indices)
+    }
+
     /// Gets the minimum element of a tensor.
     ///
     /// # Arguments
@@ -1130,4 +1338,261 @@ pub trait TensorOps<B: Backend> {
 
         (values, index)
     }
+
+    /// Computes the discrete Fourier transform of a real tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The real tensor.
+    /// * `dim` - The dimension along which to compute the transform.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the real and imaginary parts of the `n / 2 + 1` non-redundant frequencies,
+    /// `n` being the size of `dim`.
+    ///
+    /// # Notes
+    ///
+    /// The default implementation multiplies the tensor by the DFT matrix, with a quadratic cost
+    /// in the size of the dimension.
+    fn rfft<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        dim: usize,
+    ) -> (B::TensorPrimitive<D>, B::TensorPrimitive<D>) {
+        dft_real::<B, D>(tensor, dim)
+    }
+
+    /// Computes the inverse of [rfft](TensorOps::rfft).
+    ///
+    /// # Arguments
+    ///
+    /// * `real` - The real part of the non-redundant frequencies.
+    /// * `imag` - The imaginary part of the non-redundant frequencies.
+    /// * `dim` - The dimension along which to compute the transform.
+    /// * `size` - The size of `dim` in the real tensor, whose `size / 2 + 1` frequencies are given.
+    ///
+    /// # Returns
+    ///
+    /// The real tensor.
+    ///
+    /// # Notes
+    ///
+    /// The default implementation multiplies the tensors by the inverse DFT matrix, with a
+    /// quadratic cost in the size of the dimension.
+    fn irfft<const D: usize>(
+        real: B::TensorPrimitive<D>,
+        imag: B::TensorPrimitive<D>,
+        dim: usize,
+        size: usize,
+    ) -> B::TensorPrimitive<D> {
+        idft_real::<B, D>(real, imag, dim, size)
+    }
+
+    /// Computes the discrete Fourier transform of a complex tensor along an axis, or its inverse.
+    ///
+    /// # Arguments
+    ///
+    /// * `real` - The real part of the tensor.
+    /// * `imag` - The imaginary part of the tensor.
+    /// * `dim` - The dimension along which to compute the transform.
+    /// * `inverse` - Whether to compute the inverse transform, which is scaled by `1 / n`.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the real and imaginary parts of the transform.
+    ///
+    /// # Notes
+    ///
+    /// The default implementation multiplies the tensors by the DFT matrix, with a quadratic cost
+    /// in the size of the dimension.
+    fn fft<const D: usize>(
+        real: B::TensorPrimitive<D>,
+        imag: B::TensorPrimitive<D>,
+        dim: usize,
+        inverse: bool,
+    ) -> (B::TensorPrimitive<D>, B::TensorPrimitive<D>) {
+        dft::<B, D>(real, imag, dim, inverse)
+    }
+
+    /// Solves the batched linear systems `A X = B`.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The square matrices `A`, of shape `[..., n, n]`.
+    /// * `rhs` - The right-hand sides `B`, of shape `[..., n, k]`.
+    ///
+    /// # Returns
+    ///
+    /// The solutions `X`, with the same shape as `rhs`.
+    ///
+    /// # Notes
+    ///
+    /// The default implementation reads the matrices and solves the systems on the host with the
+    /// Gaussian elimination with partial pivoting, panicking if a matrix is singular.
+    fn solve<const D: usize>(
+        lhs: B::TensorPrimitive<D>,
+        rhs: B::TensorPrimitive<D>,
+    ) -> B::TensorPrimitive<D> {
+        let device = B::device(&rhs);
+        let solution = solve_data(&B::into_data(lhs), &B::into_data(rhs));
+
+        B::from_data(solution, &device)
+    }
+
+    /// Computes the Cholesky decomposition `A = L L^T` of batched symmetric positive-definite
+    /// matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices `A`, of shape `[..., n, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The lower triangular factors `L`, with the same shape as the tensor.
+    ///
+    /// # Notes
+    ///
+    /// The default implementation reads the matrices and decomposes them on the host, panicking if
+    /// a matrix is not positive-definite.
+    ///
+    /// Without the `linalg-host-fallback` feature, the default implementation panics instead, so
+    /// the data of the backends implementing the decomposition on the device is never read
+    /// implicitly.
+    fn cholesky<const D: usize>(tensor: B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
+        #[cfg(feature = "linalg-host-fallback")]
+        {
+            let device = B::device(&tensor);
+            let factors = cholesky_data(&B::into_data(tensor));
+
+            B::from_data(factors, &device)
+        }
+        #[cfg(not(feature = "linalg-host-fallback"))]
+        {
+            core::mem::drop(tensor);
+            super::host_fallback_disabled::<B>("cholesky")
+        }
+    }
+
+    /// Computes the reduced QR decomposition `A = Q R` of batched matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices `A`, of shape `[..., m, n]`.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the matrices `Q` with orthonormal columns, of shape `[..., m, k]`, and the
+    /// upper triangular matrices `R`, of shape `[..., k, n]`, with `k = min(m, n)`.
+    ///
+    /// # Notes
+    ///
+    /// The default implementation reads the matrices and decomposes them on the host with
+    /// Householder reflections.
+    ///
+    /// Without the `linalg-host-fallback` feature, the default implementation panics instead, so
+    /// the data of the backends implementing the decomposition on the device is never read
+    /// implicitly.
+    fn qr<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+    ) -> (B::TensorPrimitive<D>, B::TensorPrimitive<D>) {
+        #[cfg(feature = "linalg-host-fallback")]
+        {
+            let device = B::device(&tensor);
+            let (q, r) = qr_data(&B::into_data(tensor));
+
+            (B::from_data(q, &device), B::from_data(r, &device))
+        }
+        #[cfg(not(feature = "linalg-host-fallback"))]
+        {
+            core::mem::drop(tensor);
+            super::host_fallback_disabled::<B>("qr")
+        }
+    }
+
+    /// Computes the reduced singular value decomposition `A = U diag(S) V^T` of batched matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices `A`, of shape `[..., m, n]`.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the matrices `U`, of shape `[..., m, k]`, the singular values `S` in
+    /// descending order, of shape `[..., k]`, and the matrices `V^T`, of shape `[..., k, n]`, with
+    /// `k = min(m, n)`. The output `D2` must be `D - 1`.
+    ///
+    /// # Notes
+    ///
+    /// The default implementation reads the matrices and decomposes them on the host with the
+    /// one-sided Jacobi algorithm.
+    ///
+    /// Without the `linalg-host-fallback` feature, the default implementation panics instead, so
+    /// the data of the backends implementing the decomposition on the device is never read
+    /// implicitly.
+    #[allow(clippy::type_complexity)]
+    fn svd<const D: usize, const D2: usize>(
+        tensor: B::TensorPrimitive<D>,
+    ) -> (
+        B::TensorPrimitive<D>,
+        B::TensorPrimitive<D2>,
+        B::TensorPrimitive<D>,
+    ) {
+        #[cfg(feature = "linalg-host-fallback")]
+        {
+            let device = B::device(&tensor);
+            let (u, s, vt) = svd_data::<_, D, D2>(&B::into_data(tensor));
+
+            (
+                B::from_data(u, &device),
+                B::from_data(s, &device),
+                B::from_data(vt, &device),
+            )
+        }
+        #[cfg(not(feature = "linalg-host-fallback"))]
+        {
+            core::mem::drop(tensor);
+            super::host_fallback_disabled::<B>("svd")
+        }
+    }
+
+    /// Computes the eigendecomposition `A = V diag(W) V^T` of batched symmetric matrices, only
+    /// the lower triangular part of the matrices being used.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The matrices `A`, of shape `[..., n, n]`.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the eigenvalues `W` in ascending order, of shape `[..., n]`, and the matrices
+    /// `V` with the eigenvectors as columns, of shape `[..., n, n]`. The output `D2` must be
+    /// `D - 1`.
+    ///
+    /// # Notes
+    ///
+    /// The default implementation reads the matrices and decomposes them on the host with the
+    /// cyclic Jacobi algorithm.
+    ///
+    /// Without the `linalg-host-fallback` feature, the default implementation panics instead, so
+    /// the data of the backends implementing the decomposition on the device is never read
+    /// implicitly.
+    fn eigh<const D: usize, const D2: usize>(
+        tensor: B::TensorPrimitive<D>,
+    ) -> (B::TensorPrimitive<D2>, B::TensorPrimitive<D>) {
+        #[cfg(feature = "linalg-host-fallback")]
+        {
+            let device = B::device(&tensor);
+            let (values, vectors) = eigh_data::<_, D, D2>(&B::into_data(tensor));
+
+            (
+                B::from_data(values, &device),
+                B::from_data(vectors, &device),
+            )
+        }
+        #[cfg(not(feature = "linalg-host-fallback"))]
+        {
+            core::mem::drop(tensor);
+            super::host_fallback_disabled::<B>("eigh")
+        }
+    }
 }
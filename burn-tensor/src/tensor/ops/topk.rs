@@ -0,0 +1,73 @@
+use alloc::vec::Vec;
+
+use crate::{tensor::Shape, Data, Element, ElementConversion};
+
+/// Computes the indices of the `k` largest or smallest values along a dimension from the data of
+/// a tensor, used by the default implementations of the `topk` operations.
+///
+/// The indices are ordered by value when `sorted` is true, and by position otherwise. The ties
+/// are broken by position, and `NaN` is greater than any other value.
+pub(crate) fn topk_indices<E: Element, I: Element, const D: usize>(
+    data: &Data<E, D>,
+    dim: usize,
+    k: usize,
+    largest: bool,
+    sorted: bool,
+) -> Data<I, D> {
+    let dims = data.shape.dims;
+    let size = dims[dim];
+    let outer = dims[..dim].iter().product::<usize>();
+    let inner = dims[dim + 1..].iter().product::<usize>();
+
+    let compare = |a: f64, b: f64| {
+        a.partial_cmp(&b)
+            .unwrap_or_else(|| a.is_nan().cmp(&b.is_nan()))
+    };
+
+    let mut indices = Vec::with_capacity(outer * k * inner);
+    indices.resize(outer * k * inner, 0.elem());
+    let mut lane = Vec::with_capacity(size);
+
+    for o in 0..outer {
+        for i in 0..inner {
+            let offset = o * size * inner + i;
+            let value = |index: usize| data.value[offset + index * inner].to_f64().unwrap();
+
+            lane.clear();
+            lane.extend(0..size);
+            lane.sort_by(|a, b| match largest {
+                true => compare(value(*b), value(*a)),
+                false => compare(value(*a), value(*b)),
+            });
+            let selected = &mut lane[..k];
+            if !sorted {
+                selected.sort();
+            }
+
+            for (rank, index) in selected.iter().enumerate() {
+                indices[o * k * inner + rank * inner + i] = (*index as i64).elem();
+            }
+        }
+    }
+
+    let mut shape = dims;
+    shape[dim] = k;
+
+    Data::new(indices, Shape::new(shape))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_select_the_indices_along_an_inner_dimension() {
+        let data = Data::from([[3.0, 1.0], [f32::NAN, 2.0], [1.0, 5.0]]);
+
+        let largest = topk_indices::<f32, i64, 2>(&data, 0, 2, true, true);
+        let smallest = topk_indices::<f32, i64, 2>(&data, 0, 2, false, false);
+
+        assert_eq!(largest, Data::from([[1, 2], [0, 1]]));
+        assert_eq!(smallest, Data::from([[0, 0], [2, 1]]));
+    }
+}
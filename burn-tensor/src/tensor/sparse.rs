@@ -0,0 +1,264 @@
+use alloc::vec::Vec;
+
+use crate::{backend::Backend, check, check::TensorCheck, Data, Int, Shape, Tensor};
+
+/// A sparse tensor in the coordinate (COO) format, made of the indices of its non-zero elements
+/// and their values.
+///
+/// The indices have the shape `[nnz, D]`, each row being the position of an element, and the
+/// values have the shape `[nnz]`. The same position can appear more than once, the values at
+/// that position being summed, so the tensor doesn't have to be coalesced.
+///
+/// The values are a float tensor, so the gradients flow back to them through
+/// [to_dense](SparseTensor::to_dense) and [matmul](SparseTensor::matmul), the gradient of a
+/// sparse tensor having the same sparsity pattern as the tensor.
+#[derive(Clone, Debug)]
+pub struct SparseTensor<B: Backend, const D: usize> {
+    indices: Tensor<B, 2, Int>,
+    values: Tensor<B, 1>,
+    shape: Shape<D>,
+}
+
+impl<B: Backend, const D: usize> SparseTensor<B, D> {
+    /// Create a sparse tensor of the given shape from the positions of its non-zero elements and
+    /// their values.
+    ///
+    /// The positions are not checked against the shape, since they live on the device.
+    ///
+    /// # Panics
+    ///
+    /// If the indices don't have the shape `[nnz, D]`, or the values don't have the shape `[nnz]`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::sparse::SparseTensor;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let indices = Tensor::<B, 2, Int>::from_ints([[0, 1], [2, 0]]);
+    ///     let values = Tensor::<B, 1>::from_floats([3.0, 4.0]);
+    ///     let tensor = SparseTensor::new(indices, values, [3, 2]);
+    ///     println!("{}", tensor.to_dense());
+    ///     // [[0.0, 3.0], [0.0, 0.0], [4.0, 0.0]]
+    /// }
+    /// ```
+    pub fn new<S: Into<Shape<D>>>(
+        indices: Tensor<B, 2, Int>,
+        values: Tensor<B, 1>,
+        shape: S,
+    ) -> Self {
+        check!(TensorCheck::sparse::<D>(&indices.shape(), &values.shape()));
+
+        Self {
+            indices,
+            values,
+            shape: shape.into(),
+        }
+    }
+
+    /// Create a sparse tensor from the non-zero elements of a dense tensor.
+    ///
+    /// The non-zero elements are selected with [select](Tensor::select), so the gradients flow
+    /// back to the dense tensor.
+    ///
+    /// # Notes
+    ///
+    /// The number of non-zero elements has to be known to create the indices, so the whole dense
+    /// tensor is read back to the host, waiting for the device. Prefer [new](SparseTensor::new)
+    /// when the positions of the elements are already known.
+    pub fn from_dense(tensor: Tensor<B, D>) -> Self {
+        let shape = tensor.shape();
+        let device = tensor.device();
+        let positions = tensor
+            .to_data()
+            .convert::<f64>()
+            .value
+            .into_iter()
+            .enumerate()
+            .filter(|(_, value)| *value != 0.0)
+            .map(|(position, _)| position)
+            .collect::<Vec<_>>();
+        let nnz = positions.len();
+
+        let mut indices = Vec::with_capacity(nnz * D);
+        for position in positions.iter() {
+            let mut remainder = *position;
+            let start = indices.len();
+            for size in shape.dims.iter().rev() {
+                indices.push((remainder % size) as i64);
+                remainder /= size;
+            }
+            indices[start..].reverse();
+        }
+
+        let positions = positions
+            .into_iter()
+            .map(|position| position as i64)
+            .collect();
+        let positions = Tensor::<B, 1, Int>::from_data_device(
+            Data::new(positions, Shape::new([nnz])).convert(),
+            &device,
+        );
+        let indices = Tensor::<B, 2, Int>::from_data_device(
+            Data::new(indices, Shape::new([nnz, D])).convert(),
+            &device,
+        );
+        let values = tensor.reshape([shape.num_elements()]).select(0, positions);
+
+        Self {
+            indices,
+            values,
+            shape,
+        }
+    }
+
+    /// Returns the positions of the elements, of shape `[nnz, D]`.
+    pub fn indices(&self) -> Tensor<B, 2, Int> {
+        self.indices.clone()
+    }
+
+    /// Returns the values of the elements, of shape `[nnz]`.
+    pub fn values(&self) -> Tensor<B, 1> {
+        self.values.clone()
+    }
+
+    /// Returns the shape of the tensor.
+    pub fn shape(&self) -> Shape<D> {
+        self.shape.clone()
+    }
+
+    /// Returns the number of stored elements, counting the repeated positions.
+    pub fn nnz(&self) -> usize {
+        self.values.dims()[0]
+    }
+
+    /// Returns the device of the tensor.
+    pub fn device(&self) -> B::Device {
+        self.values.device()
+    }
+
+    /// Returns the dense tensor, the values at the same position being summed.
+    pub fn to_dense(self) -> Tensor<B, D> {
+        let device = self.device();
+        let num_elements = self.shape.num_elements();
+
+        let mut strides = [0; D];
+        let mut stride = 1;
+        for (dim, size) in self.shape.dims.iter().enumerate().rev() {
+            strides[dim] = stride as i64;
+            stride *= size;
+        }
+        let strides = Tensor::<B, 2, Int>::from_data_device(
+            Data::new(strides.to_vec(), Shape::new([1, D])).convert(),
+            &device,
+        );
+
+        let nnz = self.nnz();
+        let positions = self.indices.mul(strides).sum_dim(1).reshape([nnz]);
+
+        Tensor::zeros_device([num_elements], &device)
+            .scatter(0, positions, self.values)
+            .reshape(self.shape)
+    }
+}
+
+impl<B: Backend> SparseTensor<B, 2> {
+    /// Performs the matrix multiplication of the sparse matrix with a dense matrix.
+    ///
+    /// The rows of the dense matrix are selected by the column of each element, scaled by its
+    /// value, then summed into the row of the element, so the work is proportional to the number
+    /// of elements.
+    ///
+    /// # Panics
+    ///
+    /// If the number of columns of the sparse matrix doesn't match the number of rows of the
+    /// dense matrix.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::sparse::SparseTensor;
+    /// use burn_tensor::{Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let indices = Tensor::<B, 2, Int>::from_ints([[0, 1], [1, 0]]);
+    ///     let values = Tensor::<B, 1>::from_floats([2.0, 3.0]);
+    ///     let sparse = SparseTensor::new(indices, values, [2, 2]);
+    ///     let dense = Tensor::<B, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+    ///     println!("{}", sparse.matmul(dense));
+    ///     // [[6.0, 8.0], [3.0, 6.0]]
+    /// }
+    /// ```
+    pub fn matmul(self, other: Tensor<B, 2>) -> Tensor<B, 2> {
+        check!(TensorCheck::sparse_matmul(&self.shape, &other.shape()));
+
+        let nnz = self.nnz();
+        let [rows, _] = self.shape.dims;
+        let [_, columns] = other.dims();
+        let device = other.device();
+
+        let row = self.indices.clone().slice([0..nnz, 0..1]).reshape([nnz]);
+        let column = self.indices.slice([0..nnz, 1..2]).reshape([nnz]);
+        let products = other.select(0, column).mul(self.values.reshape([nnz, 1]));
+
+        Tensor::zeros_device([rows, columns], &device).select_assign(0, row, products)
+    }
+}
+
+/// Computes the gradient of the [embedding](crate::module::embedding) weights as a coalesced
+/// sparse matrix, the rows of the embeddings that weren't looked up being left out.
+///
+/// Each looked up row appears once, with the gradients of all its lookups summed, so the sparse
+/// matrix has `num_looked_up_rows * d_model` elements, which can be used to only update the looked
+/// up rows of a large vocabulary.
+///
+/// # Notes
+///
+/// Autodiff doesn't use this function: the gradient it computes for the embedding weights is
+/// always dense, computed with [embedding backward](crate::ops::ModuleOps::embedding_backward).
+///
+/// The indices are read back to the host to find the looked up rows, but not the gradient.
+///
+/// # Arguments
+///
+/// * `num_embeddings` - The number of rows of the embedding weights.
+/// * `output_grad` - The gradient of the embedding output, of shape `[batch_size, seq_length, d_model]`.
+/// * `indices` - The looked up indices, of shape `[batch_size, seq_length]`.
+pub fn embedding_backward<B: Backend>(
+    num_embeddings: usize,
+    output_grad: Tensor<B, 3>,
+    indices: Tensor<B, 2, Int>,
+) -> SparseTensor<B, 2> {
+    let [batch_size, seq_length, d_model] = output_grad.dims();
+    let length = batch_size * seq_length;
+    let device = output_grad.device();
+
+    let (rows, inverse, _) = indices.unique_with_inverse_and_counts();
+    let num_rows = rows.dims()[0];
+    let nnz = num_rows * d_model;
+
+    let values = Tensor::zeros_device([num_rows, d_model], &device)
+        .select_assign(
+            0,
+            inverse.reshape([length]),
+            output_grad.reshape([length, d_model]),
+        )
+        .reshape([nnz]);
+    let rows = rows
+        .reshape([num_rows, 1])
+        .expand([num_rows, d_model])
+        .reshape([nnz, 1]);
+    let columns = Tensor::<B, 1, Int>::arange_device(0..d_model, &device)
+        .reshape([1, d_model])
+        .expand([num_rows, d_model])
+        .reshape([nnz, 1]);
+
+    SparseTensor::new(
+        Tensor::cat(alloc::vec![rows, columns], 1),
+        values,
+        [num_embeddings, d_model],
+    )
+}
@@ -18,4 +18,15 @@ mod tests {
         data_expected.assert_approx_eq(&data_actual, 2); // Low precision to allow approximation
                                                          // implementation using tanh
     }
+
+    #[test]
+    fn test_gelu_tanh() {
+        let data = Data::from([[-4.0, -1.0, 0.0, 2.0, 5.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = activation::gelu_tanh(tensor).to_data();
+
+        let data_expected = Data::from([[-0.0001, -0.1588, 0.0, 1.9546, 5.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
 }
@@ -0,0 +1,16 @@
+#[burn_tensor_testgen::testgen(hard_sigmoid)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Data, Tensor};
+
+    #[test]
+    fn test_hard_sigmoid() {
+        let data = Data::from([[-4.0, -1.0, 0.0, 2.0, 5.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = activation::hard_sigmoid(tensor).to_data();
+
+        let data_expected = Data::from([[0.0, 0.3333, 0.5, 0.8333, 1.0]]);
+        data_actual.assert_approx_eq(&data_expected, 4);
+    }
+}
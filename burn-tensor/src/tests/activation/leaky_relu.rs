@@ -0,0 +1,16 @@
+#[burn_tensor_testgen::testgen(leaky_relu)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Data, Tensor};
+
+    #[test]
+    fn test_leaky_relu_d2() {
+        let data = Data::from([[0.0, -1.0, 2.0], [3.0, -4.0, 5.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = activation::leaky_relu(tensor, 0.01).to_data();
+
+        let data_expected = Data::from([[0.0, -0.01, 2.0], [3.0, -0.04, 5.0]]);
+        data_expected.assert_approx_eq(&data_actual, 4);
+    }
+}
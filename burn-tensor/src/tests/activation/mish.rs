@@ -0,0 +1,16 @@
+#[burn_tensor_testgen::testgen(mish)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Data, Tensor};
+
+    #[test]
+    fn test_mish() {
+        let data = Data::from([[-4.0, -1.0, 0.0, 2.0, 5.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = activation::mish(tensor).to_data();
+
+        let data_expected = Data::from([[-0.0726, -0.3034, 0.0, 1.944, 4.9996]]);
+        data_actual.assert_approx_eq(&data_expected, 4);
+    }
+}
@@ -1,5 +1,10 @@
 pub(crate) mod gelu;
+pub(crate) mod hard_sigmoid;
+pub(crate) mod hard_swish;
+pub(crate) mod leaky_relu;
+pub(crate) mod mish;
 pub(crate) mod relu;
 pub(crate) mod sigmoid;
 pub(crate) mod silu;
 pub(crate) mod softmax;
+pub(crate) mod softplus;
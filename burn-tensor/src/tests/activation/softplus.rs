@@ -0,0 +1,16 @@
+#[burn_tensor_testgen::testgen(softplus)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Data, Tensor};
+
+    #[test]
+    fn test_softplus() {
+        let data = Data::from([[-4.0, -1.0, 0.5, 2.0, 5.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = activation::softplus(tensor).to_data();
+
+        let data_expected = Data::from([[0.0181, 0.3133, 0.9741, 2.1269, 5.0067]]);
+        data_actual.assert_approx_eq(&data_expected, 4);
+    }
+}
@@ -10,14 +10,20 @@ macro_rules! testgen_all {
         // test activation
         burn_tensor::testgen_gelu!();
         burn_tensor::testgen_relu!();
+        burn_tensor::testgen_leaky_relu!();
         burn_tensor::testgen_softmax!();
         burn_tensor::testgen_sigmoid!();
         burn_tensor::testgen_silu!();
+        burn_tensor::testgen_mish!();
+        burn_tensor::testgen_softplus!();
+        burn_tensor::testgen_hard_sigmoid!();
+        burn_tensor::testgen_hard_swish!();
 
         // test module
         burn_tensor::testgen_module_forward!();
         burn_tensor::testgen_module_conv1d!();
         burn_tensor::testgen_module_conv2d!();
+        burn_tensor::testgen_module_conv3d!();
         burn_tensor::testgen_module_conv_transpose1d!();
         burn_tensor::testgen_module_conv_transpose2d!();
         burn_tensor::testgen_module_max_pool1d!();
@@ -26,23 +32,43 @@ macro_rules! testgen_all {
         burn_tensor::testgen_module_avg_pool2d!();
         burn_tensor::testgen_module_adaptive_avg_pool1d!();
         burn_tensor::testgen_module_adaptive_avg_pool2d!();
+        burn_tensor::testgen_module_grid_sample!();
 
         // test ops
         burn_tensor::testgen_add!();
         burn_tensor::testgen_aggregation!();
+        burn_tensor::testgen_any_all!();
         burn_tensor::testgen_arange!();
         burn_tensor::testgen_arange_step!();
         burn_tensor::testgen_arg!();
+        burn_tensor::testgen_assign!();
+        burn_tensor::testgen_bitwise!();
         burn_tensor::testgen_cast!();
         burn_tensor::testgen_cat!();
+        burn_tensor::testgen_chunk!();
         burn_tensor::testgen_clamp!();
+        burn_tensor::testgen_complex!();
         burn_tensor::testgen_cos!();
+        burn_tensor::testgen_atan!();
+        burn_tensor::testgen_atan2!();
+        burn_tensor::testgen_tan!();
+        burn_tensor::testgen_asin!();
+        burn_tensor::testgen_acos!();
+        burn_tensor::testgen_sinh!();
+        burn_tensor::testgen_cosh!();
+        burn_tensor::testgen_erfc!();
+        burn_tensor::testgen_lgamma!();
         burn_tensor::testgen_div!();
+        burn_tensor::testgen_einsum!();
         burn_tensor::testgen_erf!();
         burn_tensor::testgen_exp!();
+        burn_tensor::testgen_expand!();
         burn_tensor::testgen_flatten!();
         burn_tensor::testgen_full!();
         burn_tensor::testgen_gather_scatter!();
+        burn_tensor::testgen_grid!();
+        burn_tensor::testgen_index!();
+        burn_tensor::testgen_linalg!();
         burn_tensor::testgen_log!();
         burn_tensor::testgen_log1p!();
         burn_tensor::testgen_map_comparison!();
@@ -50,19 +76,32 @@ macro_rules! testgen_all {
         burn_tensor::testgen_matmul!();
         burn_tensor::testgen_maxmin!();
         burn_tensor::testgen_mul!();
+        burn_tensor::testgen_nan_inf!();
         burn_tensor::testgen_neg!();
+        burn_tensor::testgen_one_hot!();
+        burn_tensor::testgen_pad!();
         burn_tensor::testgen_powf!();
+        burn_tensor::testgen_quantization!();
         burn_tensor::testgen_random!();
         burn_tensor::testgen_repeat!();
+        burn_tensor::testgen_repeat_interleave!();
         burn_tensor::testgen_reshape!();
+        burn_tensor::testgen_flip!();
+        burn_tensor::testgen_roll!();
         burn_tensor::testgen_select!();
         burn_tensor::testgen_sin!();
         burn_tensor::testgen_slice!();
+        burn_tensor::testgen_sparse!();
         burn_tensor::testgen_sqrt!();
         burn_tensor::testgen_abs!();
         burn_tensor::testgen_squeeze!();
         burn_tensor::testgen_sub!();
         burn_tensor::testgen_tanh!();
+        burn_tensor::testgen_topk!();
+        burn_tensor::testgen_diagonal!();
+        burn_tensor::testgen_fft!();
+        burn_tensor::testgen_triangular!();
+        burn_tensor::testgen_unique!();
         burn_tensor::testgen_transpose!();
 
         // test stats
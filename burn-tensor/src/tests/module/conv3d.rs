@@ -0,0 +1,140 @@
+#[burn_tensor_testgen::testgen(module_conv3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::conv3d;
+    use burn_tensor::ops::ConvOptions;
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn test_conv3d_simple() {
+        let test = Conv3dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size: [2, 2, 2],
+            padding: [0, 0, 0],
+            stride: [1, 1, 1],
+            dilation: [1, 1, 1],
+            groups: 1,
+            size: [3, 3, 3],
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [
+                [[3436., 3556.], [3796., 3916.]],
+                [[4516., 4636.], [4876., 4996.]],
+            ],
+            [
+                [[8557., 8933.], [9685., 10061.]],
+                [[11941., 12317.], [13069., 13445.]],
+            ],
+        ]]));
+    }
+
+    #[test]
+    fn test_conv3d_groups() {
+        let test = Conv3dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size: [2, 2, 2],
+            padding: [1, 0, 0],
+            stride: [2, 1, 1],
+            dilation: [1, 1, 1],
+            groups: 2,
+            size: [3, 3, 3],
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [[[51., 73.], [117., 139.]], [[520., 548.], [604., 632.]]],
+            [
+                [[1574., 1628.], [1736., 1790.]],
+                [[3997., 4089.], [4273., 4365.]],
+            ],
+        ]]));
+    }
+
+    #[test]
+    fn test_conv3d_complex() {
+        let test = Conv3dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 2,
+            kernel_size: [2, 1, 2],
+            padding: [1, 0, 0],
+            stride: [1, 2, 1],
+            dilation: [2, 1, 1],
+            groups: 1,
+            size: [3, 3, 4],
+        };
+
+        test.assert_output(TestTensor::from_floats([[
+            [
+                [[694., 712., 730.], [838., 856., 874.]],
+                [[1240., 1268., 1296.], [1464., 1492., 1520.]],
+                [[450., 460., 470.], [530., 540., 550.]],
+            ],
+            [
+                [[1671., 1721., 1771.], [2071., 2121., 2171.]],
+                [[3193., 3285., 3377.], [3929., 4021., 4113.]],
+                [[1427., 1469., 1511.], [1763., 1805., 1847.]],
+            ],
+        ]]));
+    }
+
+    struct Conv3dTestCase {
+        batch_size: usize,
+        channels_in: usize,
+        channels_out: usize,
+        kernel_size: [usize; 3],
+        padding: [usize; 3],
+        stride: [usize; 3],
+        dilation: [usize; 3],
+        groups: usize,
+        size: [usize; 3],
+    }
+
+    impl Conv3dTestCase {
+        fn assert_output(self, y: TestTensor<5>) {
+            let shape_x = Shape::new([
+                self.batch_size,
+                self.channels_in,
+                self.size[0],
+                self.size[1],
+                self.size[2],
+            ]);
+            let shape_weight = Shape::new([
+                self.channels_out,
+                self.channels_in / self.groups,
+                self.kernel_size[0],
+                self.kernel_size[1],
+                self.kernel_size[2],
+            ]);
+            let weight = TestTensor::from_data(
+                TestTensorInt::arange(0..shape_weight.num_elements())
+                    .reshape(shape_weight)
+                    .into_data()
+                    .convert(),
+            );
+            let bias = TestTensor::from_data(
+                TestTensorInt::arange(0..self.channels_out)
+                    .into_data()
+                    .convert(),
+            );
+            let x = TestTensor::from_data(
+                TestTensorInt::arange(0..shape_x.num_elements())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            );
+            let output = conv3d(
+                x,
+                weight,
+                Some(bias),
+                ConvOptions::new(self.stride, self.padding, self.dilation, self.groups),
+            );
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+        }
+    }
+}
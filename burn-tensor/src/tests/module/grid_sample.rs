@@ -0,0 +1,144 @@
+#[burn_tensor_testgen::testgen(module_grid_sample)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::grid_sample_2d;
+    use burn_tensor::ops::{GridSampleMode, GridSampleOptions, GridSamplePaddingMode};
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn test_grid_sample_bilinear_zeros_align_corners() {
+        let output = sample(
+            grid(),
+            GridSampleMode::Bilinear,
+            GridSamplePaddingMode::Zeros,
+            true,
+        );
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[0.0, 8.0], [4.0, 3.0], [5.0, 3.12]]]]), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_bilinear_zeros() {
+        let output = sample(
+            grid(),
+            GridSampleMode::Bilinear,
+            GridSamplePaddingMode::Zeros,
+            false,
+        );
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[0.0, 2.0], [4.0, 2.5], [2.5, 0.87]]]]), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_bilinear_border() {
+        let output = sample(
+            grid(),
+            GridSampleMode::Bilinear,
+            GridSamplePaddingMode::Border,
+            false,
+        );
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[0.0, 8.0], [4.0, 2.5], [5.0, 4.35]]]]), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_nearest_zeros() {
+        let output = sample(
+            grid_nearest(),
+            GridSampleMode::Nearest,
+            GridSamplePaddingMode::Zeros,
+            false,
+        );
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[0.0, 8.0], [4.0, 2.0], [0.0, 0.0]]]]), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_nearest_border_align_corners() {
+        let output = sample(
+            grid_nearest(),
+            GridSampleMode::Nearest,
+            GridSamplePaddingMode::Border,
+            true,
+        );
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[0.0, 8.0], [4.0, 1.0], [5.0, 3.0]]]]), 3);
+    }
+
+    #[test]
+    fn test_grid_sample_batches_and_channels() {
+        let x = TestTensor::from_floats([
+            [
+                [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0], [6.0, 7.0, 8.0]],
+                [[10.0, 11.0, 12.0], [13.0, 14.0, 15.0], [16.0, 17.0, 18.0]],
+            ],
+            [
+                [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0], [6.0, 7.0, 8.0]],
+                [[0.0, 2.0, 4.0], [6.0, 8.0, 10.0], [12.0, 14.0, 16.0]],
+            ],
+        ]);
+        let grid =
+            TestTensor::from_floats([[[[0.0, 0.0], [0.5, -0.5]]], [[[1.0, 1.0], [-1.0, -1.0]]]]);
+        let options =
+            GridSampleOptions::new(GridSampleMode::Bilinear, GridSamplePaddingMode::Zeros, true);
+
+        let output = grid_sample_2d(x, grid, options);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([
+                [[[4.0, 3.0]], [[14.0, 13.0]]],
+                [[[8.0, 0.0]], [[16.0, 0.0]]],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_grid_sample_invalid_grid() {
+        let x = TestTensor::zeros([1, 1, 3, 3]);
+        let grid = TestTensor::zeros([1, 2, 2, 3]);
+        let options =
+            GridSampleOptions::new(GridSampleMode::Bilinear, GridSamplePaddingMode::Zeros, true);
+
+        grid_sample_2d(x, grid, options);
+    }
+
+    fn sample(
+        grid: Tensor<TestBackend, 4>,
+        mode: GridSampleMode,
+        padding_mode: GridSamplePaddingMode,
+        align_corners: bool,
+    ) -> Tensor<TestBackend, 4> {
+        let x = TestTensor::from_floats([[[[0.0, 1.0, 2.0], [3.0, 4.0, 5.0], [6.0, 7.0, 8.0]]]]);
+        let options = GridSampleOptions::new(mode, padding_mode, align_corners);
+
+        grid_sample_2d(x, grid, options)
+    }
+
+    fn grid() -> Tensor<TestBackend, 4> {
+        TestTensor::from_floats([[
+            [[-1.0, -1.0], [1.0, 1.0]],
+            [[0.0, 0.0], [0.5, -0.5]],
+            [[1.0, 0.0], [-1.2, 0.3]],
+        ]])
+    }
+
+    fn grid_nearest() -> Tensor<TestBackend, 4> {
+        TestTensor::from_floats([[
+            [[-0.9, -0.8], [0.9, 0.7]],
+            [[0.1, 0.2], [0.4, -0.6]],
+            [[1.3, 0.0], [-1.2, 0.3]],
+        ]])
+    }
+}
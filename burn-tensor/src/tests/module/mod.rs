@@ -4,8 +4,10 @@ mod avgpool1d;
 mod avgpool2d;
 mod conv1d;
 mod conv2d;
+mod conv3d;
 mod conv_transpose1d;
 mod conv_transpose2d;
 mod forward;
+mod grid_sample;
 mod maxpool1d;
 mod maxpool2d;
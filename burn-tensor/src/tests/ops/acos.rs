@@ -0,0 +1,20 @@
+#[burn_tensor_testgen::testgen(acos)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+    use core::f32::consts::{FRAC_PI_2, FRAC_PI_3};
+
+    #[test]
+    fn should_support_acos_ops() {
+        let data = Data::from([[0.0, 0.5, -0.5], [0.25, -0.75, 1.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.acos().into_data();
+
+        let data_expected = Data::from([
+            [FRAC_PI_2, FRAC_PI_3, 2.0 * FRAC_PI_3],
+            [1.3181, 2.4189, 0.0],
+        ]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+}
@@ -0,0 +1,53 @@
+#[burn_tensor_testgen::testgen(any_all)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Bool, Data, Tensor};
+
+    fn bool_tensor() -> Tensor<TestBackend, 2, Bool> {
+        Tensor::from_bool(Data::from([[true, false, true], [false, false, true]]))
+    }
+
+    #[test]
+    fn should_support_any_all_bool() {
+        assert_eq!(bool_tensor().any().into_data(), Data::from([true]));
+        assert_eq!(bool_tensor().all().into_data(), Data::from([false]));
+        assert_eq!(
+            bool_tensor().any_dim(1).into_data(),
+            Data::from([[true], [true]])
+        );
+        assert_eq!(
+            bool_tensor().all_dim(0).into_data(),
+            Data::from([[false, false, true]])
+        );
+    }
+
+    #[test]
+    fn should_support_count_nonzero_bool() {
+        assert_eq!(bool_tensor().count_nonzero().into_data(), Data::from([3]));
+        assert_eq!(
+            bool_tensor().count_nonzero_dim(1).into_data(),
+            Data::from([[2], [1]])
+        );
+    }
+
+    #[test]
+    fn should_support_any_all_int() {
+        let tensor = TestTensorInt::from_data([[0, -2, 3], [0, 0, 0]]);
+
+        assert_eq!(tensor.clone().any().into_data(), Data::from([true]));
+        assert_eq!(tensor.clone().all().into_data(), Data::from([false]));
+        assert_eq!(
+            tensor.clone().any_dim(1).into_data(),
+            Data::from([[true], [false]])
+        );
+        assert_eq!(
+            tensor.clone().all_dim(1).into_data(),
+            Data::from([[false], [false]])
+        );
+        assert_eq!(tensor.clone().count_nonzero().into_data(), Data::from([2]));
+        assert_eq!(
+            tensor.count_nonzero_dim(0).into_data(),
+            Data::from([[0, 1, 1]])
+        );
+    }
+}
@@ -0,0 +1,18 @@
+#[burn_tensor_testgen::testgen(asin)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+    use core::f32::consts::{FRAC_PI_2, FRAC_PI_6};
+
+    #[test]
+    fn should_support_asin_ops() {
+        let data = Data::from([[0.0, 0.5, -0.5], [0.25, -0.75, 1.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.asin().into_data();
+
+        let data_expected =
+            Data::from([[0.0, FRAC_PI_6, -FRAC_PI_6], [0.2527, -0.8481, FRAC_PI_2]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+}
@@ -0,0 +1,56 @@
+#[burn_tensor_testgen::testgen(assign)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_support_assign_ops() {
+        let mut tensor = TestTensor::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+        let other = TestTensor::from_floats([[1.0, 1.0, 1.0], [2.0, 2.0, 2.0]]);
+
+        tensor.add_assign(other.clone());
+        tensor.mul_assign(other.clone());
+        tensor.sub_assign(TestTensor::from_floats([[1.0, 0.0, -1.0]]));
+        tensor.div_assign(other);
+
+        let data_expected = Data::from([[0.0, 2.0, 4.0], [4.5, 6.0, 7.5]]);
+        assert_eq!(data_expected, tensor.into_data());
+    }
+
+    #[test]
+    fn should_support_scalar_assign_ops() {
+        let mut tensor = TestTensor::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        tensor.add_scalar_assign(1.0);
+        tensor.mul_scalar_assign(4.0);
+        tensor.sub_scalar_assign(2.0);
+        tensor.div_scalar_assign(2.0);
+        tensor.clamp_assign(2.0, 10.0);
+
+        let data_expected = Data::from([[2.0, 3.0, 5.0], [7.0, 9.0, 10.0]]);
+        assert_eq!(data_expected, tensor.into_data());
+    }
+
+    #[test]
+    fn should_support_int_clamp_assign_ops() {
+        let mut tensor = Tensor::<TestBackend, 1, Int>::from_ints([-5, 0, 5, 10]);
+
+        tensor.clamp_min_assign(-1);
+        tensor.clamp_max_assign(6);
+
+        let data_expected = Data::<i64, 1>::from([-1, 0, 5, 6]).convert();
+        assert_eq!(data_expected, tensor.into_data());
+    }
+
+    #[test]
+    fn should_not_modify_the_shared_tensors() {
+        let mut tensor = TestTensor::from_floats([1.0, 2.0, 3.0]);
+        let shared = tensor.clone();
+
+        tensor.mul_scalar_assign(2.0);
+        tensor.clamp_max_assign(5.0);
+
+        assert_eq!(tensor.into_data(), Data::from([2.0, 4.0, 5.0]));
+        assert_eq!(shared.into_data(), Data::from([1.0, 2.0, 3.0]));
+    }
+}
@@ -0,0 +1,17 @@
+#[burn_tensor_testgen::testgen(atan)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+    use core::f32::consts::FRAC_PI_4;
+
+    #[test]
+    fn should_support_atan_ops() {
+        let data = Data::from([[0.0, 1.0, 2.0], [-1.0, -4.0, 0.5]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.atan().into_data();
+
+        let data_expected = Data::from([[0.0, FRAC_PI_4, 1.1071], [-FRAC_PI_4, -1.3258, 0.4636]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+}
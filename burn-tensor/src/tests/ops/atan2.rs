@@ -0,0 +1,34 @@
+#[burn_tensor_testgen::testgen(atan2)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+    use core::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    #[test]
+    fn should_support_atan2_ops() {
+        let lhs = Tensor::<TestBackend, 2>::from_floats([[1.0, 1.0, -1.0], [-1.0, 0.0, 2.0]]);
+        let rhs = Tensor::<TestBackend, 2>::from_floats([[1.0, -1.0, -1.0], [1.0, -1.0, 0.5]]);
+
+        let data_actual = lhs.atan2(rhs).into_data();
+
+        let data_expected = Data::from([
+            [FRAC_PI_4, 3.0 * FRAC_PI_4, -3.0 * FRAC_PI_4],
+            [-FRAC_PI_4, PI, 1.3258],
+        ]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_atan2_ops_with_broadcast() {
+        let lhs = Tensor::<TestBackend, 2>::from_floats([[1.0], [-2.0]]);
+        let rhs = Tensor::<TestBackend, 2>::from_floats([[-1.0, 0.0, 1.0]]);
+
+        let data_actual = lhs.atan2(rhs).into_data();
+
+        let data_expected = Data::from([
+            [3.0 * FRAC_PI_4, FRAC_PI_2, FRAC_PI_4],
+            [-2.0344, -FRAC_PI_2, -1.1071],
+        ]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+}
@@ -0,0 +1,76 @@
+#[burn_tensor_testgen::testgen(bitwise)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_support_bitwise_and_or_xor() {
+        let lhs = Tensor::<TestBackend, 2, Int>::from_ints([[0b1100, 0b1010], [-1, 7]]);
+        let rhs = Tensor::<TestBackend, 2, Int>::from_ints([[0b1010, 0b0110], [5, 8]]);
+
+        let data_and = lhs.clone().bitwise_and(rhs.clone()).into_data();
+        let data_or = lhs.clone().bitwise_or(rhs.clone()).into_data();
+        let data_xor = lhs.bitwise_xor(rhs).into_data();
+
+        assert_eq!(
+            data_and,
+            Data::<i64, 2>::from([[0b1000, 0b0010], [5, 0]]).convert()
+        );
+        assert_eq!(
+            data_or,
+            Data::<i64, 2>::from([[0b1110, 0b1110], [-1, 15]]).convert()
+        );
+        assert_eq!(
+            data_xor,
+            Data::<i64, 2>::from([[0b0110, 0b1100], [-6, 15]]).convert()
+        );
+    }
+
+    #[test]
+    fn should_support_bitwise_not() {
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints([0, 1, -1, 5]);
+
+        let data_actual = tensor.bitwise_not().into_data();
+
+        assert_eq!(data_actual, Data::<i64, 1>::from([-1, -2, 0, -6]).convert());
+    }
+
+    #[test]
+    fn should_support_bitwise_shifts() {
+        let lhs = Tensor::<TestBackend, 1, Int>::from_ints([1, 3, -8, 255]);
+        let rhs = Tensor::<TestBackend, 1, Int>::from_ints([0, 2, 1, 4]);
+
+        let data_left = lhs.clone().bitwise_left_shift(rhs.clone()).into_data();
+        let data_right = lhs.bitwise_right_shift(rhs).into_data();
+
+        assert_eq!(
+            data_left,
+            Data::<i64, 1>::from([1, 12, -16, 4080]).convert()
+        );
+        assert_eq!(data_right, Data::<i64, 1>::from([1, 0, -4, 15]).convert());
+    }
+
+    #[test]
+    fn should_support_bitwise_ops_with_broadcast_and_scalars() {
+        let tensor = Tensor::<TestBackend, 2, Int>::from_ints([[1, 2, 3], [4, 5, 6]]);
+        let mask = Tensor::<TestBackend, 2, Int>::from_ints([[1], [4]]);
+
+        let data_and = tensor.clone().bitwise_and(mask).into_data();
+        let data_scalar = tensor
+            .bitwise_left_shift_scalar(4)
+            .bitwise_or_scalar(1)
+            .bitwise_right_shift_scalar(1)
+            .bitwise_xor_scalar(1)
+            .bitwise_and_scalar(0b11110)
+            .into_data();
+
+        assert_eq!(
+            data_and,
+            Data::<i64, 2>::from([[1, 0, 1], [4, 4, 4]]).convert()
+        );
+        assert_eq!(
+            data_scalar,
+            Data::<i64, 2>::from([[8, 16, 24], [0, 8, 16]]).convert()
+        );
+    }
+}
@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(cast)]
 mod tests {
     use super::*;
-    use burn_tensor::{Bool, Data, Int, Tensor};
+    use burn_tensor::{backend::Backend, Bool, DType, Data, Element, Float, Int, Tensor};
 
     #[test]
     fn cast_float_to_int() {
@@ -40,4 +40,72 @@ mod tests {
         let expected = Data::from([[1., 0., 1.], [0., 0., 1.]]);
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn cast_float_to_bool_tensor() {
+        let tensor =
+            Tensor::<TestBackend, 2>::from_floats([[0.0, 1.5, -2.0], [0.0, f32::NAN, 0.1]]);
+
+        let actual = tensor.bool().into_data();
+        let expected = Data::from([[false, true, true], [false, true, true]]);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn cast_int_to_bool_tensor() {
+        let tensor = Tensor::<TestBackend, 2, Int>::from_ints([[0, 1, -2], [3, 0, 0]]);
+
+        let actual = tensor.bool().into_data();
+        let expected = Data::from([[false, true, true], [true, false, false]]);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn cast_between_kinds() {
+        let tensor = Tensor::<TestBackend, 1>::from_floats([0.0, 1.5, -2.7]);
+
+        let actual_int = tensor.clone().cast::<Int>();
+        let actual_bool = actual_int.clone().cast::<Bool>();
+        let actual_float = actual_bool.clone().cast::<Int>().cast::<Float>();
+
+        assert_eq!(
+            actual_int.into_data(),
+            Data::<i64, 1>::from([0, 1, -2]).convert()
+        );
+        assert_eq!(actual_bool.into_data(), Data::from([false, true, true]));
+        assert_eq!(actual_float.into_data(), Data::from([0.0, 1.0, 1.0]));
+        assert_eq!(
+            tensor.clone().cast::<Float>().into_data(),
+            tensor.into_data()
+        );
+    }
+
+    #[test]
+    fn cast_float_precision() {
+        let tensor = Tensor::<TestBackend, 1>::from_floats([0.5, -1.25, 3.0]);
+
+        let actual = tensor.clone().cast_float::<TestBackend>();
+
+        assert_eq!(actual.into_data(), tensor.into_data());
+    }
+
+    #[test]
+    fn should_return_dtype_and_elem_size() {
+        type FloatElem = <TestBackend as Backend>::FloatElem;
+        type IntElem = <TestBackend as Backend>::IntElem;
+        let tensor = Tensor::<TestBackend, 1>::from_floats([1.0, 2.0]);
+
+        assert_eq!(tensor.dtype(), FloatElem::dtype());
+        assert!(tensor.dtype().is_float());
+        assert_eq!(tensor.elem_size(), core::mem::size_of::<FloatElem>());
+
+        let tensor = tensor.int();
+        assert_eq!(tensor.dtype(), IntElem::dtype());
+        assert!(tensor.dtype().is_int());
+        assert_eq!(tensor.elem_size(), core::mem::size_of::<IntElem>());
+
+        let tensor = tensor.bool();
+        assert_eq!(tensor.dtype(), DType::Bool);
+        assert_eq!(tensor.elem_size(), 1);
+    }
 }
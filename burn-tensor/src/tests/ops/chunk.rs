@@ -0,0 +1,56 @@
+#[burn_tensor_testgen::testgen(chunk)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_chunk_with_smaller_last_chunk() {
+        let tensor = TestTensor::from_data([[0.0, 1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0, 9.0]]);
+
+        let chunks = tensor.chunk(3, 1);
+
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[0].to_data(), Data::from([[0.0, 1.0], [5.0, 6.0]]));
+        assert_eq!(chunks[1].to_data(), Data::from([[2.0, 3.0], [7.0, 8.0]]));
+        assert_eq!(chunks[2].to_data(), Data::from([[4.0], [9.0]]));
+    }
+
+    #[test]
+    fn should_return_fewer_chunks_than_requested() {
+        let tensor = Tensor::<TestBackend, 1, Int>::arange(0..4);
+
+        let chunks = tensor.chunk(3, 0);
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].to_data(), Data::from([0, 1]));
+        assert_eq!(chunks[1].to_data(), Data::from([2, 3]));
+    }
+
+    #[test]
+    fn should_split_with_sizes() {
+        let tensor = TestTensor::from_data([[0.0, 1.0], [2.0, 3.0], [4.0, 5.0]]);
+
+        let parts = tensor.split(&[1, 0, 2], 0);
+
+        assert_eq!(parts.len(), 3);
+        assert_eq!(parts[0].to_data(), Data::from([[0.0, 1.0]]));
+        assert_eq!(parts[1].dims(), [0, 2]);
+        assert_eq!(parts[2].to_data(), Data::from([[2.0, 3.0], [4.0, 5.0]]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_split_sizes_dont_match() {
+        let tensor = TestTensor::from_data([[0.0, 1.0], [2.0, 3.0], [4.0, 5.0]]);
+
+        tensor.split(&[1, 1], 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_chunking_zero_chunks() {
+        let tensor = TestTensor::from_data([[0.0, 1.0], [2.0, 3.0]]);
+
+        tensor.chunk(0, 0);
+    }
+}
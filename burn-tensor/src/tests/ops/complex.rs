@@ -0,0 +1,167 @@
+#[burn_tensor_testgen::testgen(complex)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Complex, Complex32, ComplexArithmetic, DType, Data, Tensor};
+    use core::f32::consts::{FRAC_PI_2, PI, SQRT_2};
+
+    fn complex(real: [f32; 4], imag: [f32; 4]) -> Tensor<TestBackend, 1, Complex> {
+        Tensor::from_parts(
+            TestTensor::from_data(Data::from(real)),
+            TestTensor::from_data(Data::from(imag)),
+        )
+    }
+
+    #[test]
+    fn should_support_complex_data() {
+        let data = Data::from([[Complex32::new(1.0, 2.0), Complex32::new(-3.0, 0.5)]]);
+        let tensor = Tensor::<TestBackend, 2, Complex>::from_data(data.clone());
+
+        let (real, imag) = tensor.clone().into_parts();
+        let data_actual = tensor.reshape([2]).into_data();
+
+        real.into_data()
+            .assert_approx_eq(&Data::from([[1.0, -3.0]]), 3);
+        imag.into_data()
+            .assert_approx_eq(&Data::from([[2.0, 0.5]]), 3);
+        assert_eq!(data_actual.value, data.value);
+    }
+
+    #[test]
+    fn should_support_complex_arithmetic() {
+        let lhs = complex([1.0, 2.0, 0.0, -1.0], [2.0, -1.0, 1.0, 0.0]);
+        let rhs = complex([3.0, 1.0, 0.0, 2.0], [-1.0, 1.0, 1.0, 2.0]);
+
+        let (real_add, imag_add) = lhs.clone().add(rhs.clone()).into_parts();
+        let (real_sub, imag_sub) = lhs.clone().sub(rhs.clone()).into_parts();
+        let (real_mul, imag_mul) = lhs.clone().mul(rhs.clone()).into_parts();
+        let (real_div, imag_div) = lhs.div(rhs).into_parts();
+
+        real_add
+            .into_data()
+            .assert_approx_eq(&Data::from([4.0, 3.0, 0.0, 1.0]), 3);
+        imag_add
+            .into_data()
+            .assert_approx_eq(&Data::from([1.0, 0.0, 2.0, 2.0]), 3);
+        real_sub
+            .into_data()
+            .assert_approx_eq(&Data::from([-2.0, 1.0, 0.0, -3.0]), 3);
+        imag_sub
+            .into_data()
+            .assert_approx_eq(&Data::from([3.0, -2.0, 0.0, -2.0]), 3);
+        real_mul
+            .into_data()
+            .assert_approx_eq(&Data::from([5.0, 3.0, -1.0, -2.0]), 3);
+        imag_mul
+            .into_data()
+            .assert_approx_eq(&Data::from([5.0, 1.0, 0.0, -2.0]), 3);
+        real_div
+            .into_data()
+            .assert_approx_eq(&Data::from([0.1, 0.5, 1.0, -0.25]), 3);
+        imag_div
+            .into_data()
+            .assert_approx_eq(&Data::from([0.7, -1.5, 0.0, 0.25]), 3);
+    }
+
+    #[test]
+    fn should_support_complex_scalar_and_negation() {
+        let tensor = complex([1.0, -2.0, 0.0, 3.0], [0.5, 1.0, -1.0, 0.0]);
+
+        let (real, imag) = tensor.mul_scalar(2.0).neg().into_parts();
+
+        real.into_data()
+            .assert_approx_eq(&Data::from([-2.0, 4.0, 0.0, -6.0]), 3);
+        imag.into_data()
+            .assert_approx_eq(&Data::from([-1.0, -2.0, 2.0, 0.0]), 3);
+    }
+
+    #[test]
+    fn should_support_conj_abs_and_angle() {
+        let tensor = complex([3.0, -1.0, -1.0, 0.0], [4.0, 1.0, -1.0, 0.0]);
+
+        let conj = tensor.clone().conj();
+        let abs = tensor.clone().abs();
+        let angle = tensor.angle();
+
+        conj.clone()
+            .real()
+            .into_data()
+            .assert_approx_eq(&Data::from([3.0, -1.0, -1.0, 0.0]), 3);
+        conj.imag()
+            .into_data()
+            .assert_approx_eq(&Data::from([-4.0, -1.0, 1.0, 0.0]), 3);
+        abs.into_data()
+            .assert_approx_eq(&Data::from([5.0, SQRT_2, SQRT_2, 0.0]), 3);
+        angle
+            .into_data()
+            .assert_approx_eq(&Data::from([0.9273, 2.3562, -2.3562, 0.0]), 3);
+    }
+
+    #[test]
+    fn should_support_angle_on_the_axes() {
+        let tensor = complex([0.0, 0.0, -2.0, 2.0], [1.0, -1.0, 0.0, 0.0]);
+
+        let angle = tensor.angle();
+
+        angle
+            .into_data()
+            .assert_approx_eq(&Data::from([FRAC_PI_2, -FRAC_PI_2, PI, 0.0]), 3);
+    }
+
+    #[test]
+    fn should_support_from_polar() {
+        let abs = TestTensor::from_data(Data::from([2.0, 1.0]));
+        let angle = TestTensor::from_data(Data::from([0.0, FRAC_PI_2]));
+
+        let tensor = Tensor::<TestBackend, 1, Complex>::from_polar(abs, angle);
+
+        tensor
+            .clone()
+            .real()
+            .into_data()
+            .assert_approx_eq(&Data::from([2.0, 0.0]), 3);
+        tensor
+            .imag()
+            .into_data()
+            .assert_approx_eq(&Data::from([0.0, 1.0]), 3);
+    }
+
+    #[test]
+    fn should_support_complex_cat_and_slice() {
+        let lhs = complex([1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0]);
+        let rhs =
+            Tensor::<TestBackend, 1, Complex>::from_real(TestTensor::from_data(Data::from([9.0])));
+
+        let tensor = Tensor::cat(vec![lhs, rhs], 0).slice([3..5]);
+
+        tensor
+            .clone()
+            .real()
+            .into_data()
+            .assert_approx_eq(&Data::from([4.0, 9.0]), 3);
+        tensor
+            .imag()
+            .into_data()
+            .assert_approx_eq(&Data::from([8.0, 0.0]), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_the_parts_have_different_shapes() {
+        let real = TestTensor::from_data(Data::from([1.0, 2.0]));
+        let imag = TestTensor::from_data(Data::from([1.0]));
+
+        let _tensor = Tensor::<TestBackend, 1, Complex>::from_parts(real, imag);
+    }
+
+    #[test]
+    fn should_return_complex_dtype() {
+        let tensor = complex([1.0, 2.0, 3.0, 4.0], [0.0, 1.0, 0.0, -1.0]);
+        let float_dtype = tensor.clone().real().dtype();
+
+        assert!(tensor.dtype().is_complex());
+        assert_eq!(tensor.elem_size(), 2 * float_dtype.size());
+        if float_dtype == DType::F32 {
+            assert_eq!(tensor.dtype(), DType::ComplexF32);
+        }
+    }
+}
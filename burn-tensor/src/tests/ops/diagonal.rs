@@ -0,0 +1,69 @@
+#[burn_tensor_testgen::testgen(diagonal)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_support_diagonal() {
+        let tensor = TestTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+
+        let main: Tensor<TestBackend, 1> = tensor.clone().diagonal(0);
+        let above: Tensor<TestBackend, 1> = tensor.clone().diagonal(1);
+        let below: Tensor<TestBackend, 1> = tensor.diagonal(-2);
+
+        assert_eq!(main.into_data(), Data::from([1.0, 5.0, 9.0]));
+        assert_eq!(above.into_data(), Data::from([2.0, 6.0]));
+        assert_eq!(below.into_data(), Data::from([7.0]));
+    }
+
+    #[test]
+    fn should_support_batched_diagonal() {
+        let tensor = Tensor::<TestBackend, 1, Int>::arange(0..12).reshape([2, 2, 3]);
+
+        let diagonal: Tensor<TestBackend, 2, Int> = tensor.diagonal(0);
+
+        assert_eq!(diagonal.into_data(), Data::from([[0, 4], [6, 10]]));
+    }
+
+    #[test]
+    fn should_support_diag_embed() {
+        let tensor = TestTensorInt::from_data([[1, 2], [3, 4]]);
+
+        let matrices: Tensor<TestBackend, 3, Int> = tensor.clone().diag_embed(0);
+        let below: Tensor<TestBackend, 3, Int> = tensor.diag_embed(-1);
+
+        assert_eq!(
+            matrices.into_data(),
+            Data::from([[[1, 0], [0, 2]], [[3, 0], [0, 4]]])
+        );
+        assert_eq!(
+            below.into_data(),
+            Data::from([
+                [[0, 0, 0], [1, 0, 0], [0, 2, 0]],
+                [[0, 0, 0], [3, 0, 0], [0, 4, 0]]
+            ])
+        );
+    }
+
+    #[test]
+    fn should_support_diag() {
+        let vector = TestTensor::from_data([1.0, 2.0]);
+
+        let matrix = vector.diag(1);
+        let diagonal = matrix.clone().diag(1);
+
+        assert_eq!(
+            matrix.into_data(),
+            Data::from([[0.0, 1.0, 0.0], [0.0, 0.0, 2.0], [0.0, 0.0, 0.0]])
+        );
+        assert_eq!(diagonal.into_data(), Data::from([1.0, 2.0]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_diagonal_rank_is_invalid() {
+        let tensor = TestTensor::from_data([[1.0, 2.0], [3.0, 4.0]]);
+
+        let _: Tensor<TestBackend, 2> = tensor.diagonal(0);
+    }
+}
@@ -0,0 +1,81 @@
+#[burn_tensor_testgen::testgen(einsum)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_attention_contraction() {
+        let query = Tensor::<TestBackend, 3>::from_data([
+            [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]],
+            [[-1.0, 0.0], [0.5, 2.0], [1.0, -3.0]],
+        ]);
+        let key = Tensor::<TestBackend, 3>::from_data([
+            [[1.0, 0.0], [0.0, 1.0]],
+            [[2.0, 1.0], [-1.0, 1.0]],
+        ]);
+
+        let scores: Tensor<TestBackend, 3> =
+            Tensor::einsum("bqd,bkd->bqk", query.clone(), key.clone());
+
+        let expected = query.matmul(key.swap_dims(1, 2));
+        assert_eq!(scores.into_data(), expected.into_data());
+    }
+
+    #[test]
+    fn should_support_matmul_with_permuted_output() {
+        let lhs = Tensor::<TestBackend, 2>::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let rhs = Tensor::<TestBackend, 2>::from_data([[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]]);
+
+        let output: Tensor<TestBackend, 2> = Tensor::einsum("ij,jk->ki", lhs, rhs);
+
+        let data_expected = Data::from([[4.0, 10.0], [5.0, 11.0]]);
+        assert_eq!(data_expected, output.into_data());
+    }
+
+    #[test]
+    fn should_support_outer_product() {
+        let lhs = Tensor::<TestBackend, 1>::from_data([1.0, 2.0]);
+        let rhs = Tensor::<TestBackend, 1>::from_data([3.0, 4.0, 5.0]);
+
+        let output: Tensor<TestBackend, 2> = Tensor::einsum("i,j->ij", lhs, rhs);
+
+        let data_expected = Data::from([[3.0, 4.0, 5.0], [6.0, 8.0, 10.0]]);
+        assert_eq!(data_expected, output.into_data());
+    }
+
+    #[test]
+    fn should_sum_the_dimensions_missing_from_the_output() {
+        let lhs = Tensor::<TestBackend, 3>::from_data([
+            [[1.0, 2.0], [3.0, 4.0]],
+            [[5.0, 6.0], [7.0, 8.0]],
+        ]);
+        let rhs = Tensor::<TestBackend, 2>::from_data([[1.0, -1.0], [2.0, 0.5]]);
+
+        // out[b, k] = sum_i sum_j lhs[b, i, j] * sum_l rhs[k, l]
+        let output: Tensor<TestBackend, 2> = Tensor::einsum("bij,kl->bk", lhs, rhs);
+
+        let data_expected = Data::from([[0.0, 25.0], [0.0, 65.0]]);
+        assert_eq!(data_expected, output.into_data());
+    }
+
+    #[test]
+    fn should_support_implicit_output() {
+        let lhs = Tensor::<TestBackend, 2>::from_data([[1.0, 2.0], [3.0, 4.0]]);
+        let rhs = Tensor::<TestBackend, 2>::from_data([[1.0, 1.0], [0.0, 2.0], [1.0, 0.0]]);
+
+        // The output labels are `ik`, the repeated label `j` is contracted
+        let output: Tensor<TestBackend, 2> = Tensor::einsum("ij,kj", lhs, rhs);
+
+        let data_expected = Data::from([[3.0, 4.0, 1.0], [7.0, 8.0, 3.0]]);
+        assert_eq!(data_expected, output.into_data());
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_the_sizes_of_a_label_differ() {
+        let lhs = Tensor::<TestBackend, 2>::ones([2, 3]);
+        let rhs = Tensor::<TestBackend, 2>::ones([4, 2]);
+
+        let _output: Tensor<TestBackend, 2> = Tensor::einsum("ij,jk->ik", lhs, rhs);
+    }
+}
@@ -0,0 +1,16 @@
+#[burn_tensor_testgen::testgen(erfc)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_erfc_ops() {
+        let data = Data::from([[0.0, 1.0, 2.0], [-1.0, -0.5, 3.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.erfc().into_data();
+
+        let data_expected = Data::from([[1.0, 0.1573, 0.0047], [1.8427, 1.5205, 0.0]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+}
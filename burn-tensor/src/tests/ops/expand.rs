@@ -0,0 +1,36 @@
+#[burn_tensor_testgen::testgen(expand)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_support_expand_ops() {
+        let tensor = Tensor::<TestBackend, 2>::from_data(Data::from([[0.0], [1.0]]));
+
+        let data_actual = tensor.expand([2, 2, 3]).into_data();
+
+        let data_expected = Data::from([
+            [[0.0, 0.0, 0.0], [1.0, 1.0, 1.0]],
+            [[0.0, 0.0, 0.0], [1.0, 1.0, 1.0]],
+        ]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_keep_the_size_of_the_tensor_when_the_shape_is_one() {
+        let tensor = Tensor::<TestBackend, 1, Int>::from_data(Data::from([1, 2, 3]));
+
+        let data_actual = tensor.expand([2, 1]).into_data();
+
+        let data_expected = Data::from([[1, 2, 3], [1, 2, 3]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_a_dimension_can_not_be_expanded() {
+        let tensor = Tensor::<TestBackend, 1>::from_data(Data::from([1.0, 2.0, 3.0]));
+
+        let _ = tensor.expand([2, 4]);
+    }
+}
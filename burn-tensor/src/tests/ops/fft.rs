@@ -0,0 +1,90 @@
+#[burn_tensor_testgen::testgen(fft)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_rfft() {
+        let tensor = TestTensor::from_data([1.0, 2.0, 3.0, 4.0]);
+
+        let (real, imag) = tensor.rfft(0);
+
+        real.into_data()
+            .assert_approx_eq(&Data::from([10.0, -2.0, -2.0]), 3);
+        imag.into_data()
+            .assert_approx_eq(&Data::from([0.0, 2.0, 0.0]), 3);
+    }
+
+    #[test]
+    fn should_support_rfft_along_first_dim() {
+        let tensor = TestTensor::from_data([[1.0, 2.0], [3.0, 5.0], [0.0, 1.0]]);
+
+        let (real, imag) = tensor.rfft(0);
+
+        real.into_data()
+            .assert_approx_eq(&Data::from([[4.0, 8.0], [-0.5, -1.0]]), 3);
+        imag.into_data()
+            .assert_approx_eq(&Data::from([[0.0, 0.0], [-2.598, -3.464]]), 3);
+    }
+
+    #[test]
+    fn should_support_irfft_with_odd_and_even_sizes() {
+        for size in [4, 5] {
+            let values = (0..size).map(|i| (i * i) as f32).collect::<Vec<_>>();
+            let tensor = Tensor::<TestBackend, 1>::from_data(Data::new(values, [size].into()));
+
+            let (real, imag) = tensor.clone().rfft(0);
+            let output = Tensor::irfft(real, imag, 0, size);
+
+            output.into_data().assert_approx_eq(&tensor.into_data(), 3);
+        }
+    }
+
+    #[test]
+    fn should_support_fft_and_ifft() {
+        let real = TestTensor::from_data([[1.0, 0.0], [2.0, 1.0]]);
+        let imag = TestTensor::from_data([[0.0, 1.0], [0.0, -1.0]]);
+
+        let (real_out, imag_out) = Tensor::fft(real.clone(), imag.clone(), 1);
+
+        real_out
+            .clone()
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 1.0], [3.0, 1.0]]), 3);
+        imag_out
+            .clone()
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, -1.0], [-1.0, 1.0]]), 3);
+
+        let (real_inv, imag_inv) = Tensor::ifft(real_out, imag_out, 1);
+
+        real_inv.into_data().assert_approx_eq(&real.into_data(), 3);
+        imag_inv.into_data().assert_approx_eq(&imag.into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_fft2() {
+        let real = TestTensor::from_data([[1.0, 2.0], [3.0, 4.0]]);
+        let imag = TestTensor::zeros([2, 2]);
+
+        let (real_out, imag_out) = Tensor::fft2(real.clone(), imag);
+        let (real_inv, _) = Tensor::ifft2(real_out.clone(), imag_out.clone());
+
+        real_out
+            .into_data()
+            .assert_approx_eq(&Data::from([[10.0, -2.0], [-4.0, 0.0]]), 3);
+        imag_out
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0, 0.0], [0.0, 0.0]]), 3);
+        real_inv.into_data().assert_approx_eq(&real.into_data(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_irfft_size_does_not_match() {
+        let real = TestTensor::from_data([1.0, 2.0, 3.0]);
+        let imag = TestTensor::zeros([3]);
+
+        Tensor::irfft(real, imag, 0, 6);
+    }
+}
@@ -0,0 +1,32 @@
+#[burn_tensor_testgen::testgen(flip)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_flip_dims() {
+        let tensor = TestTensor::from_data([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        let data_actual = tensor.flip(&[0, 1]).into_data();
+
+        let data_expected = Data::from([[5.0, 4.0, 3.0], [2.0, 1.0, 0.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_flip_int_dim() {
+        let tensor = Tensor::<TestBackend, 1, Int>::arange(0..4).reshape([2, 2]);
+
+        let data_actual = tensor.flip(&[0]).into_data();
+
+        assert_eq!(data_actual, Data::from([[2, 3], [0, 1]]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_flipping_invalid_dim() {
+        let tensor = TestTensor::from_data([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        tensor.flip(&[2]);
+    }
+}
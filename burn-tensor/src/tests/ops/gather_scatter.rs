@@ -143,4 +143,29 @@ mod tests {
             Data::from([[0.0, 1.0, 0.0], [0.0, 0.0, 4.0]])
         );
     }
+
+    #[test]
+    fn should_scatter_assign_2d_dim1() {
+        let tensor = TestTensor::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+        let values = TestTensor::from_floats([[6.0], [7.0]]);
+        let indices = TestTensorInt::from_ints([[1], [2]]);
+
+        let output = tensor.scatter_assign(1, indices, values);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[0.0, 6.0, 2.0], [3.0, 4.0, 7.0]])
+        );
+    }
+
+    #[test]
+    fn should_scatter_assign_2d_dim0_int() {
+        let tensor = TestTensorInt::from_ints([[1, 2, 3], [4, 5, 6]]);
+        let values = TestTensorInt::from_ints([[7, 8, 9]]);
+        let indices = TestTensorInt::from_ints([[1, 0, 1]]);
+
+        let output = tensor.scatter_assign(0, indices, values);
+
+        assert_eq!(output.into_data(), Data::from([[1, 8, 3], [7, 5, 9]]));
+    }
 }
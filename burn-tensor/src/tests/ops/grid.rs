@@ -0,0 +1,87 @@
+#[burn_tensor_testgen::testgen(grid)]
+mod tests {
+    use super::*;
+    use burn_tensor::{backend::Backend, Data, GridIndexing, Int, Tensor};
+
+    #[test]
+    fn should_create_float_arange_with_step() {
+        let device = <TestBackend as Backend>::Device::default();
+
+        let tensor = TestTensor::arange_step_device(0.0, 1.0, 0.25, &device);
+
+        tensor
+            .into_data()
+            .assert_approx_eq(&Data::from([0.0, 0.25, 0.5, 0.75]), 3);
+    }
+
+    #[test]
+    fn should_create_float_arange_with_negative_step() {
+        let tensor = TestTensor::arange_step(1.0, -0.5, -0.5);
+
+        tensor
+            .into_data()
+            .assert_approx_eq(&Data::from([1.0, 0.5, 0.0]), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_step_goes_away_from_end() {
+        TestTensor::arange_step(0.0, 1.0, -0.5);
+    }
+
+    #[test]
+    fn should_create_linspace() {
+        let tensor = TestTensor::linspace(-1.0, 1.0, 5);
+
+        tensor
+            .into_data()
+            .assert_approx_eq(&Data::from([-1.0, -0.5, 0.0, 0.5, 1.0]), 3);
+    }
+
+    #[test]
+    fn should_create_linspace_with_single_value() {
+        let tensor = TestTensor::linspace(2.0, 3.0, 1);
+
+        assert_eq!(tensor.into_data(), Data::from([2.0]));
+    }
+
+    #[test]
+    fn should_create_meshgrid_with_matrix_indexing() {
+        let x = TestTensor::from_floats([0.0, 1.0, 2.0]);
+        let y = TestTensor::from_floats([3.0, 4.0]);
+
+        let [grid_x, grid_y] = Tensor::meshgrid([x, y], GridIndexing::Matrix);
+
+        assert_eq!(
+            grid_x.into_data(),
+            Data::from([[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]])
+        );
+        assert_eq!(
+            grid_y.into_data(),
+            Data::from([[3.0, 4.0], [3.0, 4.0], [3.0, 4.0]])
+        );
+    }
+
+    #[test]
+    fn should_create_meshgrid_with_cartesian_indexing() {
+        let x = Tensor::<TestBackend, 1, Int>::from_ints([0, 1, 2]);
+        let y = Tensor::<TestBackend, 1, Int>::from_ints([3, 4]);
+        let z = Tensor::<TestBackend, 1, Int>::from_ints([5]);
+
+        let [grid_x, grid_y, grid_z] = Tensor::meshgrid([x, y, z], GridIndexing::Cartesian);
+
+        assert_eq!(grid_x.dims(), [2, 3, 1]);
+        assert_eq!(
+            grid_x.into_data(),
+            Data::from([[[0], [1], [2]], [[0], [1], [2]]])
+        );
+        assert_eq!(
+            grid_y.into_data(),
+            Data::from([[[3], [3], [3]], [[4], [4], [4]]])
+        );
+        assert_eq!(
+            grid_z.into_data(),
+            Data::from([[[5], [5], [5]], [[5], [5], [5]]])
+        );
+    }
+}
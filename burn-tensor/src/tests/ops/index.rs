@@ -0,0 +1,48 @@
+#[burn_tensor_testgen::testgen(index)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_index_select_2d_dim1() {
+        let tensor = TestTensor::from_data([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+        let indices = TestTensorInt::from_data([2, 0]);
+
+        let output = tensor.index_select(1, indices);
+
+        assert_eq!(output.into_data(), Data::from([[2.0, 0.0], [5.0, 3.0]]));
+    }
+
+    #[test]
+    fn should_index_2d_with_1d_indices() {
+        let tensor = TestTensor::from_data([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+        let rows = TestTensorInt::from_data([1, 0, 1]);
+        let columns = TestTensorInt::from_data([2, 1, 0]);
+
+        let output = tensor.index([rows, columns]);
+
+        assert_eq!(output.into_data(), Data::from([5.0, 1.0, 3.0]));
+    }
+
+    #[test]
+    fn should_index_3d_with_2d_indices() {
+        let tensor = TestTensorInt::arange(0..24).reshape([2, 3, 4]);
+        let batch = Tensor::<TestBackend, 2, Int>::from_data([[0, 1], [1, 0]]);
+        let rows = Tensor::<TestBackend, 2, Int>::from_data([[2, 0], [1, 1]]);
+        let columns = Tensor::<TestBackend, 2, Int>::from_data([[3, 1], [0, 2]]);
+
+        let output = tensor.index([batch, rows, columns]);
+
+        assert_eq!(output.into_data(), Data::from([[11, 13], [16, 6]]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_index_tensors_have_different_shapes() {
+        let tensor = TestTensor::from_data([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+        let rows = TestTensorInt::from_data([1, 0]);
+        let columns = TestTensorInt::from_data([2, 1, 0]);
+
+        tensor.index([rows, columns]);
+    }
+}
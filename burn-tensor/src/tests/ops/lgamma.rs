@@ -0,0 +1,27 @@
+#[burn_tensor_testgen::testgen(lgamma)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_lgamma_ops() {
+        let data = Data::from([[1.0, 2.0, 3.5], [0.5, 10.0, 20.0]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.lgamma().into_data();
+
+        let data_expected = Data::from([[0.0, 0.0, 1.2010], [0.5724, 12.8018, 39.3399]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn should_support_lgamma_ops_with_negative_number() {
+        let data = Data::from([-0.5, -1.5, -2.5]);
+        let tensor = Tensor::<TestBackend, 1>::from_data(data);
+
+        let data_actual = tensor.lgamma().into_data();
+
+        let data_expected = Data::from([1.2655, 0.8600, -0.0562]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+}
@@ -0,0 +1,189 @@
+#[burn_tensor_testgen::testgen(linalg)]
+mod tests {
+    use super::*;
+    use burn_tensor::{linalg, Data, Tensor};
+
+    #[test]
+    fn should_solve_linear_system() {
+        let lhs = TestTensor::from_data([[2.0, 1.0], [1.0, 3.0]]);
+        let rhs = TestTensor::from_data([[3.0], [5.0]]);
+
+        let solution = linalg::solve(lhs, rhs);
+
+        solution
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.8], [1.4]]), 3);
+    }
+
+    #[test]
+    fn should_solve_batched_linear_systems_with_pivoting() {
+        let lhs = TestTensor::from_data([[[0.0, 1.0], [1.0, 0.0]], [[4.0, 0.0], [0.0, 2.0]]]);
+        let rhs = TestTensor::from_data([[[2.0, 1.0], [3.0, 1.0]], [[8.0, 4.0], [2.0, 6.0]]]);
+
+        let solution = linalg::solve(lhs, rhs);
+
+        solution.into_data().assert_approx_eq(
+            &Data::from([[[3.0, 1.0], [2.0, 1.0]], [[2.0, 1.0], [1.0, 3.0]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_compute_inverse() {
+        let tensor = TestTensor::from_data([[2.0, 1.0], [1.0, 3.0]]);
+
+        let inverse = linalg::inverse(tensor);
+
+        inverse
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.6, -0.2], [-0.2, 0.4]]), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_the_matrix_is_not_square() {
+        let lhs = TestTensor::from_data([[2.0, 1.0, 0.0], [1.0, 3.0, 0.0]]);
+        let rhs = TestTensor::from_data([[3.0], [5.0]]);
+
+        linalg::solve(lhs, rhs);
+    }
+
+    #[test]
+    fn should_compute_cholesky() {
+        let tensor = TestTensor::from_data([[[4.0, 2.0], [2.0, 5.0]], [[9.0, 3.0], [3.0, 2.0]]]);
+
+        let factor = linalg::cholesky(tensor);
+
+        factor.into_data().assert_approx_eq(
+            &Data::from([[[2.0, 0.0], [1.0, 2.0]], [[3.0, 0.0], [1.0, 1.0]]]),
+            3,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_the_matrix_is_not_positive_definite() {
+        let tensor = TestTensor::from_data([[1.0, 2.0], [2.0, 1.0]]);
+
+        linalg::cholesky(tensor);
+    }
+
+    #[test]
+    fn should_compute_qr() {
+        let tensor = TestTensor::from_data([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+
+        let (q, r) = linalg::qr(tensor.clone());
+
+        assert_eq!(q.dims(), [3, 2]);
+        assert_eq!(r.dims(), [2, 2]);
+        q.clone()
+            .matmul(r.clone())
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+        q.clone()
+            .transpose()
+            .matmul(q)
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, 0.0], [0.0, 1.0]]), 3);
+        r.tril(-1)
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0, 0.0], [0.0, 0.0]]), 3);
+    }
+
+    #[test]
+    fn should_compute_qr_of_wide_matrices() {
+        let tensor = TestTensor::from_data([
+            [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]],
+            [[0.0, 1.0, 0.0], [2.0, 0.0, 1.0]],
+        ]);
+
+        let (q, r) = linalg::qr(tensor.clone());
+
+        assert_eq!(q.dims(), [2, 2, 2]);
+        assert_eq!(r.dims(), [2, 2, 3]);
+        q.matmul(r)
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+    }
+
+    #[test]
+    fn should_compute_svd() {
+        let tensor = TestTensor::from_data([[3.0, 0.0], [4.0, 5.0]]);
+
+        let (u, s, vt) = linalg::svd::<TestBackend, 2, 1>(tensor.clone());
+
+        s.clone()
+            .into_data()
+            .assert_approx_eq(&Data::from([6.7082, 2.2361]), 3);
+        u.mul(s.unsqueeze())
+            .matmul(vt)
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+    }
+
+    #[test]
+    fn should_compute_batched_svd_of_wide_matrices() {
+        let tensor = TestTensor::from_data([
+            [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]],
+            [[0.0, 1.0, 0.0], [2.0, 0.0, 1.0]],
+        ]);
+
+        let (u, s, vt) = linalg::svd::<TestBackend, 3, 2>(tensor.clone());
+
+        assert_eq!(u.dims(), [2, 2, 2]);
+        assert_eq!(s.dims(), [2, 2]);
+        assert_eq!(vt.dims(), [2, 2, 3]);
+        vt.clone()
+            .matmul(vt.clone().transpose())
+            .into_data()
+            .assert_approx_eq(
+                &Data::from([[[1.0, 0.0], [0.0, 1.0]], [[1.0, 0.0], [0.0, 1.0]]]),
+                3,
+            );
+        u.mul(s.reshape([2, 1, 2]))
+            .matmul(vt)
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+    }
+
+    #[test]
+    fn should_compute_eigh_with_the_lower_triangular_part() {
+        let tensor = TestTensor::from_data([[2.0, 100.0], [1.0, 2.0]]);
+        let symmetric = TestTensor::from_data([[2.0, 1.0], [1.0, 2.0]]);
+
+        let (values, vectors) = linalg::eigh::<TestBackend, 2, 1>(tensor);
+
+        values
+            .clone()
+            .into_data()
+            .assert_approx_eq(&Data::from([1.0, 3.0]), 3);
+        vectors
+            .clone()
+            .mul(values.unsqueeze())
+            .matmul(vectors.transpose())
+            .into_data()
+            .assert_approx_eq(&symmetric.into_data(), 3);
+    }
+
+    #[test]
+    fn should_compute_batched_eigh() {
+        let tensor: Tensor<TestBackend, 3> = TestTensor::from_data([
+            [[4.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 2.0]],
+            [[1.0, 0.0, 0.0], [0.0, -2.0, 0.0], [0.0, 0.0, 5.0]],
+        ]);
+
+        let (values, vectors) = linalg::eigh::<TestBackend, 3, 2>(tensor.clone());
+
+        values
+            .clone()
+            .slice([1..2])
+            .into_data()
+            .assert_approx_eq(&Data::from([[-2.0, 1.0, 5.0]]), 3);
+        vectors
+            .clone()
+            .mul(values.reshape([2, 1, 3]))
+            .matmul(vectors.transpose())
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+    }
+}
@@ -27,4 +27,53 @@ mod tests {
         let data_expected = Data::from([[2.0, 7.0], [2.0, 2.0]]);
         assert_eq!(data_expected, data_actual);
     }
+
+    #[test]
+    fn should_support_masked_select_ops() {
+        let tensor = TestTensor::from_data([[1.0, 7.0], [2.0, 3.0]]);
+        let mask =
+            Tensor::<TestBackend, 2, Bool>::from_bool(Data::from([[true, false], [true, true]]));
+
+        let data_actual = tensor.masked_select(mask).into_data();
+
+        let data_expected = Data::from([1.0, 2.0, 3.0]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_masked_select_ops_without_selected_elements() {
+        let tensor = TestTensor::from_data([[1.0, 7.0], [2.0, 3.0]]);
+        let mask =
+            Tensor::<TestBackend, 2, Bool>::from_bool(Data::from([[false, false], [false, false]]));
+
+        let output = tensor.masked_select(mask);
+
+        assert_eq!(output.dims(), [0]);
+    }
+
+    #[test]
+    fn should_support_where_cond_ops() {
+        let tensor = TestTensor::from_data([[1.0, 7.0], [2.0, 3.0]]);
+        let other = TestTensor::from_data([[4.0, 5.0], [6.0, 8.0]]);
+        let condition =
+            Tensor::<TestBackend, 2, Bool>::from_bool(Data::from([[true, false], [false, true]]));
+
+        let data_actual = tensor.where_cond(condition, other).into_data();
+
+        let data_expected = Data::from([[1.0, 5.0], [6.0, 3.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_where_ops_with_broadcast() {
+        let lhs = TestTensorInt::from_data([[1, 2, 3]]);
+        let rhs = TestTensorInt::from_data([[4], [5]]);
+        let condition =
+            Tensor::<TestBackend, 2, Bool>::from_bool(Data::from([[true, false, true]]));
+
+        let data_actual = burn_tensor::where_(condition, lhs, rhs).into_data();
+
+        let data_expected = Data::from([[1, 4, 3], [1, 5, 3]]);
+        assert_eq!(data_expected, data_actual);
+    }
 }
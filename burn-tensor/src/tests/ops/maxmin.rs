@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(maxmin)]
 mod tests {
     use super::*;
-    use burn_tensor::{Data, Tensor};
+    use burn_tensor::{Data, Int, Tensor};
 
     #[test]
     fn test_max_dim_2d() {
@@ -48,4 +48,38 @@ mod tests {
         assert_eq!(output_expected, output_actual.into_data());
         assert_eq!(index_expected, index_actual.into_data());
     }
+
+    #[test]
+    fn test_max_pair_with_broadcast() {
+        let lhs = TestTensor::from_floats([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]]);
+        let rhs = TestTensor::from_floats([[2.0, 4.0, 1.0]]);
+
+        let output = lhs.max_pair(rhs);
+
+        let expected = Data::from([[2.0, 4.0, 3.0], [5.0, 9.0, 6.0]]);
+        assert_eq!(expected, output.into_data());
+    }
+
+    #[test]
+    fn test_min_pair_with_broadcast() {
+        let lhs = TestTensor::from_floats([[1.0], [5.0]]);
+        let rhs = TestTensor::from_floats([[2.0, 4.0, 1.0]]);
+
+        let output = lhs.min_pair(rhs);
+
+        let expected = Data::from([[1.0, 1.0, 1.0], [2.0, 4.0, 1.0]]);
+        assert_eq!(expected, output.into_data());
+    }
+
+    #[test]
+    fn test_max_min_pair_int() {
+        let lhs = Tensor::<TestBackend, 1, Int>::from_ints([1, -2, 3]);
+        let rhs = Tensor::<TestBackend, 1, Int>::from_ints([2, -4, 3]);
+
+        let max = lhs.clone().max_pair(rhs.clone());
+        let min = lhs.min_pair(rhs);
+
+        assert_eq!(Data::<i64, 1>::from([2, -2, 3]).convert(), max.into_data());
+        assert_eq!(Data::<i64, 1>::from([1, -4, 3]).convert(), min.into_data());
+    }
 }
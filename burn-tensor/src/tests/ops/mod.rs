@@ -1,19 +1,39 @@
 mod abs;
+mod acos;
 mod add;
 mod aggregation;
+mod any_all;
 mod arange;
 mod arange_step;
 mod arg;
+mod asin;
+mod assign;
+mod atan;
+mod atan2;
+mod bitwise;
 mod cast;
 mod cat;
+mod chunk;
 mod clamp;
+mod complex;
 mod cos;
+mod cosh;
+mod diagonal;
 mod div;
+mod einsum;
 mod erf;
+mod erfc;
 mod exp;
+mod expand;
+mod fft;
 mod flatten;
+mod flip;
 mod full;
 mod gather_scatter;
+mod grid;
+mod index;
+mod lgamma;
+mod linalg;
 mod log;
 mod log1p;
 mod map_comparison;
@@ -21,16 +41,28 @@ mod mask;
 mod matmul;
 mod maxmin;
 mod mul;
+mod nan_inf;
 mod neg;
+mod one_hot;
+mod pad;
 mod powf;
+mod quantization;
 mod random;
 mod repeat;
+mod repeat_interleave;
 mod reshape;
+mod roll;
 mod select;
 mod sin;
+mod sinh;
 mod slice;
+mod sparse;
 mod sqrt;
 mod squeeze;
 mod sub;
+mod tan;
 mod tanh;
+mod topk;
 mod transpose;
+mod triangular;
+mod unique;
@@ -0,0 +1,54 @@
+#[burn_tensor_testgen::testgen(nan_inf)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_is_nan() {
+        let tensor = TestTensor::from_floats([
+            [0.0, f32::NAN, f32::INFINITY],
+            [-1.0, f32::NEG_INFINITY, f32::NAN],
+        ]);
+
+        let data_actual = tensor.is_nan().into_data();
+
+        let data_expected = Data::from([[false, true, false], [false, false, true]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_is_inf() {
+        let tensor = TestTensor::from_floats([
+            [0.0, f32::NAN, f32::INFINITY],
+            [-1.0, f32::NEG_INFINITY, f32::NAN],
+        ]);
+
+        let data_actual = tensor.is_inf().into_data();
+
+        let data_expected = Data::from([[false, false, true], [false, true, false]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_nan_to_num() {
+        let tensor = TestTensor::from_floats([
+            [0.5, f32::NAN, f32::INFINITY],
+            [-1.0, f32::NEG_INFINITY, f32::NAN],
+        ]);
+
+        let data_actual = tensor.nan_to_num(0.0, 100.0, -100.0).into_data();
+
+        let data_expected = Data::from([[0.5, 0.0, 100.0], [-1.0, -100.0, 0.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_nan_to_num_computed_values() {
+        let tensor = Tensor::<TestBackend, 1>::from_floats([1.0, 0.0, -1.0]);
+
+        let data_actual = tensor.log().nan_to_num(-1.0, 1.0, -10.0).into_data();
+
+        let data_expected = Data::from([0.0, -10.0, -1.0]);
+        assert_eq!(data_expected, data_actual);
+    }
+}
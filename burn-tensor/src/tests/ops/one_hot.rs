@@ -0,0 +1,40 @@
+#[burn_tensor_testgen::testgen(one_hot)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Bool, Data, Int, Tensor};
+
+    #[test]
+    fn should_encode_indices_as_one_hot_vectors() {
+        let indices = Tensor::<TestBackend, 1, Int>::from_ints([2, 0, 1]);
+
+        let one_hot: Tensor<TestBackend, 2> = indices.one_hot(3);
+
+        one_hot.into_data().assert_approx_eq(
+            &Data::from([[0.0, 0.0, 1.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn should_encode_indices_out_of_range_as_zeros() {
+        let indices = Tensor::<TestBackend, 2, Int>::from_ints([[1, -1], [4, 0]]);
+
+        let one_hot: Tensor<TestBackend, 3, Bool> = indices.one_hot_bool(2);
+
+        assert_eq!(
+            one_hot.into_data(),
+            Data::from([
+                [[false, true], [false, false]],
+                [[false, false], [true, false]]
+            ])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_the_output_has_the_wrong_dimensions() {
+        let indices = Tensor::<TestBackend, 1, Int>::from_ints([2, 0, 1]);
+
+        let _one_hot: Tensor<TestBackend, 3> = indices.one_hot(3);
+    }
+}
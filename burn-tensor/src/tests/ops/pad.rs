@@ -0,0 +1,66 @@
+#[burn_tensor_testgen::testgen(pad)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, PadMode, Tensor};
+
+    #[test]
+    fn should_support_constant_pad() {
+        let tensor = Tensor::<TestBackend, 2>::from_data([[1.0, 2.0], [3.0, 4.0]]);
+
+        let data_actual = tensor
+            .pad([(1, 0), (0, 2)], PadMode::Constant(-1.0))
+            .into_data();
+
+        let data_expected = Data::from([
+            [-1.0, -1.0, -1.0, -1.0],
+            [1.0, 2.0, -1.0, -1.0],
+            [3.0, 4.0, -1.0, -1.0],
+        ]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_reflect_pad() {
+        let tensor = Tensor::<TestBackend, 2>::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let data_actual = tensor.pad([(1, 0), (2, 2)], PadMode::Reflect).into_data();
+
+        let data_expected = Data::from([
+            [6.0, 5.0, 4.0, 5.0, 6.0, 5.0, 4.0],
+            [3.0, 2.0, 1.0, 2.0, 3.0, 2.0, 1.0],
+            [6.0, 5.0, 4.0, 5.0, 6.0, 5.0, 4.0],
+        ]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_edge_pad() {
+        let tensor = Tensor::<TestBackend, 2>::from_data([[1.0, 2.0], [3.0, 4.0]]);
+
+        let data_actual = tensor.pad([(0, 1), (3, 1)], PadMode::Edge).into_data();
+
+        let data_expected = Data::from([
+            [1.0, 1.0, 1.0, 1.0, 2.0, 2.0],
+            [3.0, 3.0, 3.0, 3.0, 4.0, 4.0],
+            [3.0, 3.0, 3.0, 3.0, 4.0, 4.0],
+        ]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_int_pad() {
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints([1, 2, 3]);
+
+        let data_actual = tensor.pad([(2, 1)], PadMode::Constant(0.0)).into_data();
+
+        assert_eq!(Data::from([0, 0, 1, 2, 3, 0]), data_actual);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_reflect_pad_is_too_large() {
+        let tensor = Tensor::<TestBackend, 1>::from_data([1.0, 2.0]);
+
+        tensor.pad([(2, 0)], PadMode::Reflect);
+    }
+}
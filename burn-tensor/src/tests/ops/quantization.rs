@@ -0,0 +1,66 @@
+#[burn_tensor_testgen::testgen(quantization)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, QuantizationParameters, QuantizedTensor, Tensor};
+
+    #[test]
+    fn should_quantize_per_tensor() {
+        let tensor = TestTensor::from_floats([-1.0, 0.0, 0.26, -0.25, 100.0, -100.0]);
+
+        let data_actual = tensor.quantize(0.1, 2).into_data();
+
+        assert_eq!(data_actual, Data::from([-8, 2, 5, 0, 127, -128]));
+    }
+
+    #[test]
+    fn should_dequantize_per_tensor() {
+        let tensor = QuantizedTensor::<TestBackend, 1>::from_data(
+            Data::from([-8, 2, 5, 127]),
+            QuantizationParameters::PerTensor {
+                scale: 0.1,
+                zero_point: 2,
+            },
+            &Default::default(),
+        );
+
+        let data_actual = tensor.dequantize().into_data();
+
+        data_actual.assert_approx_eq(&Data::from([-1.0, 0.0, 0.3, 12.5]), 3);
+    }
+
+    #[test]
+    fn should_quantize_per_channel() {
+        let tensor = TestTensor::from_floats([[1.0, -2.0, 3.0], [1.0, -2.0, 3.0]]);
+        let scales = TestTensor::from_floats([0.5, 0.01]);
+        let zero_points = Tensor::<TestBackend, 1, Int>::from_ints([0, -10]);
+
+        let quantized = tensor.quantize_per_channel(scales, zero_points, 0);
+
+        assert_eq!(
+            quantized.clone().into_data(),
+            Data::from([[2, -4, 6], [90, -128, 127]])
+        );
+        quantized
+            .dequantize()
+            .into_data()
+            .assert_approx_eq(&Data::from([[1.0, -2.0, 3.0], [1.0, -1.18, 1.37]]), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_channels_dont_match() {
+        let tensor = TestTensor::from_floats([[1.0, -2.0, 3.0], [1.0, -2.0, 3.0]]);
+        let scales = TestTensor::from_floats([0.5, 0.01]);
+        let zero_points = Tensor::<TestBackend, 1, Int>::from_ints([0, 0]);
+
+        tensor.quantize_per_channel(scales, zero_points, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_with_null_scale() {
+        let tensor = TestTensor::from_floats([1.0, -2.0, 3.0]);
+
+        tensor.quantize(0.0, 0);
+    }
+}
@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(random)]
 mod tests {
     use super::*;
-    use burn_tensor::{Distribution, Tensor};
+    use burn_tensor::{Data, Distribution, Int, Tensor};
 
     #[test]
     fn rand_standard() {
@@ -10,4 +10,79 @@ mod tests {
         // check that the tensor is within the range of [0..1) (1 is exclusive)
         tensor.into_data().assert_within_range(0.0..1.0);
     }
+
+    #[test]
+    fn should_sample_normal_with_tensor_parameters() {
+        let mean = TestTensor::from_data([[0.0, 100.0], [-3.0, 5.0]]);
+        let std = TestTensor::zeros([1, 2]);
+
+        let samples = Tensor::normal(mean, std);
+
+        samples
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0, 100.0], [-3.0, 5.0]]), 3);
+    }
+
+    #[test]
+    fn should_sample_normal_around_the_mean() {
+        let mean = TestTensor::full([4096], 5.0);
+        let std = TestTensor::full([4096], 2.0);
+
+        let samples = Tensor::normal(mean, std);
+        let (var, mean) = samples.var_mean(0);
+
+        mean.into_data().assert_within_range(4.8..5.2);
+        var.into_data().assert_within_range(3.6..4.4);
+    }
+
+    #[test]
+    fn should_sample_bernoulli_with_tensor_probabilities() {
+        let probabilities = TestTensor::from_data([0.0, 1.0, 1.0, 0.0]);
+
+        let samples = probabilities.bernoulli();
+
+        assert_eq!(samples.into_data(), Data::from([0.0, 1.0, 1.0, 0.0]));
+    }
+
+    #[test]
+    fn should_sample_multinomial_with_replacement() {
+        let weights = TestTensor::from_data([[0.0, 0.0, 2.0, 0.0], [0.5, 0.0, 0.0, 0.0]]);
+
+        let samples = weights.multinomial(3, true);
+
+        assert_eq!(
+            samples.into_data(),
+            Data::<i64, 2>::from([[2, 2, 2], [0, 0, 0]]).convert()
+        );
+    }
+
+    #[test]
+    fn should_sample_multinomial_without_replacement() {
+        let weights = TestTensor::from_data([0.0, 1.0, 0.0, 3.0, 2.0]);
+
+        let samples = weights.multinomial(3, false);
+        let mut values = samples.into_data().convert::<i64>().value;
+        values.sort();
+
+        assert_eq!(values, vec![1, 3, 4]);
+    }
+
+    #[test]
+    fn should_sample_multinomial_from_the_weights() {
+        let weights = TestTensor::from_data([1.0, 3.0]);
+
+        let samples = weights.multinomial(4000, true);
+        let count = samples.equal_elem(1).int().sum().into_scalar();
+        let count = burn_tensor::ElementConversion::elem::<i64>(count);
+
+        assert!((2800..3200).contains(&count), "{count}");
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_sampling_more_than_the_categories_without_replacement() {
+        let weights = TestTensor::from_data([1.0, 3.0]);
+
+        let _samples: Tensor<TestBackend, 1, Int> = weights.multinomial(3, false);
+    }
 }
@@ -0,0 +1,53 @@
+#[burn_tensor_testgen::testgen(repeat_interleave)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_repeat_interleave_scalar() {
+        let tensor = TestTensor::from_data([[1.0, 2.0], [3.0, 4.0]]);
+
+        let data_actual = tensor.repeat_interleave(2, 1).into_data();
+
+        let data_expected = Data::from([[1.0, 1.0, 2.0, 2.0], [3.0, 3.0, 4.0, 4.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_repeat_interleave_first_dim() {
+        let tensor = TestTensor::from_data([[1.0, 2.0], [3.0, 4.0]]);
+
+        let data_actual = tensor.repeat_interleave(2, 0).into_data();
+
+        let data_expected = Data::from([[1.0, 2.0], [1.0, 2.0], [3.0, 4.0], [3.0, 4.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_repeat_interleave_counts() {
+        let tensor = Tensor::<TestBackend, 1, Int>::from_ints([7, 8, 9]);
+        let counts = Tensor::<TestBackend, 1, Int>::from_ints([2, 0, 3]);
+
+        let data_actual = tensor.repeat_interleave_counts(counts, 0).into_data();
+
+        assert_eq!(data_actual, Data::from([7, 7, 9, 9, 9]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_counts_dont_match_dim() {
+        let tensor = TestTensor::from_data([[1.0, 2.0], [3.0, 4.0]]);
+        let counts = Tensor::<TestBackend, 1, Int>::from_ints([1, 2, 3]);
+
+        tensor.repeat_interleave_counts(counts, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_with_negative_counts() {
+        let tensor = TestTensor::from_data([[1.0, 2.0], [3.0, 4.0]]);
+        let counts = Tensor::<TestBackend, 1, Int>::from_ints([1, -1]);
+
+        tensor.repeat_interleave_counts(counts, 1);
+    }
+}
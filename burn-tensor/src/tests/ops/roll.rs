@@ -0,0 +1,34 @@
+#[burn_tensor_testgen::testgen(roll)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_roll_dims() {
+        let tensor = TestTensor::from_data([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        let data_actual = tensor.roll(&[1, -1], &[0, 1]).into_data();
+
+        let data_expected = Data::from([[4.0, 5.0, 3.0], [1.0, 2.0, 0.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_roll_dim_with_shift_larger_than_size() {
+        let tensor = Tensor::<TestBackend, 1, Int>::arange(0..5);
+
+        let data_actual = tensor.clone().roll_dim(7, 0).into_data();
+        let data_unchanged = tensor.roll_dim(-5, 0).into_data();
+
+        assert_eq!(data_actual, Data::from([3, 4, 0, 1, 2]));
+        assert_eq!(data_unchanged, Data::from([0, 1, 2, 3, 4]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_shifts_and_dims_have_different_lengths() {
+        let tensor = TestTensor::from_data([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        tensor.roll(&[1, 1], &[0]);
+    }
+}
@@ -0,0 +1,16 @@
+#[burn_tensor_testgen::testgen(sinh)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_sinh_ops() {
+        let data = Data::from([[0.0, 1.0, 2.0], [-1.0, -2.0, 0.5]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.sinh().into_data();
+
+        let data_expected = Data::from([[0.0, 1.1752, 3.6269], [-1.1752, -3.6269, 0.5211]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+}
@@ -0,0 +1,66 @@
+#[burn_tensor_testgen::testgen(sparse)]
+mod tests {
+    use super::*;
+    use burn_tensor::sparse::SparseTensor;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_convert_sparse_to_dense() {
+        let indices = Tensor::<TestBackend, 2, Int>::from_ints([[0, 1], [2, 0], [0, 1]]);
+        let values = TestTensor::from_floats([3.0, 4.0, 1.0]);
+        let tensor = SparseTensor::new(indices, values, [3, 2]);
+
+        let data_actual = tensor.to_dense().into_data();
+
+        let data_expected = Data::from([[0.0, 4.0], [0.0, 0.0], [4.0, 0.0]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_convert_dense_to_sparse() {
+        let dense = TestTensor::from_floats([[[0.0, 2.0], [0.0, 0.0]], [[-1.0, 0.0], [0.0, 5.0]]]);
+
+        let tensor = SparseTensor::from_dense(dense.clone());
+
+        assert_eq!(tensor.nnz(), 3);
+        assert_eq!(
+            tensor.indices().into_data(),
+            Data::<i64, 2>::from([[0, 0, 1], [1, 0, 0], [1, 1, 1]]).convert()
+        );
+        assert_eq!(tensor.values().into_data(), Data::from([2.0, -1.0, 5.0]));
+        assert_eq!(tensor.to_dense().into_data(), dense.into_data());
+    }
+
+    #[test]
+    fn should_matmul_sparse_with_dense() {
+        let indices = Tensor::<TestBackend, 2, Int>::from_ints([[0, 1], [1, 0], [1, 2], [0, 1]]);
+        let values = TestTensor::from_floats([2.0, 3.0, -1.0, 1.0]);
+        let sparse = SparseTensor::new(indices, values, [2, 3]);
+        let dense = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+
+        let expected = sparse.clone().to_dense().matmul(dense.clone()).into_data();
+        let data_actual = sparse.matmul(dense).into_data();
+
+        assert_eq!(data_actual, Data::from([[9.0, 12.0], [-2.0, 0.0]]));
+        assert_eq!(data_actual, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_indices_dont_match_rank() {
+        let indices = Tensor::<TestBackend, 2, Int>::from_ints([[0, 1], [2, 0]]);
+        let values = TestTensor::from_floats([3.0, 4.0]);
+
+        SparseTensor::new(indices, values, [3, 2, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_matmul_shapes_dont_match() {
+        let indices = Tensor::<TestBackend, 2, Int>::from_ints([[0, 1]]);
+        let values = TestTensor::from_floats([3.0]);
+        let sparse = SparseTensor::new(indices, values, [2, 2]);
+
+        sparse.matmul(TestTensor::ones([3, 2]));
+    }
+}
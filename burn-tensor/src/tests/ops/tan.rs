@@ -0,0 +1,16 @@
+#[burn_tensor_testgen::testgen(tan)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_tan_ops() {
+        let data = Data::from([[0.0, 1.0, 2.0], [3.0, -1.0, 0.5]]);
+        let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+        let data_actual = tensor.tan().into_data();
+
+        let data_expected = Data::from([[0.0, 1.5574, -2.1850], [-0.1425, -1.5574, 0.5463]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+}
@@ -0,0 +1,47 @@
+#[burn_tensor_testgen::testgen(topk)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_support_topk_float() {
+        let tensor =
+            Tensor::<TestBackend, 2>::from_data([[1.0, 5.0, 3.0, 2.0], [4.0, -1.0, 6.0, 4.0]]);
+
+        let (values, indices) = tensor.topk(3, 1);
+
+        assert_eq!(
+            values.into_data(),
+            Data::from([[5.0, 3.0, 2.0], [6.0, 4.0, 4.0]])
+        );
+        assert_eq!(indices.into_data(), Data::from([[1, 2, 3], [2, 0, 3]]));
+    }
+
+    #[test]
+    fn should_support_topk_smallest_int() {
+        let tensor = Tensor::<TestBackend, 2, Int>::from_data([[3, 1], [-2, 4], [0, 2]]);
+
+        let (values, indices) = tensor.topk_with_options(2, 0, false, true);
+
+        assert_eq!(values.into_data(), Data::from([[-2, 1], [0, 2]]));
+        assert_eq!(indices.into_data(), Data::from([[1, 0], [2, 2]]));
+    }
+
+    #[test]
+    fn should_support_topk_unsorted() {
+        let tensor = Tensor::<TestBackend, 1>::from_data([2.0, 7.0, 1.0, 9.0, 3.0]);
+
+        let (values, indices) = tensor.topk_with_options(3, 0, true, false);
+
+        assert_eq!(values.into_data(), Data::from([7.0, 9.0, 3.0]));
+        assert_eq!(indices.into_data(), Data::from([1, 3, 4]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_k_is_greater_than_the_dimension() {
+        let tensor = Tensor::<TestBackend, 2>::ones([2, 3]);
+
+        let _ = tensor.topk(4, 1);
+    }
+}
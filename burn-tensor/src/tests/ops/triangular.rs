@@ -0,0 +1,59 @@
+#[burn_tensor_testgen::testgen(triangular)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Bool, Data, Tensor};
+
+    #[test]
+    fn should_support_tril() {
+        let tensor = TestTensor::from_data([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+
+        let data_actual = tensor.clone().tril(0).into_data();
+        let data_offset = tensor.tril(1).into_data();
+
+        assert_eq!(
+            data_actual,
+            Data::from([[1.0, 0.0, 0.0], [4.0, 5.0, 0.0], [7.0, 8.0, 9.0]])
+        );
+        assert_eq!(
+            data_offset,
+            Data::from([[1.0, 2.0, 0.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]])
+        );
+    }
+
+    #[test]
+    fn should_support_triu_with_batch_and_negative_diagonal() {
+        let tensor = TestTensorInt::from_data([[[1, 2, 3], [4, 5, 6]], [[7, 8, 9], [1, 2, 3]]]);
+
+        let data_actual = tensor.clone().triu(0).into_data();
+        let data_offset = tensor.triu(-1).into_data();
+
+        assert_eq!(
+            data_actual,
+            Data::from([[[1, 2, 3], [0, 5, 6]], [[7, 8, 9], [0, 2, 3]]])
+        );
+        assert_eq!(
+            data_offset,
+            Data::from([[[1, 2, 3], [4, 5, 6]], [[7, 8, 9], [1, 2, 3]]])
+        );
+    }
+
+    #[test]
+    fn should_create_tril_mask() {
+        let mask = Tensor::<TestBackend, 2, Bool>::tril_mask([2, 3], 0, &Default::default());
+
+        assert_eq!(
+            mask.into_data(),
+            Data::from([[false, true, true], [false, false, true]])
+        );
+    }
+
+    #[test]
+    fn should_create_triu_mask() {
+        let mask = Tensor::<TestBackend, 2, Bool>::triu_mask([3, 2], 0, &Default::default());
+
+        assert_eq!(
+            mask.into_data(),
+            Data::from([[false, false], [true, false], [true, true]])
+        );
+    }
+}
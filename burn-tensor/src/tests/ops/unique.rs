@@ -0,0 +1,28 @@
+#[burn_tensor_testgen::testgen(unique)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_support_unique() {
+        let tensor = TestTensorInt::from_data([[3, 1, 3], [2, 1, 3]]);
+
+        let (values, inverse, counts) = tensor.unique_with_inverse_and_counts();
+
+        assert_eq!(values.into_data(), Data::from([1, 2, 3]));
+        assert_eq!(inverse.into_data(), Data::from([[2, 0, 2], [1, 0, 2]]));
+        assert_eq!(counts.into_data(), Data::from([2, 1, 3]));
+    }
+
+    #[test]
+    fn should_support_unique_consecutive() {
+        let tensor = Tensor::<TestBackend, 1, Int>::from_data([1, 1, 2, 2, 3, 1, 1, 2]);
+
+        let (values, inverse, counts) = tensor.clone().unique_consecutive_with_inverse_and_counts();
+
+        assert_eq!(values.into_data(), Data::from([1, 2, 3, 1, 2]));
+        assert_eq!(inverse.into_data(), Data::from([0, 0, 1, 1, 2, 3, 3, 4]));
+        assert_eq!(counts.into_data(), Data::from([2, 2, 1, 2, 1]));
+        assert_eq!(tensor.unique().into_data(), Data::from([1, 2, 3]));
+    }
+}
@@ -2,7 +2,7 @@
 mod tests {
     use super::*;
     use burn_tensor::backend::Backend;
-    use burn_tensor::{Data, Tensor};
+    use burn_tensor::{Data, Int, PrintOptions, Tensor};
 
     type FloatElem = <TestBackend as Backend>::FloatElem;
     type IntElem = <TestBackend as Backend>::IntElem;
@@ -98,4 +98,68 @@ mod tests {
         );
         assert_eq!(output, expected);
     }
+
+    #[test]
+    fn test_display_with_precision_and_summary() {
+        let tensor = Tensor::<TestBackend, 1>::from_floats([0.12345, 2.0, 3.0, 4.0, 5.0]);
+        let options = PrintOptions::default()
+            .with_precision(2)
+            .with_threshold(4)
+            .with_edge_items(1);
+
+        let output = format!("{}", tensor.display(options));
+
+        assert!(output.starts_with("Tensor {\n  data: [0.12, ..., 5.00],\n  shape:  [5],"));
+    }
+
+    #[test]
+    fn test_display_with_formatter_precision() {
+        let tensor = Tensor::<TestBackend, 2>::from_floats([[1.25, 2.0], [3.0, -4.5]]);
+
+        let output = format!("{:.1}", tensor);
+
+        assert!(output.starts_with("Tensor {\n  data: [[1.2, 2.0], [3.0, -4.5]],"));
+    }
+
+    #[test]
+    fn test_display_summarized_int_tensor() {
+        let tensor = Tensor::<TestBackend, 1, Int>::arange(0..20).reshape([4, 5]);
+        let options = PrintOptions::default()
+            .with_threshold(10)
+            .with_edge_items(1)
+            .with_precision(3);
+
+        let output = format!("{}", tensor.display(options));
+
+        assert!(output.starts_with("Tensor {\n  data: [[0, ..., 4], ..., [15, ..., 19]],"));
+    }
+
+    #[test]
+    fn test_display_scientific_notation() {
+        let tensor = Tensor::<TestBackend, 1>::from_floats([0.00001, 1.0]);
+
+        let auto = format!("{:.2}", tensor);
+        let fixed = format!(
+            "{}",
+            tensor.display(PrintOptions::default().with_sci_mode(false))
+        );
+
+        assert!(auto.starts_with("Tensor {\n  data: [1.00e-5, 1.00e0],"));
+        assert!(fixed.starts_with("Tensor {\n  data: [0.00001, 1.0],"));
+    }
+
+    #[test]
+    fn test_string_summary() {
+        let tensor = Tensor::<TestBackend, 1, Int>::arange(0..20).reshape([4, 5]);
+
+        let output = tensor.to_string_summary();
+
+        let expected = format!(
+            "Tensor {{ shape: [4, 5], kind: \"Int\", dtype: \"{}\", device: {:?}, data: \
+                [[0, 1, ..., 3, 4], [5, 6, ..., 8, 9], [10, 11, ..., 13, 14], [15, 16, ..., 18, 19]] }}",
+            core::any::type_name::<IntElem>(),
+            tensor.device()
+        );
+        assert_eq!(output, expected);
+    }
 }
@@ -4,6 +4,7 @@ use burn_core::lr_scheduler::LRScheduler;
 use burn_core::module::{ADModule, Module};
 use burn_core::optim::Optimizer;
 use burn_core::tensor::backend::ADBackend;
+use burn_core::RngState;
 
 /// Learner struct encapsulating all components necessary to train a Neural Network model.
 ///
@@ -24,6 +25,7 @@ where
     pub(super) checkpointer_model: CheckpointModel<M, B>,
     pub(super) checkpointer_optimizer: CheckpointOptim<O, M, B>,
     pub(super) checkpointer_scheduler: CheckpointScheduler<LR>,
+    pub(super) checkpointer_rng: CheckpointRng,
     pub(super) grad_accumulation: Option<usize>,
     pub(super) devices: Vec<B::Device>,
 }
@@ -31,6 +33,7 @@ where
 type CheckpointModel<M, B> = Option<Box<dyn Checkpointer<<M as Module<B>>::Record>>>;
 type CheckpointOptim<O, M, B> = Option<Box<dyn Checkpointer<<O as Optimizer<M, B>>::Record>>>;
 type CheckpointScheduler<LR> = Option<Box<dyn Checkpointer<<LR as LRScheduler>::Record>>>;
+type CheckpointRng = Option<Box<dyn Checkpointer<RngState>>>;
 
 impl<B, M, O, LR, TO, VO> Learner<B, M, O, LR, TO, VO>
 where
@@ -41,6 +44,7 @@ where
     O: Optimizer<M, B>,
     LR: LRScheduler,
 {
+    #[allow(clippy::too_many_arguments)]
     pub(super) fn checkpoint(
         model: &M,
         optim: &O,
@@ -48,6 +52,7 @@ where
         checkpointer_model: &CheckpointModel<M, B>,
         checkpointer_optimizer: &CheckpointOptim<O, M, B>,
         checkpointer_scheduler: &CheckpointScheduler<LR>,
+        checkpointer_rng: &CheckpointRng,
         epoch: usize,
     ) {
         if let Some(checkpointer) = &checkpointer_model {
@@ -63,6 +68,10 @@ where
         if let Some(checkpointer) = &checkpointer_scheduler {
             checkpointer.save(epoch, scheduler.to_record()).unwrap();
         }
+
+        if let Some(checkpointer) = &checkpointer_rng {
+            checkpointer.save(epoch, burn_core::rng_state()).unwrap();
+        }
     }
 
     pub(super) fn load_checkpoint(mut self, epoch: usize) -> Self {
@@ -81,6 +90,18 @@ where
             self.lr_scheduler = self.lr_scheduler.load_record(record);
         }
 
+        // The checkpoints saved before the generator state was recorded don't include it, in
+        // which case training resumes with the current generator.
+        if let Some(checkpointer) = &self.checkpointer_rng {
+            match checkpointer.restore(epoch) {
+                Ok(state) => burn_core::set_rng_state(state),
+                Err(err) => log::warn!(
+                    "Unable to restore the random number generator state of epoch {epoch}, \
+                     keeping the current one: {err:?}"
+                ),
+            }
+        }
+
         self
     }
 }
@@ -11,6 +11,7 @@ use burn_core::module::ADModule;
 use burn_core::optim::Optimizer;
 use burn_core::record::FileRecorder;
 use burn_core::tensor::backend::ADBackend;
+use burn_core::RngState;
 
 use std::sync::Arc;
 
@@ -28,6 +29,7 @@ where
     checkpointer_model: Option<Arc<dyn Checkpointer<M::Record> + Send + Sync>>,
     checkpointer_optimizer: Option<Arc<dyn Checkpointer<O::Record> + Send + Sync>>,
     checkpointer_scheduler: Option<Arc<dyn Checkpointer<S::Record> + Send + Sync>>,
+    checkpointer_rng: Option<Arc<dyn Checkpointer<RngState> + Send + Sync>>,
     num_epochs: usize,
     checkpoint: Option<usize>,
     directory: String,
@@ -61,6 +63,7 @@ where
             checkpointer_model: None,
             checkpointer_optimizer: None,
             checkpointer_scheduler: None,
+            checkpointer_rng: None,
             directory: directory.to_string(),
             grad_accumulation: None,
             devices: vec![B::Device::default()],
@@ -149,8 +152,9 @@ where
         self
     }
 
-    /// Register a checkpointer that will save the [optimizer](Optimizer) and the
-    /// [model](ADModule).
+    /// Register a checkpointer that will save the [optimizer](Optimizer), the
+    /// [model](ADModule), the [learning rate scheduler](LRScheduler) and the state of the
+    /// [random number generator](burn_core::rng_state).
     ///
     /// The number of checkpoints to be keep should be set to a minimum of two to be safe, since
     /// they are saved and deleted asynchronously and a crash during training might make a
@@ -172,11 +176,17 @@ where
             num_keep,
         )));
         self.checkpointer_scheduler = Some(Arc::new(FileCheckpointer::new(
-            recorder,
+            recorder.clone(),
             format!("{}/checkpoint", self.directory).as_str(),
             "scheduler",
             num_keep,
         )));
+        self.checkpointer_rng = Some(Arc::new(FileCheckpointer::new(
+            recorder,
+            format!("{}/checkpoint", self.directory).as_str(),
+            "rng",
+            num_keep,
+        )));
         self
     }
 
@@ -222,6 +232,14 @@ where
             }
             None => None,
         };
+        let checkpointer_rng = match self.checkpointer_rng {
+            Some(checkpointer) => {
+                let checkpointer: Box<dyn Checkpointer<RngState>> =
+                    Box::new(AsyncCheckpointer::new(checkpointer));
+                Some(checkpointer)
+            }
+            None => None,
+        };
 
         Learner {
             model,
@@ -233,6 +251,7 @@ where
             checkpointer_model,
             checkpointer_optimizer,
             checkpointer_scheduler,
+            checkpointer_rng,
             grad_accumulation: self.grad_accumulation,
             devices: self.devices,
         }
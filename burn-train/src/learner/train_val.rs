@@ -141,6 +141,7 @@ where
                 &self.checkpointer_model,
                 &self.checkpointer_optimizer,
                 &self.checkpointer_scheduler,
+                &self.checkpointer_rng,
                 epoch,
             );
         }
@@ -1,14 +1,11 @@
 use burn_tensor::backend::Backend;
-use rand::{rngs::StdRng, SeedableRng};
 
 use crate::{
     element::{FloatElement, IntElement},
     tensor::WgpuTensor,
     GraphicsApi, WgpuDevice,
 };
-use std::{marker::PhantomData, sync::Mutex};
-
-pub(crate) static SEED: Mutex<Option<StdRng>> = Mutex::new(None);
+use std::marker::PhantomData;
 
 /// Wgpu backend.
 #[derive(Debug, Default, Clone)]
@@ -35,9 +32,7 @@ impl<G: GraphicsApi + 'static, F: FloatElement, I: IntElement> Backend for WgpuB
     }
 
     fn seed(seed: u64) {
-        let rng = StdRng::seed_from_u64(seed);
-        let mut seed = SEED.lock().unwrap();
-        *seed = Some(rng);
+        burn_common::rand::seed(seed);
     }
 
     fn ad_enabled() -> bool {
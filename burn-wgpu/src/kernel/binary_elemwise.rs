@@ -11,6 +11,33 @@ kernel_wgsl!(
 /// Creates a binary elementwise kernel.
 #[macro_export]
 macro_rules! binary_elemwise {
+    (
+        $struct:ident,
+        body $body:expr
+    ) => {
+        pub struct $struct;
+
+        impl $crate::kernel::StaticKernel for $struct {
+            fn source_template() -> $crate::kernel::SourceTemplate {
+                $crate::kernel::BinaryElemwiseRaw::source_template().register("body", $body)
+            }
+        }
+    };
+    (
+        $struct:ident,
+        func $func:expr
+    ) => {
+        pub struct $struct;
+
+        impl $crate::kernel::StaticKernel for $struct {
+            fn source_template() -> $crate::kernel::SourceTemplate {
+                $crate::kernel::BinaryElemwiseRaw::source_template().register(
+                    "body",
+                    format!("output[id] = {}(lhs[index_lhs], rhs[index_rhs]);", $func),
+                )
+            }
+        }
+    };
     (
         $struct:ident,
         $ops:expr
@@ -31,6 +58,33 @@ macro_rules! binary_elemwise {
 /// Creates a binary elementwise inplace kernel.
 #[macro_export]
 macro_rules! binary_elemwise_inplace {
+    (
+        $struct:ident,
+        body $body:expr
+    ) => {
+        pub struct $struct;
+
+        impl $crate::kernel::StaticKernel for $struct {
+            fn source_template() -> $crate::kernel::SourceTemplate {
+                $crate::kernel::BinaryElemwiseInplaceRaw::source_template().register("body", $body)
+            }
+        }
+    };
+    (
+        $struct:ident,
+        func $func:expr
+    ) => {
+        pub struct $struct;
+
+        impl $crate::kernel::StaticKernel for $struct {
+            fn source_template() -> $crate::kernel::SourceTemplate {
+                $crate::kernel::BinaryElemwiseInplaceRaw::source_template().register(
+                    "body",
+                    format!("lhs[id] = {}(lhs[id], rhs[index_rhs]);", $func),
+                )
+            }
+        }
+    };
     (
         $struct:ident,
         $ops:expr
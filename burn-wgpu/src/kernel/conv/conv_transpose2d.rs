@@ -4,7 +4,10 @@ use crate::{
     kernel_wgsl,
     tensor::WgpuTensor,
 };
-use burn_tensor::{ops::ConvTransposeOptions, Shape};
+use burn_tensor::{
+    ops::{conv::calculate_conv_transpose_output_size, ConvTransposeOptions},
+    Shape,
+};
 
 kernel_wgsl!(ConvTranspose2d, "../../template/conv/conv_transpose2d.wgsl");
 
@@ -21,16 +24,22 @@ pub(crate) fn conv_transpose2d<E: WgpuElement>(
     let [batch_size, _, in_height, in_width] = input.shape.dims;
     let [_, out_channels, kernel_0, kernel_1] = weight.shape.dims;
 
-    let out_0 = (in_height - 1) * options.stride[0]
-        + options.dilation[0] * (kernel_0 - 1)
-        + options.padding_out[0]
-        - 2 * options.padding[0]
-        + 1;
-    let out_1 = (in_width - 1) * options.stride[1]
-        + options.dilation[1] * (kernel_1 - 1)
-        + options.padding_out[1]
-        - 2 * options.padding[1]
-        + 1;
+    let out_0 = calculate_conv_transpose_output_size(
+        kernel_0,
+        options.stride[0],
+        options.padding[0],
+        options.padding_out[0],
+        options.dilation[0],
+        in_height,
+    );
+    let out_1 = calculate_conv_transpose_output_size(
+        kernel_1,
+        options.stride[1],
+        options.padding[1],
+        options.padding_out[1],
+        options.dilation[1],
+        in_width,
+    );
 
     let shape_out = Shape::new([batch_size, out_channels * options.groups, out_0, out_1]);
     let num_elems = shape_out.num_elements();
@@ -1,26 +1,16 @@
 use std::sync::Arc;
 
-use burn_common::rand::get_seeded_rng;
+use burn_common::rand::with_device_rng;
 use burn_tensor::Shape;
 use rand::Rng;
 use wgpu::Buffer;
 
-use crate::{context::Context, element::WgpuElement, kernel_wgsl, tensor::WgpuTensor, SEED};
+use crate::{context::Context, element::WgpuElement, kernel_wgsl, tensor::WgpuTensor};
 
 kernel_wgsl!(Prng, "../../template/prng/prng.wgsl");
 
-pub(crate) fn get_seeds() -> Vec<u32> {
-    let mut seed = SEED.lock().unwrap();
-    let mut rng = match seed.as_ref() {
-        Some(rng_seeded) => rng_seeded.clone(),
-        None => get_seeded_rng(),
-    };
-    let mut seeds: Vec<u32> = Vec::with_capacity(4);
-    for _ in 0..4 {
-        seeds.push(rng.gen());
-    }
-    *seed = Some(rng);
-    seeds
+pub(crate) fn get_seeds(context: &Context) -> Vec<u32> {
+    with_device_rng(&context.device, |rng| (0..4).map(|_| rng.gen()).collect())
 }
 
 pub(crate) fn make_output_tensor<E: WgpuElement, const D: usize>(
@@ -32,7 +22,7 @@ pub(crate) fn make_output_tensor<E: WgpuElement, const D: usize>(
 }
 
 pub(crate) fn make_info_buffer(context: Arc<Context>, n_values_per_thread: usize) -> Arc<Buffer> {
-    let mut info = get_seeds();
+    let mut info = get_seeds(&context);
     info.insert(0, n_values_per_thread as u32);
     context.create_buffer_with_data(bytemuck::cast_slice(&info))
 }
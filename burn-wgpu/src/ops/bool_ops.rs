@@ -5,7 +5,7 @@ use crate::{
     tensor::WgpuTensor,
     GraphicsApi, WgpuBackend,
 };
-use burn_tensor::{ops::BoolTensorOps, ops::IntTensorOps, Data, Shape};
+use burn_tensor::{ops::BoolTensorOps, Data, Shape};
 use std::ops::Range;
 
 impl<G, F, I> BoolTensorOps<WgpuBackend<G, F, I>> for WgpuBackend<G, F, I>
@@ -50,10 +50,7 @@ where
             return WgpuTensor::new(tensor.context, tensor.shape, tensor.buffer);
         }
 
-        let device = Self::bool_device(&tensor);
-        let data = Self::bool_into_data(tensor).convert::<I>();
-
-        Self::int_from_data(data, &device)
+        kernel::cast(tensor)
     }
 
     fn bool_device<const D: usize>(tensor: &BoolTensor<Self, D>) -> Device<Self> {
@@ -408,6 +408,79 @@ where
         unary_default::<Sin, F, D>(tensor)
     }
 
+    fn atan<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary!(Atan, func "atan");
+        unary_inplace!(AtanInplace, func "atan");
+
+        if tensor.can_mut() {
+            return unary_inplace_default::<AtanInplace, F, D>(tensor);
+        }
+
+        unary_default::<Atan, F, D>(tensor)
+    }
+
+    fn tan<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary!(Tan, func "tan");
+        unary_inplace!(TanInplace, func "tan");
+
+        if tensor.can_mut() {
+            return unary_inplace_default::<TanInplace, F, D>(tensor);
+        }
+
+        unary_default::<Tan, F, D>(tensor)
+    }
+
+    fn asin<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary!(Asin, func "asin");
+        unary_inplace!(AsinInplace, func "asin");
+
+        if tensor.can_mut() {
+            return unary_inplace_default::<AsinInplace, F, D>(tensor);
+        }
+
+        unary_default::<Asin, F, D>(tensor)
+    }
+
+    fn acos<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary!(Acos, func "acos");
+        unary_inplace!(AcosInplace, func "acos");
+
+        if tensor.can_mut() {
+            return unary_inplace_default::<AcosInplace, F, D>(tensor);
+        }
+
+        unary_default::<Acos, F, D>(tensor)
+    }
+
+    fn atan2<const D: usize>(
+        lhs: FloatTensor<Self, D>,
+        rhs: FloatTensor<Self, D>,
+    ) -> FloatTensor<Self, D> {
+        numeric::atan2(lhs, rhs)
+    }
+
+    fn sinh<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary!(Sinh, func "sinh");
+        unary_inplace!(SinhInplace, func "sinh");
+
+        if tensor.can_mut() {
+            return unary_inplace_default::<SinhInplace, F, D>(tensor);
+        }
+
+        unary_default::<Sinh, F, D>(tensor)
+    }
+
+    fn cosh<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary!(Cosh, func "cosh");
+        unary_inplace!(CoshInplace, func "cosh");
+
+        if tensor.can_mut() {
+            return unary_inplace_default::<CoshInplace, F, D>(tensor);
+        }
+
+        unary_default::<Cosh, F, D>(tensor)
+    }
+
     fn tanh<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
         // Metal has a weird numerical behaviour with tanh which require a new function
         #[cfg(target_os = "macos")]
@@ -438,6 +511,28 @@ where
         unary_default::<Erf, F, D>(tensor)
     }
 
+    fn erfc<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary!(Erfc, func "erfc", include "../template/erf.wgsl");
+        unary_inplace!(ErfcInplace, func "erfc", include "../template/erf.wgsl");
+
+        if tensor.can_mut() {
+            return unary_inplace_default::<ErfcInplace, F, D>(tensor);
+        }
+
+        unary_default::<Erfc, F, D>(tensor)
+    }
+
+    fn lgamma<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        unary!(Lgamma, func "lgamma", include "../template/lgamma.wgsl");
+        unary_inplace!(LgammaInplace, func "lgamma", include "../template/lgamma.wgsl");
+
+        if tensor.can_mut() {
+            return unary_inplace_default::<LgammaInplace, F, D>(tensor);
+        }
+
+        unary_default::<Lgamma, F, D>(tensor)
+    }
+
     fn cat<const D: usize>(tensors: Vec<FloatTensor<Self, D>>, dim: usize) -> FloatTensor<Self, D> {
         kernel::cat(tensors, dim)
     }
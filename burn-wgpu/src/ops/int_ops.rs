@@ -1,6 +1,9 @@
 use super::{numeric, BoolTensor, Device, FloatTensor, IntElem, IntTensor};
-use crate::kernel::{unary_default, unary_inplace_default};
+use crate::kernel::{
+    binary_elemwise_default, binary_elemwise_inplace_default, unary_default, unary_inplace_default,
+};
 use crate::{
+    binary_elemwise, binary_elemwise_inplace,
     element::{FloatElement, IntElement},
     kernel, unary, unary_inplace, GraphicsApi, WgpuBackend,
 };
@@ -307,6 +310,101 @@ where
         unary_default::<IntAbs, I, D>(tensor)
     }
 
+    fn int_bitwise_and<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        binary_elemwise!(IntBitwiseAnd, "&");
+        binary_elemwise_inplace!(IntBitwiseAndInplace, "&");
+
+        if lhs.can_mut_broadcast(&rhs) {
+            return binary_elemwise_inplace_default::<IntBitwiseAndInplace, I, D>(lhs, rhs);
+        }
+
+        binary_elemwise_default::<IntBitwiseAnd, I, D>(lhs, rhs)
+    }
+
+    fn int_bitwise_or<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        binary_elemwise!(IntBitwiseOr, "|");
+        binary_elemwise_inplace!(IntBitwiseOrInplace, "|");
+
+        if lhs.can_mut_broadcast(&rhs) {
+            return binary_elemwise_inplace_default::<IntBitwiseOrInplace, I, D>(lhs, rhs);
+        }
+
+        binary_elemwise_default::<IntBitwiseOr, I, D>(lhs, rhs)
+    }
+
+    fn int_bitwise_xor<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        binary_elemwise!(IntBitwiseXor, "^");
+        binary_elemwise_inplace!(IntBitwiseXorInplace, "^");
+
+        if lhs.can_mut_broadcast(&rhs) {
+            return binary_elemwise_inplace_default::<IntBitwiseXorInplace, I, D>(lhs, rhs);
+        }
+
+        binary_elemwise_default::<IntBitwiseXor, I, D>(lhs, rhs)
+    }
+
+    fn int_bitwise_not<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        unary!(IntBitwiseNot, body "output[id] = ~input[id];");
+        unary_inplace!(IntBitwiseNotInplace, body "input[id] = ~input[id];");
+
+        if tensor.can_mut() {
+            return unary_inplace_default::<IntBitwiseNotInplace, I, D>(tensor);
+        }
+
+        unary_default::<IntBitwiseNot, I, D>(tensor)
+    }
+
+    fn int_bitwise_left_shift<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        // The number of bits of the shift operators has to be unsigned.
+        binary_elemwise!(
+            IntBitwiseLeftShift,
+            body "output[id] = lhs[index_lhs] << u32(rhs[index_rhs]);"
+        );
+        binary_elemwise_inplace!(
+            IntBitwiseLeftShiftInplace,
+            body "lhs[id] = lhs[id] << u32(rhs[index_rhs]);"
+        );
+
+        if lhs.can_mut_broadcast(&rhs) {
+            return binary_elemwise_inplace_default::<IntBitwiseLeftShiftInplace, I, D>(lhs, rhs);
+        }
+
+        binary_elemwise_default::<IntBitwiseLeftShift, I, D>(lhs, rhs)
+    }
+
+    fn int_bitwise_right_shift<const D: usize>(
+        lhs: IntTensor<Self, D>,
+        rhs: IntTensor<Self, D>,
+    ) -> IntTensor<Self, D> {
+        // The number of bits of the shift operators has to be unsigned.
+        binary_elemwise!(
+            IntBitwiseRightShift,
+            body "output[id] = lhs[index_lhs] >> u32(rhs[index_rhs]);"
+        );
+        binary_elemwise_inplace!(
+            IntBitwiseRightShiftInplace,
+            body "lhs[id] = lhs[id] >> u32(rhs[index_rhs]);"
+        );
+
+        if lhs.can_mut_broadcast(&rhs) {
+            return binary_elemwise_inplace_default::<IntBitwiseRightShiftInplace, I, D>(lhs, rhs);
+        }
+
+        binary_elemwise_default::<IntBitwiseRightShift, I, D>(lhs, rhs)
+    }
+
     fn int_into_float<const D: usize>(tensor: IntTensor<Self, D>) -> FloatTensor<Self, D> {
         kernel::cast(tensor)
     }
@@ -138,6 +138,20 @@ pub fn div<E: WgpuElement, const D: usize>(
     binary_elemwise_default::<Div, E, D>(lhs, rhs)
 }
 
+pub fn atan2<E: WgpuElement, const D: usize>(
+    lhs: WgpuTensor<E, D>,
+    rhs: WgpuTensor<E, D>,
+) -> WgpuTensor<E, D> {
+    binary_elemwise!(Atan2, func "atan2");
+    binary_elemwise_inplace!(Atan2Inplace, func "atan2");
+
+    if lhs.can_mut_broadcast(&rhs) {
+        return binary_elemwise_inplace_default::<Atan2Inplace, E, D>(lhs, rhs);
+    }
+
+    binary_elemwise_default::<Atan2, E, D>(lhs, rhs)
+}
+
 pub fn div_scalar<E: WgpuElement, const D: usize>(
     lhs: WgpuTensor<E, D>,
     rhs: E,
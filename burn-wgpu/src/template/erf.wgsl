@@ -23,3 +23,7 @@ fn erf(x: {{ elem }}) -> {{ elem }} {
 
     return erf_positive(x);
 }
+
+fn erfc(x: {{ elem }}) -> {{ elem }} {
+    return 1.0 - erf(x);
+}
@@ -0,0 +1,28 @@
+/// The Lanczos approximation of the log-gamma function: https://en.wikipedia.org/wiki/Lanczos_approximation
+///
+/// > The approximation is valid for x >= 0.5, the reflection formula being used for smaller x.
+fn lgamma_lanczos(x: {{ elem }}) -> {{ elem }} {
+    let z = x - 1.0;
+    var a = 0.99999999999980993;
+    a += 676.5203681218851 / (z + 1.0);
+    a += -1259.1392167224028 / (z + 2.0);
+    a += 771.32342877765313 / (z + 3.0);
+    a += -176.61502916214059 / (z + 4.0);
+    a += 12.507343278686905 / (z + 5.0);
+    a += -0.13857109526572012 / (z + 6.0);
+    a += 9.9843695780195716e-6 / (z + 7.0);
+    a += 1.5056327351493116e-7 / (z + 8.0);
+
+    let t = z + 7.5;
+
+    return 0.91893853320467274 + (z + 0.5) * log(t) - t + log(a);
+}
+
+fn lgamma(x: {{ elem }}) -> {{ elem }} {
+    if (x < 0.5) {
+        let pi = 3.14159265358979323846;
+        return log(pi / abs(sin(pi * x))) - lgamma_lanczos(1.0 - x);
+    }
+
+    return lgamma_lanczos(x);
+}